@@ -20,6 +20,7 @@ from materialize import MZ_ROOT, buildkite, rustc_flags, spawn, ui
 from materialize.cli.run import SANITIZER_TARGET
 from materialize.mzcompose.composition import Composition, WorkflowArgumentParser
 from materialize.mzcompose.services.azure import Azurite
+from materialize.mzcompose.services.gcs import FakeGcsServer
 from materialize.mzcompose.services.kafka import Kafka
 from materialize.mzcompose.services.minio import Minio
 from materialize.mzcompose.services.postgres import (
@@ -57,6 +58,10 @@ SERVICES = [
         ports=["40111:10000"],
         allow_host_ports=True,
     ),
+    FakeGcsServer(
+        ports=["40112:4443"],
+        allow_host_ports=True,
+    ),
 ]
 
 
@@ -77,6 +82,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         c.metadata_store(),
         "minio",
         "azurite",
+        "fake-gcs-server",
     )
     # Heads up: this intentionally runs on the host rather than in a Docker
     # image. See database-issues#3739.
@@ -96,6 +102,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_S3_BUCKET="mz-test-persist-1d-lifecycle-delete",
         MZ_S3_UPLOADER_TEST_S3_BUCKET="mz-test-1d-lifecycle-delete",
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_AZURE_CONTAINER="mz-test-azure",
+        MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET="mz-test-gcs",
         MZ_PERSIST_EXTERNAL_STORAGE_TEST_POSTGRES_URL=cockroach_url,
     )
 
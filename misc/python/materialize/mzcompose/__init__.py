@@ -451,6 +451,8 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "persist_expression_cache_force_compaction_wait",
     "persist_blob_cache_mem_limit_bytes",
     "persist_blob_cache_scale_factor_bytes",
+    "persist_blob_cache_disk_enabled",
+    "persist_blob_cache_disk_limit_bytes",
     "persist_claim_unclaimed_compactions",
     "persist_claim_compaction_percent",
     "persist_claim_compaction_min_version",
@@ -0,0 +1,63 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+
+from materialize.mzcompose.service import Service, ServiceHealthcheck
+
+
+def gcs_blob_uri(address: str = "fake-gcs-server", bucket: str = "persist") -> str:
+    return f"gs://{bucket}/persist?endpoint=http://{address}:4443"
+
+
+class FakeGcsServer(Service):
+    DEFAULT_FAKE_GCS_SERVER_TAG = "1.52.2"
+
+    def __init__(
+        self,
+        name: str = "fake-gcs-server",
+        image: str | None = None,
+        healthcheck: ServiceHealthcheck | None = None,
+        ports: list[int | str] = [4443],
+        allow_host_ports: bool = False,
+    ):
+        if image is None:
+            image = f"fsouza/fake-gcs-server:{self.DEFAULT_FAKE_GCS_SERVER_TAG}"
+
+        if healthcheck is None:
+            healthcheck = {
+                "test": [
+                    "CMD",
+                    "wget",
+                    "-q",
+                    "-O",
+                    "-",
+                    "http://127.0.0.1:4443/_internal/healthcheck",
+                ],
+                "interval": "1s",
+                "start_period": "30s",
+            }
+
+        super().__init__(
+            name=name,
+            config={
+                "image": image,
+                "ports": ports,
+                "allow_host_ports": allow_host_ports,
+                "command": [
+                    "-scheme",
+                    "http",
+                    "-port",
+                    "4443",
+                    "-backend",
+                    "memory",
+                ],
+                "init": True,
+                "healthcheck": healthcheck,
+            },
+        )
@@ -1178,6 +1178,8 @@ class FlipFlagsAction(Action):
             "persist_blob_cache_mem_limit_bytes",
             "persist_blob_cache_scale_with_threads",
             "persist_blob_cache_scale_factor_bytes",
+            "persist_blob_cache_disk_enabled",
+            "persist_blob_cache_disk_limit_bytes",
             "persist_claim_compaction_percent",
             "persist_claim_compaction_min_version",
             "persist_next_listen_batch_retryer_fixed_sleep",
@@ -16,19 +16,24 @@ use std::iter;
 use anyhow::anyhow;
 use itertools::Itertools;
 use mz_adapter_types::connection::ConnectionId;
+use mz_catalog::memory::objects::DurableSubscription;
 use mz_compute_client::protocol::response::SubscribeBatch;
 use mz_controller_types::ClusterId;
 use mz_expr::compare_columns;
 use mz_ore::cast::CastFrom;
 use mz_ore::now::EpochMillis;
 use mz_repr::adt::numeric;
-use mz_repr::{CatalogItemId, Datum, Diff, GlobalId, IntoRowIterator, Row, Timestamp};
+use mz_repr::role_id::RoleId;
+use mz_repr::{
+    CatalogItemId, Datum, Diff, GlobalId, IntoRowIterator, Row, RowIterator, RowRef, Timestamp,
+};
 use mz_sql::plan::SubscribeOutput;
 use mz_storage_types::instances::StorageInstanceId;
 use timely::progress::Antichain;
 use tokio::sync::{mpsc, oneshot};
 use uuid::Uuid;
 
+use crate::coord::Message;
 use crate::coord::peek::PeekResponseUnary;
 use crate::{AdapterError, ExecuteContext, ExecuteResponse};
 
@@ -116,6 +121,36 @@ pub struct ActiveSubscribe {
     pub start_time: EpochMillis,
     /// How to present the subscribe's output.
     pub output: SubscribeOutput,
+    /// The durable subscription this subscribe reports its progress to, if any.
+    pub durable: Option<ActiveDurableSubscription>,
+    /// The `LISTEN` channel this subscribe notifies instead of sending updates to the client,
+    /// if any.
+    pub listen: Option<ActiveListenBinding>,
+}
+
+/// The durable subscription an [`ActiveSubscribe`] reports its progress to.
+#[derive(Debug)]
+pub struct ActiveDurableSubscription {
+    /// The name of the durable subscription.
+    pub name: String,
+    /// The role that owns the durable subscription.
+    pub owner_id: RoleId,
+    /// The collections the subscription reads from.
+    pub dependencies: Vec<GlobalId>,
+    /// Channel on which to acknowledge frontiers that the client has consumed.
+    pub internal_cmd_tx: mpsc::UnboundedSender<Message>,
+    /// The upper of the last batch sent to the client, which is acknowledged once the client
+    /// reads past that batch.
+    pub sent_frontier: Option<Timestamp>,
+}
+
+/// The `LISTEN` channel an [`ActiveSubscribe`] notifies of each batch of changes.
+#[derive(Debug)]
+pub struct ActiveListenBinding {
+    /// The name of the channel.
+    pub channel: String,
+    /// Channel on which to ask the coordinator to notify the listeners.
+    pub internal_cmd_tx: mpsc::UnboundedSender<Message>,
 }
 
 impl ActiveSubscribe {
@@ -160,7 +195,7 @@ impl ActiveSubscribe {
     /// Processes a subscribe response from the controller.
     ///
     /// Returns `true` if the subscribe is finished.
-    pub fn process_response(&self, batch: SubscribeBatch) -> bool {
+    pub fn process_response(&mut self, batch: SubscribeBatch) -> bool {
         let mut rows = match batch.updates {
             Ok(rows) => rows,
             Err(s) => {
@@ -169,6 +204,21 @@ impl ActiveSubscribe {
             }
         };
 
+        // Subscribes that back a `LISTEN ... ON` don't send updates to the client. Instead, each
+        // batch of changes raises a single notification carrying the latest time of a change.
+        if let Some(listen) = &self.listen {
+            if let Some(time) = rows.iter().map(|(time, _, _)| *time).max() {
+                // The coordinator may have shut down, in which case there is nobody left to
+                // notify.
+                let _ = listen.internal_cmd_tx.send(Message::ListenNotify {
+                    conn_id: self.conn_id.clone(),
+                    channel: listen.channel.clone(),
+                    payload: time.to_string(),
+                });
+            }
+            return batch.upper.is_empty();
+        }
+
         // Sort results by time. We use stable sort here because it will produce
         // deterministic results since the cursor will always produce rows in
         // the same order. Compute doesn't guarantee that the results are sorted
@@ -351,9 +401,33 @@ impl ActiveSubscribe {
                 row_buf.clone()
             })
             .collect();
-        let rows = Box::new(rows.into_row_iter());
-
-        self.send(PeekResponseUnary::Rows(rows));
+        let rows = rows.into_row_iter();
+
+        // For durable subscriptions, acknowledge the upper of the previous batch once the client
+        // starts reading this one, so that a resumed subscription starts from there. Consumers
+        // only read a batch after they have flushed the rows of the previous one to the client.
+        match (&mut self.durable, batch.upper.as_option()) {
+            (Some(durable), Some(upper)) => {
+                let ack =
+                    durable
+                        .sent_frontier
+                        .replace(*upper)
+                        .map(|frontier| DurableSubscriptionAck {
+                            internal_cmd_tx: durable.internal_cmd_tx.clone(),
+                            name: durable.name.clone(),
+                            subscription: DurableSubscription {
+                                owner_id: durable.owner_id,
+                                dependencies: durable.dependencies.clone(),
+                                frontier,
+                            },
+                        });
+                self.send(PeekResponseUnary::Rows(Box::new(AckingRowIter {
+                    rows,
+                    ack,
+                })));
+            }
+            _ => self.send(PeekResponseUnary::Rows(Box::new(rows))),
+        }
 
         // Emit progress message if requested. Don't emit progress for the first
         // batch if the upper is exactly `as_of` (we're guaranteed it is not
@@ -391,6 +465,60 @@ impl ActiveSubscribe {
     }
 }
 
+/// A pending acknowledgement of a durable subscription's frontier.
+#[derive(Debug, Clone)]
+struct DurableSubscriptionAck {
+    internal_cmd_tx: mpsc::UnboundedSender<Message>,
+    name: String,
+    subscription: DurableSubscription,
+}
+
+/// A [`RowIterator`] over a batch of a durable subscription that acknowledges the frontier of
+/// the previous batch when it is first read.
+///
+/// Consumers read the batches of a subscription in order, and only read a batch once the rows
+/// of the previous one have been flushed to the client, or once the client has asked for more
+/// rows after receiving them. Acknowledging the previous batch any earlier could lose its rows
+/// if the connection failed before they were delivered.
+#[derive(Debug, Clone)]
+struct AckingRowIter {
+    rows: <Vec<Row> as IntoRowIterator>::Iter,
+    ack: Option<DurableSubscriptionAck>,
+}
+
+impl AckingRowIter {
+    fn acknowledge(&mut self) {
+        if let Some(ack) = self.ack.take() {
+            // The coordinator may have shut down, in which case there is nobody left to record
+            // the acknowledgement.
+            let _ = ack.internal_cmd_tx.send(Message::DurableSubscriptionAck {
+                name: ack.name,
+                subscription: ack.subscription,
+            });
+        }
+    }
+}
+
+impl RowIterator for AckingRowIter {
+    fn next(&mut self) -> Option<&RowRef> {
+        self.acknowledge();
+        self.rows.next()
+    }
+
+    fn peek(&mut self) -> Option<&RowRef> {
+        self.acknowledge();
+        self.rows.peek()
+    }
+
+    fn count(&self) -> usize {
+        self.rows.count()
+    }
+
+    fn box_clone(&self) -> Box<dyn RowIterator> {
+        Box::new(self.clone())
+    }
+}
+
 /// A description of an active copy to sink from the coordinator's perspective.
 #[derive(Debug)]
 pub struct ActiveCopyTo {
@@ -412,7 +412,8 @@ impl Catalog {
                     | CatalogItemType::Func
                     | CatalogItemType::Secret
                     | CatalogItemType::Connection
-                    | CatalogItemType::ContinualTask => {
+                    | CatalogItemType::ContinualTask
+                    | CatalogItemType::Endpoint => {
                         dependencies.extend(global_ids);
                     }
                     CatalogItemType::View => {
@@ -1565,6 +1566,7 @@ pub(crate) fn comment_id_to_audit_object_type(id: CommentObjectId) -> ObjectType
         CommentObjectId::Cluster(_) => ObjectType::Cluster,
         CommentObjectId::ClusterReplica(_) => ObjectType::ClusterReplica,
         CommentObjectId::ContinualTask(_) => ObjectType::ContinualTask,
+        CommentObjectId::Endpoint(_) => ObjectType::Endpoint,
         CommentObjectId::NetworkPolicy(_) => ObjectType::NetworkPolicy,
     }
 }
@@ -1596,6 +1598,7 @@ pub(crate) fn system_object_type_to_audit_object_type(
             mz_sql::catalog::ObjectType::Schema => ObjectType::Schema,
             mz_sql::catalog::ObjectType::Func => ObjectType::Func,
             mz_sql::catalog::ObjectType::ContinualTask => ObjectType::ContinualTask,
+            mz_sql::catalog::ObjectType::Endpoint => ObjectType::Endpoint,
             mz_sql::catalog::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
         },
         SystemObjectType::System => ObjectType::System,
@@ -1719,6 +1722,14 @@ impl ExprHumanizer for ConnCatalog<'_> {
                 None => "varchar".into(),
                 Some(length) => format!("varchar({})", length.into_u32()),
             },
+            Bit { length } if !postgres_compat => match length {
+                None => "bit".into(),
+                Some(length) => format!("bit({})", length.into_u32()),
+            },
+            VarBit { max_length } if !postgres_compat => match max_length {
+                None => "varbit".into(),
+                Some(length) => format!("varbit({})", length.into_u32()),
+            },
             UInt16 => "uint2".into(),
             UInt32 => "uint4".into(),
             UInt64 => "uint8".into(),
@@ -3074,6 +3085,13 @@ mod tests {
                                     ty.name
                                 );
                             }
+                            CatalogType::MultiRange { .. } => {
+                                assert_eq!(
+                                    pg_ty.ty, "m",
+                                    "type {} is not a multirange type as expected",
+                                    ty.name
+                                );
+                            }
                             _ => {
                                 assert_eq!(
                                     pg_ty.ty, "b",
@@ -3243,10 +3261,22 @@ mod tests {
                 "avg_internal_v1",
                 "bool_and",
                 "bool_or",
+                "corr",
+                "covar_pop",
+                "covar_samp",
                 "mod",
                 "mz_panic",
                 "mz_sleep",
                 "pow",
+                "regr_avgx",
+                "regr_avgy",
+                "regr_count",
+                "regr_intercept",
+                "regr_r2",
+                "regr_slope",
+                "regr_sxx",
+                "regr_sxy",
+                "regr_syy",
                 "stddev_pop",
                 "stddev_samp",
                 "stddev",
@@ -3604,6 +3634,9 @@ mod tests {
                         | ScalarType::RegClass
                         | ScalarType::Int2Vector
                         | ScalarType::Range { .. }
+                        | ScalarType::MultiRange { .. }
+                        | ScalarType::Bit { .. }
+                        | ScalarType::VarBit { .. }
                         | ScalarType::PgLegacyName => {}
                     }
                 }
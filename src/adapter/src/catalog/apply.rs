@@ -271,6 +271,12 @@ impl CatalogState {
             StateUpdateKind::SourceReferences(source_reference) => {
                 self.apply_source_references_update(source_reference, diff, retractions);
             }
+            StateUpdateKind::DurableSubscription(durable_subscription) => {
+                self.apply_durable_subscription_update(durable_subscription, diff, retractions);
+            }
+            StateUpdateKind::ReplicationSlot(replication_slot) => {
+                self.apply_replication_slot_update(replication_slot, diff, retractions);
+            }
             StateUpdateKind::AuditLog(_audit_log) => {
                 // Audit logs are not stored in-memory.
             }
@@ -688,6 +694,7 @@ impl CatalogState {
                         is_retained_metrics_object: table.is_retained_metrics_object,
                         data_source: TableDataSource::TableWrites {
                             defaults: vec![Expr::null(); table.desc.arity()],
+                            domains: vec![None; table.desc.arity()],
                         },
                     }),
                     MZ_SYSTEM_ROLE_ID,
@@ -1139,6 +1146,63 @@ impl CatalogState {
         }
     }
 
+    #[instrument(level = "debug")]
+    fn apply_durable_subscription_update(
+        &mut self,
+        durable_subscription: mz_catalog::durable::DurableSubscription,
+        diff: StateDiff,
+        _retractions: &mut InProgressRetractions,
+    ) {
+        match diff {
+            StateDiff::Addition => {
+                let prev = self.durable_subscriptions.insert(
+                    durable_subscription.name.clone(),
+                    durable_subscription.into(),
+                );
+                assert!(
+                    prev.is_none(),
+                    "values must be explicitly retracted before inserting a new value: {prev:?}"
+                );
+            }
+            StateDiff::Retraction => {
+                let prev = self
+                    .durable_subscriptions
+                    .remove(&durable_subscription.name);
+                assert!(
+                    prev.is_some(),
+                    "retraction for a non-existent existing value: {durable_subscription:?}"
+                );
+            }
+        }
+    }
+
+    #[instrument(level = "debug")]
+    fn apply_replication_slot_update(
+        &mut self,
+        replication_slot: mz_catalog::durable::ReplicationSlot,
+        diff: StateDiff,
+        _retractions: &mut InProgressRetractions,
+    ) {
+        match diff {
+            StateDiff::Addition => {
+                let prev = self
+                    .replication_slots
+                    .insert(replication_slot.name.clone(), replication_slot.into());
+                assert!(
+                    prev.is_none(),
+                    "values must be explicitly retracted before inserting a new value: {prev:?}"
+                );
+            }
+            StateDiff::Retraction => {
+                let prev = self.replication_slots.remove(&replication_slot.name);
+                assert!(
+                    prev.is_some(),
+                    "retraction for a non-existent existing value: {replication_slot:?}"
+                );
+            }
+        }
+    }
+
     #[instrument(level = "debug")]
     fn apply_storage_collection_metadata_update(
         &mut self,
@@ -1279,6 +1343,8 @@ impl CatalogState {
                 .expect("could not pack audit log update"),
             StateUpdateKind::StorageCollectionMetadata(_)
             | StateUpdateKind::UnfinalizedShard(_)
+            | StateUpdateKind::DurableSubscription(_)
+            | StateUpdateKind::ReplicationSlot(_)
             | StateUpdateKind::RoleAuth(_) => Vec::new(),
         }
     }
@@ -1874,6 +1940,8 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             ),
             StateUpdateKind::Comment(_)
             | StateUpdateKind::SourceReferences(_)
+            | StateUpdateKind::DurableSubscription(_)
+            | StateUpdateKind::ReplicationSlot(_)
             | StateUpdateKind::AuditLog(_)
             | StateUpdateKind::StorageCollectionMetadata(_)
             | StateUpdateKind::UnfinalizedShard(_) => push_update(
@@ -1923,6 +1991,7 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
             | CatalogItemType::Type
             | CatalogItemType::Func
             | CatalogItemType::Secret
+            | CatalogItemType::Endpoint
             | CatalogItemType::Connection => push_update(
                 StateUpdate {
                     kind: StateUpdateKind::SystemObjectMapping(builtin_item_update),
@@ -2044,7 +2113,8 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
                 CatalogItemType::Table => tables.push(update),
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
-                | CatalogItemType::Index => derived_items.push(update),
+                | CatalogItemType::Index
+                | CatalogItemType::Endpoint => derived_items.push(update),
                 CatalogItemType::Sink => sinks.push(update),
                 CatalogItemType::ContinualTask => continual_tasks.push(update),
             }
@@ -2114,7 +2184,8 @@ fn sort_updates_inner(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
                 CatalogItemType::Table => tables.push(update),
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
-                | CatalogItemType::Index => derived_items.push(update),
+                | CatalogItemType::Index
+                | CatalogItemType::Endpoint => derived_items.push(update),
                 CatalogItemType::Sink => sinks.push(update),
                 CatalogItemType::ContinualTask => continual_tasks.push(update),
             }
@@ -767,6 +767,8 @@ impl CatalogState {
             CatalogItem::ContinualTask(ct) => self.pack_continual_task_update(
                 id, oid, schema_id, name, owner_id, privileges, ct, diff,
             ),
+            // Endpoints are not yet exposed in the system catalog.
+            CatalogItem::Endpoint(_) => vec![],
         };
 
         if !entry.item().is_temporary() {
@@ -782,7 +784,7 @@ impl CatalogState {
         if let Ok(desc) = entry.desc_latest(&full_name) {
             let defaults = match entry.item() {
                 CatalogItem::Table(Table {
-                    data_source: TableDataSource::TableWrites { defaults },
+                    data_source: TableDataSource::TableWrites { defaults, .. },
                     ..
                 }) => Some(defaults),
                 _ => None,
@@ -2224,7 +2226,8 @@ impl CatalogState {
             | CommentObjectId::Connection(global_id)
             | CommentObjectId::Secret(global_id)
             | CommentObjectId::Type(global_id)
-            | CommentObjectId::ContinualTask(global_id) => global_id.to_string(),
+            | CommentObjectId::ContinualTask(global_id)
+            | CommentObjectId::Endpoint(global_id) => global_id.to_string(),
             CommentObjectId::Role(role_id) => role_id.to_string(),
             CommentObjectId::Database(database_id) => database_id.to_string(),
             CommentObjectId::Schema((_, schema_id)) => schema_id.to_string(),
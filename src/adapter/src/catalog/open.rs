@@ -150,6 +150,8 @@ impl Catalog {
             system_privileges: PrivilegeMap::default(),
             comments: CommentsMap::default(),
             source_references: BTreeMap::new(),
+            durable_subscriptions: BTreeMap::new(),
+            replication_slots: BTreeMap::new(),
             storage_metadata: Default::default(),
             temporary_schemas: BTreeMap::new(),
             config: mz_sql::catalog::CatalogConfig {
@@ -311,6 +313,8 @@ impl Catalog {
                 BootstrapStateUpdateKind::Comment(_)
                 | BootstrapStateUpdateKind::StorageCollectionMetadata(_)
                 | BootstrapStateUpdateKind::SourceReferences(_)
+                | BootstrapStateUpdateKind::DurableSubscription(_)
+                | BootstrapStateUpdateKind::ReplicationSlot(_)
                 | BootstrapStateUpdateKind::UnfinalizedShard(_) => {
                     post_item_updates.push((kind, ts, diff));
                 }
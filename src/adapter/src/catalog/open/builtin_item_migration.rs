@@ -258,6 +258,7 @@ async fn migrate_builtin_items_0dt(
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Endpoint(_)
                 | CatalogItem::Connection(_) => return None,
             };
             let GlobalId::System(raw_gid) = gid else {
@@ -31,9 +31,9 @@ use mz_catalog::expr_cache::LocalExpressions;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogCollectionEntry, CatalogEntry, CatalogItem, Cluster, ClusterReplica, CommentsMap,
-    Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    NetworkPolicy, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences, Table,
-    TableDataSource, Type, View,
+    Connection, DataSourceDesc, Database, DefaultPrivileges, DurableSubscription, Endpoint, Index,
+    MaterializedView, NetworkPolicy, ReplicationSlot, Role, RoleAuth, Schema, Secret, Sink, Source,
+    SourceReferences, Table, TableDataSource, Type, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaAllocation, ReplicaLocation,
@@ -71,9 +71,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateEndpointPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -139,6 +139,8 @@ pub struct CatalogState {
     pub(super) comments: CommentsMap,
     #[serde(serialize_with = "mz_ore::serde::map_key_to_string")]
     pub(super) source_references: BTreeMap<CatalogItemId, SourceReferences>,
+    pub(super) durable_subscriptions: BTreeMap<String, DurableSubscription>,
+    pub(super) replication_slots: BTreeMap<String, ReplicationSlot>,
     pub(super) storage_metadata: StorageMetadata,
 
     // Mutable state not derived from the durable catalog.
@@ -308,6 +310,8 @@ impl CatalogState {
             system_privileges: Default::default(),
             comments: Default::default(),
             source_references: Default::default(),
+            durable_subscriptions: Default::default(),
+            replication_slots: Default::default(),
             storage_metadata: Default::default(),
         }
     }
@@ -409,7 +413,8 @@ impl CatalogState {
             item @ (CatalogItem::View(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Connection(_)
-            | CatalogItem::ContinualTask(_)) => {
+            | CatalogItem::ContinualTask(_)
+            | CatalogItem::Endpoint(_)) => {
                 // TODO(jkosh44) Unclear if this table wants to include all uses or only references.
                 for item_id in item.references().items() {
                     self.introspection_dependencies_inner(*item_id, out);
@@ -1092,8 +1097,8 @@ impl CatalogState {
                         .or(table.compaction_window),
                     is_retained_metrics_object,
                     data_source: match table.data_source {
-                        mz_sql::plan::TableDataSource::TableWrites { defaults } => {
-                            TableDataSource::TableWrites { defaults }
+                        mz_sql::plan::TableDataSource::TableWrites { defaults, domains } => {
+                            TableDataSource::TableWrites { defaults, domains }
                         }
                         mz_sql::plan::TableDataSource::DataSource {
                             desc: data_source_desc,
@@ -1392,6 +1397,15 @@ impl CatalogState {
                 create_sql: secret.create_sql,
                 global_id,
             }),
+            Plan::CreateEndpoint(CreateEndpointPlan { endpoint, .. }) => {
+                CatalogItem::Endpoint(Endpoint {
+                    create_sql: endpoint.create_sql,
+                    global_id,
+                    query: endpoint.query,
+                    resolved_ids,
+                    dependencies: endpoint.dependencies,
+                })
+            }
             Plan::CreateConnection(CreateConnectionPlan {
                 connection:
                     mz_sql::plan::Connection {
@@ -1739,7 +1753,8 @@ impl CatalogState {
             | CatalogItemType::Index
             | CatalogItemType::Secret
             | CatalogItemType::Connection
-            | CatalogItemType::ContinualTask => schema.items[builtin.name()],
+            | CatalogItemType::ContinualTask
+            | CatalogItemType::Endpoint => schema.items[builtin.name()],
         }
     }
 
@@ -1774,6 +1789,20 @@ impl CatalogState {
             CatalogType::Range { element_reference } => CatalogType::Range {
                 element_reference: self.get_system_type(element_reference).id,
             },
+            CatalogType::MultiRange { element_reference } => CatalogType::MultiRange {
+                element_reference: self.get_system_type(element_reference).id,
+            },
+            CatalogType::Domain {
+                base_reference,
+                base_modifiers,
+                not_null,
+                checks,
+            } => CatalogType::Domain {
+                base_reference: self.get_system_type(base_reference).id,
+                base_modifiers: base_modifiers.clone(),
+                not_null: *not_null,
+                checks: checks.clone(),
+            },
             CatalogType::Record { fields } => CatalogType::Record {
                 fields: fields
                     .into_iter()
@@ -1784,6 +1813,7 @@ impl CatalogState {
                     })
                     .collect(),
             },
+            CatalogType::Bit => CatalogType::Bit,
             CatalogType::Bool => CatalogType::Bool,
             CatalogType::Bytes => CatalogType::Bytes,
             CatalogType::Char => CatalogType::Char,
@@ -1812,6 +1842,7 @@ impl CatalogState {
             CatalogType::Timestamp => CatalogType::Timestamp,
             CatalogType::TimestampTz => CatalogType::TimestampTz,
             CatalogType::Uuid => CatalogType::Uuid,
+            CatalogType::VarBit => CatalogType::VarBit,
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
@@ -2148,6 +2179,7 @@ impl CatalogState {
                     CatalogItemType::Type => CommentObjectId::Type(item_id),
                     CatalogItemType::Secret => CommentObjectId::Secret(item_id),
                     CatalogItemType::ContinualTask => CommentObjectId::ContinualTask(item_id),
+                    CatalogItemType::Endpoint => CommentObjectId::Endpoint(item_id),
                 }
             }
             ObjectId::Role(role_id) => CommentObjectId::Role(role_id),
@@ -2489,6 +2521,16 @@ impl CatalogState {
         &self.storage_metadata
     }
 
+    /// Returns all durable subscriptions, keyed by name.
+    pub fn durable_subscriptions(&self) -> &BTreeMap<String, DurableSubscription> {
+        &self.durable_subscriptions
+    }
+
+    /// Returns all logical replication slots, keyed by name.
+    pub fn replication_slots(&self) -> &BTreeMap<String, ReplicationSlot> {
+        &self.replication_slots
+    }
+
     /// For the Sources ids in `ids`, return the compaction windows for all `ids` and additional ids
     /// that propagate from them. Specifically, if `ids` contains a source, it and all of its
     /// source exports will be added to the result.
@@ -2570,7 +2612,8 @@ impl CatalogState {
             | CommentObjectId::Connection(id)
             | CommentObjectId::Type(id)
             | CommentObjectId::Secret(id)
-            | CommentObjectId::ContinualTask(id) => Some(*id),
+            | CommentObjectId::ContinualTask(id)
+            | CommentObjectId::Endpoint(id) => Some(*id),
             CommentObjectId::Role(_)
             | CommentObjectId::Database(_)
             | CommentObjectId::Schema(_)
@@ -2600,7 +2643,8 @@ impl CatalogState {
             | CommentObjectId::Connection(id)
             | CommentObjectId::Type(id)
             | CommentObjectId::Secret(id)
-            | CommentObjectId::ContinualTask(id) => {
+            | CommentObjectId::ContinualTask(id)
+            | CommentObjectId::Endpoint(id) => {
                 let item = self.get_entry(&id);
                 let name = self.resolve_full_name(item.name(), Some(conn_id));
                 name.to_string()
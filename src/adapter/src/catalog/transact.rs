@@ -29,8 +29,8 @@ use mz_catalog::builtin::BuiltinLog;
 use mz_catalog::durable::{NetworkPolicy, Transaction};
 use mz_catalog::memory::error::{AmbiguousRename, Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogItem, ClusterConfig, DataSourceDesc, SourceReferences, StateDiff, StateUpdate,
-    StateUpdateKind, TemporaryItem,
+    CatalogItem, ClusterConfig, DataSourceDesc, DurableSubscription, ReplicationSlot,
+    SourceReferences, StateDiff, StateUpdate, StateUpdateKind, TemporaryItem,
 };
 use mz_controller::clusters::{ManagedReplicaLocation, ReplicaConfig, ReplicaLocation};
 use mz_controller_types::{ClusterId, ReplicaId};
@@ -98,6 +98,18 @@ pub enum Op {
         typ: ColumnType,
         sql: RawDataType,
     },
+    AlterColumnType {
+        id: CatalogItemId,
+        new_global_id: GlobalId,
+        name: ColumnName,
+        typ: ColumnType,
+        sql: RawDataType,
+    },
+    AlterDropColumn {
+        id: CatalogItemId,
+        new_global_id: GlobalId,
+        name: ColumnName,
+    },
     CreateDatabase {
         name: String,
         owner_id: RoleId,
@@ -208,6 +220,20 @@ pub enum Op {
         source_id: CatalogItemId,
         references: SourceReferences,
     },
+    UpsertDurableSubscription {
+        name: String,
+        subscription: DurableSubscription,
+    },
+    DropDurableSubscriptions {
+        names: BTreeSet<String>,
+    },
+    UpsertReplicationSlot {
+        name: String,
+        slot: ReplicationSlot,
+    },
+    DropReplicationSlots {
+        names: BTreeSet<String>,
+    },
     UpdateSystemConfiguration {
         name: String,
         value: OwnedVarInput,
@@ -216,6 +242,10 @@ pub enum Op {
         name: String,
     },
     ResetAllSystemConfiguration,
+    /// Records how a client certificate was mapped to a role at login.
+    LogCertificateLogin {
+        details: mz_audit_log::CertificateLoginV1,
+    },
     /// Performs updates to the storage usage table, which probably should be a builtin source.
     ///
     /// TODO(jkosh44) In a multi-writer or high availability catalog world, this
@@ -740,6 +770,50 @@ impl Catalog {
                 tx.update_item(id, new_entry.into())?;
                 storage_collections_to_register.insert(new_global_id, shard_id);
             }
+            Op::AlterColumnType {
+                id,
+                new_global_id,
+                name,
+                typ,
+                sql,
+            } => {
+                let mut new_entry = state.get_entry(&id).clone();
+                let version = new_entry.item.alter_column_type(name, typ, sql)?;
+                // All versions of a table share the same shard, so it shouldn't matter what
+                // GlobalId we use here.
+                let shard_id = state
+                    .storage_metadata()
+                    .get_collection_shard(new_entry.latest_global_id())?;
+
+                let CatalogItem::Table(table) = &mut new_entry.item else {
+                    return Err(AdapterError::Unsupported("altering columns of non-Table"));
+                };
+                table.collections.insert(version, new_global_id);
+
+                tx.update_item(id, new_entry.into())?;
+                storage_collections_to_register.insert(new_global_id, shard_id);
+            }
+            Op::AlterDropColumn {
+                id,
+                new_global_id,
+                name,
+            } => {
+                let mut new_entry = state.get_entry(&id).clone();
+                let version = new_entry.item.drop_column(name)?;
+                // All versions of a table share the same shard, so it shouldn't matter what
+                // GlobalId we use here.
+                let shard_id = state
+                    .storage_metadata()
+                    .get_collection_shard(new_entry.latest_global_id())?;
+
+                let CatalogItem::Table(table) = &mut new_entry.item else {
+                    return Err(AdapterError::Unsupported("dropping columns of non-Table"));
+                };
+                table.collections.insert(version, new_global_id);
+
+                tx.update_item(id, new_entry.into())?;
+                storage_collections_to_register.insert(new_global_id, shard_id);
+            }
             Op::CreateDatabase { name, owner_id } => {
                 let database_owner_privileges = vec![rbac::owner_privilege(
                     mz_sql::catalog::ObjectType::Database,
@@ -1067,6 +1141,7 @@ impl Catalog {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Endpoint(_)
                     | CatalogItem::Connection(_) => (),
                 }
 
@@ -1347,6 +1422,18 @@ impl Catalog {
                     references.updated_at,
                 )?;
             }
+            Op::UpsertDurableSubscription { name, subscription } => {
+                tx.upsert_durable_subscription(subscription.to_durable(name))?;
+            }
+            Op::DropDurableSubscriptions { names } => {
+                tx.remove_durable_subscriptions(&names);
+            }
+            Op::UpsertReplicationSlot { name, slot } => {
+                tx.upsert_replication_slot(slot.to_durable(name))?;
+            }
+            Op::DropReplicationSlots { names } => {
+                tx.remove_replication_slots(&names);
+            }
             Op::DropObjects(drop_object_infos) => {
                 // Generate all of the objects that need to get dropped.
                 let delta = ObjectsToDrop::generate(drop_object_infos, state, session)?;
@@ -1354,6 +1441,42 @@ impl Catalog {
                 // Drop any associated comments.
                 tx.drop_comments(&delta.comments)?;
 
+                // Drop any durable subscriptions that read from a dropped item or are owned
+                // by a dropped role.
+                let dropped_global_ids: BTreeSet<_> = delta
+                    .items
+                    .iter()
+                    .flat_map(|item_id| state.get_entry(item_id).global_ids())
+                    .collect();
+                let durable_subscriptions_to_drop: BTreeSet<_> = state
+                    .durable_subscriptions
+                    .iter()
+                    .filter(|(_, subscription)| {
+                        delta.roles.contains(&subscription.owner_id)
+                            || subscription
+                                .dependencies
+                                .iter()
+                                .any(|id| dropped_global_ids.contains(id))
+                    })
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                tx.remove_durable_subscriptions(&durable_subscriptions_to_drop);
+
+                // Likewise drop any replication slots that stream a dropped item or are owned
+                // by a dropped role.
+                let replication_slots_to_drop: BTreeSet<_> = state
+                    .replication_slots
+                    .iter()
+                    .filter(|(_, slot)| {
+                        delta.roles.contains(&slot.owner_id)
+                            || slot
+                                .publication
+                                .is_some_and(|id| dropped_global_ids.contains(&id))
+                    })
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                tx.remove_replication_slots(&replication_slots_to_drop);
+
                 // Drop any items.
                 let (durable_items_to_drop, temporary_items_to_drop): (BTreeSet<_>, BTreeSet<_>) =
                     delta
@@ -2339,6 +2462,18 @@ impl Catalog {
                     EventDetails::ResetAllV1,
                 )?;
             }
+            Op::LogCertificateLogin { details } => {
+                CatalogState::add_to_audit_log(
+                    &state.system_configuration,
+                    oracle_write_ts,
+                    session,
+                    tx,
+                    audit_events,
+                    EventType::Login,
+                    ObjectType::Role,
+                    EventDetails::CertificateLoginV1(details),
+                )?;
+            }
             Op::WeirdStorageUsageUpdates {
                 object_id,
                 size_bytes,
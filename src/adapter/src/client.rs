@@ -33,7 +33,7 @@ use mz_ore::result::ResultExt;
 use mz_ore::task::AbortOnDropHandle;
 use mz_ore::thread::JoinOnDropHandle;
 use mz_ore::tracing::OpenTelemetryContext;
-use mz_repr::{CatalogItemId, ColumnIndex, Row, ScalarType};
+use mz_repr::{CatalogItemId, ColumnIndex, GlobalId, Row, ScalarType, Timestamp};
 use mz_sql::ast::{Raw, Statement};
 use mz_sql::catalog::{EnvironmentId, SessionCatalog};
 use mz_sql::session::hint::ApplicationNameHint;
@@ -50,6 +50,7 @@ use uuid::Uuid;
 use crate::catalog::Catalog;
 use crate::command::{
     AuthResponse, CatalogDump, CatalogSnapshot, Command, ExecuteResponse, Response,
+    SASLChallengeResponse, SASLVerifyProofResponse,
 };
 use crate::coord::{Coordinator, ExecuteContextExtra};
 use crate::error::AdapterError;
@@ -167,6 +168,67 @@ impl Client {
         Ok(response)
     }
 
+    /// Returns the salt and iteration count to use in the SCRAM
+    /// server-first-message for the given user.
+    ///
+    /// If the user does not exist or has no password, a mock challenge is
+    /// returned, so that the client cannot tell the difference until the
+    /// exchange fails.
+    pub async fn get_sasl_challenge(
+        &self,
+        user: &String,
+    ) -> Result<SASLChallengeResponse, AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::AuthenticateGetSASLChallenge {
+            role_name: user.to_string(),
+            tx,
+        });
+        let response = rx.await.expect("sender dropped")?;
+        Ok(response)
+    }
+
+    /// Verifies the proof in a SCRAM client-final-message for the given user.
+    pub async fn verify_sasl_proof(
+        &self,
+        user: &String,
+        proof: &str,
+        auth_message: &str,
+    ) -> Result<SASLVerifyProofResponse, AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::AuthenticateVerifySASLProof {
+            role_name: user.to_string(),
+            proof: proof.to_string(),
+            auth_message: auth_message.to_string(),
+            tx,
+        });
+        let response = rx.await.expect("sender dropped")?;
+        Ok(response)
+    }
+
+    /// Authenticates the given user with a verified client certificate.
+    ///
+    /// `identity` and `rule` describe the certificate identity and mapping
+    /// rule that granted access to `user`, or are `None` if no rule did. The
+    /// decision is recorded in the audit log either way.
+    pub async fn authenticate_certificate(
+        &self,
+        user: &str,
+        subject: &str,
+        identity: Option<&str>,
+        rule: Option<&str>,
+    ) -> Result<AuthResponse, AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::AuthenticateCertificate {
+            role_name: user.to_string(),
+            subject: subject.to_string(),
+            identity: identity.map(|identity| identity.to_string()),
+            rule: rule.map(|rule| rule.to_string()),
+            tx,
+        });
+        let response = rx.await.expect("sender dropped")?;
+        Ok(response)
+    }
+
     /// Upgrades this client to a session client.
     ///
     /// A session is a connection that has successfully negotiated parameters,
@@ -795,6 +857,55 @@ impl SessionClient {
             .await
     }
 
+    /// Creates the logical replication slot `name`, owned by the role of this session.
+    pub async fn create_replication_slot(&mut self, name: String) -> Result<(), AdapterError> {
+        let conn_id = self.session().conn_id().clone();
+        self.send_without_session(|tx| Command::CreateReplicationSlot { name, conn_id, tx })
+            .await
+    }
+
+    /// Drops the logical replication slot `name`.
+    pub async fn drop_replication_slot(&mut self, name: String) -> Result<(), AdapterError> {
+        let conn_id = self.session().conn_id().clone();
+        self.send_without_session(|tx| Command::DropReplicationSlot { name, conn_id, tx })
+            .await
+    }
+
+    /// Starts streaming `publication` from the logical replication slot `name` on this
+    /// session, and returns the LSN through which the slot's client has confirmed flushing.
+    ///
+    /// The slot must be released with [`SessionClient::release_replication_slot`] when
+    /// streaming ends.
+    pub async fn acquire_replication_slot(
+        &mut self,
+        name: String,
+        publication: GlobalId,
+    ) -> Result<Timestamp, AdapterError> {
+        let conn_id = self.session().conn_id().clone();
+        self.send_without_session(|tx| Command::AcquireReplicationSlot {
+            name,
+            publication,
+            conn_id,
+            tx,
+        })
+        .await
+    }
+
+    /// Records that the client streaming from the logical replication slot `name` has flushed
+    /// all changes through `lsn`.
+    pub fn confirm_replication_slot(&mut self, name: String, lsn: Timestamp) {
+        let conn_id = self.session().conn_id().clone();
+        self.inner()
+            .send(Command::ConfirmReplicationSlot { name, conn_id, lsn });
+    }
+
+    /// Stops streaming from the logical replication slot `name` on this session.
+    pub fn release_replication_slot(&mut self, name: String) {
+        let conn_id = self.session().conn_id().clone();
+        self.inner()
+            .send(Command::ReleaseReplicationSlot { name, conn_id });
+    }
+
     /// Terminates the client session.
     pub async fn terminate(&mut self) {
         let conn_id = self.session().conn_id().clone();
@@ -889,6 +1000,9 @@ impl SessionClient {
                 Command::GetWebhook { .. } => typ = Some("webhook"),
                 Command::Startup { .. }
                 | Command::AuthenticatePassword { .. }
+                | Command::AuthenticateGetSASLChallenge { .. }
+                | Command::AuthenticateVerifySASLProof { .. }
+                | Command::AuthenticateCertificate { .. }
                 | Command::CatalogSnapshot { .. }
                 | Command::Commit { .. }
                 | Command::CancelRequest { .. }
@@ -896,6 +1010,11 @@ impl SessionClient {
                 | Command::GetSystemVars { .. }
                 | Command::SetSystemVars { .. }
                 | Command::Terminate { .. }
+                | Command::CreateReplicationSlot { .. }
+                | Command::DropReplicationSlot { .. }
+                | Command::AcquireReplicationSlot { .. }
+                | Command::ConfirmReplicationSlot { .. }
+                | Command::ReleaseReplicationSlot { .. }
                 | Command::RetireExecute { .. }
                 | Command::CheckConsistency { .. }
                 | Command::Dump { .. } => {}
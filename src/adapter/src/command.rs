@@ -23,7 +23,7 @@ use mz_ore::soft_assert_no_log;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::role_id::RoleId;
-use mz_repr::{CatalogItemId, ColumnIndex, RowIterator};
+use mz_repr::{CatalogItemId, ColumnIndex, GlobalId, RowIterator, Timestamp};
 use mz_sql::ast::{FetchDirection, Raw, Statement};
 use mz_sql::catalog::ObjectType;
 use mz_sql::plan::{ExecuteTimeout, Plan, PlanKind};
@@ -73,6 +73,26 @@ pub enum Command {
         password: Option<Password>,
     },
 
+    AuthenticateGetSASLChallenge {
+        tx: oneshot::Sender<Result<SASLChallengeResponse, AdapterError>>,
+        role_name: String,
+    },
+
+    AuthenticateVerifySASLProof {
+        tx: oneshot::Sender<Result<SASLVerifyProofResponse, AdapterError>>,
+        role_name: String,
+        proof: String,
+        auth_message: String,
+    },
+
+    AuthenticateCertificate {
+        tx: oneshot::Sender<Result<AuthResponse, AdapterError>>,
+        role_name: String,
+        subject: String,
+        identity: Option<String>,
+        rule: Option<String>,
+    },
+
     Execute {
         portal_name: String,
         session: Session,
@@ -121,6 +141,44 @@ pub enum Command {
         tx: Option<oneshot::Sender<Result<(), AdapterError>>>,
     },
 
+    /// Creates the logical replication slot `name`, owned by the role of connection `conn_id`.
+    CreateReplicationSlot {
+        name: String,
+        conn_id: ConnectionId,
+        tx: oneshot::Sender<Result<(), AdapterError>>,
+    },
+
+    /// Drops the logical replication slot `name`.
+    DropReplicationSlot {
+        name: String,
+        conn_id: ConnectionId,
+        tx: oneshot::Sender<Result<(), AdapterError>>,
+    },
+
+    /// Marks the logical replication slot `name` as streamed by connection `conn_id`, and
+    /// returns the LSN through which its client has confirmed flushing. Binds the slot to
+    /// `publication` if this is the first time it is streamed.
+    AcquireReplicationSlot {
+        name: String,
+        publication: GlobalId,
+        conn_id: ConnectionId,
+        tx: oneshot::Sender<Result<Timestamp, AdapterError>>,
+    },
+
+    /// Records that the client streaming the logical replication slot `name` on connection
+    /// `conn_id` has flushed all changes through `lsn`.
+    ConfirmReplicationSlot {
+        name: String,
+        conn_id: ConnectionId,
+        lsn: Timestamp,
+    },
+
+    /// Marks the logical replication slot `name` as no longer streamed by connection `conn_id`.
+    ReleaseReplicationSlot {
+        name: String,
+        conn_id: ConnectionId,
+    },
+
     /// Performs any cleanup and logging actions necessary for
     /// finalizing a statement execution.
     ///
@@ -148,12 +206,20 @@ impl Command {
             Command::CancelRequest { .. }
             | Command::Startup { .. }
             | Command::AuthenticatePassword { .. }
+            | Command::AuthenticateGetSASLChallenge { .. }
+            | Command::AuthenticateVerifySASLProof { .. }
+            | Command::AuthenticateCertificate { .. }
             | Command::CatalogSnapshot { .. }
             | Command::PrivilegedCancelRequest { .. }
             | Command::GetWebhook { .. }
             | Command::Terminate { .. }
             | Command::GetSystemVars { .. }
             | Command::SetSystemVars { .. }
+            | Command::CreateReplicationSlot { .. }
+            | Command::DropReplicationSlot { .. }
+            | Command::AcquireReplicationSlot { .. }
+            | Command::ConfirmReplicationSlot { .. }
+            | Command::ReleaseReplicationSlot { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
             | Command::Dump { .. } => None,
@@ -166,12 +232,20 @@ impl Command {
             Command::CancelRequest { .. }
             | Command::Startup { .. }
             | Command::AuthenticatePassword { .. }
+            | Command::AuthenticateGetSASLChallenge { .. }
+            | Command::AuthenticateVerifySASLProof { .. }
+            | Command::AuthenticateCertificate { .. }
             | Command::CatalogSnapshot { .. }
             | Command::PrivilegedCancelRequest { .. }
             | Command::GetWebhook { .. }
             | Command::Terminate { .. }
             | Command::GetSystemVars { .. }
             | Command::SetSystemVars { .. }
+            | Command::CreateReplicationSlot { .. }
+            | Command::DropReplicationSlot { .. }
+            | Command::AcquireReplicationSlot { .. }
+            | Command::ConfirmReplicationSlot { .. }
+            | Command::ReleaseReplicationSlot { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
             | Command::Dump { .. } => None,
@@ -210,6 +284,24 @@ pub struct AuthResponse {
     pub superuser: bool,
 }
 
+/// The response to [`Client::get_sasl_challenge`](crate::Client::get_sasl_challenge).
+#[derive(Debug)]
+pub struct SASLChallengeResponse {
+    /// The PBKDF2 iteration count of the role's password hash.
+    pub iteration_count: usize,
+    /// The base64-encoded salt of the role's password hash.
+    pub salt: String,
+}
+
+/// The response to [`Client::verify_sasl_proof`](crate::Client::verify_sasl_proof).
+#[derive(Debug)]
+pub struct SASLVerifyProofResponse {
+    /// The base64-encoded server signature to send to the client.
+    pub verifier: String,
+    /// The result of authenticating the role.
+    pub auth_resp: AuthResponse,
+}
+
 // Facile implementation for `StartupResponse`, which does not use the `allowed`
 // feature of `ClientTransmitter`.
 impl Transmittable for StartupResponse {
@@ -289,6 +381,8 @@ pub enum ExecuteResponse {
     CreatedCluster,
     /// The requested cluster replica was created.
     CreatedClusterReplica,
+    /// The requested endpoint was created.
+    CreatedEndpoint,
     /// The requested index was created.
     CreatedIndex,
     /// The requested introspection subscribe was created.
@@ -321,12 +415,16 @@ pub enum ExecuteResponse {
     Deleted(usize),
     /// The temporary objects associated with the session have been discarded.
     DiscardedTemp,
+    /// The query plans cached for the session have been discarded.
+    DiscardedPlans,
     /// All state associated with the session has been discarded.
     DiscardedAll,
     /// The requested object was dropped.
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
     DroppedOwned,
+    /// The requested durable subscription was dropped.
+    DroppedSubscription,
     /// The provided query was empty.
     EmptyQuery,
     /// Fetch results from a cursor.
@@ -345,6 +443,10 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// The session started listening on the requested channel.
+    Listened,
+    /// A notification was sent on the requested channel.
+    Notified,
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -393,6 +495,8 @@ pub enum ExecuteResponse {
         /// Session parameters that changed because the transaction ended.
         params: BTreeMap<&'static str, String>,
     },
+    /// The session stopped listening on the requested channels.
+    Unlistened,
     /// The specified number of rows were updated in the requested table.
     Updated(usize),
     /// A connection was validated.
@@ -468,6 +572,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedClusterReplica => {
                 Ok(ExecuteResponse::CreatedClusterReplica)
             }
+            ExecuteResponseKind::CreatedEndpoint => Ok(ExecuteResponse::CreatedEndpoint),
             ExecuteResponseKind::CreatedIndex => Ok(ExecuteResponse::CreatedIndex),
             ExecuteResponseKind::CreatedSecret => Ok(ExecuteResponse::CreatedSecret),
             ExecuteResponseKind::CreatedSink => Ok(ExecuteResponse::CreatedSink),
@@ -485,14 +590,18 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
             ExecuteResponseKind::Deleted => Err(()),
             ExecuteResponseKind::DiscardedTemp => Ok(ExecuteResponse::DiscardedTemp),
+            ExecuteResponseKind::DiscardedPlans => Ok(ExecuteResponse::DiscardedPlans),
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
+            ExecuteResponseKind::DroppedSubscription => Ok(ExecuteResponse::DroppedSubscription),
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
             ExecuteResponseKind::Fetch => Err(()),
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Listened => Ok(ExecuteResponse::Listened),
+            ExecuteResponseKind::Notified => Ok(ExecuteResponse::Notified),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
@@ -503,6 +612,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::Unlistened => Ok(ExecuteResponse::Unlistened),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsStreaming => Err(()),
@@ -533,6 +643,7 @@ impl ExecuteResponse {
             CreatedRole => Some("CREATE ROLE".into()),
             CreatedCluster { .. } => Some("CREATE CLUSTER".into()),
             CreatedClusterReplica { .. } => Some("CREATE CLUSTER REPLICA".into()),
+            CreatedEndpoint => Some("CREATE ENDPOINT".into()),
             CreatedIndex { .. } => Some("CREATE INDEX".into()),
             CreatedSecret { .. } => Some("CREATE SECRET".into()),
             CreatedSink { .. } => Some("CREATE SINK".into()),
@@ -548,9 +659,11 @@ impl ExecuteResponse {
             DeclaredCursor => Some("DECLARE CURSOR".into()),
             Deleted(n) => Some(format!("DELETE {}", n)),
             DiscardedTemp => Some("DISCARD TEMP".into()),
+            DiscardedPlans => Some("DISCARD PLANS".into()),
             DiscardedAll => Some("DISCARD ALL".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
+            DroppedSubscription => Some("DROP SUBSCRIPTION".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
             GrantedPrivilege => Some("GRANT".into()),
@@ -565,6 +678,8 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Listened => Some("LISTEN".into()),
+            Notified => Some("NOTIFY".into()),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -577,6 +692,7 @@ impl ExecuteResponse {
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            Unlistened => Some("UNLISTEN".into()),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
             CreatedIntrospectionSubscribe => Some("CREATE INTROSPECTION SUBSCRIBE".into()),
@@ -607,6 +723,8 @@ impl ExecuteResponse {
             | AlterSource
             | AlterSink
             | AlterTableAddColumn
+            | AlterTableAlterColumnType
+            | AlterTableDropColumn
             | AlterNetworkPolicy => &[AlteredObject],
             AlterDefaultPrivileges => &[AlteredDefaultPrivileges],
             AlterSetCluster => &[AlteredObject],
@@ -633,17 +751,21 @@ impl ExecuteResponse {
             CreateMaterializedView => &[CreatedMaterializedView],
             CreateContinualTask => &[CreatedContinualTask],
             CreateIndex => &[CreatedIndex],
+            CreateEndpoint => &[CreatedEndpoint],
             CreateType => &[CreatedType],
             PlanKind::Deallocate => &[ExecuteResponseKind::Deallocate],
             CreateNetworkPolicy => &[CreatedNetworkPolicy],
             Declare => &[DeclaredCursor],
             DiscardTemp => &[DiscardedTemp],
+            DiscardPlans => &[DiscardedPlans],
             DiscardAll => &[DiscardedAll],
             DropObjects => &[DroppedObject],
             DropOwned => &[DroppedOwned],
+            DropSubscription => &[DroppedSubscription],
             PlanKind::EmptyQuery => &[ExecuteResponseKind::EmptyQuery],
             ExplainPlan | ExplainPushdown | ExplainTimestamp | Select | ShowAllVariables
-            | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => &[
+            | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema
+            | ExecuteEndpoint => &[
                 ExecuteResponseKind::CopyTo,
                 SendingRowsStreaming,
                 SendingRowsImmediate,
@@ -659,6 +781,8 @@ impl ExecuteResponse {
             GrantPrivileges => &[GrantedPrivilege],
             GrantRole => &[GrantedRole],
             Insert => &[Inserted, SendingRowsImmediate],
+            Listen => &[Listened],
+            Notify => &[Notified],
             PlanKind::Prepare => &[ExecuteResponseKind::Prepare],
             PlanKind::Raise => &[ExecuteResponseKind::Raised],
             PlanKind::ReassignOwned => &[ExecuteResponseKind::ReassignOwned],
@@ -670,6 +794,7 @@ impl ExecuteResponse {
             PlanKind::Subscribe => &[Subscribing, ExecuteResponseKind::CopyTo],
             StartTransaction => &[StartedTransaction],
             SideEffectingFunc => &[SendingRowsStreaming, SendingRowsImmediate],
+            Unlisten => &[Unlistened],
             ValidateConnection => &[ExecuteResponseKind::ValidatedConnection],
         }
     }
@@ -100,7 +100,8 @@ use mz_catalog::durable::{AuditLogIterator, OpenableDurableCatalogState};
 use mz_catalog::expr_cache::{GlobalExpressions, LocalExpressions};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, ClusterReplicaProcessStatus, ClusterVariantManaged, Connection,
-    DataSourceDesc, StateDiff, StateUpdate, StateUpdateKind, Table, TableDataSource,
+    DataSourceDesc, DurableSubscription, StateDiff, StateUpdate, StateUpdateKind, Table,
+    TableDataSource,
 };
 use mz_cloud_resources::{CloudResourceController, VpcEndpointConfig, VpcEndpointEvent};
 use mz_compute_client::as_of_selection;
@@ -158,10 +159,7 @@ use mz_storage_types::read_holds::ReadHold;
 use mz_storage_types::sinks::{S3SinkFormat, StorageSinkDesc};
 use mz_storage_types::sources::Timeline;
 use mz_storage_types::sources::kafka::KAFKA_PROGRESS_DESC;
-use mz_timestamp_oracle::WriteTimestamp;
-use mz_timestamp_oracle::postgres_oracle::{
-    PostgresTimestampOracle, PostgresTimestampOracleConfig,
-};
+use mz_timestamp_oracle::{TimestampOracleConfig, WriteTimestamp};
 use mz_transform::dataflow::DataflowMetainfo;
 use opentelemetry::trace::TraceContextExt;
 use serde::Serialize;
@@ -188,6 +186,7 @@ use crate::coord::cluster_scheduling::SchedulingDecision;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::introspection::IntrospectionSubscribe;
 use crate::coord::peek::PendingPeek;
+use crate::coord::plan_cache::PlanCacheLookup;
 use crate::coord::statement_logging::{StatementLogging, StatementLoggingId};
 use crate::coord::timeline::{TimelineContext, TimelineState};
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
@@ -200,7 +199,7 @@ use crate::optimize::dataflows::{
     ComputeInstanceSnapshot, DataflowBuilder, dataflow_import_id_bundle,
 };
 use crate::optimize::{self, Optimize, OptimizerConfig};
-use crate::session::{EndTransactionAction, Session};
+use crate::session::{EndTransactionAction, Notification, Session};
 use crate::statement_logging::{StatementEndedExecutionReason, StatementLifecycleEvent};
 use crate::util::{ClientTransmitter, ResultExt};
 use crate::webhook::{WebhookAppenderInvalidator, WebhookConcurrencyLimiter};
@@ -220,11 +219,15 @@ pub mod cluster_scheduling;
 mod command_handler;
 pub mod consistency;
 mod ddl;
+mod durable_subscriptions;
 mod indexes;
 mod introspection;
+mod listen;
 mod message_handler;
+mod plan_cache;
 mod privatelink_status;
 pub mod read_policy;
+mod replication_slots;
 mod sequencer;
 mod sql;
 mod validity;
@@ -326,6 +329,19 @@ pub enum Message {
         stage: ExplainTimestampStage,
     },
     DrainStatementLog,
+    /// A client has consumed all updates of the named durable subscription up to
+    /// `subscription.frontier`.
+    DurableSubscriptionAck {
+        name: String,
+        subscription: DurableSubscription,
+    },
+    /// The relation that connection `conn_id` bound to `channel` with `LISTEN ... ON`
+    /// changed.
+    ListenNotify {
+        conn_id: ConnectionId,
+        channel: String,
+        payload: String,
+    },
     PrivateLinkVpcEndpointEvents(Vec<VpcEndpointEvent>),
     CheckSchedulingPolicies,
 
@@ -351,10 +367,18 @@ impl Message {
                 Command::GetSystemVars { .. } => "command-get_system_vars",
                 Command::SetSystemVars { .. } => "command-set_system_vars",
                 Command::Terminate { .. } => "command-terminate",
+                Command::CreateReplicationSlot { .. } => "command-create_replication_slot",
+                Command::DropReplicationSlot { .. } => "command-drop_replication_slot",
+                Command::AcquireReplicationSlot { .. } => "command-acquire_replication_slot",
+                Command::ConfirmReplicationSlot { .. } => "command-confirm_replication_slot",
+                Command::ReleaseReplicationSlot { .. } => "command-release_replication_slot",
                 Command::RetireExecute { .. } => "command-retire_execute",
                 Command::CheckConsistency { .. } => "command-check_consistency",
                 Command::Dump { .. } => "command-dump",
                 Command::AuthenticatePassword { .. } => "command-auth_check",
+                Command::AuthenticateGetSASLChallenge { .. } => "command-auth_get_sasl_challenge",
+                Command::AuthenticateVerifySASLProof { .. } => "command-auth_verify_sasl_proof",
+                Command::AuthenticateCertificate { .. } => "command-auth_certificate",
             },
             Message::ControllerReady => "controller_ready",
             Message::PurifiedStatementReady(_) => "purified_statement_ready",
@@ -388,6 +412,8 @@ impl Message {
             Message::SecretStageReady { .. } => "secret_stage_ready",
             Message::ClusterStageReady { .. } => "cluster_stage_ready",
             Message::DrainStatementLog => "drain_statement_log",
+            Message::DurableSubscriptionAck { .. } => "durable_subscription_ack",
+            Message::ListenNotify { .. } => "listen_notify",
             Message::AlterConnectionValidationReady(..) => "alter_connection_validation_ready",
             Message::PrivateLinkVpcEndpointEvents(_) => "private_link_vpc_endpoint_events",
             Message::CheckSchedulingPolicies => "check_scheduling_policies",
@@ -466,14 +492,28 @@ pub struct CopyToContext {
     pub output_batch_count: Option<u64>,
 }
 
+/// An endpoint executed by a peek.
+#[derive(Debug, Clone)]
+pub struct PeekEndpoint {
+    /// The ID of the endpoint.
+    pub id: CatalogItemId,
+    /// The fully qualified name of the endpoint.
+    pub name: String,
+}
+
 #[derive(Debug)]
 pub struct PeekStageLinearizeTimestamp {
     validity: PlanValidity,
     plan: mz_sql::plan::SelectPlan,
     max_query_result_size: Option<u64>,
+    /// The endpoint this peek executes, if any. Endpoint peeks must be answerable on the fast
+    /// path.
+    endpoint: Option<PeekEndpoint>,
     source_ids: BTreeSet<GlobalId>,
     target_replica: Option<ReplicaId>,
     timeline_context: TimelineContext,
+    /// The result of looking up the plan of the peek in the plan cache.
+    plan_cache: PlanCacheLookup,
     optimizer: Either<optimize::peek::Optimizer, optimize::copy_to::Optimizer>,
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
@@ -485,10 +525,15 @@ pub struct PeekStageRealTimeRecency {
     validity: PlanValidity,
     plan: mz_sql::plan::SelectPlan,
     max_query_result_size: Option<u64>,
+    /// The endpoint this peek executes, if any. Endpoint peeks must be answerable on the fast
+    /// path.
+    endpoint: Option<PeekEndpoint>,
     source_ids: BTreeSet<GlobalId>,
     target_replica: Option<ReplicaId>,
     timeline_context: TimelineContext,
     oracle_read_ts: Option<Timestamp>,
+    /// The result of looking up the plan of the peek in the plan cache.
+    plan_cache: PlanCacheLookup,
     optimizer: Either<optimize::peek::Optimizer, optimize::copy_to::Optimizer>,
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
@@ -500,11 +545,16 @@ pub struct PeekStageTimestampReadHold {
     validity: PlanValidity,
     plan: mz_sql::plan::SelectPlan,
     max_query_result_size: Option<u64>,
+    /// The endpoint this peek executes, if any. Endpoint peeks must be answerable on the fast
+    /// path.
+    endpoint: Option<PeekEndpoint>,
     source_ids: BTreeSet<GlobalId>,
     target_replica: Option<ReplicaId>,
     timeline_context: TimelineContext,
     oracle_read_ts: Option<Timestamp>,
     real_time_recency_ts: Option<mz_repr::Timestamp>,
+    /// The result of looking up the plan of the peek in the plan cache.
+    plan_cache: PlanCacheLookup,
     optimizer: Either<optimize::peek::Optimizer, optimize::copy_to::Optimizer>,
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
@@ -516,10 +566,15 @@ pub struct PeekStageOptimize {
     validity: PlanValidity,
     plan: mz_sql::plan::SelectPlan,
     max_query_result_size: Option<u64>,
+    /// The endpoint this peek executes, if any. Endpoint peeks must be answerable on the fast
+    /// path.
+    endpoint: Option<PeekEndpoint>,
     source_ids: BTreeSet<GlobalId>,
     id_bundle: CollectionIdBundle,
     target_replica: Option<ReplicaId>,
     determination: TimestampDetermination<mz_repr::Timestamp>,
+    /// The result of looking up the plan of the peek in the plan cache.
+    plan_cache: PlanCacheLookup,
     optimizer: Either<optimize::peek::Optimizer, optimize::copy_to::Optimizer>,
     /// An optional context set iff the state machine is initiated from
     /// sequencing an EXPLAIN for this statement.
@@ -531,6 +586,9 @@ pub struct PeekStageFinish {
     validity: PlanValidity,
     plan: mz_sql::plan::SelectPlan,
     max_query_result_size: Option<u64>,
+    /// The endpoint this peek executes, if any. Endpoint peeks must be answerable on the fast
+    /// path.
+    endpoint: Option<PeekEndpoint>,
     id_bundle: CollectionIdBundle,
     target_replica: Option<ReplicaId>,
     source_ids: BTreeSet<GlobalId>,
@@ -542,6 +600,8 @@ pub struct PeekStageFinish {
     plan_insights_optimizer_trace: Option<OptimizerTrace>,
     insights_ctx: Option<Box<PlanInsightsContext>>,
     global_lir_plan: optimize::peek::GlobalLirPlan,
+    /// The plan optimized from the generic source of the peek, to be cached.
+    generic_plan: Option<optimize::peek::LocalMirPlan>,
     optimization_finished_at: EpochMillis,
 }
 
@@ -1679,6 +1739,37 @@ pub struct Coordinator {
     active_compute_sinks: BTreeMap<GlobalId, ActiveComputeSink>,
     /// A map from active webhooks to their invalidation handle.
     active_webhooks: BTreeMap<CatalogItemId, WebhookAppenderInvalidator>,
+    /// A map from the names of durable subscriptions that are currently being consumed to the
+    /// ID of the subscribe sink consuming them.
+    active_durable_subscriptions: BTreeMap<String, GlobalId>,
+    /// Read holds that keep the inputs of durable subscriptions readable at their frontier.
+    durable_subscription_holds: BTreeMap<String, read_policy::ReadHolds<Timestamp>>,
+    /// Acknowledged durable subscription frontiers that have not yet been written to the
+    /// catalog.
+    pending_durable_subscription_acks: BTreeMap<String, DurableSubscription>,
+    /// When acknowledged durable subscription frontiers were last written to the catalog.
+    durable_subscriptions_checkpointed_at: EpochMillis,
+    /// Durable subscriptions whose inputs could not be held at their frontier on bootstrap,
+    /// and which can therefore no longer be resumed.
+    broken_durable_subscriptions: BTreeSet<String>,
+    /// When disconnected durable subscriptions that have not acknowledged anything yet were
+    /// first seen disconnected.
+    unacknowledged_durable_subscriptions_since: BTreeMap<String, EpochMillis>,
+    /// A map from the names of replication slots that are currently being streamed from to the
+    /// connection streaming from them.
+    active_replication_slots: BTreeMap<String, ConnectionId>,
+    /// Confirmed replication slot LSNs that have not yet been written to the catalog.
+    pending_replication_slot_confirms: BTreeMap<String, Timestamp>,
+    /// When confirmed replication slot LSNs were last written to the catalog.
+    replication_slots_checkpointed_at: EpochMillis,
+    /// A map from `LISTEN` channels to the connections listening on them and the channel on
+    /// which to deliver notifications to each connection.
+    listeners: BTreeMap<String, BTreeMap<ConnectionId, mpsc::UnboundedSender<Notification>>>,
+    /// A map from the connections and channels that `LISTEN ... ON` bound to a relation to the
+    /// ID of the subscribe sink watching the relation.
+    listen_bindings: BTreeMap<(ConnectionId, String), GlobalId>,
+    /// Optimized peek plans, cached per session and shared among sessions.
+    plan_cache: plan_cache::PlanCache,
     /// A map of active `COPY FROM` statements. The Coordinator waits for `clusterd`
     /// to stage Batches in Persist that we will then link into the shard.
     active_copies: BTreeMap<ConnectionId, ActiveCopyFrom>,
@@ -1758,10 +1849,9 @@ pub struct Coordinator {
     /// Limit for how many concurrent webhook requests we allow.
     webhook_concurrency_limit: WebhookConcurrencyLimiter,
 
-    /// Optional config for the Postgres-backed timestamp oracle. This is
-    /// _required_ when `postgres` is configured using the `timestamp_oracle`
-    /// system variable.
-    pg_timestamp_oracle_config: Option<PostgresTimestampOracleConfig>,
+    /// Optional config for the durable backend of the timestamp oracle, either
+    /// Postgres or a local directory. This is _required_ to open timelines.
+    timestamp_oracle_config: Option<TimestampOracleConfig>,
 
     /// Periodically asks cluster scheduling policies to make their decisions.
     check_cluster_scheduling_policies_interval: Interval,
@@ -2157,7 +2247,8 @@ impl Coordinator {
                 CatalogItem::Log(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
-                | CatalogItem::Secret(_) => {}
+                | CatalogItem::Secret(_)
+                | CatalogItem::Endpoint(_) => {}
             }
         }
 
@@ -2186,6 +2277,10 @@ impl Coordinator {
             }
         }
 
+        // Durable subscriptions must keep their inputs readable at their frontiers, so reacquire
+        // their read holds before relaxing read policies.
+        self.bootstrap_durable_subscription_holds();
+
         // Having installed all entries, creating all constraints, we can now drop read holds and
         // relax read policies.
         drop(dataflow_read_holds);
@@ -2736,7 +2831,7 @@ impl Coordinator {
                 }
                 CatalogItem::Table(table) => {
                     match &table.data_source {
-                        TableDataSource::TableWrites { defaults: _ } => {
+                        TableDataSource::TableWrites { .. } => {
                             let versions: BTreeMap<_, _> = table
                                 .collection_descs()
                                 .map(|(gid, version, desc)| (version, (gid, desc)))
@@ -3223,6 +3318,7 @@ impl Coordinator {
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Endpoint(_)
                 | CatalogItem::Connection(_) => continue,
             };
             if let Some(plan) = self.catalog.try_get_physical_plan(&gid) {
@@ -4006,10 +4102,10 @@ pub fn serve(
         let oracle_init_start = Instant::now();
         info!("startup: coordinator init: timestamp oracle init beginning");
 
-        let pg_timestamp_oracle_config = timestamp_oracle_url
-            .map(|pg_url| PostgresTimestampOracleConfig::new(&pg_url, &metrics_registry));
+        let timestamp_oracle_config = timestamp_oracle_url
+            .map(|url| TimestampOracleConfig::new(&url, &metrics_registry));
         let mut initial_timestamps =
-            get_initial_oracle_timestamps(&pg_timestamp_oracle_config).await?;
+            get_initial_oracle_timestamps(&timestamp_oracle_config).await?;
 
         // Insert an entry for the `EpochMilliseconds` timeline if one doesn't exist,
         // which will ensure that the timeline is initialized since it's required
@@ -4023,7 +4119,7 @@ pub fn serve(
                 &timeline,
                 initial_timestamp,
                 now.clone(),
-                pg_timestamp_oracle_config.clone(),
+                timestamp_oracle_config.clone(),
                 &mut timestamp_oracles,
                 read_only_controllers,
             )
@@ -4183,7 +4279,7 @@ pub fn serve(
                 exclude_collections: new_builtin_collections.into_iter().collect(),
             });
 
-        if let Some(config) = pg_timestamp_oracle_config.as_ref() {
+        if let Some(TimestampOracleConfig::Postgres(config)) = timestamp_oracle_config.as_ref() {
             // Apply settings from system vars as early as possible because some
             // of them are locked in right when an oracle is first opened!
             let pg_timestamp_oracle_params =
@@ -4275,6 +4371,18 @@ pub fn serve(
                     serialized_ddl: LockedVecDeque::new(),
                     active_compute_sinks: BTreeMap::new(),
                     active_webhooks: BTreeMap::new(),
+                    active_durable_subscriptions: BTreeMap::new(),
+                    durable_subscription_holds: BTreeMap::new(),
+                    pending_durable_subscription_acks: BTreeMap::new(),
+                    durable_subscriptions_checkpointed_at: 0,
+                    broken_durable_subscriptions: BTreeSet::new(),
+                    unacknowledged_durable_subscriptions_since: BTreeMap::new(),
+                    active_replication_slots: BTreeMap::new(),
+                    pending_replication_slot_confirms: BTreeMap::new(),
+                    replication_slots_checkpointed_at: 0,
+                    listeners: BTreeMap::new(),
+                    listen_bindings: BTreeMap::new(),
+                    plan_cache: Default::default(),
                     active_copies: BTreeMap::new(),
                     staged_cancellation: BTreeMap::new(),
                     introspection_subscribes: BTreeMap::new(),
@@ -4294,7 +4402,7 @@ pub fn serve(
                     tracing_handle,
                     statement_logging: StatementLogging::new(coord_now.clone()),
                     webhook_concurrency_limit,
-                    pg_timestamp_oracle_config,
+                    timestamp_oracle_config,
                     check_cluster_scheduling_policies_interval: check_scheduling_policies_interval,
                     cluster_scheduling_decisions: BTreeMap::new(),
                     caught_up_check_interval: clusters_caught_up_check_interval,
@@ -4398,27 +4506,25 @@ pub fn serve(
 // window (which is the only point where we should switch oracle
 // implementations).
 async fn get_initial_oracle_timestamps(
-    pg_timestamp_oracle_config: &Option<PostgresTimestampOracleConfig>,
+    timestamp_oracle_config: &Option<TimestampOracleConfig>,
 ) -> Result<BTreeMap<Timeline, Timestamp>, AdapterError> {
     let mut initial_timestamps = BTreeMap::new();
 
-    if let Some(pg_timestamp_oracle_config) = pg_timestamp_oracle_config {
-        let postgres_oracle_timestamps =
-            PostgresTimestampOracle::<NowFn>::get_all_timelines(pg_timestamp_oracle_config.clone())
-                .await?;
+    if let Some(timestamp_oracle_config) = timestamp_oracle_config {
+        let oracle_timestamps = timestamp_oracle_config.get_all_timelines().await?;
 
         let debug_msg = || {
-            postgres_oracle_timestamps
+            oracle_timestamps
                 .iter()
                 .map(|(timeline, ts)| format!("{:?} -> {}", timeline, ts))
                 .join(", ")
         };
         info!(
-            "current timestamps from the postgres-backed timestamp oracle: {}",
+            "current timestamps from the durable timestamp oracle: {}",
             debug_msg()
         );
 
-        for (timeline, ts) in postgres_oracle_timestamps {
+        for (timeline, ts) in oracle_timestamps {
             let entry = initial_timestamps
                 .entry(Timeline::from_str(&timeline).expect("could not parse timeline"));
 
@@ -4427,7 +4533,7 @@ async fn get_initial_oracle_timestamps(
                 .or_insert(ts);
         }
     } else {
-        info!("no postgres url for postgres-backed timestamp oracle configured!");
+        info!("no url for the durable timestamp oracle configured!");
     };
 
     let debug_msg = || {
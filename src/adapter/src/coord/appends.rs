@@ -26,7 +26,10 @@ use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::{assert_none, instrument};
 use mz_repr::{CatalogItemId, Timestamp};
 use mz_sql::names::ResolvedIds;
-use mz_sql::plan::{ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan};
+use mz_sql::plan::{
+    ExecuteEndpointPlan, ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement,
+    ListenPlan, Plan,
+};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_storage_client::client::TableData;
 use mz_timestamp_oracle::WriteTimestamp;
@@ -938,10 +941,16 @@ pub(crate) fn waiting_on_startup_appends(
     // referenced builtin tables out of mz_internal, or we allow creating views on
     // mz_internal objects.
     let depends_on = match plan {
-        Plan::Select(plan) => plan.source.depends_on(),
+        Plan::Select(plan) | Plan::ExecuteEndpoint(ExecuteEndpointPlan { select: plan, .. }) => {
+            plan.source.depends_on()
+        }
         Plan::ReadThenWrite(plan) => plan.selection.depends_on(),
         Plan::ShowColumns(plan) => plan.select_plan.source.depends_on(),
-        Plan::Subscribe(plan) => plan.from.depends_on(),
+        Plan::Subscribe(plan)
+        | Plan::Listen(ListenPlan {
+            subscribe: Some(plan),
+            ..
+        }) => plan.from.depends_on(),
         Plan::ExplainPlan(ExplainPlanPlan {
             explainee: Explainee::Statement(ExplaineeStatement::Select { plan, .. }),
             ..
@@ -958,6 +967,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateSource(_)
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
+        | Plan::CreateEndpoint(_)
         | Plan::CreateSink(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
@@ -966,9 +976,11 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateType(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
+        | Plan::DiscardPlans
         | Plan::DiscardAll
         | Plan::DropObjects(_)
         | Plan::DropOwned(_)
+        | Plan::DropSubscription(_)
         | Plan::EmptyQuery
         | Plan::ShowAllVariables
         | Plan::ShowCreate(_)
@@ -1007,6 +1019,8 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTableAlterColumnType(_)
+        | Plan::AlterTableDropColumn(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
         | Plan::Close(_)
@@ -1014,6 +1028,9 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::Execute(_)
         | Plan::Deallocate(_)
         | Plan::Raise(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
+        | Plan::Notify(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
         | Plan::GrantPrivileges(_)
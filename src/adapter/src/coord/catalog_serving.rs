@@ -23,7 +23,8 @@ use mz_repr::GlobalId;
 use mz_repr::namespaces::is_system_schema;
 use mz_sql::catalog::SessionCatalog;
 use mz_sql::plan::{
-    ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan, SubscribeFrom,
+    ExecuteEndpointPlan, ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement,
+    ListenPlan, Plan, SubscribeFrom,
 };
 use smallvec::SmallVec;
 
@@ -42,7 +43,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
     plan: &'p Plan,
 ) -> TargetCluster {
     let (depends_on, could_run_expensive_function) = match plan {
-        Plan::Select(plan) => (
+        Plan::Select(plan) | Plan::ExecuteEndpoint(ExecuteEndpointPlan { select: plan, .. }) => (
             plan.source.depends_on(),
             plan.source.could_run_expensive_function(),
         ),
@@ -50,7 +51,11 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
             plan.select_plan.source.depends_on(),
             plan.select_plan.source.could_run_expensive_function(),
         ),
-        Plan::Subscribe(plan) => (
+        Plan::Subscribe(plan)
+        | Plan::Listen(ListenPlan {
+            subscribe: Some(plan),
+            ..
+        }) => (
             plan.from.depends_on(),
             match &plan.from {
                 SubscribeFrom::Id(_) => false,
@@ -79,6 +84,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateSource(_)
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
+        | Plan::CreateEndpoint(_)
         | Plan::CreateSink(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
@@ -87,9 +93,11 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateType(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
+        | Plan::DiscardPlans
         | Plan::DiscardAll
         | Plan::DropObjects(_)
         | Plan::DropOwned(_)
+        | Plan::DropSubscription(_)
         | Plan::EmptyQuery
         | Plan::ShowAllVariables
         | Plan::ShowCreate(_)
@@ -128,6 +136,8 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTableAlterColumnType(_)
+        | Plan::AlterTableDropColumn(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
         | Plan::Close(_)
@@ -136,6 +146,9 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::Execute(_)
         | Plan::Deallocate(_)
         | Plan::Raise(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
+        | Plan::Notify(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
         | Plan::GrantPrivileges(_)
@@ -214,11 +227,17 @@ pub fn check_cluster_restrictions(
     // 'mz_catalog_server' cluster to be "read-only", which restricts these actions.
     let depends_on: Box<dyn Iterator<Item = GlobalId>> = match plan {
         Plan::ReadThenWrite(plan) => Box::new(plan.selection.depends_on().into_iter()),
-        Plan::Subscribe(plan) => match plan.from {
+        Plan::Subscribe(plan)
+        | Plan::Listen(ListenPlan {
+            subscribe: Some(plan),
+            ..
+        }) => match plan.from {
             SubscribeFrom::Id(id) => Box::new(std::iter::once(id)),
             SubscribeFrom::Query { ref expr, .. } => Box::new(expr.depends_on().into_iter()),
         },
-        Plan::Select(plan) => Box::new(plan.source.depends_on().into_iter()),
+        Plan::Select(plan) | Plan::ExecuteEndpoint(ExecuteEndpointPlan { select: plan, .. }) => {
+            Box::new(plan.source.depends_on().into_iter())
+        }
         _ => return Ok(()),
     };
 
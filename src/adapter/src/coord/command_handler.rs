@@ -24,6 +24,7 @@ use futures::future::LocalBoxFuture;
 use mz_adapter_types::connection::{ConnectionId, ConnectionIdType};
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_catalog::memory::objects::{CatalogItem, DataSourceDesc, Source, Table, TableDataSource};
+use mz_ore::cast::CastFrom;
 use mz_ore::task;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::{instrument, soft_panic_or_log};
@@ -59,7 +60,10 @@ use tokio::sync::{mpsc, oneshot};
 use tracing::{Instrument, debug_span, info, warn};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
-use crate::command::{AuthResponse, CatalogSnapshot, Command, ExecuteResponse, StartupResponse};
+use crate::command::{
+    AuthResponse, CatalogSnapshot, Command, ExecuteResponse, SASLChallengeResponse,
+    SASLVerifyProofResponse, StartupResponse,
+};
 use crate::coord::appends::PendingWriteTxn;
 use crate::coord::{
     ConnMeta, Coordinator, DeferredPlanStatement, Message, PendingTxn, PlanStatement, PlanValidity,
@@ -120,6 +124,32 @@ impl Coordinator {
                         .await;
                 }
 
+                Command::AuthenticateGetSASLChallenge { tx, role_name } => {
+                    self.handle_authenticate_get_sasl_challenge(tx, role_name)
+                        .await;
+                }
+
+                Command::AuthenticateVerifySASLProof {
+                    tx,
+                    role_name,
+                    proof,
+                    auth_message,
+                } => {
+                    self.handle_authenticate_verify_sasl_proof(tx, role_name, proof, auth_message)
+                        .await;
+                }
+
+                Command::AuthenticateCertificate {
+                    tx,
+                    role_name,
+                    subject,
+                    identity,
+                    rule,
+                } => {
+                    self.handle_authenticate_certificate(tx, role_name, subject, identity, rule)
+                        .await;
+                }
+
                 Command::Execute {
                     portal_name,
                     session,
@@ -182,6 +212,36 @@ impl Coordinator {
                     let _ = tx.send(result);
                 }
 
+                Command::CreateReplicationSlot { name, conn_id, tx } => {
+                    let result = self.create_replication_slot(conn_id, name).await;
+                    let _ = tx.send(result);
+                }
+
+                Command::DropReplicationSlot { name, conn_id, tx } => {
+                    let result = self.drop_replication_slot(conn_id, name).await;
+                    let _ = tx.send(result);
+                }
+
+                Command::AcquireReplicationSlot {
+                    name,
+                    publication,
+                    conn_id,
+                    tx,
+                } => {
+                    let result = self
+                        .acquire_replication_slot(conn_id, name, publication)
+                        .await;
+                    let _ = tx.send(result);
+                }
+
+                Command::ConfirmReplicationSlot { name, conn_id, lsn } => {
+                    self.confirm_replication_slot(conn_id, name, lsn);
+                }
+
+                Command::ReleaseReplicationSlot { name, conn_id } => {
+                    self.release_replication_slot(conn_id, name);
+                }
+
                 Command::Terminate { conn_id, tx } => {
                     self.handle_terminate(conn_id).await;
                     // Note: We purposefully do not use a ClientTransmitter here because we're already
@@ -285,6 +345,106 @@ impl Coordinator {
         }
     }
 
+    #[mz_ore::instrument(level = "debug")]
+    async fn handle_authenticate_get_sasl_challenge(
+        &mut self,
+        tx: oneshot::Sender<Result<SASLChallengeResponse, AdapterError>>,
+        role_name: String,
+    ) {
+        let opts = self
+            .catalog()
+            .try_get_role_by_name(role_name.as_str())
+            .and_then(|role| self.catalog().try_get_role_auth_by_id(&role.id))
+            .and_then(|auth| auth.password_hash.as_ref())
+            .and_then(|hash| mz_auth::hash::scram256_parse_opts(hash).ok())
+            // Hand out a mock challenge rather than failing, so that the client
+            // cannot probe for the existence of roles.
+            .unwrap_or_else(|| mz_auth::hash::mock_sasl_challenge(&role_name));
+        let _ = tx.send(Ok(SASLChallengeResponse {
+            iteration_count: usize::cast_from(opts.iterations.get()),
+            salt: opts.encoded_salt(),
+        }));
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn handle_authenticate_verify_sasl_proof(
+        &mut self,
+        tx: oneshot::Sender<Result<SASLVerifyProofResponse, AdapterError>>,
+        role_name: String,
+        proof: String,
+        auth_message: String,
+    ) {
+        let Some(role) = self.catalog().try_get_role_by_name(role_name.as_str()) else {
+            // The user does not exist.
+            let _ = tx.send(Err(AdapterError::AuthenticationError));
+            return;
+        };
+        if !role.attributes.login.unwrap_or(false) {
+            // The user is not allowed to login.
+            let _ = tx.send(Err(AdapterError::AuthenticationError));
+            return;
+        }
+        let hash = self
+            .catalog()
+            .try_get_role_auth_by_id(&role.id)
+            .and_then(|auth| auth.password_hash.as_ref());
+        let Some(hash) = hash else {
+            // The user has no password.
+            let _ = tx.send(Err(AdapterError::AuthenticationError));
+            return;
+        };
+        let _ = match mz_auth::hash::sasl_verify(hash, &proof, &auth_message) {
+            Ok(verifier) => tx.send(Ok(SASLVerifyProofResponse {
+                verifier,
+                auth_resp: AuthResponse {
+                    role_id: role.id,
+                    superuser: role.attributes.superuser.unwrap_or(false),
+                },
+            })),
+            Err(_) => tx.send(Err(AdapterError::AuthenticationError)),
+        };
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    async fn handle_authenticate_certificate(
+        &mut self,
+        tx: oneshot::Sender<Result<AuthResponse, AdapterError>>,
+        role_name: String,
+        subject: String,
+        identity: Option<String>,
+        rule: Option<String>,
+    ) {
+        // A mapping rule must have granted access, and the role must exist and
+        // be allowed to login.
+        let auth_resp = match self.catalog().try_get_role_by_name(role_name.as_str()) {
+            Some(role) if identity.is_some() && role.attributes.login.unwrap_or(false) => {
+                Some(AuthResponse {
+                    role_id: role.id,
+                    superuser: role.attributes.superuser.unwrap_or(false),
+                })
+            }
+            _ => None,
+        };
+
+        let op = catalog::Op::LogCertificateLogin {
+            details: mz_audit_log::CertificateLoginV1 {
+                subject,
+                identity,
+                rule,
+                role_name,
+                accepted: auth_resp.is_some(),
+            },
+        };
+        if let Err(err) = self.catalog_transact(None, vec![op]).await {
+            // Refuse logins that we cannot record.
+            warn!("unable to record certificate login in the audit log: {err}");
+            let _ = tx.send(Err(AdapterError::AuthenticationError));
+            return;
+        }
+
+        let _ = tx.send(auth_resp.ok_or(AdapterError::AuthenticationError));
+    }
+
     #[mz_ore::instrument(level = "debug")]
     async fn handle_startup(
         &mut self,
@@ -705,6 +865,7 @@ impl Coordinator {
                     | Statement::Declare(_)
                     | Statement::Discard(_)
                     | Statement::Execute(_)
+                    | Statement::ExecuteEndpoint(_)
                     | Statement::ExplainPlan(_)
                     | Statement::ExplainPushdown(_)
                     | Statement::ExplainAnalyze(_)
@@ -720,7 +881,10 @@ impl Coordinator {
                     | Statement::ResetVariable(_)
                     | Statement::StartTransaction(_)
                     | Statement::Subscribe(_)
-                    | Statement::Raise(_) => {
+                    | Statement::Raise(_)
+                    | Statement::Listen(_)
+                    | Statement::Unlisten(_)
+                    | Statement::Notify(_) => {
                         // Always safe.
                     }
 
@@ -764,11 +928,14 @@ impl Coordinator {
                     | Statement::AlterSystemResetAll(_)
                     | Statement::AlterSystemSet(_)
                     | Statement::AlterTableAddColumn(_)
+                    | Statement::AlterTableAlterColumnType(_)
+                    | Statement::AlterTableDropColumn(_)
                     | Statement::AlterNetworkPolicy(_)
                     | Statement::CreateCluster(_)
                     | Statement::CreateClusterReplica(_)
                     | Statement::CreateConnection(_)
                     | Statement::CreateDatabase(_)
+                    | Statement::CreateEndpoint(_)
                     | Statement::CreateIndex(_)
                     | Statement::CreateMaterializedView(_)
                     | Statement::CreateContinualTask(_)
@@ -787,6 +954,7 @@ impl Coordinator {
                     | Statement::Delete(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
+                    | Statement::DropSubscription(_)
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
@@ -1345,6 +1513,10 @@ impl Coordinator {
         // We do not need to call clear_transaction here because there are no side effects to run
         // based on any session transaction state.
         self.clear_connection(&conn_id).await;
+        self.unlisten(&conn_id, None).await;
+        self.plan_cache.discard_session(&conn_id);
+        self.active_replication_slots
+            .retain(|_, holder| holder != &conn_id);
 
         self.drop_temp_items(&conn_id).await;
         self.catalog_mut()
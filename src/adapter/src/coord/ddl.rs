@@ -52,6 +52,7 @@ use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sources::GenericSourceConnection;
 use mz_storage_types::sources::kafka::KAFKA_PROGRESS_DESC;
+use mz_timestamp_oracle::TimestampOracleConfig;
 use serde_json::json;
 use tracing::{Instrument, Level, event, info_span, warn};
 
@@ -220,6 +221,9 @@ impl Coordinator {
         let mut update_secrets_caching_config = false;
         let mut update_cluster_scheduling_config = false;
         let mut update_http_config = false;
+        let mut plan_cache_items_to_invalidate = BTreeSet::new();
+        let mut plan_cache_clusters_to_invalidate = BTreeSet::new();
+        let mut clear_plan_cache = false;
 
         for op in &ops {
             match op {
@@ -227,6 +231,7 @@ impl Coordinator {
                     for drop_object_info in drop_object_infos {
                         match &drop_object_info {
                             catalog::DropObjectInfo::Item(id) => {
+                                plan_cache_items_to_invalidate.insert(*id);
                                 match self.catalog().get_entry(id).item() {
                                     CatalogItem::Table(table) => {
                                         table_gids_to_drop
@@ -295,6 +300,7 @@ impl Coordinator {
                             }
                             catalog::DropObjectInfo::Cluster(id) => {
                                 clusters_to_drop.push(*id);
+                                plan_cache_clusters_to_invalidate.insert(*id);
                             }
                             catalog::DropObjectInfo::ClusterReplica((
                                 cluster_id,
@@ -337,6 +343,7 @@ impl Coordinator {
                     update_secrets_caching_config |= vars::is_secrets_caching_var(name);
                     update_cluster_scheduling_config |= vars::is_cluster_scheduling_var(name);
                     update_http_config |= vars::is_http_config_var(name);
+                    clear_plan_cache = true;
                 }
                 catalog::Op::ResetAllSystemConfiguration => {
                     // Assume they all need to be updated.
@@ -351,6 +358,7 @@ impl Coordinator {
                     update_secrets_caching_config = true;
                     update_cluster_scheduling_config = true;
                     update_http_config = true;
+                    clear_plan_cache = true;
                 }
                 catalog::Op::RenameItem { id, .. } => {
                     let item = self.catalog().get_entry(id);
@@ -383,6 +391,16 @@ impl Coordinator {
                 catalog::Op::CreateCluster { id, .. } => {
                     clusters_to_create.push(*id);
                 }
+                catalog::Op::UpdateItem { id, .. }
+                | catalog::Op::AlterAddColumn { id, .. }
+                | catalog::Op::AlterColumnType { id, .. }
+                | catalog::Op::AlterDropColumn { id, .. }
+                | catalog::Op::AlterRetainHistory { id, .. } => {
+                    plan_cache_items_to_invalidate.insert(*id);
+                }
+                catalog::Op::UpdateClusterConfig { id, .. } => {
+                    plan_cache_clusters_to_invalidate.insert(*id);
+                }
                 catalog::Op::CreateClusterReplica {
                     cluster_id,
                     name,
@@ -668,6 +686,18 @@ impl Coordinator {
             if !webhook_sources_to_restart.is_empty() {
                 self.restart_webhook_sources(webhook_sources_to_restart);
             }
+            if clear_plan_cache {
+                self.plan_cache.clear();
+            } else {
+                if !plan_cache_items_to_invalidate.is_empty() {
+                    self.plan_cache
+                        .invalidate_items(&plan_cache_items_to_invalidate);
+                }
+                if !plan_cache_clusters_to_invalidate.is_empty() {
+                    self.plan_cache
+                        .invalidate_clusters(&plan_cache_clusters_to_invalidate);
+                }
+            }
             if !storage_sink_gids_to_drop.is_empty() {
                 self.drop_storage_sinks(storage_sink_gids_to_drop);
             }
@@ -1258,7 +1288,8 @@ impl Coordinator {
 
     fn update_pg_timestamp_oracle_config(&self) {
         let config_params = flags::pg_timstamp_oracle_config(self.catalog().system_config());
-        if let Some(config) = self.pg_timestamp_oracle_config.as_ref() {
+        if let Some(TimestampOracleConfig::Postgres(config)) = self.timestamp_oracle_config.as_ref()
+        {
             config_params.apply(config)
         }
     }
@@ -1501,7 +1532,8 @@ impl Coordinator {
                         | CatalogItem::View(_)
                         | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
-                        | CatalogItem::Func(_) => {}
+                        | CatalogItem::Func(_)
+                        | CatalogItem::Endpoint(_) => {}
                     }
                 }
                 Op::DropObjects(drop_object_infos) => {
@@ -1583,7 +1615,8 @@ impl Coordinator {
                                     | CatalogItem::View(_)
                                     | CatalogItem::Index(_)
                                     | CatalogItem::Type(_)
-                                    | CatalogItem::Func(_) => {}
+                                    | CatalogItem::Func(_)
+                                    | CatalogItem::Endpoint(_) => {}
                                 }
                             }
                         }
@@ -1609,6 +1642,7 @@ impl Coordinator {
                     | CatalogItem::Sink(_)
                     | CatalogItem::MaterializedView(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Endpoint(_)
                     | CatalogItem::Log(_)
                     | CatalogItem::View(_)
                     | CatalogItem::Index(_)
@@ -1620,6 +1654,8 @@ impl Coordinator {
                 | Op::AlterRetainHistory { .. }
                 | Op::AlterNetworkPolicy { .. }
                 | Op::AlterAddColumn { .. }
+                | Op::AlterColumnType { .. }
+                | Op::AlterDropColumn { .. }
                 | Op::UpdatePrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
@@ -1632,9 +1668,14 @@ impl Coordinator {
                 | Op::UpdateClusterConfig { .. }
                 | Op::UpdateClusterReplicaConfig { .. }
                 | Op::UpdateSourceReferences { .. }
+                | Op::UpsertDurableSubscription { .. }
+                | Op::DropDurableSubscriptions { .. }
+                | Op::UpsertReplicationSlot { .. }
+                | Op::DropReplicationSlots { .. }
                 | Op::UpdateSystemConfiguration { .. }
                 | Op::ResetSystemConfiguration { .. }
                 | Op::ResetAllSystemConfiguration { .. }
+                | Op::LogCertificateLogin { .. }
                 | Op::Comment { .. }
                 | Op::WeirdStorageUsageUpdates { .. }
                 | Op::TransactionDryRun => {}
@@ -0,0 +1,303 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Coordinator bookkeeping for durable subscriptions.
+//!
+//! A durable subscription is a `SUBSCRIBE ... WITH (NAME = ...)` whose acknowledged frontier is
+//! recorded in the catalog, so that a client can resume it after disconnecting or after
+//! `environmentd` restarts. While a durable subscription exists, the coordinator holds back
+//! compaction of the storage collections it reads from to just before its frontier. The holds
+//! are reacquired on bootstrap for storage collections only; indexes that a subscription read
+//! from are not held across restarts. A subscription whose holds cannot be reacquired is marked
+//! broken, and can then only be dropped.
+//!
+//! A durable subscription is recorded as soon as it starts. Until its client acknowledges a
+//! frontier, resuming it starts over with a snapshot, so it does not need to hold anything back.
+//!
+//! Acknowledged frontiers are buffered and written to the catalog every
+//! `durable_subscription_checkpoint_interval`, so delivery is at least once: a client that
+//! resumes a subscription after a restart may receive updates it had already acknowledged again.
+
+use std::collections::BTreeSet;
+
+use mz_catalog::memory::objects::{CatalogItem, DurableSubscription};
+use mz_repr::{GlobalId, Timestamp};
+use mz_sql::plan;
+use mz_sql::session::metadata::SessionMetadata;
+use timely::progress::Timestamp as _;
+use tracing::warn;
+
+use crate::catalog;
+use crate::command::ExecuteResponse;
+use crate::coord::Coordinator;
+use crate::coord::read_policy::ReadHolds;
+use crate::error::AdapterError;
+use crate::notice::AdapterNotice;
+use crate::session::Session;
+
+impl Coordinator {
+    /// Records that a client has consumed all updates of the durable subscription `name` before
+    /// `subscription.frontier`. The frontier is written to the catalog at the next checkpoint of
+    /// [`Coordinator::maintain_durable_subscriptions`].
+    pub(crate) fn durable_subscription_ack(
+        &mut self,
+        name: String,
+        subscription: DurableSubscription,
+    ) {
+        let pending = self
+            .pending_durable_subscription_acks
+            .entry(name)
+            .or_insert_with(|| subscription.clone());
+        if pending.frontier < subscription.frontier {
+            *pending = subscription;
+        }
+    }
+
+    /// Writes acknowledged frontiers to the catalog, drops durable subscriptions that have been
+    /// disconnected for longer than `max_durable_subscription_lag`, and downgrades or releases
+    /// the read holds of all durable subscriptions accordingly.
+    ///
+    /// Does nothing if it last ran less than `durable_subscription_checkpoint_interval` ago, so
+    /// that the frontiers acknowledged in the meantime are written in a single catalog
+    /// transaction.
+    pub(crate) async fn maintain_durable_subscriptions(&mut self) {
+        if self.controller.read_only() {
+            return;
+        }
+        let interval = self
+            .catalog()
+            .system_config()
+            .durable_subscription_checkpoint_interval();
+        let interval = u64::try_from(interval.as_millis()).unwrap_or(u64::MAX);
+        let now = self.now();
+        if now.saturating_sub(self.durable_subscriptions_checkpointed_at) < interval {
+            return;
+        }
+        self.durable_subscriptions_checkpointed_at = now;
+
+        let pending_acks = std::mem::take(&mut self.pending_durable_subscription_acks);
+        let existing = self.catalog.state().durable_subscriptions();
+        let mut ops = Vec::new();
+
+        for (name, subscription) in pending_acks {
+            // Skip acknowledgements that arrive after the owner or an input of the subscription
+            // was dropped.
+            let owner_exists = self
+                .catalog()
+                .try_get_role(&subscription.owner_id)
+                .is_some();
+            let inputs_exist = subscription
+                .dependencies
+                .iter()
+                .all(|id| self.catalog().try_get_entry_by_global_id(id).is_some());
+            let advances = existing
+                .get(&name)
+                .map_or(true, |existing| existing.frontier < subscription.frontier);
+            if owner_exists && inputs_exist && advances {
+                ops.push(catalog::Op::UpsertDurableSubscription { name, subscription });
+            }
+        }
+
+        let max_lag = self
+            .catalog()
+            .system_config()
+            .max_durable_subscription_lag();
+        let max_lag = u64::try_from(max_lag.as_millis()).unwrap_or(u64::MAX);
+        let mut expired = BTreeSet::new();
+        for (name, subscription) in existing.iter() {
+            if self.active_durable_subscriptions.contains_key(name) {
+                self.unacknowledged_durable_subscriptions_since.remove(name);
+                continue;
+            }
+            // Subscriptions that have not acknowledged anything yet have no frontier to measure
+            // their lag by, so measure it from when they were first seen disconnected instead.
+            let since = if subscription.frontier == Timestamp::minimum() {
+                *self
+                    .unacknowledged_durable_subscriptions_since
+                    .entry(name.clone())
+                    .or_insert(now)
+            } else {
+                self.unacknowledged_durable_subscriptions_since.remove(name);
+                u64::from(subscription.frontier)
+            };
+            if now.saturating_sub(since) > max_lag {
+                expired.insert(name.clone());
+            }
+        }
+        for name in &expired {
+            warn!(%name, "dropping durable subscription that exceeded max_durable_subscription_lag");
+        }
+        self.broken_durable_subscriptions
+            .retain(|name| !expired.contains(name));
+        self.unacknowledged_durable_subscriptions_since
+            .retain(|name, _| existing.contains_key(name) && !expired.contains(name));
+        if !expired.is_empty() {
+            ops.push(catalog::Op::DropDurableSubscriptions { names: expired });
+        }
+
+        if !ops.is_empty() {
+            if let Err(e) = self.catalog_transact(None, ops).await {
+                warn!("failed to record durable subscription frontiers: {e}");
+            }
+        }
+
+        self.update_durable_subscription_holds();
+    }
+
+    /// Downgrades the read holds of every durable subscription to just before its frontier and
+    /// releases the holds of durable subscriptions that no longer exist.
+    fn update_durable_subscription_holds(&mut self) {
+        let existing = self.catalog.state().durable_subscriptions();
+        self.durable_subscription_holds.retain(|name, holds| {
+            match existing.get(name) {
+                // Subscriptions that have not acknowledged anything yet start over when they are
+                // resumed, so they only need their holds while they are running.
+                Some(subscription) if subscription.frontier == Timestamp::minimum() => {
+                    self.active_durable_subscriptions.contains_key(name)
+                }
+                Some(subscription) => {
+                    holds.downgrade(subscription.frontier.saturating_sub(1));
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
+    /// Reacquires the read holds of all durable subscriptions in the catalog.
+    ///
+    /// Must be called during bootstrap, before read policies are initialized, so that the
+    /// storage collections the subscriptions read from are still readable at their frontiers.
+    /// Subscriptions whose inputs cannot be held are marked broken.
+    pub(crate) fn bootstrap_durable_subscription_holds(&mut self) {
+        let subscriptions: Vec<_> = self
+            .catalog()
+            .state()
+            .durable_subscriptions()
+            .iter()
+            // Subscriptions that have not acknowledged anything yet start over when they are
+            // resumed, and don't need any holds.
+            .filter(|(_, subscription)| subscription.frontier != Timestamp::minimum())
+            .map(|(name, subscription)| (name.clone(), subscription.clone()))
+            .collect();
+        for (name, subscription) in subscriptions {
+            let storage_ids = self.durable_subscription_storage_ids(&subscription.dependencies);
+            let storage_holds = match self
+                .controller
+                .storage_collections
+                .acquire_read_holds(storage_ids.into_iter().collect())
+            {
+                Ok(storage_holds) => storage_holds,
+                Err(e) => {
+                    warn!(%name, "durable subscription can no longer be resumed: {e}");
+                    self.broken_durable_subscriptions.insert(name);
+                    continue;
+                }
+            };
+            let mut holds = ReadHolds::new();
+            holds.storage_holds = storage_holds
+                .into_iter()
+                .map(|hold| (hold.id(), hold))
+                .collect();
+            holds.downgrade(subscription.frontier.saturating_sub(1));
+            self.durable_subscription_holds.insert(name, holds);
+        }
+    }
+
+    /// Returns the storage collections that the collections in `ids` are ultimately computed
+    /// from.
+    fn durable_subscription_storage_ids(&self, ids: &[GlobalId]) -> BTreeSet<GlobalId> {
+        let mut storage_ids = BTreeSet::new();
+        let mut seen = BTreeSet::new();
+        let mut todo = ids.to_vec();
+        while let Some(id) = todo.pop() {
+            if !seen.insert(id) {
+                continue;
+            }
+            let Some(entry) = self.catalog().try_get_entry_by_global_id(&id) else {
+                continue;
+            };
+            match entry.item() {
+                CatalogItem::View(view) => todo.extend(view.optimized_expr.0.depends_on()),
+                CatalogItem::Source(_)
+                | CatalogItem::Table(_)
+                | CatalogItem::MaterializedView(_)
+                | CatalogItem::ContinualTask(_) => {
+                    storage_ids.insert(id);
+                }
+                CatalogItem::Log(_)
+                | CatalogItem::Sink(_)
+                | CatalogItem::Index(_)
+                | CatalogItem::Type(_)
+                | CatalogItem::Func(_)
+                | CatalogItem::Secret(_)
+                | CatalogItem::Endpoint(_)
+                | CatalogItem::Connection(_) => {}
+            }
+        }
+        storage_ids
+    }
+
+    /// Stores the read holds that keep the inputs of the durable subscription `name` readable,
+    /// replacing any holds it previously had.
+    pub(crate) fn store_durable_subscription_holds(
+        &mut self,
+        name: String,
+        holds: ReadHolds<Timestamp>,
+    ) {
+        self.durable_subscription_holds.insert(name, holds);
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    pub(super) async fn sequence_drop_subscription(
+        &mut self,
+        session: &Session,
+        plan::DropSubscriptionPlan { name, if_exists }: plan::DropSubscriptionPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let Some(subscription) = self.catalog().state().durable_subscriptions().get(&name) else {
+            if if_exists {
+                session.add_notice(AdapterNotice::DurableSubscriptionDoesNotExist { name });
+                return Ok(ExecuteResponse::DroppedSubscription);
+            }
+            return Err(AdapterError::UnknownDurableSubscription(name));
+        };
+        if !self.is_durable_subscription_owner(session, subscription) {
+            return Err(AdapterError::DurableSubscriptionOwnership(name));
+        }
+        if self.active_durable_subscriptions.contains_key(&name) {
+            return Err(AdapterError::DurableSubscriptionInUse(name));
+        }
+
+        self.pending_durable_subscription_acks.remove(&name);
+        let op = catalog::Op::DropDurableSubscriptions {
+            names: BTreeSet::from([name.clone()]),
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        self.durable_subscription_holds.remove(&name);
+        self.broken_durable_subscriptions.remove(&name);
+        self.unacknowledged_durable_subscriptions_since
+            .remove(&name);
+
+        Ok(ExecuteResponse::DroppedSubscription)
+    }
+
+    /// Reports whether the current role of `session` may resume or drop `subscription`.
+    pub(crate) fn is_durable_subscription_owner(
+        &self,
+        session: &Session,
+        subscription: &DurableSubscription,
+    ) -> bool {
+        session.is_superuser()
+            || self
+                .catalog()
+                .state()
+                .collect_role_membership(session.current_role_id())
+                .contains(&subscription.owner_id)
+    }
+}
@@ -72,6 +72,7 @@ impl DataflowBuilder<'_> {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Endpoint(_)
                     | CatalogItem::Connection(_) => {
                         // Non-indexable thing; no work to do.
                     }
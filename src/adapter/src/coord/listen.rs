@@ -0,0 +1,121 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Coordinator bookkeeping for `LISTEN` and `NOTIFY`.
+//!
+//! Sessions listen on named channels, and `NOTIFY` delivers a notification to every session
+//! listening on its channel. A channel can additionally be bound to a relation with
+//! `LISTEN channel ON relation`, which installs a subscribe that raises a notification on the
+//! channel for every batch of changes to the relation. Unlike in PostgreSQL, `LISTEN`, `UNLISTEN`
+//! and `NOTIFY` take effect immediately rather than when the transaction commits.
+
+use std::collections::BTreeMap;
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_sql::plan::{ListenPlan, NotifyPlan, UnlistenPlan};
+use mz_sql::session::metadata::SessionMetadata;
+
+use crate::ExecuteContext;
+use crate::active_compute_sink::ActiveComputeSinkRetireReason;
+use crate::command::ExecuteResponse;
+use crate::coord::{Coordinator, TargetCluster};
+use crate::session::{Notification, Session};
+
+impl Coordinator {
+    #[mz_ore::instrument(level = "debug")]
+    pub(super) async fn sequence_listen(
+        &mut self,
+        ctx: ExecuteContext,
+        ListenPlan { channel, subscribe }: ListenPlan,
+        target_cluster: TargetCluster,
+    ) {
+        let conn_id = ctx.session().conn_id().clone();
+        let tx = ctx.session().retain_notification_transmitter();
+        self.listeners
+            .entry(channel.clone())
+            .or_default()
+            .insert(conn_id.clone(), tx);
+
+        match subscribe {
+            Some(subscribe) => {
+                // Binding a channel to a relation replaces any relation it was bound to before.
+                self.retire_listen_bindings(&conn_id, Some(&channel)).await;
+                self.sequence_subscribe(ctx, subscribe, target_cluster)
+                    .await;
+            }
+            None => ctx.retire(Ok(ExecuteResponse::Listened)),
+        }
+    }
+
+    #[mz_ore::instrument(level = "debug")]
+    pub(super) async fn sequence_unlisten(
+        &mut self,
+        session: &Session,
+        UnlistenPlan { channel }: UnlistenPlan,
+    ) -> ExecuteResponse {
+        self.unlisten(session.conn_id(), channel.as_deref()).await;
+        ExecuteResponse::Unlistened
+    }
+
+    pub(super) fn sequence_notify(
+        &self,
+        session: &Session,
+        NotifyPlan { channel, payload }: NotifyPlan,
+    ) -> ExecuteResponse {
+        self.notify_listeners(session.conn_id(), channel, payload);
+        ExecuteResponse::Notified
+    }
+
+    /// Delivers a notification on `channel` from the connection `conn_id` to every session
+    /// listening on `channel`, including the notifying session itself.
+    pub(crate) fn notify_listeners(
+        &self,
+        conn_id: &ConnectionId,
+        channel: String,
+        payload: String,
+    ) {
+        let Some(listeners) = self.listeners.get(&channel) else {
+            return;
+        };
+        let notification = Notification {
+            pid: conn_id.unhandled(),
+            channel,
+            payload,
+        };
+        for tx in listeners.values() {
+            // The session may have gone away without unlistening yet.
+            let _ = tx.send(notification.clone());
+        }
+    }
+
+    /// Stops the connection `conn_id` from listening on `channel`, or on all channels if
+    /// `channel` is `None`, and drops the relations bound to those channels.
+    pub(crate) async fn unlisten(&mut self, conn_id: &ConnectionId, channel: Option<&str>) {
+        self.listeners.retain(|name, listeners| {
+            if channel.map_or(true, |channel| channel == name) {
+                listeners.remove(conn_id);
+            }
+            !listeners.is_empty()
+        });
+        self.retire_listen_bindings(conn_id, channel).await;
+    }
+
+    /// Drops the subscribes that bind the channels of the connection `conn_id` to relations.
+    async fn retire_listen_bindings(&mut self, conn_id: &ConnectionId, channel: Option<&str>) {
+        let sink_ids: BTreeMap<_, _> = self
+            .listen_bindings
+            .iter()
+            .filter(|((id, name), _)| {
+                id == conn_id && channel.map_or(true, |channel| channel == name)
+            })
+            .map(|(_, sink_id)| (*sink_id, ActiveComputeSinkRetireReason::Finished))
+            .collect();
+        self.retire_compute_sinks(sink_ids).await;
+    }
+}
@@ -104,6 +104,8 @@ impl Coordinator {
             }
             Message::AdvanceTimelines => {
                 self.advance_timelines().boxed_local().await;
+                self.maintain_durable_subscriptions().boxed_local().await;
+                self.maintain_replication_slots().boxed_local().await;
             }
             Message::ClusterEvent(event) => self.message_cluster_event(event).boxed_local().await,
             Message::CancelPendingPeeks { conn_id } => {
@@ -180,6 +182,16 @@ impl Coordinator {
             Message::DrainStatementLog => {
                 self.drain_statement_log();
             }
+            Message::DurableSubscriptionAck { name, subscription } => {
+                self.durable_subscription_ack(name, subscription);
+            }
+            Message::ListenNotify {
+                conn_id,
+                channel,
+                payload,
+            } => {
+                self.notify_listeners(&conn_id, channel, payload);
+            }
             Message::PrivateLinkVpcEndpointEvents(events) => {
                 if !self.controller.read_only() {
                     self.controller
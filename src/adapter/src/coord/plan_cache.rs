@@ -0,0 +1,276 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A cache of optimized peek plans.
+//!
+//! Optimizing a peek is often the dominant cost of a point lookup against an index, so the
+//! coordinator caches the plans of peeks that can be answered on the fast path. Plans are cached
+//! by statement: the normalized SQL text of a query, or the endpoint it executes, together with
+//! the types of its parameters and the optimizer features in effect.
+//!
+//! A cached plan is the result of lowering and locally optimizing the statement with placeholders
+//! in place of its parameters, so one plan serves all the arguments the statement is executed
+//! with. The arguments of an execution are bound into the cached plan, which is then optimized
+//! globally at the timestamp of the peek, against the indexes that exist at that time. Statements
+//! whose arguments have to be cast to the types of their parameters, or that take the values of
+//! an `OFFSET` from parameters, are not cached.
+//!
+//! Each session has its own cache of at most `plan_cache_max_entries` plans, which `DISCARD
+//! PLANS` clears. With `enable_shared_plan_cache`, plans are additionally shared among all
+//! sessions. The least recently used plan is evicted first. Cached plans are invalidated when an
+//! object they depend on is dropped or altered, when their cluster is dropped or altered, and
+//! when the system configuration changes.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use lru::LruCache;
+use mz_adapter_types::connection::ConnectionId;
+use mz_compute_types::ComputeInstanceId;
+use mz_repr::optimize::{OptimizerFeatures, OverrideFrom};
+use mz_repr::{CatalogItemId, GlobalId, ScalarType};
+use mz_sql::ast::display::AstDisplay;
+use mz_sql::plan::SelectPlan;
+
+use crate::coord::Coordinator;
+use crate::optimize::peek::LocalMirPlan;
+use crate::session::Session;
+
+/// Cached peek plans, per session and shared among sessions.
+#[derive(Debug, Default)]
+pub(crate) struct PlanCache {
+    sessions: BTreeMap<ConnectionId, PlanCacheShard>,
+    shared: PlanCacheShard,
+}
+
+/// A bounded set of cached plans.
+#[derive(Debug)]
+struct PlanCacheShard {
+    plans: LruCache<PlanCacheKey, PlanCacheEntry>,
+}
+
+impl Default for PlanCacheShard {
+    fn default() -> Self {
+        PlanCacheShard {
+            plans: LruCache::unbounded(),
+        }
+    }
+}
+
+/// Identifies a statement whose plan is cached.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PlanCacheKey {
+    cluster_id: ComputeInstanceId,
+    statement: PlanCacheStatement,
+    /// The types that the statement expects for its parameters.
+    param_types: Vec<ScalarType>,
+    features: OptimizerFeatures,
+}
+
+/// What a cached plan was optimized from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PlanCacheStatement {
+    /// A query, by its normalized SQL text.
+    Sql(String),
+    /// An execution of an endpoint.
+    Endpoint(CatalogItemId),
+}
+
+#[derive(Debug, Clone)]
+struct PlanCacheEntry {
+    plan: LocalMirPlan,
+    /// The catalog items the plan transitively depends on.
+    dependencies: BTreeSet<CatalogItemId>,
+}
+
+/// The result of looking up the plan of a peek in the [`PlanCache`].
+#[derive(Debug)]
+pub(crate) enum PlanCacheLookup {
+    /// The plan of the peek cannot be cached.
+    Uncacheable,
+    /// No plan is cached for the peek. The plan optimized from its
+    /// `SelectPlan::generic_source` is to be cached.
+    Miss,
+    /// The cached plan of the peek, with placeholders for its parameters.
+    Hit(LocalMirPlan),
+}
+
+impl PlanCache {
+    /// Drops the plans cached for the connection `conn_id`.
+    pub(crate) fn discard_session(&mut self, conn_id: &ConnectionId) {
+        self.sessions.remove(conn_id);
+    }
+
+    /// Drops the cached plans that depend on any of `ids`.
+    pub(crate) fn invalidate_items(&mut self, ids: &BTreeSet<CatalogItemId>) {
+        self.retain(|_, entry| entry.dependencies.is_disjoint(ids));
+    }
+
+    /// Drops the cached plans for any of the clusters `cluster_ids`.
+    pub(crate) fn invalidate_clusters(&mut self, cluster_ids: &BTreeSet<ComputeInstanceId>) {
+        self.retain(|key, _| !cluster_ids.contains(&key.cluster_id));
+    }
+
+    /// Drops all cached plans.
+    pub(crate) fn clear(&mut self) {
+        self.retain(|_, _| false);
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&PlanCacheKey, &PlanCacheEntry) -> bool) {
+        for shard in self.sessions.values_mut().chain(Some(&mut self.shared)) {
+            shard.retain(&mut f);
+        }
+    }
+
+    fn get(
+        &mut self,
+        conn_id: &ConnectionId,
+        shared: bool,
+        key: &PlanCacheKey,
+    ) -> Option<LocalMirPlan> {
+        let session = self.sessions.get_mut(conn_id);
+        let shards = session
+            .into_iter()
+            .chain(shared.then_some(&mut self.shared));
+        for shard in shards {
+            if let Some(entry) = shard.plans.get(key) {
+                return Some(entry.plan.clone());
+            }
+        }
+        None
+    }
+
+    fn insert(
+        &mut self,
+        conn_id: &ConnectionId,
+        shared: bool,
+        max_entries: usize,
+        key: PlanCacheKey,
+        entry: PlanCacheEntry,
+    ) {
+        let session = self.sessions.entry(conn_id.clone()).or_default();
+        if shared {
+            self.shared.insert(max_entries, key.clone(), entry.clone());
+        }
+        session.insert(max_entries, key, entry);
+    }
+}
+
+impl PlanCacheShard {
+    fn insert(&mut self, max_entries: usize, key: PlanCacheKey, entry: PlanCacheEntry) {
+        self.plans.put(key, entry);
+        while self.plans.len() > max_entries {
+            self.plans.pop_lru();
+        }
+    }
+
+    fn retain(&mut self, f: &mut impl FnMut(&PlanCacheKey, &PlanCacheEntry) -> bool) {
+        let stale: Vec<_> = self
+            .plans
+            .iter()
+            .filter(|(key, entry)| !f(key, entry))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in stale {
+            self.plans.pop(&key);
+        }
+    }
+}
+
+impl Coordinator {
+    /// Returns the key under which the plan of `plan` is cached, or `None` if its plan cannot
+    /// be cached.
+    ///
+    /// `endpoint_id` is the endpoint that `plan` executes, if any.
+    fn plan_cache_key(
+        &self,
+        cluster_id: ComputeInstanceId,
+        endpoint_id: Option<CatalogItemId>,
+        plan: &SelectPlan,
+    ) -> Option<PlanCacheKey> {
+        plan.generic_source.as_ref()?;
+        let statement = match (endpoint_id, &plan.select) {
+            (Some(id), _) => PlanCacheStatement::Endpoint(id),
+            (None, Some(select)) => PlanCacheStatement::Sql(select.to_ast_string_stable()),
+            (None, None) => return None,
+        };
+        let features = OptimizerFeatures::from(self.catalog().system_config())
+            .override_from(&self.catalog().get_cluster(cluster_id).config.features());
+        Some(PlanCacheKey {
+            cluster_id,
+            statement,
+            param_types: plan.params.expected_types.clone(),
+            features,
+        })
+    }
+
+    /// Looks up the cached plan for peeking `plan` on the cluster `cluster_id`.
+    ///
+    /// `endpoint_id` is the endpoint that `plan` executes, if any.
+    pub(crate) fn plan_cache_get(
+        &mut self,
+        session: &Session,
+        cluster_id: ComputeInstanceId,
+        endpoint_id: Option<CatalogItemId>,
+        plan: &SelectPlan,
+    ) -> PlanCacheLookup {
+        let system_config = self.catalog().system_config();
+        if system_config.plan_cache_max_entries() == 0 {
+            return PlanCacheLookup::Uncacheable;
+        }
+        let shared = system_config.enable_shared_plan_cache();
+        let Some(key) = self.plan_cache_key(cluster_id, endpoint_id, plan) else {
+            return PlanCacheLookup::Uncacheable;
+        };
+        let (lookup, result) = match self.plan_cache.get(session.conn_id(), shared, &key) {
+            Some(cached) => (PlanCacheLookup::Hit(cached), "hit"),
+            None => (PlanCacheLookup::Miss, "miss"),
+        };
+        self.metrics
+            .plan_cache_lookups
+            .with_label_values(&[result])
+            .inc();
+        lookup
+    }
+
+    /// Caches `local_mir_plan`, optimized from the generic source of `plan`, as the plan for
+    /// peeking `plan` on the cluster `cluster_id`.
+    pub(crate) fn plan_cache_insert(
+        &mut self,
+        session: &Session,
+        cluster_id: ComputeInstanceId,
+        endpoint_id: Option<CatalogItemId>,
+        plan: &SelectPlan,
+        source_ids: &BTreeSet<GlobalId>,
+        local_mir_plan: LocalMirPlan,
+    ) {
+        let system_config = self.catalog().system_config();
+        let max_entries = system_config.plan_cache_max_entries();
+        let shared = system_config.enable_shared_plan_cache();
+        if max_entries == 0 {
+            return;
+        }
+        let Some(key) = self.plan_cache_key(cluster_id, endpoint_id, plan) else {
+            return;
+        };
+
+        let state = self.catalog().state();
+        let dependencies = source_ids
+            .iter()
+            .map(|id| state.get_entry_by_global_id(id).id())
+            .flat_map(|id| state.transitive_uses(id))
+            .chain(endpoint_id)
+            .collect();
+        let entry = PlanCacheEntry {
+            plan: local_mir_plan,
+            dependencies,
+        };
+        self.plan_cache
+            .insert(session.conn_id(), shared, max_entries, key, entry);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Coordinator bookkeeping for logical replication slots.
+//!
+//! A replication slot records the LSN through which a logical replication client has confirmed
+//! flushing the changes streamed to it, so that the client can resume streaming after
+//! disconnecting or after `environmentd` restarts. Slots are created and dropped with the
+//! `CREATE_REPLICATION_SLOT` and `DROP_REPLICATION_SLOT` replication commands and are owned by
+//! the role that created them. The first `START_REPLICATION` of a slot binds it to the relation
+//! of the publication it streams; the slot is dropped together with that relation.
+//!
+//! Confirmed LSNs are buffered and written to the catalog every
+//! `replication_slot_checkpoint_interval`, so, as in PostgreSQL, a client that resumes a slot
+//! after a restart may receive changes it had already confirmed again.
+
+use std::collections::BTreeSet;
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_catalog::memory::objects::ReplicationSlot;
+use mz_repr::{GlobalId, Timestamp};
+use mz_sql::rbac::UnauthorizedError;
+use timely::progress::Timestamp as _;
+use tracing::warn;
+
+use crate::catalog;
+use crate::coord::Coordinator;
+use crate::error::AdapterError;
+
+impl Coordinator {
+    /// Creates the replication slot `name`, owned by the role that connection `conn_id`
+    /// authenticated as.
+    pub(crate) async fn create_replication_slot(
+        &mut self,
+        conn_id: ConnectionId,
+        name: String,
+    ) -> Result<(), AdapterError> {
+        if self
+            .catalog()
+            .state()
+            .replication_slots()
+            .contains_key(&name)
+        {
+            return Err(AdapterError::ReplicationSlotExists(name));
+        }
+        if self.active_replication_slots.contains_key(&name) {
+            return Err(AdapterError::ReplicationSlotInUse(name));
+        }
+        let owner_id = *self.active_conns[&conn_id].authenticated_role_id();
+        if self.catalog().try_get_role(&owner_id).is_none() {
+            return Err(AdapterError::Unauthorized(
+                UnauthorizedError::ConcurrentRoleDrop(owner_id),
+            ));
+        }
+
+        self.pending_replication_slot_confirms.remove(&name);
+        let op = catalog::Op::UpsertReplicationSlot {
+            name,
+            slot: ReplicationSlot {
+                owner_id,
+                publication: None,
+                confirmed_flush: Timestamp::minimum(),
+            },
+        };
+        self.catalog_transact_conn(Some(&conn_id), vec![op]).await
+    }
+
+    /// Drops the replication slot `name` on behalf of connection `conn_id`.
+    pub(crate) async fn drop_replication_slot(
+        &mut self,
+        conn_id: ConnectionId,
+        name: String,
+    ) -> Result<(), AdapterError> {
+        self.check_replication_slot_owner(&conn_id, &name)?;
+        if self.active_replication_slots.contains_key(&name) {
+            return Err(AdapterError::ReplicationSlotInUse(name));
+        }
+
+        self.pending_replication_slot_confirms.remove(&name);
+        let op = catalog::Op::DropReplicationSlots {
+            names: BTreeSet::from([name]),
+        };
+        self.catalog_transact_conn(Some(&conn_id), vec![op]).await
+    }
+
+    /// Starts streaming `publication` from the replication slot `name` on connection `conn_id`,
+    /// binding the slot to `publication` if it is not yet bound, and returns the LSN through
+    /// which the slot's client has confirmed flushing.
+    pub(crate) async fn acquire_replication_slot(
+        &mut self,
+        conn_id: ConnectionId,
+        name: String,
+        publication: GlobalId,
+    ) -> Result<Timestamp, AdapterError> {
+        let slot = self.check_replication_slot_owner(&conn_id, &name)?.clone();
+        if self.active_replication_slots.contains_key(&name) {
+            return Err(AdapterError::ReplicationSlotInUse(name));
+        }
+        match slot.publication {
+            Some(bound) if bound != publication => {
+                return Err(AdapterError::ReplicationSlotPublicationMismatch(name));
+            }
+            Some(_) => {}
+            None => {
+                let op = catalog::Op::UpsertReplicationSlot {
+                    name: name.clone(),
+                    slot: ReplicationSlot {
+                        publication: Some(publication),
+                        ..slot.clone()
+                    },
+                };
+                self.catalog_transact_conn(Some(&conn_id), vec![op]).await?;
+            }
+        }
+
+        let confirmed_flush = self
+            .pending_replication_slot_confirms
+            .get(&name)
+            .map_or(slot.confirmed_flush, |pending| {
+                std::cmp::max(*pending, slot.confirmed_flush)
+            });
+        self.active_replication_slots.insert(name, conn_id);
+        Ok(confirmed_flush)
+    }
+
+    /// Records that the client streaming from the replication slot `name` on connection
+    /// `conn_id` has flushed all changes through `lsn`. The LSN is written to the catalog at the
+    /// next checkpoint of [`Coordinator::maintain_replication_slots`].
+    pub(crate) fn confirm_replication_slot(
+        &mut self,
+        conn_id: ConnectionId,
+        name: String,
+        lsn: Timestamp,
+    ) {
+        if self.active_replication_slots.get(&name) != Some(&conn_id) {
+            return;
+        }
+        let pending = self
+            .pending_replication_slot_confirms
+            .entry(name)
+            .or_insert(lsn);
+        *pending = std::cmp::max(*pending, lsn);
+    }
+
+    /// Stops streaming from the replication slot `name` on connection `conn_id`.
+    pub(crate) fn release_replication_slot(&mut self, conn_id: ConnectionId, name: String) {
+        if self.active_replication_slots.get(&name) == Some(&conn_id) {
+            self.active_replication_slots.remove(&name);
+        }
+    }
+
+    /// Writes confirmed LSNs to the catalog.
+    ///
+    /// Does nothing if it last ran less than `replication_slot_checkpoint_interval` ago, so that
+    /// the LSNs confirmed in the meantime are written in a single catalog transaction.
+    pub(crate) async fn maintain_replication_slots(&mut self) {
+        if self.controller.read_only() {
+            return;
+        }
+        let interval = self
+            .catalog()
+            .system_config()
+            .replication_slot_checkpoint_interval();
+        let interval = u64::try_from(interval.as_millis()).unwrap_or(u64::MAX);
+        let now = self.now();
+        if now.saturating_sub(self.replication_slots_checkpointed_at) < interval {
+            return;
+        }
+        self.replication_slots_checkpointed_at = now;
+
+        let pending_confirms = std::mem::take(&mut self.pending_replication_slot_confirms);
+        let existing = self.catalog().state().replication_slots();
+        let mut ops = Vec::new();
+        for (name, confirmed_flush) in pending_confirms {
+            // Skip confirmations that arrive after the slot was dropped.
+            let Some(slot) = existing.get(&name) else {
+                continue;
+            };
+            if slot.confirmed_flush < confirmed_flush {
+                let slot = ReplicationSlot {
+                    confirmed_flush,
+                    ..slot.clone()
+                };
+                ops.push(catalog::Op::UpsertReplicationSlot { name, slot });
+            }
+        }
+
+        if !ops.is_empty() {
+            if let Err(e) = self.catalog_transact(None, ops).await {
+                warn!("failed to record replication slot LSNs: {e}");
+            }
+        }
+    }
+
+    /// Returns the replication slot `name`, if the role that connection `conn_id`
+    /// authenticated as may use it.
+    fn check_replication_slot_owner(
+        &self,
+        conn_id: &ConnectionId,
+        name: &str,
+    ) -> Result<&ReplicationSlot, AdapterError> {
+        let Some(slot) = self.catalog().state().replication_slots().get(name) else {
+            return Err(AdapterError::UnknownReplicationSlot(name.into()));
+        };
+        let conn = &self.active_conns[conn_id];
+        let is_owner = conn.user().is_superuser()
+            || self
+                .catalog()
+                .state()
+                .collect_role_membership(conn.authenticated_role_id())
+                .contains(&slot.owner_id);
+        if !is_owner {
+            return Err(AdapterError::ReplicationSlotOwnership(name.into()));
+        }
+        Ok(slot)
+    }
+}
@@ -243,6 +243,12 @@ impl Coordinator {
                 Plan::CreateSecret(plan) => {
                     self.sequence_create_secret(ctx, plan).await;
                 }
+                Plan::CreateEndpoint(plan) => {
+                    let result = self
+                        .sequence_create_endpoint(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::CreateSink(plan) => {
                     self.sequence_create_sink(ctx, plan, resolved_ids).await;
                 }
@@ -289,6 +295,12 @@ impl Coordinator {
                     let result = self.sequence_drop_owned(ctx.session_mut(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::DropSubscription(plan) => {
+                    let result = self
+                        .sequence_drop_subscription(ctx.session_mut(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::EmptyQuery => {
                     ctx.retire(Ok(ExecuteResponse::EmptyQuery));
                 }
@@ -382,6 +394,10 @@ impl Coordinator {
                     let max = Some(ctx.session().vars().max_query_result_size());
                     self.sequence_peek(ctx, plan, target_cluster, max).await;
                 }
+                Plan::ExecuteEndpoint(plan) => {
+                    self.sequence_execute_endpoint(ctx, plan, target_cluster)
+                        .await;
+                }
                 Plan::Subscribe(plan) => {
                     self.sequence_subscribe(ctx, plan, target_cluster).await;
                 }
@@ -521,6 +537,18 @@ impl Coordinator {
                     let result = self.sequence_alter_table(ctx.session(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterTableAlterColumnType(plan) => {
+                    let result = self
+                        .sequence_alter_table_alter_column_type(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::AlterTableDropColumn(plan) => {
+                    let result = self
+                        .sequence_alter_table_drop_column(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterNetworkPolicy(plan) => {
                     let res = self
                         .sequence_alter_network_policy(ctx.session(), plan)
@@ -531,10 +559,16 @@ impl Coordinator {
                     self.drop_temp_items(ctx.session().conn_id()).await;
                     ctx.retire(Ok(ExecuteResponse::DiscardedTemp));
                 }
+                Plan::DiscardPlans => {
+                    self.plan_cache.discard_session(ctx.session().conn_id());
+                    ctx.retire(Ok(ExecuteResponse::DiscardedPlans));
+                }
                 Plan::DiscardAll => {
                     let ret = if let TransactionStatus::Started(_) = ctx.session().transaction() {
                         self.clear_transaction(ctx.session_mut()).await;
                         self.drop_temp_items(ctx.session().conn_id()).await;
+                        self.unlisten(ctx.session().conn_id(), None).await;
+                        self.plan_cache.discard_session(ctx.session().conn_id());
                         ctx.session_mut().reset();
                         Ok(ExecuteResponse::DiscardedAll)
                     } else {
@@ -623,6 +657,17 @@ impl Coordinator {
                         .add_notice(AdapterNotice::UserRequested { severity });
                     ctx.retire(Ok(ExecuteResponse::Raised));
                 }
+                Plan::Listen(plan) => {
+                    self.sequence_listen(ctx, plan, target_cluster).await;
+                }
+                Plan::Unlisten(plan) => {
+                    let resp = self.sequence_unlisten(ctx.session(), plan).await;
+                    ctx.retire(Ok(resp));
+                }
+                Plan::Notify(plan) => {
+                    let resp = self.sequence_notify(ctx.session(), plan);
+                    ctx.retire(Ok(resp));
+                }
                 Plan::GrantPrivileges(plan) => {
                     let result = self
                         .sequence_grant_privileges(ctx.session_mut(), plan)
@@ -125,6 +125,7 @@ mod create_continual_task;
 mod create_index;
 mod create_materialized_view;
 mod create_view;
+mod endpoint;
 mod explain_timestamp;
 mod peek;
 mod secret;
@@ -1088,8 +1089,8 @@ impl Coordinator {
         let collections = [(RelationVersion::root(), global_id)].into_iter().collect();
 
         let data_source = match table.data_source {
-            plan::TableDataSource::TableWrites { defaults } => {
-                TableDataSource::TableWrites { defaults }
+            plan::TableDataSource::TableWrites { defaults, domains } => {
+                TableDataSource::TableWrites { defaults, domains }
             }
             plan::TableDataSource::DataSource {
                 desc: data_source_plan,
@@ -1151,7 +1152,7 @@ impl Coordinator {
                 // by environmentd (e.g. with INSERT INTO statements) or by the storage layer
                 // (e.g. a source-fed table).
                 let (collections, register_ts, read_policies) = match table.data_source {
-                    TableDataSource::TableWrites { defaults: _ } => {
+                    TableDataSource::TableWrites { .. } => {
                         // Determine the initial validity for the table.
                         let register_ts = coord.get_local_write_ts().await.timestamp;
 
@@ -2594,6 +2595,7 @@ impl Coordinator {
                         None,
                         ExplainContext::Pushdown,
                         Some(ctx.session().vars().max_query_result_size()),
+                        None,
                     ),
                     ctx
                 );
@@ -3030,6 +3032,8 @@ impl Coordinator {
                 when: QueryWhen::FreshestTableWrite,
                 finishing,
                 copy_to: None,
+                params: Params::empty(),
+                generic_source: None,
             },
             TargetCluster::Active,
             None,
@@ -3351,6 +3355,7 @@ impl Coordinator {
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Endpoint(_)
                 | CatalogItem::Connection(_) => unreachable!(),
             };
             match cluster {
@@ -5014,16 +5019,77 @@ impl Coordinator {
             raw_sql_type,
         } = plan;
 
+        self.sequence_alter_table_version(session, relation_id, |new_global_id| {
+            catalog::Op::AlterAddColumn {
+                id: relation_id,
+                new_global_id,
+                name: column_name,
+                typ: column_type,
+                sql: raw_sql_type,
+            }
+        })
+        .await
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_table_alter_column_type(
+        &mut self,
+        session: &Session,
+        plan: plan::AlterTablePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTablePlan {
+            relation_id,
+            column_name,
+            column_type,
+            raw_sql_type,
+        } = plan;
+
+        self.sequence_alter_table_version(session, relation_id, |new_global_id| {
+            catalog::Op::AlterColumnType {
+                id: relation_id,
+                new_global_id,
+                name: column_name,
+                typ: column_type,
+                sql: raw_sql_type,
+            }
+        })
+        .await
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_table_drop_column(
+        &mut self,
+        session: &Session,
+        plan: plan::AlterTableDropColumnPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTableDropColumnPlan {
+            relation_id,
+            column_name,
+        } = plan;
+
+        self.sequence_alter_table_version(session, relation_id, |new_global_id| {
+            catalog::Op::AlterDropColumn {
+                id: relation_id,
+                new_global_id,
+                name: column_name,
+            }
+        })
+        .await
+    }
+
+    /// Creates a new version of the table `relation_id` by applying the catalog
+    /// op returned by `op`, and evolves the schema of the table's storage
+    /// collection to match.
+    async fn sequence_alter_table_version(
+        &mut self,
+        session: &Session,
+        relation_id: CatalogItemId,
+        op: impl FnOnce(GlobalId) -> catalog::Op,
+    ) -> Result<ExecuteResponse, AdapterError> {
         // TODO(alter_table): Support allocating GlobalIds without a CatalogItemId.
         let id_ts = self.get_catalog_write_ts().await;
         let (_, new_global_id) = self.catalog.allocate_user_id(id_ts).await?;
-        let ops = vec![catalog::Op::AlterAddColumn {
-            id: relation_id,
-            new_global_id,
-            name: column_name,
-            typ: column_type,
-            sql: raw_sql_type,
-        }];
+        let ops = vec![op(new_global_id)];
 
         let entry = self.catalog().get_entry(&relation_id);
         let CatalogItem::Table(table) = &entry.item else {
@@ -75,6 +75,7 @@ impl Coordinator {
                 is_retained_metrics_object: false,
                 data_source: TableDataSource::TableWrites {
                     defaults: Vec::new(),
+                    domains: Vec::new(),
                 },
             }),
             referenced_by: Vec::new(),
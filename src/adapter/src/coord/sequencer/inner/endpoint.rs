@@ -0,0 +1,98 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_catalog::memory::objects::{CatalogItem, Endpoint};
+use mz_ore::instrument;
+use mz_sql::catalog::CatalogError;
+use mz_sql::names::ResolvedIds;
+use mz_sql::plan::{CreateEndpointPlan, ExecuteEndpointPlan};
+use mz_sql::session::metadata::SessionMetadata;
+use tracing::Span;
+
+use crate::coord::sequencer::inner::return_if_err;
+use crate::coord::{Coordinator, ExplainContext, PeekEndpoint, TargetCluster};
+use crate::session::Session;
+use crate::{AdapterError, AdapterNotice, ExecuteContext, ExecuteResponse, catalog};
+
+impl Coordinator {
+    #[instrument]
+    pub(crate) async fn sequence_create_endpoint(
+        &mut self,
+        session: &Session,
+        CreateEndpointPlan {
+            name,
+            endpoint,
+            if_not_exists,
+        }: CreateEndpointPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let id_ts = self.get_catalog_write_ts().await;
+        let (item_id, global_id) = self.catalog_mut().allocate_user_id(id_ts).await?;
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::Endpoint(Endpoint {
+                create_sql: endpoint.create_sql,
+                global_id,
+                query: endpoint.query,
+                resolved_ids,
+                dependencies: endpoint.dependencies,
+            }),
+            owner_id: *session.current_role_id(),
+        };
+
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedEndpoint),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "endpoint",
+                });
+                Ok(ExecuteResponse::CreatedEndpoint)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Executes an endpoint as a peek that must be answerable without building a dataflow.
+    #[instrument]
+    pub(crate) async fn sequence_execute_endpoint(
+        &mut self,
+        ctx: ExecuteContext,
+        ExecuteEndpointPlan { id, select }: ExecuteEndpointPlan,
+        target_cluster: TargetCluster,
+    ) {
+        let entry = self.catalog().get_entry(&id);
+        let endpoint = PeekEndpoint {
+            id,
+            name: self
+                .catalog()
+                .resolve_full_name(entry.name(), Some(ctx.session().conn_id()))
+                .to_string(),
+        };
+        let max_query_result_size = Some(ctx.session().vars().max_query_result_size());
+
+        let stage = return_if_err!(
+            self.peek_validate(
+                ctx.session(),
+                select,
+                target_cluster,
+                None,
+                ExplainContext::None,
+                max_query_result_size,
+                Some(endpoint),
+            ),
+            ctx
+        );
+        self.sequence_staged(ctx, Span::current(), stage).await;
+    }
+}
@@ -38,6 +38,7 @@ use crate::active_compute_sink::{ActiveComputeSink, ActiveCopyTo};
 use crate::command::ExecuteResponse;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::{self, PeekDataflowPlan, PeekPlan, PlannedPeek};
+use crate::coord::plan_cache::PlanCacheLookup;
 use crate::coord::sequencer::inner::{check_log_reads, return_if_err};
 use crate::coord::timeline::TimelineContext;
 use crate::coord::timestamp_selection::{
@@ -45,8 +46,8 @@ use crate::coord::timestamp_selection::{
 };
 use crate::coord::{
     Coordinator, CopyToContext, ExecuteContext, ExplainContext, ExplainPlanContext, Message,
-    PeekStage, PeekStageCopyTo, PeekStageExplainPlan, PeekStageExplainPushdown, PeekStageFinish,
-    PeekStageLinearizeTimestamp, PeekStageOptimize, PeekStageRealTimeRecency,
+    PeekEndpoint, PeekStage, PeekStageCopyTo, PeekStageExplainPlan, PeekStageExplainPushdown,
+    PeekStageFinish, PeekStageLinearizeTimestamp, PeekStageOptimize, PeekStageRealTimeRecency,
     PeekStageTimestampReadHold, PlanValidity, StageResult, Staged, TargetCluster, WatchSetResponse,
 };
 use crate::error::AdapterError;
@@ -143,7 +144,8 @@ impl Coordinator {
                 target_cluster,
                 None,
                 explain_ctx,
-                max_query_result_size
+                max_query_result_size,
+                None,
             ),
             ctx
         );
@@ -209,6 +211,7 @@ impl Coordinator {
                 }),
                 ExplainContext::None,
                 Some(ctx.session().vars().max_query_result_size()),
+                None,
             ),
             ctx
         );
@@ -258,6 +261,7 @@ impl Coordinator {
                     optimizer_trace,
                 }),
                 Some(ctx.session().vars().max_query_result_size()),
+                None,
             ),
             ctx
         );
@@ -267,13 +271,14 @@ impl Coordinator {
     /// Do some simple validation. We must defer most of it until after any off-thread work.
     #[instrument]
     pub fn peek_validate(
-        &self,
+        &mut self,
         session: &Session,
         plan: mz_sql::plan::SelectPlan,
         target_cluster: TargetCluster,
         copy_to_ctx: Option<CopyToContext>,
         explain_ctx: ExplainContext,
         max_query_result_size: Option<u64>,
+        endpoint: Option<PeekEndpoint>,
     ) -> Result<PeekStage, AdapterError> {
         // Collect optimizer parameters.
         let catalog = self.owned_catalog();
@@ -387,13 +392,24 @@ impl Coordinator {
             session.role_metadata().clone(),
         );
 
+        // Peeks whose plan is cached skip HIR ⇒ MIR lowering and local MIR optimization.
+        let plan_cache = match (&optimizer, &explain_ctx) {
+            (Either::Left(_), ExplainContext::None) => {
+                let endpoint_id = endpoint.as_ref().map(|endpoint| endpoint.id);
+                self.plan_cache_get(session, cluster.id(), endpoint_id, &plan)
+            }
+            _ => PlanCacheLookup::Uncacheable,
+        };
+
         Ok(PeekStage::LinearizeTimestamp(PeekStageLinearizeTimestamp {
             validity,
             plan,
             max_query_result_size,
+            endpoint,
             source_ids,
             target_replica,
             timeline_context,
+            plan_cache,
             optimizer,
             explain_ctx,
         }))
@@ -409,8 +425,10 @@ impl Coordinator {
             source_ids,
             plan,
             max_query_result_size,
+            endpoint,
             target_replica,
             timeline_context,
+            plan_cache,
             optimizer,
             explain_ctx,
         }: PeekStageLinearizeTimestamp,
@@ -424,10 +442,12 @@ impl Coordinator {
             validity,
             plan,
             max_query_result_size,
+            endpoint,
             source_ids,
             target_replica,
             timeline_context,
             oracle_read_ts,
+            plan_cache,
             optimizer,
             explain_ctx,
         };
@@ -468,11 +488,13 @@ impl Coordinator {
             mut validity,
             plan,
             max_query_result_size,
+            endpoint,
             source_ids,
             target_replica,
             timeline_context,
             oracle_read_ts,
             real_time_recency_ts,
+            plan_cache,
             optimizer,
             explain_ctx,
         }: PeekStageTimestampReadHold,
@@ -508,10 +530,12 @@ impl Coordinator {
             validity,
             plan,
             max_query_result_size,
+            endpoint,
             source_ids,
             id_bundle,
             target_replica,
             determination,
+            plan_cache,
             optimizer,
             explain_ctx,
         });
@@ -526,10 +550,12 @@ impl Coordinator {
             validity,
             plan,
             max_query_result_size,
+            endpoint,
             source_ids,
             id_bundle,
             target_replica,
             determination,
+            plan_cache,
             mut optimizer,
             explain_ctx,
         }: PeekStageOptimize,
@@ -559,6 +585,7 @@ impl Coordinator {
             || "optimize peek",
             move || {
                 span.in_scope(|| {
+                    let mut generic_plan = None;
                     let pipeline = || -> Result<Either<optimize::peek::GlobalLirPlan, optimize::copy_to::GlobalLirPlan>, AdapterError> {
                         let _dispatch_guard = explain_ctx.dispatch_guard();
 
@@ -567,8 +594,24 @@ impl Coordinator {
                         match optimizer.as_mut() {
                             // Optimize SELECT statement.
                             Either::Left(optimizer) => {
-                                // HIR ⇒ MIR lowering and MIR optimization (local)
-                                let local_mir_plan = optimizer.catch_unwind_optimize(raw_expr)?;
+                                let local_mir_plan = match plan_cache {
+                                    PlanCacheLookup::Uncacheable => {
+                                        // HIR ⇒ MIR lowering and MIR optimization (local)
+                                        optimizer.catch_unwind_optimize(raw_expr)?
+                                    }
+                                    PlanCacheLookup::Miss => {
+                                        let generic_source = plan.generic_source.clone().expect("cacheable plans have a generic source");
+                                        // HIR ⇒ MIR lowering and MIR optimization (local)
+                                        let mut local_mir_plan = optimizer.catch_unwind_optimize(generic_source)?;
+                                        generic_plan = Some(local_mir_plan.clone());
+                                        local_mir_plan.bind_parameters(&plan.params)?;
+                                        local_mir_plan
+                                    }
+                                    PlanCacheLookup::Hit(mut local_mir_plan) => {
+                                        local_mir_plan.bind_parameters(&plan.params)?;
+                                        local_mir_plan
+                                    }
+                                };
                                 // Attach resolved context required to continue the pipeline.
                                 let local_mir_plan = local_mir_plan.resolve(timestamp_context.clone(), &session, stats);
                                 // MIR optimization (global), MIR ⇒ LIR lowering, and LIR optimization (global)
@@ -641,6 +684,7 @@ impl Coordinator {
                                         validity,
                                         plan,
                                         max_query_result_size,
+                                        endpoint,
                                         id_bundle,
                                         target_replica,
                                         source_ids,
@@ -649,6 +693,7 @@ impl Coordinator {
                                         finishing: optimizer.finishing().clone(),
                                         plan_insights_optimizer_trace: Some(optimizer_trace),
                                         global_lir_plan,
+                                        generic_plan: None,
                                         optimization_finished_at,
                                         insights_ctx,
                                     })
@@ -657,6 +702,7 @@ impl Coordinator {
                                     validity,
                                     plan,
                                     max_query_result_size,
+                                    endpoint,
                                     id_bundle,
                                     target_replica,
                                     source_ids,
@@ -665,6 +711,7 @@ impl Coordinator {
                                     finishing: optimizer.finishing().clone(),
                                     plan_insights_optimizer_trace: None,
                                     global_lir_plan,
+                                    generic_plan,
                                     optimization_finished_at,
                                     insights_ctx,
                                 }),
@@ -746,10 +793,12 @@ impl Coordinator {
             validity,
             plan,
             max_query_result_size,
+            endpoint,
             source_ids,
             target_replica,
             timeline_context,
             oracle_read_ts,
+            plan_cache,
             optimizer,
             explain_ctx,
         }: PeekStageRealTimeRecency,
@@ -773,9 +822,11 @@ impl Coordinator {
                             validity,
                             plan,
                             max_query_result_size,
+                            endpoint,
                             target_replica,
                             timeline_context,
                             source_ids,
+                            plan_cache,
                             optimizer,
                             explain_ctx,
                             oracle_read_ts,
@@ -791,9 +842,11 @@ impl Coordinator {
                     validity,
                     plan,
                     max_query_result_size,
+                    endpoint,
                     target_replica,
                     timeline_context,
                     source_ids,
+                    plan_cache,
                     optimizer,
                     explain_ctx,
                     oracle_read_ts,
@@ -811,6 +864,7 @@ impl Coordinator {
             validity: _,
             plan,
             max_query_result_size,
+            endpoint,
             id_bundle,
             target_replica,
             source_ids,
@@ -819,6 +873,7 @@ impl Coordinator {
             finishing,
             plan_insights_optimizer_trace,
             global_lir_plan,
+            generic_plan,
             optimization_finished_at,
             insights_ctx,
         }: PeekStageFinish,
@@ -831,12 +886,28 @@ impl Coordinator {
             );
         }
 
+        // Only the plans of peeks that can be answered on the fast path are cached.
+        if let Some(generic_plan) = generic_plan.filter(|_| global_lir_plan.is_fast_path()) {
+            self.plan_cache_insert(
+                ctx.session(),
+                cluster_id,
+                endpoint.as_ref().map(|endpoint| endpoint.id),
+                &plan,
+                &source_ids,
+                generic_plan,
+            );
+        }
+
         let session = ctx.session_mut();
         let conn_id = session.conn_id().clone();
 
         let (peek_plan, df_meta, typ) = global_lir_plan.unapply();
         let source_arity = typ.arity();
 
+        if let (Some(endpoint), PeekPlan::SlowPath(_)) = (endpoint, &peek_plan) {
+            return Err(AdapterError::EndpointNotFastPath(endpoint.name));
+        }
+
         self.emit_optimizer_notices(&*session, &df_meta.optimizer_notices);
 
         let target_cluster = self.catalog().get_cluster(cluster_id);
@@ -7,15 +7,23 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::BTreeSet;
+
+use mz_catalog::memory::objects::DurableSubscription;
+use mz_expr::MirScalarExpr;
 use mz_ore::instrument;
 use mz_repr::optimize::OverrideFrom;
+use mz_repr::{Datum, ScalarType, Timestamp};
 use mz_sql::plan::{self, QueryWhen};
 use mz_sql::session::metadata::SessionMetadata;
-use timely::progress::Antichain;
+use timely::progress::{Antichain, Timestamp as _};
 use tokio::sync::mpsc;
 use tracing::Span;
 
-use crate::active_compute_sink::{ActiveComputeSink, ActiveSubscribe};
+use crate::active_compute_sink::{
+    ActiveComputeSink, ActiveDurableSubscription, ActiveListenBinding, ActiveSubscribe,
+};
+use crate::catalog;
 use crate::command::ExecuteResponse;
 use crate::coord::sequencer::inner::{check_log_reads, return_if_err};
 use crate::coord::{
@@ -86,10 +94,18 @@ impl Coordinator {
     fn subscribe_validate(
         &mut self,
         session: &mut Session,
-        plan: plan::SubscribePlan,
+        mut plan: plan::SubscribePlan,
         target_cluster: TargetCluster,
     ) -> Result<SubscribeStage, AdapterError> {
-        let plan::SubscribePlan { from, when, .. } = &plan;
+        if let Some(name) = &plan.name {
+            self.validate_durable_subscribe(session, name.clone(), &mut plan)?;
+        }
+        let plan::SubscribePlan {
+            from,
+            when,
+            listen_channel,
+            ..
+        } = &plan;
 
         let cluster = self
             .catalog()
@@ -117,8 +133,9 @@ impl Coordinator {
             .transpose()?;
 
         // SUBSCRIBE AS OF, similar to peeks, doesn't need to worry about transaction
-        // timestamp semantics.
-        if when == &QueryWhen::Immediately {
+        // timestamp semantics. Neither does the SUBSCRIBE behind a `LISTEN ... ON`, which
+        // outlives the transaction.
+        if when == &QueryWhen::Immediately && listen_channel.is_none() {
             // If this isn't a SUBSCRIBE AS OF, the SUBSCRIBE can be in a transaction if it's the
             // only operation.
             session.add_transaction_ops(TransactionOps::Subscribe)?;
@@ -166,6 +183,68 @@ impl Coordinator {
         }))
     }
 
+    /// Validates a `SUBSCRIBE` of the durable subscription `name`. If the durable subscription
+    /// already exists, rewrites `plan` to resume it from the frontier that its previous consumer
+    /// acknowledged.
+    fn validate_durable_subscribe(
+        &self,
+        session: &Session,
+        name: String,
+        plan: &mut plan::SubscribePlan,
+    ) -> Result<(), AdapterError> {
+        let depends_on = plan.from.depends_on();
+        let log_names: Vec<_> = depends_on
+            .iter()
+            .map(|id| self.catalog().resolve_item_id(id))
+            .flat_map(|id| self.catalog().introspection_dependencies(id))
+            .map(|id| self.catalog().get_entry(&id).name().item.clone())
+            .collect();
+        if !log_names.is_empty() {
+            return Err(AdapterError::InvalidLogDependency {
+                object_type: "durable subscription".into(),
+                log_names,
+            });
+        }
+
+        let Some(subscription) = self.catalog().state().durable_subscriptions().get(&name) else {
+            // A new durable subscription, which is created once it starts.
+            if self.active_durable_subscriptions.contains_key(&name) {
+                return Err(AdapterError::DurableSubscriptionInUse(name));
+            }
+            return Ok(());
+        };
+        if !self.is_durable_subscription_owner(session, subscription) {
+            return Err(AdapterError::DurableSubscriptionOwnership(name));
+        }
+        if self.broken_durable_subscriptions.contains(&name) {
+            return Err(AdapterError::DurableSubscriptionBroken(name));
+        }
+        if plan.when != QueryWhen::Immediately {
+            return Err(AdapterError::DurableSubscriptionAsOf(name));
+        }
+        let dependencies: BTreeSet<_> = subscription.dependencies.iter().copied().collect();
+        if dependencies != depends_on {
+            return Err(AdapterError::DurableSubscriptionMismatch(name));
+        }
+        if self.active_durable_subscriptions.contains_key(&name) {
+            return Err(AdapterError::DurableSubscriptionInUse(name));
+        }
+
+        // A subscription that has not acknowledged anything yet starts over with a snapshot.
+        if subscription.frontier == Timestamp::minimum() {
+            return Ok(());
+        }
+
+        // All updates before the frontier have been consumed, so resume right after them.
+        let as_of = subscription.frontier.saturating_sub(1);
+        plan.when = QueryWhen::AtTimestamp(MirScalarExpr::literal_ok(
+            Datum::MzTimestamp(as_of),
+            ScalarType::MzTimestamp,
+        ));
+        plan.with_snapshot = false;
+        Ok(())
+    }
+
     #[instrument]
     fn subscribe_optimize_mir(
         &mut self,
@@ -329,6 +408,8 @@ impl Coordinator {
                     copy_to,
                     emit_progress,
                     output,
+                    name,
+                    listen_channel,
                     ..
                 },
             global_lir_plan,
@@ -338,6 +419,49 @@ impl Coordinator {
     ) -> Result<StageResult<Box<SubscribeStage>>, AdapterError> {
         let sink_id = global_lir_plan.sink_id();
 
+        let durable = match name {
+            Some(name) => {
+                // Another session may have started consuming the subscription while this one
+                // was being optimized.
+                if self.active_durable_subscriptions.contains_key(&name) {
+                    return Err(AdapterError::DurableSubscriptionInUse(name));
+                }
+                let owner_id = match self.catalog().state().durable_subscriptions().get(&name) {
+                    Some(subscription) => subscription.owner_id,
+                    None => {
+                        // Record a new subscription right away, so that it can be resumed even
+                        // if its client disconnects before acknowledging anything.
+                        let owner_id = *ctx.session().current_role_id();
+                        let op = catalog::Op::UpsertDurableSubscription {
+                            name: name.clone(),
+                            subscription: DurableSubscription {
+                                owner_id,
+                                dependencies: dependency_ids.iter().copied().collect(),
+                                frontier: Timestamp::minimum(),
+                            },
+                        };
+                        let conn_id = ctx.session().conn_id().clone();
+                        self.catalog_transact_conn(Some(&conn_id), vec![op]).await?;
+                        owner_id
+                    }
+                };
+                Some(ActiveDurableSubscription {
+                    name,
+                    owner_id,
+                    dependencies: dependency_ids.iter().copied().collect(),
+                    internal_cmd_tx: self.internal_cmd_tx.clone(),
+                    sent_frontier: None,
+                })
+            }
+            None => None,
+        };
+        let durable_name = durable.as_ref().map(|durable| durable.name.clone());
+        let listen = listen_channel.map(|channel| ActiveListenBinding {
+            channel,
+            internal_cmd_tx: self.internal_cmd_tx.clone(),
+        });
+        let is_listen = listen.is_some();
+
         let (tx, rx) = mpsc::unbounded_channel();
         let active_subscribe = ActiveSubscribe {
             conn_id: ctx.session().conn_id().clone(),
@@ -352,6 +476,8 @@ impl Coordinator {
             depends_on: dependency_ids,
             start_time: self.now(),
             output,
+            durable,
+            listen,
         };
         active_subscribe.initialize();
 
@@ -377,8 +503,18 @@ impl Coordinator {
             .remove(ctx.session().conn_id())
             .expect("must have previously installed read holds");
 
-        // Explicitly drop read holds, just to make it obvious what's happening.
-        drop(txn_read_holds);
+        match durable_name {
+            // Durable subscriptions keep their inputs readable so that they can be resumed.
+            Some(name) => self.store_durable_subscription_holds(name, txn_read_holds),
+            // Explicitly drop read holds, just to make it obvious what's happening.
+            None => drop(txn_read_holds),
+        }
+
+        // The updates of a `LISTEN ... ON` are turned into notifications, so the client only
+        // needs to know that it is listening.
+        if is_listen {
+            return Ok(StageResult::Response(ExecuteResponse::Listened));
+        }
 
         let resp = ExecuteResponse::Subscribing {
             rx,
@@ -248,14 +248,34 @@ impl Coordinator {
         let user = self.active_conns()[active_sink.connection_id()].user();
         let session_type = metrics::session_type_label_value(user);
 
-        self.active_conns
-            .get_mut(active_sink.connection_id())
-            .expect("must exist for active sessions")
-            .drop_sinks
-            .insert(id);
+        // Subscribes that back a `LISTEN ... ON` outlive the transaction that created them, and
+        // are dropped by `UNLISTEN` or when the connection terminates instead.
+        let listen = match &active_sink {
+            ActiveComputeSink::Subscribe(active_subscribe) => active_subscribe.listen.as_ref(),
+            ActiveComputeSink::CopyTo(_) => None,
+        };
+        match listen {
+            Some(listen) => {
+                let conn_id = active_sink.connection_id().clone();
+                self.listen_bindings
+                    .insert((conn_id, listen.channel.clone()), id);
+            }
+            None => {
+                self.active_conns
+                    .get_mut(active_sink.connection_id())
+                    .expect("must exist for active sessions")
+                    .drop_sinks
+                    .insert(id);
+            }
+        }
 
         let ret_fut = match &active_sink {
             ActiveComputeSink::Subscribe(active_subscribe) => {
+                if let Some(durable) = &active_subscribe.durable {
+                    self.active_durable_subscriptions
+                        .insert(durable.name.clone(), id);
+                }
+
                 let update =
                     self.catalog()
                         .state()
@@ -303,6 +323,14 @@ impl Coordinator {
 
             match &sink {
                 ActiveComputeSink::Subscribe(active_subscribe) => {
+                    if let Some(durable) = &active_subscribe.durable {
+                        self.active_durable_subscriptions.remove(&durable.name);
+                    }
+                    if let Some(listen) = &active_subscribe.listen {
+                        self.listen_bindings
+                            .remove(&(active_subscribe.conn_id.clone(), listen.channel.clone()));
+                    }
+
                     let update = self.catalog().state().pack_subscribe_update(
                         id,
                         active_subscribe,
@@ -391,11 +391,15 @@ pub trait TimestampProvider {
             );
             candidate
         } else {
-            coord_bail!(generate_timestamp_not_valid_error_msg(
+            return Err(timestamp_not_valid_error(
+                self.catalog_state(),
+                session,
                 id_bundle,
                 compute_instance,
                 read_holds,
-                candidate
+                when,
+                timeline.as_ref(),
+                candidate,
             ));
         };
         Ok(RawTimestampDetermination {
@@ -577,11 +581,15 @@ pub trait TimestampProvider {
             }
             // If the candidate strictly exceeds the upper bound, we didn't have a viable timestamp.
             if constraints.upper_bound().less_than(&candidate) {
-                coord_bail!(generate_timestamp_not_valid_error_msg(
+                return Err(timestamp_not_valid_error(
+                    self.catalog_state(),
+                    session,
                     id_bundle,
                     compute_instance,
                     read_holds,
-                    candidate
+                    when,
+                    timeline.as_ref(),
+                    candidate,
                 ));
             } else {
                 candidate
@@ -781,12 +789,22 @@ pub trait TimestampProvider {
     }
 }
 
-fn generate_timestamp_not_valid_error_msg(
+/// Returns the error for a query whose `candidate` timestamp is not valid for
+/// all of its inputs.
+///
+/// When the query asked for a specific `AS OF` time that some of its inputs no
+/// longer retain history for, the error names that time and the earliest time
+/// each of those inputs can still be read at.
+fn timestamp_not_valid_error(
+    catalog: &CatalogState,
+    session: &Session,
     id_bundle: &CollectionIdBundle,
     compute_instance: ComputeInstanceId,
     read_holds: &ReadHolds<mz_repr::Timestamp>,
+    when: &QueryWhen,
+    timeline: Option<&Timeline>,
     candidate: mz_repr::Timestamp,
-) -> String {
+) -> AdapterError {
     let mut invalid = Vec::new();
 
     if let Some(compute_ids) = id_bundle.compute_ids.get(&compute_instance) {
@@ -805,10 +823,55 @@ fn generate_timestamp_not_valid_error_msg(
         }
     }
 
-    format!(
+    let as_of = match when.advance_to_timestamp() {
+        Some(expr) if when.constrains_upper() => {
+            Coordinator::evaluate_when(catalog, expr, session).ok()
+        }
+        _ => None,
+    };
+    if let Some(as_of) = as_of {
+        let earliest: Vec<_> = invalid
+            .iter()
+            .filter(|(_, since)| !since.less_equal(&as_of))
+            .filter_map(|(id, since)| {
+                let since = since.as_option()?;
+                let name = match catalog.try_get_entry_by_global_id(id) {
+                    Some(entry) => catalog
+                        .resolve_full_name(entry.name(), Some(session.conn_id()))
+                        .to_string(),
+                    None => id.to_string(),
+                };
+                Some((name, format_in_timeline(*since, timeline)))
+            })
+            .collect();
+        if !earliest.is_empty() {
+            return AdapterError::AsOfOutsideRetainedHistory {
+                as_of: format_in_timeline(as_of, timeline),
+                earliest,
+            };
+        }
+    }
+
+    AdapterError::Unstructured(anyhow::anyhow!(
         "Timestamp ({}) is not valid for all inputs: {:?}",
-        candidate, invalid,
-    )
+        candidate,
+        invalid,
+    ))
+}
+
+/// Formats `ts` for error messages, including its wall-clock time if it is in
+/// the [`Timeline::EpochMilliseconds`] timeline.
+fn format_in_timeline(ts: mz_repr::Timestamp, timeline: Option<&Timeline>) -> String {
+    if let Some(Timeline::EpochMilliseconds) = timeline {
+        let ts_ms: u64 = ts.into();
+        if let Some(dt) = i64::try_from(ts_ms)
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+        {
+            return format!("{} ({})", ts, dt.format("%Y-%m-%d %H:%M:%S%.3f UTC"));
+        }
+    }
+    ts.to_string()
 }
 
 impl Coordinator {
@@ -49,12 +49,35 @@ pub enum AdapterError {
     // We don't allow this until https://github.com/MaterializeInc/database-issues/issues/4824 is
     // resolved because it prevents us from adding columns to system tables.
     AmbiguousSystemColumnReference,
+    /// A query requested an `AS OF` time that some of its inputs no longer
+    /// retain history for.
+    AsOfOutsideRetainedHistory {
+        /// The requested time.
+        as_of: String,
+        /// The names of the offending inputs and the earliest time each of
+        /// them can be read at.
+        earliest: Vec<(String, String)>,
+    },
     /// An error occurred in a catalog operation.
     Catalog(mz_catalog::memory::error::Error),
     /// The cached plan or descriptor changed.
     ChangedPlan(String),
     /// The cursor already exists.
     DuplicateCursor(String),
+    /// Attempted to resume a durable subscription with an `AS OF` clause.
+    DurableSubscriptionAsOf(String),
+    /// Attempted to resume a durable subscription whose inputs could not be held at its
+    /// frontier.
+    DurableSubscriptionBroken(String),
+    /// Attempted to resume a durable subscription that another session is consuming.
+    DurableSubscriptionInUse(String),
+    /// Attempted to resume a durable subscription with a query that reads from different
+    /// collections than the one that created it.
+    DurableSubscriptionMismatch(String),
+    /// Attempted to use a durable subscription owned by another role.
+    DurableSubscriptionOwnership(String),
+    /// Attempted to execute an endpoint whose query cannot be answered on the fast path.
+    EndpointNotFastPath(String),
     /// An error while evaluating an expression.
     Eval(EvalError),
     /// An error occurred while planning the statement.
@@ -124,6 +147,14 @@ pub enum AdapterError {
         relations: Vec<String>,
         names: Vec<String>,
     },
+    /// Attempted to create a replication slot that already exists.
+    ReplicationSlotExists(String),
+    /// Attempted to use a replication slot that another connection is streaming from.
+    ReplicationSlotInUse(String),
+    /// Attempted to use a replication slot owned by another role.
+    ReplicationSlotOwnership(String),
+    /// Attempted to stream a publication from a replication slot bound to another one.
+    ReplicationSlotPublicationMismatch(String),
     /// A query tried to create more resources than is allowed in the system configuration.
     ResourceExhaustion {
         resource_type: String,
@@ -159,6 +190,10 @@ pub enum AdapterError {
     Unauthorized(rbac::UnauthorizedError),
     /// The named cursor does not exist.
     UnknownCursor(String),
+    /// The named durable subscription does not exist.
+    UnknownDurableSubscription(String),
+    /// The named replication slot does not exist.
+    UnknownReplicationSlot(String),
     /// The named role does not exist.
     UnknownLoginRole(String),
     UnknownPreparedStatement(String),
@@ -272,6 +307,14 @@ impl AdapterError {
             AdapterError::AmbiguousSystemColumnReference => {
                 Some("This is a current limitation in Materialize".into())
             },
+            AdapterError::AsOfOutsideRetainedHistory { earliest, .. } => Some(
+                earliest
+                    .iter()
+                    .map(|(name, ts)| {
+                        format!("The earliest time {} can be read at is {}.", name.quoted(), ts)
+                    })
+                    .join("\n"),
+            ),
             AdapterError::Catalog(c) => c.detail(),
             AdapterError::Eval(e) => e.detail(),
             AdapterError::RelationOutsideTimeDomain { relations, names } => Some(format!(
@@ -370,7 +413,24 @@ impl AdapterError {
                 convert all NATURAL JOINs to USING joins."
                     .to_string(),
             ),
+            AdapterError::AsOfOutsideRetainedHistory { .. } => Some(
+                "Query a later time, or retain more history for the inputs with \
+                `ALTER ... SET (RETAIN HISTORY FOR '<duration>')`. \
+                Use SHOW HISTORY RETENTION to see how much history each object retains."
+                    .into(),
+            ),
             AdapterError::Catalog(c) => c.hint(),
+            AdapterError::DurableSubscriptionBroken(name)
+            | AdapterError::DurableSubscriptionMismatch(name) => Some(format!(
+                "Use DROP SUBSCRIPTION {} to discard the subscription's progress.",
+                name.quoted()
+            )),
+            AdapterError::EndpointNotFastPath(_) => Some(
+                "Create an index that the endpoint's query can read from directly.".into(),
+            ),
+            AdapterError::ReplicationSlotPublicationMismatch(_) => {
+                Some("Create another replication slot to stream this publication.".into())
+            }
             AdapterError::Eval(e) => e.hint(),
             AdapterError::InvalidClusterReplicaAz { expected, az: _ } => {
                 Some(if expected.is_empty() {
@@ -442,6 +502,7 @@ impl AdapterError {
             // range bounds
             AdapterError::AbsurdSubscribeBounds { .. } => SqlState::DATA_EXCEPTION,
             AdapterError::AmbiguousSystemColumnReference => SqlState::FEATURE_NOT_SUPPORTED,
+            AdapterError::AsOfOutsideRetainedHistory { .. } => SqlState::DATA_EXCEPTION,
             AdapterError::Catalog(e) => match &e.kind {
                 mz_catalog::memory::error::ErrorKind::VarError(e) => match e {
                     VarError::ConstrainedParameter { .. } => SqlState::INVALID_PARAMETER_VALUE,
@@ -457,6 +518,14 @@ impl AdapterError {
             },
             AdapterError::ChangedPlan(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::DuplicateCursor(_) => SqlState::DUPLICATE_CURSOR,
+            AdapterError::DurableSubscriptionAsOf(_) => SqlState::FEATURE_NOT_SUPPORTED,
+            AdapterError::DurableSubscriptionBroken(_) => {
+                SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE
+            }
+            AdapterError::DurableSubscriptionInUse(_) => SqlState::OBJECT_IN_USE,
+            AdapterError::DurableSubscriptionMismatch(_) => SqlState::FEATURE_NOT_SUPPORTED,
+            AdapterError::DurableSubscriptionOwnership(_) => SqlState::INSUFFICIENT_PRIVILEGE,
+            AdapterError::EndpointNotFastPath(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::Eval(EvalError::CharacterNotValidForEncoding(_)) => {
                 SqlState::PROGRAM_LIMIT_EXCEEDED
             }
@@ -489,6 +558,12 @@ impl AdapterError {
             AdapterError::PlanError(PlanError::ColumnAlreadyExists { .. }) => {
                 SqlState::DUPLICATE_COLUMN
             }
+            AdapterError::PlanError(PlanError::AlterColumnDependentObjectsExist { .. }) => {
+                SqlState::DEPENDENT_OBJECTS_STILL_EXIST
+            }
+            AdapterError::PlanError(PlanError::InvalidColumnTypeChange { .. }) => {
+                SqlState::FEATURE_NOT_SUPPORTED
+            }
             AdapterError::PlanError(PlanError::UnknownParameter(_)) => {
                 SqlState::UNDEFINED_PARAMETER
             }
@@ -508,6 +583,12 @@ impl AdapterError {
             }
             AdapterError::RecursionLimit(_) => SqlState::INTERNAL_ERROR,
             AdapterError::RelationOutsideTimeDomain { .. } => SqlState::INVALID_TRANSACTION_STATE,
+            AdapterError::ReplicationSlotExists(_) => SqlState::DUPLICATE_OBJECT,
+            AdapterError::ReplicationSlotInUse(_) => SqlState::OBJECT_IN_USE,
+            AdapterError::ReplicationSlotOwnership(_) => SqlState::INSUFFICIENT_PRIVILEGE,
+            AdapterError::ReplicationSlotPublicationMismatch(_) => {
+                SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE
+            }
             AdapterError::ResourceExhaustion { .. } => SqlState::INSUFFICIENT_RESOURCES,
             AdapterError::ResultSize(_) => SqlState::OUT_OF_MEMORY,
             AdapterError::SafeModeViolation(_) => SqlState::INTERNAL_ERROR,
@@ -537,6 +618,8 @@ impl AdapterError {
             }
             AdapterError::Unauthorized(_) => SqlState::INSUFFICIENT_PRIVILEGE,
             AdapterError::UnknownCursor(_) => SqlState::INVALID_CURSOR_NAME,
+            AdapterError::UnknownDurableSubscription(_) => SqlState::UNDEFINED_OBJECT,
+            AdapterError::UnknownReplicationSlot(_) => SqlState::UNDEFINED_OBJECT,
             AdapterError::UnknownPreparedStatement(_) => SqlState::UNDEFINED_PSTATEMENT,
             AdapterError::UnknownLoginRole(_) => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
             AdapterError::UnknownClusterReplica { .. } => SqlState::UNDEFINED_OBJECT,
@@ -598,11 +681,47 @@ impl fmt::Display for AdapterError {
                     system objects"
                 )
             }
+            AdapterError::AsOfOutsideRetainedHistory { as_of, .. } => write!(
+                f,
+                "AS OF time {} is outside the retained history of the query's inputs",
+                as_of
+            ),
             AdapterError::ChangedPlan(e) => write!(f, "{}", e),
             AdapterError::Catalog(e) => e.fmt(f),
             AdapterError::DuplicateCursor(name) => {
                 write!(f, "cursor {} already exists", name.quoted())
             }
+            AdapterError::DurableSubscriptionAsOf(name) => write!(
+                f,
+                "cannot specify AS OF when resuming durable subscription {}",
+                name.quoted()
+            ),
+            AdapterError::DurableSubscriptionBroken(name) => write!(
+                f,
+                "durable subscription {} can no longer be resumed because its inputs could not be \
+                 held at its frontier",
+                name.quoted()
+            ),
+            AdapterError::DurableSubscriptionInUse(name) => {
+                write!(
+                    f,
+                    "durable subscription {} is already in use",
+                    name.quoted()
+                )
+            }
+            AdapterError::DurableSubscriptionMismatch(name) => write!(
+                f,
+                "durable subscription {} was created for a query over different relations",
+                name.quoted()
+            ),
+            AdapterError::DurableSubscriptionOwnership(name) => {
+                write!(f, "must be owner of durable subscription {}", name.quoted())
+            }
+            AdapterError::EndpointNotFastPath(name) => write!(
+                f,
+                "endpoint {} cannot be executed without building a dataflow",
+                name.quoted()
+            ),
             AdapterError::Eval(e) => e.fmt(f),
             AdapterError::Explain(e) => e.fmt(f),
             AdapterError::IdExhaustionError => f.write_str("ID allocator exhausted all valid IDs"),
@@ -687,6 +806,20 @@ impl fmt::Display for AdapterError {
                      See https://materialize.com/docs/sql/begin/#same-timedomain-error",
                 )
             }
+            AdapterError::ReplicationSlotExists(name) => {
+                write!(f, "replication slot {} already exists", name.quoted())
+            }
+            AdapterError::ReplicationSlotInUse(name) => {
+                write!(f, "replication slot {} is active", name.quoted())
+            }
+            AdapterError::ReplicationSlotOwnership(name) => {
+                write!(f, "must be owner of replication slot {}", name.quoted())
+            }
+            AdapterError::ReplicationSlotPublicationMismatch(name) => write!(
+                f,
+                "replication slot {} streams a different publication",
+                name.quoted()
+            ),
             AdapterError::ResourceExhaustion {
                 resource_type,
                 limit_name,
@@ -724,6 +857,12 @@ impl fmt::Display for AdapterError {
             AdapterError::UnknownCursor(name) => {
                 write!(f, "cursor {} does not exist", name.quoted())
             }
+            AdapterError::UnknownDurableSubscription(name) => {
+                write!(f, "durable subscription {} does not exist", name.quoted())
+            }
+            AdapterError::UnknownReplicationSlot(name) => {
+                write!(f, "replication slot {} does not exist", name.quoted())
+            }
             AdapterError::UnknownLoginRole(name) => {
                 write!(f, "role {} does not exist", name.quoted())
             }
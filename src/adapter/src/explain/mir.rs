@@ -135,7 +135,12 @@ impl<'a> Explainable<'a, DataflowDescription<OptimizedMirRelationExpr>> {
             .iter_mut()
             .map(|(id, (source_desc, _, _upper))| {
                 let op = source_desc.arguments.operators.as_ref();
-                ExplainSource::new(*id, op, context.config.filter_pushdown)
+                ExplainSource::new(
+                    *id,
+                    op,
+                    context.config.filter_pushdown,
+                    context.features.persist_projection_pushdown,
+                )
             })
             .collect::<Vec<_>>();
 
@@ -45,6 +45,7 @@ pub struct Metrics {
     pub session_startup_table_writes_seconds: HistogramVec,
     pub parse_seconds: HistogramVec,
     pub pgwire_message_processing_seconds: HistogramVec,
+    pub plan_cache_lookups: IntCounterVec,
 }
 
 impl Metrics {
@@ -192,7 +193,12 @@ impl Metrics {
                 help: "The time it takes to process each of the pgwire message types, measured in the Adapter frontend",
                 var_labels: ["message_type"],
                 buckets: histogram_seconds_buckets(0.000_128, 128.0),
-            ))
+            )),
+            plan_cache_lookups: registry.register(metric!(
+                name: "mz_plan_cache_lookups_total",
+                help: "The total number of plan cache lookups, by whether they found a cached plan.",
+                var_labels: ["result"],
+            )),
         }
     }
 
@@ -50,6 +50,9 @@ pub enum AdapterNotice {
     ClusterDoesNotExist {
         name: String,
     },
+    DurableSubscriptionDoesNotExist {
+        name: String,
+    },
     DefaultClusterDoesNotExist {
         name: String,
         kind: &'static str,
@@ -159,6 +162,7 @@ impl AdapterNotice {
             AdapterNotice::ObjectAlreadyExists { .. } => Severity::Notice,
             AdapterNotice::DatabaseDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::ClusterDoesNotExist { .. } => Severity::Notice,
+            AdapterNotice::DurableSubscriptionDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::DefaultClusterDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::NoResolvableSearchPathSchema { .. } => Severity::Notice,
             AdapterNotice::ExistingTransactionInProgress => Severity::Warning,
@@ -190,6 +194,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
+                PlanNotice::ColumnDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
             },
             AdapterNotice::UnknownSessionDatabase(_) => Severity::Notice,
@@ -262,6 +267,7 @@ impl AdapterNotice {
             AdapterNotice::ObjectAlreadyExists { .. } => SqlState::DUPLICATE_OBJECT,
             AdapterNotice::DatabaseDoesNotExist { .. } => SqlState::from_code("MZ006"),
             AdapterNotice::ClusterDoesNotExist { .. } => SqlState::from_code("MZ007"),
+            AdapterNotice::DurableSubscriptionDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
             AdapterNotice::NoResolvableSearchPathSchema { .. } => SqlState::from_code("MZ008"),
             AdapterNotice::ExistingTransactionInProgress => SqlState::ACTIVE_SQL_TRANSACTION,
             AdapterNotice::ExplicitTransactionControlInImplicitTransaction => {
@@ -291,6 +297,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
+                PlanNotice::ColumnDoesNotExist { .. } => SqlState::UNDEFINED_COLUMN,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
             },
             AdapterNotice::UnknownSessionDatabase(_) => SqlState::from_code("MZ004"),
@@ -332,6 +339,11 @@ impl fmt::Display for AdapterNotice {
             AdapterNotice::ClusterDoesNotExist { name } => {
                 write!(f, "cluster {} does not exist", name.quoted())
             }
+            AdapterNotice::DurableSubscriptionDoesNotExist { name } => write!(
+                f,
+                "durable subscription {} does not exist, skipping",
+                name.quoted()
+            ),
             AdapterNotice::DefaultClusterDoesNotExist { kind, name, .. } => {
                 write!(f, "{kind} default cluster {} does not exist", name.quoted())
             }
@@ -393,6 +393,7 @@ impl<'a> DataflowBuilder<'a> {
                     self.monotonic_object_inner(*on, memo, features)
                 }
                 CatalogItem::Secret(_)
+                | CatalogItem::Endpoint(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Connection(_)
                 | CatalogItem::Table(_)
@@ -666,6 +667,10 @@ fn eval_unmaterializable_func(
         UnmaterializableFunc::MzVersionNum => {
             pack(Datum::Int32(state.config().build_info.version_num()))
         }
+        // Parameters are bound before optimization, see `LocalMirPlan::bind_parameters`.
+        UnmaterializableFunc::Parameter { .. } => {
+            Err(OptimizerError::Internal(format!("unbound parameter {f}")))
+        }
         UnmaterializableFunc::PgBackendPid => pack(Datum::Int32(i32::reinterpret_cast(
             session.conn_id().unhandled(),
         ))),
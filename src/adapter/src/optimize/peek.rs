@@ -16,12 +16,14 @@ use std::time::{Duration, Instant};
 use mz_compute_types::ComputeInstanceId;
 use mz_compute_types::dataflows::IndexDesc;
 use mz_compute_types::plan::Plan;
-use mz_expr::{MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr, RowSetFinishing};
+use mz_expr::{
+    MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr, RowSetFinishing, UnmaterializableFunc,
+};
 use mz_ore::soft_assert_or_log;
 use mz_repr::explain::trace_plan;
 use mz_repr::{GlobalId, RelationType, Timestamp};
 use mz_sql::optimizer_metrics::OptimizerMetrics;
-use mz_sql::plan::HirRelationExpr;
+use mz_sql::plan::{HirRelationExpr, Params};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::normalize_lets::normalize_lets;
@@ -130,11 +132,12 @@ impl Debug for Optimizer {
 
 /// Marker type for [`LocalMirPlan`] representing an optimization result without
 /// context.
+#[derive(Clone, Debug)]
 pub struct Unresolved;
 
 /// The (sealed intermediate) result after HIR ⇒ MIR lowering and decorrelation
 /// and local MIR optimization.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct LocalMirPlan<T = Unresolved> {
     expr: MirRelationExpr,
     df_meta: DataflowMetainfo,
@@ -157,7 +160,7 @@ pub struct Resolved<'s> {
 /// 4. optimizing the resulting `DataflowDescription` with `MIR` plans.
 /// 5. MIR ⇒ LIR lowering, and
 /// 6. optimizing the resulting `DataflowDescription` with `LIR` plans.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GlobalLirPlan {
     peek_plan: PeekPlan,
     df_meta: DataflowMetainfo,
@@ -199,6 +202,29 @@ impl Optimize<HirRelationExpr> for Optimizer {
 }
 
 impl LocalMirPlan<Unresolved> {
+    /// Replaces the parameter placeholders in a plan optimized from a
+    /// `SelectPlan::generic_source` with the datums bound to them by `params`.
+    pub fn bind_parameters(&mut self, params: &Params) -> Result<(), OptimizerError> {
+        let datums = params.datums.unpack();
+        self.expr.try_visit_scalars_mut(&mut |s| {
+            s.try_visit_mut_post(&mut |e| {
+                if let MirScalarExpr::CallUnmaterializable(UnmaterializableFunc::Parameter {
+                    position,
+                    typ,
+                }) = e
+                {
+                    let Some(datum) = datums.get(*position - 1) else {
+                        return Err(OptimizerError::Internal(format!(
+                            "unbound parameter ${position}"
+                        )));
+                    };
+                    *e = MirScalarExpr::literal_ok(*datum, typ.clone());
+                }
+                Ok(())
+            })
+        })
+    }
+
     /// Produces the [`LocalMirPlan`] with [`Resolved`] contextual information
     /// required for the next stage.
     pub fn resolve(
@@ -453,4 +479,9 @@ impl GlobalLirPlan {
     pub fn unapply(self) -> (PeekPlan, DataflowMetainfo, RelationType) {
         (self.peek_plan, self.df_meta, self.typ)
     }
+
+    /// Returns whether the peek can be answered without building a dataflow.
+    pub fn is_fast_path(&self) -> bool {
+        matches!(self.peek_plan, PeekPlan::FastPath(_))
+    }
 }
@@ -101,6 +101,8 @@ where
     vars: SessionVars,
     notices_tx: mpsc::UnboundedSender<AdapterNotice>,
     notices_rx: mpsc::UnboundedReceiver<AdapterNotice>,
+    notifications_tx: mpsc::UnboundedSender<Notification>,
+    notifications_rx: mpsc::UnboundedReceiver<Notification>,
     next_transaction_id: TransactionId,
     secret_key: u32,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
@@ -185,6 +187,18 @@ impl SessionMetadata for SessionMeta {
     }
 }
 
+/// An asynchronous notification delivered to a session that is listening on a channel, either
+/// because some session executed `NOTIFY` or because a relation bound to the channel changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// The process ID of the notifying session.
+    pub pid: u32,
+    /// The channel on which the notification was raised.
+    pub channel: String,
+    /// The notification payload.
+    pub payload: String,
+}
+
 /// Configures a new [`Session`].
 #[derive(Debug, Clone)]
 pub struct SessionConfig {
@@ -312,6 +326,7 @@ impl<T: TimestampManipulation> Session<T> {
         metrics: SessionMetrics,
     ) -> Session<T> {
         let (notices_tx, notices_rx) = mpsc::unbounded_channel();
+        let (notifications_tx, notifications_rx) = mpsc::unbounded_channel();
         let default_cluster = INTERNAL_USER_NAME_TO_DEFAULT_CLUSTER.get(&user);
         let user = User {
             name: user,
@@ -338,6 +353,8 @@ impl<T: TimestampManipulation> Session<T> {
             vars,
             notices_tx,
             notices_rx,
+            notifications_tx,
+            notifications_rx,
             next_transaction_id: 0,
             secret_key: rand::thread_rng().r#gen(),
             external_metadata_rx,
@@ -541,6 +558,31 @@ impl<T: TimestampManipulation> Session<T> {
         notices
     }
 
+    /// Returns a channel on which to send asynchronous notifications to the session.
+    pub fn retain_notification_transmitter(&self) -> UnboundedSender<Notification> {
+        self.notifications_tx.clone()
+    }
+
+    /// Awaits a possible asynchronous notification.
+    ///
+    /// This method is cancel safe.
+    pub async fn recv_notification(&mut self) -> Notification {
+        // This method is cancel safe because recv is cancel safe.
+        self.notifications_rx
+            .recv()
+            .await
+            .expect("Session also holds a sender, so recv won't ever return None")
+    }
+
+    /// Returns a draining iterator over the asynchronous notifications attached to the session.
+    pub fn drain_notifications(&mut self) -> Vec<Notification> {
+        let mut notifications = Vec::new();
+        while let Ok(notification) = self.notifications_rx.try_recv() {
+            notifications.push(notification);
+        }
+        notifications
+    }
+
     /// Returns Some if the notice should be reported, otherwise None.
     fn notice_filter(&self, notice: AdapterNotice) -> Option<AdapterNotice> {
         // Filter out low threshold severity.
@@ -211,6 +211,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedRole
             | ExecuteResponse::CreatedCluster
             | ExecuteResponse::CreatedClusterReplica
+            | ExecuteResponse::CreatedEndpoint
             | ExecuteResponse::CreatedIndex
             | ExecuteResponse::CreatedIntrospectionSubscribe
             | ExecuteResponse::CreatedSecret
@@ -227,13 +228,18 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::DeclaredCursor
             | ExecuteResponse::Deleted(_)
             | ExecuteResponse::DiscardedTemp
+            | ExecuteResponse::DiscardedPlans
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedSubscription
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(_)
+            | ExecuteResponse::Listened
+            | ExecuteResponse::Notified
+            | ExecuteResponse::Unlistened
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
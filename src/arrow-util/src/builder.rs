@@ -24,7 +24,7 @@ use arrow::record_batch::RecordBatch;
 use chrono::Timelike;
 use mz_ore::cast::CastFrom;
 use mz_repr::adt::jsonb::JsonbRef;
-use mz_repr::{Datum, RelationDesc, Row, ScalarType};
+use mz_repr::{Datum, RelationDesc, RowRef, ScalarType};
 
 pub struct ArrowBuilder {
     columns: Vec<ArrowColumn>,
@@ -122,7 +122,7 @@ impl ArrowBuilder {
 
     /// Appends a row to the builder.
     /// Errors if the row contains an unimplemented or out-of-range value.
-    pub fn add_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
+    pub fn add_row(&mut self, row: &RowRef) -> Result<(), anyhow::Error> {
         for (col, datum) in self.columns.iter_mut().zip(row.iter()) {
             col.append_datum(datum)?;
         }
@@ -83,6 +83,7 @@ pub enum EventType {
     Grant,
     Revoke,
     Comment,
+    Login,
 }
 
 impl EventType {
@@ -94,6 +95,7 @@ impl EventType {
             EventType::Grant => "Granted",
             EventType::Revoke => "Revoked",
             EventType::Comment => "Comment",
+            EventType::Login => "Logged In",
         }
     }
 }
@@ -110,6 +112,7 @@ pub enum ObjectType {
     Connection,
     ContinualTask,
     Database,
+    Endpoint,
     Func,
     Index,
     MaterializedView,
@@ -133,6 +136,7 @@ impl ObjectType {
             ObjectType::Connection => "Connection",
             ObjectType::ContinualTask => "Continual Task",
             ObjectType::Database => "Database",
+            ObjectType::Endpoint => "Endpoint",
             ObjectType::Func => "Function",
             ObjectType::Index => "Index",
             ObjectType::MaterializedView => "Materialized View",
@@ -192,6 +196,7 @@ pub enum EventDetails {
     SetV1(SetV1),
     ResetAllV1,
     RotateKeysV1(RotateKeysV1),
+    CertificateLoginV1(CertificateLoginV1),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
@@ -206,6 +211,15 @@ pub struct RotateKeysV1 {
     pub name: String,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct CertificateLoginV1 {
+    pub subject: String,
+    pub identity: Option<String>,
+    pub rule: Option<String>,
+    pub role_name: String,
+    pub accepted: bool,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct IdFullNameV1 {
     pub id: String,
@@ -620,6 +634,7 @@ impl EventDetails {
             EventDetails::SetV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::ResetAllV1 => serde_json::Value::Null,
             EventDetails::RotateKeysV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::CertificateLoginV1(v) => serde_json::to_value(v).expect("must serialize"),
         }
     }
 }
@@ -12,6 +12,7 @@
 
 use std::fmt::Display;
 use std::num::NonZeroU32;
+use std::sync::LazyLock;
 
 use base64::prelude::*;
 
@@ -26,6 +27,17 @@ const DEFAULT_SALT_SIZE: usize = 32;
 
 const SHA256_OUTPUT_LEN: usize = 32;
 
+/// The number of random bytes the server contributes to a SCRAM nonce.
+const SERVER_NONCE_SIZE: usize = 18;
+
+/// A per-process secret mixed into mock SCRAM salts, so that the salt handed
+/// out for a nonexistent role is stable but not predictable.
+static MOCK_NONCE: LazyLock<[u8; SHA256_OUTPUT_LEN]> = LazyLock::new(|| {
+    let mut nonce = [0u8; SHA256_OUTPUT_LEN];
+    openssl::rand::rand_bytes(&mut nonce).expect("failed to generate mock nonce");
+    nonce
+});
+
 /// The options for hashing a password
 pub struct HashOpts {
     /// The number of iterations to use for PBKDF2
@@ -36,6 +48,14 @@ pub struct HashOpts {
     pub salt: [u8; DEFAULT_SALT_SIZE],
 }
 
+impl HashOpts {
+    /// Returns the salt base64-encoded, as it appears in a SCRAM
+    /// server-first-message.
+    pub fn encoded_salt(&self) -> String {
+        BASE64_STANDARD.encode(self.salt)
+    }
+}
+
 pub struct PasswordHash {
     /// The salt used for hashing
     pub salt: [u8; DEFAULT_SALT_SIZE],
@@ -115,7 +135,7 @@ pub fn scram256_verify(password: &Password, hashed_password: &str) -> Result<(),
 }
 
 /// Parses a SCRAM-SHA-256 hash and returns the options used to create it.
-fn scram256_parse_opts(hashed_password: &str) -> Result<HashOpts, VerifyError> {
+pub fn scram256_parse_opts(hashed_password: &str) -> Result<HashOpts, VerifyError> {
     let parts: Vec<&str> = hashed_password.split('$').collect();
     if parts.len() != 3 {
         return Err(VerifyError::MalformedHash);
@@ -149,6 +169,101 @@ fn scram256_parse_opts(hashed_password: &str) -> Result<HashOpts, VerifyError> {
     })
 }
 
+/// Parses the client and server keys out of a SCRAM-SHA-256 hash.
+fn scram256_parse_keys(
+    hashed_password: &str,
+) -> Result<([u8; SHA256_OUTPUT_LEN], [u8; SHA256_OUTPUT_LEN]), VerifyError> {
+    let parts: Vec<&str> = hashed_password.split('$').collect();
+    if parts.len() != 3 || parts[0] != "SCRAM-SHA-256" {
+        return Err(VerifyError::MalformedHash);
+    }
+    let Some((client_key, server_key)) = parts[2].split_once(':') else {
+        return Err(VerifyError::MalformedHash);
+    };
+    let decode = |key: &str| -> Result<[u8; SHA256_OUTPUT_LEN], VerifyError> {
+        BASE64_STANDARD
+            .decode(key)
+            .map_err(|_| VerifyError::MalformedHash)?
+            .try_into()
+            .map_err(|_| VerifyError::MalformedHash)
+    };
+    Ok((decode(client_key)?, decode(server_key)?))
+}
+
+/// Returns the salt and iteration count to present to a client that is
+/// attempting SCRAM authentication as a role that does not exist or has no
+/// password.
+///
+/// The salt is derived from the role name so that repeated attempts see the
+/// same challenge, which keeps the response indistinguishable from that of a
+/// real role.
+pub fn mock_sasl_challenge(role_name: &str) -> HashOpts {
+    let mut hasher = openssl::sha::Sha256::new();
+    hasher.update(role_name.as_bytes());
+    hasher.update(&*MOCK_NONCE);
+    HashOpts {
+        iterations: DEFAULT_ITERATIONS,
+        salt: hasher.finish(),
+    }
+}
+
+/// Extends the nonce supplied by a client in a SCRAM client-first-message with
+/// a random server nonce, as required by RFC 5802.
+pub fn generate_nonce(client_nonce: &str) -> Result<String, HashError> {
+    let mut nonce = [0u8; SERVER_NONCE_SIZE];
+    openssl::rand::rand_bytes(&mut nonce).map_err(HashError::Openssl)?;
+    Ok(format!("{}{}", client_nonce, BASE64_STANDARD.encode(nonce)))
+}
+
+/// Verifies the proof a client sent in a SCRAM client-final-message against a
+/// SCRAM-SHA-256 hash.
+///
+/// `proof` is the base64-encoded `p` attribute of the client-final-message and
+/// `auth_message` is the SCRAM AuthMessage for the exchange. On success,
+/// returns the base64-encoded server signature that the server must send back
+/// to the client.
+pub fn sasl_verify(
+    hashed_password: &str,
+    proof: &str,
+    auth_message: &str,
+) -> Result<String, VerifyError> {
+    let (client_key, server_key) = scram256_parse_keys(hashed_password)?;
+    let stored_key = openssl::sha::sha256(&client_key);
+
+    let proof = BASE64_STANDARD
+        .decode(proof)
+        .map_err(|_| VerifyError::InvalidPassword)?;
+    if proof.len() != SHA256_OUTPUT_LEN {
+        return Err(VerifyError::InvalidPassword);
+    }
+
+    // The proof is the client key XOR'd with the client signature, so XOR'ing
+    // the signature back out recovers the key the client used.
+    let client_signature =
+        hmac_sha256(&stored_key, auth_message.as_bytes()).map_err(VerifyError::Hash)?;
+    let recovered_client_key: Vec<u8> = proof
+        .iter()
+        .zip(client_signature.iter())
+        .map(|(p, s)| p ^ s)
+        .collect();
+    if !openssl::memcmp::eq(&openssl::sha::sha256(&recovered_client_key), &stored_key) {
+        return Err(VerifyError::InvalidPassword);
+    }
+
+    let server_signature =
+        hmac_sha256(&server_key, auth_message.as_bytes()).map_err(VerifyError::Hash)?;
+    Ok(BASE64_STANDARD.encode(server_signature))
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>, HashError> {
+    let signing_key = openssl::pkey::PKey::hmac(key).map_err(HashError::Openssl)?;
+    let mut signer =
+        openssl::sign::Signer::new(openssl::hash::MessageDigest::sha256(), &signing_key)
+            .map_err(HashError::Openssl)?;
+    signer.update(data).map_err(HashError::Openssl)?;
+    signer.sign_to_vec().map_err(HashError::Openssl)
+}
+
 /// The SCRAM-SHA-256 hash
 struct ScramSha256Hash {
     /// The number of iterations used for hashing
@@ -235,6 +350,50 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    fn test_sasl_verify() {
+        let password = "password".into();
+        let scram_hash = scram256_hash(&password).expect("Failed to hash password");
+        let opts = scram256_parse_opts(&scram_hash).expect("Failed to parse hash");
+        let auth_message = "n=,r=abc,r=abcdef,s=c2FsdA==,i=4096,c=biws,r=abcdef";
+
+        // Compute the proof the way a client would.
+        let salted_password = hash_password_with_opts(&opts, &password)
+            .expect("Failed to hash password")
+            .hash;
+        let client_key = hmac_sha256(&salted_password, b"Client Key").unwrap();
+        let stored_key = openssl::sha::sha256(&client_key);
+        let client_signature = hmac_sha256(&stored_key, auth_message.as_bytes()).unwrap();
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(k, s)| k ^ s)
+            .collect();
+        let proof = BASE64_STANDARD.encode(proof);
+
+        let server_key = hmac_sha256(&salted_password, b"Server Key").unwrap();
+        let expected_verifier =
+            BASE64_STANDARD.encode(hmac_sha256(&server_key, auth_message.as_bytes()).unwrap());
+
+        let verifier = sasl_verify(&scram_hash, &proof, auth_message);
+        assert_eq!(verifier.unwrap(), expected_verifier);
+
+        let res = sasl_verify(&scram_hash, &proof, "n=,r=abc,r=abcdef,s=c2FsdA==,i=1");
+        assert!(res.is_err());
+        let res = sasl_verify(&scram_hash, "bm90IGEgcHJvb2Y=", auth_message);
+        assert!(res.is_err());
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    fn test_mock_sasl_challenge() {
+        let a = mock_sasl_challenge("a");
+        assert_eq!(a.salt, mock_sasl_challenge("a").salt);
+        assert_ne!(a.salt, mock_sasl_challenge("b").salt);
+        assert_eq!(a.iterations, DEFAULT_ITERATIONS);
+    }
+
     #[mz_ore::test]
     fn test_scram256_parse_opts() {
         let salt = "9bkIQQjQ7f1OwPsXZGC/YfIkbZsOMDXK0cxxvPBaSfM=";
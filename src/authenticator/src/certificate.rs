@@ -0,0 +1,484 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client certificate (mTLS) authentication.
+//!
+//! The TLS layer verifies that a client certificate chains to the configured
+//! client CA. This module then maps identities from the certificate (its
+//! Common Name or Subject Alternative Names) to Materialize roles via an
+//! ordered list of [`CertificateMappingRule`]s. Certificates with no matching
+//! rule are rejected. Every decision is recorded in the audit log.
+
+use std::fmt;
+use std::sync::Arc;
+
+use mz_adapter::{AdapterError, AuthResponse, Client as AdapterClient};
+use openssl::nid::Nid;
+use openssl::x509::X509Ref;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The part of a client certificate that a mapping rule matches against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CertificateField {
+    /// The Common Name (CN) of the certificate's subject.
+    CommonName,
+    /// A DNS name Subject Alternative Name.
+    DnsSan,
+    /// An email address Subject Alternative Name.
+    EmailSan,
+    /// A URI Subject Alternative Name.
+    UriSan,
+}
+
+impl fmt::Display for CertificateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CertificateField::CommonName => "CN",
+            CertificateField::DnsSan => "DNS",
+            CertificateField::EmailSan => "email",
+            CertificateField::UriSan => "URI",
+        })
+    }
+}
+
+/// The configuration of a single certificate mapping rule, as read from the
+/// rules file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificateMappingRuleConfig {
+    /// A name for the rule, recorded in the audit log when the rule matches.
+    pub name: String,
+    /// The certificate field to match against.
+    pub field: CertificateField,
+    /// A regular expression that must match the entire field value.
+    pub pattern: String,
+    /// The role to map matching identities to. May refer to capture groups in
+    /// `pattern`, e.g. `${1}` or `${name}`.
+    pub role: String,
+}
+
+/// A compiled certificate mapping rule.
+#[derive(Debug, Clone)]
+pub struct CertificateMappingRule {
+    name: String,
+    field: CertificateField,
+    pattern: Regex,
+    role: String,
+}
+
+impl CertificateMappingRule {
+    /// Returns the role that `value` maps to, if it matches this rule.
+    fn map(&self, value: &str) -> Option<String> {
+        let captures = self.pattern.captures(value)?;
+        let mut role = String::new();
+        captures.expand(&self.role, &mut role);
+        Some(role)
+    }
+}
+
+/// An error in the configuration of the certificate mapping rules.
+#[derive(Debug, Error)]
+pub enum CertificateRuleError {
+    #[error("certificate mapping rule {name:?} has an invalid pattern: {source}")]
+    InvalidPattern { name: String, source: regex::Error },
+    #[error("certificate mapping rule {0:?} is defined more than once")]
+    DuplicateName(String),
+}
+
+/// An ordered list of certificate mapping rules.
+#[derive(Debug, Clone, Default)]
+pub struct CertificateMappingRules {
+    rules: Vec<CertificateMappingRule>,
+}
+
+impl CertificateMappingRules {
+    /// Compiles the provided rule configurations.
+    pub fn new(configs: Vec<CertificateMappingRuleConfig>) -> Result<Self, CertificateRuleError> {
+        let mut rules: Vec<CertificateMappingRule> = Vec::with_capacity(configs.len());
+        for config in configs {
+            if rules.iter().any(|rule| rule.name == config.name) {
+                return Err(CertificateRuleError::DuplicateName(config.name));
+            }
+            // Patterns must match the entire value, so that e.g. a rule for
+            // `foo` does not also admit `foo.attacker.com`.
+            let pattern = match Regex::new(&format!("^(?:{})$", config.pattern)) {
+                Ok(pattern) => pattern,
+                Err(source) => {
+                    return Err(CertificateRuleError::InvalidPattern {
+                        name: config.name,
+                        source,
+                    });
+                }
+            };
+            rules.push(CertificateMappingRule {
+                name: config.name,
+                field: config.field,
+                pattern,
+                role: config.role,
+            });
+        }
+        Ok(CertificateMappingRules { rules })
+    }
+
+    /// Maps `identity` to a role.
+    ///
+    /// If `requested_role` is provided, returns the first mapping to that
+    /// role. Otherwise returns the first mapping to any role. Rules are tried
+    /// in order.
+    pub fn map(
+        &self,
+        identity: &CertificateIdentity,
+        requested_role: Option<&str>,
+    ) -> Option<CertificateMapping> {
+        for rule in &self.rules {
+            for value in identity.values(rule.field) {
+                let Some(role) = rule.map(value) else {
+                    continue;
+                };
+                if requested_role.is_none_or(|requested| requested == role) {
+                    return Some(CertificateMapping {
+                        identity: format!("{}={}", rule.field, value),
+                        rule: rule.name.clone(),
+                        role,
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The identities presented by a client certificate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CertificateIdentity {
+    /// The certificate's subject, e.g. `CN=alice,O=Example`.
+    pub subject: String,
+    /// The Common Names of the certificate's subject.
+    pub common_names: Vec<String>,
+    /// The DNS name Subject Alternative Names.
+    pub dns_sans: Vec<String>,
+    /// The email address Subject Alternative Names.
+    pub email_sans: Vec<String>,
+    /// The URI Subject Alternative Names.
+    pub uri_sans: Vec<String>,
+}
+
+impl CertificateIdentity {
+    /// Extracts the identities from a verified certificate.
+    ///
+    /// Entries that are not valid UTF-8 are ignored.
+    pub fn from_certificate(cert: &X509Ref) -> Self {
+        let subject = cert
+            .subject_name()
+            .entries()
+            .filter_map(|entry| {
+                let key = entry.object().nid().short_name().ok()?;
+                let value = entry.data().as_utf8().ok()?;
+                Some(format!("{key}={value}"))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let common_names = cert
+            .subject_name()
+            .entries_by_nid(Nid::COMMONNAME)
+            .filter_map(|entry| entry.data().as_utf8().ok())
+            .map(|cn| cn.to_string())
+            .collect();
+        let mut identity = CertificateIdentity {
+            subject,
+            common_names,
+            ..Default::default()
+        };
+        for name in cert.subject_alt_names().into_iter().flatten() {
+            if let Some(dns) = name.dnsname() {
+                identity.dns_sans.push(dns.to_string());
+            } else if let Some(email) = name.email() {
+                identity.email_sans.push(email.to_string());
+            } else if let Some(uri) = name.uri() {
+                identity.uri_sans.push(uri.to_string());
+            }
+        }
+        identity
+    }
+
+    fn values(&self, field: CertificateField) -> impl Iterator<Item = &str> {
+        let values = match field {
+            CertificateField::CommonName => &self.common_names,
+            CertificateField::DnsSan => &self.dns_sans,
+            CertificateField::EmailSan => &self.email_sans,
+            CertificateField::UriSan => &self.uri_sans,
+        };
+        values.iter().map(|value| value.as_str())
+    }
+}
+
+/// The result of mapping a certificate identity to a role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateMapping {
+    /// The identity that matched, e.g. `CN=alice`.
+    pub identity: String,
+    /// The name of the rule that matched.
+    pub rule: String,
+    /// The role the identity maps to.
+    pub role: String,
+}
+
+/// An error authenticating a client certificate.
+#[derive(Debug, Error)]
+pub enum CertificateError {
+    #[error("client certificate required")]
+    MissingCertificate,
+    #[error("no certificate mapping rule matches the client certificate")]
+    NoMatchingRule,
+    #[error(transparent)]
+    Adapter(#[from] AdapterError),
+}
+
+/// The result of a successful certificate login.
+#[derive(Debug)]
+pub struct CertificateLogin {
+    /// The role that the client logged in as.
+    pub role_name: String,
+    /// The result of authenticating the role.
+    pub auth_resp: AuthResponse,
+}
+
+/// Authenticates clients by their TLS client certificate.
+#[derive(Debug, Clone)]
+pub struct CertificateAuthenticator {
+    adapter_client: AdapterClient,
+    rules: Arc<CertificateMappingRules>,
+}
+
+impl CertificateAuthenticator {
+    /// Creates a new certificate authenticator.
+    pub fn new(adapter_client: AdapterClient, rules: CertificateMappingRules) -> Self {
+        CertificateAuthenticator {
+            adapter_client,
+            rules: Arc::new(rules),
+        }
+    }
+
+    /// Authenticates a client that presented the verified certificate `cert`.
+    ///
+    /// If `requested_role` is `None`, the client is logged in as the role
+    /// that the first matching rule maps the certificate to.
+    pub async fn authenticate(
+        &self,
+        cert: Option<&X509Ref>,
+        requested_role: Option<&str>,
+    ) -> Result<CertificateLogin, CertificateError> {
+        let Some(cert) = cert else {
+            return Err(CertificateError::MissingCertificate);
+        };
+        let identity = CertificateIdentity::from_certificate(cert);
+        let mapping = self.rules.map(&identity, requested_role);
+        let role_name = match (&mapping, requested_role) {
+            (Some(mapping), _) => mapping.role.clone(),
+            (None, Some(requested_role)) => requested_role.to_string(),
+            (None, None) => return Err(CertificateError::NoMatchingRule),
+        };
+        // The adapter records the decision in the audit log, including when no
+        // rule matched.
+        let auth_resp = self
+            .adapter_client
+            .authenticate_certificate(
+                &role_name,
+                &identity.subject,
+                mapping.as_ref().map(|mapping| mapping.identity.as_str()),
+                mapping.as_ref().map(|mapping| mapping.rule.as_str()),
+            )
+            .await?;
+        Ok(CertificateLogin {
+            role_name,
+            auth_resp,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openssl::asn1::Asn1Time;
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::PKey;
+    use openssl::rsa::Rsa;
+    use openssl::x509::extension::SubjectAlternativeName;
+    use openssl::x509::{X509, X509NameBuilder};
+
+    use super::*;
+
+    fn rules(rules: &[(&str, CertificateField, &str, &str)]) -> CertificateMappingRules {
+        CertificateMappingRules::new(
+            rules
+                .iter()
+                .map(
+                    |(name, field, pattern, role)| CertificateMappingRuleConfig {
+                        name: name.to_string(),
+                        field: *field,
+                        pattern: pattern.to_string(),
+                        role: role.to_string(),
+                    },
+                )
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_map() {
+        let rules = rules(&[
+            (
+                "services",
+                CertificateField::CommonName,
+                r"(?<svc>[a-z]+)\.svc\.example\.com",
+                "svc_${svc}",
+            ),
+            (
+                "ops",
+                CertificateField::EmailSan,
+                r".+@ops\.example\.com",
+                "ops",
+            ),
+            (
+                "dns",
+                CertificateField::DnsSan,
+                r"([a-z]+)\.example\.com",
+                "${1}",
+            ),
+        ]);
+        let identity = CertificateIdentity {
+            subject: "CN=billing.svc.example.com".into(),
+            common_names: vec!["billing.svc.example.com".into()],
+            dns_sans: vec!["billing.example.com".into()],
+            email_sans: vec!["oncall@ops.example.com".into()],
+            uri_sans: vec![],
+        };
+
+        // The first matching rule wins when no role is requested.
+        assert_eq!(
+            rules.map(&identity, None),
+            Some(CertificateMapping {
+                identity: "CN=billing.svc.example.com".into(),
+                rule: "services".into(),
+                role: "svc_billing".into(),
+            })
+        );
+        // Otherwise the first rule that maps to the requested role wins.
+        assert_eq!(
+            rules.map(&identity, Some("ops")),
+            Some(CertificateMapping {
+                identity: "email=oncall@ops.example.com".into(),
+                rule: "ops".into(),
+                role: "ops".into(),
+            })
+        );
+        assert_eq!(
+            rules.map(&identity, Some("billing")).map(|m| m.rule),
+            Some("dns".into())
+        );
+        assert_eq!(rules.map(&identity, Some("mz_system")), None);
+
+        // Patterns are anchored.
+        let identity = CertificateIdentity {
+            common_names: vec!["billing.svc.example.com.attacker.com".into()],
+            email_sans: vec!["oncall@ops.example.com.attacker.com".into()],
+            ..Default::default()
+        };
+        assert_eq!(rules.map(&identity, None), None);
+
+        // Unknown identities are not mapped.
+        assert_eq!(
+            rules.map(&CertificateIdentity::default(), Some("svc_billing")),
+            None
+        );
+        assert_eq!(
+            CertificateMappingRules::default().map(&identity, None),
+            None
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_invalid_rules() {
+        let config = |name: &str, pattern: &str| CertificateMappingRuleConfig {
+            name: name.into(),
+            field: CertificateField::CommonName,
+            pattern: pattern.into(),
+            role: "r".into(),
+        };
+        assert!(matches!(
+            CertificateMappingRules::new(vec![config("a", "(")]),
+            Err(CertificateRuleError::InvalidPattern { .. })
+        ));
+        assert!(matches!(
+            CertificateMappingRules::new(vec![config("a", "x"), config("a", "y")]),
+            Err(CertificateRuleError::DuplicateName(_))
+        ));
+    }
+
+    #[mz_ore::test]
+    fn test_rule_config_json() {
+        let configs: Vec<CertificateMappingRuleConfig> = serde_json::from_str(
+            r#"[{"name": "svc", "field": "dns_san", "pattern": "(.+)\\.svc", "role": "${1}"}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            configs,
+            vec![CertificateMappingRuleConfig {
+                name: "svc".into(),
+                field: CertificateField::DnsSan,
+                pattern: r"(.+)\.svc".into(),
+                role: "${1}".into(),
+            }]
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_identity_from_certificate() {
+        let pkey = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_nid(Nid::COMMONNAME, "alice").unwrap();
+        name.append_entry_by_nid(Nid::ORGANIZATIONNAME, "Example")
+            .unwrap();
+        let name = name.build();
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_pubkey(&pkey).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder
+            .set_not_before(&*Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&*Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        let san = SubjectAlternativeName::new()
+            .dns("alice.example.com")
+            .email("alice@example.com")
+            .uri("spiffe://example.com/alice")
+            .build(&builder.x509v3_context(None, None))
+            .unwrap();
+        builder.append_extension(san).unwrap();
+        builder.sign(&pkey, MessageDigest::sha256()).unwrap();
+        let cert = builder.build();
+
+        assert_eq!(
+            CertificateIdentity::from_certificate(&cert),
+            CertificateIdentity {
+                subject: "CN=alice,O=Example".into(),
+                common_names: vec!["alice".into()],
+                dns_sans: vec!["alice.example.com".into()],
+                email_sans: vec!["alice@example.com".into()],
+                uri_sans: vec!["spiffe://example.com/alice".into()],
+            }
+        );
+    }
+}
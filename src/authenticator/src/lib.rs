@@ -7,12 +7,25 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod certificate;
+mod oidc;
+
+pub use certificate::{
+    CertificateAuthenticator, CertificateError, CertificateField, CertificateIdentity,
+    CertificateLogin, CertificateMapping, CertificateMappingRule, CertificateMappingRuleConfig,
+    CertificateMappingRules, CertificateRuleError,
+};
 use mz_adapter::Client as AdapterClient;
 use mz_frontegg_auth::Authenticator as FronteggAuthenticator;
+pub use oidc::{
+    JwksError, JwksSource, OidcAuthenticator, OidcCliArgs, OidcConfig, OidcError, OidcLogin,
+};
 
 #[derive(Debug, Clone)]
 pub enum Authenticator {
     Frontegg(FronteggAuthenticator),
     Password(AdapterClient),
+    Certificate(CertificateAuthenticator),
+    Oidc(OidcAuthenticator),
     None,
 }
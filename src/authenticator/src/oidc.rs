@@ -0,0 +1,718 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! OpenID Connect (OIDC) bearer token authentication.
+//!
+//! Clients present a JWT issued by an external identity provider, either as
+//! their password (pgwire, HTTP basic auth) or as a bearer token (HTTP,
+//! WebSocket). Tokens are verified against the signing keys in a JSON Web Key
+//! Set (JWKS), which is read from a local file or fetched from a URL and
+//! periodically reloaded to pick up key rotations. A configurable claim names
+//! the role that the client logs in as.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use mz_adapter::Client as AdapterClient;
+use mz_ore::now::NowFn;
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::warn;
+use url::Url;
+
+/// The signing algorithms that tokens may use.
+///
+/// Symmetric algorithms are deliberately excluded: a JWKS is public, so a key
+/// in it must never be usable to forge a signature.
+const SUPPORTED_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+/// The minimum amount of time between reloads of the JWKS that are triggered
+/// by tokens signed with an unknown key.
+const MIN_JWKS_RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long to wait for the JWKS to be fetched from a URL.
+const JWKS_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Command line arguments for OIDC authentication.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct OidcCliArgs {
+    /// Enables OIDC authentication for JWTs issued by the specified issuer.
+    #[clap(long, env = "OIDC_ISSUER", requires = "oidc_jwks", value_name = "URL")]
+    oidc_issuer: Option<String>,
+    /// The JSON Web Key Set used to validate JWTs during OIDC authentication,
+    /// as either a path to a local file or an `http(s)://` URL.
+    #[clap(
+        long,
+        env = "OIDC_JWKS",
+        requires = "oidc_issuer",
+        value_name = "PATH|URL"
+    )]
+    oidc_jwks: Option<JwksSource>,
+    /// The audience that JWTs must be issued for. If unset, the audience is
+    /// not validated.
+    #[clap(long, env = "OIDC_AUDIENCE", requires = "oidc_issuer")]
+    oidc_audience: Option<String>,
+    /// The JWT claim that names the role to log in as.
+    #[clap(long, env = "OIDC_ROLE_CLAIM", default_value = "sub")]
+    oidc_role_claim: String,
+    /// Whether to create roles that do not yet exist on login. If unset, the
+    /// role named by the token must already exist.
+    #[clap(long, env = "OIDC_AUTO_CREATE_ROLES")]
+    oidc_auto_create_roles: bool,
+    /// How often to reload the JSON Web Key Set. Must be nonzero.
+    #[clap(
+        long,
+        env = "OIDC_JWKS_REFRESH_INTERVAL",
+        value_parser = parse_jwks_refresh_interval,
+        default_value = "5m"
+    )]
+    oidc_jwks_refresh_interval: Duration,
+}
+
+/// Parses a nonzero JWKS refresh interval.
+fn parse_jwks_refresh_interval(s: &str) -> Result<Duration, String> {
+    match humantime::parse_duration(s) {
+        Ok(interval) if interval.is_zero() => {
+            Err("JWKS refresh interval must be nonzero".to_string())
+        }
+        Ok(interval) => Ok(interval),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Where to load a JSON Web Key Set from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JwksSource {
+    /// A local file.
+    File(PathBuf),
+    /// An HTTP or HTTPS URL.
+    Url(Url),
+}
+
+impl FromStr for JwksSource {
+    type Err = url::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            Ok(JwksSource::Url(s.parse()?))
+        } else {
+            Ok(JwksSource::File(PathBuf::from(s)))
+        }
+    }
+}
+
+impl fmt::Display for JwksSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JwksSource::File(path) => write!(f, "{}", path.display()),
+            JwksSource::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+/// Configures an [`OidcAuthenticator`].
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    /// The issuer that JWTs must be issued by.
+    pub issuer: String,
+    /// The audience that JWTs must be issued for, if any.
+    pub audience: Option<String>,
+    /// The JSON Web Key Set used to validate JWTs.
+    pub jwks: JwksSource,
+    /// The JWT claim that names the role to log in as.
+    pub role_claim: String,
+    /// Whether to create roles that do not yet exist on login.
+    pub auto_create_roles: bool,
+    /// How often to reload the JSON Web Key Set.
+    pub jwks_refresh_interval: Duration,
+    /// Function to provide system time to validate the `exp` and `nbf` claims
+    /// of JWTs.
+    pub now: NowFn,
+}
+
+impl OidcConfig {
+    /// Creates an [`OidcConfig`] from [`OidcCliArgs`].
+    ///
+    /// Returns `None` if OIDC authentication is not configured.
+    pub fn from_args(args: OidcCliArgs, now: NowFn) -> Option<Self> {
+        let (issuer, jwks) = match (args.oidc_issuer, args.oidc_jwks) {
+            (Some(issuer), Some(jwks)) => (issuer, jwks),
+            // clap enforces that both or neither are present.
+            _ => return None,
+        };
+        Some(OidcConfig {
+            issuer,
+            audience: args.oidc_audience,
+            jwks,
+            role_claim: args.oidc_role_claim,
+            auto_create_roles: args.oidc_auto_create_roles,
+            jwks_refresh_interval: args.oidc_jwks_refresh_interval,
+            now,
+        })
+    }
+}
+
+/// An error loading a JSON Web Key Set.
+#[derive(Debug, Error)]
+pub enum JwksError {
+    #[error("reading {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("fetching {url}: {source}")]
+    Http { url: Url, source: reqwest::Error },
+    #[error("parsing JWKS: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("invalid key in JWKS: {0}")]
+    InvalidKey(jsonwebtoken::errors::Error),
+}
+
+/// An error authenticating an OIDC token.
+#[derive(Debug, Error)]
+pub enum OidcError {
+    #[error("invalid token: {0}")]
+    InvalidToken(#[from] jsonwebtoken::errors::Error),
+    #[error("token expired")]
+    TokenExpired,
+    #[error("token not yet valid")]
+    TokenNotYetValid,
+    #[error("token signed with unsupported algorithm {0:?}")]
+    UnsupportedAlgorithm(Algorithm),
+    #[error("token signed with unknown key")]
+    UnknownKey,
+    #[error("token is missing the string claim {0:?}")]
+    MissingRoleClaim(String),
+    #[error("token is for role {actual:?}, not {expected:?}")]
+    WrongRole { expected: String, actual: String },
+    #[error("role {0:?} does not exist")]
+    UnknownRole(String),
+    #[error(transparent)]
+    Jwks(#[from] JwksError),
+}
+
+/// The result of a successful OIDC login.
+#[derive(Debug, Clone)]
+pub struct OidcLogin {
+    /// The role that the client logged in as.
+    pub role_name: String,
+    /// Whether the role is a superuser.
+    pub superuser: bool,
+}
+
+/// Authenticates clients by an OIDC JWT.
+#[derive(Debug, Clone)]
+pub struct OidcAuthenticator {
+    adapter_client: AdapterClient,
+    validator: Arc<OidcValidator>,
+    auto_create_roles: bool,
+}
+
+impl OidcAuthenticator {
+    /// Creates a new OIDC authenticator.
+    pub fn new(adapter_client: AdapterClient, config: OidcConfig) -> Self {
+        let auto_create_roles = config.auto_create_roles;
+        OidcAuthenticator {
+            adapter_client,
+            validator: Arc::new(OidcValidator::new(config)),
+            auto_create_roles,
+        }
+    }
+
+    /// Authenticates a client that presented `token`.
+    ///
+    /// If `requested_role` is provided, the token must name that role.
+    /// Otherwise the client is logged in as the role the token names.
+    pub async fn authenticate(
+        &self,
+        token: &str,
+        requested_role: Option<&str>,
+    ) -> Result<OidcLogin, OidcError> {
+        let role_name = self.validator.validate(token, requested_role).await?;
+        let catalog = self.adapter_client.catalog_snapshot().await;
+        let superuser = match catalog.try_get_role_by_name(&role_name) {
+            Some(role) => role.attributes.superuser.unwrap_or(false),
+            // Roles that do not exist are created during session startup.
+            None if self.auto_create_roles => false,
+            None => return Err(OidcError::UnknownRole(role_name)),
+        };
+        Ok(OidcLogin {
+            role_name,
+            superuser,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    exp: u64,
+    nbf: Option<u64>,
+    #[serde(flatten)]
+    other: BTreeMap<String, serde_json::Value>,
+}
+
+/// Validates OIDC tokens against a cached JSON Web Key Set.
+#[derive(Debug)]
+struct OidcValidator {
+    issuer: String,
+    audience: Option<String>,
+    role_claim: String,
+    now: NowFn,
+    jwks: JwksSource,
+    jwks_refresh_interval: Duration,
+    http_client: reqwest::Client,
+    keys: Mutex<JwksCache>,
+    /// Held while reloading the JWKS, so that concurrent authentications that
+    /// need a reload share a single fetch. The fetch happens without holding
+    /// `keys`, so authentications that can use the cached keys never wait on
+    /// the identity provider.
+    reload_lock: tokio::sync::Mutex<()>,
+}
+
+#[derive(Default)]
+struct JwksCache {
+    /// The keys from the most recent successful load of the JWKS, with their
+    /// key IDs.
+    keys: Vec<(Option<String>, DecodingKey)>,
+    /// When the JWKS was last loaded, successfully or not.
+    loaded_at: Option<Instant>,
+    /// The number of loads of the JWKS attempted so far.
+    generation: u64,
+}
+
+impl fmt::Debug for JwksCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwksCache")
+            .field(
+                "key_ids",
+                &self.keys.iter().map(|(kid, _)| kid).collect::<Vec<_>>(),
+            )
+            .field("loaded_at", &self.loaded_at)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+impl JwksCache {
+    fn find(&self, kid: Option<&str>) -> Option<&DecodingKey> {
+        match kid {
+            Some(kid) => self
+                .keys
+                .iter()
+                .find(|(key_id, _)| key_id.as_deref() == Some(kid))
+                .map(|(_, key)| key),
+            // A token that does not name its key is only unambiguous if there
+            // is exactly one key.
+            None => match &self.keys[..] {
+                [(_, key)] => Some(key),
+                _ => None,
+            },
+        }
+    }
+
+    fn loaded_within(&self, interval: Duration) -> bool {
+        self.loaded_at
+            .is_some_and(|loaded_at| loaded_at.elapsed() < interval)
+    }
+}
+
+impl OidcValidator {
+    fn new(config: OidcConfig) -> Self {
+        OidcValidator {
+            issuer: config.issuer,
+            audience: config.audience,
+            role_claim: config.role_claim,
+            now: config.now,
+            jwks: config.jwks,
+            jwks_refresh_interval: config.jwks_refresh_interval,
+            http_client: reqwest::Client::new(),
+            keys: Mutex::new(JwksCache::default()),
+            reload_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Validates `token`, returning the role it names.
+    async fn validate(
+        &self,
+        token: &str,
+        expected_role: Option<&str>,
+    ) -> Result<String, OidcError> {
+        let header = jsonwebtoken::decode_header(token)?;
+        if !SUPPORTED_ALGORITHMS.contains(&header.alg) {
+            return Err(OidcError::UnsupportedAlgorithm(header.alg));
+        }
+        let key = self.decoding_key(header.kid.as_deref()).await?;
+
+        let mut validation = Validation::new(header.alg);
+        // We validate the token expiration with our own now function.
+        validation.validate_exp = false;
+        validation.set_required_spec_claims(&["exp", "iss"]);
+        validation.set_issuer(&[&self.issuer]);
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+        let msg = jsonwebtoken::decode::<Claims>(token, &key, &validation)?;
+        let now = self.now.as_secs();
+        if msg.claims.exp < now {
+            return Err(OidcError::TokenExpired);
+        }
+        if msg.claims.nbf.is_some_and(|nbf| nbf > now) {
+            return Err(OidcError::TokenNotYetValid);
+        }
+
+        let role = match msg.claims.other.get(&self.role_claim) {
+            Some(serde_json::Value::String(role)) if !role.is_empty() => role.clone(),
+            _ => return Err(OidcError::MissingRoleClaim(self.role_claim.clone())),
+        };
+        if let Some(expected_role) = expected_role {
+            if role != expected_role {
+                return Err(OidcError::WrongRole {
+                    expected: expected_role.to_string(),
+                    actual: role,
+                });
+            }
+        }
+        Ok(role)
+    }
+
+    /// Returns the key with ID `kid`, reloading the JWKS if it is stale or
+    /// does not contain the key.
+    async fn decoding_key(&self, kid: Option<&str>) -> Result<DecodingKey, OidcError> {
+        let mut reloaded = false;
+        loop {
+            let generation = {
+                let cache = self.keys.lock().expect("lock poisoned");
+                // Having just reloaded, use the keys we got even if they are
+                // already stale, so a short refresh interval cannot make us
+                // reload forever.
+                if reloaded || cache.loaded_within(self.jwks_refresh_interval) {
+                    if let Some(key) = cache.find(kid) {
+                        return Ok(key.clone());
+                    }
+                    // The identity provider may have rotated its keys since we
+                    // last loaded them. Reload, but not so often that tokens
+                    // signed with bogus keys can be used to hammer the
+                    // identity provider.
+                    if reloaded || cache.loaded_within(MIN_JWKS_RELOAD_INTERVAL) {
+                        return Err(OidcError::UnknownKey);
+                    }
+                }
+                cache.generation
+            };
+            self.reload(generation).await?;
+            reloaded = true;
+        }
+    }
+
+    /// Reloads the JWKS, unless it has already been reloaded since the cache
+    /// was at `generation`.
+    ///
+    /// If the JWKS cannot be loaded, the previously loaded keys are retained
+    /// so that an unavailable identity provider does not lock out clients.
+    async fn reload(&self, generation: u64) -> Result<(), JwksError> {
+        let _reload_guard = self.reload_lock.lock().await;
+        if self.keys.lock().expect("lock poisoned").generation != generation {
+            // Another authentication reloaded the JWKS while we waited.
+            return Ok(());
+        }
+        let result = self.load_jwks().await;
+
+        let mut cache = self.keys.lock().expect("lock poisoned");
+        cache.loaded_at = Some(Instant::now());
+        cache.generation += 1;
+        match result {
+            Ok(keys) => {
+                cache.keys = keys;
+                Ok(())
+            }
+            Err(err) if !cache.keys.is_empty() => {
+                warn!(jwks = %self.jwks, "failed to reload OIDC JWKS: {err}");
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn load_jwks(&self) -> Result<Vec<(Option<String>, DecodingKey)>, JwksError> {
+        let jwks: JwkSet = match &self.jwks {
+            JwksSource::File(path) => {
+                let bytes = tokio::fs::read(path)
+                    .await
+                    .map_err(|source| JwksError::Io {
+                        path: path.clone(),
+                        source,
+                    })?;
+                serde_json::from_slice(&bytes)?
+            }
+            JwksSource::Url(url) => {
+                let http_err = |source| JwksError::Http {
+                    url: url.clone(),
+                    source,
+                };
+                let bytes = self
+                    .http_client
+                    .get(url.clone())
+                    .timeout(JWKS_FETCH_TIMEOUT)
+                    .send()
+                    .await
+                    .and_then(|res| res.error_for_status())
+                    .map_err(http_err)?
+                    .bytes()
+                    .await
+                    .map_err(http_err)?;
+                serde_json::from_slice(&bytes)?
+            }
+        };
+        jwks.keys
+            .iter()
+            .map(|jwk| {
+                let key = DecodingKey::from_jwk(jwk).map_err(JwksError::InvalidKey)?;
+                Ok((jwk.common.key_id.clone(), key))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use jsonwebtoken::{EncodingKey, Header};
+    use mz_ore::assert_ok;
+    use openssl::rsa::Rsa;
+    use serde_json::json;
+
+    use super::*;
+
+    const ISSUER: &str = "https://idp.example.com";
+
+    struct Key {
+        kid: String,
+        encoding_key: EncodingKey,
+        jwk: serde_json::Value,
+    }
+
+    impl Key {
+        fn generate(kid: &str) -> Key {
+            let rsa = Rsa::generate(2048).unwrap();
+            let encoding_key =
+                EncodingKey::from_rsa_pem(&rsa.private_key_to_pem().unwrap()).unwrap();
+            let jwk = json!({
+                "kty": "RSA",
+                "use": "sig",
+                "alg": "RS256",
+                "kid": kid,
+                "n": URL_SAFE_NO_PAD.encode(rsa.n().to_vec()),
+                "e": URL_SAFE_NO_PAD.encode(rsa.e().to_vec()),
+            });
+            Key {
+                kid: kid.to_string(),
+                encoding_key,
+                jwk,
+            }
+        }
+
+        fn sign(&self, claims: serde_json::Value) -> String {
+            let mut header = Header::new(Algorithm::RS256);
+            header.kid = Some(self.kid.clone());
+            jsonwebtoken::encode(&header, &claims, &self.encoding_key).unwrap()
+        }
+    }
+
+    fn write_jwks(path: &std::path::Path, keys: &[&Key]) {
+        let jwks = json!({ "keys": keys.iter().map(|key| &key.jwk).collect::<Vec<_>>() });
+        std::fs::write(path, jwks.to_string()).unwrap();
+    }
+
+    fn validator(path: PathBuf, audience: Option<&str>, now: Arc<AtomicU64>) -> OidcValidator {
+        OidcValidator::new(OidcConfig {
+            issuer: ISSUER.to_string(),
+            audience: audience.map(|audience| audience.to_string()),
+            jwks: JwksSource::File(path),
+            role_claim: "preferred_username".to_string(),
+            auto_create_roles: false,
+            jwks_refresh_interval: Duration::from_secs(300),
+            now: NowFn::from(move || now.load(Ordering::SeqCst)),
+        })
+    }
+
+    #[mz_ore::test]
+    fn test_jwks_source() {
+        assert_eq!(
+            "/etc/jwks.json".parse::<JwksSource>().unwrap(),
+            JwksSource::File("/etc/jwks.json".into())
+        );
+        assert_eq!(
+            "https://idp.example.com/jwks"
+                .parse::<JwksSource>()
+                .unwrap(),
+            JwksSource::Url("https://idp.example.com/jwks".parse().unwrap())
+        );
+        assert!("https://".parse::<JwksSource>().is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_parse_jwks_refresh_interval() {
+        assert_eq!(
+            parse_jwks_refresh_interval("5m"),
+            Ok(Duration::from_secs(300))
+        );
+        assert!(parse_jwks_refresh_interval("0s").is_err());
+        assert!(parse_jwks_refresh_interval("soon").is_err());
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn test_validate() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwks.json");
+        let key = Key::generate("key-1");
+        let other_key = Key::generate("key-2");
+        write_jwks(&path, &[&key]);
+
+        // Times are in milliseconds; claims are in seconds.
+        let now = Arc::new(AtomicU64::new(1_000_000));
+        let validator = validator(path, Some("materialize"), Arc::clone(&now));
+        let claims = |overrides: serde_json::Value| {
+            let mut claims = json!({
+                "iss": ISSUER,
+                "aud": "materialize",
+                "sub": "0f5c4a4e",
+                "preferred_username": "alice",
+                "exp": 2_000,
+            });
+            for (k, v) in overrides.as_object().unwrap() {
+                claims[k] = v.clone();
+            }
+            claims
+        };
+
+        let token = key.sign(claims(json!({})));
+        assert_eq!(validator.validate(&token, None).await.unwrap(), "alice");
+        assert_eq!(
+            validator.validate(&token, Some("alice")).await.unwrap(),
+            "alice"
+        );
+        assert!(matches!(
+            validator.validate(&token, Some("bob")).await,
+            Err(OidcError::WrongRole { .. })
+        ));
+
+        for (overrides, desc) in [
+            (json!({ "iss": "https://evil.example.com" }), "wrong issuer"),
+            (json!({ "aud": "other" }), "wrong audience"),
+            (json!({ "exp": 999 }), "expired"),
+            (json!({ "nbf": 1_001 }), "not yet valid"),
+            (json!({ "preferred_username": 42 }), "non-string role"),
+        ] {
+            let token = key.sign(claims(overrides));
+            assert!(
+                validator.validate(&token, None).await.is_err(),
+                "{desc} token was accepted"
+            );
+        }
+
+        // Tokens signed by keys not in the JWKS are rejected.
+        let token = other_key.sign(claims(json!({})));
+        assert!(matches!(
+            validator.validate(&token, None).await,
+            Err(OidcError::UnknownKey)
+        ));
+
+        // Tokens signed with a symmetric algorithm are rejected outright.
+        let token = jsonwebtoken::encode(
+            &Header::new(Algorithm::HS256),
+            &claims(json!({})),
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap();
+        assert!(matches!(
+            validator.validate(&token, None).await,
+            Err(OidcError::UnsupportedAlgorithm(Algorithm::HS256))
+        ));
+
+        // Garbage is rejected.
+        assert!(matches!(
+            validator.validate("not a token", None).await,
+            Err(OidcError::InvalidToken(_))
+        ));
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn test_key_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwks.json");
+        let old_key = Key::generate("old");
+        let new_key = Key::generate("new");
+        write_jwks(&path, &[&old_key]);
+
+        let now = Arc::new(AtomicU64::new(1_000_000));
+        let validator = validator(path.clone(), None, now);
+        let claims = json!({ "iss": ISSUER, "preferred_username": "alice", "exp": 2_000 });
+        let old_token = old_key.sign(claims.clone());
+        let new_token = new_key.sign(claims);
+        assert_ok!(validator.validate(&old_token, None).await);
+
+        // Rotate the keys. Tokens signed with the new key trigger a reload,
+        // but not within the minimum reload interval of the last one.
+        write_jwks(&path, &[&new_key]);
+        assert!(matches!(
+            validator.validate(&new_token, None).await,
+            Err(OidcError::UnknownKey)
+        ));
+        validator.keys.lock().expect("lock poisoned").loaded_at = None;
+        assert_ok!(validator.validate(&new_token, None).await);
+
+        // If the JWKS becomes unavailable, the cached keys continue to work.
+        std::fs::remove_file(&path).unwrap();
+        validator.keys.lock().expect("lock poisoned").loaded_at = None;
+        assert_ok!(validator.validate(&new_token, None).await);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn test_zero_refresh_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwks.json");
+        let key = Key::generate("key-1");
+        let other_key = Key::generate("key-2");
+        write_jwks(&path, &[&key]);
+
+        // Every lookup finds the keys stale, but an unknown key must still
+        // be rejected after a single reload rather than reloading forever.
+        let mut validator = validator(path, None, Arc::new(AtomicU64::new(1_000_000)));
+        validator.jwks_refresh_interval = Duration::ZERO;
+        let claims = json!({ "iss": ISSUER, "preferred_username": "alice", "exp": 2_000 });
+        assert_ok!(validator.validate(&key.sign(claims.clone()), None).await);
+        assert!(matches!(
+            validator.validate(&other_key.sign(claims), None).await,
+            Err(OidcError::UnknownKey)
+        ));
+    }
+}
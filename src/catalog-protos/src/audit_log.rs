@@ -15,15 +15,16 @@
 
 use mz_audit_log::{
     AlterDefaultPrivilegeV1, AlterRetainHistoryV1, AlterSetClusterV1, AlterSourceSinkV1,
-    CreateClusterReplicaV1, CreateClusterReplicaV2, CreateClusterReplicaV3, CreateIndexV1,
-    CreateMaterializedViewV1, CreateOrDropClusterReplicaReasonV1, CreateSourceSinkV1,
-    CreateSourceSinkV2, CreateSourceSinkV3, CreateSourceSinkV4, DropClusterReplicaV1,
-    DropClusterReplicaV2, DropClusterReplicaV3, EventDetails, EventType, EventV1, FromPreviousIdV1,
-    FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1, RefreshDecisionWithReasonV1,
-    RefreshDecisionWithReasonV2, RenameClusterReplicaV1, RenameClusterV1, RenameItemV1,
-    RenameSchemaV1, RevokeRoleV1, RevokeRoleV2, RotateKeysV1, SchedulingDecisionV1,
-    SchedulingDecisionsWithReasonsV1, SchedulingDecisionsWithReasonsV2, SchemaV1, SchemaV2, SetV1,
-    ToNewIdV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
+    CertificateLoginV1, CreateClusterReplicaV1, CreateClusterReplicaV2, CreateClusterReplicaV3,
+    CreateIndexV1, CreateMaterializedViewV1, CreateOrDropClusterReplicaReasonV1,
+    CreateSourceSinkV1, CreateSourceSinkV2, CreateSourceSinkV3, CreateSourceSinkV4,
+    DropClusterReplicaV1, DropClusterReplicaV2, DropClusterReplicaV3, EventDetails, EventType,
+    EventV1, FromPreviousIdV1, FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1,
+    RefreshDecisionWithReasonV1, RefreshDecisionWithReasonV2, RenameClusterReplicaV1,
+    RenameClusterV1, RenameItemV1, RenameSchemaV1, RevokeRoleV1, RevokeRoleV2, RotateKeysV1,
+    SchedulingDecisionV1, SchedulingDecisionsWithReasonsV1, SchedulingDecisionsWithReasonsV2,
+    SchemaV1, SchemaV2, SetV1, ToNewIdV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1,
+    VersionedEvent,
 };
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 
@@ -56,6 +57,7 @@ impl RustType<crate::objects::audit_log_event_v1::EventType> for EventType {
             EventType::Grant => crate::objects::audit_log_event_v1::EventType::Grant,
             EventType::Revoke => crate::objects::audit_log_event_v1::EventType::Revoke,
             EventType::Comment => crate::objects::audit_log_event_v1::EventType::Comment,
+            EventType::Login => crate::objects::audit_log_event_v1::EventType::Login,
         }
     }
 
@@ -69,6 +71,7 @@ impl RustType<crate::objects::audit_log_event_v1::EventType> for EventType {
             crate::objects::audit_log_event_v1::EventType::Grant => Ok(EventType::Grant),
             crate::objects::audit_log_event_v1::EventType::Revoke => Ok(EventType::Revoke),
             crate::objects::audit_log_event_v1::EventType::Comment => Ok(EventType::Comment),
+            crate::objects::audit_log_event_v1::EventType::Login => Ok(EventType::Login),
             crate::objects::audit_log_event_v1::EventType::Unknown => Err(
                 TryFromProtoError::unknown_enum_variant("EventType::Unknown"),
             ),
@@ -94,6 +97,9 @@ impl RustType<crate::objects::audit_log_event_v1::ObjectType> for mz_audit_log::
             mz_audit_log::ObjectType::Database => {
                 crate::objects::audit_log_event_v1::ObjectType::Database
             }
+            mz_audit_log::ObjectType::Endpoint => {
+                crate::objects::audit_log_event_v1::ObjectType::Endpoint
+            }
             mz_audit_log::ObjectType::Func => crate::objects::audit_log_event_v1::ObjectType::Func,
             mz_audit_log::ObjectType::Index => {
                 crate::objects::audit_log_event_v1::ObjectType::Index
@@ -145,6 +151,9 @@ impl RustType<crate::objects::audit_log_event_v1::ObjectType> for mz_audit_log::
             crate::objects::audit_log_event_v1::ObjectType::Database => {
                 Ok(mz_audit_log::ObjectType::Database)
             }
+            crate::objects::audit_log_event_v1::ObjectType::Endpoint => {
+                Ok(mz_audit_log::ObjectType::Endpoint)
+            }
             crate::objects::audit_log_event_v1::ObjectType::Func => {
                 Ok(mz_audit_log::ObjectType::Func)
             }
@@ -1238,6 +1247,30 @@ impl RustType<crate::objects::audit_log_event_v1::RotateKeysV1> for RotateKeysV1
     }
 }
 
+impl RustType<crate::objects::audit_log_event_v1::CertificateLoginV1> for CertificateLoginV1 {
+    fn into_proto(&self) -> crate::objects::audit_log_event_v1::CertificateLoginV1 {
+        crate::objects::audit_log_event_v1::CertificateLoginV1 {
+            subject: self.subject.clone(),
+            identity: self.identity.clone(),
+            rule: self.rule.clone(),
+            role_name: self.role_name.clone(),
+            accepted: self.accepted,
+        }
+    }
+
+    fn from_proto(
+        proto: crate::objects::audit_log_event_v1::CertificateLoginV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(CertificateLoginV1 {
+            subject: proto.subject,
+            identity: proto.identity,
+            rule: proto.rule,
+            role_name: proto.role_name,
+            accepted: proto.accepted,
+        })
+    }
+}
+
 impl RustType<crate::objects::audit_log_event_v1::Details> for EventDetails {
     fn into_proto(&self) -> crate::objects::audit_log_event_v1::Details {
         use crate::objects::audit_log_event_v1::Details::*;
@@ -1299,6 +1332,7 @@ impl RustType<crate::objects::audit_log_event_v1::Details> for EventDetails {
             EventDetails::SetV1(details) => SetV1(details.into_proto()),
             EventDetails::ResetAllV1 => ResetAllV1(Empty {}),
             EventDetails::RotateKeysV1(details) => RotateKeysV1(details.into_proto()),
+            EventDetails::CertificateLoginV1(details) => CertificateLoginV1(details.into_proto()),
         }
     }
 
@@ -1372,6 +1406,9 @@ impl RustType<crate::objects::audit_log_event_v1::Details> for EventDetails {
             SetV1(details) => Ok(EventDetails::SetV1(details.into_rust()?)),
             ResetAllV1(Empty {}) => Ok(EventDetails::ResetAllV1),
             RotateKeysV1(details) => Ok(EventDetails::RotateKeysV1(details.into_rust()?)),
+            CertificateLoginV1(details) => {
+                Ok(EventDetails::CertificateLoginV1(details.into_rust()?))
+            }
         }
     }
 }
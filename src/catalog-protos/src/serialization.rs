@@ -220,6 +220,7 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             CatalogItemType::Secret => crate::objects::CatalogItemType::Secret,
             CatalogItemType::Connection => crate::objects::CatalogItemType::Connection,
             CatalogItemType::ContinualTask => crate::objects::CatalogItemType::ContinualTask,
+            CatalogItemType::Endpoint => crate::objects::CatalogItemType::Endpoint,
         }
     }
 
@@ -236,6 +237,7 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             crate::objects::CatalogItemType::Secret => CatalogItemType::Secret,
             crate::objects::CatalogItemType::Connection => CatalogItemType::Connection,
             crate::objects::CatalogItemType::ContinualTask => CatalogItemType::ContinualTask,
+            crate::objects::CatalogItemType::Endpoint => CatalogItemType::Endpoint,
             crate::objects::CatalogItemType::Unknown => {
                 return Err(TryFromProtoError::unknown_enum_variant("CatalogItemType"));
             }
@@ -263,6 +265,7 @@ impl RustType<crate::objects::ObjectType> for ObjectType {
             ObjectType::Schema => crate::objects::ObjectType::Schema,
             ObjectType::Func => crate::objects::ObjectType::Func,
             ObjectType::ContinualTask => crate::objects::ObjectType::ContinualTask,
+            ObjectType::Endpoint => crate::objects::ObjectType::Endpoint,
             ObjectType::NetworkPolicy => crate::objects::ObjectType::NetworkPolicy,
         }
     }
@@ -285,6 +288,7 @@ impl RustType<crate::objects::ObjectType> for ObjectType {
             crate::objects::ObjectType::Schema => Ok(ObjectType::Schema),
             crate::objects::ObjectType::Func => Ok(ObjectType::Func),
             crate::objects::ObjectType::ContinualTask => Ok(ObjectType::ContinualTask),
+            crate::objects::ObjectType::Endpoint => Ok(ObjectType::Endpoint),
             crate::objects::ObjectType::NetworkPolicy => Ok(ObjectType::NetworkPolicy),
             crate::objects::ObjectType::Unknown => Err(TryFromProtoError::unknown_enum_variant(
                 "ObjectType::Unknown",
@@ -464,6 +468,9 @@ impl RustType<crate::objects::comment_key::Object> for CommentObjectId {
             CommentObjectId::ContinualTask(global_id) => {
                 crate::objects::comment_key::Object::ContinualTask(global_id.into_proto())
             }
+            CommentObjectId::Endpoint(global_id) => {
+                crate::objects::comment_key::Object::Endpoint(global_id.into_proto())
+            }
             CommentObjectId::NetworkPolicy(network_policy_id) => {
                 crate::objects::comment_key::Object::NetworkPolicy(network_policy_id.into_proto())
             }
@@ -521,6 +528,9 @@ impl RustType<crate::objects::comment_key::Object> for CommentObjectId {
             crate::objects::comment_key::Object::ContinualTask(item_id) => {
                 CommentObjectId::ContinualTask(item_id.into_rust()?)
             }
+            crate::objects::comment_key::Object::Endpoint(item_id) => {
+                CommentObjectId::Endpoint(item_id.into_rust()?)
+            }
             crate::objects::comment_key::Object::NetworkPolicy(global_id) => {
                 CommentObjectId::NetworkPolicy(global_id.into_rust()?)
             }
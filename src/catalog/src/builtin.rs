@@ -1065,6 +1065,20 @@ pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYMULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "anymultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4229,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_CHAR: BuiltinType<NameReference> = BuiltinType {
     name: "char",
     schema: PG_CATALOG_SCHEMA,
@@ -1169,6 +1183,66 @@ pub const TYPE_VARCHAR_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_BIT: BuiltinType<NameReference> = BuiltinType {
+    name: "bit",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_BIT_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Bit,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 1564,
+            typreceive_oid: 2456,
+        }),
+    },
+};
+
+pub const TYPE_BIT_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_bit",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_BIT_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_BIT.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_VARBIT: BuiltinType<NameReference> = BuiltinType {
+    name: "varbit",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_VARBIT_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::VarBit,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 1579,
+            typreceive_oid: 2458,
+        }),
+    },
+};
+
+pub const TYPE_VARBIT_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_varbit",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_VARBIT_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_VARBIT.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_BPCHAR_ARRAY: BuiltinType<NameReference> = BuiltinType {
     name: "_bpchar",
     schema: PG_CATALOG_SCHEMA,
@@ -1361,6 +1435,20 @@ pub const TYPE_ANYCOMPATIBLERANGE: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYCOMPATIBLEMULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "anycompatiblemultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYCOMPATIBLEMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4226,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_LIST: BuiltinType<NameReference> = BuiltinType {
     name: "list",
     schema: MZ_CATALOG_SCHEMA,
@@ -1693,6 +1781,198 @@ pub const TYPE_TSTZ_RANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_INT4_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "int4multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT4MULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_INT4.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_INT4_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_int4multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT4MULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INT4_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_INT8_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "int8multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT8MULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_INT8.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_INT8_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_int8multirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_INT8MULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INT8_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_DATE_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "datemultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_DATEMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_DATE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_DATE_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_datemultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_DATEMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_DATE_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_NUM_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "nummultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_NUMMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_NUMERIC.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_NUM_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_nummultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_NUMMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_NUM_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TS_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "tsmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_TIMESTAMP.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_TS_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TS_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_TSTZ_MULTIRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "tstzmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSTZMULTIRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MultiRange {
+            element_reference: TYPE_TIMESTAMPTZ.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 4231,
+            typreceive_oid: 4233,
+        }),
+    },
+};
+
+pub const TYPE_TSTZ_MULTIRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tstzmultirange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: mz_pgrepr::oid::TYPE_TSTZMULTIRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSTZ_MULTIRANGE.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_MZ_ACL_ITEM: BuiltinType<NameReference> = BuiltinType {
     name: "mz_aclitem",
     schema: MZ_CATALOG_SCHEMA,
@@ -13502,6 +13782,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_ANYELEMENT),
         Builtin::Type(&TYPE_ANYNONARRAY),
         Builtin::Type(&TYPE_ANYRANGE),
+        Builtin::Type(&TYPE_ANYMULTIRANGE),
         Builtin::Type(&TYPE_BOOL),
         Builtin::Type(&TYPE_BOOL_ARRAY),
         Builtin::Type(&TYPE_BYTEA),
@@ -13554,6 +13835,10 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_UUID_ARRAY),
         Builtin::Type(&TYPE_VARCHAR),
         Builtin::Type(&TYPE_VARCHAR_ARRAY),
+        Builtin::Type(&TYPE_BIT),
+        Builtin::Type(&TYPE_BIT_ARRAY),
+        Builtin::Type(&TYPE_VARBIT),
+        Builtin::Type(&TYPE_VARBIT_ARRAY),
         Builtin::Type(&TYPE_INT2_VECTOR),
         Builtin::Type(&TYPE_INT2_VECTOR_ARRAY),
         Builtin::Type(&TYPE_ANYCOMPATIBLE),
@@ -13562,6 +13847,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_ANYCOMPATIBLELIST),
         Builtin::Type(&TYPE_ANYCOMPATIBLEMAP),
         Builtin::Type(&TYPE_ANYCOMPATIBLERANGE),
+        Builtin::Type(&TYPE_ANYCOMPATIBLEMULTIRANGE),
         Builtin::Type(&TYPE_UINT2),
         Builtin::Type(&TYPE_UINT2_ARRAY),
         Builtin::Type(&TYPE_UINT4),
@@ -13582,6 +13868,18 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Type(&TYPE_TS_RANGE_ARRAY),
         Builtin::Type(&TYPE_TSTZ_RANGE),
         Builtin::Type(&TYPE_TSTZ_RANGE_ARRAY),
+        Builtin::Type(&TYPE_INT4_MULTIRANGE),
+        Builtin::Type(&TYPE_INT4_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_INT8_MULTIRANGE),
+        Builtin::Type(&TYPE_INT8_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_DATE_MULTIRANGE),
+        Builtin::Type(&TYPE_DATE_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_NUM_MULTIRANGE),
+        Builtin::Type(&TYPE_NUM_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_TS_MULTIRANGE),
+        Builtin::Type(&TYPE_TS_MULTIRANGE_ARRAY),
+        Builtin::Type(&TYPE_TSTZ_MULTIRANGE),
+        Builtin::Type(&TYPE_TSTZ_MULTIRANGE_ARRAY),
         Builtin::Type(&TYPE_MZ_ACL_ITEM),
         Builtin::Type(&TYPE_MZ_ACL_ITEM_ARRAY),
         Builtin::Type(&TYPE_ACL_ITEM),
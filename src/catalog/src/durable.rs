@@ -34,10 +34,10 @@ use crate::durable::objects::state_update::{StateUpdateKindJson, TryIntoStateUpd
 use crate::durable::objects::{AuditLog, Snapshot};
 pub use crate::durable::objects::{
     Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged, Comment,
-    Database, DefaultPrivilege, IntrospectionSourceIndex, Item, NetworkPolicy, ReplicaConfig,
-    ReplicaLocation, Role, RoleAuth, Schema, SourceReference, SourceReferences,
-    StorageCollectionMetadata, SystemConfiguration, SystemObjectDescription, SystemObjectMapping,
-    UnfinalizedShard,
+    Database, DefaultPrivilege, DurableSubscription, IntrospectionSourceIndex, Item, NetworkPolicy,
+    ReplicaConfig, ReplicaLocation, ReplicationSlot, Role, RoleAuth, Schema, SourceReference,
+    SourceReferences, StorageCollectionMetadata, SystemConfiguration, SystemObjectDescription,
+    SystemObjectMapping, UnfinalizedShard,
 };
 pub use crate::durable::persist::shard_id;
 use crate::durable::persist::{Timestamp, UnopenedPersistCatalogState};
@@ -61,9 +61,11 @@ pub enum CollectionType {
     Config,
     Database,
     DefaultPrivileges,
+    DurableSubscription,
     IdAlloc,
     Item,
     NetworkPolicy,
+    ReplicationSlot,
     Role,
     RoleAuth,
     Schema,
@@ -297,6 +299,22 @@ collection_impl!({
     trace_field: txn_wal_shard,
     update: StateUpdateKind::TxnWalShard,
 });
+collection_impl!({
+    name: DurableSubscriptionCollection,
+    key: proto::DurableSubscriptionKey,
+    value: proto::DurableSubscriptionValue,
+    collection_type: CollectionType::DurableSubscription,
+    trace_field: durable_subscriptions,
+    update: StateUpdateKind::DurableSubscription,
+});
+collection_impl!({
+    name: ReplicationSlotCollection,
+    key: proto::ReplicationSlotKey,
+    value: proto::ReplicationSlotValue,
+    collection_type: CollectionType::ReplicationSlot,
+    trace_field: replication_slots,
+    update: StateUpdateKind::ReplicationSlot,
+});
 
 /// A trace of timestamped diffs for a particular [`Collection`].
 ///
@@ -350,6 +368,8 @@ pub struct Trace {
     pub storage_collection_metadata: CollectionTrace<StorageCollectionMetadataCollection>,
     pub unfinalized_shards: CollectionTrace<UnfinalizedShardsCollection>,
     pub txn_wal_shard: CollectionTrace<TxnWalShardCollection>,
+    pub durable_subscriptions: CollectionTrace<DurableSubscriptionCollection>,
+    pub replication_slots: CollectionTrace<ReplicationSlotCollection>,
 }
 
 impl Trace {
@@ -377,6 +397,8 @@ impl Trace {
             storage_collection_metadata: CollectionTrace::new(),
             unfinalized_shards: CollectionTrace::new(),
             txn_wal_shard: CollectionTrace::new(),
+            durable_subscriptions: CollectionTrace::new(),
+            replication_slots: CollectionTrace::new(),
         }
     }
 
@@ -404,6 +426,8 @@ impl Trace {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
         } = self;
         audit_log.sort();
         clusters.sort();
@@ -427,6 +451,8 @@ impl Trace {
         storage_collection_metadata.sort();
         unfinalized_shards.sort();
         txn_wal_shard.sort();
+        durable_subscriptions.sort();
+        replication_slots.sort();
     }
 }
 
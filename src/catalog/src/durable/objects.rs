@@ -38,7 +38,7 @@ use mz_persist_types::ShardId;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::role_id::RoleId;
-use mz_repr::{CatalogItemId, GlobalId, RelationVersion};
+use mz_repr::{CatalogItemId, GlobalId, RelationVersion, Timestamp};
 use mz_sql::catalog::{
     CatalogItemType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, ObjectType, RoleAttributes,
     RoleMembership, RoleVars,
@@ -1142,6 +1142,91 @@ impl DurableType for UnfinalizedShard {
     }
 }
 
+/// A named subscription whose progress survives client disconnects and restarts.
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq)]
+pub struct DurableSubscription {
+    pub name: String,
+    pub owner_id: RoleId,
+    /// The objects the subscription reads from.
+    pub dependencies: Vec<GlobalId>,
+    /// All updates at times not beyond this frontier have been acknowledged by the client.
+    pub frontier: Timestamp,
+}
+
+impl DurableType for DurableSubscription {
+    type Key = DurableSubscriptionKey;
+    type Value = DurableSubscriptionValue;
+
+    fn into_key_value(self) -> (Self::Key, Self::Value) {
+        (
+            DurableSubscriptionKey { name: self.name },
+            DurableSubscriptionValue {
+                owner_id: self.owner_id,
+                dependencies: self.dependencies,
+                frontier: self.frontier,
+            },
+        )
+    }
+
+    fn from_key_value(key: Self::Key, value: Self::Value) -> Self {
+        Self {
+            name: key.name,
+            owner_id: value.owner_id,
+            dependencies: value.dependencies,
+            frontier: value.frontier,
+        }
+    }
+
+    fn key(&self) -> Self::Key {
+        DurableSubscriptionKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
+/// A logical replication slot, which records how far its client has consumed a replication
+/// stream.
+#[derive(Debug, Clone, Ord, PartialOrd, PartialEq, Eq)]
+pub struct ReplicationSlot {
+    pub name: String,
+    pub owner_id: RoleId,
+    /// The relation the slot streams, once it has been streamed from.
+    pub publication: Option<GlobalId>,
+    /// The LSN through which the client has confirmed flushing the changes sent to it.
+    pub confirmed_flush: Timestamp,
+}
+
+impl DurableType for ReplicationSlot {
+    type Key = ReplicationSlotKey;
+    type Value = ReplicationSlotValue;
+
+    fn into_key_value(self) -> (Self::Key, Self::Value) {
+        (
+            ReplicationSlotKey { name: self.name },
+            ReplicationSlotValue {
+                owner_id: self.owner_id,
+                publication: self.publication,
+                confirmed_flush: self.confirmed_flush,
+            },
+        )
+    }
+
+    fn from_key_value(key: Self::Key, value: Self::Value) -> Self {
+        Self {
+            name: key.name,
+            owner_id: value.owner_id,
+            publication: value.publication,
+            confirmed_flush: value.confirmed_flush,
+        }
+    }
+
+    fn key(&self) -> Self::Key {
+        ReplicationSlotKey {
+            name: self.name.clone(),
+        }
+    }
+}
+
 // Structs used internally to represent on-disk state.
 
 /// A snapshot of the current on-disk state.
@@ -1173,6 +1258,9 @@ pub struct Snapshot {
         BTreeMap<proto::StorageCollectionMetadataKey, proto::StorageCollectionMetadataValue>,
     pub unfinalized_shards: BTreeMap<proto::UnfinalizedShardKey, ()>,
     pub txn_wal_shard: BTreeMap<(), proto::TxnWalShardValue>,
+    pub durable_subscriptions:
+        BTreeMap<proto::DurableSubscriptionKey, proto::DurableSubscriptionValue>,
+    pub replication_slots: BTreeMap<proto::ReplicationSlotKey, proto::ReplicationSlotValue>,
 }
 
 impl Snapshot {
@@ -1363,6 +1451,7 @@ fn item_type(create_sql: &str) -> CatalogItemType {
         Some("FUNCTION") => CatalogItemType::Func,
         Some("SECRET") => CatalogItemType::Secret,
         Some("CONNECTION") => CatalogItemType::Connection,
+        Some("ENDPOINT") => CatalogItemType::Endpoint,
         _ => panic!("unexpected create sql: {}", create_sql),
     }
 }
@@ -1447,6 +1536,30 @@ pub struct TxnWalShardValue {
     pub(crate) shard: ShardId,
 }
 
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct DurableSubscriptionKey {
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct DurableSubscriptionValue {
+    pub(crate) owner_id: RoleId,
+    pub(crate) dependencies: Vec<GlobalId>,
+    pub(crate) frontier: Timestamp,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct ReplicationSlotKey {
+    pub(crate) name: String,
+}
+
+#[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord, Arbitrary)]
+pub struct ReplicationSlotValue {
+    pub(crate) owner_id: RoleId,
+    pub(crate) publication: Option<GlobalId>,
+    pub(crate) confirmed_flush: Timestamp,
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Ord, Hash)]
 pub struct ServerConfigurationKey {
     pub(crate) name: String,
@@ -17,9 +17,10 @@ use crate::durable::objects::{
     AuditLogKey, ClusterIntrospectionSourceIndexKey, ClusterIntrospectionSourceIndexValue,
     ClusterKey, ClusterReplicaKey, ClusterReplicaValue, ClusterValue, CommentKey, CommentValue,
     ConfigKey, ConfigValue, DatabaseKey, DatabaseValue, DefaultPrivilegesKey,
-    DefaultPrivilegesValue, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
-    IntrospectionSourceIndexCatalogItemId, IntrospectionSourceIndexGlobalId, ItemKey, ItemValue,
-    NetworkPolicyKey, NetworkPolicyValue, RoleKey, RoleValue, SchemaKey, SchemaValue,
+    DefaultPrivilegesValue, DurableSubscriptionKey, DurableSubscriptionValue, GidMappingKey,
+    GidMappingValue, IdAllocKey, IdAllocValue, IntrospectionSourceIndexCatalogItemId,
+    IntrospectionSourceIndexGlobalId, ItemKey, ItemValue, NetworkPolicyKey, NetworkPolicyValue,
+    ReplicationSlotKey, ReplicationSlotValue, RoleKey, RoleValue, SchemaKey, SchemaValue,
     ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, SourceReference,
     SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
     StorageCollectionMetadataValue, SystemCatalogItemId, SystemGlobalId, SystemPrivilegesKey,
@@ -789,6 +790,74 @@ impl RustType<proto::TxnWalShardValue> for TxnWalShardValue {
     }
 }
 
+impl RustType<proto::DurableSubscriptionKey> for DurableSubscriptionKey {
+    fn into_proto(&self) -> proto::DurableSubscriptionKey {
+        proto::DurableSubscriptionKey {
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::DurableSubscriptionKey) -> Result<Self, TryFromProtoError> {
+        Ok(DurableSubscriptionKey { name: proto.name })
+    }
+}
+
+impl RustType<proto::DurableSubscriptionValue> for DurableSubscriptionValue {
+    fn into_proto(&self) -> proto::DurableSubscriptionValue {
+        proto::DurableSubscriptionValue {
+            owner_id: Some(self.owner_id.into_proto()),
+            dependencies: self.dependencies.into_proto(),
+            frontier: Some(self.frontier.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::DurableSubscriptionValue) -> Result<Self, TryFromProtoError> {
+        Ok(DurableSubscriptionValue {
+            owner_id: proto
+                .owner_id
+                .into_rust_if_some("DurableSubscriptionValue::owner_id")?,
+            dependencies: proto.dependencies.into_rust()?,
+            frontier: proto
+                .frontier
+                .into_rust_if_some("DurableSubscriptionValue::frontier")?,
+        })
+    }
+}
+
+impl RustType<proto::ReplicationSlotKey> for ReplicationSlotKey {
+    fn into_proto(&self) -> proto::ReplicationSlotKey {
+        proto::ReplicationSlotKey {
+            name: self.name.clone(),
+        }
+    }
+
+    fn from_proto(proto: proto::ReplicationSlotKey) -> Result<Self, TryFromProtoError> {
+        Ok(ReplicationSlotKey { name: proto.name })
+    }
+}
+
+impl RustType<proto::ReplicationSlotValue> for ReplicationSlotValue {
+    fn into_proto(&self) -> proto::ReplicationSlotValue {
+        proto::ReplicationSlotValue {
+            owner_id: Some(self.owner_id.into_proto()),
+            publication: self.publication.into_proto(),
+            confirmed_flush: Some(self.confirmed_flush.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: proto::ReplicationSlotValue) -> Result<Self, TryFromProtoError> {
+        Ok(ReplicationSlotValue {
+            owner_id: proto
+                .owner_id
+                .into_rust_if_some("ReplicationSlotValue::owner_id")?,
+            publication: proto.publication.into_rust()?,
+            confirmed_flush: proto
+                .confirmed_flush
+                .into_rust_if_some("ReplicationSlotValue::confirmed_flush")?,
+        })
+    }
+}
+
 impl RustType<proto::ServerConfigurationKey> for ServerConfigurationKey {
     fn into_proto(&self) -> proto::ServerConfigurationKey {
         proto::ServerConfigurationKey {
@@ -153,6 +153,8 @@ impl StateUpdate {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
             audit_log_updates,
             upper: _,
         } = txn_batch;
@@ -185,6 +187,9 @@ impl StateUpdate {
         );
         let unfinalized_shards = from_batch(unfinalized_shards, StateUpdateKind::UnfinalizedShard);
         let txn_wal_shard = from_batch(txn_wal_shard, StateUpdateKind::TxnWalShard);
+        let durable_subscriptions =
+            from_batch(durable_subscriptions, StateUpdateKind::DurableSubscription);
+        let replication_slots = from_batch(replication_slots, StateUpdateKind::ReplicationSlot);
         let audit_logs = from_batch(audit_log_updates, StateUpdateKind::AuditLog);
 
         databases
@@ -208,6 +213,8 @@ impl StateUpdate {
             .chain(storage_collection_metadata)
             .chain(unfinalized_shards)
             .chain(txn_wal_shard)
+            .chain(durable_subscriptions)
+            .chain(replication_slots)
             .chain(audit_logs)
     }
 }
@@ -225,6 +232,10 @@ pub enum StateUpdateKind {
     Config(proto::ConfigKey, proto::ConfigValue),
     Database(proto::DatabaseKey, proto::DatabaseValue),
     DefaultPrivilege(proto::DefaultPrivilegesKey, proto::DefaultPrivilegesValue),
+    DurableSubscription(
+        proto::DurableSubscriptionKey,
+        proto::DurableSubscriptionValue,
+    ),
     FenceToken(FenceToken),
     IdAllocator(proto::IdAllocKey, proto::IdAllocValue),
     IntrospectionSourceIndex(
@@ -233,6 +244,7 @@ pub enum StateUpdateKind {
     ),
     Item(proto::ItemKey, proto::ItemValue),
     NetworkPolicy(proto::NetworkPolicyKey, proto::NetworkPolicyValue),
+    ReplicationSlot(proto::ReplicationSlotKey, proto::ReplicationSlotValue),
     Role(proto::RoleKey, proto::RoleValue),
     RoleAuth(proto::RoleAuthKey, proto::RoleAuthValue),
     Schema(proto::SchemaKey, proto::SchemaValue),
@@ -262,6 +274,7 @@ impl StateUpdateKind {
             StateUpdateKind::Config(_, _) => Some(CollectionType::Config),
             StateUpdateKind::Database(_, _) => Some(CollectionType::Database),
             StateUpdateKind::DefaultPrivilege(_, _) => Some(CollectionType::DefaultPrivileges),
+            StateUpdateKind::DurableSubscription(_, _) => Some(CollectionType::DurableSubscription),
             StateUpdateKind::FenceToken(_) => None,
             StateUpdateKind::IdAllocator(_, _) => Some(CollectionType::IdAlloc),
             StateUpdateKind::IntrospectionSourceIndex(_, _) => {
@@ -269,6 +282,7 @@ impl StateUpdateKind {
             }
             StateUpdateKind::Item(_, _) => Some(CollectionType::Item),
             StateUpdateKind::NetworkPolicy(_, _) => Some(CollectionType::NetworkPolicy),
+            StateUpdateKind::ReplicationSlot(_, _) => Some(CollectionType::ReplicationSlot),
             StateUpdateKind::Role(_, _) => Some(CollectionType::Role),
             StateUpdateKind::RoleAuth(_, _) => Some(CollectionType::RoleAuth),
             StateUpdateKind::Schema(_, _) => Some(CollectionType::Schema),
@@ -460,6 +474,18 @@ impl TryFrom<&StateUpdateKind> for Option<memory::objects::StateUpdateKind> {
                     default_privilege,
                 ))
             }
+            StateUpdateKind::DurableSubscription(key, value) => {
+                let durable_subscription = into_durable(key, value)?;
+                Some(memory::objects::StateUpdateKind::DurableSubscription(
+                    durable_subscription,
+                ))
+            }
+            StateUpdateKind::ReplicationSlot(key, value) => {
+                let replication_slot = into_durable(key, value)?;
+                Some(memory::objects::StateUpdateKind::ReplicationSlot(
+                    replication_slot,
+                ))
+            }
             StateUpdateKind::Item(key, value) => {
                 let item = into_durable(key, value)?;
                 Some(memory::objects::StateUpdateKind::Item(item))
@@ -741,6 +767,22 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         proto::state_update_kind::TxnWalShard { value: Some(value) },
                     )
                 }
+                StateUpdateKind::DurableSubscription(key, value) => {
+                    proto::state_update_kind::Kind::DurableSubscription(
+                        proto::state_update_kind::DurableSubscription {
+                            key: Some(key),
+                            value: Some(value),
+                        },
+                    )
+                }
+                StateUpdateKind::ReplicationSlot(key, value) => {
+                    proto::state_update_kind::Kind::ReplicationSlot(
+                        proto::state_update_kind::ReplicationSlot {
+                            key: Some(key),
+                            value: Some(value),
+                        },
+                    )
+                }
             }),
         }
     }
@@ -1008,6 +1050,32 @@ impl RustType<proto::StateUpdateKind> for StateUpdateKind {
                         TryFromProtoError::missing_field("state_update_kind::NetworkPolicy::value")
                     })?,
                 ),
+                proto::state_update_kind::Kind::DurableSubscription(
+                    proto::state_update_kind::DurableSubscription { key, value },
+                ) => StateUpdateKind::DurableSubscription(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field(
+                            "state_update_kind::DurableSubscription::key",
+                        )
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field(
+                            "state_update_kind::DurableSubscription::value",
+                        )
+                    })?,
+                ),
+                proto::state_update_kind::Kind::ReplicationSlot(
+                    proto::state_update_kind::ReplicationSlot { key, value },
+                ) => StateUpdateKind::ReplicationSlot(
+                    key.ok_or_else(|| {
+                        TryFromProtoError::missing_field("state_update_kind::ReplicationSlot::key")
+                    })?,
+                    value.ok_or_else(|| {
+                        TryFromProtoError::missing_field(
+                            "state_update_kind::ReplicationSlot::value",
+                        )
+                    })?,
+                ),
             },
         )
     }
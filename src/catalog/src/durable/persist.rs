@@ -862,6 +862,12 @@ impl<U: ApplyUpdate<StateUpdateKind>> PersistHandle<StateUpdateKind, U> {
                     StateUpdateKind::RoleAuth(key, value) => {
                         apply(&mut snapshot.role_auth, key, value, diff);
                     }
+                    StateUpdateKind::DurableSubscription(key, value) => {
+                        apply(&mut snapshot.durable_subscriptions, key, value, diff);
+                    }
+                    StateUpdateKind::ReplicationSlot(key, value) => {
+                        apply(&mut snapshot.replication_slots, key, value, diff);
+                    }
                 }
             }
             f(snapshot)
@@ -1998,6 +2004,12 @@ impl Trace {
                     trace.txn_wal_shard.values.push((((), v), ts, diff))
                 }
                 StateUpdateKind::RoleAuth(k, v) => trace.role_auth.values.push(((k, v), ts, diff)),
+                StateUpdateKind::DurableSubscription(k, v) => {
+                    trace.durable_subscriptions.values.push(((k, v), ts, diff))
+                }
+                StateUpdateKind::ReplicationSlot(k, v) => {
+                    trace.replication_slots.values.push(((k, v), ts, diff))
+                }
             }
         }
         trace
@@ -50,11 +50,12 @@ use crate::durable::objects::{
     ClusterIntrospectionSourceIndexValue, ClusterKey, ClusterReplica, ClusterReplicaKey,
     ClusterReplicaValue, ClusterValue, CommentKey, CommentValue, Config, ConfigKey, ConfigValue,
     Database, DatabaseKey, DatabaseValue, DefaultPrivilegesKey, DefaultPrivilegesValue,
-    DurableType, GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue,
-    IntrospectionSourceIndex, Item, ItemKey, ItemValue, NetworkPolicyKey, NetworkPolicyValue,
-    ReplicaConfig, Role, RoleKey, RoleValue, Schema, SchemaKey, SchemaValue,
-    ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue, SourceReference,
-    SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
+    DurableSubscription, DurableSubscriptionKey, DurableSubscriptionValue, DurableType,
+    GidMappingKey, GidMappingValue, IdAllocKey, IdAllocValue, IntrospectionSourceIndex, Item,
+    ItemKey, ItemValue, NetworkPolicyKey, NetworkPolicyValue, ReplicaConfig, ReplicationSlot,
+    ReplicationSlotKey, ReplicationSlotValue, Role, RoleKey, RoleValue, Schema, SchemaKey,
+    SchemaValue, ServerConfigurationKey, ServerConfigurationValue, SettingKey, SettingValue,
+    SourceReference, SourceReferencesKey, SourceReferencesValue, StorageCollectionMetadataKey,
     StorageCollectionMetadataValue, SystemObjectDescription, SystemObjectMapping,
     SystemPrivilegesKey, SystemPrivilegesValue, TxnWalShardValue, UnfinalizedShardKey,
 };
@@ -101,6 +102,8 @@ pub struct Transaction<'a> {
         TableTransaction<StorageCollectionMetadataKey, StorageCollectionMetadataValue>,
     unfinalized_shards: TableTransaction<UnfinalizedShardKey, ()>,
     txn_wal_shard: TableTransaction<(), TxnWalShardValue>,
+    durable_subscriptions: TableTransaction<DurableSubscriptionKey, DurableSubscriptionValue>,
+    replication_slots: TableTransaction<ReplicationSlotKey, ReplicationSlotValue>,
     // Don't make this a table transaction so that it's not read into the
     // in-memory cache.
     audit_log_updates: Vec<(AuditLogKey, Diff, Timestamp)>,
@@ -135,6 +138,8 @@ impl<'a> Transaction<'a> {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
         }: Snapshot,
         upper: mz_repr::Timestamp,
     ) -> Result<Transaction<'a>, CatalogError> {
@@ -188,6 +193,8 @@ impl<'a> Transaction<'a> {
             // the value (the key is the unit struct `()` so this is a singleton
             // value).
             txn_wal_shard: TableTransaction::new(txn_wal_shard)?,
+            durable_subscriptions: TableTransaction::new(durable_subscriptions)?,
+            replication_slots: TableTransaction::new(replication_slots)?,
             audit_log_updates: Vec::new(),
             upper,
             op_id: 0,
@@ -2089,6 +2096,44 @@ impl<'a> Transaction<'a> {
         Ok(())
     }
 
+    /// Inserts or replaces the durable subscription with the same name as `subscription`.
+    pub fn upsert_durable_subscription(
+        &mut self,
+        subscription: DurableSubscription,
+    ) -> Result<(), CatalogError> {
+        let (key, value) = subscription.into_key_value();
+        self.durable_subscriptions
+            .set(key, Some(value), self.op_id)?;
+        Ok(())
+    }
+
+    /// Removes all durable subscriptions whose name is in `names`.
+    pub fn remove_durable_subscriptions(&mut self, names: &BTreeSet<String>) {
+        if names.is_empty() {
+            return;
+        }
+
+        self.durable_subscriptions
+            .delete(|k, _v| names.contains(&k.name), self.op_id);
+    }
+
+    /// Inserts or replaces the replication slot with the same name as `slot`.
+    pub fn upsert_replication_slot(&mut self, slot: ReplicationSlot) -> Result<(), CatalogError> {
+        let (key, value) = slot.into_key_value();
+        self.replication_slots.set(key, Some(value), self.op_id)?;
+        Ok(())
+    }
+
+    /// Removes all replication slots whose name is in `names`.
+    pub fn remove_replication_slots(&mut self, names: &BTreeSet<String>) {
+        if names.is_empty() {
+            return;
+        }
+
+        self.replication_slots
+            .delete(|k, _v| names.contains(&k.name), self.op_id);
+    }
+
     /// Upserts persisted system configuration `name` to `value`.
     pub fn upsert_system_config(&mut self, name: &str, value: String) -> Result<(), CatalogError> {
         let key = ServerConfigurationKey {
@@ -2287,6 +2332,8 @@ impl<'a> Transaction<'a> {
             audit_log_updates,
             storage_collection_metadata,
             unfinalized_shards,
+            durable_subscriptions,
+            replication_slots,
             // Not representable as a `StateUpdate`.
             id_allocator: _,
             configs: _,
@@ -2382,6 +2429,16 @@ impl<'a> Transaction<'a> {
                 StateUpdateKind::UnfinalizedShard,
                 self.op_id,
             ))
+            .chain(get_collection_op_updates(
+                durable_subscriptions,
+                StateUpdateKind::DurableSubscription,
+                self.op_id,
+            ))
+            .chain(get_collection_op_updates(
+                replication_slots,
+                StateUpdateKind::ReplicationSlot,
+                self.op_id,
+            ))
             .chain(get_large_collection_op_updates(
                 audit_log_updates,
                 StateUpdateKind::AuditLog,
@@ -2442,6 +2499,8 @@ impl<'a> Transaction<'a> {
             storage_collection_metadata: self.storage_collection_metadata.pending(),
             unfinalized_shards: self.unfinalized_shards.pending(),
             txn_wal_shard: self.txn_wal_shard.pending(),
+            durable_subscriptions: self.durable_subscriptions.pending(),
+            replication_slots: self.replication_slots.pending(),
             audit_log_updates,
             upper: self.upper,
         };
@@ -2487,6 +2546,8 @@ impl<'a> Transaction<'a> {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
             audit_log_updates,
             upper,
         } = &mut txn_batch;
@@ -2513,6 +2574,8 @@ impl<'a> Transaction<'a> {
         differential_dataflow::consolidation::consolidate_updates(storage_collection_metadata);
         differential_dataflow::consolidation::consolidate_updates(unfinalized_shards);
         differential_dataflow::consolidation::consolidate_updates(txn_wal_shard);
+        differential_dataflow::consolidation::consolidate_updates(durable_subscriptions);
+        differential_dataflow::consolidation::consolidate_updates(replication_slots);
         differential_dataflow::consolidation::consolidate_updates(audit_log_updates);
 
         assert!(
@@ -2730,6 +2793,13 @@ pub struct TransactionBatch {
     )>,
     pub(crate) unfinalized_shards: Vec<(proto::UnfinalizedShardKey, (), Diff)>,
     pub(crate) txn_wal_shard: Vec<((), proto::TxnWalShardValue, Diff)>,
+    pub(crate) durable_subscriptions: Vec<(
+        proto::DurableSubscriptionKey,
+        proto::DurableSubscriptionValue,
+        Diff,
+    )>,
+    pub(crate) replication_slots:
+        Vec<(proto::ReplicationSlotKey, proto::ReplicationSlotValue, Diff)>,
     pub(crate) audit_log_updates: Vec<(proto::AuditLogKey, (), Diff)>,
     /// The upper of the catalog when the transaction started.
     pub(crate) upper: mz_repr::Timestamp,
@@ -2759,6 +2829,8 @@ impl TransactionBatch {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
             audit_log_updates,
             upper: _,
         } = self;
@@ -2783,6 +2855,8 @@ impl TransactionBatch {
             && storage_collection_metadata.is_empty()
             && unfinalized_shards.is_empty()
             && txn_wal_shard.is_empty()
+            && durable_subscriptions.is_empty()
+            && replication_slots.is_empty()
             && audit_log_updates.is_empty()
     }
 }
@@ -188,7 +188,7 @@ macro_rules! objects {
     }
 }
 
-objects!(v67, v68, v69, v70, v71, v72, v73, v74);
+objects!(v67, v68, v69, v70, v71, v72, v73, v74, v75, v76, v77, v78);
 
 /// The current version of the `Catalog`.
 pub use mz_catalog_protos::CATALOG_VERSION;
@@ -207,6 +207,10 @@ mod v70_to_v71;
 mod v71_to_v72;
 mod v72_to_v73;
 mod v73_to_v74;
+mod v74_to_v75;
+mod v75_to_v76;
+mod v76_to_v77;
+mod v77_to_v78;
 
 /// Describes a single action to take during a migration from `V1` to `V2`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -352,6 +356,42 @@ async fn run_upgrade(
             )
             .await
         }
+        74 => {
+            run_versioned_upgrade(
+                unopened_catalog_state,
+                version,
+                commit_ts,
+                v74_to_v75::upgrade,
+            )
+            .await
+        }
+        75 => {
+            run_versioned_upgrade(
+                unopened_catalog_state,
+                version,
+                commit_ts,
+                v75_to_v76::upgrade,
+            )
+            .await
+        }
+        76 => {
+            run_versioned_upgrade(
+                unopened_catalog_state,
+                version,
+                commit_ts,
+                v76_to_v77::upgrade,
+            )
+            .await
+        }
+        77 => {
+            run_versioned_upgrade(
+                unopened_catalog_state,
+                version,
+                commit_ts,
+                v77_to_v78::upgrade,
+            )
+            .await
+        }
 
         // Up-to-date, no migration needed!
         CATALOG_VERSION => Ok((CATALOG_VERSION, commit_ts)),
@@ -0,0 +1,18 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::durable::upgrade::MigrationAction;
+use crate::durable::upgrade::{objects_v77 as v77, objects_v78 as v78};
+
+/// In v78, we add the replication slots collection.
+pub fn upgrade(
+    _snapshot: Vec<v77::StateUpdateKind>,
+) -> Vec<MigrationAction<v77::StateUpdateKind, v78::StateUpdateKind>> {
+    vec![]
+}
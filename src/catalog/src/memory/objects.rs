@@ -51,8 +51,8 @@ use mz_sql::names::{
 use mz_sql::plan::{
     ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
     CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant, CreateSourcePlan,
-    HirRelationExpr, Ingestion as PlanIngestion, NetworkPolicyRule, PlanError, WebhookBodyFormat,
-    WebhookHeaders, WebhookValidation,
+    EndpointQuery, HirRelationExpr, Ingestion as PlanIngestion, NetworkPolicyRule, PlanError,
+    WebhookBodyFormat, WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -634,6 +634,86 @@ impl From<SourceReference> for mz_sql::plan::SourceReference {
     }
 }
 
+/// The persisted state of a durable, named `SUBSCRIBE`.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct DurableSubscription {
+    /// The role that created the subscription.
+    pub owner_id: RoleId,
+    /// The collections the subscription reads from.
+    pub dependencies: Vec<GlobalId>,
+    /// The frontier up to which all updates have been acknowledged by a client.
+    pub frontier: Timestamp,
+}
+
+impl DurableSubscription {
+    pub fn to_durable(self, name: String) -> durable::DurableSubscription {
+        durable::DurableSubscription {
+            name,
+            owner_id: self.owner_id,
+            dependencies: self.dependencies,
+            frontier: self.frontier,
+        }
+    }
+}
+
+impl From<durable::DurableSubscription> for DurableSubscription {
+    fn from(
+        durable::DurableSubscription {
+            name: _,
+            owner_id,
+            dependencies,
+            frontier,
+        }: durable::DurableSubscription,
+    ) -> DurableSubscription {
+        DurableSubscription {
+            owner_id,
+            dependencies,
+            frontier,
+        }
+    }
+}
+
+/// The persisted state of a logical replication slot.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ReplicationSlot {
+    /// The role that created the slot.
+    pub owner_id: RoleId,
+    /// The relation the slot streams, which is bound on the first `START_REPLICATION` of the
+    /// slot.
+    pub publication: Option<GlobalId>,
+    /// The LSN through which the client of the slot has confirmed flushing the changes sent to
+    /// it.
+    pub confirmed_flush: Timestamp,
+}
+
+impl ReplicationSlot {
+    pub fn to_durable(self, name: String) -> durable::ReplicationSlot {
+        durable::ReplicationSlot {
+            name,
+            owner_id: self.owner_id,
+            publication: self.publication,
+            confirmed_flush: self.confirmed_flush,
+        }
+    }
+}
+
+impl From<durable::ReplicationSlot> for ReplicationSlot {
+    fn from(
+        durable::ReplicationSlot {
+            name: _,
+            owner_id,
+            publication,
+            confirmed_flush,
+        }: durable::ReplicationSlot,
+    ) -> ReplicationSlot {
+        ReplicationSlot {
+            owner_id,
+            publication,
+            confirmed_flush,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct CatalogEntry {
     pub item: CatalogItem,
@@ -702,6 +782,7 @@ impl mz_sql::catalog::CatalogCollectionItem for CatalogCollectionEntry {
             CatalogItem::Secret(secret) => secret.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
             CatalogItem::ContinualTask(ct) => ct.global_id,
+            CatalogItem::Endpoint(endpoint) => endpoint.global_id,
             CatalogItem::Table(table) => match self.version {
                 RelationVersionSelector::Latest => {
                     let (_version, gid) = table
@@ -776,10 +857,18 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.writable_table_details()
     }
 
+    fn writable_table_domains(&self) -> Option<&[Option<CatalogItemId>]> {
+        self.entry.writable_table_domains()
+    }
+
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>> {
         self.entry.type_details()
     }
 
+    fn endpoint_query(&self) -> Option<&EndpointQuery> {
+        self.entry.endpoint_query()
+    }
+
     fn references(&self) -> &ResolvedIds {
         self.entry.references()
     }
@@ -862,6 +951,7 @@ pub enum CatalogItem {
     Secret(Secret),
     Connection(Connection),
     ContinualTask(ContinualTask),
+    Endpoint(Endpoint),
 }
 
 impl From<CatalogEntry> for durable::Item {
@@ -960,6 +1050,9 @@ pub enum TableDataSource {
     TableWrites {
         #[serde(skip)]
         defaults: Vec<Expr<Aug>>,
+        /// The domain type of each column, if any.
+        #[serde(skip)]
+        domains: Vec<Option<CatalogItemId>>,
     },
 
     /// The table receives its data from the identified `DataSourceDesc`.
@@ -1482,6 +1575,21 @@ pub struct Connection {
     pub resolved_ids: ResolvedIds,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Endpoint {
+    /// Parse-able SQL that defines this endpoint.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this endpoint from outside the catalog.
+    pub global_id: GlobalId,
+    /// The query executed by `EXECUTE ENDPOINT`, planned from the `create_sql`.
+    #[serde(skip)]
+    pub query: EndpointQuery,
+    /// Other catalog objects that are referenced by this endpoint, determined at name resolution.
+    pub resolved_ids: ResolvedIds,
+    /// All of the catalog objects that are referenced by this endpoint.
+    pub dependencies: DependencyIds,
+}
+
 impl Connection {
     /// The single [`GlobalId`] used to reference this connection.
     pub fn global_id(&self) -> GlobalId {
@@ -1604,6 +1712,7 @@ impl CatalogItem {
             CatalogItem::Secret(_) => mz_sql::catalog::CatalogItemType::Secret,
             CatalogItem::Connection(_) => mz_sql::catalog::CatalogItemType::Connection,
             CatalogItem::ContinualTask(_) => mz_sql::catalog::CatalogItemType::ContinualTask,
+            CatalogItem::Endpoint(_) => mz_sql::catalog::CatalogItemType::Endpoint,
         }
     }
 
@@ -1621,6 +1730,7 @@ impl CatalogItem {
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
+            CatalogItem::Endpoint(endpoint) => endpoint.global_id,
             CatalogItem::Table(table) => {
                 return itertools::Either::Left(table.collections.values().copied());
             }
@@ -1644,6 +1754,7 @@ impl CatalogItem {
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
+            CatalogItem::Endpoint(endpoint) => endpoint.global_id,
             CatalogItem::Table(table) => table.global_id_writes(),
         }
     }
@@ -1662,6 +1773,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_) => false,
         }
     }
@@ -1691,6 +1803,7 @@ impl CatalogItem {
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_) => None,
         }
     }
@@ -1757,6 +1870,7 @@ impl CatalogItem {
             CatalogItem::Secret(_) => &*EMPTY,
             CatalogItem::Connection(connection) => &connection.resolved_ids,
             CatalogItem::ContinualTask(ct) => &ct.resolved_ids,
+            CatalogItem::Endpoint(endpoint) => &endpoint.resolved_ids,
         }
     }
 
@@ -1784,6 +1898,7 @@ impl CatalogItem {
             CatalogItem::ContinualTask(ct) => uses.extend(ct.dependencies.0.iter().copied()),
             CatalogItem::Secret(_) => {}
             CatalogItem::Connection(_) => {}
+            CatalogItem::Endpoint(endpoint) => uses.extend(endpoint.dependencies.0.iter().copied()),
         }
         uses
     }
@@ -1800,6 +1915,7 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Connection(_)
@@ -1888,6 +2004,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::ContinualTask(i))
             }
+            CatalogItem::Endpoint(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Endpoint(i))
+            }
         }
     }
 
@@ -1963,6 +2084,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::ContinualTask(i))
             }
+            CatalogItem::Endpoint(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Endpoint(i))
+            }
         }
     }
 
@@ -2073,6 +2199,70 @@ impl CatalogItem {
         Ok(next_version)
     }
 
+    pub fn alter_column_type(
+        &mut self,
+        name: ColumnName,
+        typ: ColumnType,
+        sql: RawDataType,
+    ) -> Result<RelationVersion, PlanError> {
+        let CatalogItem::Table(table) = self else {
+            return Err(PlanError::Unsupported {
+                feature: "altering columns of a non-Table".to_string(),
+                discussion_no: None,
+            });
+        };
+        let next_version = table.desc.alter_column_type(name.clone(), typ);
+
+        let update = |mut ast: &mut Statement<Raw>| match &mut ast {
+            Statement::CreateTable(stmt) => {
+                let column = live_column_mut(&mut stmt.columns, &name).ok_or(())?;
+                column.data_type = sql;
+                column.options.push(ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Versioned {
+                        action: ColumnVersioned::Altered,
+                        version: next_version.into(),
+                    },
+                });
+                Ok(())
+            }
+            _ => Err(()),
+        };
+
+        self.update_sql(update)
+            .map_err(|()| PlanError::Unstructured("expected CREATE TABLE statement".to_string()))?;
+        Ok(next_version)
+    }
+
+    pub fn drop_column(&mut self, name: ColumnName) -> Result<RelationVersion, PlanError> {
+        let CatalogItem::Table(table) = self else {
+            return Err(PlanError::Unsupported {
+                feature: "dropping columns of a non-Table".to_string(),
+                discussion_no: None,
+            });
+        };
+        let next_version = table.desc.drop_column(name.clone());
+
+        let update = |mut ast: &mut Statement<Raw>| match &mut ast {
+            Statement::CreateTable(stmt) => {
+                let column = live_column_mut(&mut stmt.columns, &name).ok_or(())?;
+                column.options.push(ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Versioned {
+                        action: ColumnVersioned::Dropped,
+                        version: next_version.into(),
+                    },
+                });
+                Ok(())
+            }
+            _ => Err(()),
+        };
+
+        self.update_sql(update)
+            .map_err(|()| PlanError::Unstructured("expected CREATE TABLE statement".to_string()))?;
+        Ok(next_version)
+    }
+
     /// Updates the create_sql field of this item. Returns an error if this is a builtin item,
     /// otherwise returns f's result.
     pub fn update_sql<F, T>(&mut self, f: F) -> Result<T, ()>
@@ -2089,7 +2279,8 @@ impl CatalogItem {
             | CatalogItem::Index(Index { create_sql, .. })
             | CatalogItem::Secret(Secret { create_sql, .. })
             | CatalogItem::Connection(Connection { create_sql, .. })
-            | CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => Some(create_sql),
+            | CatalogItem::ContinualTask(ContinualTask { create_sql, .. })
+            | CatalogItem::Endpoint(Endpoint { create_sql, .. }) => Some(create_sql),
             CatalogItem::Func(_) | CatalogItem::Log(_) => None,
         };
         let Some(create_sql) = create_sql else {
@@ -2124,6 +2315,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2150,6 +2342,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_) => None,
         }
     }
@@ -2168,6 +2361,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2190,6 +2384,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => return None,
         };
@@ -2216,6 +2411,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_) => return None,
         };
         Some(custom_logical_compaction_window.unwrap_or(CompactionWindow::Default))
@@ -2236,6 +2432,7 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => false,
         }
@@ -2293,6 +2490,11 @@ impl CatalogItem {
             CatalogItem::ContinualTask(ct) => {
                 (ct.create_sql.clone(), ct.global_id, BTreeMap::new())
             }
+            CatalogItem::Endpoint(endpoint) => (
+                endpoint.create_sql.clone(),
+                endpoint.global_id,
+                BTreeMap::new(),
+            ),
         }
     }
 
@@ -2335,10 +2537,33 @@ impl CatalogItem {
             }
             CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
             CatalogItem::ContinualTask(ct) => (ct.create_sql, ct.global_id, BTreeMap::new()),
+            CatalogItem::Endpoint(endpoint) => {
+                (endpoint.create_sql, endpoint.global_id, BTreeMap::new())
+            }
         }
     }
 }
 
+/// Returns the column named `name` in a `CREATE TABLE` statement that hasn't
+/// been dropped.
+fn live_column_mut<'a>(
+    columns: &'a mut [ColumnDef<Raw>],
+    name: &ColumnName,
+) -> Option<&'a mut ColumnDef<Raw>> {
+    columns.iter_mut().find(|column| {
+        let dropped = column.options.iter().any(|option| {
+            matches!(
+                option.option,
+                ColumnOption::Versioned {
+                    action: ColumnVersioned::Dropped,
+                    ..
+                }
+            )
+        });
+        column.name.as_str() == name.as_str() && !dropped
+    })
+}
+
 impl CatalogEntry {
     /// Reports the latest [`RelationDesc`] of the rows produced by this [`CatalogEntry`],
     /// returning an error if this [`CatalogEntry`] does not produce rows.
@@ -2536,6 +2761,7 @@ impl CatalogEntry {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Endpoint(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -3264,6 +3490,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
             CatalogItem::Func(_) => "<builtin>",
             CatalogItem::Log(_) => "<builtin>",
             CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => create_sql,
+            CatalogItem::Endpoint(Endpoint { create_sql, .. }) => create_sql,
         }
     }
 
@@ -3281,7 +3508,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
 
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]> {
         if let CatalogItem::Table(Table {
-            data_source: TableDataSource::TableWrites { defaults },
+            data_source: TableDataSource::TableWrites { defaults, .. },
             ..
         }) = self.item()
         {
@@ -3291,6 +3518,18 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn writable_table_domains(&self) -> Option<&[Option<CatalogItemId>]> {
+        if let CatalogItem::Table(Table {
+            data_source: TableDataSource::TableWrites { domains, .. },
+            ..
+        }) = self.item()
+        {
+            Some(domains.as_slice())
+        } else {
+            None
+        }
+    }
+
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>> {
         if let CatalogItem::Type(Type { details, .. }) = self.item() {
             Some(details)
@@ -3299,6 +3538,14 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn endpoint_query(&self) -> Option<&EndpointQuery> {
+        if let CatalogItem::Endpoint(Endpoint { query, .. }) = self.item() {
+            Some(query)
+        } else {
+            None
+        }
+    }
+
     fn references(&self) -> &ResolvedIds {
         self.references()
     }
@@ -3398,6 +3645,8 @@ pub enum StateUpdateKind {
     TemporaryItem(TemporaryItem),
     Item(durable::objects::Item),
     Comment(durable::objects::Comment),
+    DurableSubscription(durable::objects::DurableSubscription),
+    ReplicationSlot(durable::objects::ReplicationSlot),
     AuditLog(durable::objects::AuditLog),
     // Storage updates.
     StorageCollectionMetadata(durable::objects::StorageCollectionMetadata),
@@ -3473,6 +3722,8 @@ pub enum BootstrapStateUpdateKind {
     SystemObjectMapping(durable::objects::SystemObjectMapping),
     Item(durable::objects::Item),
     Comment(durable::objects::Comment),
+    DurableSubscription(durable::objects::DurableSubscription),
+    ReplicationSlot(durable::objects::ReplicationSlot),
     AuditLog(durable::objects::AuditLog),
     // Storage updates.
     StorageCollectionMetadata(durable::objects::StorageCollectionMetadata),
@@ -3509,6 +3760,12 @@ impl From<BootstrapStateUpdateKind> for StateUpdateKind {
             }
             BootstrapStateUpdateKind::Item(kind) => StateUpdateKind::Item(kind),
             BootstrapStateUpdateKind::Comment(kind) => StateUpdateKind::Comment(kind),
+            BootstrapStateUpdateKind::DurableSubscription(kind) => {
+                StateUpdateKind::DurableSubscription(kind)
+            }
+            BootstrapStateUpdateKind::ReplicationSlot(kind) => {
+                StateUpdateKind::ReplicationSlot(kind)
+            }
             BootstrapStateUpdateKind::AuditLog(kind) => StateUpdateKind::AuditLog(kind),
             BootstrapStateUpdateKind::StorageCollectionMetadata(kind) => {
                 StateUpdateKind::StorageCollectionMetadata(kind)
@@ -3557,6 +3814,12 @@ impl TryFrom<StateUpdateKind> for BootstrapStateUpdateKind {
             StateUpdateKind::TemporaryItem(kind) => Err(kind),
             StateUpdateKind::Item(kind) => Ok(BootstrapStateUpdateKind::Item(kind)),
             StateUpdateKind::Comment(kind) => Ok(BootstrapStateUpdateKind::Comment(kind)),
+            StateUpdateKind::DurableSubscription(kind) => {
+                Ok(BootstrapStateUpdateKind::DurableSubscription(kind))
+            }
+            StateUpdateKind::ReplicationSlot(kind) => {
+                Ok(BootstrapStateUpdateKind::ReplicationSlot(kind))
+            }
             StateUpdateKind::AuditLog(kind) => Ok(BootstrapStateUpdateKind::AuditLog(kind)),
             StateUpdateKind::StorageCollectionMetadata(kind) => {
                 Ok(BootstrapStateUpdateKind::StorageCollectionMetadata(kind))
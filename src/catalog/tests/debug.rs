@@ -102,6 +102,8 @@ impl Debug for StableTrace<'_> {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
         } = self.0;
         let configs: CollectionTrace<ConfigCollection> = CollectionTrace {
             values: configs
@@ -145,6 +147,8 @@ impl Debug for StableTrace<'_> {
             .field("storage_collection_metadata", storage_collection_metadata)
             .field("unfinalized_shards", unfinalized_shards)
             .field("txn_wal_shard", txn_wal_shard)
+            .field("durable_subscriptions", durable_subscriptions)
+            .field("replication_slots", replication_slots)
             .finish()
     }
 }
@@ -91,6 +91,8 @@ impl Debug for StableSnapshot<'_> {
             storage_collection_metadata,
             unfinalized_shards,
             txn_wal_shard,
+            durable_subscriptions,
+            replication_slots,
         } = self.0;
         let mut configs: BTreeMap<proto::ConfigKey, proto::ConfigValue> = configs.clone();
         configs.remove(&Self::user_version_key());
@@ -119,6 +121,8 @@ impl Debug for StableSnapshot<'_> {
             .field("storage_collection_metadata", storage_collection_metadata)
             .field("unfinalized_shards", unfinalized_shards)
             .field("txn_wal_shard", txn_wal_shard)
+            .field("durable_subscriptions", durable_subscriptions)
+            .field("replication_slots", replication_slots)
             .finish()
     }
 }
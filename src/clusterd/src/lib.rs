@@ -30,7 +30,7 @@ use mz_ore::metrics::{MetricsRegistry, register_runtime_metrics};
 use mz_ore::netio::{Listener, SocketAddr};
 use mz_ore::now::SYSTEM_TIME;
 use mz_persist_client::cache::PersistClientCache;
-use mz_persist_client::cfg::PersistConfig;
+use mz_persist_client::cfg::{BlobKeyring, PersistConfig};
 use mz_persist_client::rpc::{GrpcPubSubClient, PersistPubSubClient, PersistPubSubClientConfig};
 use mz_service::emit_boot_diagnostics;
 use mz_service::grpc::{GrpcServer, GrpcServerMetrics, MAX_GRPC_MESSAGE_SIZE};
@@ -107,6 +107,9 @@ struct Args {
         default_value = "http://localhost:6879"
     )]
     persist_pubsub_url: String,
+    /// A file containing the keys to encrypt persist blob data with.
+    #[clap(long, env = "PERSIST_BLOB_ENCRYPTION_KEYRING", value_name = "PATH")]
+    persist_blob_encryption_keyring: Option<PathBuf>,
 
     // === Cloud options. ===
     /// An external ID to be supplied to all AWS AssumeRole operations.
@@ -312,6 +315,13 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), mz_dyncfgs::all_dyncfgs());
     persist_cfg.is_cc_active = args.is_cc;
     persist_cfg.announce_memory_limit = args.announce_memory_limit;
+    if let Some(path) = &args.persist_blob_encryption_keyring {
+        persist_cfg.blob_encryption_keyring = Some(Arc::new(BlobKeyring::load(path)?));
+    }
+    persist_cfg.blob_cache_disk_dir = args
+        .scratch_directory
+        .as_ref()
+        .map(|dir| dir.join("persist-blob-cache"));
     // Start with compaction disabled, will get enabled once a cluster receives AllowWrites.
     persist_cfg.disable_compaction();
 
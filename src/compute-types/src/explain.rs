@@ -71,7 +71,12 @@ impl<'a> DataflowDescription<Plan> {
             .iter_mut()
             .map(|(id, (source_desc, _, _upper))| {
                 let op = source_desc.arguments.operators.as_ref();
-                ExplainSource::new(*id, op, context.config.filter_pushdown)
+                ExplainSource::new(
+                    *id,
+                    op,
+                    context.config.filter_pushdown,
+                    context.features.persist_projection_pushdown,
+                )
             })
             .collect::<Vec<_>>();
 
@@ -139,7 +144,12 @@ impl<'a> DataflowDescription<OptimizedMirRelationExpr> {
             .iter_mut()
             .map(|(id, (source_desc, _, _upper))| {
                 let op = source_desc.arguments.operators.as_ref();
-                ExplainSource::new(*id, op, context.config.filter_pushdown)
+                ExplainSource::new(
+                    *id,
+                    op,
+                    context.config.filter_pushdown,
+                    context.features.persist_projection_pushdown,
+                )
             })
             .collect::<Vec<_>>();
 
@@ -1052,6 +1052,8 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::ArrayConcat { .. }
         | AggregateFunc::ListConcat { .. }
         | AggregateFunc::StringAgg { .. }
+        | AggregateFunc::RangeAgg
+        | AggregateFunc::RangeIntersectAgg
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::Rank { .. }
         | AggregateFunc::DenseRank { .. }
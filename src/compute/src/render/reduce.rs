@@ -2529,6 +2529,8 @@ mod monoids {
             | AggregateFunc::ArrayConcat { .. }
             | AggregateFunc::ListConcat { .. }
             | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
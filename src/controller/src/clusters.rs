@@ -637,6 +637,7 @@ where
         let aws_external_id_prefix = self.connection_context().aws_external_id_prefix.clone();
         let aws_connection_role_arn = self.connection_context().aws_connection_role_arn.clone();
         let persist_pubsub_url = self.persist_pubsub_url.clone();
+        let persist_blob_encryption_keyring = self.persist_blob_encryption_keyring.clone();
         let secrets_args = self.secrets_args.to_flags();
 
         let mut storage_proto_timely_config = None;
@@ -681,6 +682,12 @@ where
                         format!("--persist-pubsub-url={}", persist_pubsub_url),
                         format!("--environment-id={}", environment_id),
                     ];
+                    if let Some(keyring) = &persist_blob_encryption_keyring {
+                        args.push(format!(
+                            "--persist-blob-encryption-keyring={}",
+                            keyring.display()
+                        ));
+                    }
                     if let Some(aws_external_id_prefix) = &aws_external_id_prefix {
                         args.push(format!(
                             "--aws-external-id-prefix={}",
@@ -25,6 +25,7 @@ use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::mem;
 use std::num::NonZeroI64;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -102,6 +103,8 @@ pub struct ControllerConfig {
     pub metrics_registry: MetricsRegistry,
     /// The URL for Persist PubSub.
     pub persist_pubsub_url: String,
+    /// The file containing the keys to encrypt persist blob data with, if any.
+    pub persist_blob_encryption_keyring: Option<PathBuf>,
     /// Arguments for secrets readers.
     pub secrets_args: SecretsReaderCliArgs,
     /// The connection context, to thread through to clusterd, with cli flags.
@@ -178,6 +181,8 @@ pub struct Controller<T: ComputeControllerTimestamp = mz_repr::Timestamp> {
 
     /// The URL for Persist PubSub.
     persist_pubsub_url: String,
+    /// The file containing the keys to encrypt persist blob data with, if any.
+    persist_blob_encryption_keyring: Option<PathBuf>,
 
     /// Arguments for secrets readers.
     secrets_args: SecretsReaderCliArgs,
@@ -259,6 +264,7 @@ impl<T: ComputeControllerTimestamp> Controller<T> {
             metrics_rx: _,
             now: _,
             persist_pubsub_url: _,
+            persist_blob_encryption_keyring: _,
             secrets_args: _,
             unfulfilled_watch_sets_by_object: _,
             unfulfilled_watch_sets,
@@ -724,6 +730,7 @@ where
             metrics_rx,
             now: config.now,
             persist_pubsub_url: config.persist_pubsub_url,
+            persist_blob_encryption_keyring: config.persist_blob_encryption_keyring,
             secrets_args: config.secrets_args,
             unfulfilled_watch_sets_by_object: BTreeMap::new(),
             unfulfilled_watch_sets: BTreeMap::new(),
@@ -36,6 +36,7 @@ use mz_adapter_types::bootstrap_builtin_cluster_config::{
     SYSTEM_CLUSTER_DEFAULT_REPLICATION_FACTOR,
 };
 use mz_auth::password::Password;
+use mz_authenticator::{CertificateMappingRules, OidcCliArgs, OidcConfig};
 use mz_aws_secrets_controller::AwsSecretsController;
 use mz_build_info::BuildInfo;
 use mz_catalog::builtin::{
@@ -64,7 +65,7 @@ use mz_ore::task::RuntimeExt;
 use mz_ore::url::SensitiveUrl;
 use mz_persist_client::PersistLocation;
 use mz_persist_client::cache::PersistClientCache;
-use mz_persist_client::cfg::PersistConfig;
+use mz_persist_client::cfg::{BlobKeyring, PersistConfig};
 use mz_persist_client::rpc::{
     MetricsSameProcessPubSubSender, PersistGrpcPubSubServer, PubSubClientConnection, PubSubSender,
 };
@@ -81,7 +82,9 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 use url::Url;
 
 use crate::environmentd::sys;
-use crate::{BUILD_INFO, CatalogConfig, ListenerConfig, Listeners, ListenersConfig};
+use crate::{
+    AuthenticatorKind, BUILD_INFO, CatalogConfig, ListenerConfig, Listeners, ListenersConfig,
+};
 
 static VERSION: LazyLock<String> = LazyLock::new(|| BUILD_INFO.human_version(None));
 static LONG_VERSION: LazyLock<String> = LazyLock::new(|| {
@@ -126,6 +129,16 @@ pub struct Args {
         action = ArgAction::Set,
     )]
     external_login_password_mz_system: Option<Password>,
+    /// Path to a file containing the json-formatted rules that map TLS client
+    /// certificate identities to roles, for listeners that use certificate
+    /// authentication.
+    #[clap(
+        long,
+        env = "CERTIFICATE_AUTH_RULES_PATH",
+        value_name = "PATH",
+        action = ArgAction::Set,
+    )]
+    certificate_auth_rules_path: Option<PathBuf>,
     /// The address on which to listen for Persist PubSub connections.
     ///
     /// Connections to this address are not subject to encryption, authentication,
@@ -175,6 +188,9 @@ pub struct Args {
     /// Frontegg arguments.
     #[clap(flatten)]
     frontegg: FronteggCliArgs,
+    /// OIDC arguments.
+    #[clap(flatten)]
+    oidc: OidcCliArgs,
     // === Orchestrator options. ===
     /// The service orchestrator implementation to use.
     #[structopt(long, value_enum, env = "ORCHESTRATOR")]
@@ -378,6 +394,18 @@ pub struct Args {
     /// of threads returned by [`num_cpus::get`].
     #[clap(long, env = "PERSIST_ISOLATED_RUNTIME_THREADS")]
     persist_isolated_runtime_threads: Option<isize>,
+    /// A file containing the keys to encrypt persist blob data with.
+    ///
+    /// Each line holds a key id and the base64 encoding of a 256-bit key. New
+    /// data is encrypted with the key on the first line; the other keys are
+    /// only used to read data written before the keys were rotated. Data
+    /// written unencrypted is rejected unless the
+    /// `persist_blob_encryption_allow_plaintext` configuration is enabled.
+    ///
+    /// This path is passed to `clusterd`, so the file must be available to it
+    /// as well.
+    #[clap(long, env = "PERSIST_BLOB_ENCRYPTION_KEYRING", value_name = "PATH")]
+    persist_blob_encryption_keyring: Option<PathBuf>,
     /// The interval in seconds at which to collect storage usage information.
     #[clap(
         long,
@@ -393,8 +421,9 @@ pub struct Args {
     storage_usage_retention_period: Option<Duration>,
 
     // === Adapter options. ===
-    /// The PostgreSQL URL for the Postgres-backed timestamp oracle.
-    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "POSTGRES_URL")]
+    /// The URL of the durable backend of the timestamp oracle: a PostgreSQL
+    /// URL, or a `file://` URL naming a local directory.
+    #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "URL")]
     timestamp_oracle_url: Option<SensitiveUrl>,
     /// Availability zones in which storage and compute resources may be
     /// deployed.
@@ -764,10 +793,45 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     // Configure connections.
     let tls = args.tls.into_config()?;
     let frontegg = FronteggAuthenticator::from_args(args.frontegg, &metrics_registry)?;
+    let oidc = OidcConfig::from_args(args.oidc, SYSTEM_TIME.clone());
     let listeners_config: ListenersConfig = {
         let f = File::open(args.listeners_config_path)?;
         serde_json::from_reader(f)?
     };
+    let certificate_mapping_rules = match args.certificate_auth_rules_path {
+        Some(path) => {
+            let f = File::open(path)?;
+            CertificateMappingRules::new(serde_json::from_reader(f)?)?
+        }
+        None => CertificateMappingRules::default(),
+    };
+    let uses_authenticator = |kind| {
+        listeners_config
+            .sql
+            .values()
+            .map(|listener| listener.authenticator_kind())
+            .chain(
+                listeners_config
+                    .http
+                    .values()
+                    .map(|listener| listener.authenticator_kind()),
+            )
+            .chain(
+                listeners_config
+                    .flight
+                    .values()
+                    .map(|listener| listener.authenticator_kind()),
+            )
+            .any(|k| k == kind)
+    };
+    if uses_authenticator(AuthenticatorKind::Certificate)
+        && tls.as_ref().is_none_or(|tls| tls.client_ca.is_none())
+    {
+        bail!("certificate authentication requires --tls-client-ca");
+    }
+    if uses_authenticator(AuthenticatorKind::Oidc) && oidc.is_none() {
+        bail!("OIDC authentication requires --oidc-issuer and --oidc-jwks");
+    }
 
     // Configure CORS.
     let allowed_origins = if !args.cors_allowed_origin.is_empty() {
@@ -942,6 +1006,9 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, now.clone(), mz_dyncfgs::all_dyncfgs());
     // Start with compaction disabled, later enable it if we're not in read-only mode.
     persist_config.disable_compaction();
+    if let Some(path) = &args.persist_blob_encryption_keyring {
+        persist_config.blob_encryption_keyring = Some(Arc::new(BlobKeyring::load(path)?));
+    }
 
     let persist_pubsub_server = PersistGrpcPubSubServer::new(&persist_config, &metrics_registry);
     let persist_pubsub_client = persist_pubsub_server.new_same_process_connection();
@@ -1041,6 +1108,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         now: SYSTEM_TIME.clone(),
         metrics_registry: metrics_registry.clone(),
         persist_pubsub_url: args.persist_pubsub_url,
+        persist_blob_encryption_keyring: args.persist_blob_encryption_keyring,
         connection_context,
         // When serialized to args in the controller, only the relevant flags will be passed
         // through, so we just set all of them
@@ -1084,7 +1152,9 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
                 tls,
                 tls_reload_certs: mz_server_core::default_cert_reload_ticker(),
                 external_login_password_mz_system: args.external_login_password_mz_system,
+                certificate_mapping_rules,
                 frontegg,
+                oidc,
                 cors_allowed_origin,
                 egress_addresses: args.announce_egress_address,
                 http_host_name: args.http_host_name,
@@ -1185,6 +1255,9 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     for (name, handle) in &server.http_listener_handles {
         println!("{} HTTP address: {}", name, handle.local_addr);
     }
+    for (name, handle) in &server.flight_listener_handles {
+        println!("{} Flight SQL address: {}", name, handle.local_addr);
+    }
     // TODO move persist pubsub address like metrics address?
     println!(
         " Internal Persist PubSub address: {}",
@@ -0,0 +1,595 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Embedded Arrow Flight SQL server.
+//!
+//! Flight SQL clients authenticate the same way as HTTP clients: with a
+//! username and password in a basic `authorization` header, or with a bearer
+//! token. A successful handshake returns a bearer token that authenticates
+//! later calls without repeating the password.
+//!
+//! `GetFlightInfo` plans a query and returns the Arrow schema of its results
+//! along with a ticket that carries the query text. `DoGet` with that ticket
+//! executes the query in a fresh session and streams its results as Arrow
+//! record batches. `SUBSCRIBE` queries stream their updates for as long as
+//! the client keeps the `DoGet` call open.
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use arrow::record_batch::RecordBatch;
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::{CommandStatementQuery, ProstMessageExt, SqlInfo, TicketStatementQuery};
+use arrow_flight::{
+    FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest, HandshakeResponse, Ticket,
+};
+use async_trait::async_trait;
+use futures::{Stream, TryFutureExt, TryStreamExt};
+use headers::HeaderMapExt;
+use headers::authorization::{Authorization, Basic, Bearer};
+use hyper_openssl::SslStream;
+use hyper_openssl::client::legacy::MaybeHttpsStream;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::service::TowerToHyperService;
+use mz_adapter::client::RecordFirstRowStream;
+use mz_adapter::session::EndTransactionAction;
+use mz_adapter::statement_logging::StatementEndedExecutionReason;
+use mz_adapter::{
+    AdapterError, Client, ExecuteResponse, ExecuteResponseKind, PeekResponseUnary, SessionClient,
+    verify_datum_desc,
+};
+use mz_arrow_util::builder::ArrowBuilder;
+use mz_auth::password::Password;
+use mz_authenticator::Authenticator;
+use mz_ore::cast::CastFrom;
+use mz_ore::now::SYSTEM_TIME;
+use mz_ore::task;
+use mz_pgwire_common::ConnectionCounter;
+use mz_repr::{RelationDesc, RowIterator};
+use mz_server_core::listeners::AllowedRoles;
+use mz_server_core::{Connection, ConnectionHandler, ReloadingSslContext, Server};
+use mz_sql::ast::{Raw, Statement};
+use mz_sql::parse::StatementParseResult;
+use openssl::ssl::Ssl;
+use openssl::x509::{X509, X509VerifyResult};
+use prost::Message;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
+use tonic::{Request, Response, Status, Streaming};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::http::{self, AuthError, AuthedClient, AuthedUser, Credentials};
+
+/// How long a token issued by a handshake remains valid.
+const TOKEN_DURATION: Duration = Duration::from_secs(3600); // 1 hour
+
+/// The maximum number of rows in each record batch sent to clients.
+const MAX_BATCH_ROWS: usize = 8192;
+
+/// The number of record batches buffered for each `DoGet` call before
+/// execution waits for the client to catch up.
+const BATCH_BUFFER: usize = 4;
+
+const EMPTY_PORTAL: &str = "";
+
+#[derive(Debug)]
+pub struct FlightConfig {
+    pub source: &'static str,
+    pub tls: Option<ReloadingSslContext>,
+    pub authenticator: Authenticator,
+    pub adapter_client: Client,
+    pub active_connection_counter: ConnectionCounter,
+    pub helm_chart_version: Option<String>,
+    pub allowed_roles: AllowedRoles,
+}
+
+pub struct FlightServer {
+    tls: Option<ReloadingSslContext>,
+    state: Arc<FlightState>,
+}
+
+/// State shared by all connections to a [`FlightServer`].
+struct FlightState {
+    authenticator: Authenticator,
+    adapter_client: Client,
+    active_connection_counter: ConnectionCounter,
+    helm_chart_version: Option<String>,
+    allowed_roles: AllowedRoles,
+    /// The users authenticated by each outstanding handshake token, and when
+    /// the token was issued.
+    tokens: Mutex<BTreeMap<String, (AuthedUser, Instant)>>,
+}
+
+impl FlightServer {
+    pub fn new(
+        FlightConfig {
+            source: _,
+            tls,
+            authenticator,
+            adapter_client,
+            active_connection_counter,
+            helm_chart_version,
+            allowed_roles,
+        }: FlightConfig,
+    ) -> Self {
+        FlightServer {
+            tls,
+            state: Arc::new(FlightState {
+                authenticator,
+                adapter_client,
+                active_connection_counter,
+                helm_chart_version,
+                allowed_roles,
+                tokens: Mutex::new(BTreeMap::new()),
+            }),
+        }
+    }
+}
+
+impl Server for FlightServer {
+    const NAME: &'static str = "flight";
+
+    fn handle_connection(&self, conn: Connection) -> ConnectionHandler {
+        let state = Arc::clone(&self.state);
+        let tls_context = self.tls.clone();
+        let mut conn = TokioIo::new(conn);
+
+        Box::pin(async {
+            let direct_peer_addr = conn.inner().peer_addr().context("fetching peer addr")?;
+            let peer_addr = conn
+                .inner_mut()
+                .take_proxy_header_address()
+                .await
+                .map(|a| a.source)
+                .unwrap_or(direct_peer_addr);
+
+            let (conn, peer_certificate) = match tls_context {
+                Some(tls_context) => {
+                    let mut ssl_stream = SslStream::new(Ssl::new(&tls_context.get())?, conn)?;
+                    if let Err(e) = Pin::new(&mut ssl_stream).accept().await {
+                        let _ = ssl_stream.get_mut().inner_mut().shutdown().await;
+                        return Err(e.into());
+                    }
+                    // Only certificates that chain to the configured client CA
+                    // are made available for authentication.
+                    let peer_certificate = match ssl_stream.ssl().verify_result() {
+                        X509VerifyResult::OK => ssl_stream.ssl().peer_certificate(),
+                        _ => None,
+                    };
+                    (MaybeHttpsStream::Https(ssl_stream), peer_certificate)
+                }
+                _ => (MaybeHttpsStream::Http(conn), None),
+            };
+            let handler = FlightSqlHandler {
+                state,
+                peer_addr: peer_addr.ip(),
+                peer_certificate,
+            };
+            let svc = TowerToHyperService::new(FlightServiceServer::new(handler));
+            hyper::server::conn::http2::Builder::new(TokioExecutor::new())
+                .serve_connection(conn, svc)
+                .err_into()
+                .await
+        })
+    }
+}
+
+/// Serves the Flight SQL calls made over one connection.
+struct FlightSqlHandler {
+    state: Arc<FlightState>,
+    peer_addr: IpAddr,
+    /// The verified certificate the client presented, if any.
+    peer_certificate: Option<X509>,
+}
+
+impl FlightSqlHandler {
+    /// Authenticates the user that made `request`.
+    async fn authenticate<T>(&self, request: &Request<T>) -> Result<AuthedUser, Status> {
+        let headers = request.metadata().clone().into_headers();
+        let creds = if let Some(basic) = headers.typed_get::<Authorization<Basic>>() {
+            Some(Credentials::Password {
+                username: basic.username().to_owned(),
+                password: Password(basic.password().to_owned()),
+            })
+        } else if let Some(bearer) = headers.typed_get::<Authorization<Bearer>>() {
+            if let Some(user) = self.state.token_user(bearer.token()) {
+                return Ok(user);
+            }
+            Some(Credentials::Token {
+                token: bearer.token().to_owned(),
+            })
+        } else {
+            None
+        };
+
+        let allowed_roles = self.state.allowed_roles;
+        let user = match (&self.state.authenticator, creds) {
+            (
+                Authenticator::Password(adapter_client),
+                Some(Credentials::Password { username, password }),
+            ) => {
+                if let Err(err) = adapter_client.authenticate(&username, &password).await {
+                    warn!(?err, "Flight SQL request failed authentication");
+                    return Err(Status::unauthenticated("unauthorized"));
+                }
+                http::check_role_allowed(&username, allowed_roles).map(|()| AuthedUser {
+                    name: username,
+                    external_metadata_rx: None,
+                })
+            }
+            // Password logins are only valid until their token expires.
+            (Authenticator::Password(_), Some(Credentials::Token { .. })) => {
+                Err(AuthError::SessionExpired)
+            }
+            (Authenticator::Password(_), None) => Err(AuthError::MissingHttpAuthentication),
+            (authenticator, creds) => {
+                http::auth(
+                    authenticator,
+                    creds,
+                    self.peer_certificate.as_deref(),
+                    allowed_roles,
+                )
+                .await
+            }
+        };
+        user.map_err(|err| {
+            warn!("Flight SQL request failed authentication: {}", err);
+            // We omit detail from the error message we send to the client, to
+            // avoid giving attackers unnecessary information.
+            Status::unauthenticated("unauthorized")
+        })
+    }
+
+    /// Authenticates the user that made `request` and starts a session for
+    /// them.
+    async fn session<T>(&self, request: &Request<T>) -> Result<AuthedClient, Status> {
+        let user = self.authenticate(request).await?;
+        AuthedClient::new(
+            &self.state.adapter_client,
+            user,
+            self.peer_addr,
+            self.state.active_connection_counter.clone(),
+            self.state.helm_chart_version.clone(),
+            |_session| {},
+            BTreeMap::new(),
+            SYSTEM_TIME.clone(),
+        )
+        .await
+        .map_err(adapter_error)
+    }
+}
+
+impl FlightState {
+    /// Returns the user authenticated by a handshake token, if the token is
+    /// known and has not expired.
+    fn token_user(&self, token: &str) -> Option<AuthedUser> {
+        let tokens = self.tokens.lock().expect("lock poisoned");
+        match tokens.get(token) {
+            Some((user, issued_at)) if issued_at.elapsed() < TOKEN_DURATION => Some(user.clone()),
+            _ => None,
+        }
+    }
+
+    /// Issues a new handshake token that authenticates `user`.
+    fn issue_token(&self, user: AuthedUser) -> String {
+        let token = Uuid::new_v4().to_string();
+        let mut tokens = self.tokens.lock().expect("lock poisoned");
+        tokens.retain(|_, (_, issued_at)| issued_at.elapsed() < TOKEN_DURATION);
+        tokens.insert(token.clone(), (user, Instant::now()));
+        token
+    }
+}
+
+#[async_trait]
+impl FlightSqlService for FlightSqlHandler {
+    type FlightService = Self;
+
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        let user = self.authenticate(&request).await?;
+        let token = self.state.issue_token(user);
+        let handshake = HandshakeResponse {
+            protocol_version: 0,
+            payload: token.clone().into(),
+        };
+        let stream = futures::stream::iter([Ok(handshake)]);
+        let mut response: Response<Pin<Box<dyn Stream<Item = _> + Send>>> =
+            Response::new(Box::pin(stream));
+        let header = format!("Bearer {token}")
+            .parse()
+            .map_err(|_| Status::internal("invalid token"))?;
+        response.metadata_mut().insert("authorization", header);
+        Ok(response)
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        if query.transaction_id.is_some() {
+            return Err(Status::unimplemented("transactions are not supported"));
+        }
+        let mut client = self.session(&request).await?;
+        let client = &mut client.client;
+        let (stmt, sql) = parse(client, &query.query)?;
+        client
+            .prepare(EMPTY_PORTAL.into(), Some(stmt), sql, vec![])
+            .await
+            .map_err(adapter_error)?;
+        let desc = client
+            .get_prepared_statement(EMPTY_PORTAL)
+            .await
+            .map_err(adapter_error)?
+            .desc();
+        if !desc.param_types.is_empty() {
+            return Err(Status::invalid_argument(
+                "query parameters are not supported",
+            ));
+        }
+        let Some(relation_desc) = &desc.relation_desc else {
+            return Err(Status::invalid_argument("statement does not return rows"));
+        };
+        let schema = ArrowBuilder::new(relation_desc, 0, 0)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?
+            .schema();
+
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.into(),
+        };
+        let ticket = Ticket::new(ticket.as_any().encode_to_vec());
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(|e| Status::internal(e.to_string()))?
+            .with_endpoint(FlightEndpoint::new().with_ticket(ticket))
+            .with_descriptor(request.into_inner());
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let sql = String::from_utf8(ticket.statement_handle.to_vec())
+            .map_err(|_| Status::invalid_argument("invalid ticket"))?;
+        let mut client = self.session(&request).await?;
+        let session_client = &mut client.client;
+        let (stmt, sql) = parse(session_client, &sql)?;
+        session_client
+            .start_transaction(Some(1))
+            .map_err(adapter_error)?;
+        session_client
+            .declare(EMPTY_PORTAL.into(), stmt, sql)
+            .await
+            .map_err(adapter_error)?;
+        let desc = session_client
+            .session()
+            // We do not need to verify here because `client.execute` verifies below.
+            .get_portal_unverified(EMPTY_PORTAL)
+            .and_then(|portal| portal.desc.relation_desc.clone())
+            .ok_or_else(|| Status::invalid_argument("statement does not return rows"))?;
+        let schema = ArrowBuilder::new(&desc, 0, 0)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?
+            .schema();
+
+        let (res, execute_started) = session_client
+            .execute(EMPTY_PORTAL.into(), futures::future::pending(), None)
+            .await
+            .map_err(adapter_error)?;
+        let (rows, ctx_extra) = match res {
+            ExecuteResponse::SendingRowsStreaming {
+                rows,
+                instance_id,
+                strategy,
+            } => {
+                let rows = RecordFirstRowStream::new(
+                    Box::new(rows),
+                    execute_started,
+                    session_client,
+                    Some(instance_id),
+                    Some(strategy),
+                );
+                (rows, None)
+            }
+            ExecuteResponse::SendingRowsImmediate { rows } => {
+                let rows =
+                    futures::stream::once(futures::future::ready(PeekResponseUnary::Rows(rows)));
+                let rows = RecordFirstRowStream::new(
+                    Box::new(rows),
+                    execute_started,
+                    session_client,
+                    None,
+                    None,
+                );
+                (rows, None)
+            }
+            ExecuteResponse::Subscribing {
+                rx,
+                ctx_extra,
+                instance_id,
+            } => {
+                let rows = RecordFirstRowStream::new(
+                    Box::new(UnboundedReceiverStream::new(rx)),
+                    execute_started,
+                    session_client,
+                    Some(instance_id),
+                    None,
+                );
+                (rows, Some(ctx_extra))
+            }
+            res => {
+                return Err(Status::invalid_argument(format!(
+                    "unsupported statement response: {:?}",
+                    ExecuteResponseKind::from(res)
+                )));
+            }
+        };
+
+        // Execution continues in a task that owns the session, so that the
+        // session lives for as long as the client reads results.
+        let (tx, rx) = mpsc::channel(BATCH_BUFFER);
+        task::spawn(|| "flight_do_get", async move {
+            let client = &mut client.client;
+            let reason = send_results(&desc, rows, &tx).await;
+            let action = match reason {
+                StatementEndedExecutionReason::Success { .. } => EndTransactionAction::Commit,
+                _ => EndTransactionAction::Rollback,
+            };
+            if let Some(ctx_extra) = ctx_extra {
+                client.retire_execute(ctx_extra, reason);
+            }
+            if client.session().transaction().is_implicit() {
+                if let Err(err) = client.end_transaction(action).await {
+                    let _ = tx.send(Err(external_error(err.to_string()))).await;
+                }
+            }
+        });
+
+        let stream = FlightDataEncoderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .build(ReceiverStream::new(rx))
+            .map_err(Status::from);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+/// Parses `sql`, which must contain exactly one statement.
+fn parse(client: &SessionClient, sql: &str) -> Result<(Statement<Raw>, String), Status> {
+    let mut stmts = client
+        .parse(sql)
+        .map_err(Status::invalid_argument)?
+        .map_err(|e| adapter_error(e.into()))?;
+    if stmts.len() != 1 {
+        return Err(Status::invalid_argument(format!(
+            "query must contain exactly 1 statement, but contains {}",
+            stmts.len()
+        )));
+    }
+    let StatementParseResult { ast, sql } = stmts.pop().expect("known to exist");
+    Ok((ast, sql.to_string()))
+}
+
+/// Sends the results of a statement to `tx` as record batches, returning why
+/// execution ended.
+async fn send_results(
+    desc: &RelationDesc,
+    mut rows: RecordFirstRowStream,
+    tx: &mpsc::Sender<Result<RecordBatch, FlightError>>,
+) -> StatementEndedExecutionReason {
+    let mut rows_returned = 0;
+    let mut result_size = 0;
+    loop {
+        let response = tokio::select! {
+            // The client hanging up cancels the statement, matching the
+            // behavior for pgwire.
+            _ = tx.closed() => return StatementEndedExecutionReason::Canceled,
+            response = rows.recv() => response,
+        };
+        let error = match response {
+            Some(PeekResponseUnary::Rows(mut rows)) => {
+                match send_rows(desc, &mut *rows, tx).await {
+                    Ok((rows, bytes)) => {
+                        rows_returned += rows;
+                        result_size += bytes;
+                        continue;
+                    }
+                    Err(SendError::Canceled) => return StatementEndedExecutionReason::Canceled,
+                    Err(SendError::Errored(error)) => error,
+                }
+            }
+            Some(PeekResponseUnary::Error(error)) => error,
+            Some(PeekResponseUnary::Canceled) => {
+                let _ = tx
+                    .send(Err(external_error(AdapterError::Canceled.to_string())))
+                    .await;
+                return StatementEndedExecutionReason::Canceled;
+            }
+            None => break,
+        };
+        let _ = tx.send(Err(external_error(error.clone()))).await;
+        return StatementEndedExecutionReason::Errored { error };
+    }
+    StatementEndedExecutionReason::Success {
+        result_size: Some(u64::cast_from(result_size)),
+        rows_returned: Some(u64::cast_from(rows_returned)),
+        execution_strategy: None,
+    }
+}
+
+/// Why sending a batch of rows stopped early.
+enum SendError {
+    /// The client went away.
+    Canceled,
+    /// The rows could not be encoded.
+    Errored(String),
+}
+
+/// Encodes `rows` as record batches of at most [`MAX_BATCH_ROWS`] rows and
+/// sends them to `tx`, returning the number of rows and bytes sent.
+async fn send_rows(
+    desc: &RelationDesc,
+    rows: &mut (dyn RowIterator + Send + Sync),
+    tx: &mpsc::Sender<Result<RecordBatch, FlightError>>,
+) -> Result<(usize, usize), SendError> {
+    verify_datum_desc(desc, rows).map_err(|e| SendError::Errored(e.to_string()))?;
+    let new_builder = || {
+        ArrowBuilder::new(desc, MAX_BATCH_ROWS, 0).map_err(|e| SendError::Errored(e.to_string()))
+    };
+    let mut builder = new_builder()?;
+    let mut batch_rows = 0;
+    let mut rows_sent = 0;
+    let mut bytes_sent = 0;
+    while let Some(row) = rows.next() {
+        builder
+            .add_row(row)
+            .map_err(|e| SendError::Errored(e.to_string()))?;
+        batch_rows += 1;
+        if batch_rows == MAX_BATCH_ROWS || rows.peek().is_none() {
+            let full = std::mem::replace(&mut builder, new_builder()?);
+            rows_sent += batch_rows;
+            bytes_sent += full.row_size_bytes();
+            batch_rows = 0;
+            let batch = full
+                .to_record_batch()
+                .map_err(|e| SendError::Errored(e.to_string()))?;
+            tx.send(Ok(batch)).await.map_err(|_| SendError::Canceled)?;
+        }
+    }
+    Ok((rows_sent, bytes_sent))
+}
+
+fn external_error(message: String) -> FlightError {
+    FlightError::ExternalError(message.into())
+}
+
+fn adapter_error(err: AdapterError) -> Status {
+    match err {
+        AdapterError::UserSessionsDisallowed | AdapterError::NetworkPolicyDenied(_) => {
+            Status::permission_denied(err.to_string())
+        }
+        _ => Status::invalid_argument(err.to_string()),
+    }
+}
@@ -43,7 +43,7 @@ use hyper_util::rt::TokioIo;
 use mz_adapter::session::{Session as AdapterSession, SessionConfig as AdapterSessionConfig};
 use mz_adapter::{AdapterError, AdapterNotice, Client, SessionClient, WebhookAppenderCache};
 use mz_auth::password::Password;
-use mz_authenticator::Authenticator;
+use mz_authenticator::{Authenticator, CertificateError, OidcError};
 use mz_frontegg_auth::Error as FronteggError;
 use mz_http_util::DynamicFilterTarget;
 use mz_ore::cast::u64_to_usize;
@@ -60,6 +60,7 @@ use mz_sql::session::user::{
 };
 use mz_sql::session::vars::{Value, Var, VarInput, WELCOME_MESSAGE};
 use openssl::ssl::Ssl;
+use openssl::x509::{X509, X509Ref, X509VerifyResult};
 use prometheus::{
     COMPUTE_METRIC_QUERIES, FRONTIER_METRIC_QUERIES, STORAGE_METRIC_QUERIES, USAGE_METRIC_QUERIES,
 };
@@ -198,6 +199,8 @@ impl HttpServer {
                     }),
                 )
                 .route("/api/sql", routing::post(sql::handle_sql))
+                .route("/api/subscribe", routing::get(sql::handle_subscribe_sse))
+                .route("/api/endpoints/:name", routing::get(sql::handle_endpoint))
                 .route("/memory", routing::get(memory::handle_memory))
                 .route(
                     "/hierarchical-memory",
@@ -214,8 +217,19 @@ impl HttpServer {
                     helm_chart_version,
                     allowed_roles,
                 });
-            if let AuthenticatorKind::None = authenticator_kind {
-                ws_router = ws_router.layer(middleware::from_fn(x_materialize_user_header_auth));
+            match authenticator_kind {
+                AuthenticatorKind::None => {
+                    ws_router =
+                        ws_router.layer(middleware::from_fn(x_materialize_user_header_auth));
+                }
+                // The certificate is only available from the connection, so
+                // authenticate before upgrading to a WebSocket.
+                AuthenticatorKind::Certificate => {
+                    ws_router = ws_router.layer(auth_middleware.clone());
+                }
+                AuthenticatorKind::Frontegg
+                | AuthenticatorKind::Password
+                | AuthenticatorKind::Oidc => {}
             }
             router = router.merge(ws_router);
         }
@@ -389,6 +403,7 @@ impl HttpServer {
                         AUTHORIZATION,
                         CONTENT_TYPE,
                         HeaderName::from_static("x-materialize-version"),
+                        HeaderName::from_static("last-event-id"),
                     ])
                     .allow_methods(Any)
                     .allow_origin(allowed_origin)
@@ -445,7 +460,16 @@ impl Server for HttpServer {
                         let _ = ssl_stream.get_mut().inner_mut().shutdown().await;
                         return Err(e.into());
                     }
-                    (MaybeHttpsStream::Https(ssl_stream), ConnProtocol::Https)
+                    // Only certificates that chain to the configured client CA
+                    // are made available for authentication.
+                    let peer_certificate = match ssl_stream.ssl().verify_result() {
+                        X509VerifyResult::OK => ssl_stream.ssl().peer_certificate(),
+                        _ => None,
+                    };
+                    (
+                        MaybeHttpsStream::Https(ssl_stream),
+                        ConnProtocol::Https { peer_certificate },
+                    )
                 }
                 _ => (MaybeHttpsStream::Http(conn), ConnProtocol::Http),
             };
@@ -534,13 +558,16 @@ type Delayed<T> = Shared<oneshot::Receiver<T>>;
 #[derive(Clone)]
 enum ConnProtocol {
     Http,
-    Https,
+    Https {
+        /// The verified certificate the client presented, if any.
+        peer_certificate: Option<X509>,
+    },
 }
 
 #[derive(Clone, Debug)]
 pub struct AuthedUser {
-    name: String,
-    external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
+    pub(crate) name: String,
+    pub(crate) external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
 }
 
 pub struct AuthedClient {
@@ -549,7 +576,7 @@ pub struct AuthedClient {
 }
 
 impl AuthedClient {
-    async fn new<F>(
+    pub(crate) async fn new<F>(
         adapter_client: &Client,
         user: AuthedUser,
         peer_addr: IpAddr,
@@ -684,7 +711,7 @@ where
 }
 
 #[derive(Debug, Error)]
-enum AuthError {
+pub(crate) enum AuthError {
     #[error("HTTPS is required")]
     HttpsRequired,
     #[error("invalid username in client certificate")]
@@ -697,6 +724,10 @@ enum AuthError {
     MismatchedUser(String),
     #[error("session expired")]
     SessionExpired,
+    #[error("{0}")]
+    Certificate(#[from] CertificateError),
+    #[error("{0}")]
+    Oidc(#[from] OidcError),
     #[error("failed to update session")]
     FailedToUpdateSession,
 }
@@ -826,7 +857,11 @@ async fn http_auth(
         None
     };
 
-    let user = auth(&authenticator, creds, allowed_roles).await?;
+    let peer_certificate = match conn_protocol {
+        ConnProtocol::Https { peer_certificate } => peer_certificate.as_deref(),
+        ConnProtocol::Http => None,
+    };
+    let user = auth(&authenticator, creds, peer_certificate, allowed_roles).await?;
 
     // Add the authenticated user as an extension so downstream handlers can
     // inspect it if necessary.
@@ -898,7 +933,7 @@ async fn init_ws(
                 anyhow::bail!("expected auth information");
             }
         };
-        let user = auth(&authenticator, Some(creds), *allowed_roles).await?;
+        let user = auth(&authenticator, Some(creds), None, *allowed_roles).await?;
         (user, options)
     };
 
@@ -917,7 +952,7 @@ async fn init_ws(
     Ok(client)
 }
 
-enum Credentials {
+pub(crate) enum Credentials {
     Password {
         username: String,
         password: Password,
@@ -927,9 +962,10 @@ enum Credentials {
     },
 }
 
-async fn auth(
+pub(crate) async fn auth(
     authenticator: &Authenticator,
     creds: Option<Credentials>,
+    peer_certificate: Option<&X509Ref>,
     allowed_roles: AllowedRoles,
 ) -> Result<AuthedUser, AuthError> {
     // TODO pass session data here?
@@ -956,6 +992,32 @@ async fn auth(
             // TODO tell the user to login here?
             return Err(AuthError::MissingHttpAuthentication);
         }
+        Authenticator::Certificate(certificate) => {
+            // The client may name the role to log in as in the HTTP auth
+            // header (the password is ignored). Otherwise the first rule that
+            // matches the certificate decides.
+            let requested_role = match &creds {
+                Some(Credentials::Password { username, .. }) => Some(username.as_str()),
+                _ => None,
+            };
+            let login = certificate
+                .authenticate(peer_certificate, requested_role)
+                .await?;
+            (login.role_name, None)
+        }
+        Authenticator::Oidc(oidc) => {
+            // The token may be sent as a bearer token, or as the password in
+            // the HTTP auth header, in which case it must be for the named
+            // role.
+            let login = match &creds {
+                Some(Credentials::Token { token }) => oidc.authenticate(token, None).await?,
+                Some(Credentials::Password { username, password }) => {
+                    oidc.authenticate(&password.0, Some(username)).await?
+                }
+                None => return Err(AuthError::MissingHttpAuthentication),
+            };
+            (login.role_name, None)
+        }
         Authenticator::None => {
             // If no authentication, use whatever is in the HTTP auth
             // header (without checking the password), or fall back to the
@@ -977,7 +1039,7 @@ async fn auth(
 }
 
 // TODO move this somewhere it can be shared with PGWIRE
-fn check_role_allowed(name: &str, allowed_roles: AllowedRoles) -> Result<(), AuthError> {
+pub(crate) fn check_role_allowed(name: &str, allowed_roles: AllowedRoles) -> Result<(), AuthError> {
     let is_internal_user = INTERNAL_USER_NAMES.contains(name);
     // this is a superset of internal users
     let is_reserved_user = mz_adapter::catalog::is_reserved_role_name(name);
@@ -9,6 +9,7 @@
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::convert::Infallible;
 use std::net::{IpAddr, SocketAddr};
 use std::pin::pin;
 use std::sync::Arc;
@@ -18,13 +19,13 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use axum::extract::connect_info::ConnectInfo;
 use axum::extract::ws::{CloseFrame, Message, WebSocket};
-use axum::extract::{State, WebSocketUpgrade};
+use axum::extract::{Path, Query, State, WebSocketUpgrade};
 use axum::response::IntoResponse;
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::{Extension, Json};
-use futures::Future;
 use futures::future::BoxFuture;
-
-use http::StatusCode;
+use futures::{Future, StreamExt};
+use http::{HeaderMap, StatusCode};
 use itertools::izip;
 use mz_adapter::client::RecordFirstRowStream;
 use mz_adapter::session::{EndTransactionAction, TransactionStatus};
@@ -40,18 +41,24 @@ use mz_interchange::json::{JsonNumberPolicy, ToJson};
 use mz_ore::cast::CastFrom;
 use mz_ore::metrics::{MakeCollectorOpts, MetricsRegistry};
 use mz_ore::result::ResultExt;
-use mz_repr::{Datum, RelationDesc, RowArena, RowIterator};
+use mz_ore::task;
+use mz_repr::{Datum, RelationDesc, RowArena, RowIterator, Timestamp};
 use mz_sql::ast::display::AstDisplay;
-use mz_sql::ast::{CopyDirection, CopyStatement, CopyTarget, Raw, Statement, StatementKind};
-use mz_sql::parse::StatementParseResult;
+use mz_sql::ast::{
+    AsOf, CopyDirection, CopyStatement, CopyTarget, ExecuteEndpointStatement, Expr, Raw,
+    RawItemName, Statement, StatementKind, SubscribeOption, SubscribeOptionName, Value,
+    WithOptionValue,
+};
+use mz_sql::parse::{StatementParseResult, parse_item_name};
 use mz_sql::plan::Plan;
 use mz_sql::session::metadata::SessionMetadata;
 use prometheus::Opts;
 use prometheus::core::{AtomicF64, GenericGaugeVec};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 use tokio::{select, time};
 use tokio_postgres::error::SqlState;
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
 use tower_sessions::Session as TowerSession;
 use tracing::{debug, error, info};
 use tungstenite::protocol::frame::coding::CloseCode;
@@ -325,6 +332,137 @@ pub async fn handle_sql_ws(
         .on_upgrade(|ws| async move { run_ws(&state, user, *addr, ws).await }))
 }
 
+/// The number of server-sent events to buffer for a slow client before
+/// applying backpressure to the `SUBSCRIBE`.
+const SSE_BUFFER: usize = 1024;
+
+/// Executes the endpoint `name` with the values of the `param` query parameters,
+/// in order, as its arguments.
+///
+/// `name` is parsed as a possibly qualified SQL name, like `schema.endpoint`,
+/// and resolved against the session's search path like any other item name.
+/// Arguments are passed as strings and cast to the types of the endpoint's
+/// parameters, as if by `EXECUTE ENDPOINT name ('value', ...)`.
+pub async fn handle_endpoint(
+    mut client: AuthedClient,
+    Path(name): Path<String>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let name = match parse_item_name(&name) {
+        Ok(name) => name,
+        Err(e) => return Err((StatusCode::BAD_REQUEST, e.to_string())),
+    };
+    let stmt = Statement::<Raw>::ExecuteEndpoint(ExecuteEndpointStatement {
+        name: RawItemName::Name(name),
+        params: params
+            .into_iter()
+            .filter(|(key, _)| key == "param")
+            .map(|(_, value)| Expr::Value(Value::String(value)))
+            .collect(),
+    });
+    let request = SqlRequest::Simple {
+        query: stmt.to_ast_string_stable(),
+    };
+    let mut res = SqlResponse {
+        results: Vec::new(),
+    };
+    match execute_request(&mut client, request, &mut res).await {
+        Ok(()) => Ok(Json(res)),
+        Err(e) => Err((StatusCode::BAD_REQUEST, e.to_string())),
+    }
+}
+
+/// The query parameters of a `GET /api/subscribe` request.
+#[derive(Debug, Deserialize)]
+pub struct SubscribeSseParams {
+    /// A query string containing exactly one `SUBSCRIBE` statement.
+    query: String,
+}
+
+/// Runs a `SUBSCRIBE` and streams its results as server-sent events.
+///
+/// Each event contains one JSON-encoded [`WebSocketResponse`]. The last event
+/// of every batch of updates has the timestamp through which all updates have
+/// been sent as its ID, so a client that reconnects with a `Last-Event-ID`
+/// header resumes right after that timestamp.
+pub async fn handle_subscribe_sse(
+    mut client: AuthedClient,
+    headers: HeaderMap,
+    Query(SubscribeSseParams { query }): Query<SubscribeSseParams>,
+) -> impl IntoResponse {
+    let last_event_id = match headers.get("last-event-id") {
+        None => None,
+        Some(id) => match id.to_str().ok().and_then(|id| id.parse().ok()) {
+            Some(id) => Some(Timestamp::new(id)),
+            None => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "invalid Last-Event-ID header".to_string(),
+                ));
+            }
+        },
+    };
+    let query = match sse_subscribe_query(&client.client, &query, last_event_id) {
+        Ok(query) => query,
+        Err(e) => return Err((StatusCode::BAD_REQUEST, e.to_string())),
+    };
+
+    // Execution continues in a task that owns the session, so that the
+    // session lives for as long as the client reads results.
+    let (tx, rx) = mpsc::channel(SSE_BUFFER);
+    task::spawn(|| "handle_subscribe_sse", async move {
+        let mut sender = SseSender { tx };
+        let request = SqlRequest::Simple { query };
+        if let Err(e) = execute_request(&mut client, request, &mut sender).await {
+            debug!("SUBSCRIBE over server-sent events ended: {e}");
+        }
+    });
+
+    let events = ReceiverStream::new(rx).map(Ok::<_, Infallible>);
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Parses `query`, which must contain exactly one `SUBSCRIBE` statement, and
+/// returns it rewritten to only emit updates after `resume_after`, if present.
+fn sse_subscribe_query(
+    client: &SessionClient,
+    query: &str,
+    resume_after: Option<Timestamp>,
+) -> Result<String, Error> {
+    let mut stmts = client
+        .parse(query)
+        .map_err(|e| Error::Unstructured(anyhow!(e)))?
+        .map_err(AdapterError::from)?;
+    if stmts.len() != 1 {
+        return Err(Error::Unstructured(anyhow!(
+            "query must contain exactly 1 statement, but contains {}",
+            stmts.len()
+        )));
+    }
+    let StatementParseResult { ast, sql } = stmts.pop().expect("known to exist");
+    let Statement::Subscribe(mut stmt) = ast else {
+        return Err(Error::Unsupported(sql.to_string()));
+    };
+    let Some(resume_after) = resume_after else {
+        return Ok(sql.to_string());
+    };
+    if stmt.as_of.is_some() {
+        return Err(Error::Unstructured(anyhow!(
+            "cannot resume a SUBSCRIBE that specifies AS OF"
+        )));
+    }
+    stmt.as_of = Some(AsOf::At(Expr::Value(Value::Number(
+        resume_after.to_string(),
+    ))));
+    stmt.options
+        .retain(|option| option.name != SubscribeOptionName::Snapshot);
+    stmt.options.push(SubscribeOption {
+        name: SubscribeOptionName::Snapshot,
+        value: Some(WithOptionValue::Value(Value::Boolean(false))),
+    });
+    Ok(stmt.to_ast_string_stable())
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum WebSocketAuth {
@@ -1120,6 +1258,215 @@ impl ResultSender for WebSocket {
     }
 }
 
+/// Sends results to an HTTP client as server-sent events.
+struct SseSender {
+    tx: mpsc::Sender<Event>,
+}
+
+impl SseSender {
+    async fn send(&mut self, resp: WebSocketResponse, id: Option<Timestamp>) -> Result<(), Error> {
+        let mut event = Event::default().json_data(resp)?;
+        if let Some(id) = id {
+            event = event.id(id.to_string());
+        }
+        self.tx
+            .send(event)
+            .await
+            .map_err(|_| Error::Unstructured(anyhow!("client disconnected")))
+    }
+
+    /// Streams the updates of a `SUBSCRIBE` to the client. Returns whether the
+    /// `SUBSCRIBE` failed, the final message to send, and the reason execution
+    /// ended.
+    async fn send_subscribe(
+        &mut self,
+        client: &mut SessionClient,
+        desc: &RelationDesc,
+        tag: String,
+        mut rx: RecordFirstRowStream,
+    ) -> Result<(bool, WebSocketResponse, StatementEndedExecutionReason), Error> {
+        self.send(WebSocketResponse::Rows(desc.into()), None)
+            .await?;
+
+        // Progress messages only promise that all updates before their
+        // timestamp have been sent.
+        let has_progress = desc.arity() > 1 && desc.get_name(1).as_str() == "mz_progressed";
+        let types = &desc.typ().column_types;
+        let mut datum_vec = mz_repr::DatumVec::new();
+        let mut result_size: usize = 0;
+        let mut rows_returned = 0;
+        let mut complete_through = None;
+        loop {
+            match await_rows(self, client, rx.recv()).await? {
+                Some(PeekResponseUnary::Rows(mut rows)) => {
+                    if let Err(err) = verify_datum_desc(desc, &mut rows) {
+                        let error = err.to_string();
+                        return Ok((
+                            true,
+                            WebSocketResponse::Error(err.into()),
+                            StatementEndedExecutionReason::Errored { error },
+                        ));
+                    }
+
+                    // A batch contains all updates before the next frontier of
+                    // the `SUBSCRIBE`, so the updates through its greatest
+                    // timestamp are complete once the whole batch is sent.
+                    let count = rows.count();
+                    rows_returned += count;
+                    let mut sent = 0;
+                    while let Some(row) = rows.next() {
+                        sent += 1;
+                        result_size += row.byte_len();
+                        let datums = datum_vec.borrow_with(row);
+                        if let Datum::Numeric(ts) = datums[0] {
+                            if let Ok(ts) = Timestamp::try_from(ts.0) {
+                                let ts = if has_progress && datums[1] == Datum::True {
+                                    ts.saturating_sub(1)
+                                } else {
+                                    ts
+                                };
+                                complete_through = complete_through.max(Some(ts));
+                            }
+                        }
+                        let row = datums
+                            .iter()
+                            .enumerate()
+                            .map(|(i, d)| {
+                                TypedDatum::new(*d, &types[i])
+                                    .json(&JsonNumberPolicy::ConvertNumberToString)
+                            })
+                            .collect();
+                        let id = if sent == count {
+                            complete_through
+                        } else {
+                            None
+                        };
+                        self.send(WebSocketResponse::Row(row), id).await?;
+                    }
+                }
+                Some(PeekResponseUnary::Error(error)) => {
+                    return Ok((
+                        true,
+                        WebSocketResponse::Error(
+                            Error::Unstructured(anyhow!(error.clone())).into(),
+                        ),
+                        StatementEndedExecutionReason::Errored { error },
+                    ));
+                }
+                Some(PeekResponseUnary::Canceled) => {
+                    return Ok((
+                        true,
+                        WebSocketResponse::Error(AdapterError::Canceled.into()),
+                        StatementEndedExecutionReason::Canceled,
+                    ));
+                }
+                None => {
+                    return Ok((
+                        false,
+                        WebSocketResponse::CommandComplete(tag),
+                        StatementEndedExecutionReason::Success {
+                            result_size: Some(u64::cast_from(result_size)),
+                            rows_returned: Some(u64::cast_from(rows_returned)),
+                            execution_strategy: Some(StatementExecutionStrategy::Standard),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ResultSender for SseSender {
+    const SUPPORTS_STREAMING_NOTICES: bool = true;
+
+    async fn add_result(
+        &mut self,
+        client: &mut SessionClient,
+        res: StatementResult,
+    ) -> (
+        Result<Result<(), ()>, Error>,
+        Option<(StatementEndedExecutionReason, ExecuteContextExtra)>,
+    ) {
+        let (is_err, msgs, stmt_logging) = match res {
+            StatementResult::SqlResult(SqlResult::Rows {
+                tag,
+                rows,
+                desc,
+                notices,
+            }) => {
+                let mut msgs = vec![WebSocketResponse::Rows(desc)];
+                msgs.extend(rows.into_iter().map(WebSocketResponse::Row));
+                msgs.push(WebSocketResponse::CommandComplete(tag));
+                msgs.extend(notices.into_iter().map(WebSocketResponse::Notice));
+                (false, msgs, None)
+            }
+            StatementResult::SqlResult(SqlResult::Ok { ok, notices, .. }) => {
+                let mut msgs = vec![WebSocketResponse::CommandComplete(ok)];
+                msgs.extend(notices.into_iter().map(WebSocketResponse::Notice));
+                (false, msgs, None)
+            }
+            StatementResult::SqlResult(SqlResult::Err { error, notices }) => {
+                let mut msgs = vec![WebSocketResponse::Error(error)];
+                msgs.extend(notices.into_iter().map(WebSocketResponse::Notice));
+                (true, msgs, None)
+            }
+            StatementResult::Subscribe {
+                ref desc,
+                tag,
+                rx,
+                ctx_extra,
+            } => match self.send_subscribe(client, desc, tag, rx).await {
+                Ok((is_err, msg, reason)) => (is_err, vec![msg], Some((reason, ctx_extra))),
+                // We consider the remote breaking the connection to be a cancellation,
+                // matching the behavior for pgwire
+                Err(e) => {
+                    return (
+                        Err(e),
+                        Some((StatementEndedExecutionReason::Canceled, ctx_extra)),
+                    );
+                }
+            },
+        };
+        for msg in msgs {
+            if let Err(e) = self.send(msg, None).await {
+                return (
+                    Err(e),
+                    stmt_logging.map(|(_old_reason, ctx_extra)| {
+                        (StatementEndedExecutionReason::Canceled, ctx_extra)
+                    }),
+                );
+            }
+        }
+        (Ok(if is_err { Err(()) } else { Ok(()) }), stmt_logging)
+    }
+
+    fn connection_error(&mut self) -> BoxFuture<Error> {
+        Box::pin(async {
+            self.tx.closed().await;
+            Error::Unstructured(anyhow!("client disconnected"))
+        })
+    }
+
+    fn allow_subscribe(&self) -> bool {
+        true
+    }
+
+    async fn emit_streaming_notices(&mut self, notices: Vec<AdapterNotice>) -> Result<(), Error> {
+        for notice in notices {
+            let notice = WebSocketResponse::Notice(Notice {
+                message: notice.to_string(),
+                code: notice.code().code().to_string(),
+                severity: notice.severity().as_str().to_lowercase(),
+                detail: notice.detail(),
+                hint: notice.hint(),
+            });
+            self.send(notice, None).await?;
+        }
+        Ok(())
+    }
+}
+
 async fn await_rows<S, F, R>(sender: &mut S, client: &mut SessionClient, f: F) -> Result<R, Error>
 where
     S: ResultSender,
@@ -1459,6 +1806,7 @@ async fn execute_stmt<S: ResultSender>(
     Ok(match res {
         ExecuteResponse::CreatedConnection { .. }
         | ExecuteResponse::CreatedDatabase { .. }
+        | ExecuteResponse::CreatedEndpoint
         | ExecuteResponse::CreatedSchema { .. }
         | ExecuteResponse::CreatedRole
         | ExecuteResponse::CreatedCluster { .. }
@@ -1478,13 +1826,18 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
+        | ExecuteResponse::DiscardedPlans
         | ExecuteResponse::DiscardedAll
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
+        | ExecuteResponse::DroppedSubscription
         | ExecuteResponse::EmptyQuery
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
         | ExecuteResponse::Inserted(_)
+        | ExecuteResponse::Listened
+        | ExecuteResponse::Notified
+        | ExecuteResponse::Unlistened
         | ExecuteResponse::Copied(_)
         | ExecuteResponse::Raised
         | ExecuteResponse::ReassignOwned
@@ -36,7 +36,9 @@ use mz_adapter_types::dyncfgs::{
     WITH_0DT_DEPLOYMENT_DDL_CHECK_INTERVAL, WITH_0DT_DEPLOYMENT_MAX_WAIT,
 };
 use mz_auth::password::Password;
-use mz_authenticator::Authenticator;
+use mz_authenticator::{
+    Authenticator, CertificateAuthenticator, CertificateMappingRules, OidcAuthenticator, OidcConfig,
+};
 use mz_build_info::{BuildInfo, build_info};
 use mz_catalog::config::ClusterReplicaSizeMap;
 use mz_catalog::durable::BootstrapArgs;
@@ -57,7 +59,8 @@ use mz_pgwire_common::ConnectionCounter;
 use mz_repr::strconv;
 use mz_secrets::SecretsController;
 use mz_server_core::listeners::{
-    AuthenticatorKind, HttpListenerConfig, ListenerConfig, ListenersConfig, SqlListenerConfig,
+    AuthenticatorKind, FlightListenerConfig, HttpListenerConfig, ListenerConfig, ListenersConfig,
+    SqlListenerConfig,
 };
 use mz_server_core::{
     ConnectionStream, ListenerHandle, ReloadTrigger, ReloadingSslContext, ServeConfig,
@@ -71,12 +74,14 @@ use tracing::{Instrument, info, info_span};
 
 use crate::deployment::preflight::{PreflightInput, PreflightOutput};
 use crate::deployment::state::DeploymentState;
+use crate::flight::{FlightConfig, FlightServer};
 use crate::http::{HttpConfig, HttpServer, InternalRouteConfig};
 
 pub use crate::http::{SqlResponse, WebSocketAuth, WebSocketResponse};
 
 mod deployment;
 pub mod environmentd;
+mod flight;
 pub mod http;
 mod telemetry;
 #[cfg(feature = "test")]
@@ -104,8 +109,12 @@ pub struct Config {
     pub tls_reload_certs: ReloadTrigger,
     /// Password of the mz_system user.
     pub external_login_password_mz_system: Option<Password>,
+    /// Rules mapping TLS client certificate identities to roles.
+    pub certificate_mapping_rules: CertificateMappingRules,
     /// Frontegg JWT authentication configuration.
     pub frontegg: Option<FronteggAuthenticator>,
+    /// OIDC JWT authentication configuration.
+    pub oidc: Option<OidcConfig>,
     /// Origins for which cross-origin resource sharing (CORS) for HTTP requests
     /// is permitted.
     pub cors_allowed_origin: AllowOrigin,
@@ -256,6 +265,8 @@ impl Listener<SqlListenerConfig> {
         active_connection_counter: ConnectionCounter,
         tls_reloading_context: Option<ReloadingSslContext>,
         frontegg: Option<FronteggAuthenticator>,
+        certificate_mapping_rules: CertificateMappingRules,
+        oidc: Option<OidcAuthenticator>,
         adapter_client: AdapterClient,
         metrics: MetricsConfig,
         helm_chart_version: Option<String>,
@@ -269,13 +280,13 @@ impl Listener<SqlListenerConfig> {
                 TlsMode::Allow
             },
         });
-        let authenticator = match self.config.authenticator_kind {
-            AuthenticatorKind::Frontegg => Authenticator::Frontegg(
-                frontegg.expect("Frontegg args are required with AuthenticatorKind::Frontegg"),
-            ),
-            AuthenticatorKind::Password => Authenticator::Password(adapter_client.clone()),
-            AuthenticatorKind::None => Authenticator::None,
-        };
+        let authenticator = authenticator(
+            self.config.authenticator_kind,
+            frontegg,
+            certificate_mapping_rules,
+            oidc,
+            &adapter_client,
+        );
 
         task::spawn(|| format!("{}_sql_server", label), {
             let sql_server = mz_pgwire::Server::new(mz_pgwire::Config {
@@ -300,6 +311,30 @@ impl Listener<SqlListenerConfig> {
     }
 }
 
+/// Returns the authenticator for a listener that authenticates users with the
+/// given kind of authenticator.
+fn authenticator(
+    kind: AuthenticatorKind,
+    frontegg: Option<FronteggAuthenticator>,
+    certificate_mapping_rules: CertificateMappingRules,
+    oidc: Option<OidcAuthenticator>,
+    adapter_client: &AdapterClient,
+) -> Authenticator {
+    match kind {
+        AuthenticatorKind::Frontegg => Authenticator::Frontegg(
+            frontegg.expect("Frontegg args are required with AuthenticatorKind::Frontegg"),
+        ),
+        AuthenticatorKind::Password => Authenticator::Password(adapter_client.clone()),
+        AuthenticatorKind::Certificate => Authenticator::Certificate(
+            CertificateAuthenticator::new(adapter_client.clone(), certificate_mapping_rules),
+        ),
+        AuthenticatorKind::Oidc => {
+            Authenticator::Oidc(oidc.expect("OIDC args are required with AuthenticatorKind::Oidc"))
+        }
+        AuthenticatorKind::None => Authenticator::None,
+    }
+}
+
 impl Listener<HttpListenerConfig> {
     #[instrument(name = "environmentd::serve_http")]
     pub async fn serve_http(self, config: HttpConfig) -> ListenerHandle {
@@ -318,9 +353,28 @@ impl Listener<HttpListenerConfig> {
     }
 }
 
+impl Listener<FlightListenerConfig> {
+    #[instrument(name = "environmentd::serve_flight")]
+    pub async fn serve_flight(self, config: FlightConfig) -> ListenerHandle {
+        let task_name = format!("{}_flight_server", &config.source);
+        task::spawn(|| task_name, {
+            let flight_server = FlightServer::new(config);
+            mz_server_core::serve(ServeConfig {
+                conns: self.connection_stream,
+                server: flight_server,
+                // `environmentd` does not currently need to dynamically
+                // configure graceful termination behavior.
+                dyncfg: None,
+            })
+        });
+        self.handle
+    }
+}
+
 pub struct Listeners {
     pub http: BTreeMap<String, Listener<HttpListenerConfig>>,
     pub sql: BTreeMap<String, Listener<SqlListenerConfig>>,
+    pub flight: BTreeMap<String, Listener<FlightListenerConfig>>,
 }
 
 impl Listeners {
@@ -335,7 +389,12 @@ impl Listeners {
             http.insert(name, Listener::bind(config).await?);
         }
 
-        Ok(Listeners { http, sql })
+        let mut flight = BTreeMap::new();
+        for (name, config) in config.flight {
+            flight.insert(name, Listener::bind(config).await?);
+        }
+
+        Ok(Listeners { http, sql, flight })
     }
 
     /// Starts an `environmentd` server.
@@ -374,11 +433,16 @@ impl Listeners {
         let authenticator_frontegg_rx = authenticator_frontegg_rx.shared();
         let (authenticator_password_tx, authenticator_password_rx) = oneshot::channel();
         let authenticator_password_rx = authenticator_password_rx.shared();
+        let (authenticator_certificate_tx, authenticator_certificate_rx) = oneshot::channel();
+        let authenticator_certificate_rx = authenticator_certificate_rx.shared();
+        let (authenticator_oidc_tx, authenticator_oidc_rx) = oneshot::channel();
+        let authenticator_oidc_rx = authenticator_oidc_rx.shared();
         let (authenticator_none_tx, authenticator_none_rx) = oneshot::channel();
         let authenticator_none_rx = authenticator_none_rx.shared();
 
         // We can only send the Frontegg and None variants immediately.
-        // The Password variant requires an adapter client.
+        // The Password, Certificate, and Oidc variants require an adapter
+        // client.
         if let Some(frontegg) = &config.frontegg {
             authenticator_frontegg_tx
                 .send(Arc::new(Authenticator::Frontegg(frontegg.clone())))
@@ -399,6 +463,8 @@ impl Listeners {
             let authenticator_rx = match authenticator_kind {
                 AuthenticatorKind::Frontegg => authenticator_frontegg_rx.clone(),
                 AuthenticatorKind::Password => authenticator_password_rx.clone(),
+                AuthenticatorKind::Certificate => authenticator_certificate_rx.clone(),
+                AuthenticatorKind::Oidc => authenticator_oidc_rx.clone(),
                 AuthenticatorKind::None => authenticator_none_rx.clone(),
             };
             let source: &'static str = Box::leak(name.clone().into_boxed_str());
@@ -837,6 +903,25 @@ impl Listeners {
         authenticator_password_tx
             .send(Arc::new(Authenticator::Password(adapter_client.clone())))
             .expect("rx known to be live");
+        authenticator_certificate_tx
+            .send(Arc::new(Authenticator::Certificate(
+                CertificateAuthenticator::new(
+                    adapter_client.clone(),
+                    config.certificate_mapping_rules.clone(),
+                ),
+            )))
+            .expect("rx known to be live");
+        // All listeners share one OIDC authenticator, and so one cache of
+        // signing keys.
+        let oidc = config
+            .oidc
+            .clone()
+            .map(|oidc| OidcAuthenticator::new(adapter_client.clone(), oidc));
+        if let Some(oidc) = &oidc {
+            authenticator_oidc_tx
+                .send(Arc::new(Authenticator::Oidc(oidc.clone())))
+                .expect("rx known to be live");
+        }
         adapter_client_tx
             .send(adapter_client.clone())
             .expect("internal HTTP server should not drop first");
@@ -854,6 +939,8 @@ impl Listeners {
                         active_connection_counter.clone(),
                         tls_reloading_context.clone(),
                         config.frontegg.clone(),
+                        config.certificate_mapping_rules.clone(),
+                        oidc.clone(),
                         adapter_client.clone(),
                         metrics.clone(),
                         config.helm_chart_version.clone(),
@@ -862,6 +949,33 @@ impl Listeners {
             );
         }
 
+        // Launch Arrow Flight SQL servers.
+        let mut flight_listener_handles = BTreeMap::new();
+        for (name, listener) in self.flight {
+            let authenticator = authenticator(
+                listener.config.authenticator_kind,
+                config.frontegg.clone(),
+                config.certificate_mapping_rules.clone(),
+                oidc.clone(),
+                &adapter_client,
+            );
+            let tls = if listener.config.enable_tls {
+                tls_reloading_context.clone()
+            } else {
+                None
+            };
+            let flight_config = FlightConfig {
+                source: Box::leak(name.clone().into_boxed_str()),
+                tls,
+                authenticator,
+                adapter_client: adapter_client.clone(),
+                active_connection_counter: active_connection_counter.clone(),
+                helm_chart_version: config.helm_chart_version.clone(),
+                allowed_roles: listener.config.allowed_roles,
+            };
+            flight_listener_handles.insert(name, listener.serve_flight(flight_config).await);
+        }
+
         // Start telemetry reporting loop.
         if let Some(segment_client) = segment_client {
             telemetry::start_reporting(telemetry::Config {
@@ -897,6 +1011,7 @@ impl Listeners {
         Ok(Server {
             sql_listener_handles,
             http_listener_handles,
+            flight_listener_handles,
             _adapter_handle: adapter_handle,
         })
     }
@@ -921,5 +1036,6 @@ pub struct Server {
     // Drop order matters for these fields.
     pub sql_listener_handles: BTreeMap<String, ListenerHandle>,
     pub http_listener_handles: BTreeMap<String, ListenerHandle>,
+    pub flight_listener_handles: BTreeMap<String, ListenerHandle>,
     _adapter_handle: mz_adapter::Handle,
 }
@@ -34,6 +34,7 @@ use mz_adapter_types::bootstrap_builtin_cluster_config::{
 };
 
 use mz_auth::password::Password;
+use mz_authenticator::{CertificateMappingRules, OidcConfig};
 use mz_catalog::config::ClusterReplicaSizeMap;
 use mz_controller::ControllerConfig;
 use mz_dyncfg::ConfigUpdates;
@@ -88,8 +89,8 @@ use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Message, WebSocket};
 
 use crate::{
-    CatalogConfig, FronteggAuthenticator, HttpListenerConfig, ListenersConfig, SqlListenerConfig,
-    WebSocketAuth, WebSocketResponse,
+    CatalogConfig, FlightListenerConfig, FronteggAuthenticator, HttpListenerConfig,
+    ListenersConfig, SqlListenerConfig, WebSocketAuth, WebSocketResponse,
 };
 
 pub static KAFKA_ADDRS: LazyLock<String> =
@@ -102,6 +103,8 @@ pub struct TestHarness {
     tls: Option<TlsCertConfig>,
     frontegg: Option<FronteggAuthenticator>,
     external_login_password_mz_system: Option<Password>,
+    certificate_mapping_rules: CertificateMappingRules,
+    oidc: Option<OidcConfig>,
     listeners_config: ListenersConfig,
     unsafe_mode: bool,
     workers: usize,
@@ -139,6 +142,8 @@ impl Default for TestHarness {
             tls: None,
             frontegg: None,
             external_login_password_mz_system: None,
+            certificate_mapping_rules: CertificateMappingRules::default(),
+            oidc: None,
             listeners_config: ListenersConfig {
                 sql: btreemap![
                     "external".to_owned() => SqlListenerConfig {
@@ -186,6 +191,14 @@ impl Default for TestHarness {
                         },
                     },
                 ],
+                flight: btreemap![
+                    "external".to_owned() => FlightListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::None,
+                        allowed_roles: AllowedRoles::Normal,
+                        enable_tls: false,
+                    },
+                ],
             },
             unsafe_mode: false,
             workers: 1,
@@ -287,6 +300,7 @@ impl TestHarness {
         self.tls = Some(TlsCertConfig {
             cert: cert_path.into(),
             key: key_path.into(),
+            client_ca: None,
         });
         for (_, listener) in &mut self.listeners_config.sql {
             listener.enable_tls = true;
@@ -294,6 +308,9 @@ impl TestHarness {
         for (_, listener) in &mut self.listeners_config.http {
             listener.base.enable_tls = true;
         }
+        for (_, listener) in &mut self.listeners_config.flight {
+            listener.enable_tls = true;
+        }
         self
     }
 
@@ -357,6 +374,14 @@ impl TestHarness {
                     },
                 },
             },
+            flight: btreemap! {
+                "external".to_owned() => FlightListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Frontegg,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls,
+                },
+            },
         };
         self
     }
@@ -405,6 +430,149 @@ impl TestHarness {
                     },
                 },
             },
+            flight: btreemap! {
+                "external".to_owned() => FlightListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Password,
+                    allowed_roles: AllowedRoles::NormalAndInternal,
+                    enable_tls,
+                },
+            },
+        };
+        self
+    }
+
+    /// Authenticates external connections by client certificate.
+    ///
+    /// Must be called after [`TestHarness::with_tls`].
+    pub fn with_certificate_auth(
+        mut self,
+        client_ca_path: impl Into<PathBuf>,
+        rules: CertificateMappingRules,
+    ) -> Self {
+        let tls = self
+            .tls
+            .as_mut()
+            .expect("certificate authentication requires TLS");
+        tls.client_ca = Some(client_ca_path.into());
+        self.certificate_mapping_rules = rules;
+        self.listeners_config = ListenersConfig {
+            sql: btreemap! {
+                "external".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Certificate,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls: true,
+                },
+                "internal".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::None,
+                    allowed_roles: AllowedRoles::NormalAndInternal,
+                    enable_tls: false,
+                },
+            },
+            http: btreemap! {
+                "external".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::Certificate,
+                        allowed_roles: AllowedRoles::Normal,
+                        enable_tls: true,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: false,
+                        metrics: false,
+                        profiling: false,
+                    },
+                },
+                "internal".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::None,
+                        allowed_roles: AllowedRoles::NormalAndInternal,
+                        enable_tls: false,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: true,
+                        metrics: true,
+                        profiling: true,
+                    },
+                },
+            },
+            flight: btreemap! {
+                "external".to_owned() => FlightListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Certificate,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls: true,
+                },
+            },
+        };
+        self
+    }
+
+    /// Authenticates external connections by OIDC JWT.
+    pub fn with_oidc_auth(mut self, oidc: OidcConfig) -> Self {
+        self.oidc = Some(oidc);
+        self.listeners_config = ListenersConfig {
+            sql: btreemap! {
+                "external".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Oidc,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls: false,
+                },
+                "internal".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::None,
+                    allowed_roles: AllowedRoles::NormalAndInternal,
+                    enable_tls: false,
+                },
+            },
+            http: btreemap! {
+                "external".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::Oidc,
+                        allowed_roles: AllowedRoles::Normal,
+                        enable_tls: false,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: false,
+                        metrics: false,
+                        profiling: false,
+                    },
+                },
+                "internal".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::None,
+                        allowed_roles: AllowedRoles::NormalAndInternal,
+                        enable_tls: false,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: true,
+                        metrics: true,
+                        profiling: true,
+                    },
+                },
+            },
+            flight: btreemap! {
+                "external".to_owned() => FlightListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Oidc,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls: false,
+                },
+            },
         };
         self
     }
@@ -692,6 +860,7 @@ impl Listeners {
                     now: config.now.clone(),
                     metrics_registry: metrics_registry.clone(),
                     persist_pubsub_url: format!("http://localhost:{}", persist_pubsub_server_port),
+                    persist_blob_encryption_keyring: None,
                     secrets_args: mz_service::secrets::SecretsReaderCliArgs {
                         secrets_reader: mz_service::secrets::SecretsControllerKind::LocalFile,
                         secrets_reader_local_file_dir: Some(data_directory.join("secrets")),
@@ -743,6 +912,8 @@ impl Listeners {
                 helm_chart_version: None,
                 license_key: ValidatedLicenseKey::for_tests(),
                 external_login_password_mz_system: config.external_login_password_mz_system,
+                certificate_mapping_rules: config.certificate_mapping_rules,
+                oidc: config.oidc,
             })
             .await?;
 
@@ -812,6 +983,10 @@ impl TestServer {
     pub fn internal_sql_local_addr(&self) -> SocketAddr {
         self.inner.sql_listener_handles["internal"].local_addr
     }
+
+    pub fn flight_local_addr(&self) -> SocketAddr {
+        self.inner.flight_listener_handles["external"].local_addr
+    }
 }
 
 /// A builder struct to configure a pgwire connection to a running [`TestServer`].
@@ -16,6 +16,7 @@ use std::future::IntoFuture;
 use std::io::{Read, Write};
 use std::net::{IpAddr, Ipv4Addr, TcpStream};
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -36,6 +37,9 @@ use hyper_util::rt::TokioExecutor;
 use itertools::Itertools;
 use jsonwebtoken::{self, DecodingKey, EncodingKey};
 use mz_auth::password::Password;
+use mz_authenticator::{
+    CertificateField, CertificateMappingRuleConfig, CertificateMappingRules, JwksSource, OidcConfig,
+};
 use mz_environmentd::test_util::{self, Ca, make_header, make_pg_tls};
 use mz_environmentd::{WebSocketAuth, WebSocketResponse};
 use mz_frontegg_auth::{
@@ -52,8 +56,10 @@ use mz_ore::{assert_contains, assert_err, assert_none, assert_ok};
 use mz_sql::names::PUBLIC_ROLE_NAME;
 use mz_sql::session::user::{HTTP_DEFAULT_USER, SYSTEM_USER};
 use openssl::error::ErrorStack;
-use openssl::ssl::{SslConnector, SslConnectorBuilder, SslMethod, SslOptions, SslVerifyMode};
-use postgres::config::SslMode;
+use openssl::ssl::{
+    SslConnector, SslConnectorBuilder, SslFiletype, SslMethod, SslOptions, SslVerifyMode,
+};
+use postgres::config::{ChannelBinding, SslMode};
 use postgres::error::SqlState;
 use serde::Deserialize;
 use serde_json::json;
@@ -3159,6 +3165,366 @@ async fn test_password_auth() {
     );
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_password_auth_scram_channel_binding() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+    let metrics_registry = MetricsRegistry::new();
+
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_system_parameter_default("enable_password_auth".to_string(), "true".to_string())
+        .with_password_auth(Password("mz_system_password".to_owned()))
+        .with_metrics_registry(metrics_registry)
+        .start()
+        .await;
+    let tls = make_pg_tls(|b| Ok(b.set_verify(SslVerifyMode::NONE)));
+
+    let mz_system_client = server
+        .connect()
+        .ssl_mode(SslMode::Require)
+        .user("mz_system")
+        .password("mz_system_password")
+        .with_tls(tls.clone())
+        .await
+        .unwrap();
+    mz_system_client
+        .execute("CREATE ROLE foo WITH LOGIN PASSWORD 'bar'", &[])
+        .await
+        .unwrap();
+
+    // Requiring channel binding forces the client to use SCRAM-SHA-256-PLUS.
+    let connect = |user: &str, password: &str| {
+        let builder = server
+            .connect()
+            .ssl_mode(SslMode::Require)
+            .user(user)
+            .password(password);
+        let mut pg_config = builder.as_pg_config().clone();
+        pg_config.channel_binding(ChannelBinding::Require);
+        builder.with_config(pg_config).with_tls(tls.clone())
+    };
+
+    let client = connect("foo", "bar").await.unwrap();
+    assert_eq!(
+        client
+            .query_one("SELECT current_user", &[])
+            .await
+            .unwrap()
+            .get::<_, String>(0),
+        "foo"
+    );
+
+    let err = connect("foo", "baz").await.unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_PASSWORD
+    );
+
+    // Nonexistent roles fail the same way as a wrong password.
+    let err = connect("nonexistent", "bar").await.unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_PASSWORD
+    );
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_certificate_auth() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+    let (alice_cert, alice_key) = ca.request_client_cert("alice.svc.example.com").unwrap();
+    let (mallory_cert, mallory_key) = ca.request_client_cert("mallory.example.com").unwrap();
+    let rogue_ca = Ca::new_root("rogue ca").unwrap();
+    let (rogue_cert, rogue_key) = rogue_ca
+        .request_client_cert("alice.svc.example.com")
+        .unwrap();
+
+    let rules = CertificateMappingRules::new(vec![CertificateMappingRuleConfig {
+        name: "services".to_string(),
+        field: CertificateField::CommonName,
+        pattern: r"([a-z]+)\.svc\.example\.com".to_string(),
+        role: "${1}".to_string(),
+    }])
+    .unwrap();
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_certificate_auth(ca.ca_cert_path(), rules)
+        .with_system_parameter_default("enable_password_auth".to_string(), "true".to_string())
+        .start()
+        .await;
+
+    let internal_client = server.connect().internal().await.unwrap();
+    internal_client
+        .batch_execute("CREATE ROLE alice WITH LOGIN; CREATE ROLE bob WITH LOGIN")
+        .await
+        .unwrap();
+
+    let connect = |user: &str, cert: Option<(&PathBuf, &PathBuf)>| {
+        let cert = cert.map(|(cert, key)| (cert.clone(), key.clone()));
+        server
+            .connect()
+            .ssl_mode(SslMode::Require)
+            .user(user)
+            .with_tls(make_pg_tls(move |b| {
+                b.set_verify(SslVerifyMode::NONE);
+                if let Some((cert, key)) = cert {
+                    b.set_certificate_file(cert, SslFiletype::PEM)?;
+                    b.set_private_key_file(key, SslFiletype::PEM)?;
+                }
+                Ok(())
+            }))
+    };
+
+    // A certificate that maps to the requested role is accepted.
+    let client = connect("alice", Some((&alice_cert, &alice_key)))
+        .await
+        .unwrap();
+    assert_eq!(
+        client
+            .query_one("SELECT current_user", &[])
+            .await
+            .unwrap()
+            .get::<_, String>(0),
+        "alice"
+    );
+
+    // A certificate cannot be used to log in as a different role.
+    let err = connect("bob", Some((&alice_cert, &alice_key)))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_AUTHORIZATION_SPECIFICATION
+    );
+
+    // Identities that no rule maps are rejected.
+    let err = connect("mallory", Some((&mallory_cert, &mallory_key)))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_AUTHORIZATION_SPECIFICATION
+    );
+
+    // Clients must present a certificate.
+    let err = connect("alice", None).await.unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_AUTHORIZATION_SPECIFICATION
+    );
+
+    // Certificates that are not signed by the client CA fail the handshake.
+    assert_err!(connect("alice", Some((&rogue_cert, &rogue_key))).await);
+
+    // The HTTP API maps the certificate to a role when no user is named.
+    let http_client = hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(
+        make_http_tls(|b| {
+            b.set_verify(SslVerifyMode::NONE);
+            b.set_certificate_file(&alice_cert, SslFiletype::PEM)?;
+            b.set_private_key_file(&alice_key, SslFiletype::PEM)?;
+            Ok(())
+        }),
+    );
+    let res = http_client
+        .request(
+            Request::post(format!("https://{}/api/sql", server.http_local_addr()))
+                .header("Content-Type", "application/json")
+                .body(json!({ "query": "SELECT current_user" }).to_string())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body = res.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["results"][0]["rows"], json!([["alice"]]));
+
+    // Every decision is recorded in the audit log.
+    let rows = internal_client
+        .query(
+            "SELECT object_type, details->>'role_name', details->>'rule', details->>'accepted'
+             FROM mz_audit_events
+             WHERE event_type = 'login'
+             ORDER BY id",
+            &[],
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| {
+            (
+                row.get::<_, String>(0),
+                row.get::<_, String>(1),
+                row.get::<_, Option<String>>(2),
+                row.get::<_, String>(3),
+            )
+        })
+        .collect::<Vec<_>>();
+    let event = |role: &str, rule: Option<&str>, accepted: &str| {
+        (
+            "role".to_string(),
+            role.to_string(),
+            rule.map(|rule| rule.to_string()),
+            accepted.to_string(),
+        )
+    };
+    assert_eq!(
+        rows,
+        vec![
+            event("alice", Some("services"), "true"),
+            event("bob", None, "false"),
+            event("mallory", None, "false"),
+            event("alice", Some("services"), "true"),
+        ]
+    );
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_oidc_auth() {
+    const ISSUER: &str = "https://idp.example.com";
+
+    // Publish the identity provider's signing key in a local JWKS file.
+    let ca = Ca::new_root("test ca").unwrap();
+    let rsa = ca.pkey.rsa().unwrap();
+    let jwks_path = ca.dir.path().join("jwks.json");
+    let jwks = json!({
+        "keys": [{
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": "test-key",
+            "n": URL_SAFE_NO_PAD.encode(rsa.n().to_vec()),
+            "e": URL_SAFE_NO_PAD.encode(rsa.e().to_vec()),
+        }]
+    });
+    fs::write(&jwks_path, jwks.to_string()).unwrap();
+    let encoding_key =
+        EncodingKey::from_rsa_pem(&ca.pkey.private_key_to_pem_pkcs8().unwrap()).unwrap();
+    let make_token = |role: &str, exp: i64| {
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = Some("test-key".to_string());
+        let claims = json!({
+            "iss": ISSUER,
+            "aud": "materialize",
+            "sub": "0f5c4a4e-0000-4000-8000-000000000000",
+            "preferred_username": role,
+            "exp": exp,
+        });
+        jsonwebtoken::encode(&header, &claims, &encoding_key).unwrap()
+    };
+    let valid_exp = Utc::now().timestamp() + 300;
+
+    let server = test_util::TestHarness::default()
+        .with_oidc_auth(OidcConfig {
+            issuer: ISSUER.to_string(),
+            audience: Some("materialize".to_string()),
+            jwks: JwksSource::File(jwks_path),
+            role_claim: "preferred_username".to_string(),
+            auto_create_roles: false,
+            jwks_refresh_interval: Duration::from_secs(300),
+            now: SYSTEM_TIME.clone(),
+        })
+        .start()
+        .await;
+
+    let internal_client = server.connect().internal().await.unwrap();
+    internal_client
+        .batch_execute("CREATE ROLE alice")
+        .await
+        .unwrap();
+
+    // A token for the requested role is accepted as the password.
+    let alice_token = make_token("alice", valid_exp);
+    let client = server
+        .connect()
+        .no_tls()
+        .user("alice")
+        .password(&alice_token)
+        .await
+        .unwrap();
+    assert_eq!(
+        client
+            .query_one("SELECT current_user", &[])
+            .await
+            .unwrap()
+            .get::<_, String>(0),
+        "alice"
+    );
+
+    // A token cannot be used to log in as a different role.
+    let err = server
+        .connect()
+        .no_tls()
+        .user("bob")
+        .password(&alice_token)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_PASSWORD
+    );
+
+    // Roles are not created on login unless configured to be.
+    let err = server
+        .connect()
+        .no_tls()
+        .user("carol")
+        .password(&make_token("carol", valid_exp))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_PASSWORD
+    );
+
+    // Expired tokens are rejected.
+    let err = server
+        .connect()
+        .no_tls()
+        .user("alice")
+        .password(&make_token("alice", Utc::now().timestamp() - 300))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.as_db_error().unwrap().code(),
+        &SqlState::INVALID_PASSWORD
+    );
+
+    // The HTTP API accepts the token as a bearer token.
+    let http_client =
+        hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build_http();
+    let query = |token: &str| {
+        Request::post(format!("http://{}/api/sql", server.http_local_addr()))
+            .header("Content-Type", "application/json")
+            .header(AUTHORIZATION, format!("Bearer {token}"))
+            .body(json!({ "query": "SELECT current_user" }).to_string())
+            .unwrap()
+    };
+    let res = http_client.request(query(&alice_token)).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body = res.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["results"][0]["rows"], json!([["alice"]]));
+
+    // Tokens that are not signed by the identity provider are rejected.
+    let forged_token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+        &json!({ "iss": ISSUER, "preferred_username": "alice", "exp": valid_exp }),
+        &EncodingKey::from_secret(b"secret"),
+    )
+    .unwrap();
+    let res = http_client.request(query(&forged_token)).await.unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
 async fn test_password_auth_superuser() {
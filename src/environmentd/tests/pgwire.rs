@@ -613,6 +613,74 @@ fn test_record_types() {
     assert_eq!(rows.len(), 2);
 }
 
+#[mz_ore::test]
+fn test_listen_notify() {
+    let server = test_util::TestHarness::default().start_blocking();
+    server.enable_feature_flags(&["enable_listen_notify"]);
+    let mut listener = server.connect(postgres::NoTls).unwrap();
+    let mut notifier = server.connect(postgres::NoTls).unwrap();
+    let timeout = Duration::from_secs(30);
+
+    let notifier_pid: i32 = notifier
+        .query_one("SELECT pg_backend_pid()", &[])
+        .unwrap()
+        .get(0);
+    listener.batch_execute("LISTEN jobs").unwrap();
+    notifier.batch_execute("NOTIFY jobs, 'done'").unwrap();
+    let notification = listener
+        .notifications()
+        .timeout_iter(timeout)
+        .next()
+        .unwrap()
+        .expect("notification delivered");
+    assert_eq!(notification.channel(), "jobs");
+    assert_eq!(notification.payload(), "done");
+    assert_eq!(notification.process_id(), notifier_pid);
+
+    // Notifications are held back until the listener's transaction ends.
+    listener.batch_execute("BEGIN").unwrap();
+    notifier.batch_execute("NOTIFY jobs, 'held'").unwrap();
+    listener.batch_execute("SELECT 1").unwrap();
+    assert_eq!(listener.notifications().len(), 0);
+    listener.batch_execute("COMMIT").unwrap();
+    let notification = listener
+        .notifications()
+        .timeout_iter(timeout)
+        .next()
+        .unwrap()
+        .expect("notification delivered");
+    assert_eq!(notification.payload(), "held");
+
+    // Changes to a relation bound to a channel raise notifications.
+    listener
+        .batch_execute("CREATE TABLE t (a int); LISTEN changes ON t")
+        .unwrap();
+    notifier.batch_execute("INSERT INTO t VALUES (1)").unwrap();
+    let notification = listener
+        .notifications()
+        .timeout_iter(timeout)
+        .next()
+        .unwrap()
+        .expect("notification delivered");
+    assert_eq!(notification.channel(), "changes");
+    assert_ok!(notification.payload().parse::<u64>());
+
+    // Once unlistened, nothing is delivered anymore.
+    listener.batch_execute("UNLISTEN *").unwrap();
+    notifier
+        .batch_execute("NOTIFY jobs; INSERT INTO t VALUES (2)")
+        .unwrap();
+    listener.batch_execute("SELECT 1").unwrap();
+    assert_eq!(listener.notifications().len(), 0);
+    assert_eq!(
+        listener
+            .query_one("SELECT count(*) FROM mz_internal.mz_subscriptions", &[])
+            .unwrap()
+            .get::<_, i64>(0),
+        0
+    );
+}
+
 fn pg_test_inner(dir: PathBuf, flags: &[&'static str]) {
     // We want a new server per file, so we can't use pgtest::walk.
     datadriven::walk(dir.to_str().unwrap(), |tf| {
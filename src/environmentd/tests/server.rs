@@ -21,10 +21,13 @@ use std::time::{Duration, Instant};
 use std::{iter, thread};
 
 use anyhow::bail;
+use arrow::array::AsArray;
+use arrow::datatypes::{Int32Type, Int64Type};
+use arrow_flight::sql::client::FlightSqlServiceClient;
 use chrono::{DateTime, Utc};
 use flate2::Compression;
 use flate2::write::GzEncoder;
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt, TryStreamExt};
 use http::Request;
 use itertools::Itertools;
 use jsonwebtoken::{DecodingKey, EncodingKey};
@@ -4719,3 +4722,279 @@ fn test_webhook_request_compression() {
     let rnd_body = row.as_ref().map(|r| r.get("body"));
     assert_eq!(rnd_body, Some(og_body));
 }
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_flight_sql() {
+    let server = test_util::TestHarness::default().start().await;
+    let client = server.connect().await.unwrap();
+    client
+        .batch_execute("CREATE TABLE t (a int, b text)")
+        .await
+        .unwrap();
+    client
+        .batch_execute("INSERT INTO t SELECT generate_series(1, 10000), 'x'")
+        .await
+        .unwrap();
+
+    let endpoint = format!("http://{}", server.flight_local_addr());
+    let channel = tonic::transport::Endpoint::new(endpoint)
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let mut flight = FlightSqlServiceClient::new(channel);
+    flight.handshake("materialize", "").await.unwrap();
+
+    // Results are split across record batches that together hold every row.
+    let info = flight
+        .execute("SELECT a, b FROM t ORDER BY a".into(), None)
+        .await
+        .unwrap();
+    let schema = info.clone().try_decode_schema().unwrap();
+    let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+    assert_eq!(names, ["a", "b"]);
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let batches: Vec<_> = flight
+        .do_get(ticket)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(batches.len() > 1);
+    let values: Vec<i32> = batches
+        .iter()
+        .flat_map(|batch| {
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .to_vec()
+        })
+        .collect();
+    assert_eq!(values, (1..=10000).collect::<Vec<_>>());
+
+    // Only statements that return rows are supported.
+    let err = flight
+        .execute("CREATE TABLE u (a int)".into(), None)
+        .await
+        .unwrap_err();
+    assert_contains!(err.to_string(), "statement does not return rows");
+    let err = flight
+        .execute("SELECT * FROM nonexistent".into(), None)
+        .await
+        .unwrap_err();
+    assert_contains!(err.to_string(), "unknown catalog item 'nonexistent'");
+
+    // SUBSCRIBE streams its snapshot, and then its updates, until the client
+    // hangs up.
+    let info = flight.execute("SUBSCRIBE t".into(), None).await.unwrap();
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let mut stream = flight.do_get(ticket).await.unwrap();
+    let mut diffs = 0;
+    while diffs < 10000 {
+        let batch = stream.next().await.unwrap().unwrap();
+        assert_eq!(batch.schema().field(1).name(), "mz_diff");
+        diffs += batch
+            .column(1)
+            .as_primitive::<Int64Type>()
+            .values()
+            .iter()
+            .sum::<i64>();
+    }
+    assert_eq!(diffs, 10000);
+    client
+        .batch_execute("INSERT INTO t VALUES (10001, 'y')")
+        .await
+        .unwrap();
+    let batch = stream.next().await.unwrap().unwrap();
+    assert_eq!(batch.num_rows(), 1);
+    assert_eq!(batch.column(2).as_primitive::<Int32Type>().value(0), 10001);
+    drop(stream);
+}
+
+/// Reads the next server-sent event with data from `res`, returning its ID and
+/// its data.
+async fn next_sse_event(
+    res: &mut reqwest::Response,
+    buf: &mut String,
+) -> (Option<String>, WebSocketResponse) {
+    loop {
+        while let Some(end) = buf.find("\n\n") {
+            let event: String = buf.drain(..end + 2).collect();
+            let mut id = None;
+            let mut data = None;
+            for line in event.lines() {
+                if let Some(value) = line.strip_prefix("id:") {
+                    id = Some(value.trim().to_string());
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data = Some(serde_json::from_str(value.trim()).unwrap());
+                }
+            }
+            // Skip keep-alive comments.
+            if let Some(data) = data {
+                return (id, data);
+            }
+        }
+        let chunk = res.chunk().await.unwrap().expect("stream ended");
+        buf.push_str(std::str::from_utf8(&chunk).unwrap());
+    }
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_http_subscribe_sse() {
+    let server = test_util::TestHarness::default().start().await;
+    let client = server.connect().await.unwrap();
+    client
+        .batch_execute("CREATE TABLE t (a text)")
+        .await
+        .unwrap();
+    client
+        .batch_execute("INSERT INTO t VALUES ('a')")
+        .await
+        .unwrap();
+
+    let mut url = Url::parse(&format!(
+        "http://{}/api/subscribe",
+        server.http_local_addr()
+    ))
+    .unwrap();
+    url.query_pairs_mut().append_pair("query", "SUBSCRIBE t");
+
+    // The snapshot is followed by its updates, and the last event of each
+    // batch carries the timestamp through which updates are complete.
+    let mut res = reqwest::Client::new()
+        .get(url.clone())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let mut buf = String::new();
+    let (_, desc) = next_sse_event(&mut res, &mut buf).await;
+    assert!(matches!(desc, WebSocketResponse::Rows(_)), "{desc:?}");
+    let (id, row) = next_sse_event(&mut res, &mut buf).await;
+    let WebSocketResponse::Row(row) = row else {
+        panic!("unexpected event: {row:?}");
+    };
+    assert_eq!(row[1..], [serde_json::json!("1"), serde_json::json!("a")]);
+    let id = id.expect("last event of batch has an id");
+    client
+        .batch_execute("INSERT INTO t VALUES ('b')")
+        .await
+        .unwrap();
+    let (_, row) = next_sse_event(&mut res, &mut buf).await;
+    let WebSocketResponse::Row(row) = row else {
+        panic!("unexpected event: {row:?}");
+    };
+    assert_eq!(row[2], serde_json::json!("b"));
+    drop(res);
+
+    // Reconnecting with the last event ID skips the updates that were already
+    // sent.
+    let mut res = reqwest::Client::new()
+        .get(url.clone())
+        .header("Last-Event-ID", id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let mut buf = String::new();
+    let (_, desc) = next_sse_event(&mut res, &mut buf).await;
+    assert!(matches!(desc, WebSocketResponse::Rows(_)), "{desc:?}");
+    let (_, row) = next_sse_event(&mut res, &mut buf).await;
+    let WebSocketResponse::Row(row) = row else {
+        panic!("unexpected event: {row:?}");
+    };
+    assert_eq!(row[2], serde_json::json!("b"));
+    drop(res);
+
+    // Only a single SUBSCRIBE is supported.
+    let mut url = url;
+    url.query_pairs_mut()
+        .clear()
+        .append_pair("query", "SELECT * FROM t");
+    let res = reqwest::Client::new().get(url).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_http_endpoint() {
+    let server = test_util::TestHarness::default()
+        .with_system_parameter_default("enable_endpoints".to_string(), "true".to_string())
+        .start()
+        .await;
+    let client = server.connect().await.unwrap();
+    client
+        .batch_execute(
+            "CREATE TABLE t (id int, name text);
+             INSERT INTO t VALUES (1, 'a'), (2, 'b');
+             CREATE DEFAULT INDEX ON t;
+             CREATE ENDPOINT lookup AS SELECT name FROM t WHERE id = $1;
+             CREATE ENDPOINT total AS SELECT count(*) FROM t WHERE id > $1;",
+        )
+        .await
+        .unwrap();
+
+    let endpoint_url = |name: &str, param: &str| {
+        let mut url = Url::parse(&format!(
+            "http://{}/api/endpoints/{name}",
+            server.http_local_addr()
+        ))
+        .unwrap();
+        url.query_pairs_mut().append_pair("param", param);
+        url
+    };
+
+    // Arguments are cast to the types of the endpoint's parameters.
+    let res = reqwest::Client::new()
+        .get(endpoint_url("lookup", "2"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(
+        body["results"][0]["rows"],
+        serde_json::json!([["b"]]),
+        "{body}"
+    );
+
+    // Schema-qualified names resolve like they do in SQL.
+    let res = reqwest::Client::new()
+        .get(endpoint_url("materialize.public.lookup", "1"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(
+        body["results"][0]["rows"],
+        serde_json::json!([["a"]]),
+        "{body}"
+    );
+
+    // Arguments that cannot be cast are rejected.
+    let res = reqwest::Client::new()
+        .get(endpoint_url("lookup", "b"))
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body["results"][0]["error"].is_object(), "{body}");
+
+    // Endpoints must be answerable without building a dataflow.
+    let res = reqwest::Client::new()
+        .get(endpoint_url("total", "0"))
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = res.json().await.unwrap();
+    let message = body["results"][0]["error"]["message"].as_str().unwrap();
+    assert!(
+        message.contains("cannot be executed without building a dataflow"),
+        "{body}"
+    );
+}
@@ -994,6 +994,215 @@ fn test_subscribe_fetch_timeout() {
     }
 }
 
+#[mz_ore::test]
+fn test_subscribe_durable_resume() {
+    let server = test_util::TestHarness::default().start_blocking();
+    server.enable_feature_flags(&["enable_durable_subscriptions"]);
+    let mut client_writes = server.connect(postgres::NoTls).unwrap();
+
+    client_writes
+        .batch_execute("CREATE TABLE t (data text)")
+        .unwrap();
+    client_writes
+        .batch_execute("INSERT INTO t VALUES ('a')")
+        .unwrap();
+
+    // Consume the snapshot of a new durable subscription, then disconnect.
+    {
+        let mut client_reads = server.connect(postgres::NoTls).unwrap();
+        client_reads
+            .batch_execute(
+                "BEGIN;
+             DECLARE c CURSOR FOR SUBSCRIBE t WITH (NAME = durable_t);",
+            )
+            .unwrap();
+        let rows = client_reads.query("FETCH ALL c", &[]).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get::<_, String>("data"), "a");
+        // The snapshot is only acknowledged once the client asks for more rows after receiving
+        // it.
+        let rows = client_reads
+            .query("FETCH ALL c WITH (timeout = '2s')", &[])
+            .unwrap();
+        assert!(rows.is_empty());
+    }
+
+    client_writes
+        .batch_execute("INSERT INTO t VALUES ('b')")
+        .unwrap();
+
+    // Resuming the subscription only returns the updates that were not consumed before. The
+    // acknowledged frontier is recorded asynchronously, and the previous session is cleaned up
+    // asynchronously, so retry until both have happened.
+    Retry::default()
+        .max_duration(Duration::from_secs(30))
+        .retry(|_| {
+            let mut client_reads = server.connect(postgres::NoTls).unwrap();
+            client_reads
+                .batch_execute(
+                    "BEGIN;
+                 DECLARE c CURSOR FOR SUBSCRIBE t WITH (NAME = durable_t);",
+                )
+                .map_err(|e| e.to_string())?;
+            let rows = client_reads
+                .query("FETCH ALL c", &[])
+                .map_err(|e| e.to_string())?;
+            let data: Vec<String> = rows.iter().map(|row| row.get("data")).collect();
+            if data == ["b"] {
+                Ok(())
+            } else {
+                Err(format!("unexpected rows: {data:?}"))
+            }
+        })
+        .unwrap();
+
+    // Resuming with a different query is rejected.
+    client_writes
+        .batch_execute("CREATE TABLE u (data text)")
+        .unwrap();
+    let err = client_writes
+        .batch_execute("SUBSCRIBE u WITH (NAME = durable_t)")
+        .unwrap_db_error();
+    assert_contains!(
+        err.message(),
+        "was created for a query over different relations"
+    );
+
+    // The subscription can only be dropped once the resuming session has been cleaned up.
+    Retry::default()
+        .max_duration(Duration::from_secs(30))
+        .retry(|_| client_writes.batch_execute("DROP SUBSCRIPTION durable_t"))
+        .unwrap();
+    client_writes
+        .batch_execute("DROP SUBSCRIPTION IF EXISTS durable_t")
+        .unwrap();
+    let err = client_writes
+        .batch_execute("DROP SUBSCRIPTION durable_t")
+        .unwrap_db_error();
+    assert_eq!(err.code(), &SqlState::UNDEFINED_OBJECT);
+}
+
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // too slow
+fn test_subscribe_durable_resume_after_restart() {
+    let data_dir = tempfile::tempdir().unwrap();
+    let harness = test_util::TestHarness::default().data_directory(data_dir.path());
+
+    // Start a durable subscription, and disconnect before acknowledging anything.
+    {
+        let server = harness.clone().start_blocking();
+        server.enable_feature_flags(&["enable_durable_subscriptions"]);
+        server
+            .connect_internal(postgres::NoTls)
+            .unwrap()
+            .batch_execute("ALTER SYSTEM SET durable_subscription_checkpoint_interval = '100ms'")
+            .unwrap();
+        let mut client_writes = server.connect(postgres::NoTls).unwrap();
+        client_writes
+            .batch_execute("CREATE TABLE t (data text); CREATE TABLE u (data text);")
+            .unwrap();
+        client_writes
+            .batch_execute("INSERT INTO t VALUES ('a')")
+            .unwrap();
+
+        let mut client_reads = server.connect(postgres::NoTls).unwrap();
+        client_reads
+            .batch_execute(
+                "BEGIN;
+             DECLARE c CURSOR FOR SUBSCRIBE t WITH (NAME = durable_t);",
+            )
+            .unwrap();
+        let rows = client_reads.query("FETCH ALL c", &[]).unwrap();
+        assert_eq!(rows.len(), 1);
+    }
+
+    // The subscription survives the restart, and starts over with a snapshot because nothing was
+    // acknowledged.
+    {
+        let server = harness.clone().start_blocking();
+        let mut client_writes = server.connect(postgres::NoTls).unwrap();
+        let err = client_writes
+            .batch_execute("SUBSCRIBE u WITH (NAME = durable_t)")
+            .unwrap_db_error();
+        assert_contains!(
+            err.message(),
+            "was created for a query over different relations"
+        );
+
+        {
+            let mut client_reads = server.connect(postgres::NoTls).unwrap();
+            client_reads
+                .batch_execute(
+                    "BEGIN;
+                 DECLARE c CURSOR FOR SUBSCRIBE t WITH (NAME = durable_t);",
+                )
+                .unwrap();
+            let rows = client_reads.query("FETCH ALL c", &[]).unwrap();
+            let data: Vec<String> = rows.iter().map(|row| row.get("data")).collect();
+            assert_eq!(data, ["a"]);
+            // Acknowledge the snapshot.
+            let rows = client_reads
+                .query("FETCH ALL c WITH (timeout = '2s')", &[])
+                .unwrap();
+            assert!(rows.is_empty());
+        }
+
+        client_writes
+            .batch_execute("INSERT INTO t VALUES ('b')")
+            .unwrap();
+
+        // Wait until the acknowledged frontier has been written to the catalog.
+        Retry::default()
+            .max_duration(Duration::from_secs(30))
+            .retry(|_| {
+                let mut client_reads = server.connect(postgres::NoTls).unwrap();
+                client_reads
+                    .batch_execute(
+                        "BEGIN;
+                     DECLARE c CURSOR FOR SUBSCRIBE t WITH (NAME = durable_t);",
+                    )
+                    .map_err(|e| e.to_string())?;
+                let rows = client_reads
+                    .query("FETCH ALL c", &[])
+                    .map_err(|e| e.to_string())?;
+                let data: Vec<String> = rows.iter().map(|row| row.get("data")).collect();
+                if data == ["b"] {
+                    Ok(())
+                } else {
+                    Err(format!("unexpected rows: {data:?}"))
+                }
+            })
+            .unwrap();
+    }
+
+    // After another restart, the subscription resumes from the acknowledged frontier. The update
+    // that was delivered but not acknowledged is delivered again.
+    {
+        let server = harness.clone().start_blocking();
+        Retry::default()
+            .max_duration(Duration::from_secs(30))
+            .retry(|_| {
+                let mut client_reads = server.connect(postgres::NoTls).unwrap();
+                client_reads
+                    .batch_execute(
+                        "BEGIN;
+                     DECLARE c CURSOR FOR SUBSCRIBE t WITH (NAME = durable_t);",
+                    )
+                    .map_err(|e| e.to_string())?;
+                let rows = client_reads
+                    .query("FETCH ALL c", &[])
+                    .map_err(|e| e.to_string())?;
+                let data: Vec<String> = rows.iter().map(|row| row.get("data")).collect();
+                if data == ["b"] {
+                    Ok(())
+                } else {
+                    Err(format!("unexpected rows: {data:?}"))
+                }
+            })
+            .unwrap();
+    }
+}
+
 #[mz_ore::test]
 fn test_subscribe_fetch_wait() {
     let server = test_util::TestHarness::default().start_blocking();
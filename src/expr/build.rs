@@ -15,6 +15,7 @@ fn main() {
         .extern_path(".mz_pgtz", "::mz_pgtz")
         .extern_path(".mz_proto", "::mz_proto")
         .extern_path(".mz_repr.adt.array", "::mz_repr::adt::array")
+        .extern_path(".mz_repr.adt.bit", "::mz_repr::adt::bit")
         .extern_path(".mz_repr.adt.char", "::mz_repr::adt::char")
         .extern_path(".mz_repr.adt.datetime", "::mz_repr::adt::datetime")
         .extern_path(".mz_repr.adt.numeric", "::mz_repr::adt::numeric")
@@ -38,6 +39,7 @@ fn main() {
                 "expr/src/row/collection.proto",
                 "expr/src/scalar.proto",
                 "expr/src/scalar/func/format.proto",
+                "expr/src/scalar/jsonpath.proto",
                 "expr/src/scalar/like_pattern.proto",
             ],
             &[PathBuf::from(".."), mz_build_tools::protoc_include()],
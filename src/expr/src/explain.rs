@@ -73,6 +73,9 @@ pub struct ExplainSinglePlan<'a, T> {
 pub struct PushdownInfo<'a> {
     /// Pushdown-able filters in the source, by index.
     pub pushdown: Vec<&'a MirScalarExpr>,
+    /// The source columns needed by the MFP, if it doesn't need all of them.
+    /// Only these columns are fetched and decoded.
+    pub projection: Option<Vec<usize>>,
 }
 
 impl<'a, C, M> DisplayText<C> for HumanizedExpr<'a, PushdownInfo<'a>, M>
@@ -81,7 +84,10 @@ where
     M: HumanizerMode,
 {
     fn fmt_text(&self, f: &mut Formatter<'_>, ctx: &mut C) -> std::fmt::Result {
-        let PushdownInfo { pushdown } = self.expr;
+        let PushdownInfo {
+            pushdown,
+            projection,
+        } = self.expr;
 
         if !pushdown.is_empty() {
             let pushdown = pushdown.iter().map(|e| self.mode.expr(*e, self.cols));
@@ -89,6 +95,16 @@ where
             writeln!(f, "{}pushdown=({})", ctx.as_mut(), pushdown)?;
         }
 
+        if let Some(projection) = projection {
+            let columns = projection
+                .iter()
+                .map(|c| MirScalarExpr::column(*c))
+                .collect::<Vec<_>>();
+            let columns = columns.iter().map(|e| self.mode.expr(e, self.cols));
+            let columns = separated(", ", columns);
+            writeln!(f, "{}pushdown_projection=({})", ctx.as_mut(), columns)?;
+        }
+
         Ok(())
     }
 }
@@ -105,6 +121,7 @@ impl<'a> ExplainSource<'a> {
         id: GlobalId,
         op: Option<&'a MapFilterProject>,
         filter_pushdown: bool,
+        projection_pushdown: bool,
     ) -> ExplainSource<'a> {
         let pushdown_info = if filter_pushdown {
             op.map(|op| {
@@ -115,7 +132,13 @@ impl<'a> ExplainSource<'a> {
                     .filter(|(_, e)| mfp_mapped.expr(e).pushdownable())
                     .map(|(_, e)| e)
                     .collect();
-                PushdownInfo { pushdown }
+                let demand = op.demand();
+                let projection = (projection_pushdown && demand.len() < op.input_arity)
+                    .then(|| demand.into_iter().collect());
+                PushdownInfo {
+                    pushdown,
+                    projection,
+                }
             })
         } else {
             None
@@ -53,9 +53,19 @@ where
                         "op": op,
                     });
 
-                    if let Some(PushdownInfo { pushdown }) = pushdown_info {
+                    if let Some(PushdownInfo {
+                        pushdown,
+                        projection,
+                    }) = pushdown_info
+                    {
                         let object = json.as_object_mut().unwrap();
                         object.insert("pushdown".to_owned(), serde_json::json!(pushdown));
+                        if let Some(projection) = projection {
+                            object.insert(
+                                "pushdown_projection".to_owned(),
+                                serde_json::json!(projection),
+                            );
+                        }
                     }
 
                     json
@@ -52,7 +52,7 @@ pub use relation::{
 pub use scalar::func::{self, BinaryFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc};
 pub use scalar::{
     EvalError, FilterCharacteristics, MirScalarExpr, ProtoDomainLimit, ProtoEvalError,
-    ProtoMirScalarExpr, like_pattern,
+    ProtoMirScalarExpr, jsonpath, like_pattern,
 };
 
 /// A [`MirRelationExpr`] that claims to have been optimized, e.g., by an
@@ -3214,6 +3214,8 @@ impl AggregateExpr {
             | AggregateFunc::SumNumeric
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg
             | AggregateFunc::Dummy => self.expr.clone(),
         }
     }
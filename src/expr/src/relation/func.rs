@@ -27,6 +27,7 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::interval::Interval;
+use mz_repr::adt::multirange;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::adt::regex::Regex as ReprRegex;
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
@@ -197,6 +198,66 @@ where
         })
 }
 
+/// Packs the normalized `ranges` into a multirange datum.
+fn pack_multirange<'a>(
+    ranges: Vec<mz_repr::adt::range::Range<Datum<'a>>>,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
+    temp_storage.make_datum(|packer| {
+        packer.push_list_with(|packer| {
+            for range in ranges {
+                packer
+                    .push_range(range)
+                    .expect("normalized ranges are valid");
+            }
+        })
+    })
+}
+
+/// Unions multiranges, ignoring nulls.
+fn range_agg<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut datums = datums.into_iter().filter(|d| !d.is_null()).peekable();
+    if datums.peek().is_none() {
+        return Datum::Null;
+    }
+    // Collect every input range and merge them all at once, rather than
+    // re-normalizing the running union after each input.
+    let ranges = datums.flat_map(multirange::unwrap_ranges).collect();
+    pack_multirange(multirange::normalize(ranges), temp_storage)
+}
+
+/// Intersects either ranges or multiranges, ignoring nulls.
+fn range_intersect_agg<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut datums = datums.into_iter().filter(|d| !d.is_null()).peekable();
+    match datums.peek() {
+        None => Datum::Null,
+        Some(Datum::Range(_)) => {
+            let range = datums
+                .map(|d| d.unwrap_range())
+                .reduce(|l, r| l.intersection(&r))
+                .expect("peeked a datum");
+            temp_storage.make_datum(|packer| {
+                packer
+                    .push_range(range)
+                    .expect("intersections of valid ranges are valid")
+            })
+        }
+        Some(_) => {
+            let ranges = datums
+                .map(multirange::unwrap_ranges)
+                .reduce(|l, r| multirange::intersection(&l, &r))
+                .expect("peeked a datum");
+            pack_multirange(ranges, temp_storage)
+        }
+    }
+}
+
 fn string_agg<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -1887,6 +1948,11 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Unions multiranges into a single multirange.
+    RangeAgg,
+    /// Intersects ranges into a single range, or multiranges into a single
+    /// multirange.
+    RangeIntersectAgg,
     RowNumber {
         order_by: Vec<ColumnOrder>,
     },
@@ -2055,6 +2121,8 @@ impl Arbitrary for AggregateFunc {
                     window_frame,
                 })
                 .boxed(),
+            Just(AggregateFunc::RangeAgg).boxed(),
+            Just(AggregateFunc::RangeIntersectAgg).boxed(),
             Just(AggregateFunc::Dummy).boxed(),
         ])
     }
@@ -2192,6 +2260,8 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     order_by: Some(order_by.into_proto()),
                     window_frame: Some(window_frame.into_proto()),
                 }),
+                AggregateFunc::RangeAgg => Kind::RangeAgg(()),
+                AggregateFunc::RangeIntersectAgg => Kind::RangeIntersectAgg(()),
                 AggregateFunc::Dummy => Kind::Dummy(()),
             }),
         }
@@ -2338,6 +2408,8 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     .window_frame
                     .into_rust_if_some("ProtoFusedWindowAggregate::window_frame")?,
             },
+            Kind::RangeAgg(()) => AggregateFunc::RangeAgg,
+            Kind::RangeIntersectAgg(()) => AggregateFunc::RangeIntersectAgg,
             Kind::Dummy(()) => AggregateFunc::Dummy,
         })
     }
@@ -2414,6 +2486,8 @@ impl AggregateFunc {
             AggregateFunc::ArrayConcat { order_by } => array_concat(datums, temp_storage, order_by),
             AggregateFunc::ListConcat { order_by } => list_concat(datums, temp_storage, order_by),
             AggregateFunc::StringAgg { order_by } => string_agg(datums, temp_storage, order_by),
+            AggregateFunc::RangeAgg => range_agg(datums, temp_storage),
+            AggregateFunc::RangeIntersectAgg => range_intersect_agg(datums, temp_storage),
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
@@ -2642,7 +2716,9 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => Datum::Null,
         }
     }
 
@@ -2709,7 +2785,9 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => false,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => false,
         }
     }
 
@@ -2922,6 +3000,8 @@ impl AggregateFunc {
                 }
             }
             AggregateFunc::Dummy
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg
             | AggregateFunc::MaxNumeric
             | AggregateFunc::MaxInt16
             | AggregateFunc::MaxInt32
@@ -3381,6 +3461,8 @@ impl AggregateFunc {
             Self::ArrayConcat { .. } => "array_agg",
             Self::ListConcat { .. } => "list_agg",
             Self::StringAgg { .. } => "string_agg",
+            Self::RangeAgg => "range_agg",
+            Self::RangeIntersectAgg => "range_intersect_agg",
             Self::RowNumber { .. } => "row_number",
             Self::Rank { .. } => "rank",
             Self::DenseRank { .. } => "dense_rank",
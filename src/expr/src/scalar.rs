@@ -41,11 +41,13 @@ use crate::scalar::func::{
     BinaryFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc, parse_timezone,
     regexp_replace_parse_flags,
 };
+use crate::scalar::jsonpath::JsonPathFunc;
 use crate::scalar::proto_eval_error::proto_incompatible_array_dimensions::ProtoDims;
 use crate::scalar::proto_mir_scalar_expr::*;
 use crate::visit::{Visit, VisitChildren};
 
 pub mod func;
+pub mod jsonpath;
 pub mod like_pattern;
 
 include!(concat!(env!("OUT_DIR"), "/mz_expr.scalar.rs"));
@@ -1308,6 +1310,39 @@ impl MirScalarExpr {
                                     e.typ(column_types).scalar_type,
                                 ),
                             };
+                        } else if matches!(func, VariadicFunc::JsonbPath { .. })
+                            && exprs[1].as_literal_str().is_some()
+                            && matches!(exprs[3].as_literal(), Some(Ok(d)) if !d.is_null())
+                        {
+                            let kind = match func {
+                                VariadicFunc::JsonbPath { kind } => *kind,
+                                _ => unreachable!(),
+                            };
+                            // Only a non-NULL `silent` is folded: a NULL one makes the
+                            // result NULL, which is left to evaluation.
+                            let silent = exprs[3].as_literal() == Some(Ok(Datum::True));
+                            // As with `regexp_replace`, an invalid path is only an error if the
+                            // target is non-NULL.
+                            *e = match jsonpath::compile(exprs[1].as_literal_str().unwrap()) {
+                                Ok(path) => {
+                                    let mut exprs = mem::take(exprs);
+                                    let vars = exprs.swap_remove(2);
+                                    let target = exprs.swap_remove(0);
+                                    target.call_binary(
+                                        vars,
+                                        BinaryFunc::JsonbPath(JsonPathFunc { kind, path, silent }),
+                                    )
+                                }
+                                Err(err) => {
+                                    let mut exprs = mem::take(exprs);
+                                    let target = exprs.swap_remove(0);
+                                    let scalar_type = e.typ(column_types).scalar_type;
+                                    target.call_is_null().if_then_else(
+                                        MirScalarExpr::literal_null(scalar_type.clone()),
+                                        MirScalarExpr::literal(Err(err), scalar_type),
+                                    )
+                                }
+                            };
                         } else if *func == VariadicFunc::ListIndex && is_list_create_call(&exprs[0])
                         {
                             // We are looking for ListIndex(ListCreate, literal), and eliminate
@@ -2641,6 +2676,9 @@ pub enum EvalError {
     InvalidRegexFlag(char),
     InvalidParameterValue(Box<str>),
     InvalidDatePart(Box<str>),
+    InvalidJsonPath(Box<str>),
+    JsonPathEvaluation(Box<str>),
+    InvalidBitString(Box<str>),
     KeyCannotBeNull,
     NegSqrt,
     NegLimit,
@@ -2803,6 +2841,9 @@ impl fmt::Display for EvalError {
             }
             EvalError::Parse(e) => e.fmt(f),
             EvalError::PrettyError(e) => e.fmt(f),
+            EvalError::InvalidJsonPath(e) => e.fmt(f),
+            EvalError::JsonPathEvaluation(e) => e.fmt(f),
+            EvalError::InvalidBitString(e) => e.fmt(f),
             EvalError::ParseHex(e) => e.fmt(f),
             EvalError::Internal(s) => write!(f, "internal error: {}", s),
             EvalError::InfinityOutOfDomain(s) => {
@@ -3103,6 +3144,9 @@ impl RustType<ProtoEvalError> for EvalError {
             EvalError::UnterminatedLikeEscapeSequence => UnterminatedLikeEscapeSequence(()),
             EvalError::Parse(error) => Parse(error.into_proto()),
             EvalError::PrettyError(error) => PrettyError(error.into_proto()),
+            EvalError::InvalidJsonPath(v) => InvalidJsonPath(v.into_proto()),
+            EvalError::JsonPathEvaluation(v) => JsonPathEvaluation(v.into_proto()),
+            EvalError::InvalidBitString(v) => InvalidBitString(v.into_proto()),
             EvalError::ParseHex(error) => ParseHex(error.into_proto()),
             EvalError::Internal(v) => Internal(v.into_proto()),
             EvalError::InfinityOutOfDomain(v) => InfinityOutOfDomain(v.into_proto()),
@@ -3287,6 +3331,9 @@ impl RustType<ProtoEvalError> for EvalError {
                 MzAclArrayNullElement(()) => Ok(EvalError::MzAclArrayNullElement),
                 InvalidIanaTimezoneId(s) => Ok(EvalError::InvalidIanaTimezoneId(s.into())),
                 PrettyError(s) => Ok(EvalError::PrettyError(s.into())),
+                InvalidJsonPath(s) => Ok(EvalError::InvalidJsonPath(s.into())),
+                JsonPathEvaluation(s) => Ok(EvalError::JsonPathEvaluation(s.into())),
+                InvalidBitString(s) => Ok(EvalError::InvalidBitString(s.into())),
             },
             None => Err(TryFromProtoError::missing_field("ProtoEvalError::kind")),
         }
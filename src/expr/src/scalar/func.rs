@@ -41,9 +41,11 @@ use mz_pgtz::timezone::{Timezone, TimezoneSpec};
 use mz_proto::chrono::any_naive_datetime;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::bit::{BitString, BitStringError};
 use mz_repr::adt::date::Date;
 use mz_repr::adt::interval::{Interval, RoundBehavior};
 use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::multirange;
 use mz_repr::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
 use mz_repr::adt::range::{self, Range, RangeBound, RangeOps};
@@ -64,6 +66,7 @@ use sha2::{Sha224, Sha256, Sha384, Sha512};
 use subtle::ConstantTimeEq;
 
 use crate::scalar::func::format::DateTimeFormat;
+use crate::scalar::jsonpath::{self, JsonPathFunc, JsonPathKind};
 use crate::scalar::{
     ProtoBinaryFunc, ProtoUnaryFunc, ProtoUnmaterializableFunc, ProtoVariadicFunc,
 };
@@ -102,6 +105,12 @@ pub enum UnmaterializableFunc {
     MzUptime,
     MzVersion,
     MzVersionNum,
+    /// A placeholder for the value of the parameter at `position` of a prepared statement,
+    /// which is bound when the statement is executed.
+    Parameter {
+        position: usize,
+        typ: ScalarType,
+    },
     PgBackendPid,
     PgPostmasterStartTime,
     SessionUser,
@@ -144,6 +153,7 @@ impl UnmaterializableFunc {
             UnmaterializableFunc::MzUptime => ScalarType::Interval.nullable(true),
             UnmaterializableFunc::MzVersion => ScalarType::String.nullable(false),
             UnmaterializableFunc::MzVersionNum => ScalarType::Int32.nullable(false),
+            UnmaterializableFunc::Parameter { typ, .. } => typ.clone().nullable(true),
             UnmaterializableFunc::PgBackendPid => ScalarType::Int32.nullable(false),
             UnmaterializableFunc::PgPostmasterStartTime => {
                 ScalarType::TimestampTz { precision: None }.nullable(false)
@@ -179,6 +189,7 @@ impl fmt::Display for UnmaterializableFunc {
             UnmaterializableFunc::MzUptime => f.write_str("mz_uptime"),
             UnmaterializableFunc::MzVersion => f.write_str("mz_version"),
             UnmaterializableFunc::MzVersionNum => f.write_str("mz_version_num"),
+            UnmaterializableFunc::Parameter { position, .. } => write!(f, "${position}"),
             UnmaterializableFunc::PgBackendPid => f.write_str("pg_backend_pid"),
             UnmaterializableFunc::PgPostmasterStartTime => f.write_str("pg_postmaster_start_time"),
             UnmaterializableFunc::SessionUser => f.write_str("session_user"),
@@ -208,6 +219,12 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
             UnmaterializableFunc::MzUptime => MzUptime(()),
             UnmaterializableFunc::MzVersion => MzVersion(()),
             UnmaterializableFunc::MzVersionNum => MzVersionNum(()),
+            UnmaterializableFunc::Parameter { position, typ } => {
+                Parameter(crate::scalar::proto_unmaterializable_func::ProtoParameter {
+                    position: position.into_proto(),
+                    typ: Some(typ.into_proto()),
+                })
+            }
             UnmaterializableFunc::PgBackendPid => PgBackendPid(()),
             UnmaterializableFunc::PgPostmasterStartTime => PgPostmasterStartTime(()),
             UnmaterializableFunc::SessionUser => SessionUser(()),
@@ -238,6 +255,10 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
                 MzUptime(()) => Ok(UnmaterializableFunc::MzUptime),
                 MzVersion(()) => Ok(UnmaterializableFunc::MzVersion),
                 MzVersionNum(()) => Ok(UnmaterializableFunc::MzVersionNum),
+                Parameter(inner) => Ok(UnmaterializableFunc::Parameter {
+                    position: usize::from_proto(inner.position)?,
+                    typ: inner.typ.into_rust_if_some("ProtoParameter::typ")?,
+                }),
                 PgBackendPid(()) => Ok(UnmaterializableFunc::PgBackendPid),
                 PgPostmasterStartTime(()) => Ok(UnmaterializableFunc::PgPostmasterStartTime),
                 SessionUser(()) => Ok(UnmaterializableFunc::SessionUser),
@@ -1987,6 +2008,79 @@ fn get_bit<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     Ok(Datum::from(i32::from(i)))
 }
 
+fn bit_string_binary_op<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+    op: impl FnOnce(&BitString, &BitString) -> Result<BitString, BitStringError>,
+) -> Result<Datum<'a>, EvalError> {
+    let a = BitString::decode(a.unwrap_bytes());
+    let b = BitString::decode(b.unwrap_bytes());
+    let out = op(&a, &b).map_err(|e| EvalError::InvalidBitString(e.to_string().into()))?;
+    Ok(Datum::Bytes(temp_storage.push_bytes(out.encode())))
+}
+
+fn bit_string_shift<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+    left: bool,
+) -> Datum<'a> {
+    let s = BitString::decode(a.unwrap_bytes());
+    let n = b.unwrap_int32();
+    // As in PostgreSQL, shifting by a negative amount shifts in the opposite
+    // direction.
+    let n = if left { n } else { n.saturating_neg() };
+    Datum::Bytes(temp_storage.push_bytes(s.shift_left(n).encode()))
+}
+
+fn bit_string_concat<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
+    let a = BitString::decode(a.unwrap_bytes());
+    let b = BitString::decode(b.unwrap_bytes());
+    Datum::Bytes(temp_storage.push_bytes(a.concat(&b).encode()))
+}
+
+fn get_bit_bit_string<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let s = BitString::decode(a.unwrap_bytes());
+    let index = b.unwrap_int32();
+    let err = EvalError::IndexOutOfRange {
+        provided: index,
+        valid_end: i32::try_from(s.len()).unwrap_or(i32::MAX) - 1,
+    };
+    let index = usize::try_from(index).map_err(|_| err.clone())?;
+    if index >= s.len() {
+        return Err(err);
+    }
+    Ok(Datum::from(i32::from(s.get(index))))
+}
+
+fn set_bit_bit_string<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let mut s = BitString::decode(datums[0].unwrap_bytes());
+    let index = datums[1].unwrap_int32();
+    let err = EvalError::IndexOutOfRange {
+        provided: index,
+        valid_end: i32::try_from(s.len()).unwrap_or(i32::MAX) - 1,
+    };
+    let index = usize::try_from(index).map_err(|_| err.clone())?;
+    if index >= s.len() {
+        return Err(err);
+    }
+    let bit = match datums[2].unwrap_int32() {
+        0 => false,
+        1 => true,
+        _ => {
+            return Err(EvalError::InvalidParameterValue(
+                "new bit must be 0 or 1".into(),
+            ));
+        }
+    };
+    s.set(index, bit);
+    Ok(Datum::Bytes(temp_storage.push_bytes(s.encode())))
+}
+
 #[sqlfunc(output_type = "i32", propagates_nulls = true)]
 fn get_byte<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let bytes = a.unwrap_bytes();
@@ -2191,6 +2285,79 @@ fn range_difference<'a>(
     l.difference(&r)?.into_result(temp_storage)
 }
 
+/// Packs the normalized `ranges` into a multirange datum.
+fn make_multirange<'a>(ranges: Vec<Range<Datum<'a>>>, temp_storage: &'a RowArena) -> Datum<'a> {
+    temp_storage.make_datum(|packer| {
+        packer.push_list_with(|packer| {
+            for range in ranges {
+                packer
+                    .push_range(range)
+                    .expect("normalized ranges are valid");
+            }
+        })
+    })
+}
+
+fn multirange_contains_elem<'a, R: RangeOps<'a>>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a>
+where
+    <R as TryFrom<Datum<'a>>>::Error: std::fmt::Debug,
+{
+    let ranges = multirange::unwrap_ranges(a);
+    let elem = R::try_from(b).expect("type checking must produce correct R");
+    Datum::from(multirange::contains_elem(&ranges, &elem))
+}
+
+fn multirange_contains_range<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    let ranges = multirange::unwrap_ranges(a);
+    Datum::from(multirange::contains_range(&ranges, &b.unwrap_range()))
+}
+
+fn multirange_contains_multirange<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    let l = multirange::unwrap_ranges(a);
+    let r = multirange::unwrap_ranges(b);
+    Datum::from(multirange::contains_multirange(&l, &r))
+}
+
+fn multirange_overlaps_range<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    let ranges = multirange::unwrap_ranges(a);
+    Datum::from(multirange::overlaps_range(&ranges, &b.unwrap_range()))
+}
+
+fn multirange_overlaps_multirange<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    let l = multirange::unwrap_ranges(a);
+    let r = multirange::unwrap_ranges(b);
+    Datum::from(multirange::overlaps_multirange(&l, &r))
+}
+
+fn multirange_union<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Datum<'a> {
+    let l = multirange::unwrap_ranges(a);
+    let r = multirange::unwrap_ranges(b);
+    make_multirange(multirange::union(&l, &r), temp_storage)
+}
+
+fn multirange_intersection<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Datum<'a> {
+    let l = multirange::unwrap_ranges(a);
+    let r = multirange::unwrap_ranges(b);
+    make_multirange(multirange::intersection(&l, &r), temp_storage)
+}
+
+fn multirange_difference<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let l = multirange::unwrap_ranges(a);
+    let r = multirange::unwrap_ranges(b);
+    Ok(make_multirange(
+        multirange::difference(&l, &r)?,
+        temp_storage,
+    ))
+}
+
 #[sqlfunc(
     output_type = "bool",
     is_infix_op = true,
@@ -3508,12 +3675,28 @@ pub enum BinaryFunc {
     RangeUnion,
     RangeIntersection,
     RangeDifference,
+    MultiRangeContainsElem { elem_type: ScalarType, rev: bool },
+    MultiRangeContainsRange { rev: bool },
+    MultiRangeContainsMultiRange { rev: bool },
+    MultiRangeOverlapsRange,
+    MultiRangeOverlapsMultiRange,
+    MultiRangeUnion,
+    MultiRangeIntersection,
+    MultiRangeDifference,
+    BitAndBitString,
+    BitOrBitString,
+    BitXorBitString,
+    BitShiftLeftBitString,
+    BitShiftRightBitString,
+    BitStringConcat,
+    GetBitBitString,
     UuidGenerateV5,
     MzAclItemContainsPrivilege,
     ParseIdent,
     PrettySql,
     RegexpReplace { regex: Regex, limit: usize },
     StartsWith,
+    JsonbPath(JsonPathFunc),
 }
 
 impl BinaryFunc {
@@ -3775,6 +3958,37 @@ impl BinaryFunc {
             BinaryFunc::RangeUnion => range_union(a, b, temp_storage),
             BinaryFunc::RangeIntersection => range_intersection(a, b, temp_storage),
             BinaryFunc::RangeDifference => range_difference(a, b, temp_storage),
+            BinaryFunc::MultiRangeContainsElem { elem_type, rev: _ } => Ok(match elem_type {
+                ScalarType::Int32 => multirange_contains_elem::<i32>(a, b),
+                ScalarType::Int64 => multirange_contains_elem::<i64>(a, b),
+                ScalarType::Date => multirange_contains_elem::<Date>(a, b),
+                ScalarType::Numeric { .. } => {
+                    multirange_contains_elem::<OrderedDecimal<Numeric>>(a, b)
+                }
+                ScalarType::Timestamp { .. } => {
+                    multirange_contains_elem::<CheckedTimestamp<NaiveDateTime>>(a, b)
+                }
+                ScalarType::TimestampTz { .. } => {
+                    multirange_contains_elem::<CheckedTimestamp<DateTime<Utc>>>(a, b)
+                }
+                _ => unreachable!(),
+            }),
+            BinaryFunc::MultiRangeContainsRange { rev: _ } => Ok(multirange_contains_range(a, b)),
+            BinaryFunc::MultiRangeContainsMultiRange { rev: _ } => {
+                Ok(multirange_contains_multirange(a, b))
+            }
+            BinaryFunc::MultiRangeOverlapsRange => Ok(multirange_overlaps_range(a, b)),
+            BinaryFunc::MultiRangeOverlapsMultiRange => Ok(multirange_overlaps_multirange(a, b)),
+            BinaryFunc::MultiRangeUnion => Ok(multirange_union(a, b, temp_storage)),
+            BinaryFunc::MultiRangeIntersection => Ok(multirange_intersection(a, b, temp_storage)),
+            BinaryFunc::MultiRangeDifference => multirange_difference(a, b, temp_storage),
+            BinaryFunc::BitAndBitString => bit_string_binary_op(a, b, temp_storage, BitString::and),
+            BinaryFunc::BitOrBitString => bit_string_binary_op(a, b, temp_storage, BitString::or),
+            BinaryFunc::BitXorBitString => bit_string_binary_op(a, b, temp_storage, BitString::xor),
+            BinaryFunc::BitShiftLeftBitString => Ok(bit_string_shift(a, b, temp_storage, true)),
+            BinaryFunc::BitShiftRightBitString => Ok(bit_string_shift(a, b, temp_storage, false)),
+            BinaryFunc::BitStringConcat => Ok(bit_string_concat(a, b, temp_storage)),
+            BinaryFunc::GetBitBitString => get_bit_bit_string(a, b),
             BinaryFunc::UuidGenerateV5 => Ok(uuid_generate_v5(a, b)),
             BinaryFunc::MzAclItemContainsPrivilege => mz_acl_item_contains_privilege(a, b),
             BinaryFunc::ParseIdent => parse_ident(a, b, temp_storage),
@@ -3783,6 +3997,7 @@ impl BinaryFunc {
                 regexp_replace_static(a, b, regex, *limit, temp_storage)
             }
             BinaryFunc::StartsWith => Ok(starts_with(a, b)),
+            BinaryFunc::JsonbPath(func) => func.eval(a, b, temp_storage),
         }
     }
 
@@ -3971,6 +4186,34 @@ impl BinaryFunc {
                 input1_type.scalar_type.without_modifiers().nullable(true)
             }
 
+            MultiRangeContainsElem { .. }
+            | MultiRangeContainsRange { .. }
+            | MultiRangeContainsMultiRange { .. }
+            | MultiRangeOverlapsRange
+            | MultiRangeOverlapsMultiRange => ScalarType::Bool.nullable(in_nullable),
+
+            MultiRangeUnion | MultiRangeIntersection | MultiRangeDifference => {
+                soft_assert_eq_or_log!(
+                    input1_type.scalar_type.without_modifiers(),
+                    input2_type.scalar_type.without_modifiers()
+                );
+                input1_type
+                    .scalar_type
+                    .without_modifiers()
+                    .nullable(in_nullable)
+            }
+
+            BitAndBitString
+            | BitOrBitString
+            | BitXorBitString
+            | BitShiftLeftBitString
+            | BitShiftRightBitString => input1_type
+                .scalar_type
+                .without_modifiers()
+                .nullable(in_nullable),
+            BitStringConcat => ScalarType::VarBit { max_length: None }.nullable(in_nullable),
+            GetBitBitString => ScalarType::Int32.nullable(in_nullable),
+
             MzAclItemContainsPrivilege => ScalarType::Bool.nullable(in_nullable),
 
             ParseIdent => ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable),
@@ -3978,6 +4221,8 @@ impl BinaryFunc {
             RegexpReplace { .. } => ScalarType::String.nullable(in_nullable),
 
             StartsWith => ScalarType::Bool.nullable(in_nullable),
+
+            JsonbPath(func) => jsonb_path_output_type(func.kind, in_nullable),
         }
     }
 
@@ -4178,6 +4423,21 @@ impl BinaryFunc {
             | RangeUnion
             | RangeIntersection
             | RangeDifference
+            | MultiRangeContainsElem { .. }
+            | MultiRangeContainsRange { .. }
+            | MultiRangeContainsMultiRange { .. }
+            | MultiRangeOverlapsRange
+            | MultiRangeOverlapsMultiRange
+            | MultiRangeUnion
+            | MultiRangeIntersection
+            | MultiRangeDifference
+            | BitAndBitString
+            | BitOrBitString
+            | BitXorBitString
+            | BitShiftLeftBitString
+            | BitShiftRightBitString
+            | BitStringConcat
+            | GetBitBitString
             | UuidGenerateV5
             | MzAclItemContainsPrivilege
             | ParseIdent
@@ -4198,7 +4458,8 @@ impl BinaryFunc {
             | ListLengthMax { .. }
             | ArrayLength
             | ArrayLower
-            | ArrayUpper => true,
+            | ArrayUpper
+            | JsonbPath(_) => true,
         }
     }
 
@@ -4342,7 +4603,21 @@ impl BinaryFunc {
             | RangeAdjacent
             | RangeUnion
             | RangeIntersection
-            | RangeDifference => true,
+            | RangeDifference
+            | MultiRangeContainsElem { .. }
+            | MultiRangeContainsRange { .. }
+            | MultiRangeContainsMultiRange { .. }
+            | MultiRangeOverlapsRange
+            | MultiRangeOverlapsMultiRange
+            | MultiRangeUnion
+            | MultiRangeIntersection
+            | MultiRangeDifference
+            | BitAndBitString
+            | BitOrBitString
+            | BitXorBitString
+            | BitShiftLeftBitString
+            | BitShiftRightBitString
+            | BitStringConcat => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | AgeTimestamp
@@ -4391,6 +4666,7 @@ impl BinaryFunc {
             | LikeEscape
             | UuidGenerateV5
             | GetBit
+            | GetBitBitString
             | GetByte
             | MzAclItemContainsPrivilege
             | ConstantTimeEqBytes
@@ -4398,7 +4674,8 @@ impl BinaryFunc {
             | ParseIdent
             | PrettySql
             | RegexpReplace { .. }
-            | StartsWith => false,
+            | StartsWith
+            | JsonbPath(_) => false,
         }
     }
 
@@ -4485,6 +4762,16 @@ impl BinaryFunc {
             | BinaryFunc::RangeOverleft
             | BinaryFunc::RangeOverright
             | BinaryFunc::RangeAdjacent
+            | BinaryFunc::MultiRangeContainsElem { .. }
+            | BinaryFunc::MultiRangeContainsRange { .. }
+            | BinaryFunc::MultiRangeContainsMultiRange { .. }
+            | BinaryFunc::MultiRangeOverlapsRange
+            | BinaryFunc::MultiRangeOverlapsMultiRange
+            | BinaryFunc::MultiRangeUnion
+            | BinaryFunc::MultiRangeIntersection
+            | BinaryFunc::BitShiftLeftBitString
+            | BinaryFunc::BitShiftRightBitString
+            | BinaryFunc::BitStringConcat
             | BinaryFunc::ArrayLower
             | BinaryFunc::ArrayContains
             | BinaryFunc::ArrayContainsArray { rev: _ }
@@ -4707,7 +4994,22 @@ impl BinaryFunc {
             | BinaryFunc::RangeAdjacent
             | BinaryFunc::RangeUnion
             | BinaryFunc::RangeIntersection
-            | BinaryFunc::RangeDifference => (false, false),
+            | BinaryFunc::RangeDifference
+            | BinaryFunc::MultiRangeContainsElem { .. }
+            | BinaryFunc::MultiRangeContainsRange { .. }
+            | BinaryFunc::MultiRangeContainsMultiRange { .. }
+            | BinaryFunc::MultiRangeOverlapsRange
+            | BinaryFunc::MultiRangeOverlapsMultiRange
+            | BinaryFunc::MultiRangeUnion
+            | BinaryFunc::MultiRangeIntersection
+            | BinaryFunc::MultiRangeDifference => (false, false),
+            BinaryFunc::BitAndBitString
+            | BinaryFunc::BitOrBitString
+            | BinaryFunc::BitXorBitString
+            | BinaryFunc::BitShiftLeftBitString
+            | BinaryFunc::BitShiftRightBitString
+            | BinaryFunc::BitStringConcat
+            | BinaryFunc::GetBitBitString => (false, false),
             BinaryFunc::UuidGenerateV5 => (false, false),
             BinaryFunc::MzAclItemContainsPrivilege => (false, false),
             BinaryFunc::ParseIdent => (false, false),
@@ -4715,6 +5017,7 @@ impl BinaryFunc {
             BinaryFunc::PrettySql => (false, false),
             BinaryFunc::RegexpReplace { .. } => (false, false),
             BinaryFunc::StartsWith => (false, false),
+            BinaryFunc::JsonbPath(_) => (false, false),
         }
     }
 }
@@ -4921,6 +5224,24 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::RangeUnion => f.write_str("+"),
             BinaryFunc::RangeIntersection => f.write_str("*"),
             BinaryFunc::RangeDifference => f.write_str("-"),
+            BinaryFunc::MultiRangeContainsElem { rev, .. }
+            | BinaryFunc::MultiRangeContainsRange { rev }
+            | BinaryFunc::MultiRangeContainsMultiRange { rev } => {
+                f.write_str(if *rev { "<@" } else { "@>" })
+            }
+            BinaryFunc::MultiRangeOverlapsRange | BinaryFunc::MultiRangeOverlapsMultiRange => {
+                f.write_str("&&")
+            }
+            BinaryFunc::MultiRangeUnion => f.write_str("+"),
+            BinaryFunc::MultiRangeIntersection => f.write_str("*"),
+            BinaryFunc::MultiRangeDifference => f.write_str("-"),
+            BinaryFunc::BitAndBitString => f.write_str("&"),
+            BinaryFunc::BitOrBitString => f.write_str("|"),
+            BinaryFunc::BitXorBitString => f.write_str("#"),
+            BinaryFunc::BitShiftLeftBitString => f.write_str("<<"),
+            BinaryFunc::BitShiftRightBitString => f.write_str(">>"),
+            BinaryFunc::BitStringConcat => f.write_str("||"),
+            BinaryFunc::GetBitBitString => f.write_str("get_bit"),
             BinaryFunc::UuidGenerateV5 => f.write_str("uuid_generate_v5"),
             BinaryFunc::MzAclItemContainsPrivilege => f.write_str("mz_aclitem_contains_privilege"),
             BinaryFunc::ParseIdent => f.write_str("parse_ident"),
@@ -4933,6 +5254,13 @@ impl fmt::Display for BinaryFunc {
                 limit
             ),
             BinaryFunc::StartsWith => f.write_str("starts_with"),
+            BinaryFunc::JsonbPath(func) => write!(
+                f,
+                "{}[{}, silent={}]",
+                func.kind,
+                func.path.source.escaped(),
+                func.silent
+            ),
         }
     }
 }
@@ -5142,7 +5470,31 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::RangeUnion).boxed(),
             Just(BinaryFunc::RangeIntersection).boxed(),
             Just(BinaryFunc::RangeDifference).boxed(),
+            (bool::arbitrary(), mz_repr::arb_range_type())
+                .prop_map(|(rev, elem_type)| BinaryFunc::MultiRangeContainsElem { elem_type, rev })
+                .boxed(),
+            bool::arbitrary()
+                .prop_map(|rev| BinaryFunc::MultiRangeContainsRange { rev })
+                .boxed(),
+            bool::arbitrary()
+                .prop_map(|rev| BinaryFunc::MultiRangeContainsMultiRange { rev })
+                .boxed(),
+            Just(BinaryFunc::MultiRangeOverlapsRange).boxed(),
+            Just(BinaryFunc::MultiRangeOverlapsMultiRange).boxed(),
+            Just(BinaryFunc::MultiRangeUnion).boxed(),
+            Just(BinaryFunc::MultiRangeIntersection).boxed(),
+            Just(BinaryFunc::MultiRangeDifference).boxed(),
+            Just(BinaryFunc::BitAndBitString).boxed(),
+            Just(BinaryFunc::BitOrBitString).boxed(),
+            Just(BinaryFunc::BitXorBitString).boxed(),
+            Just(BinaryFunc::BitShiftLeftBitString).boxed(),
+            Just(BinaryFunc::BitShiftRightBitString).boxed(),
+            Just(BinaryFunc::BitStringConcat).boxed(),
+            Just(BinaryFunc::GetBitBitString).boxed(),
             Just(BinaryFunc::ParseIdent).boxed(),
+            jsonpath::any_json_path_func()
+                .prop_map(BinaryFunc::JsonbPath)
+                .boxed(),
         ])
     }
 }
@@ -5342,6 +5694,26 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::RangeUnion => RangeUnion(()),
             BinaryFunc::RangeIntersection => RangeIntersection(()),
             BinaryFunc::RangeDifference => RangeDifference(()),
+            BinaryFunc::MultiRangeContainsElem { elem_type, rev } => {
+                MultiRangeContainsElem(crate::scalar::proto_binary_func::ProtoRangeContainsInner {
+                    elem_type: Some(elem_type.into_proto()),
+                    rev: *rev,
+                })
+            }
+            BinaryFunc::MultiRangeContainsRange { rev } => MultiRangeContainsRange(*rev),
+            BinaryFunc::MultiRangeContainsMultiRange { rev } => MultiRangeContainsMultiRange(*rev),
+            BinaryFunc::MultiRangeOverlapsRange => MultiRangeOverlapsRange(()),
+            BinaryFunc::MultiRangeOverlapsMultiRange => MultiRangeOverlapsMultiRange(()),
+            BinaryFunc::MultiRangeUnion => MultiRangeUnion(()),
+            BinaryFunc::MultiRangeIntersection => MultiRangeIntersection(()),
+            BinaryFunc::MultiRangeDifference => MultiRangeDifference(()),
+            BinaryFunc::BitAndBitString => BitAndBitString(()),
+            BinaryFunc::BitOrBitString => BitOrBitString(()),
+            BinaryFunc::BitXorBitString => BitXorBitString(()),
+            BinaryFunc::BitShiftLeftBitString => BitShiftLeftBitString(()),
+            BinaryFunc::BitShiftRightBitString => BitShiftRightBitString(()),
+            BinaryFunc::BitStringConcat => BitStringConcat(()),
+            BinaryFunc::GetBitBitString => GetBitBitString(()),
             BinaryFunc::UuidGenerateV5 => UuidGenerateV5(()),
             BinaryFunc::MzAclItemContainsPrivilege => MzAclItemContainsPrivilege(()),
             BinaryFunc::ParseIdent => ParseIdent(()),
@@ -5356,6 +5728,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 })
             }
             BinaryFunc::StartsWith => StartsWith(()),
+            BinaryFunc::JsonbPath(func) => JsonbPath(func.into_proto()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -5559,6 +5932,28 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 RangeUnion(()) => Ok(BinaryFunc::RangeUnion),
                 RangeIntersection(()) => Ok(BinaryFunc::RangeIntersection),
                 RangeDifference(()) => Ok(BinaryFunc::RangeDifference),
+                MultiRangeContainsElem(inner) => Ok(BinaryFunc::MultiRangeContainsElem {
+                    elem_type: inner
+                        .elem_type
+                        .into_rust_if_some("ProtoRangeContainsInner::elem_type")?,
+                    rev: inner.rev,
+                }),
+                MultiRangeContainsRange(rev) => Ok(BinaryFunc::MultiRangeContainsRange { rev }),
+                MultiRangeContainsMultiRange(rev) => {
+                    Ok(BinaryFunc::MultiRangeContainsMultiRange { rev })
+                }
+                MultiRangeOverlapsRange(()) => Ok(BinaryFunc::MultiRangeOverlapsRange),
+                MultiRangeOverlapsMultiRange(()) => Ok(BinaryFunc::MultiRangeOverlapsMultiRange),
+                MultiRangeUnion(()) => Ok(BinaryFunc::MultiRangeUnion),
+                MultiRangeIntersection(()) => Ok(BinaryFunc::MultiRangeIntersection),
+                MultiRangeDifference(()) => Ok(BinaryFunc::MultiRangeDifference),
+                BitAndBitString(()) => Ok(BinaryFunc::BitAndBitString),
+                BitOrBitString(()) => Ok(BinaryFunc::BitOrBitString),
+                BitXorBitString(()) => Ok(BinaryFunc::BitXorBitString),
+                BitShiftLeftBitString(()) => Ok(BinaryFunc::BitShiftLeftBitString),
+                BitShiftRightBitString(()) => Ok(BinaryFunc::BitShiftRightBitString),
+                BitStringConcat(()) => Ok(BinaryFunc::BitStringConcat),
+                GetBitBitString(()) => Ok(BinaryFunc::GetBitBitString),
                 UuidGenerateV5(()) => Ok(BinaryFunc::UuidGenerateV5),
                 MzAclItemContainsPrivilege(()) => Ok(BinaryFunc::MzAclItemContainsPrivilege),
                 ParseIdent(()) => Ok(BinaryFunc::ParseIdent),
@@ -5570,6 +5965,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                     limit: inner.limit.into_rust()?,
                 }),
                 StartsWith(()) => Ok(BinaryFunc::StartsWith),
+                JsonbPath(func) => Ok(BinaryFunc::JsonbPath(func.into_rust()?)),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -5901,6 +6297,7 @@ derive_unary!(
     CastStringToList,
     CastStringToMap,
     CastStringToRange,
+    CastStringToMultiRange,
     CastStringToTime,
     CastStringToTimestamp,
     CastStringToTimestampTz,
@@ -5912,6 +6309,15 @@ derive_unary!(
     CastStringToVarChar,
     CastCharToString,
     CastVarCharToString,
+    CastStringToBit,
+    CastStringToVarBit,
+    CastBitToBit,
+    CastBitToVarBit,
+    CastBitToString,
+    CastInt32ToBit,
+    CastInt64ToBit,
+    CastBitToInt32,
+    CastBitToInt64,
     CastDateToTimestamp,
     CastDateToTimestampTz,
     CastDateToString,
@@ -5957,6 +6363,7 @@ derive_unary!(
     CastMapToString,
     CastInt2VectorToString,
     CastRangeToString,
+    CastMultiRangeToString,
     CeilFloat32,
     CeilFloat64,
     CeilNumeric,
@@ -5966,6 +6373,9 @@ derive_unary!(
     Ascii,
     BitCountBytes,
     BitLengthBytes,
+    BitNotBitString,
+    BitCountBitString,
+    BitLengthBitString,
     BitLengthString,
     ByteLengthBytes,
     ByteLengthString,
@@ -6049,6 +6459,8 @@ derive_unary!(
     RangeUpperInc,
     RangeLowerInf,
     RangeUpperInf,
+    RangeToMultiRange,
+    RangeMerge,
     MzAclItemGrantor,
     MzAclItemGrantee,
     MzAclItemPrivileges,
@@ -6291,6 +6703,14 @@ impl Arbitrary for UnaryFunc {
                     })
                 })
                 .boxed(),
+            (any::<ScalarType>(), any::<MirScalarExpr>())
+                .prop_map(|(return_ty, expr)| {
+                    UnaryFunc::CastStringToMultiRange(CastStringToMultiRange {
+                        return_ty,
+                        cast_expr: Box::new(expr),
+                    })
+                })
+                .boxed(),
             CastStringToTime::arbitrary().prop_map_into().boxed(),
             CastStringToTimestamp::arbitrary().prop_map_into().boxed(),
             CastStringToTimestampTz::arbitrary().prop_map_into().boxed(),
@@ -6302,6 +6722,15 @@ impl Arbitrary for UnaryFunc {
             CastStringToVarChar::arbitrary().prop_map_into().boxed(),
             CastCharToString::arbitrary().prop_map_into().boxed(),
             CastVarCharToString::arbitrary().prop_map_into().boxed(),
+            CastStringToBit::arbitrary().prop_map_into().boxed(),
+            CastStringToVarBit::arbitrary().prop_map_into().boxed(),
+            CastBitToBit::arbitrary().prop_map_into().boxed(),
+            CastBitToVarBit::arbitrary().prop_map_into().boxed(),
+            CastBitToString::arbitrary().prop_map_into().boxed(),
+            CastInt32ToBit::arbitrary().prop_map_into().boxed(),
+            CastInt64ToBit::arbitrary().prop_map_into().boxed(),
+            CastBitToInt32::arbitrary().prop_map_into().boxed(),
+            CastBitToInt64::arbitrary().prop_map_into().boxed(),
             CastDateToTimestamp::arbitrary().prop_map_into().boxed(),
             CastDateToTimestampTz::arbitrary().prop_map_into().boxed(),
             CastDateToString::arbitrary().prop_map_into().boxed(),
@@ -6369,6 +6798,7 @@ impl Arbitrary for UnaryFunc {
             CastMapToString::arbitrary().prop_map_into().boxed(),
             CastInt2VectorToString::arbitrary().prop_map_into().boxed(),
             CastRangeToString::arbitrary().prop_map_into().boxed(),
+            CastMultiRangeToString::arbitrary().prop_map_into().boxed(),
             CeilFloat32::arbitrary().prop_map_into().boxed(),
             CeilFloat64::arbitrary().prop_map_into().boxed(),
             CeilNumeric::arbitrary().prop_map_into().boxed(),
@@ -6378,6 +6808,9 @@ impl Arbitrary for UnaryFunc {
             Ascii::arbitrary().prop_map_into().boxed(),
             BitCountBytes::arbitrary().prop_map_into().boxed(),
             BitLengthBytes::arbitrary().prop_map_into().boxed(),
+            BitNotBitString::arbitrary().prop_map_into().boxed(),
+            BitCountBitString::arbitrary().prop_map_into().boxed(),
+            BitLengthBitString::arbitrary().prop_map_into().boxed(),
             BitLengthString::arbitrary().prop_map_into().boxed(),
             ByteLengthBytes::arbitrary().prop_map_into().boxed(),
             ByteLengthString::arbitrary().prop_map_into().boxed(),
@@ -6471,6 +6904,8 @@ impl Arbitrary for UnaryFunc {
             RangeUpperInc::arbitrary().prop_map_into().boxed(),
             RangeLowerInf::arbitrary().prop_map_into().boxed(),
             RangeUpperInf::arbitrary().prop_map_into().boxed(),
+            RangeToMultiRange::arbitrary().prop_map_into().boxed(),
+            RangeMerge::arbitrary().prop_map_into().boxed(),
             MzAclItemGrantor::arbitrary().prop_map_into().boxed(),
             MzAclItemGrantee::arbitrary().prop_map_into().boxed(),
             MzAclItemPrivileges::arbitrary().prop_map_into().boxed(),
@@ -6655,6 +7090,12 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                     cast_expr: Some(inner.cast_expr.into_proto()),
                 }))
             }
+            UnaryFunc::CastStringToMultiRange(inner) => {
+                CastStringToMultiRange(Box::new(ProtoCastToVariableType {
+                    return_ty: Some(inner.return_ty.into_proto()),
+                    cast_expr: Some(inner.cast_expr.into_proto()),
+                }))
+            }
             UnaryFunc::CastStringToTime(_) => CastStringToTime(()),
             UnaryFunc::CastStringToTimestamp(precision) => {
                 CastStringToTimestamp(precision.0.into_proto())
@@ -6678,6 +7119,25 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             }),
             UnaryFunc::CastCharToString(_) => CastCharToString(()),
             UnaryFunc::CastVarCharToString(_) => CastVarCharToString(()),
+            UnaryFunc::CastStringToBit(func) => CastStringToBit(ProtoCastStringToBit {
+                length: func.length.into_proto(),
+            }),
+            UnaryFunc::CastStringToVarBit(func) => CastStringToVarBit(ProtoCastStringToBit {
+                length: func.max_length.into_proto(),
+            }),
+            UnaryFunc::CastBitToBit(func) => CastBitToBit(ProtoCastBitToBit {
+                length: func.length.into_proto(),
+                fail_on_len: func.fail_on_len,
+            }),
+            UnaryFunc::CastBitToVarBit(func) => CastBitToVarBit(ProtoCastBitToBit {
+                length: func.max_length.into_proto(),
+                fail_on_len: func.fail_on_len,
+            }),
+            UnaryFunc::CastBitToString(_) => CastBitToString(()),
+            UnaryFunc::CastInt32ToBit(func) => CastInt32ToBit(func.length.into_proto()),
+            UnaryFunc::CastInt64ToBit(func) => CastInt64ToBit(func.length.into_proto()),
+            UnaryFunc::CastBitToInt32(_) => CastBitToInt32(()),
+            UnaryFunc::CastBitToInt64(_) => CastBitToInt64(()),
             UnaryFunc::CastDateToTimestamp(func) => CastDateToTimestamp(func.0.into_proto()),
             UnaryFunc::CastDateToTimestampTz(func) => CastDateToTimestampTz(func.0.into_proto()),
             UnaryFunc::CastDateToString(_) => CastDateToString(()),
@@ -6758,6 +7218,7 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastMapToString(func) => CastMapToString(func.ty.into_proto()),
             UnaryFunc::CastInt2VectorToString(_) => CastInt2VectorToString(()),
             UnaryFunc::CastRangeToString(func) => CastRangeToString(func.ty.into_proto()),
+            UnaryFunc::CastMultiRangeToString(func) => CastMultiRangeToString(func.ty.into_proto()),
             UnaryFunc::CeilFloat32(_) => CeilFloat32(()),
             UnaryFunc::CeilFloat64(_) => CeilFloat64(()),
             UnaryFunc::CeilNumeric(_) => CeilNumeric(()),
@@ -6767,6 +7228,9 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::Ascii(_) => Ascii(()),
             UnaryFunc::BitCountBytes(_) => BitCountBytes(()),
             UnaryFunc::BitLengthBytes(_) => BitLengthBytes(()),
+            UnaryFunc::BitNotBitString(_) => BitNotBitString(()),
+            UnaryFunc::BitCountBitString(_) => BitCountBitString(()),
+            UnaryFunc::BitLengthBitString(_) => BitLengthBitString(()),
             UnaryFunc::BitLengthString(_) => BitLengthString(()),
             UnaryFunc::ByteLengthBytes(_) => ByteLengthBytes(()),
             UnaryFunc::ByteLengthString(_) => ByteLengthString(()),
@@ -6875,6 +7339,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::RangeUpperInc(_) => RangeUpperInc(()),
             UnaryFunc::RangeLowerInf(_) => RangeLowerInf(()),
             UnaryFunc::RangeUpperInf(_) => RangeUpperInf(()),
+            UnaryFunc::RangeToMultiRange(_) => RangeToMultiRange(()),
+            UnaryFunc::RangeMerge(_) => RangeMerge(()),
             UnaryFunc::MzAclItemGrantor(_) => MzAclItemGrantor(()),
             UnaryFunc::MzAclItemGrantee(_) => MzAclItemGrantee(()),
             UnaryFunc::MzAclItemPrivileges(_) => MzAclItemPrivileges(()),
@@ -7087,6 +7553,15 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                         .into_rust_if_some("ProtoCastStringToRange::cast_expr")?,
                 }
                 .into()),
+                CastStringToMultiRange(inner) => Ok(impls::CastStringToMultiRange {
+                    return_ty: inner
+                        .return_ty
+                        .into_rust_if_some("ProtoCastStringToMultiRange::return_ty")?,
+                    cast_expr: inner
+                        .cast_expr
+                        .into_rust_if_some("ProtoCastStringToMultiRange::cast_expr")?,
+                }
+                .into()),
                 CastStringToMap(inner) => Ok(impls::CastStringToMap {
                     return_ty: inner
                         .return_ty
@@ -7124,6 +7599,35 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 .into()),
                 CastCharToString(()) => Ok(impls::CastCharToString.into()),
                 CastVarCharToString(()) => Ok(impls::CastVarCharToString.into()),
+                CastStringToBit(func) => Ok(impls::CastStringToBit {
+                    length: func.length.into_rust()?,
+                }
+                .into()),
+                CastStringToVarBit(func) => Ok(impls::CastStringToVarBit {
+                    max_length: func.length.into_rust()?,
+                }
+                .into()),
+                CastBitToBit(func) => Ok(impls::CastBitToBit {
+                    length: func.length.into_rust()?,
+                    fail_on_len: func.fail_on_len,
+                }
+                .into()),
+                CastBitToVarBit(func) => Ok(impls::CastBitToVarBit {
+                    max_length: func.length.into_rust()?,
+                    fail_on_len: func.fail_on_len,
+                }
+                .into()),
+                CastBitToString(()) => Ok(impls::CastBitToString.into()),
+                CastInt32ToBit(length) => Ok(impls::CastInt32ToBit {
+                    length: length.into_rust()?,
+                }
+                .into()),
+                CastInt64ToBit(length) => Ok(impls::CastInt64ToBit {
+                    length: length.into_rust()?,
+                }
+                .into()),
+                CastBitToInt32(()) => Ok(impls::CastBitToInt32.into()),
+                CastBitToInt64(()) => Ok(impls::CastBitToInt64.into()),
                 CastDateToTimestamp(precision) => {
                     Ok(impls::CastDateToTimestamp(precision.into_rust()?).into())
                 }
@@ -7234,6 +7738,10 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                     ty: ty.into_rust()?,
                 }
                 .into()),
+                CastMultiRangeToString(ty) => Ok(impls::CastMultiRangeToString {
+                    ty: ty.into_rust()?,
+                }
+                .into()),
                 CeilFloat32(_) => Ok(impls::CeilFloat32.into()),
                 CeilFloat64(_) => Ok(impls::CeilFloat64.into()),
                 CeilNumeric(_) => Ok(impls::CeilNumeric.into()),
@@ -7243,6 +7751,9 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 Ascii(_) => Ok(impls::Ascii.into()),
                 BitCountBytes(_) => Ok(impls::BitCountBytes.into()),
                 BitLengthBytes(_) => Ok(impls::BitLengthBytes.into()),
+                BitNotBitString(_) => Ok(impls::BitNotBitString.into()),
+                BitCountBitString(_) => Ok(impls::BitCountBitString.into()),
+                BitLengthBitString(_) => Ok(impls::BitLengthBitString.into()),
                 BitLengthString(_) => Ok(impls::BitLengthString.into()),
                 ByteLengthBytes(_) => Ok(impls::ByteLengthBytes.into()),
                 ByteLengthString(_) => Ok(impls::ByteLengthString.into()),
@@ -7374,6 +7885,8 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 RangeUpperInc(_) => Ok(impls::RangeUpperInc.into()),
                 RangeLowerInf(_) => Ok(impls::RangeLowerInf.into()),
                 RangeUpperInf(_) => Ok(impls::RangeUpperInf.into()),
+                RangeToMultiRange(_) => Ok(impls::RangeToMultiRange.into()),
+                RangeMerge(_) => Ok(impls::RangeMerge.into()),
                 MzAclItemGrantor(_) => Ok(impls::MzAclItemGrantor.into()),
                 MzAclItemGrantee(_) => Ok(impls::MzAclItemGrantee.into()),
                 MzAclItemPrivileges(_) => Ok(impls::MzAclItemPrivileges.into()),
@@ -7925,6 +8438,143 @@ fn jsonb_build_object<'a>(
     })
 }
 
+fn jsonb_path_output_type(kind: JsonPathKind, in_nullable: bool) -> ColumnType {
+    match kind {
+        JsonPathKind::Exists | JsonPathKind::Match => ScalarType::Bool.nullable(true),
+        JsonPathKind::QueryArray => ScalarType::Jsonb.nullable(in_nullable),
+        JsonPathKind::QueryFirst => ScalarType::Jsonb.nullable(true),
+    }
+}
+
+/// How [`jsonb_set_path`] places the new value at the end of the path.
+#[derive(Clone, Copy)]
+enum JsonbSetOp {
+    /// Replace the existing value, as `jsonb_set` does. A missing final key or
+    /// out-of-range final index is added only if `create_missing` is set.
+    Set { create_missing: bool },
+    /// Insert the value before (or `after`) the array element at the final
+    /// index, as `jsonb_insert` does. Replacing an existing key is an error.
+    Insert { after: bool },
+}
+
+impl JsonbSetOp {
+    /// Whether a missing final key or out-of-range final index is added.
+    fn creates_missing(&self) -> bool {
+        match self {
+            JsonbSetOp::Set { create_missing } => *create_missing,
+            JsonbSetOp::Insert { .. } => true,
+        }
+    }
+}
+
+fn jsonb_set_path<'a>(
+    target: Datum<'a>,
+    path: Datum<'a>,
+    new_value: Datum<'a>,
+    op: JsonbSetOp,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if !matches!(target, Datum::Map(_) | Datum::List(_)) {
+        return Err(EvalError::InvalidParameterValue(
+            "cannot set path in scalar".into(),
+        ));
+    }
+    let path = path.unwrap_array().elements().iter().collect::<Vec<_>>();
+    if let Some(i) = path.iter().position(|elem| elem.is_null()) {
+        return Err(EvalError::InvalidParameterValue(
+            format!("path element at position {} is null", i + 1).into(),
+        ));
+    }
+    if path.is_empty() {
+        return Ok(target);
+    }
+    jsonb_set_path_inner(target, &path, 0, new_value, op, temp_storage)
+}
+
+fn jsonb_set_path_inner<'a>(
+    json: Datum<'a>,
+    path: &[Datum<'a>],
+    depth: usize,
+    new_value: Datum<'a>,
+    op: JsonbSetOp,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let is_last = depth + 1 == path.len();
+    let key = path[depth].unwrap_str();
+    match json {
+        Datum::Map(map) => {
+            let mut pairs = map.iter().collect::<Vec<_>>();
+            match pairs.iter().position(|(k, _v)| *k == key) {
+                Some(_) if is_last && matches!(op, JsonbSetOp::Insert { .. }) => {
+                    return Err(EvalError::InvalidParameterValue(
+                        "cannot replace existing key".into(),
+                    ));
+                }
+                Some(i) if is_last => pairs[i].1 = new_value,
+                Some(i) => {
+                    pairs[i].1 = jsonb_set_path_inner(
+                        pairs[i].1,
+                        path,
+                        depth + 1,
+                        new_value,
+                        op,
+                        temp_storage,
+                    )?;
+                }
+                None if is_last && op.creates_missing() => {
+                    pairs.push((key, new_value));
+                    pairs.sort_by(|(k1, _v1), (k2, _v2)| k1.cmp(k2));
+                }
+                None => return Ok(json),
+            }
+            Ok(temp_storage.make_datum(|packer| packer.push_dict(pairs)))
+        }
+        Datum::List(list) => {
+            let mut elems = list.iter().collect::<Vec<_>>();
+            let i = strconv::parse_int64(key).map_err(|_| {
+                EvalError::InvalidParameterValue(
+                    format!(
+                        "path element at position {} is not an integer: \"{}\"",
+                        depth + 1,
+                        key
+                    )
+                    .into(),
+                )
+            })?;
+            // Index backwards from the end if negative. `None` indicates a
+            // position before the start of the array.
+            let i = if i >= 0 {
+                Some(usize::cast_from(i.unsigned_abs()))
+            } else {
+                elems.len().checked_sub(usize::cast_from(i.unsigned_abs()))
+            };
+            let in_range = i.filter(|i| *i < elems.len());
+            match (in_range, op) {
+                (Some(i), _) if !is_last => {
+                    elems[i] = jsonb_set_path_inner(
+                        elems[i],
+                        path,
+                        depth + 1,
+                        new_value,
+                        op,
+                        temp_storage,
+                    )?;
+                }
+                (Some(i), JsonbSetOp::Set { .. }) => elems[i] = new_value,
+                (Some(i), JsonbSetOp::Insert { after }) => {
+                    elems.insert(if after { i + 1 } else { i }, new_value)
+                }
+                (None, _) if !is_last || !op.creates_missing() => return Ok(json),
+                (None, _) if i.is_none() => elems.insert(0, new_value),
+                (None, _) => elems.push(new_value),
+            }
+            Ok(temp_storage.make_datum(|packer| packer.push_list(elems)))
+        }
+        // The path extends past a scalar, so there is nothing to set.
+        _ => Ok(json),
+    }
+}
+
 fn map_build<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Datum<'a> {
     // Collect into a `BTreeMap` to provide the same semantics as it.
     let map: std::collections::BTreeMap<&str, _> = datums
@@ -8074,6 +8724,10 @@ where
         TimestampTz { .. } => Ok(strconv::format_timestamptz(buf, &d.unwrap_timestamptz())),
         Interval => Ok(strconv::format_interval(buf, d.unwrap_interval())),
         Bytes => Ok(strconv::format_bytes(buf, d.unwrap_bytes())),
+        Bit { .. } | VarBit { .. } => Ok(strconv::format_bit(
+            buf,
+            &BitString::decode(d.unwrap_bytes()),
+        )),
         String | VarChar { .. } | PgLegacyName => Ok(strconv::format_string(buf, d.unwrap_str())),
         Char { length } => Ok(strconv::format_string(
             buf,
@@ -8130,6 +8784,14 @@ where
             Some(d) => stringify_datum(buf.nonnull_buffer(), *d, element_type),
             None => Ok::<_, EvalError>(buf.write_null()),
         }),
+        MultiRange { element_type } => {
+            strconv::format_multirange(buf, multirange::unwrap_ranges(d), |buf, range| {
+                strconv::format_range(buf, &range, |buf, d| match d {
+                    Some(d) => stringify_datum(buf.nonnull_buffer(), *d, element_type),
+                    None => Ok::<_, EvalError>(buf.write_null()),
+                })
+            })
+        }
         MzAclItem => Ok(strconv::format_mz_acl_item(buf, d.unwrap_mz_acl_item())),
     }
 }
@@ -8280,6 +8942,20 @@ fn create_range<'a>(
     }))
 }
 
+fn create_multirange<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let mut ranges = Vec::with_capacity(datums.len());
+    for d in datums {
+        if d.is_null() {
+            return Err(EvalError::MustNotBeNull("multirange member".into()));
+        }
+        ranges.push(d.unwrap_range());
+    }
+    Ok(make_multirange(multirange::normalize(ranges), temp_storage))
+}
+
 fn array_position<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
     let array = match datums[0] {
         Datum::Null => return Ok(Datum::Null),
@@ -9095,6 +9771,9 @@ pub enum VariadicFunc {
     RangeCreate {
         elem_type: ScalarType,
     },
+    MultiRangeCreate {
+        elem_type: ScalarType,
+    },
     MakeAclItem,
     MakeMzAclItem,
     Translate,
@@ -9106,6 +9785,12 @@ pub enum VariadicFunc {
     TimezoneTime,
     RegexpSplitToArray,
     RegexpReplace,
+    JsonbPath {
+        kind: JsonPathKind,
+    },
+    JsonbSet,
+    JsonbInsert,
+    SetBitBitString,
 }
 
 impl VariadicFunc {
@@ -9187,6 +9872,7 @@ impl VariadicFunc {
             VariadicFunc::DateDiffDate => date_diff_date(ds[0], ds[1], ds[2]),
             VariadicFunc::DateDiffTime => date_diff_time(ds[0], ds[1], ds[2]),
             VariadicFunc::RangeCreate { .. } => create_range(&ds, temp_storage),
+            VariadicFunc::MultiRangeCreate { .. } => create_multirange(&ds, temp_storage),
             VariadicFunc::MakeAclItem => make_acl_item(&ds),
             VariadicFunc::MakeMzAclItem => make_mz_acl_item(&ds),
             VariadicFunc::ArrayPosition => array_position(&ds),
@@ -9214,6 +9900,23 @@ impl VariadicFunc {
 
                 string_to_array(ds[0], ds[1], null_string, temp_storage)
             }
+            VariadicFunc::JsonbPath { kind } => {
+                let path = jsonpath::compile(ds[1].unwrap_str())?;
+                path.eval(*kind, ds[0], ds[2], ds[3].unwrap_bool(), temp_storage)
+            }
+            VariadicFunc::JsonbSet => {
+                let op = JsonbSetOp::Set {
+                    create_missing: ds[3].unwrap_bool(),
+                };
+                jsonb_set_path(ds[0], ds[1], ds[2], op, temp_storage)
+            }
+            VariadicFunc::JsonbInsert => {
+                let op = JsonbSetOp::Insert {
+                    after: ds[3].unwrap_bool(),
+                };
+                jsonb_set_path(ds[0], ds[1], ds[2], op, temp_storage)
+            }
+            VariadicFunc::SetBitBitString => set_bit_bit_string(&ds, temp_storage),
         }
     }
 
@@ -9254,6 +9957,7 @@ impl VariadicFunc {
             | VariadicFunc::DateDiffDate
             | VariadicFunc::DateDiffTime
             | VariadicFunc::RangeCreate { .. }
+            | VariadicFunc::MultiRangeCreate { .. }
             | VariadicFunc::MakeAclItem
             | VariadicFunc::MakeMzAclItem
             | VariadicFunc::ArrayPosition
@@ -9261,7 +9965,11 @@ impl VariadicFunc {
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
             | VariadicFunc::StringToArray
-            | VariadicFunc::RegexpReplace => false,
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::JsonbPath { .. }
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbInsert
+            | VariadicFunc::SetBitBitString => false,
         }
     }
 
@@ -9355,6 +10063,10 @@ impl VariadicFunc {
                 element_type: Box::new(elem_type.clone()),
             }
             .nullable(false),
+            MultiRangeCreate { elem_type } => ScalarType::MultiRange {
+                element_type: Box::new(elem_type.clone()),
+            }
+            .nullable(false),
             MakeAclItem => ScalarType::AclItem.nullable(true),
             MakeMzAclItem => ScalarType::MzAclItem.nullable(true),
             ArrayPosition => ScalarType::Int32.nullable(true),
@@ -9367,6 +10079,12 @@ impl VariadicFunc {
             }
             RegexpReplace => ScalarType::String.nullable(in_nullable),
             StringToArray => ScalarType::Array(Box::new(ScalarType::String)).nullable(true),
+            JsonbPath { kind } => jsonb_path_output_type(*kind, in_nullable),
+            JsonbSet | JsonbInsert => ScalarType::Jsonb.nullable(in_nullable),
+            SetBitBitString => input_types[0]
+                .scalar_type
+                .without_modifiers()
+                .nullable(in_nullable),
         }
     }
 
@@ -9395,6 +10113,7 @@ impl VariadicFunc {
                 | VariadicFunc::ArrayToString { .. }
                 | VariadicFunc::ErrorIfNull
                 | VariadicFunc::RangeCreate { .. }
+                | VariadicFunc::MultiRangeCreate { .. }
                 | VariadicFunc::ArrayPosition
                 | VariadicFunc::ArrayFill { .. }
                 | VariadicFunc::StringToArray
@@ -9434,6 +10153,7 @@ impl VariadicFunc {
             | DateDiffDate
             | DateDiffTime
             | RangeCreate { .. }
+            | MultiRangeCreate { .. }
             | And
             | Or
             | MakeAclItem
@@ -9442,7 +10162,10 @@ impl VariadicFunc {
             | ArrayFill { .. }
             | TimezoneTime
             | RegexpSplitToArray
-            | RegexpReplace => false,
+            | RegexpReplace
+            | JsonbSet
+            | JsonbInsert
+            | SetBitBitString => false,
             Coalesce
             | Greatest
             | Least
@@ -9450,7 +10173,8 @@ impl VariadicFunc {
             | ArrayIndex { .. }
             | StringToArray
             | ListIndex
-            | RegexpMatch => true,
+            | RegexpMatch
+            | JsonbPath { .. } => true,
         }
     }
 
@@ -9545,6 +10269,7 @@ impl VariadicFunc {
             | VariadicFunc::DateBinTimestamp
             | VariadicFunc::DateBinTimestampTz
             | VariadicFunc::RangeCreate { .. }
+            | VariadicFunc::MultiRangeCreate { .. }
             | VariadicFunc::MakeAclItem
             | VariadicFunc::MakeMzAclItem
             | VariadicFunc::Translate
@@ -9557,7 +10282,11 @@ impl VariadicFunc {
             | VariadicFunc::TimezoneTime
             | VariadicFunc::RegexpSplitToArray
             | VariadicFunc::StringToArray
-            | VariadicFunc::RegexpReplace => false,
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::JsonbPath { .. }
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbInsert
+            | VariadicFunc::SetBitBitString => false,
         }
     }
 }
@@ -9608,6 +10337,15 @@ impl fmt::Display for VariadicFunc {
                 ScalarType::TimestampTz { .. } => "tstzrange",
                 _ => unreachable!(),
             }),
+            VariadicFunc::MultiRangeCreate { elem_type } => f.write_str(match elem_type {
+                ScalarType::Int32 => "int4multirange",
+                ScalarType::Int64 => "int8multirange",
+                ScalarType::Date => "datemultirange",
+                ScalarType::Numeric { .. } => "nummultirange",
+                ScalarType::Timestamp { .. } => "tsmultirange",
+                ScalarType::TimestampTz { .. } => "tstzmultirange",
+                _ => unreachable!(),
+            }),
             VariadicFunc::MakeAclItem => f.write_str("makeaclitem"),
             VariadicFunc::MakeMzAclItem => f.write_str("make_mz_aclitem"),
             VariadicFunc::ArrayPosition => f.write_str("array_position"),
@@ -9616,6 +10354,10 @@ impl fmt::Display for VariadicFunc {
             VariadicFunc::RegexpSplitToArray => f.write_str("regexp_split_to_array"),
             VariadicFunc::RegexpReplace => f.write_str("regexp_replace"),
             VariadicFunc::StringToArray => f.write_str("string_to_array"),
+            VariadicFunc::JsonbPath { kind } => kind.fmt(f),
+            VariadicFunc::JsonbSet => f.write_str("jsonb_set"),
+            VariadicFunc::JsonbInsert => f.write_str("jsonb_insert"),
+            VariadicFunc::SetBitBitString => f.write_str("set_bit"),
         }
     }
 }
@@ -9682,10 +10424,19 @@ impl Arbitrary for VariadicFunc {
             mz_repr::arb_range_type()
                 .prop_map(|elem_type| VariadicFunc::RangeCreate { elem_type })
                 .boxed(),
+            mz_repr::arb_range_type()
+                .prop_map(|elem_type| VariadicFunc::MultiRangeCreate { elem_type })
+                .boxed(),
             Just(VariadicFunc::ArrayPosition).boxed(),
             ScalarType::arbitrary()
                 .prop_map(|elem_type| VariadicFunc::ArrayFill { elem_type })
                 .boxed(),
+            JsonPathKind::arbitrary()
+                .prop_map(|kind| VariadicFunc::JsonbPath { kind })
+                .boxed(),
+            Just(VariadicFunc::JsonbSet).boxed(),
+            Just(VariadicFunc::JsonbInsert).boxed(),
+            Just(VariadicFunc::SetBitBitString).boxed(),
         ])
     }
 }
@@ -9731,6 +10482,9 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::And => And(()),
             VariadicFunc::Or => Or(()),
             VariadicFunc::RangeCreate { elem_type } => RangeCreate(elem_type.into_proto()),
+            VariadicFunc::MultiRangeCreate { elem_type } => {
+                MultiRangeCreate(elem_type.into_proto())
+            }
             VariadicFunc::MakeAclItem => MakeAclItem(()),
             VariadicFunc::MakeMzAclItem => MakeMzAclItem(()),
             VariadicFunc::ArrayPosition => ArrayPosition(()),
@@ -9739,6 +10493,10 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::RegexpSplitToArray => RegexpSplitToArray(()),
             VariadicFunc::RegexpReplace => RegexpReplace(()),
             VariadicFunc::StringToArray => StringToArray(()),
+            VariadicFunc::JsonbPath { kind } => JsonbPath(kind.into_proto()),
+            VariadicFunc::JsonbSet => JsonbSet(()),
+            VariadicFunc::JsonbInsert => JsonbInsert(()),
+            VariadicFunc::SetBitBitString => SetBitBitString(()),
         };
         ProtoVariadicFunc { kind: Some(kind) }
     }
@@ -9796,6 +10554,9 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 RangeCreate(elem_type) => Ok(VariadicFunc::RangeCreate {
                     elem_type: elem_type.into_rust()?,
                 }),
+                MultiRangeCreate(elem_type) => Ok(VariadicFunc::MultiRangeCreate {
+                    elem_type: elem_type.into_rust()?,
+                }),
                 MakeAclItem(()) => Ok(VariadicFunc::MakeAclItem),
                 MakeMzAclItem(()) => Ok(VariadicFunc::MakeMzAclItem),
                 ArrayPosition(()) => Ok(VariadicFunc::ArrayPosition),
@@ -9806,6 +10567,12 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 RegexpSplitToArray(()) => Ok(VariadicFunc::RegexpSplitToArray),
                 RegexpReplace(()) => Ok(VariadicFunc::RegexpReplace),
                 StringToArray(()) => Ok(VariadicFunc::StringToArray),
+                JsonbPath(kind) => Ok(VariadicFunc::JsonbPath {
+                    kind: kind.into_rust()?,
+                }),
+                JsonbSet(()) => Ok(VariadicFunc::JsonbSet),
+                JsonbInsert(()) => Ok(VariadicFunc::JsonbInsert),
+                SetBitBitString(()) => Ok(VariadicFunc::SetBitBitString),
             }
         } else {
             Err(TryFromProtoError::missing_field(
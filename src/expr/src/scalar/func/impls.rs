@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 mod array;
+mod bit;
 mod boolean;
 mod byte;
 mod char;
@@ -23,6 +24,7 @@ mod interval;
 mod jsonb;
 mod list;
 mod map;
+mod multirange;
 mod mz_acl_item;
 mod mz_timestamp;
 mod numeric;
@@ -41,6 +43,7 @@ mod uuid;
 mod varchar;
 
 pub use crate::scalar::func::impls::array::*;
+pub use crate::scalar::func::impls::bit::*;
 pub use crate::scalar::func::impls::boolean::*;
 pub use crate::scalar::func::impls::byte::*;
 pub use crate::scalar::func::impls::char::*;
@@ -56,6 +59,7 @@ pub use crate::scalar::func::impls::interval::*;
 pub use crate::scalar::func::impls::jsonb::*;
 pub use crate::scalar::func::impls::list::*;
 pub use crate::scalar::func::impls::map::*;
+pub use crate::scalar::func::impls::multirange::*;
 pub use crate::scalar::func::impls::mz_acl_item::*;
 pub use crate::scalar::func::impls::mz_timestamp::*;
 pub use crate::scalar::func::impls::numeric::*;
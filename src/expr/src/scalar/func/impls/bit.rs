@@ -0,0 +1,383 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
+use mz_repr::adt::bit::{BitLength, BitString};
+use mz_repr::{ColumnType, ScalarType, strconv};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::EvalError;
+use crate::scalar::func::EagerUnaryFunc;
+
+/// Returns an error unless `s` has exactly `length` bits.
+fn check_bit_length(s: &BitString, length: BitLength) -> Result<(), EvalError> {
+    let length = usize::cast_from(length.into_u32());
+    if s.len() != length {
+        return Err(EvalError::InvalidBitString(
+            format!(
+                "bit string length {} does not match type bit({})",
+                s.len(),
+                length
+            )
+            .into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns an error if `s` has more than `max_length` bits.
+fn check_varbit_length(s: &BitString, max_length: BitLength) -> Result<(), EvalError> {
+    let max_length = usize::cast_from(max_length.into_u32());
+    if s.len() > max_length {
+        return Err(EvalError::InvalidBitString(
+            format!("bit string too long for type bit varying({})", max_length).into(),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToBit {
+    pub length: Option<BitLength>,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToBit {
+    type Input = &'a str;
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<Vec<u8>, EvalError> {
+        let s = strconv::parse_bit(a)?;
+        if let Some(length) = self.length {
+            check_bit_length(&s, length)?;
+        }
+        Ok(s.encode())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Bit {
+            length: self.length,
+        }
+        .nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastBitToString)
+    }
+}
+
+impl fmt::Display for CastStringToBit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.length {
+            Some(length) => write!(f, "text_to_bit[len={}]", length.into_u32()),
+            None => f.write_str("text_to_bit[len=unbounded]"),
+        }
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToVarBit {
+    pub max_length: Option<BitLength>,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToVarBit {
+    type Input = &'a str;
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<Vec<u8>, EvalError> {
+        let s = strconv::parse_bit(a)?;
+        if let Some(max_length) = self.max_length {
+            check_varbit_length(&s, max_length)?;
+        }
+        Ok(s.encode())
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::VarBit {
+            max_length: self.max_length,
+        }
+        .nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastBitToString)
+    }
+}
+
+impl fmt::Display for CastStringToVarBit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.max_length {
+            Some(max_length) => write!(f, "text_to_varbit[len={}]", max_length.into_u32()),
+            None => f.write_str("text_to_varbit[len=unbounded]"),
+        }
+    }
+}
+
+/// Casts a bit string to `bit(length)`. Like PostgreSQL, explicit casts pad
+/// or truncate the input on the right, while other casts require the input to
+/// already have the target length.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastBitToBit {
+    pub length: Option<BitLength>,
+    pub fail_on_len: bool,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastBitToBit {
+    type Input = &'a [u8];
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+        let Some(length) = self.length else {
+            return Ok(a.to_vec());
+        };
+        let s = BitString::decode(a);
+        if self.fail_on_len {
+            check_bit_length(&s, length)?;
+            Ok(a.to_vec())
+        } else {
+            Ok(s.resize(usize::cast_from(length.into_u32())).encode())
+        }
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Bit {
+            length: self.length,
+        }
+        .nullable(input.nullable)
+    }
+
+    fn could_error(&self) -> bool {
+        self.fail_on_len && self.length.is_some()
+    }
+}
+
+impl fmt::Display for CastBitToBit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.length {
+            Some(length) => write!(
+                f,
+                "bit_to_bit[len={}, fail_on_len={}]",
+                length.into_u32(),
+                self.fail_on_len
+            ),
+            None => f.write_str("bit_to_bit[len=unbounded]"),
+        }
+    }
+}
+
+/// Casts a bit string to `varbit(max_length)`. Like PostgreSQL, explicit
+/// casts truncate overlong input, while other casts reject it.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastBitToVarBit {
+    pub max_length: Option<BitLength>,
+    pub fail_on_len: bool,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastBitToVarBit {
+    type Input = &'a [u8];
+    type Output = Result<Vec<u8>, EvalError>;
+
+    fn call(&self, a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+        let Some(max_length) = self.max_length else {
+            return Ok(a.to_vec());
+        };
+        let s = BitString::decode(a);
+        if self.fail_on_len {
+            check_varbit_length(&s, max_length)?;
+            Ok(a.to_vec())
+        } else {
+            let len = s.len().min(usize::cast_from(max_length.into_u32()));
+            Ok(s.resize(len).encode())
+        }
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::VarBit {
+            max_length: self.max_length,
+        }
+        .nullable(input.nullable)
+    }
+
+    fn could_error(&self) -> bool {
+        self.fail_on_len && self.max_length.is_some()
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        self.fail_on_len || self.max_length.is_none()
+    }
+}
+
+impl fmt::Display for CastBitToVarBit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.max_length {
+            Some(max_length) => write!(
+                f,
+                "bit_to_varbit[len={}, fail_on_len={}]",
+                max_length.into_u32(),
+                self.fail_on_len
+            ),
+            None => f.write_str("bit_to_varbit[len=unbounded]"),
+        }
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "bit_to_text"]
+    #[preserves_uniqueness = true]
+    #[inverse = to_unary!(super::CastStringToVarBit { max_length: None })]
+    fn cast_bit_to_string<'a>(a: &'a [u8]) -> String {
+        let mut buf = String::new();
+        strconv::format_bit(&mut buf, &BitString::decode(a));
+        buf
+    }
+);
+
+/// Casts an integer to the rightmost `length` bits of its two's complement
+/// representation.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastInt32ToBit {
+    pub length: BitLength,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastInt32ToBit {
+    type Input = i32;
+    type Output = Vec<u8>;
+
+    fn call(&self, a: i32) -> Vec<u8> {
+        BitString::from_i64(i64::from(a), usize::cast_from(self.length.into_u32())).encode()
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Bit {
+            length: Some(self.length),
+        }
+        .nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for CastInt32ToBit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "integer_to_bit[len={}]", self.length.into_u32())
+    }
+}
+
+/// Casts a bigint to the rightmost `length` bits of its two's complement
+/// representation.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastInt64ToBit {
+    pub length: BitLength,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastInt64ToBit {
+    type Input = i64;
+    type Output = Vec<u8>;
+
+    fn call(&self, a: i64) -> Vec<u8> {
+        BitString::from_i64(a, usize::cast_from(self.length.into_u32())).encode()
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Bit {
+            length: Some(self.length),
+        }
+        .nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for CastInt64ToBit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bigint_to_bit[len={}]", self.length.into_u32())
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "bit_to_integer"]
+    fn cast_bit_to_int32<'a>(a: &'a [u8]) -> Result<i32, EvalError> {
+        let s = BitString::decode(a);
+        match s.to_u64().map(u32::try_from) {
+            Some(Ok(n)) => Ok(i32::from_ne_bytes(n.to_ne_bytes())),
+            _ => Err(EvalError::Int32OutOfRange(s.to_string().into())),
+        }
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "bit_to_bigint"]
+    fn cast_bit_to_int64<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
+        let s = BitString::decode(a);
+        match s.to_u64() {
+            Some(n) => Ok(i64::from_ne_bytes(n.to_ne_bytes())),
+            None => Err(EvalError::Int64OutOfRange(s.to_string().into())),
+        }
+    }
+);
+
+/// Inverts every bit of a bit string, preserving its type.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct BitNotBitString;
+
+impl<'a> EagerUnaryFunc<'a> for BitNotBitString {
+    type Input = &'a [u8];
+    type Output = Vec<u8>;
+
+    fn call(&self, a: &'a [u8]) -> Vec<u8> {
+        BitString::decode(a).invert().encode()
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        input
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::BitNotBitString)
+    }
+}
+
+impl fmt::Display for BitNotBitString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("~")
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "bit_count"]
+    fn bit_count_bit_string<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
+        let count = BitString::decode(a).count_ones();
+        i64::try_from(count).or_else(|_| Err(EvalError::Int64OutOfRange(count.to_string().into())))
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "bit_length"]
+    fn bit_length_bit_string<'a>(a: &'a [u8]) -> Result<i32, EvalError> {
+        let val = BitString::decode(a).len();
+        i32::try_from(val).or_else(|_| Err(EvalError::Int32OutOfRange(val.to_string().into())))
+    }
+);
@@ -0,0 +1,218 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_repr::adt::multirange;
+use mz_repr::adt::range::{Range, RangeInner};
+use mz_repr::{ColumnType, Datum, RowArena, ScalarType};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::scalar::func::{LazyUnaryFunc, stringify_datum};
+use crate::{EvalError, MirScalarExpr};
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastMultiRangeToString {
+    pub ty: ScalarType,
+}
+
+impl LazyUnaryFunc for CastMultiRangeToString {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let mut buf = String::new();
+        stringify_datum(&mut buf, a, &self.ty)?;
+        Ok(Datum::String(temp_storage.push_string(buf)))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        // TODO? if typeconv was in expr, we could determine this
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for CastMultiRangeToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("multirangetostr")
+    }
+}
+
+/// Converts a range into the multirange containing only that range.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct RangeToMultiRange;
+
+impl LazyUnaryFunc for RangeToMultiRange {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let range = a.unwrap_range();
+        Ok(temp_storage.make_datum(|packer| {
+            packer.push_list_with(|packer| {
+                // Empty ranges are not stored in multiranges.
+                if range.inner.is_some() {
+                    packer
+                        .push_range(range)
+                        .expect("ranges from datums are valid");
+                }
+            })
+        }))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::MultiRange {
+            element_type: Box::new(input_type.scalar_type.unwrap_range_element_type().clone()),
+        }
+        .nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for RangeToMultiRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("multirange")
+    }
+}
+
+/// Returns the smallest range that contains every range of a multirange.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct RangeMerge;
+
+impl LazyUnaryFunc for RangeMerge {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let ranges = multirange::unwrap_ranges(a);
+        // Normalized multiranges are sorted and contain no empty ranges, so
+        // the bounds of the merged range are the outermost bounds.
+        let inner = match (ranges.first(), ranges.last()) {
+            (Some(first), Some(last)) => Some(RangeInner {
+                lower: first
+                    .inner
+                    .expect("multiranges contain no empty ranges")
+                    .lower,
+                upper: last
+                    .inner
+                    .expect("multiranges contain no empty ranges")
+                    .upper,
+            }),
+            _ => None,
+        };
+        Ok(temp_storage.make_datum(|packer| {
+            packer
+                .push_range(Range { inner })
+                .expect("bounds taken from valid ranges")
+        }))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Range {
+            element_type: Box::new(
+                input_type
+                    .scalar_type
+                    .unwrap_multirange_element_type()
+                    .clone(),
+            ),
+        }
+        .nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+
+    fn is_monotone(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for RangeMerge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("range_merge")
+    }
+}
@@ -0,0 +1,1713 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! SQL/JSON path expressions.
+//!
+//! This module implements the SQL/JSON path language used by the
+//! `jsonb_path_*` family of functions and the `@?` and `@@` operators. A path
+//! is compiled once into a [`JsonPath`], which can then be evaluated against
+//! any number of `jsonb` datums.
+//!
+//! See: <https://www.postgresql.org/docs/current/functions-json.html#FUNCTIONS-SQLJSON-PATH>
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use dec::{OrderedDecimal, Rounding};
+use mz_lowertest::MzReflect;
+use mz_proto::{IntoRustIfSome, RustType, TryFromProtoError};
+use mz_repr::adt::numeric::{self, Numeric};
+use mz_repr::adt::regex::Regex;
+use mz_repr::{Datum, RowArena, strconv};
+use proptest::prelude::{Strategy, any};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::scalar::EvalError;
+
+include!(concat!(env!("OUT_DIR"), "/mz_expr.scalar.jsonpath.rs"));
+
+/// The flavor of result a JSON path function produces.
+#[derive(
+    Arbitrary,
+    Ord,
+    PartialOrd,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect,
+)]
+pub enum JsonPathKind {
+    /// `jsonb_path_exists` and `@?`: whether the path returns any item.
+    Exists,
+    /// `jsonb_path_match` and `@@`: the single boolean returned by a predicate.
+    Match,
+    /// `jsonb_path_query_array`: all returned items, wrapped in an array.
+    QueryArray,
+    /// `jsonb_path_query_first`: the first returned item.
+    QueryFirst,
+}
+
+impl fmt::Display for JsonPathKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            JsonPathKind::Exists => "jsonb_path_exists",
+            JsonPathKind::Match => "jsonb_path_match",
+            JsonPathKind::QueryArray => "jsonb_path_query_array",
+            JsonPathKind::QueryFirst => "jsonb_path_query_first",
+        })
+    }
+}
+
+impl RustType<ProtoJsonPathKind> for JsonPathKind {
+    fn into_proto(&self) -> ProtoJsonPathKind {
+        use proto_json_path_kind::Kind::*;
+        ProtoJsonPathKind {
+            kind: Some(match self {
+                JsonPathKind::Exists => Exists(()),
+                JsonPathKind::Match => Match(()),
+                JsonPathKind::QueryArray => QueryArray(()),
+                JsonPathKind::QueryFirst => QueryFirst(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonPathKind) -> Result<Self, TryFromProtoError> {
+        use proto_json_path_kind::Kind::*;
+        match proto.kind {
+            Some(Exists(())) => Ok(JsonPathKind::Exists),
+            Some(Match(())) => Ok(JsonPathKind::Match),
+            Some(QueryArray(())) => Ok(JsonPathKind::QueryArray),
+            Some(QueryFirst(())) => Ok(JsonPathKind::QueryFirst),
+            None => Err(TryFromProtoError::missing_field("ProtoJsonPathKind::kind")),
+        }
+    }
+}
+
+/// A JSON path function whose path and `silent` arguments are constants, so
+/// that the path need only be compiled once.
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct JsonPathFunc {
+    pub kind: JsonPathKind,
+    pub path: JsonPath,
+    pub silent: bool,
+}
+
+impl JsonPathFunc {
+    pub fn eval<'a>(
+        &self,
+        target: Datum<'a>,
+        vars: Datum<'a>,
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError> {
+        self.path
+            .eval(self.kind, target, vars, self.silent, temp_storage)
+    }
+}
+
+impl RustType<ProtoJsonPathFunc> for JsonPathFunc {
+    fn into_proto(&self) -> ProtoJsonPathFunc {
+        ProtoJsonPathFunc {
+            kind: Some(self.kind.into_proto()),
+            path: Some(self.path.into_proto()),
+            silent: self.silent,
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonPathFunc) -> Result<Self, TryFromProtoError> {
+        Ok(JsonPathFunc {
+            kind: proto.kind.into_rust_if_some("ProtoJsonPathFunc::kind")?,
+            path: proto.path.into_rust_if_some("ProtoJsonPathFunc::path")?,
+            silent: proto.silent,
+        })
+    }
+}
+
+pub fn any_json_path_func() -> impl Strategy<Value = JsonPathFunc> {
+    (any::<JsonPathKind>(), any_json_path(), any::<bool>())
+        .prop_map(|(kind, path, silent)| JsonPathFunc { kind, path, silent })
+}
+
+/// A compiled SQL/JSON path expression.
+///
+/// Like [`Regex`], two paths are considered equal iff their source text is
+/// identical, and only the source text is serialized; the compiled form is
+/// rebuilt upon deserialization.
+#[derive(Debug, Clone, MzReflect)]
+pub struct JsonPath {
+    pub source: String,
+    path: Arc<Path>,
+}
+
+impl JsonPath {
+    /// Evaluates the path against `target` and returns every resulting item.
+    ///
+    /// `vars` must be a `jsonb` object, whose fields are the values of the
+    /// `$name` variables referenced by the path.
+    pub fn query<'a>(
+        &self,
+        target: Datum<'a>,
+        vars: Datum<'a>,
+        temp_storage: &'a RowArena,
+    ) -> Result<Vec<Datum<'a>>, EvalError> {
+        if !matches!(vars, Datum::Map(_)) {
+            return Err(EvalError::InvalidParameterValue(
+                "\"vars\" argument is not an object".into(),
+            ));
+        }
+        let evaluator = Evaluator {
+            strict: self.path.strict,
+            root: target,
+            vars,
+            temp_storage,
+        };
+        evaluator.eval(&self.path.expr, target, None)
+    }
+
+    /// Evaluates the path against `target` and shapes the result as
+    /// requested by `kind`.
+    ///
+    /// If `silent` is set, evaluation errors (e.g., accessing a missing key in
+    /// strict mode, or a non-numeric arithmetic operand) are suppressed in the
+    /// same way as PostgreSQL's `silent` argument does.
+    pub fn eval<'a>(
+        &self,
+        kind: JsonPathKind,
+        target: Datum<'a>,
+        vars: Datum<'a>,
+        silent: bool,
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError> {
+        let items = match self.query(target, vars, temp_storage) {
+            Ok(items) => items,
+            Err(EvalError::JsonPathEvaluation(_)) if silent => {
+                return Ok(match kind {
+                    JsonPathKind::QueryArray => {
+                        temp_storage.make_datum(|packer| packer.push_list(None::<Datum>))
+                    }
+                    _ => Datum::Null,
+                });
+            }
+            Err(e) => return Err(e),
+        };
+        match kind {
+            JsonPathKind::Exists => Ok(Datum::from(!items.is_empty())),
+            JsonPathKind::Match => match items[..] {
+                [d @ (Datum::True | Datum::False)] => Ok(d),
+                [Datum::JsonNull] => Ok(Datum::Null),
+                _ if silent => Ok(Datum::Null),
+                _ => Err(EvalError::JsonPathEvaluation(
+                    "single boolean result is expected".into(),
+                )),
+            },
+            JsonPathKind::QueryArray => {
+                Ok(temp_storage.make_datum(|packer| packer.push_list(items)))
+            }
+            JsonPathKind::QueryFirst => Ok(items.into_iter().next().unwrap_or(Datum::Null)),
+        }
+    }
+}
+
+impl PartialEq for JsonPath {
+    fn eq(&self, other: &JsonPath) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for JsonPath {}
+
+impl PartialOrd for JsonPath {
+    fn partial_cmp(&self, other: &JsonPath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonPath {
+    fn cmp(&self, other: &JsonPath) -> Ordering {
+        self.source.cmp(&other.source)
+    }
+}
+
+impl Hash for JsonPath {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.source.hash(hasher);
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Serialize for JsonPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonPath {
+    fn deserialize<D>(deserializer: D) -> Result<JsonPath, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let source = String::deserialize(deserializer)?;
+        compile(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+impl RustType<ProtoJsonPath> for JsonPath {
+    fn into_proto(&self) -> ProtoJsonPath {
+        ProtoJsonPath {
+            source: self.source.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonPath) -> Result<Self, TryFromProtoError> {
+        compile(&proto.source).map_err(|e| TryFromProtoError::InvalidFieldError(e.to_string()))
+    }
+}
+
+pub fn any_json_path() -> impl Strategy<Value = JsonPath> {
+    proptest::sample::select(vec![
+        "$",
+        "$.a",
+        "strict $.a[*] ? (@ > 1)",
+        "lax $[0 to last].size()",
+        "$.** ? (@ like_regex \"^a\" flag \"i\")",
+        "exists($.a) && $.b starts with \"x\"",
+        "$.a + $b * 2",
+        "$.a.keyvalue().value.type()",
+    ])
+    .prop_map(|source| compile(source).unwrap())
+}
+
+/// Compiles the text of a SQL/JSON path expression.
+pub fn compile(source: &str) -> Result<JsonPath, EvalError> {
+    let tokens = Lexer { source, pos: 0 }.lex()?;
+    let mut parser = Parser {
+        source,
+        tokens,
+        pos: 0,
+        filter_depth: 0,
+        subscript_depth: 0,
+    };
+    let path = parser.parse_path()?;
+    Ok(JsonPath {
+        source: source.into(),
+        path: Arc::new(path),
+    })
+}
+
+#[derive(Debug)]
+struct Path {
+    strict: bool,
+    expr: Expr,
+}
+
+#[derive(Debug)]
+enum Expr {
+    Null,
+    Bool(bool),
+    Numeric(Numeric),
+    String(String),
+    /// `$`
+    Root,
+    /// `@`
+    Current,
+    /// `last`
+    Last,
+    /// `$name`
+    Variable(String),
+    Accessor(Box<Expr>, Accessor),
+    Neg(Box<Expr>),
+    Pos(Box<Expr>),
+    Arith(ArithOp, Box<Expr>, Box<Expr>),
+    Cmp(CmpOp, Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Exists(Box<Expr>),
+    IsUnknown(Box<Expr>),
+    StartsWith(Box<Expr>, Box<Expr>),
+    LikeRegex(Box<Expr>, Regex),
+}
+
+impl Expr {
+    /// Reports whether the expression produces a boolean truth value (a
+    /// predicate) rather than a sequence of JSON items.
+    fn is_predicate(&self) -> bool {
+        matches!(
+            self,
+            Expr::Cmp(..)
+                | Expr::And(..)
+                | Expr::Or(..)
+                | Expr::Not(..)
+                | Expr::Exists(..)
+                | Expr::IsUnknown(..)
+                | Expr::StartsWith(..)
+                | Expr::LikeRegex(..)
+        )
+    }
+}
+
+#[derive(Debug)]
+enum Accessor {
+    /// `.key` or `."key"`
+    Member(String),
+    /// `.*`
+    MemberWildcard,
+    /// `.**` or `.**{min to max}`
+    Descendant { min: u32, max: u32 },
+    /// `[a, b to c]`
+    Subscripts(Vec<(Expr, Option<Expr>)>),
+    /// `[*]`
+    ArrayWildcard,
+    /// `? (predicate)`
+    Filter(Box<Expr>),
+    /// `.method()`
+    Method(Method),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Method {
+    Type,
+    Size,
+    Double,
+    Ceiling,
+    Floor,
+    Abs,
+    KeyValue,
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Method::Type => "type",
+            Method::Size => "size",
+            Method::Double => "double",
+            Method::Ceiling => "ceiling",
+            Method::Floor => "floor",
+            Method::Abs => "abs",
+            Method::KeyValue => "keyvalue",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl fmt::Display for ArithOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ArithOp::Add => "+",
+            ArithOp::Sub => "-",
+            ArithOp::Mul => "*",
+            ArithOp::Div => "/",
+            ArithOp::Mod => "%",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl CmpOp {
+    fn holds(&self, ord: Ordering) -> bool {
+        match self {
+            CmpOp::Eq => ord == Ordering::Equal,
+            CmpOp::Ne => ord != Ordering::Equal,
+            CmpOp::Lt => ord == Ordering::Less,
+            CmpOp::Lte => ord != Ordering::Greater,
+            CmpOp::Gt => ord == Ordering::Greater,
+            CmpOp::Gte => ord != Ordering::Less,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Variable(String),
+    String(String),
+    Number(String),
+    Op(&'static str),
+}
+
+#[derive(Debug)]
+struct Lexeme {
+    token: Token,
+    start: usize,
+    end: usize,
+}
+
+/// Operators, longest first so that e.g. `**` is preferred to `*`.
+const OPERATORS: &[&str] = &[
+    "**", "==", "!=", "<>", "<=", ">=", "&&", "||", "$", "@", ".", ",", "(", ")", "[", "]", "{",
+    "}", "?", "+", "-", "*", "/", "%", "<", ">", "!",
+];
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+struct Lexer<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Lexer<'s> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.pos..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn lex(mut self) -> Result<Vec<Lexeme>, EvalError> {
+        let mut tokens = vec![];
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+                continue;
+            }
+            let start = self.pos;
+            let token = if c == '"' {
+                Token::String(self.lex_string()?)
+            } else if c.is_ascii_digit() {
+                Token::Number(self.lex_number()?)
+            } else if is_ident_start(c) {
+                Token::Ident(self.lex_ident())
+            } else if c == '$' && self.peek_nth(1) == Some('"') {
+                self.bump();
+                Token::Variable(self.lex_string()?)
+            } else if c == '$' && self.peek_nth(1).is_some_and(is_ident_continue) {
+                self.bump();
+                Token::Variable(self.lex_ident())
+            } else {
+                let rest = &self.source[self.pos..];
+                match OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+                    Some(op) => {
+                        self.pos += op.len();
+                        Token::Op(op)
+                    }
+                    None => {
+                        return Err(EvalError::InvalidJsonPath(
+                            format!(
+                                "syntax error at or near \"{}\" of jsonpath input",
+                                &rest[..c.len_utf8()]
+                            )
+                            .into(),
+                        ));
+                    }
+                }
+            };
+            tokens.push(Lexeme {
+                token,
+                start,
+                end: self.pos,
+            });
+        }
+        Ok(tokens)
+    }
+
+    fn lex_ident(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(is_ident_continue) {
+            self.bump();
+        }
+        self.source[start..self.pos].into()
+    }
+
+    fn lex_number(&mut self) -> Result<String, EvalError> {
+        let start = self.pos;
+        let digits = |lexer: &mut Self| {
+            while lexer.peek().is_some_and(|c| c.is_ascii_digit()) {
+                lexer.bump();
+            }
+        };
+        digits(self);
+        if self.peek() == Some('.') && self.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+            digits(self);
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let exponent = match self.peek_nth(1) {
+                Some('+' | '-') => self.peek_nth(2),
+                c => c,
+            };
+            if exponent.is_some_and(|c| c.is_ascii_digit()) {
+                self.bump();
+                if matches!(self.peek(), Some('+' | '-')) {
+                    self.bump();
+                }
+                digits(self);
+            }
+        }
+        if let Some(c) = self.peek().filter(|c| is_ident_continue(*c)) {
+            return Err(EvalError::InvalidJsonPath(
+                format!(
+                    "trailing junk after numeric literal at or near \"{}\" of jsonpath input",
+                    &self.source[start..self.pos + c.len_utf8()]
+                )
+                .into(),
+            ));
+        }
+        Ok(self.source[start..self.pos].into())
+    }
+
+    fn lex_string(&mut self) -> Result<String, EvalError> {
+        let unterminated = || EvalError::InvalidJsonPath("unexpected end of quoted string".into());
+        // Skip the opening quote.
+        self.bump();
+        let mut s = String::new();
+        loop {
+            match self.bump().ok_or_else(unterminated)? {
+                '"' => return Ok(s),
+                '\\' => match self.bump().ok_or_else(unterminated)? {
+                    'b' => s.push('\u{8}'),
+                    'f' => s.push('\u{c}'),
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'v' => s.push('\u{b}'),
+                    'u' => {
+                        let hex = self.source.get(self.pos..self.pos + 4).unwrap_or("");
+                        let c = u32::from_str_radix(hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| {
+                                EvalError::InvalidJsonPath(
+                                    "invalid Unicode escape sequence in jsonpath input".into(),
+                                )
+                            })?;
+                        self.pos += 4;
+                        s.push(c);
+                    }
+                    c => s.push(c),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    tokens: Vec<Lexeme>,
+    pos: usize,
+    /// The number of filter expressions the parser is nested within; `@` is
+    /// only valid inside of a filter.
+    filter_depth: usize,
+    /// The number of array subscripts the parser is nested within; `last` is
+    /// only valid inside of a subscript.
+    subscript_depth: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn syntax_error(&self) -> EvalError {
+        let msg = match self.tokens.get(self.pos) {
+            Some(lexeme) => format!(
+                "syntax error at or near \"{}\" of jsonpath input",
+                &self.source[lexeme.start..lexeme.end]
+            ),
+            None => "syntax error at end of jsonpath input".into(),
+        };
+        EvalError::InvalidJsonPath(msg.into())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|lexeme| &lexeme.token)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + n).map(|lexeme| &lexeme.token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos)?.token.clone();
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn peek_op(&self, op: &str) -> bool {
+        matches!(self.peek(), Some(Token::Op(o)) if *o == op)
+    }
+
+    fn consume_op(&mut self, op: &str) -> bool {
+        let found = self.peek_op(op);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_op(&mut self, op: &str) -> Result<(), EvalError> {
+        match self.consume_op(op) {
+            true => Ok(()),
+            false => Err(self.syntax_error()),
+        }
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(i)) if i == keyword)
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek_keyword(keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), EvalError> {
+        match self.consume_keyword(keyword) {
+            true => Ok(()),
+            false => Err(self.syntax_error()),
+        }
+    }
+
+    fn expect_string(&mut self) -> Result<String, EvalError> {
+        match self.peek() {
+            Some(Token::String(s)) => {
+                let s = s.clone();
+                self.pos += 1;
+                Ok(s)
+            }
+            _ => Err(self.syntax_error()),
+        }
+    }
+
+    /// Errors at the current token unless `expr` is a predicate.
+    fn require_predicate(&self, expr: &Expr) -> Result<(), EvalError> {
+        match expr.is_predicate() {
+            true => Ok(()),
+            false => Err(self.syntax_error()),
+        }
+    }
+
+    /// Errors at the current token if `expr` is a predicate.
+    fn require_value(&self, expr: &Expr) -> Result<(), EvalError> {
+        match expr.is_predicate() {
+            true => Err(self.syntax_error()),
+            false => Ok(()),
+        }
+    }
+
+    fn parse_path(&mut self) -> Result<Path, EvalError> {
+        let strict = if self.consume_keyword("strict") {
+            true
+        } else {
+            self.consume_keyword("lax");
+            false
+        };
+        let expr = self.parse_or()?;
+        if self.pos < self.tokens.len() {
+            return Err(self.syntax_error());
+        }
+        Ok(Path { strict, expr })
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, EvalError> {
+        let mut expr = self.parse_and()?;
+        while self.peek_op("||") {
+            self.require_predicate(&expr)?;
+            self.pos += 1;
+            let right = self.parse_and()?;
+            self.require_predicate(&right)?;
+            expr = Expr::Or(Box::new(expr), Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, EvalError> {
+        let mut expr = self.parse_not()?;
+        while self.peek_op("&&") {
+            self.require_predicate(&expr)?;
+            self.pos += 1;
+            let right = self.parse_not()?;
+            self.require_predicate(&right)?;
+            expr = Expr::And(Box::new(expr), Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, EvalError> {
+        if self.consume_op("!") {
+            self.expect_op("(")?;
+            let expr = self.parse_or()?;
+            self.require_predicate(&expr)?;
+            self.expect_op(")")?;
+            return Ok(Expr::Not(Box::new(expr)));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, EvalError> {
+        let left = self.parse_additive()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => CmpOp::Eq,
+            Some(Token::Op("!=" | "<>")) => CmpOp::Ne,
+            Some(Token::Op("<")) => CmpOp::Lt,
+            Some(Token::Op("<=")) => CmpOp::Lte,
+            Some(Token::Op(">")) => CmpOp::Gt,
+            Some(Token::Op(">=")) => CmpOp::Gte,
+            Some(Token::Ident(i)) if i == "like_regex" => {
+                self.require_value(&left)?;
+                self.pos += 1;
+                let regex = self.parse_like_regex()?;
+                return Ok(Expr::LikeRegex(Box::new(left), regex));
+            }
+            Some(Token::Ident(i)) if i == "starts" => {
+                self.require_value(&left)?;
+                self.pos += 1;
+                self.expect_keyword("with")?;
+                let prefix = match self.peek() {
+                    Some(Token::Variable(v)) => {
+                        let v = v.clone();
+                        self.pos += 1;
+                        Expr::Variable(v)
+                    }
+                    _ => Expr::String(self.expect_string()?),
+                };
+                return Ok(Expr::StartsWith(Box::new(left), Box::new(prefix)));
+            }
+            _ => return Ok(left),
+        };
+        self.require_value(&left)?;
+        self.pos += 1;
+        let right = self.parse_additive()?;
+        self.require_value(&right)?;
+        Ok(Expr::Cmp(op, Box::new(left), Box::new(right)))
+    }
+
+    fn parse_like_regex(&mut self) -> Result<Regex, EvalError> {
+        let pattern = self.expect_string()?;
+        let flags = match self.consume_keyword("flag") {
+            true => self.expect_string()?,
+            false => String::new(),
+        };
+        let mut case_insensitive = false;
+        let mut dot_matches_new_line = false;
+        let mut multi_line = false;
+        let mut extended = false;
+        let mut quote = false;
+        for flag in flags.chars() {
+            match flag {
+                'i' => case_insensitive = true,
+                's' => dot_matches_new_line = true,
+                'm' => multi_line = true,
+                'x' => extended = true,
+                'q' => quote = true,
+                _ => {
+                    return Err(EvalError::InvalidJsonPath(
+                        format!("unrecognized flag character \"{flag}\" in LIKE_REGEX predicate")
+                            .into(),
+                    ));
+                }
+            }
+        }
+        let mut regex = String::new();
+        if multi_line {
+            regex.push_str("(?m)");
+        }
+        match quote {
+            true => regex.push_str(&regex::escape(&pattern)),
+            false => {
+                if extended {
+                    regex.push_str("(?x)");
+                }
+                regex.push_str(&pattern);
+            }
+        }
+        Ok(Regex::new_dot_matches_new_line(
+            &regex,
+            case_insensitive,
+            dot_matches_new_line,
+        )?)
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, EvalError> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("+")) => ArithOp::Add,
+                Some(Token::Op("-")) => ArithOp::Sub,
+                _ => return Ok(expr),
+            };
+            self.require_value(&expr)?;
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            self.require_value(&right)?;
+            expr = Expr::Arith(op, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, EvalError> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("*")) => ArithOp::Mul,
+                Some(Token::Op("/")) => ArithOp::Div,
+                Some(Token::Op("%")) => ArithOp::Mod,
+                _ => return Ok(expr),
+            };
+            self.require_value(&expr)?;
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            self.require_value(&right)?;
+            expr = Expr::Arith(op, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, EvalError> {
+        if self.consume_op("-") {
+            let expr = self.parse_unary()?;
+            self.require_value(&expr)?;
+            return Ok(match expr {
+                Expr::Numeric(mut n) => {
+                    numeric::cx_datum().neg(&mut n);
+                    Expr::Numeric(n)
+                }
+                expr => Expr::Neg(Box::new(expr)),
+            });
+        }
+        if self.consume_op("+") {
+            let expr = self.parse_unary()?;
+            self.require_value(&expr)?;
+            return Ok(match expr {
+                expr @ Expr::Numeric(_) => expr,
+                expr => Expr::Pos(Box::new(expr)),
+            });
+        }
+        self.parse_postfix()
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr, EvalError> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            let accessor = if self.peek_op(".") {
+                self.require_value(&expr)?;
+                self.pos += 1;
+                self.parse_dot_accessor()?
+            } else if self.peek_op("[") {
+                self.require_value(&expr)?;
+                self.pos += 1;
+                self.parse_subscripts()?
+            } else if self.peek_op("?") {
+                self.require_value(&expr)?;
+                self.pos += 1;
+                self.expect_op("(")?;
+                self.filter_depth += 1;
+                let predicate = self.parse_or()?;
+                self.require_predicate(&predicate)?;
+                self.filter_depth -= 1;
+                self.expect_op(")")?;
+                Accessor::Filter(Box::new(predicate))
+            } else {
+                return Ok(expr);
+            };
+            expr = Expr::Accessor(Box::new(expr), accessor);
+        }
+    }
+
+    fn parse_dot_accessor(&mut self) -> Result<Accessor, EvalError> {
+        match self.next() {
+            Some(Token::Op("*")) => Ok(Accessor::MemberWildcard),
+            Some(Token::Op("**")) => {
+                let (mut min, mut max) = (0, u32::MAX);
+                if self.consume_op("{") {
+                    min = self.parse_level()?;
+                    max = match self.consume_keyword("to") {
+                        true => self.parse_level()?,
+                        false => min,
+                    };
+                    self.expect_op("}")?;
+                }
+                Ok(Accessor::Descendant { min, max })
+            }
+            Some(Token::String(key)) => Ok(Accessor::Member(key)),
+            Some(Token::Ident(name)) if self.peek_op("(") => {
+                let method = match name.as_str() {
+                    "type" => Method::Type,
+                    "size" => Method::Size,
+                    "double" => Method::Double,
+                    "ceiling" => Method::Ceiling,
+                    "floor" => Method::Floor,
+                    "abs" => Method::Abs,
+                    "keyvalue" => Method::KeyValue,
+                    "bigint" | "boolean" | "date" | "datetime" | "decimal" | "integer"
+                    | "number" | "string" | "time" | "time_tz" | "timestamp" | "timestamp_tz" => {
+                        return Err(EvalError::Unsupported {
+                            feature: format!("jsonpath item method .{name}()").into(),
+                            discussion_no: None,
+                        });
+                    }
+                    _ => {
+                        self.pos -= 1;
+                        return Err(self.syntax_error());
+                    }
+                };
+                self.expect_op("(")?;
+                self.expect_op(")")?;
+                Ok(Accessor::Method(method))
+            }
+            Some(Token::Ident(key)) => Ok(Accessor::Member(key)),
+            Some(_) => {
+                self.pos -= 1;
+                Err(self.syntax_error())
+            }
+            None => Err(self.syntax_error()),
+        }
+    }
+
+    fn parse_level(&mut self) -> Result<u32, EvalError> {
+        if self.consume_keyword("last") {
+            return Ok(u32::MAX);
+        }
+        match self.peek() {
+            Some(Token::Number(n)) => match n.parse() {
+                Ok(level) => {
+                    self.pos += 1;
+                    Ok(level)
+                }
+                Err(_) => Err(self.syntax_error()),
+            },
+            _ => Err(self.syntax_error()),
+        }
+    }
+
+    fn parse_subscripts(&mut self) -> Result<Accessor, EvalError> {
+        if self.peek_op("*") && matches!(self.peek_nth(1), Some(Token::Op("]"))) {
+            self.pos += 2;
+            return Ok(Accessor::ArrayWildcard);
+        }
+        self.subscript_depth += 1;
+        let mut subscripts = vec![];
+        loop {
+            let from = self.parse_additive()?;
+            let to = match self.consume_keyword("to") {
+                true => Some(self.parse_additive()?),
+                false => None,
+            };
+            subscripts.push((from, to));
+            if !self.consume_op(",") {
+                break;
+            }
+        }
+        self.subscript_depth -= 1;
+        self.expect_op("]")?;
+        Ok(Accessor::Subscripts(subscripts))
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, EvalError> {
+        let token = match self.next() {
+            Some(token) => token,
+            None => return Err(self.syntax_error()),
+        };
+        match token {
+            Token::Op("$") => Ok(Expr::Root),
+            Token::Op("@") if self.filter_depth == 0 => Err(EvalError::InvalidJsonPath(
+                "@ is not allowed in root expressions".into(),
+            )),
+            Token::Op("@") => Ok(Expr::Current),
+            Token::Variable(name) => Ok(Expr::Variable(name)),
+            Token::String(s) => Ok(Expr::String(s)),
+            Token::Number(n) => match strconv::parse_numeric(&n) {
+                Ok(n) => Ok(Expr::Numeric(n.0)),
+                Err(_) => {
+                    self.pos -= 1;
+                    Err(self.syntax_error())
+                }
+            },
+            Token::Ident(i) if i == "true" => Ok(Expr::Bool(true)),
+            Token::Ident(i) if i == "false" => Ok(Expr::Bool(false)),
+            Token::Ident(i) if i == "null" => Ok(Expr::Null),
+            Token::Ident(i) if i == "last" && self.subscript_depth == 0 => Err(
+                EvalError::InvalidJsonPath("LAST is allowed only in array subscripts".into()),
+            ),
+            Token::Ident(i) if i == "last" => Ok(Expr::Last),
+            Token::Ident(i) if i == "exists" && self.peek_op("(") => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                self.require_value(&expr)?;
+                self.expect_op(")")?;
+                Ok(Expr::Exists(Box::new(expr)))
+            }
+            Token::Op("(") => {
+                let expr = self.parse_or()?;
+                self.expect_op(")")?;
+                if self.consume_keyword("is") {
+                    self.expect_keyword("unknown")?;
+                    self.require_predicate(&expr)?;
+                    return Ok(Expr::IsUnknown(Box::new(expr)));
+                }
+                Ok(expr)
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.syntax_error())
+            }
+        }
+    }
+}
+
+/// Constructs an error that is suppressed when a JSON path function is
+/// called with `silent => true`.
+fn eval_error(msg: impl fmt::Display) -> EvalError {
+    EvalError::JsonPathEvaluation(msg.to_string().into())
+}
+
+fn numeric_datum<'a>(n: i64) -> Datum<'a> {
+    Datum::from(Numeric::from(n))
+}
+
+fn type_name(datum: Datum) -> Result<&'static str, EvalError> {
+    match datum {
+        Datum::Map(_) => Ok("object"),
+        Datum::List(_) => Ok("array"),
+        Datum::String(_) => Ok("string"),
+        Datum::Numeric(_) => Ok("number"),
+        Datum::True | Datum::False => Ok("boolean"),
+        Datum::JsonNull => Ok("null"),
+        d => Err(EvalError::Internal(
+            format!("JSON path item is not jsonb: {d:?}").into(),
+        )),
+    }
+}
+
+/// Compares two JSON items, returning `None` if they are not comparable.
+fn compare(op: CmpOp, a: Datum, b: Datum) -> Option<bool> {
+    let ord = match (a, b) {
+        (Datum::JsonNull, Datum::JsonNull) => Ordering::Equal,
+        // Null is comparable with any other scalar, but is equal to none of
+        // them.
+        (Datum::JsonNull, _) | (_, Datum::JsonNull) => return Some(op == CmpOp::Ne),
+        (Datum::True | Datum::False, Datum::True | Datum::False) => {
+            (a == Datum::True).cmp(&(b == Datum::True))
+        }
+        (Datum::Numeric(a), Datum::Numeric(b)) => a.cmp(&b),
+        (Datum::String(a), Datum::String(b)) => a.cmp(b),
+        _ => return None,
+    };
+    Some(op.holds(ord))
+}
+
+struct Evaluator<'a> {
+    strict: bool,
+    root: Datum<'a>,
+    vars: Datum<'a>,
+    temp_storage: &'a RowArena,
+}
+
+impl<'a> Evaluator<'a> {
+    /// In lax mode, replaces an array with its elements. Other items, and all
+    /// items in strict mode, are returned as is.
+    fn auto_unwrap(&self, item: Datum<'a>) -> Vec<Datum<'a>> {
+        match item {
+            Datum::List(list) if !self.strict => list.iter().collect(),
+            item => vec![item],
+        }
+    }
+
+    /// Evaluates `expr`, where `current` is the value of `@` and `last` is the
+    /// length of the innermost array being subscripted, if any.
+    fn eval(
+        &self,
+        expr: &Expr,
+        current: Datum<'a>,
+        last: Option<usize>,
+    ) -> Result<Vec<Datum<'a>>, EvalError> {
+        let items = match expr {
+            Expr::Null => vec![Datum::JsonNull],
+            Expr::Bool(b) => vec![Datum::from(*b)],
+            Expr::Numeric(n) => vec![Datum::from(*n)],
+            Expr::String(s) => vec![Datum::String(self.temp_storage.push_string(s.clone()))],
+            Expr::Root => vec![self.root],
+            Expr::Current => vec![current],
+            Expr::Last => match last {
+                Some(len) => vec![numeric_datum(
+                    i64::try_from(len).expect("array length fits in i64") - 1,
+                )],
+                None => {
+                    return Err(eval_error(
+                        "evaluating jsonpath LAST outside of array subscript",
+                    ));
+                }
+            },
+            Expr::Variable(name) => {
+                let value = match self.vars {
+                    Datum::Map(vars) => vars.iter().find(|(k, _)| k == name).map(|(_, v)| v),
+                    _ => None,
+                };
+                match value {
+                    Some(value) => vec![value],
+                    None => {
+                        return Err(EvalError::InvalidJsonPath(
+                            format!("could not find jsonpath variable \"{name}\"").into(),
+                        ));
+                    }
+                }
+            }
+            Expr::Accessor(base, accessor) => {
+                let mut items = vec![];
+                for item in self.eval(base, current, last)? {
+                    self.eval_accessor(accessor, item, current, last, &mut items)?;
+                }
+                items
+            }
+            Expr::Neg(expr) => self.eval_unary(expr, true, current, last)?,
+            Expr::Pos(expr) => self.eval_unary(expr, false, current, last)?,
+            Expr::Arith(op, left, right) => {
+                let mut a = self.eval_single_numeric(left, current, last, || {
+                    format!("left operand of jsonpath operator {op} is not a single numeric value")
+                })?;
+                let b = self.eval_single_numeric(right, current, last, || {
+                    format!("right operand of jsonpath operator {op} is not a single numeric value")
+                })?;
+                if matches!(op, ArithOp::Div | ArithOp::Mod) && b.is_zero() {
+                    return Err(eval_error("division by zero"));
+                }
+                let mut cx = numeric::cx_datum();
+                match op {
+                    ArithOp::Add => cx.add(&mut a, &b),
+                    ArithOp::Sub => cx.sub(&mut a, &b),
+                    ArithOp::Mul => cx.mul(&mut a, &b),
+                    ArithOp::Div => cx.div(&mut a, &b),
+                    ArithOp::Mod => cx.rem(&mut a, &b),
+                }
+                let cx_status = cx.status();
+                if cx_status.overflow() || numeric::munge_numeric(&mut a).is_err() {
+                    return Err(eval_error("value out of range: overflow"));
+                } else if cx_status.subnormal() {
+                    return Err(eval_error("value out of range: underflow"));
+                }
+                vec![Datum::from(a)]
+            }
+            expr => {
+                let result = self.eval_predicate(expr, current, last)?;
+                vec![match result {
+                    Some(b) => Datum::from(b),
+                    None => Datum::JsonNull,
+                }]
+            }
+        };
+        Ok(items)
+    }
+
+    /// Evaluates the operand of a unary `-` (if `neg` is set) or `+`.
+    fn eval_unary(
+        &self,
+        expr: &Expr,
+        neg: bool,
+        current: Datum<'a>,
+        last: Option<usize>,
+    ) -> Result<Vec<Datum<'a>>, EvalError> {
+        let mut items = vec![];
+        for item in self.eval(expr, current, last)? {
+            for item in self.auto_unwrap(item) {
+                let mut n = match item {
+                    Datum::Numeric(n) => n.0,
+                    _ => {
+                        return Err(eval_error(format!(
+                            "operand of unary jsonpath operator {} is not a numeric value",
+                            if neg { "-" } else { "+" }
+                        )));
+                    }
+                };
+                if neg {
+                    numeric::cx_datum().neg(&mut n);
+                }
+                items.push(Datum::from(n));
+            }
+        }
+        Ok(items)
+    }
+
+    /// Evaluates `expr`, which must return exactly one number (after
+    /// unwrapping arrays in lax mode).
+    fn eval_single_numeric(
+        &self,
+        expr: &Expr,
+        current: Datum<'a>,
+        last: Option<usize>,
+        msg: impl FnOnce() -> String,
+    ) -> Result<Numeric, EvalError> {
+        let items: Vec<_> = self
+            .eval(expr, current, last)?
+            .into_iter()
+            .flat_map(|item| self.auto_unwrap(item))
+            .collect();
+        match items[..] {
+            [Datum::Numeric(n)] => Ok(n.0),
+            _ => Err(eval_error(msg())),
+        }
+    }
+
+    fn eval_accessor(
+        &self,
+        accessor: &Accessor,
+        item: Datum<'a>,
+        current: Datum<'a>,
+        last: Option<usize>,
+        out: &mut Vec<Datum<'a>>,
+    ) -> Result<(), EvalError> {
+        match accessor {
+            Accessor::Member(key) => {
+                for item in self.auto_unwrap(item) {
+                    match item {
+                        Datum::Map(map) => match map.iter().find(|(k, _)| k == key) {
+                            Some((_, v)) => out.push(v),
+                            None if self.strict => {
+                                return Err(eval_error(format!(
+                                    "JSON object does not contain key \"{key}\""
+                                )));
+                            }
+                            None => {}
+                        },
+                        _ if self.strict => {
+                            return Err(eval_error(
+                                "jsonpath member accessor can only be applied to an object",
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Accessor::MemberWildcard => {
+                for item in self.auto_unwrap(item) {
+                    match item {
+                        Datum::Map(map) => out.extend(map.iter().map(|(_, v)| v)),
+                        _ if self.strict => {
+                            return Err(eval_error(
+                                "jsonpath wildcard member accessor can only be applied to an object",
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Accessor::Descendant { min, max } => descend(item, 0, *min, *max, out),
+            Accessor::ArrayWildcard => match item {
+                Datum::List(list) => out.extend(list.iter()),
+                _ if self.strict => {
+                    return Err(eval_error(
+                        "jsonpath wildcard array accessor can only be applied to an array",
+                    ));
+                }
+                item => out.push(item),
+            },
+            Accessor::Subscripts(subscripts) => {
+                let elems: Vec<_> = match item {
+                    Datum::List(list) => list.iter().collect(),
+                    _ if self.strict => {
+                        return Err(eval_error(
+                            "jsonpath array accessor can only be applied to an array",
+                        ));
+                    }
+                    item => vec![item],
+                };
+                let len = i64::try_from(elems.len()).expect("array length fits in i64");
+                for (from, to) in subscripts {
+                    let from = self.eval_subscript(from, current, elems.len())?;
+                    let to = match to {
+                        Some(to) => self.eval_subscript(to, current, elems.len())?,
+                        None => from,
+                    };
+                    let (from, to) = if self.strict {
+                        if from < 0 || from > to || to >= len {
+                            return Err(eval_error("jsonpath array subscript is out of bounds"));
+                        }
+                        (from, to)
+                    } else {
+                        (from.max(0), to.min(len - 1))
+                    };
+                    if from <= to {
+                        // Both bounds are known to be within `0..len`.
+                        let from = usize::try_from(from).expect("known non-negative");
+                        let to = usize::try_from(to).expect("known non-negative");
+                        out.extend_from_slice(&elems[from..=to]);
+                    }
+                }
+            }
+            Accessor::Filter(predicate) => {
+                for item in self.auto_unwrap(item) {
+                    if self.eval_predicate(predicate, item, last)? == Some(true) {
+                        out.push(item);
+                    }
+                }
+            }
+            Accessor::Method(Method::Type) => out.push(Datum::String(type_name(item)?)),
+            Accessor::Method(Method::Size) => match item {
+                Datum::List(list) => out.push(numeric_datum(
+                    i64::try_from(list.iter().count()).expect("array length fits in i64"),
+                )),
+                _ if self.strict => {
+                    return Err(eval_error(
+                        "jsonpath item method .size() can only be applied to an array",
+                    ));
+                }
+                _ => out.push(numeric_datum(1)),
+            },
+            Accessor::Method(Method::KeyValue) => {
+                for item in self.auto_unwrap(item) {
+                    let map = match item {
+                        Datum::Map(map) => map,
+                        _ => {
+                            return Err(eval_error(
+                                "jsonpath item method .keyvalue() can only be applied to an object",
+                            ));
+                        }
+                    };
+                    for (k, v) in map.iter() {
+                        out.push(self.temp_storage.make_datum(|packer| {
+                            packer.push_dict([("key", Datum::String(k)), ("value", v)])
+                        }));
+                    }
+                }
+            }
+            Accessor::Method(method) => {
+                for item in self.auto_unwrap(item) {
+                    out.push(eval_numeric_method(*method, item)?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates an array subscript to an integer, truncating any fractional
+    /// part.
+    fn eval_subscript(
+        &self,
+        expr: &Expr,
+        current: Datum<'a>,
+        len: usize,
+    ) -> Result<i64, EvalError> {
+        let mut n = self.eval_single_numeric(expr, current, Some(len), || {
+            "jsonpath array subscript is not a single numeric value".into()
+        })?;
+        let mut cx = numeric::cx_datum();
+        cx.set_rounding(Rounding::Down);
+        cx.round(&mut n);
+        cx.try_into_i64(n)
+            .map_err(|_| eval_error("jsonpath array subscript is out of integer range"))
+    }
+
+    /// Evaluates `expr` and, in lax mode, unwraps any resulting arrays. Errors
+    /// that may be suppressed result in `None`.
+    fn eval_operand(
+        &self,
+        expr: &Expr,
+        current: Datum<'a>,
+        last: Option<usize>,
+    ) -> Result<Option<Vec<Datum<'a>>>, EvalError> {
+        match self.eval(expr, current, last) {
+            Ok(items) => Ok(Some(
+                items
+                    .into_iter()
+                    .flat_map(|item| self.auto_unwrap(item))
+                    .collect(),
+            )),
+            Err(EvalError::JsonPathEvaluation(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Combines the results of testing a predicate against a sequence of
+    /// items: the predicate holds if it holds for any item.
+    ///
+    /// An item for which the result is unknown makes the entire result
+    /// unknown in strict mode; in lax mode, it does so only if the predicate
+    /// holds for no other item.
+    fn any(&self, results: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+        let mut found = false;
+        let mut unknown = false;
+        for result in results {
+            match result {
+                Some(true) if !self.strict => return Some(true),
+                Some(true) => found = true,
+                Some(false) => {}
+                None if self.strict => return None,
+                None => unknown = true,
+            }
+        }
+        if found {
+            Some(true)
+        } else if unknown {
+            None
+        } else {
+            Some(false)
+        }
+    }
+
+    /// Evaluates a predicate using three-valued logic, where `None` means
+    /// unknown.
+    fn eval_predicate(
+        &self,
+        expr: &Expr,
+        current: Datum<'a>,
+        last: Option<usize>,
+    ) -> Result<Option<bool>, EvalError> {
+        let result = match expr {
+            Expr::Cmp(op, left, right) => {
+                let Some(left) = self.eval_operand(left, current, last)? else {
+                    return Ok(None);
+                };
+                let Some(right) = self.eval_operand(right, current, last)? else {
+                    return Ok(None);
+                };
+                self.any(
+                    left.iter()
+                        .flat_map(|a| right.iter().map(move |b| compare(*op, *a, *b))),
+                )
+            }
+            Expr::And(left, right) => match self.eval_predicate(left, current, last)? {
+                Some(false) => Some(false),
+                l => match self.eval_predicate(right, current, last)? {
+                    Some(false) => Some(false),
+                    Some(true) => l,
+                    None => None,
+                },
+            },
+            Expr::Or(left, right) => match self.eval_predicate(left, current, last)? {
+                Some(true) => Some(true),
+                l => match self.eval_predicate(right, current, last)? {
+                    Some(true) => Some(true),
+                    Some(false) => l,
+                    None => None,
+                },
+            },
+            Expr::Not(expr) => self.eval_predicate(expr, current, last)?.map(|b| !b),
+            Expr::IsUnknown(expr) => Some(self.eval_predicate(expr, current, last)?.is_none()),
+            Expr::Exists(expr) => match self.eval(expr, current, last) {
+                Ok(items) => Some(!items.is_empty()),
+                Err(EvalError::JsonPathEvaluation(_)) => None,
+                Err(e) => return Err(e),
+            },
+            Expr::StartsWith(expr, prefix) => {
+                let Some(items) = self.eval_operand(expr, current, last)? else {
+                    return Ok(None);
+                };
+                let prefix = match self.eval_operand(prefix, current, last)?.as_deref() {
+                    Some([Datum::String(prefix)]) => *prefix,
+                    _ => return Ok(None),
+                };
+                self.any(items.iter().map(|item| match item {
+                    Datum::String(s) => Some(s.starts_with(prefix)),
+                    _ => None,
+                }))
+            }
+            Expr::LikeRegex(expr, regex) => {
+                let Some(items) = self.eval_operand(expr, current, last)? else {
+                    return Ok(None);
+                };
+                self.any(items.iter().map(|item| match item {
+                    Datum::String(s) => Some(regex.is_match(s)),
+                    _ => None,
+                }))
+            }
+            // Any other expression is a value, which the parser does not
+            // permit in a predicate position.
+            expr => unreachable!("{expr:?} is not a predicate"),
+        };
+        Ok(result)
+    }
+}
+
+/// Collects `item` and its descendants whose nesting level, relative to
+/// `item`, is between `min` and `max` inclusive.
+fn descend<'a>(item: Datum<'a>, level: u32, min: u32, max: u32, out: &mut Vec<Datum<'a>>) {
+    if level >= min {
+        out.push(item);
+    }
+    if level < max {
+        match item {
+            Datum::Map(map) => {
+                for (_, v) in map.iter() {
+                    descend(v, level + 1, min, max, out);
+                }
+            }
+            Datum::List(list) => {
+                for elem in list.iter() {
+                    descend(elem, level + 1, min, max, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn eval_numeric_method<'a>(method: Method, item: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let mut n = match (method, item) {
+        (Method::Double, Datum::Numeric(n)) => {
+            let f = n.0.to_string().parse::<f64>().unwrap();
+            if f.is_infinite() {
+                return Err(eval_error(
+                    "numeric argument of jsonpath item method .double() is out of range for type double precision",
+                ));
+            }
+            return double_to_datum(f);
+        }
+        (Method::Double, Datum::String(s)) => {
+            let f = s.trim().parse::<f64>().map_err(|_| {
+                eval_error(
+                    "string argument of jsonpath item method .double() is not a valid representation of a double precision number",
+                )
+            })?;
+            if !f.is_finite() {
+                return Err(eval_error(
+                    "NaN or Infinity is not allowed for jsonpath item method .double()",
+                ));
+            }
+            return double_to_datum(f);
+        }
+        (Method::Double, _) => {
+            return Err(eval_error(
+                "jsonpath item method .double() can only be applied to a string or numeric value",
+            ));
+        }
+        (_, Datum::Numeric(n)) => n.0,
+        _ => {
+            return Err(eval_error(format!(
+                "jsonpath item method .{method}() can only be applied to a numeric value"
+            )));
+        }
+    };
+    let mut cx = numeric::cx_datum();
+    match method {
+        Method::Abs => cx.abs(&mut n),
+        // Rounding is a no-op if there are no fractional digits.
+        Method::Ceiling | Method::Floor if n.exponent() < 0 => {
+            cx.set_rounding(match method {
+                Method::Ceiling => Rounding::Ceiling,
+                _ => Rounding::Floor,
+            });
+            cx.round(&mut n);
+            numeric::munge_numeric(&mut n).unwrap();
+        }
+        _ => {}
+    }
+    Ok(Datum::from(n))
+}
+
+fn double_to_datum<'a>(f: f64) -> Result<Datum<'a>, EvalError> {
+    // Format in scientific notation to avoid exceeding the maximum numeric
+    // precision with large or small magnitudes.
+    let n: OrderedDecimal<Numeric> =
+        strconv::parse_numeric(&format!("{f:e}")).map_err(eval_error)?;
+    Ok(Datum::Numeric(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::adt::jsonb::{Jsonb, JsonbRef};
+
+    use super::*;
+
+    fn query(target: &str, path: &str) -> Result<Vec<String>, String> {
+        let target: Jsonb = target.parse().unwrap();
+        let vars: Jsonb = "{}".parse().unwrap();
+        let path = compile(path).map_err(|e| e.to_string())?;
+        let temp_storage = RowArena::new();
+        let items = path
+            .query(
+                target.as_ref().into_datum(),
+                vars.as_ref().into_datum(),
+                &temp_storage,
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(items
+            .into_iter()
+            .map(|d| JsonbRef::from_datum(d).to_string())
+            .collect())
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `decContextDefault` on OS `linux`
+    fn test_query() {
+        let target = r#"{"a": [1, 2, {"b": "x"}], "c": 2.5, "d": null}"#;
+        let test_cases = [
+            ("$.a[*]", Ok(vec!["1", "2", r#"{"b":"x"}"#])),
+            ("$.a[1 to last]", Ok(vec!["2", r#"{"b":"x"}"#])),
+            ("$.a.b", Ok(vec![r#""x""#])),
+            (
+                "strict $.a.b",
+                Err("jsonpath member accessor can only be applied to an object"),
+            ),
+            ("$.missing", Ok(vec![])),
+            (
+                "strict $.missing",
+                Err(r#"JSON object does not contain key "missing""#),
+            ),
+            ("$.a[*] ? (@ >= 2)", Ok(vec!["2"])),
+            ("$.c.ceiling() + $.a[0]", Ok(vec!["4"])),
+            ("$.c.type()", Ok(vec![r#""number""#])),
+            ("$.a.size()", Ok(vec!["3"])),
+            ("$.d == null", Ok(vec!["true"])),
+            ("$.c > \"x\"", Ok(vec!["null"])),
+            ("($.c > \"x\") is unknown", Ok(vec!["true"])),
+            ("$.a[2].b starts with \"x\"", Ok(vec!["true"])),
+            ("$.a[2].b like_regex \"X\" flag \"i\"", Ok(vec!["true"])),
+            ("$.c / 0", Err("division by zero")),
+            ("$.**{2}", Ok(vec!["1", "2", r#"{"b":"x"}"#])),
+            ("@", Err("@ is not allowed in root expressions")),
+            ("$.a[last", Err("syntax error at end of jsonpath input")),
+            (
+                "$.a && $.c",
+                Err("syntax error at or near \"&&\" of jsonpath input"),
+            ),
+            (
+                "$.a ? (@)",
+                Err("syntax error at or near \")\" of jsonpath input"),
+            ),
+            ("$.c + $x", Err(r#"could not find jsonpath variable "x""#)),
+        ];
+        for (path, expected) in test_cases {
+            let expected = expected
+                .map(|items| items.into_iter().map(String::from).collect::<Vec<_>>())
+                .map_err(String::from);
+            assert_eq!(query(target, path), expected, "path: {path}");
+        }
+    }
+}
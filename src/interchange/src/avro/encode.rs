@@ -18,7 +18,9 @@ use itertools::Itertools;
 use mz_avro::Schema;
 use mz_avro::types::{DecimalValue, ToAvro, Value};
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::bit::BitString;
 use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::multirange;
 use mz_repr::adt::numeric::{self, NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::{CatalogItemId, ColumnName, ColumnType, Datum, RelationDesc, Row, ScalarType};
 use serde_json::json;
@@ -417,6 +419,10 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 }
                 ScalarType::MzTimestamp => Value::String(datum.unwrap_mz_timestamp().to_string()),
                 ScalarType::Range { .. } => Value::String(datum.unwrap_range().to_string()),
+                ScalarType::MultiRange { .. } => Value::String(multirange::to_string(datum)),
+                ScalarType::Bit { .. } | ScalarType::VarBit { .. } => {
+                    Value::String(BitString::decode(datum.unwrap_bytes()).to_string())
+                }
                 ScalarType::MzAclItem => Value::String(datum.unwrap_mz_acl_item().to_string()),
             };
             if typ.nullable {
@@ -11,8 +11,10 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::bit::BitString;
 use mz_repr::adt::char;
 use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::multirange;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::{CatalogItemId, ColumnName, ColumnType, Datum, RelationDesc, ScalarType};
 use serde_json::{Map, json};
@@ -230,6 +232,10 @@ impl ToJson for TypedDatum<'_> {
                 // records.
                 json!(datum.unwrap_range().to_string())
             }
+            ScalarType::MultiRange { .. } => json!(multirange::to_string(datum)),
+            ScalarType::Bit { .. } | ScalarType::VarBit { .. } => {
+                json!(BitString::decode(datum.unwrap_bytes()).to_string())
+            }
             ScalarType::MzAclItem => json!(datum.unwrap_mz_acl_item().to_string()),
         };
         // We don't need to recurse into map or object here because those already recursively call
@@ -305,7 +311,9 @@ fn build_row_schema_field_type(
         ScalarType::String
         | ScalarType::Char { .. }
         | ScalarType::VarChar { .. }
-        | ScalarType::PgLegacyName => {
+        | ScalarType::PgLegacyName
+        | ScalarType::Bit { .. }
+        | ScalarType::VarBit { .. } => {
             json!("string")
         }
         ScalarType::Jsonb => json!({
@@ -393,7 +401,7 @@ fn build_row_schema_field_type(
         }
         ScalarType::MzTimestamp => json!("string"),
         // https://debezium.io/documentation/reference/stable/connectors/postgresql.html
-        ScalarType::Range { .. } => json!("string"),
+        ScalarType::Range { .. } | ScalarType::MultiRange { .. } => json!("string"),
         ScalarType::MzAclItem => json!("string"),
     };
     if typ.nullable {
@@ -1627,6 +1627,7 @@ fn create_connection_info(
                 }
             },
         },
+        flight: btreemap! {},
     };
     if authenticator_kind == AuthenticatorKind::Password {
         listeners_config.sql.remove("internal");
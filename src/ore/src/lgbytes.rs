@@ -96,6 +96,8 @@ pub struct LgBytesMetrics {
     pub persist_s3: LgBytesOpMetrics,
     /// Metrics for the "persist_azure" usage of lgalloc bytes.
     pub persist_azure: LgBytesOpMetrics,
+    /// Metrics for the "persist_gcs" usage of lgalloc bytes.
+    pub persist_gcs: LgBytesOpMetrics,
     /// Metrics for the "persist_arrow" usage of lgalloc bytes.
     pub persist_arrow: LgBytesOpMetrics,
 }
@@ -190,6 +192,7 @@ impl LgBytesMetrics {
         LgBytesMetrics {
             persist_s3: op("persist_s3"),
             persist_azure: op("persist_azure"),
+            persist_gcs: op("persist_gcs"),
             persist_arrow: op("persist_arrow"),
         }
     }
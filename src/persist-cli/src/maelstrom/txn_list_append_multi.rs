@@ -34,6 +34,7 @@ use mz_persist_client::rpc::PubSubClientConnection;
 use mz_persist_client::{Diagnostics, PersistClient, ShardId};
 use mz_persist_types::codec_impls::{StringSchema, UnitSchema};
 use mz_timestamp_oracle::TimestampOracle;
+use mz_timestamp_oracle::file_oracle::{FileTimestampOracle, FileTimestampOracleConfig};
 use mz_timestamp_oracle::postgres_oracle::{
     PostgresTimestampOracle, PostgresTimestampOracleConfig,
 };
@@ -478,6 +479,19 @@ impl Service for TransactorService {
                     .await,
                 )
             }
+            Some(("file", uri)) => {
+                let cfg = FileTimestampOracleConfig::new(uri, &metrics_registry);
+                Box::new(
+                    FileTimestampOracle::open(
+                        cfg,
+                        "maelstrom".to_owned(),
+                        mz_repr::Timestamp::minimum(),
+                        NOW_ZERO.clone(),
+                        false, /* read-only */
+                    )
+                    .await,
+                )
+            }
             Some(("mem", _)) => Box::new(MemTimestampOracle::default()),
             Some((scheme, _)) => unimplemented!("unsupported oracle type: {}", scheme),
             None => unimplemented!("TODO: support maelstrom oracle"),
@@ -74,6 +74,7 @@ fn main() {
         // is to re-run if any file in the crate changes; that's still a bit too
         // broad, but it's better.
         .emit_rerun_if_changed(false)
+        .extern_path(".mz_dyncfg", "::mz_dyncfg")
         .extern_path(".mz_persist", "::mz_persist")
         .extern_path(".mz_persist.gen", "::mz_persist::generated")
         .extern_path(".mz_persist_types", "::mz_persist_types")
@@ -360,6 +360,8 @@ pub struct BatchBuilderConfig {
     /// continue to generate one run per part for unordered batches.
     /// See the config definition for details.
     pub(crate) max_runs: Option<usize>,
+    /// The id of the key that blob data is encrypted with, if any.
+    pub(crate) encryption_key_id: Option<String>,
 }
 
 // TODO: Remove this once we're comfortable that there aren't any bugs.
@@ -456,6 +458,10 @@ impl BatchBuilderConfig {
                 limit @ 2.. => Some(limit),
                 _ => None,
             },
+            encryption_key_id: value
+                .blob_encryption_keyring
+                .as_ref()
+                .map(|keyring| keyring.current_key_id().to_owned()),
         }
     }
 }
@@ -1079,6 +1085,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
         let goodbytes = updates.updates.goodbytes();
         let metrics_ = Arc::clone(&metrics);
         let schema_id = write_schemas.id;
+        let encryption_key_id = cfg.encryption_key_id.clone();
 
         let (stats, key_lower, structured_key_lower, (buf, encode_time)) = isolated_runtime
             .spawn_named(|| "batch::encode_part", async move {
@@ -1223,6 +1230,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
             schema_id,
             // Field has been deprecated but kept around to roundtrip state.
             deprecated_schema_id: None,
+            encryption_key_id,
         })
     }
 
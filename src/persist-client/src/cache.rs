@@ -24,6 +24,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::task::{AbortOnDropHandle, JoinHandle};
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encryption::EncryptedBlob;
 use mz_persist::location::{
     BLOB_GET_LIVENESS_KEY, Blob, CONSENSUS_HEAD_LIVENESS_KEY, Consensus, ExternalError, Tasked,
     VersionedData,
@@ -31,11 +32,11 @@ use mz_persist::location::{
 use mz_persist_types::{Codec, Codec64};
 use timely::progress::Timestamp;
 use tokio::sync::{Mutex, OnceCell};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::error::{CodecConcreteType, CodecMismatch};
-use crate::internal::cache::BlobMemCache;
+use crate::internal::cache::{BlobDiskCache, BlobMemCache, DiskCache};
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{LockMetrics, Metrics, MetricsBlob, MetricsConsensus, ShardMetrics};
 use crate::internal::state::TypedState;
@@ -58,6 +59,8 @@ pub struct PersistClientCache {
     pub cfg: PersistConfig,
     pub(crate) metrics: Arc<Metrics>,
     blob_by_uri: Mutex<BTreeMap<SensitiveUrl, (RttLatencyTask, Arc<dyn Blob>)>>,
+    /// The on-disk blob cache, shared by all blob locations, if configured.
+    blob_cache_disk: Option<Arc<DiskCache>>,
     consensus_by_uri: Mutex<BTreeMap<SensitiveUrl, (RttLatencyTask, Arc<dyn Consensus>)>>,
     isolated_runtime: Arc<IsolatedRuntime>,
     pub(crate) state_cache: Arc<StateCache>,
@@ -88,11 +91,27 @@ impl PersistClientCache {
         );
         let isolated_runtime =
             IsolatedRuntime::new(registry, Some(cfg.isolated_runtime_worker_threads));
+        // The disk cache is only an optimization, so run without it rather
+        // than fail if the directory is unusable.
+        let blob_cache_disk = cfg.blob_cache_disk_dir.as_ref().and_then(
+            |dir| match DiskCache::open(&cfg, Arc::clone(&metrics), dir.clone()) {
+                Ok(cache) => Some(Arc::new(cache)),
+                Err(err) => {
+                    warn!(
+                        "failed to open persist disk blob cache at {}, continuing without it: {}",
+                        dir.display(),
+                        err
+                    );
+                    None
+                }
+            },
+        );
 
         PersistClientCache {
             cfg,
             metrics,
             blob_by_uri: Mutex::new(BTreeMap::new()),
+            blob_cache_disk,
             consensus_by_uri: Mutex::new(BTreeMap::new()),
             isolated_runtime: Arc::new(isolated_runtime),
             state_cache,
@@ -216,6 +235,24 @@ impl PersistClientCache {
                     blob.clone().open()
                 })
                 .await;
+                // This is intentionally "inside" EncryptedBlob so that only
+                // encrypted data is written to local disk. Note that this
+                // means blob metrics include disk cache hits; the disk cache
+                // reports its own hit ratio.
+                let blob = match &self.blob_cache_disk {
+                    Some(cache) => BlobDiskCache::new(Arc::clone(cache), blob),
+                    None => blob,
+                };
+                // This is intentionally "inside" MetricsBlob so that blob
+                // metrics reflect the sizes actually stored.
+                let blob = match &self.cfg.blob_encryption_keyring {
+                    Some(keyring) => Arc::new(EncryptedBlob::new(
+                        blob,
+                        Arc::clone(keyring),
+                        Arc::clone(&self.cfg.configs),
+                    )),
+                    None => blob,
+                };
                 let blob = Arc::new(MetricsBlob::new(blob, Arc::clone(&self.metrics)));
                 let blob = Arc::new(Tasked(blob));
                 let task = blob_rtt_latency_task(
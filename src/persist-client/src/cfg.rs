@@ -11,6 +11,7 @@
 
 //! The tunable knobs for persist.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -20,6 +21,7 @@ use mz_dyncfg::{Config, ConfigDefault, ConfigSet, ConfigUpdates};
 use mz_ore::instrument;
 use mz_ore::now::NowFn;
 use mz_persist::cfg::BlobKnobs;
+pub use mz_persist::encryption::BlobKeyring;
 use mz_persist::retry::Retry;
 use mz_postgres_client::PostgresClientKnobs;
 use proptest_derive::Arbitrary;
@@ -132,6 +134,11 @@ pub struct PersistConfig {
     /// Number of worker threads to create for the [`crate::IsolatedRuntime`], defaults to the
     /// number of threads.
     pub isolated_runtime_worker_threads: usize,
+    /// The keys to encrypt blob data with, if blob data is to be encrypted.
+    pub blob_encryption_keyring: Option<Arc<BlobKeyring>>,
+    /// The local directory to cache fetched blobs in, if any. The cache is
+    /// only used while `persist_blob_cache_disk_enabled` is set.
+    pub blob_cache_disk_dir: Option<PathBuf>,
 }
 
 // Impl Deref to ConfigSet for convenience of accessing the dynamic configs.
@@ -173,6 +180,8 @@ impl PersistConfig {
             writer_lease_duration: 60 * Duration::from_secs(60),
             critical_downgrade_interval: Duration::from_secs(30),
             isolated_runtime_worker_threads: num_cpus::get(),
+            blob_encryption_keyring: None,
+            blob_cache_disk_dir: None,
             // TODO: This doesn't work with the process orchestrator. Instead,
             // separate --log-prefix into --service-name and --enable-log-prefix
             // options, where the first is always provided and the second is
@@ -187,6 +196,16 @@ impl PersistConfig {
         updates.apply(self)
     }
 
+    /// Returns the current values of all configs in this configuration, for
+    /// applying to the configuration of another process.
+    pub fn config_updates(&self) -> ConfigUpdates {
+        let mut updates = ConfigUpdates::default();
+        for entry in self.configs.entries() {
+            updates.add_dynamic(entry.name(), entry.val());
+        }
+        updates
+    }
+
     /// Applies the provided updates to this configuration.
     ///
     /// You should prefer calling this method over mutating `self.configs`
@@ -316,12 +335,17 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::internal::cache::BLOB_CACHE_MEM_LIMIT_BYTES)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_WITH_THREADS)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_FACTOR_BYTES)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_ENABLED)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_LIMIT_BYTES)
         .add(&crate::internal::compact::COMPACTION_MINIMUM_TIMEOUT)
         .add(&crate::internal::compact::COMPACTION_USE_MOST_RECENT_SCHEMA)
         .add(&crate::internal::compact::COMPACTION_CHECK_PROCESS_FLAG)
+        .add(&crate::internal::compact::COMPACTION_SERVICE_ENABLED)
+        .add(&crate::internal::compact::COMPACTION_SERVICE_RESPONSE_TIMEOUT)
         .add(&crate::internal::machine::CLAIM_UNCLAIMED_COMPACTIONS)
         .add(&crate::internal::machine::CLAIM_COMPACTION_PERCENT)
         .add(&crate::internal::machine::CLAIM_COMPACTION_MIN_VERSION)
+        .add(&crate::internal::machine::BLOB_REENCRYPTION_INTERVAL)
         .add(&crate::internal::machine::NEXT_LISTEN_BATCH_RETRYER_CLAMP)
         .add(&crate::internal::machine::NEXT_LISTEN_BATCH_RETRYER_FIXED_SLEEP)
         .add(&crate::internal::machine::NEXT_LISTEN_BATCH_RETRYER_INITIAL_BACKOFF)
@@ -352,6 +376,9 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::stats::STATS_UNTRIMMABLE_COLUMNS_PREFIX)
         .add(&crate::stats::STATS_UNTRIMMABLE_COLUMNS_SUFFIX)
         .add(&crate::fetch::PART_DECODE_FORMAT)
+        .add(&crate::fetch::PART_PROJECTION_PUSHDOWN)
+        .add(&crate::fetch::PART_PROJECTION_RANGE_READS)
+        .add(&crate::fetch::PART_PROJECTION_RANGE_READS_MIN_BYTES)
         .add(&crate::write::COMBINE_INLINE_WRITES)
 }
 
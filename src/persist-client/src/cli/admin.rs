@@ -10,6 +10,7 @@
 //! CLI introspection tools for persist
 
 use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -23,7 +24,7 @@ use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::url::SensitiveUrl;
-use mz_persist::location::{Blob, Consensus, ExternalError};
+use mz_persist::location::{Blob, Consensus, ExternalError, SeqNo};
 use mz_persist_types::codec_impls::TodoSchema;
 use mz_persist_types::{Codec, Codec64};
 use prometheus::proto::{MetricFamily, MetricType};
@@ -35,7 +36,9 @@ use crate::async_runtime::IsolatedRuntime;
 use crate::cache::StateCache;
 use crate::cfg::{COMPACTION_MEMORY_BOUND_BYTES, all_dyncfgs};
 use crate::cli::args::{StateArgs, StoreArgs, make_blob, make_consensus};
-use crate::cli::inspect::FAKE_OPAQUE_CODEC;
+use crate::cli::inspect::{FAKE_OPAQUE_CODEC, KVTD_CODECS};
+use crate::error::CodecConcreteType;
+use crate::internal::backup::BackupManifest;
 use crate::internal::compact::{CompactConfig, CompactReq, Compactor};
 use crate::internal::encoding::Schemas;
 use crate::internal::gc::{GarbageCollector, GcReq};
@@ -44,7 +47,8 @@ use crate::internal::trace::FueledMergeRes;
 use crate::rpc::{NoopPubSubSender, PubSubSender};
 use crate::write::{WriteHandle, WriterId};
 use crate::{
-    BUILD_INFO, Diagnostics, Metrics, PersistClient, PersistConfig, ShardId, StateVersions,
+    BUILD_INFO, Diagnostics, LeasedReaderId, Metrics, PersistClient, PersistConfig,
+    READER_LEASE_DURATION, ShardId, StateVersions,
 };
 
 /// Commands for read-write administration of persist state
@@ -77,6 +81,10 @@ pub(crate) enum Command {
     /// Attempt to ensure that all the files referenced by consensus are available
     /// in Blob.
     RestoreBlob(RestoreBlobArgs),
+    /// Back up every shard, as of a single timestamp, to another Blob.
+    Backup(BackupArgs),
+    /// Restore every shard in a backup into an empty Consensus and Blob.
+    RestoreBackup(RestoreBackupArgs),
 }
 
 /// Manually completes all fueled compactions in a shard.
@@ -123,6 +131,44 @@ pub(crate) struct RestoreBlobArgs {
     concurrency: usize,
 }
 
+/// Back up the state of every shard, as of a single timestamp, along with all
+/// the blobs that state references.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct BackupArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    /// Blob to write the backup to. This should be a location dedicated to
+    /// this backup.
+    #[clap(long, env = "BACKUP_BLOB_URI")]
+    backup_blob_uri: SensitiveUrl,
+
+    /// The timestamp to back up every shard as of, which must be readable in
+    /// every shard. Defaults to the latest such timestamp.
+    #[clap(long)]
+    as_of: Option<u64>,
+
+    /// The number of concurrent backup operations to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
+/// Restore every shard in a backup, cut back to the backup's as_of. The target
+/// Consensus must not already contain any of the backed up shards.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct RestoreBackupArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    /// Blob containing the backup to restore.
+    #[clap(long, env = "BACKUP_BLOB_URI")]
+    backup_blob_uri: SensitiveUrl,
+
+    /// The number of concurrent restore operations to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
 /// Runs the given read-write admin command.
 pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
     match command.command {
@@ -374,6 +420,207 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
                 bail!("referenced blobs were not restored: {not_restored:#?}")
             }
         }
+        Command::Backup(args) => {
+            let BackupArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                backup_blob_uri,
+                as_of,
+                concurrency,
+            } = args;
+            let commit = command.commit;
+            let expected_version = command
+                .expected_version
+                .as_ref()
+                .map(|v| Version::parse(v))
+                .transpose()?;
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            // The source environment's data is only ever read from, but we
+            // register a reader in each of its shards.
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, commit, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &blob_uri, false, Arc::clone(&metrics)).await?;
+            let backup = make_blob(&cfg, &backup_blob_uri, commit, Arc::clone(&metrics)).await?;
+            let versions = StateVersions::new(
+                cfg.clone(),
+                Arc::clone(&consensus),
+                Arc::clone(&blob),
+                Arc::clone(&metrics),
+            );
+
+            // Blobs already in the backup (e.g. from an interrupted previous
+            // attempt) don't need to be copied again.
+            let mut existing = BTreeSet::new();
+            backup
+                .list_keys_and_metadata("", &mut |x| {
+                    existing.insert(x.key.to_owned());
+                })
+                .await?;
+
+            let shard_ids = consensus
+                .list_keys()
+                .map_ok(|shard| ShardId::from_str(&shard).expect("invalid shard id"))
+                .try_collect::<Vec<_>>()
+                .await?;
+            // Registered one at a time, because the fake codecs we open each
+            // shard with are process-global.
+            let heartbeat_interval = READER_LEASE_DURATION.get(&cfg) / 4;
+            let mut readers = Vec::with_capacity(shard_ids.len());
+            let mut last_heartbeat = Instant::now();
+            for shard_id in shard_ids {
+                let reader = BackupReader::register(
+                    &cfg,
+                    Arc::clone(&consensus),
+                    Arc::clone(&blob),
+                    Arc::clone(&metrics),
+                    shard_id,
+                    commit,
+                    expected_version.clone(),
+                )
+                .await;
+                let reader = match reader {
+                    Ok(reader) => reader,
+                    Err(err) => {
+                        BackupReader::expire_all(readers).await;
+                        return Err(err);
+                    }
+                };
+                readers.push(reader);
+                if last_heartbeat.elapsed() >= heartbeat_interval {
+                    if let Err(err) = BackupReader::heartbeat_all(&cfg, &readers).await {
+                        BackupReader::expire_all(readers).await;
+                        return Err(err);
+                    }
+                    last_heartbeat = Instant::now();
+                }
+            }
+
+            let res = async {
+                let as_of = crate::internal::backup::backup_as_of(
+                    readers
+                        .iter()
+                        .map(|reader| (reader.shard_id(), &reader.since, &reader.upper)),
+                    as_of,
+                )?;
+                info!("Backing up {} shards as of {as_of}.", readers.len());
+                let copy = stream::iter(readers.iter())
+                    .map(|reader| {
+                        let (versions, blob, backup, existing, metrics) =
+                            (&versions, &blob, &backup, &existing, &metrics);
+                        async move {
+                            let start = Instant::now();
+                            let shard_id = reader.shard_id();
+                            let shard = crate::internal::backup::backup_shard(
+                                versions,
+                                blob.as_ref(),
+                                backup.as_ref(),
+                                existing,
+                                shard_id,
+                                reader.seqno,
+                                as_of,
+                                metrics,
+                            )
+                            .await?;
+                            info!(
+                                "Backed up shard {shard_id} at {}, {:?} elapsed.",
+                                shard.seqno,
+                                start.elapsed()
+                            );
+                            Ok::<_, anyhow::Error>((shard_id, shard))
+                        }
+                    })
+                    .buffer_unordered(concurrency)
+                    .try_collect::<BTreeMap<_, _>>();
+                // Keep the readers' leases alive for as long as the copy runs.
+                let heartbeats = async {
+                    loop {
+                        tokio::time::sleep(heartbeat_interval).await;
+                        if let Err(err) = BackupReader::heartbeat_all(&cfg, &readers).await {
+                            return err;
+                        }
+                    }
+                };
+                let shards = tokio::select! {
+                    shards = copy => shards?,
+                    err = heartbeats => return Err(err),
+                };
+
+                // Written last, so that a backup without a manifest is never
+                // mistaken for a complete one.
+                let manifest = BackupManifest { as_of, shards };
+                manifest.write(backup.as_ref()).await?;
+                info!("Backed up {} shards as of {as_of}.", manifest.shards.len());
+                Ok::<_, anyhow::Error>(())
+            }
+            .await;
+            BackupReader::expire_all(readers).await;
+            res?;
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
+        Command::RestoreBackup(args) => {
+            let RestoreBackupArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                backup_blob_uri,
+                concurrency,
+            } = args;
+            let commit = command.commit;
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, commit, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &blob_uri, commit, Arc::clone(&metrics)).await?;
+            let backup = make_blob(&cfg, &backup_blob_uri, false, Arc::clone(&metrics)).await?;
+
+            let manifest = BackupManifest::fetch(backup.as_ref()).await?;
+            info!(
+                "Restoring {} shards as of {}.",
+                manifest.shards.len(),
+                manifest.as_of
+            );
+            stream::iter(manifest.shards.iter())
+                .map(|(shard_id, shard)| {
+                    let (cfg, backup, blob, consensus, metrics) =
+                        (&cfg, &backup, &blob, &consensus, &metrics);
+                    async move {
+                        let start = Instant::now();
+                        crate::internal::backup::restore_shard(
+                            cfg,
+                            backup.as_ref(),
+                            blob.as_ref(),
+                            consensus.as_ref(),
+                            *shard_id,
+                            shard,
+                            manifest.as_of,
+                            metrics,
+                        )
+                        .await?;
+                        info!(
+                            "Restored shard {shard_id} at {}, {:?} elapsed.",
+                            shard.seqno,
+                            start.elapsed()
+                        );
+                        Ok::<_, anyhow::Error>(())
+                    }
+                })
+                .buffer_unordered(concurrency)
+                .try_collect::<()>()
+                .await?;
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
     }
     Ok(())
 }
@@ -489,8 +736,20 @@ where
                 val: Arc::clone(&val_schema),
             };
 
+            // As in regular compaction, runs written before a schema change
+            // that affects their sort order must be re-sorted while merging.
+            let schema_id = req
+                .inputs
+                .iter()
+                .flat_map(|batch| batch.run_meta.iter())
+                .filter_map(|run_meta| run_meta.schema)
+                .max();
+            let mut compact_cfg = CompactConfig::new(&cfg, shard_id);
+            compact_cfg.resort_runs_before =
+                schema_id.and_then(|id| machine.applier.resort_runs_before(id));
+
             let res = Compactor::<K, V, T, D>::compact(
-                CompactConfig::new(&cfg, shard_id),
+                compact_cfg,
                 Arc::clone(&blob),
                 Arc::clone(&metrics),
                 Arc::clone(&machine.applier.shard_metrics),
@@ -532,6 +791,110 @@ where
     }
 }
 
+/// A leased reader registered on a shard for the duration of a backup, which
+/// holds back the shard's since and the garbage collection of the version of
+/// state being backed up.
+struct BackupReader {
+    machine: Machine<crate::cli::inspect::K, crate::cli::inspect::V, u64, i64>,
+    reader_id: LeasedReaderId,
+    /// The shard's codecs, which must be set as the fake codecs before each
+    /// use of `machine`.
+    codecs: (String, String, String, String, Option<CodecConcreteType>),
+    /// The version of state to back up.
+    seqno: SeqNo,
+    since: Antichain<u64>,
+    upper: Antichain<u64>,
+}
+
+impl BackupReader {
+    async fn register(
+        cfg: &PersistConfig,
+        consensus: Arc<dyn Consensus>,
+        blob: Arc<dyn Blob>,
+        metrics: Arc<Metrics>,
+        shard_id: ShardId,
+        commit: bool,
+        expected_version: Option<Version>,
+    ) -> anyhow::Result<Self> {
+        let machine = make_machine(
+            cfg,
+            consensus,
+            blob,
+            metrics,
+            shard_id,
+            commit,
+            expected_version,
+        )
+        .await?;
+        let codecs = KVTD_CODECS.lock().expect("lockable").clone();
+        let seqno = machine.seqno();
+        let reader_id = LeasedReaderId::new();
+        let (reader_state, _maintenance) = machine
+            .register_leased_reader(
+                &reader_id,
+                "persist-cli backup",
+                READER_LEASE_DURATION.get(cfg),
+                (cfg.now)(),
+                false,
+            )
+            .await;
+        // In a dry run, the registration is never made durable, so back up
+        // the version of state it would have been applied to instead.
+        let seqno = if commit { machine.seqno() } else { seqno };
+        Ok(BackupReader {
+            seqno,
+            upper: machine.applier.clone_upper(),
+            since: reader_state.since,
+            machine,
+            reader_id,
+            codecs,
+        })
+    }
+
+    fn shard_id(&self) -> ShardId {
+        self.machine.shard_id()
+    }
+
+    /// Whether the shard is a tombstone, on which readers are never actually
+    /// registered. There's nothing to hold back on those.
+    fn is_tombstone(&self) -> bool {
+        self.since.is_empty() && self.upper.is_empty()
+    }
+
+    async fn heartbeat_all(cfg: &PersistConfig, readers: &[BackupReader]) -> anyhow::Result<()> {
+        for reader in readers.iter().filter(|x| !x.is_tombstone()) {
+            KVTD_CODECS
+                .lock()
+                .expect("lockable")
+                .clone_from(&reader.codecs);
+            let (_seqno, existed, _maintenance) = reader
+                .machine
+                .heartbeat_leased_reader(&reader.reader_id, (cfg.now)())
+                .await;
+            if !existed {
+                bail!(
+                    "backup reader {} of shard {} expired",
+                    reader.reader_id,
+                    reader.shard_id()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Expires every reader. Best effort: a reader we fail to expire here
+    /// stops holding anything back once its lease runs out.
+    async fn expire_all(readers: Vec<BackupReader>) {
+        for reader in readers.into_iter().filter(|x| !x.is_tombstone()) {
+            KVTD_CODECS
+                .lock()
+                .expect("lockable")
+                .clone_from(&reader.codecs);
+            let _ = reader.machine.expire_leased_reader(&reader.reader_id).await;
+        }
+    }
+}
+
 async fn make_machine(
     cfg: &PersistConfig,
     consensus: Arc<dyn Consensus>,
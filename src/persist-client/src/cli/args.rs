@@ -22,6 +22,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encryption::EncryptedBlob;
 use mz_persist::location::{
     Blob, BlobMetadata, CaSResult, Consensus, ExternalError, ResultStream, SeqNo, Tasked,
     VersionedData,
@@ -157,6 +158,14 @@ pub(super) async fn make_blob(
     )
     .await?;
     let blob = blob.clone().open().await?;
+    let blob = match &cfg.blob_encryption_keyring {
+        Some(keyring) => Arc::new(EncryptedBlob::new(
+            blob,
+            Arc::clone(keyring),
+            Arc::clone(&cfg.configs),
+        )),
+        None => blob,
+    };
     let blob = if commit {
         blob
     } else {
@@ -11,11 +11,15 @@
 
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
 use std::pin::pin;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
 use anyhow::anyhow;
+use arrow::array::{Array, ArrayRef, Int64Array, RecordBatch, UInt64Array};
+use arrow::compute::interleave;
+use arrow::datatypes::{DataType, Field, Schema};
 use bytes::{BufMut, Bytes};
 use differential_dataflow::difference::{IsZero, Semigroup};
 use differential_dataflow::lattice::Lattice;
@@ -26,9 +30,12 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::url::SensitiveUrl;
 use mz_persist::indexed::encoding::BlobTraceBatchPart;
+use mz_persist_types::arrow::ArrayOrd;
 use mz_persist_types::codec_impls::TodoSchema;
+use mz_persist_types::schema::backward_compatible;
 use mz_persist_types::{Codec, Codec64, Opaque};
 use mz_proto::RustType;
+use parquet::arrow::ArrowWriter;
 use prost::Message;
 use serde_json::json;
 
@@ -41,7 +48,7 @@ use crate::internal::encoding::{Rollup, UntypedState};
 use crate::internal::paths::{
     BlobKey, BlobKeyPrefix, PartialBatchKey, PartialBlobKey, PartialRollupKey, WriterKey,
 };
-use crate::internal::state::{BatchPart, ProtoRollup, ProtoStateDiff, State};
+use crate::internal::state::{BatchPart, EncodedSchemas, ProtoRollup, ProtoStateDiff, State};
 use crate::rpc::NoopPubSubSender;
 use crate::usage::{HumanBytes, StorageUsageClient};
 use crate::{Metrics, PersistClient, PersistConfig, ShardId};
@@ -108,6 +115,9 @@ pub(crate) enum Command {
     ///
     #[clap(verbatim_doc_comment)]
     StateDiff(StateArgs),
+
+    /// Writes the contents of a shard as of some timestamp to local Parquet files
+    Export(ExportArgs),
 }
 
 /// Runs the given read-only inspect command.
@@ -168,6 +178,9 @@ pub async fn run(command: InspectArgs) -> Result<(), anyhow::Error> {
         Command::ShardStats(args) => {
             shard_stats(&args.blob_uri).await?;
         }
+        Command::Export(args) => {
+            let () = export(&args).await?;
+        }
     }
 
     Ok(())
@@ -433,6 +446,222 @@ async fn consolidated_size(args: &StateArgs) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+/// Arguments for exporting the contents of a shard to Parquet files.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportArgs {
+    #[clap(flatten)]
+    pub(crate) state: StateArgs,
+
+    /// Timestamp to read the shard as of. Defaults to the greatest readable
+    /// timestamp, i.e. one less than the shard's upper.
+    #[clap(long)]
+    pub(crate) as_of: Option<u64>,
+
+    /// Write every update not beyond the as_of along with its time and diff,
+    /// instead of the consolidated contents of the shard at the as_of.
+    #[clap(long)]
+    pub(crate) raw: bool,
+
+    /// Local directory to write the Parquet files into.
+    #[clap(long)]
+    pub(crate) output_dir: PathBuf,
+
+    /// Maximum number of rows to write to each Parquet file.
+    #[clap(long, default_value_t = 1_000_000)]
+    pub(crate) rows_per_file: usize,
+}
+
+/// Writes the contents of a shard as of some timestamp to Parquet files.
+///
+/// Keys and values are decoded with the most recently registered schema of the
+/// shard, migrating parts written with older schemas as necessary. All parts
+/// are held in memory, so this is intended for offline analysis of
+/// reasonably-sized shards.
+async fn export(args: &ExportArgs) -> Result<(), anyhow::Error> {
+    let shard_id = args.state.shard_id();
+    let state_versions = args.state.open().await?;
+    let versions = state_versions
+        .fetch_recent_live_diffs::<u64>(&shard_id)
+        .await;
+    let state = state_versions
+        .fetch_current_state::<u64>(&shard_id, versions.0.clone())
+        .await;
+    let state = state.check_ts_codec(&shard_id)?;
+    let shard_metrics = state_versions.metrics.shards.shard(&shard_id, "unknown");
+
+    let as_of = match (args.as_of, state.upper().as_option()) {
+        (Some(as_of), _) => as_of,
+        (None, Some(upper)) => upper
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("shard {} has no readable timestamps", shard_id))?,
+        (None, None) => anyhow::bail!("shard {} is finalized, --as-of is required", shard_id),
+    };
+    if !state.since().less_equal(&as_of) {
+        anyhow::bail!(
+            "as_of {} is not beyond the since {:?} of shard {}",
+            as_of,
+            state.since().elements(),
+            shard_id
+        );
+    }
+    if state.upper().less_equal(&as_of) {
+        anyhow::bail!(
+            "as_of {} is not before the upper {:?} of shard {}",
+            as_of,
+            state.upper().elements(),
+            shard_id
+        );
+    }
+    if args.rows_per_file == 0 {
+        anyhow::bail!("--rows-per-file must be positive");
+    }
+
+    let mut parts = Vec::new();
+    for batch in state.collections.trace.batches() {
+        // Every update in a batch whose lower is beyond the as_of is too.
+        if !batch.desc.lower().less_equal(&as_of) {
+            continue;
+        }
+        let mut part_stream =
+            pin!(batch.part_stream(shard_id, &*state_versions.blob, &*state_versions.metrics));
+        while let Some(part) = part_stream.try_next().await? {
+            tracing::info!("fetching {}", part.printable_name());
+            let encoded_part = EncodedPart::fetch(
+                &shard_id,
+                &*state_versions.blob,
+                &state_versions.metrics,
+                &shard_metrics,
+                &state_versions.metrics.read.snapshot,
+                &batch.desc,
+                &part,
+            )
+            .await
+            .map_err(|blob_key| anyhow!("missing blob {}", blob_key))?;
+            let updates = encoded_part.normalize(&state_versions.metrics.columnar);
+            let part = updates.as_part().ok_or_else(|| {
+                anyhow!(
+                    "part {} has no structured data to export",
+                    part.printable_name()
+                )
+            })?;
+            parts.push(part);
+        }
+    }
+
+    // Decode everything with the latest registered schema, falling back to
+    // whatever the parts were written with if the shard has none registered.
+    let (key_type, val_type) = match state.collections.schemas.values().next_back() {
+        Some(schemas) => (
+            EncodedSchemas::decode_data_type(&schemas.key_data_type),
+            EncodedSchemas::decode_data_type(&schemas.val_data_type),
+        ),
+        None => match parts.first() {
+            Some(part) => (part.key.data_type().clone(), part.val.data_type().clone()),
+            None => (DataType::Null, DataType::Null),
+        },
+    };
+    let migrate = |array: ArrayRef, data_type: &DataType| -> Result<ArrayRef, anyhow::Error> {
+        if array.data_type() == data_type {
+            return Ok(array);
+        }
+        let migration = backward_compatible(array.data_type(), data_type).ok_or_else(|| {
+            anyhow!(
+                "cannot migrate {:?} to registered schema {:?}",
+                array.data_type(),
+                data_type
+            )
+        })?;
+        Ok(migration.migrate(array))
+    };
+
+    let mut keys = Vec::with_capacity(parts.len());
+    let mut vals = Vec::with_capacity(parts.len());
+    // (part, row) index into keys and vals, along with the time and diff.
+    let mut updates = Vec::new();
+    for (part_idx, part) in parts.into_iter().enumerate() {
+        for row_idx in 0..part.len() {
+            let time = <u64 as Codec64>::decode(part.time.value(row_idx).to_le_bytes());
+            if time <= as_of {
+                updates.push(((part_idx, row_idx), time, part.diff.value(row_idx)));
+            }
+        }
+        keys.push(migrate(part.key, &key_type)?);
+        vals.push(migrate(part.val, &val_type)?);
+    }
+
+    if !args.raw {
+        let key_ords: Vec<_> = keys.iter().map(|k| ArrayOrd::new(k.as_ref())).collect();
+        let val_ords: Vec<_> = vals.iter().map(|v| ArrayOrd::new(v.as_ref())).collect();
+        let kv = |(part, row): (usize, usize)| (key_ords[part].at(row), val_ords[part].at(row));
+        updates.sort_by(|(a, _, _), (b, _, _)| kv(*a).cmp(&kv(*b)));
+        let mut consolidated: Vec<((usize, usize), u64, i64)> = Vec::new();
+        for (idx, _, diff) in updates {
+            match consolidated.last_mut() {
+                Some((prev, _, prev_diff)) if kv(*prev) == kv(idx) => *prev_diff += diff,
+                _ => {
+                    // The previous entry is final, so drop it if it cancelled out.
+                    if consolidated.last().is_some_and(|(_, _, d)| *d == 0) {
+                        consolidated.pop();
+                    }
+                    consolidated.push((idx, as_of, diff));
+                }
+            }
+        }
+        if consolidated.last().is_some_and(|(_, _, d)| *d == 0) {
+            consolidated.pop();
+        }
+        updates = consolidated;
+    }
+
+    let mut fields = vec![
+        Field::new("key", key_type, true),
+        Field::new("val", val_type, true),
+    ];
+    if args.raw {
+        fields.push(Field::new("time", DataType::UInt64, false));
+    }
+    fields.push(Field::new("diff", DataType::Int64, false));
+    let schema = Arc::new(Schema::new(fields));
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let keys: Vec<&dyn Array> = keys.iter().map(|k| k.as_ref()).collect();
+    let vals: Vec<&dyn Array> = vals.iter().map(|v| v.as_ref()).collect();
+    // Always write at least one file, so the schema is available even if the
+    // shard is empty at the as_of.
+    let mut chunks: Vec<_> = updates.chunks(args.rows_per_file).collect();
+    if chunks.is_empty() {
+        chunks.push(&[]);
+    }
+    for (file_idx, chunk) in chunks.iter().enumerate() {
+        let indices: Vec<_> = chunk.iter().map(|(idx, _, _)| *idx).collect();
+        let mut columns = vec![interleave(&keys, &indices)?, interleave(&vals, &indices)?];
+        if args.raw {
+            let times = UInt64Array::from_iter_values(chunk.iter().map(|(_, t, _)| *t));
+            columns.push(Arc::new(times));
+        }
+        let diffs = Int64Array::from_iter_values(chunk.iter().map(|(_, _, d)| *d));
+        columns.push(Arc::new(diffs));
+        let batch = RecordBatch::try_new(Arc::clone(&schema), columns)?;
+
+        let path = args
+            .output_dir
+            .join(format!("part-{:05}.parquet", file_idx));
+        let file = std::fs::File::create(&path)?;
+        let mut writer = ArrowWriter::try_new(file, Arc::clone(&schema), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+    }
+    println!(
+        "wrote {} updates as of {} to {} files in {}",
+        updates.len(),
+        as_of,
+        chunks.len(),
+        args.output_dir.display()
+    );
+
+    Ok(())
+}
+
 /// Arguments for commands that run only against the blob store.
 #[derive(Debug, Clone, clap::Parser)]
 pub struct BlobArgs {
@@ -844,3 +1073,122 @@ impl Opaque for O {
         Self([0; 8])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::AsArray;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use crate::PersistLocation;
+    use crate::cache::PersistClientCache;
+
+    use super::*;
+
+    /// Reads the `part-*.parquet` files in `dir` back as (key, val, time,
+    /// diff) tuples, sorted. The time is `None` if the files have no time
+    /// column.
+    fn read_export(dir: &std::path::Path) -> Vec<(String, String, Option<u64>, i64)> {
+        let mut rows = Vec::new();
+        for entry in std::fs::read_dir(dir).expect("valid dir") {
+            let file = std::fs::File::open(entry.expect("valid entry").path()).expect("file");
+            let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+                .expect("valid parquet")
+                .build()
+                .expect("valid parquet");
+            for batch in reader {
+                let batch: RecordBatch = batch.expect("valid batch");
+                let keys = batch.column_by_name("key").expect("key").as_string::<i32>();
+                let vals = batch.column_by_name("val").expect("val").as_string::<i32>();
+                let times = batch
+                    .column_by_name("time")
+                    .map(|times| times.as_primitive::<arrow::datatypes::UInt64Type>());
+                let diffs = batch
+                    .column_by_name("diff")
+                    .expect("diff")
+                    .as_primitive::<arrow::datatypes::Int64Type>();
+                for idx in 0..batch.num_rows() {
+                    rows.push((
+                        keys.value(idx).to_owned(),
+                        vals.value(idx).to_owned(),
+                        times.map(|times| times.value(idx)),
+                        diffs.value(idx),
+                    ));
+                }
+            }
+        }
+        rows.sort();
+        rows
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock`
+    async fn export_raw_and_consolidated() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let url = |name: &str| {
+            SensitiveUrl::from_str(&format!("file://{}", dir.path().join(name).display()))
+                .expect("valid url")
+        };
+        let location = PersistLocation {
+            blob_uri: url("blob"),
+            consensus_uri: url("consensus"),
+        };
+        let client = PersistClientCache::new_no_metrics()
+            .open(location.clone())
+            .await
+            .expect("client");
+
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        let update = |k: &str, v: &str, t: u64, d: i64| ((k.to_owned(), v.to_owned()), t, d);
+        write
+            .expect_compare_and_append(&[update("a", "x", 0, 1), update("b", "y", 0, 1)], 0, 2)
+            .await;
+        write
+            .expect_compare_and_append(&[update("a", "x", 2, 1), update("b", "y", 2, -1)], 2, 3)
+            .await;
+        write
+            .expect_compare_and_append(&[update("c", "z", 3, 1)], 3, 4)
+            .await;
+
+        let export = |as_of: Option<u64>, raw: bool, output_dir: PathBuf| ExportArgs {
+            state: StateArgs {
+                shard_id: shard_id.to_string(),
+                consensus_uri: location.consensus_uri.clone(),
+                blob_uri: location.blob_uri.clone(),
+            },
+            as_of,
+            raw,
+            output_dir,
+            rows_per_file: 2,
+        };
+        let row = |k: &str, v: &str, t: Option<u64>, d: i64| (k.to_owned(), v.to_owned(), t, d);
+
+        // Raw mode writes every update not beyond the as_of.
+        let raw_dir = dir.path().join("raw");
+        super::export(&export(Some(2), true, raw_dir.clone()))
+            .await
+            .expect("export");
+        assert_eq!(
+            read_export(&raw_dir),
+            vec![
+                row("a", "x", Some(0), 1),
+                row("a", "x", Some(2), 1),
+                row("b", "y", Some(0), 1),
+                row("b", "y", Some(2), -1),
+            ]
+        );
+
+        // Consolidated mode sums the diffs of each key and value and drops
+        // those that cancel out. The as_of defaults to one less than the upper.
+        let consolidated_dir = dir.path().join("consolidated");
+        super::export(&export(None, false, consolidated_dir.clone()))
+            .await
+            .expect("export");
+        assert_eq!(
+            read_export(&consolidated_dir),
+            vec![row("a", "x", None, 2), row("c", "z", None, 1)]
+        );
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A standalone service that performs compaction on behalf of other persist
+//! clients.
+//!
+//! Writers normally compact their shards in-process. When
+//! `persist_compaction_service_enabled` is set, they instead send each
+//! compaction request to the Persist PubSub server, which forwards it to one
+//! of the services registered with it (see [PubSubSender::register_compactor]).
+//! The service then performs the compaction and applies the result to the
+//! shard's state, exactly as the writer would have, and acks the request. If
+//! the service can't perform a request, it nacks it instead, and the writer
+//! performs the compaction in-process.
+//!
+//! Each request carries the writer's dyncfgs, which the service applies to its
+//! own configuration before performing the request, so that it compacts just
+//! as the writer would have. Configs set with [CompactionService::override_configs]
+//! take precedence.
+//!
+//! [PubSubSender::register_compactor]: crate::rpc::PubSubSender::register_compactor
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use differential_dataflow::difference::Semigroup;
+use differential_dataflow::lattice::Lattice;
+use futures_util::StreamExt;
+use mz_dyncfg::ConfigUpdates;
+use mz_ore::error::ErrorExt;
+use mz_persist_types::schema::SchemaId;
+use mz_persist_types::{Codec, Codec64};
+use mz_proto::{IntoRustIfSome, ProtoType, RustType};
+use timely::progress::Timestamp;
+use tokio::sync::Semaphore;
+use tracing::{debug, info, warn};
+
+use crate::internal::compact::{CompactReq, Compactor};
+use crate::internal::encoding::Schemas;
+use crate::internal::gc::GarbageCollector;
+use crate::internal::service::{
+    ProtoCompactionAck, ProtoCompactionNack, ProtoCompactionRequest, ProtoCompactionResponse,
+    proto_compaction_response, proto_pub_sub_message,
+};
+use crate::internal::trace::ApplyMergeResult;
+use crate::rpc::PubSubClientConnection;
+use crate::{Diagnostics, PersistClient};
+
+/// The codecs of a shard, which determine how to compact it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct CodecNames {
+    key: String,
+    val: String,
+    ts: String,
+    diff: String,
+}
+
+/// Performs compaction requests forwarded by the Persist PubSub server.
+#[derive(Debug)]
+pub struct CompactionService {
+    client: PersistClient,
+    handlers: BTreeMap<CodecNames, Arc<dyn CompactionHandler>>,
+    concurrency_limit: Arc<Semaphore>,
+    config_overrides: ConfigUpdates,
+}
+
+impl CompactionService {
+    /// Returns a new [CompactionService] that compacts using the given client,
+    /// performing at most `concurrency_limit` compactions at a time.
+    ///
+    /// The memory used by each compaction is bounded by the client's
+    /// `persist_compaction_memory_bound_bytes`.
+    pub fn new(client: PersistClient, concurrency_limit: usize) -> Self {
+        CompactionService {
+            client,
+            handlers: BTreeMap::new(),
+            concurrency_limit: Arc::new(Semaphore::new(concurrency_limit)),
+            config_overrides: ConfigUpdates::default(),
+        }
+    }
+
+    /// Sets configs that take precedence over the dyncfgs of requests.
+    pub fn override_configs(&mut self, overrides: ConfigUpdates) {
+        self.client.cfg.apply_from(&overrides);
+        self.config_overrides = overrides;
+    }
+
+    /// Registers a combination of codecs this service is able to compact.
+    ///
+    /// Requests for shards with any other codecs are nacked.
+    pub fn register<K, V, T, D>(&mut self)
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64 + Sync,
+        D: Semigroup + Ord + Codec64 + Send + Sync,
+    {
+        let names = CodecNames {
+            key: K::codec_name(),
+            val: V::codec_name(),
+            ts: T::codec_name(),
+            diff: D::codec_name(),
+        };
+        let handler = TypedCompactionHandler::<K, V, T, D>(PhantomData);
+        self.handlers.insert(names, Arc::new(handler));
+    }
+
+    /// Registers as a compaction service over the given connection and
+    /// performs the requests forwarded to it, until the connection is closed.
+    pub async fn run(self, connection: PubSubClientConnection) {
+        let PubSubClientConnection {
+            sender,
+            mut receiver,
+        } = connection;
+        sender.register_compactor();
+        info!(
+            "compaction service registered for codecs: {:?}",
+            self.handlers.keys().collect::<Vec<_>>()
+        );
+
+        while let Some(msg) = receiver.next().await {
+            let mut req = match msg.message {
+                Some(proto_pub_sub_message::Message::Compact(req)) => req,
+                msg => {
+                    debug!("compaction service ignoring message: {:?}", msg);
+                    continue;
+                }
+            };
+            let names = CodecNames {
+                key: req.key_codec.clone(),
+                val: req.val_codec.clone(),
+                ts: req.ts_codec.clone(),
+                diff: req.diff_codec.clone(),
+            };
+            let request_id = req.request_id;
+            let Some(handler) = self.handlers.get(&names) else {
+                self.client.metrics.compaction.service_unknown_codecs.inc();
+                warn!(
+                    "compaction service nacking request with unknown codecs: {:?}",
+                    names
+                );
+                sender.respond_compaction(nack(request_id, format!("unknown codecs: {:?}", names)));
+                continue;
+            };
+
+            // Compactions that are already running may observe these too, but
+            // all requests come from the same environment.
+            let mut dyncfgs = req.dyncfgs.take().unwrap_or_default();
+            dyncfgs.extend(self.config_overrides.clone());
+            self.client.cfg.apply_from(&dyncfgs);
+
+            // Waiting here (instead of queueing) pushes back on the server,
+            // which nacks requests once our connection's channel is full.
+            let permit = Arc::clone(&self.concurrency_limit)
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let handler = Arc::clone(handler);
+            let client = self.client.clone();
+            let sender = Arc::clone(&sender);
+            mz_ore::task::spawn(|| "persist::compaction_service::compact", async move {
+                let resp = match handler.compact(&client, req).await {
+                    Ok(res) => ProtoCompactionResponse {
+                        request_id,
+                        response: Some(proto_compaction_response::Response::Ack(
+                            ProtoCompactionAck {
+                                result: res.into_proto().into(),
+                            },
+                        )),
+                    },
+                    Err(err) => {
+                        warn!(
+                            "compaction service request failed: {}",
+                            err.display_with_causes()
+                        );
+                        nack(request_id, err.to_string_with_causes())
+                    }
+                };
+                sender.respond_compaction(resp);
+                drop(permit);
+            });
+        }
+
+        info!("compaction service connection closed");
+    }
+}
+
+fn nack(request_id: u64, reason: String) -> ProtoCompactionResponse {
+    ProtoCompactionResponse {
+        request_id,
+        response: Some(proto_compaction_response::Response::Nack(
+            ProtoCompactionNack { reason },
+        )),
+    }
+}
+
+#[async_trait]
+trait CompactionHandler: Debug + Send + Sync {
+    async fn compact(
+        &self,
+        client: &PersistClient,
+        req: ProtoCompactionRequest,
+    ) -> Result<ApplyMergeResult, anyhow::Error>;
+}
+
+#[derive(Debug)]
+struct TypedCompactionHandler<K, V, T, D>(PhantomData<fn() -> (K, V, T, D)>);
+
+#[async_trait]
+impl<K, V, T, D> CompactionHandler for TypedCompactionHandler<K, V, T, D>
+where
+    K: Debug + Codec,
+    V: Debug + Codec,
+    T: Timestamp + Lattice + Codec64 + Sync,
+    D: Semigroup + Ord + Codec64 + Send + Sync,
+{
+    async fn compact(
+        &self,
+        client: &PersistClient,
+        req: ProtoCompactionRequest,
+    ) -> Result<ApplyMergeResult, anyhow::Error> {
+        let schema_id: Option<SchemaId> = req.schema_id.into_rust()?;
+        let req: CompactReq<T> = req.req.into_rust_if_some("ProtoCompactionRequest::req")?;
+        let machine = client
+            .make_machine::<K, V, T, D>(req.shard_id, Diagnostics::from_purpose("compaction"))
+            .await
+            .map_err(|err| anyhow!("{}", err))?;
+
+        // This is only the fallback: by default, compaction uses the most
+        // recent schema of its inputs.
+        let write_schema =
+            schema_id.and_then(|id| machine.get_schema(id).map(|(key, val)| (id, key, val)));
+        let (id, key, val) = match write_schema {
+            Some(schema) => schema,
+            None => machine
+                .latest_schema()
+                .ok_or_else(|| anyhow!("no registered schema for {}", machine.shard_id()))?,
+        };
+        let schemas = Schemas {
+            id: Some(id),
+            key: Arc::new(key),
+            val: Arc::new(val),
+        };
+
+        let (res, maintenance) = Compactor::compact_and_apply(&machine, req, schemas).await?;
+        client.metrics.compaction.service_count.inc();
+
+        let gc = GarbageCollector::new(machine.clone(), Arc::clone(&client.isolated_runtime));
+        maintenance.start_performing(&machine, &gc);
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use differential_dataflow::trace::Description;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_ore::url::SensitiveUrl;
+    use timely::progress::Antichain;
+
+    use crate::batch::BLOB_TARGET_SIZE;
+    use crate::cache::PersistClientCache;
+    use crate::cfg::{COMPACTION_MEMORY_BOUND_BYTES, PersistConfig};
+    use crate::rpc::PersistGrpcPubSubServer;
+    use crate::{PersistLocation, ShardId};
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock`
+    async fn compaction_service_uses_requester_dyncfgs() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let url = |name: &str| {
+            SensitiveUrl::from_str(&format!("file://{}", dir.path().join(name).display()))
+                .expect("valid url")
+        };
+        let location = PersistLocation {
+            blob_uri: url("blob"),
+            consensus_uri: url("consensus"),
+        };
+
+        // The writer runs with a non-default blob target size, which splits
+        // the output of compaction into a part per update.
+        let mut writer_cache = PersistClientCache::new_no_metrics();
+        writer_cache.cfg.compaction_enabled = false;
+        writer_cache.cfg.set_config(&BLOB_TARGET_SIZE, 10);
+        let writer_client = writer_cache.open(location.clone()).await.expect("client");
+        let shard_id = ShardId::new();
+        let (mut write, _read) = writer_client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        for t in 0..3 {
+            let update = ((format!("k{t}"), "v".repeat(100)), t, 1);
+            write.expect_compare_and_append(&[update], t, t + 1).await;
+        }
+        let machine = write.machine.clone();
+        let inputs = machine.applier.all_batches();
+        assert_eq!(inputs.len(), 3);
+        let req = CompactReq {
+            shard_id,
+            desc: Description::new(
+                Antichain::from_elem(0),
+                Antichain::from_elem(3),
+                Antichain::from_elem(0),
+            ),
+            inputs,
+        };
+
+        // The service runs with the defaults, plus an override of its own.
+        let server =
+            PersistGrpcPubSubServer::new(&PersistConfig::new_for_tests(), &MetricsRegistry::new());
+        let compactor_client = PersistClientCache::new_no_metrics()
+            .open(location)
+            .await
+            .expect("client");
+        let compactor_cfg = compactor_client.cfg.clone();
+        let mut service = CompactionService::new(compactor_client, 1);
+        service.register::<String, String, u64, i64>();
+        let mut overrides = ConfigUpdates::default();
+        overrides.add(&COMPACTION_MEMORY_BOUND_BYTES, 64 * 1024 * 1024);
+        service.override_configs(overrides);
+        mz_ore::task::spawn(
+            || "compaction_service",
+            service.run(server.new_same_process_connection()),
+        );
+
+        // Responses are delivered while the writer's receiver is polled.
+        let PubSubClientConnection { sender, receiver } = server.new_same_process_connection();
+        mz_ore::task::spawn(|| "writer_receiver", receiver.for_each(|_| async {}));
+        let result = loop {
+            let resp = sender
+                .compact(ProtoCompactionRequest {
+                    req: Some(req.into_proto()),
+                    key_codec: String::codec_name(),
+                    val_codec: String::codec_name(),
+                    ts_codec: u64::codec_name(),
+                    diff_codec: i64::codec_name(),
+                    schema_id: None,
+                    request_id: 0,
+                    dyncfgs: Some(writer_client.cfg.config_updates()),
+                })
+                .await
+                .expect("response");
+            match resp {
+                ProtoCompactionResponse {
+                    response: Some(proto_compaction_response::Response::Ack(ack)),
+                    ..
+                } => break ack.result(),
+                // The service may not have registered yet.
+                _ => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+        assert!(matches!(
+            ApplyMergeResult::from_proto(result),
+            Ok(ApplyMergeResult::AppliedExact)
+        ));
+
+        // The service compacted with the writer's configs, except where it
+        // overrode them.
+        assert_eq!(BLOB_TARGET_SIZE.get(&compactor_cfg), 10);
+        assert_eq!(
+            COMPACTION_MEMORY_BOUND_BYTES.get(&compactor_cfg),
+            64 * 1024 * 1024
+        );
+        machine.applier.fetch_and_update_state(None).await;
+        let outputs = machine.applier.all_batches();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].part_count(), 3);
+    }
+}
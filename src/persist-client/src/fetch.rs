@@ -11,6 +11,7 @@
 
 use std::fmt::{self, Debug};
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -26,6 +27,7 @@ use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use mz_ore::{soft_panic_no_log, soft_panic_or_log};
 use mz_persist::indexed::columnar::arrow::{realloc_any, realloc_array};
+use mz_persist::indexed::columnar::parquet::{PartProjection, PartialFile, leaf_byte_ranges};
 use mz_persist::indexed::columnar::{ColumnarRecords, ColumnarRecordsStructuredExt};
 use mz_persist::indexed::encoding::{BlobTraceBatchPart, BlobTraceUpdates};
 use mz_persist::location::{Blob, SeqNo};
@@ -37,6 +39,7 @@ use mz_persist_types::schema::backward_compatible;
 use mz_persist_types::stats::PartStats;
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::RustType;
+use parquet::file::reader::ChunkReader;
 use serde::{Deserialize, Serialize};
 use timely::PartialOrder;
 use timely::progress::frontier::AntichainRef;
@@ -85,6 +88,31 @@ pub(crate) const PART_DECODE_FORMAT: Config<&'static str> = Config::new(
     'row_with_validate', or 'arrow' (Materialize).",
 );
 
+pub const PART_PROJECTION_PUSHDOWN: Config<bool> = Config::new(
+    "persist_part_projection_pushdown",
+    true,
+    "\
+    Whether to skip decoding the columns of a Persist Part that the reader's \
+    schema doesn't need (Materialize).",
+);
+
+pub(crate) const PART_PROJECTION_RANGE_READS: Config<bool> = Config::new(
+    "persist_part_projection_range_reads",
+    false,
+    "\
+    Whether to fetch only the byte ranges of a Persist Part that contain the \
+    columns the reader's schema needs, instead of the entire blob. Ignored \
+    when blobs are encrypted (Materialize).",
+);
+
+pub(crate) const PART_PROJECTION_RANGE_READS_MIN_BYTES: Config<usize> = Config::new(
+    "persist_part_projection_range_reads_min_bytes",
+    8 * 1024 * 1024,
+    "\
+    The minimum encoded size of a Persist Part for it to be fetched with range \
+    reads, see persist_part_projection_range_reads (Materialize).",
+);
+
 pub(crate) const OPTIMIZE_IGNORED_DATA_FETCH: Config<bool> = Config::new(
     "persist_optimize_ignored_data_fetch",
     true,
@@ -94,12 +122,23 @@ pub(crate) const OPTIMIZE_IGNORED_DATA_FETCH: Config<bool> = Config::new(
 #[derive(Debug, Clone)]
 pub(crate) struct BatchFetcherConfig {
     pub(crate) part_decode_format: ConfigValHandle<String>,
+    pub(crate) part_projection_pushdown: ConfigValHandle<bool>,
+    pub(crate) part_projection_range_reads: ConfigValHandle<bool>,
+    pub(crate) part_projection_range_reads_min_bytes: ConfigValHandle<usize>,
+    /// Whether blobs are encrypted, in which case every range read has to
+    /// fetch and decrypt the entire blob.
+    pub(crate) blob_encrypted: bool,
 }
 
 impl BatchFetcherConfig {
     pub fn new(value: &PersistConfig) -> Self {
         BatchFetcherConfig {
             part_decode_format: PART_DECODE_FORMAT.handle(value),
+            part_projection_pushdown: PART_PROJECTION_PUSHDOWN.handle(value),
+            part_projection_range_reads: PART_PROJECTION_RANGE_READS.handle(value),
+            part_projection_range_reads_min_bytes: PART_PROJECTION_RANGE_READS_MIN_BYTES
+                .handle(value),
+            blob_encrypted: value.blob_encryption_keyring.is_some(),
         }
     }
 
@@ -125,6 +164,8 @@ where
     pub(crate) shard_id: ShardId,
     pub(crate) read_schemas: Schemas<K, V>,
     pub(crate) schema_cache: SchemaCache<K, V, T, D>,
+    /// The columns of the structured key and val needed by `read_schemas`.
+    pub(crate) projection: Option<Arc<PartProjection>>,
     pub(crate) is_transient: bool,
 
     // Ensures that `BatchFetcher` is of the same type as the `ReadHandle` it's
@@ -174,6 +215,18 @@ where
                     )
                 });
 
+        // Columns are only skipped when decoding to arrow, because a migration
+        // is what drops them.
+        let projection = match &migration {
+            PartMigration::Either { .. }
+                if self.cfg.part_projection_pushdown.get()
+                    && matches!(self.cfg.part_decode_format(), PartDecodeFormat::Arrow) =>
+            {
+                self.projection.clone()
+            }
+            _ => None,
+        };
+
         let (buf, fetch_permit) = match &part {
             BatchPart::Hollow(x) => {
                 let fetch_permit = self
@@ -186,22 +239,51 @@ where
                 } else {
                     &self.metrics.read.batch_fetcher
                 };
-                let buf = fetch_batch_part_blob(
-                    &shard_id,
-                    self.blob.as_ref(),
-                    &self.metrics,
-                    &self.shard_metrics,
-                    read_metrics,
-                    x,
-                )
-                .await;
-                let buf = match buf {
-                    Ok(buf) => buf,
-                    Err(key) => return Ok(Err(key)),
+                let ranged = match &projection {
+                    Some(projection)
+                        if self.cfg.part_projection_range_reads.get()
+                            && !self.cfg.blob_encrypted
+                            && x.encoded_size_bytes
+                                >= self.cfg.part_projection_range_reads_min_bytes.get() =>
+                    {
+                        fetch_batch_part_blob_ranges(
+                            &shard_id,
+                            self.blob.as_ref(),
+                            &self.metrics,
+                            &self.shard_metrics,
+                            read_metrics,
+                            x,
+                            projection,
+                        )
+                        .await
+                    }
+                    _ => Ok(None),
                 };
-                let buf = FetchedBlobBuf::Hollow {
-                    buf,
-                    part: x.clone(),
+                let buf = match ranged {
+                    Ok(Some(buf)) => FetchedBlobBuf::Ranged {
+                        buf,
+                        part: x.clone(),
+                    },
+                    Ok(None) => {
+                        let buf = fetch_batch_part_blob(
+                            &shard_id,
+                            self.blob.as_ref(),
+                            &self.metrics,
+                            &self.shard_metrics,
+                            read_metrics,
+                            x,
+                        )
+                        .await;
+                        let buf = match buf {
+                            Ok(buf) => buf,
+                            Err(key) => return Ok(Err(key)),
+                        };
+                        FetchedBlobBuf::Hollow {
+                            buf,
+                            part: x.clone(),
+                        }
+                    }
+                    Err(key) => return Ok(Err(key)),
                 };
                 (buf, Some(Arc::new(fetch_permit)))
             }
@@ -224,6 +306,7 @@ where
             buf,
             registered_desc: desc.clone(),
             migration,
+            projection,
             filter: filter.clone(),
             filter_pushdown_audit,
             structured_part_audit: self.cfg.part_decode_format(),
@@ -376,6 +459,88 @@ pub(crate) async fn fetch_batch_part_blob<T>(
     Ok(value)
 }
 
+/// Fetches only the byte ranges of a part's blob that are needed to decode it
+/// with `projection`.
+///
+/// Returns None if the part can't be projected, in which case the entire blob
+/// should be fetched instead.
+pub(crate) async fn fetch_batch_part_blob_ranges<T>(
+    shard_id: &ShardId,
+    blob: &dyn Blob,
+    metrics: &Metrics,
+    shard_metrics: &ShardMetrics,
+    read_metrics: &ReadMetrics,
+    part: &HollowBatchPart<T>,
+    projection: &PartProjection,
+) -> Result<Option<PartialFile>, BlobKey> {
+    // Large enough to usually include all of the file metadata, so that it
+    // only takes one request to find out which ranges we need.
+    const TAIL_BYTES: usize = 64 * 1024;
+
+    let now = Instant::now();
+    let get_span = debug_span!("fetch_batch::get_range");
+    let blob_key = part.key.complete(shard_id);
+    let len = part.encoded_size_bytes;
+    let get_range = |range: Range<usize>| {
+        let blob_key = &blob_key;
+        retry_external(&metrics.retries.external.fetch_batch_get_range, move || {
+            shard_metrics.blob_gets.inc();
+            blob.get_range(blob_key, range.clone())
+        })
+        .instrument(get_span.clone())
+    };
+
+    let mut file = PartialFile::new(len);
+    let tail_start = len.saturating_sub(TAIL_BYTES);
+    let tail = get_range(tail_start..len).await.ok_or(blob_key.clone())?;
+    let mut fetched = tail.len();
+    file.insert(tail_start, tail);
+    let metadata_range = match file.metadata_range() {
+        Ok(range) => range,
+        Err(err) => {
+            soft_panic_or_log!("invalid footer for {}: {}", blob_key, err);
+            return Ok(None);
+        }
+    };
+    if !file.contains(&metadata_range) {
+        let buf = get_range(metadata_range.start..len)
+            .await
+            .ok_or(blob_key.clone())?;
+        fetched += buf.len();
+        file.insert(metadata_range.start, buf);
+    }
+    let metadata = match file.metadata() {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            soft_panic_or_log!("invalid metadata for {}: {}", blob_key, err);
+            return Ok(None);
+        }
+    };
+    let Some(leaves) = projection.leaves(&metadata) else {
+        return Ok(None);
+    };
+
+    let ranges = leaf_byte_ranges(&metadata, &leaves);
+    let bufs = futures::future::join_all(ranges.iter().map(|range| get_range(range.clone()))).await;
+    for (range, buf) in ranges.into_iter().zip(bufs) {
+        let buf = buf.ok_or(blob_key.clone())?;
+        fetched += buf.len();
+        file.insert(range.start, buf);
+    }
+    drop(get_span);
+
+    read_metrics.part_count.inc();
+    read_metrics.part_bytes.inc_by(u64::cast_from(fetched));
+    read_metrics.seconds.inc_by(now.elapsed().as_secs_f64());
+    metrics.pushdown.parts_projection_range_read_count.inc();
+    metrics
+        .pushdown
+        .parts_projection_skipped_bytes
+        .inc_by(u64::cast_from(len.saturating_sub(fetched)));
+
+    Ok(Some(file))
+}
+
 pub(crate) fn decode_batch_part_blob<T>(
     metrics: &Metrics,
     read_metrics: &ReadMetrics,
@@ -385,12 +550,39 @@ pub(crate) fn decode_batch_part_blob<T>(
 ) -> EncodedPart<T>
 where
     T: Timestamp + Lattice + Codec64,
+{
+    let (part, _projected) = decode_batch_part_blob_projected(
+        metrics,
+        read_metrics,
+        registered_desc,
+        part,
+        buf.clone(),
+        None,
+    );
+    part
+}
+
+/// [decode_batch_part_blob], but only decoding the columns needed by
+/// `projection`.
+///
+/// Returns whether the part was projected.
+pub(crate) fn decode_batch_part_blob_projected<T, R>(
+    metrics: &Metrics,
+    read_metrics: &ReadMetrics,
+    registered_desc: Description<T>,
+    part: &HollowBatchPart<T>,
+    buf: R,
+    projection: Option<&PartProjection>,
+) -> (EncodedPart<T>, bool)
+where
+    T: Timestamp + Lattice + Codec64,
+    R: ChunkReader + 'static,
 {
     trace_span!("fetch_batch::decode").in_scope(|| {
-        let parsed = metrics
+        let (parsed, projected) = metrics
             .codecs
             .batch
-            .decode(|| BlobTraceBatchPart::decode(buf, &metrics.columnar))
+            .decode(|| BlobTraceBatchPart::decode_projected(buf, projection, &metrics.columnar))
             .map_err(|err| anyhow!("couldn't decode batch at key {}: {}", part.key, err))
             // We received a State that we couldn't decode. This could happen if
             // persist messes up backward/forward compatibility, if the durable
@@ -400,7 +592,11 @@ where
         read_metrics
             .part_goodbytes
             .inc_by(u64::cast_from(parsed.updates.goodbytes()));
-        EncodedPart::from_hollow(read_metrics.clone(), registered_desc, part, parsed)
+        if projected {
+            metrics.pushdown.parts_projection_count.inc();
+        }
+        let part = EncodedPart::from_hollow(read_metrics.clone(), registered_desc, part, parsed);
+        (part, projected)
     })
 }
 
@@ -627,6 +823,7 @@ pub struct FetchedBlob<K: Codec, V: Codec, T, D> {
     buf: FetchedBlobBuf<T>,
     registered_desc: Description<T>,
     migration: PartMigration<K, V>,
+    projection: Option<Arc<PartProjection>>,
     filter: FetchBatchFilter<T>,
     filter_pushdown_audit: bool,
     structured_part_audit: PartDecodeFormat,
@@ -640,6 +837,12 @@ enum FetchedBlobBuf<T> {
         buf: SegmentedBytes,
         part: HollowBatchPart<T>,
     },
+    /// Only the byte ranges of the blob needed to decode the projected
+    /// columns.
+    Ranged {
+        buf: PartialFile,
+        part: HollowBatchPart<T>,
+    },
     Inline {
         desc: Description<T>,
         updates: LazyInlineBatchPart,
@@ -655,6 +858,7 @@ impl<K: Codec, V: Codec, T: Clone, D> Clone for FetchedBlob<K, V, T, D> {
             buf: self.buf.clone(),
             registered_desc: self.registered_desc.clone(),
             migration: self.migration.clone(),
+            projection: self.projection.clone(),
             filter: self.filter.clone(),
             filter_pushdown_audit: self.filter_pushdown_audit.clone(),
             fetch_permit: self.fetch_permit.clone(),
@@ -691,16 +895,32 @@ where
 impl<K: Codec, V: Codec, T: Timestamp + Lattice + Codec64, D> FetchedBlob<K, V, T, D> {
     /// Partially decodes this blob into a [FetchedPart].
     pub fn parse(&self) -> ShardSourcePart<K, V, T, D> {
-        let (part, stats) = match &self.buf {
+        let (part, projected, stats) = match &self.buf {
             FetchedBlobBuf::Hollow { buf, part } => {
-                let parsed = decode_batch_part_blob(
+                let (parsed, projected) = decode_batch_part_blob_projected(
                     &self.metrics,
                     &self.read_metrics,
                     self.registered_desc.clone(),
                     part,
-                    buf,
+                    buf.clone(),
+                    self.projection.as_deref(),
+                );
+                (parsed, projected, part.stats.as_ref())
+            }
+            FetchedBlobBuf::Ranged { buf, part } => {
+                let (parsed, projected) = decode_batch_part_blob_projected(
+                    &self.metrics,
+                    &self.read_metrics,
+                    self.registered_desc.clone(),
+                    part,
+                    buf.clone(),
+                    self.projection.as_deref(),
+                );
+                assert!(
+                    projected,
+                    "ranged fetches are only used for projected parts"
                 );
-                (parsed, part.stats.as_ref())
+                (parsed, projected, part.stats.as_ref())
             }
             FetchedBlobBuf::Inline {
                 desc,
@@ -714,13 +934,45 @@ impl<K: Codec, V: Codec, T: Timestamp + Lattice + Codec64, D> FetchedBlob<K, V,
                     updates,
                     ts_rewrite.as_ref(),
                 );
-                (parsed, None)
+                (parsed, false, None)
             }
         };
+        let migration = match (&self.migration, &self.projection) {
+            (
+                PartMigration::Either {
+                    write,
+                    read,
+                    key_migration: _,
+                    val_migration: _,
+                },
+                Some(projection),
+            ) if projected => {
+                // The projected part is missing some of the fields that the
+                // write schema has, so migrate from what was actually decoded.
+                let structured = part
+                    .part
+                    .updates
+                    .structured()
+                    .expect("projected parts are structured");
+                let key_migration =
+                    backward_compatible(structured.key.data_type(), &projection.key)
+                        .expect("projected key should be compatible with the read schema");
+                let val_migration =
+                    backward_compatible(structured.val.data_type(), &projection.val)
+                        .expect("projected val should be compatible with the read schema");
+                PartMigration::Either {
+                    write: write.clone(),
+                    read: read.clone(),
+                    key_migration: Arc::new(key_migration),
+                    val_migration: Arc::new(val_migration),
+                }
+            }
+            (migration, _) => migration.clone(),
+        };
         let part = FetchedPart::new(
             Arc::clone(&self.metrics),
             part,
-            self.migration.clone(),
+            migration,
             self.filter.clone(),
             self.filter_pushdown_audit,
             self.structured_part_audit,
@@ -735,7 +987,9 @@ impl<K: Codec, V: Codec, T: Timestamp + Lattice + Codec64, D> FetchedBlob<K, V,
     /// Decodes and returns the pushdown stats for this part, if known.
     pub fn stats(&self) -> Option<PartStats> {
         match &self.buf {
-            FetchedBlobBuf::Hollow { part, .. } => part.stats.as_ref().map(|x| x.decode()),
+            FetchedBlobBuf::Hollow { part, .. } | FetchedBlobBuf::Ranged { part, .. } => {
+                part.stats.as_ref().map(|x| x.decode())
+            }
             FetchedBlobBuf::Inline { .. } => None,
         }
     }
@@ -22,6 +22,7 @@ use mz_persist::location::{CaSResult, Indeterminate, SeqNo, VersionedData};
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use timely::progress::{Antichain, Timestamp};
+use tokio::sync::oneshot;
 use tracing::debug;
 
 use crate::cache::{LockingTypedState, StateCache};
@@ -30,6 +31,7 @@ use crate::internal::gc::GcReq;
 use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::{CmdMetrics, Metrics, ShardMetrics};
 use crate::internal::paths::{PartialRollupKey, RollupId};
+use crate::internal::service::{ProtoCompactionRequest, ProtoCompactionResponse};
 use crate::internal::state::{
     ActiveGc, ActiveRollup, EncodedSchemas, ExpiryMetrics, GC_FALLBACK_THRESHOLD_MS,
     GC_USE_ACTIVE_GC, HollowBatch, LeasedReaderState, ROLLUP_FALLBACK_THRESHOLD_MS,
@@ -127,6 +129,15 @@ where
         Ok(ret)
     }
 
+    /// Hands a compaction request for this shard to a compaction service,
+    /// returning a receiver for its response.
+    pub fn send_compaction_request(
+        &self,
+        req: ProtoCompactionRequest,
+    ) -> oneshot::Receiver<ProtoCompactionResponse> {
+        self.pubsub_sender.compact(req)
+    }
+
     /// Returns a new [StateWatch] for changes to this Applier's State.
     pub fn watch(&self) -> StateWatch<K, V, T, D> {
         StateWatch::new(Arc::clone(&self.state), Arc::clone(&self.metrics))
@@ -254,6 +265,14 @@ where
             })
     }
 
+    /// See [crate::internal::state::StateCollections::resort_runs_before].
+    pub fn resort_runs_before(&self, schema_id: SchemaId) -> Option<SchemaId> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                state.collections.resort_runs_before(schema_id)
+            })
+    }
+
     /// Returns whether the current's state `since` and `upper` are both empty.
     ///
     /// Due to sharing state with other handles, successive reads to this fn or any other may
@@ -301,6 +320,19 @@ where
             })
     }
 
+    /// Returns merge reqs for all batches, including compact ones, whose compaction has not
+    /// been claimed since `threshold_ms`.
+    pub fn all_merge_reqs_before_ms(&self, threshold_ms: u64) -> Vec<FueledMergeReq<T>> {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_noncacheable, |state| {
+                state
+                    .collections
+                    .trace
+                    .all_merge_reqs_before_ms(threshold_ms)
+                    .collect()
+            })
+    }
+
     pub fn snapshot(&self, as_of: &Antichain<T>) -> Result<Vec<HollowBatch<T>>, SnapshotErr<T>> {
         self.state
             .read_lock(&self.metrics.locks.applier_read_noncacheable, |state| {
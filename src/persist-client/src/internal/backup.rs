@@ -0,0 +1,989 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Backup and restore of entire persist environments.
+//!
+//! A backup is a [Blob] location containing, for every shard:
+//! - A version of its state, written as a standalone rollup that references
+//!   no other version of state.
+//! - A copy of every blob referenced by that version of state.
+//! - For txn-wal txns shards, a copy of every batch committed as of `as_of`
+//!   that the txns shard still references. Until txn-wal applies it, the txns
+//!   shard is the only thing that references it, and (once applied) it may be
+//!   missing from the data shard's backed up version of state, if that's
+//!   earlier.
+//!
+//! It also contains a [BackupManifest], which lists the shards and the rollup
+//! to restore each from, along with a single timestamp `as_of` that is in
+//! every backed up version of state's `[since, upper)`. Reading every shard as
+//! of `as_of` gives a consistent cut of the environment (including the
+//! catalog, which is stored in persist shards like everything else).
+//!
+//! While a backup is taken, a leased reader registered on each shard holds
+//! back its since (so that `as_of` stays readable) and the garbage collection
+//! of the version of state being copied.
+//!
+//! Restoring copies the blobs into another (empty) environment and then
+//! initializes each shard's consensus state from its rollup, cut back to
+//! `as_of`: batches beyond it are dropped, and those that contain it are
+//! rewritten to end at `as_of + 1`, which becomes the shard's upper. Shards
+//! with nothing readable as of `as_of` are restored as they were backed up.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::pin::pin;
+
+use anyhow::{anyhow, bail};
+use arrow::array::{Array, AsArray, BooleanArray};
+use arrow::compute::FilterBuilder;
+use arrow::datatypes::Int64Type;
+use bytes::Bytes;
+use differential_dataflow::trace::Description;
+use futures_util::TryStreamExt;
+use mz_ore::cast::CastFrom;
+use mz_persist::indexed::columnar::{ColumnarRecords, ColumnarRecordsStructuredExt};
+use mz_persist::indexed::encoding::{BatchColumnarFormat, BlobTraceBatchPart, BlobTraceUpdates};
+use mz_persist::location::{Blob, CaSResult, Consensus, SeqNo, VersionedData};
+use mz_persist_types::codec_impls::{ShardIdSchema, VecU8Schema};
+use mz_persist_types::{Codec, Codec64};
+use mz_proto::{IntoRustIfSome, RustType};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use timely::PartialOrder;
+use timely::progress::Antichain;
+
+use crate::batch::{BatchBuilderConfig, ProtoBatch, ProtoTxnsBatch};
+use crate::fetch::EncodedPart;
+use crate::internal::encoding::{Rollup, UntypedState};
+use crate::internal::paths::{
+    BlobKey, BlobKeyPrefix, PartId, PartialBatchKey, PartialBlobKey, PartialRollupKey, RollupId,
+    WriterKey,
+};
+use crate::internal::state::{
+    BatchPart, HollowBatch, HollowBatchPart, HollowBlobRef, HollowRollup, RunPart, State,
+    TypedState,
+};
+use crate::internal::state_diff::StateDiff;
+use crate::internal::state_versions::StateVersions;
+use crate::internal::trace::Trace;
+use crate::metrics::Metrics;
+use crate::{PersistConfig, ShardId};
+
+/// The key in a backup's [Blob] at which its [BackupManifest] is stored.
+///
+/// This can't collide with any key persist writes, which always begin with a
+/// [ShardId].
+pub(crate) const BACKUP_MANIFEST_KEY: &str = "BACKUP_MANIFEST";
+
+/// The contents of a backup.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BackupManifest {
+    /// The timestamp that the backup is consistent as of.
+    pub as_of: u64,
+    /// The version of state backed up for each shard.
+    pub shards: BTreeMap<ShardId, BackupShard>,
+}
+
+/// The version of state backed up for a single shard.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BackupShard {
+    /// The seqno of the backed up version of state.
+    pub seqno: SeqNo,
+    /// The walltime at which the backed up version of state was written.
+    pub walltime_ms: u64,
+    /// The standalone rollup of the backed up version of state.
+    pub rollup_key: PartialRollupKey,
+}
+
+impl BackupManifest {
+    /// Fetches the manifest of the backup in `backup`.
+    pub(crate) async fn fetch(backup: &dyn Blob) -> anyhow::Result<Self> {
+        let buf = backup
+            .get(BACKUP_MANIFEST_KEY)
+            .await?
+            .ok_or_else(|| anyhow!("no backup manifest found at {}", BACKUP_MANIFEST_KEY))?;
+        Ok(serde_json::from_slice(&buf.into_contiguous())?)
+    }
+
+    /// Writes this manifest to `backup`.
+    pub(crate) async fn write(&self, backup: &dyn Blob) -> anyhow::Result<()> {
+        let buf = serde_json::to_vec_pretty(self)?;
+        backup.set(BACKUP_MANIFEST_KEY, Bytes::from(buf)).await?;
+        Ok(())
+    }
+}
+
+/// Returns the timestamp to take a backup as of, given the since and upper of
+/// every shard in it.
+///
+/// This is `as_of`, if given, and otherwise the latest timestamp that is
+/// readable in every shard. Shards with nothing readable (those that have not
+/// yet been written to or that have been finalized) don't constrain it.
+///
+/// Returns an error if no timestamp is in every shard's `[since, upper)`, or
+/// if `as_of` is not.
+pub(crate) fn backup_as_of<'a>(
+    frontiers: impl IntoIterator<Item = (ShardId, &'a Antichain<u64>, &'a Antichain<u64>)>,
+    as_of: Option<u64>,
+) -> anyhow::Result<u64> {
+    let readable = frontiers
+        .into_iter()
+        .filter_map(|(shard_id, since, upper)| {
+            let since = *since.as_option()?;
+            match upper.as_option() {
+                Some(upper) if *upper <= since => None,
+                upper => Some((shard_id, since, upper.copied())),
+            }
+        })
+        .collect::<Vec<_>>();
+    let as_of = match as_of {
+        Some(as_of) => as_of,
+        None => {
+            let max_since = readable.iter().map(|(_, since, _)| *since).max();
+            let min_upper = readable.iter().filter_map(|(_, _, upper)| *upper).min();
+            match min_upper {
+                // Every shard's upper is greater than its since, so this can't
+                // underflow.
+                Some(min_upper) => min_upper - 1,
+                None => max_since.unwrap_or_default(),
+            }
+        }
+    };
+    for (shard_id, since, upper) in readable {
+        if as_of < since || upper.is_some_and(|upper| upper <= as_of) {
+            bail!(
+                "shard {} is not readable as of {}: since {} upper {:?}",
+                shard_id,
+                as_of,
+                since,
+                upper
+            );
+        }
+    }
+    Ok(as_of)
+}
+
+/// Copies the version of the given shard's state at `seqno`, along with every
+/// blob it references, to `backup`.
+///
+/// The caller is responsible for holding a lease on `seqno` (e.g. by
+/// registering a leased reader) until this returns, so that neither the
+/// version of state nor the blobs it references are garbage collected.
+///
+/// If the shard is a txn-wal txns shard, this also copies the batches committed
+/// to it as of `as_of` (see the module docs).
+///
+/// Blobs whose keys are in `existing` are assumed to already have been copied
+/// (e.g. by a previous, interrupted attempt at the backup), which is safe
+/// because blobs are write-once modify-never.
+pub(crate) async fn backup_shard(
+    versions: &StateVersions,
+    blob: &dyn Blob,
+    backup: &dyn Blob,
+    existing: &BTreeSet<String>,
+    shard_id: ShardId,
+    seqno: SeqNo,
+    as_of: u64,
+    metrics: &Metrics,
+) -> anyhow::Result<BackupShard> {
+    let states = versions
+        .fetch_all_live_states::<u64>(shard_id)
+        .await
+        .ok_or_else(|| anyhow!("no state for shard {}", shard_id))?;
+    let mut states = states.check_ts_codec()?;
+
+    let earliest = states
+        .next(|_| {})
+        .expect("initialized shard should have at least one state")
+        .seqno;
+    if earliest > seqno {
+        bail!(
+            "shard {} state at seqno {} is no longer retained, earliest live state is {}",
+            shard_id,
+            seqno,
+            earliest
+        );
+    }
+    while states.state().seqno < seqno {
+        if states.next(|_| {}).is_none() {
+            bail!("shard {} has no state at seqno {}", shard_id, seqno);
+        }
+    }
+    let state = states.state();
+
+    let mut parts = vec![];
+    for blob_ref in state.blobs() {
+        match blob_ref {
+            HollowBlobRef::Batch(batch) => parts.extend(batch.parts.iter().cloned()),
+            // Replaced below by the standalone rollup.
+            HollowBlobRef::Rollup(_) => {}
+        }
+    }
+    copy_parts(blob, backup, existing, shard_id, parts, metrics)
+        .await
+        .map_err(|err| anyhow!("shard {} at seqno {}: {}", shard_id, state.seqno, err))?;
+
+    let (key_codec, val_codec) = states.kv_codecs();
+    if key_codec == ShardId::codec_name() && val_codec == Vec::<u8>::codec_name() {
+        for (data_id, batch) in txns_batches(shard_id, state, blob, as_of, metrics).await? {
+            copy_parts(blob, backup, existing, data_id, batch.parts, metrics)
+                .await
+                .map_err(|err| anyhow!("txns shard {} batch: {}", shard_id, err))?;
+        }
+    }
+
+    let rollup_key = PartialRollupKey::new(state.seqno, &RollupId::new());
+    let rollup = states.encode_standalone_rollup(rollup_key.clone());
+    backup.set(&rollup_key.complete(&shard_id), rollup).await?;
+    Ok(BackupShard {
+        seqno: state.seqno,
+        walltime_ms: state.walltime_ms,
+        rollup_key,
+    })
+}
+
+/// Copies the blobs of `parts`, which belong to the given shard, from `blob` to
+/// `backup`, skipping those in `existing`.
+async fn copy_parts(
+    blob: &dyn Blob,
+    backup: &dyn Blob,
+    existing: &BTreeSet<String>,
+    shard_id: ShardId,
+    mut parts: Vec<RunPart<u64>>,
+    metrics: &Metrics,
+) -> anyhow::Result<()> {
+    let copy = |key: BlobKey| async move {
+        if existing.contains(&*key) {
+            return Ok::<_, anyhow::Error>(());
+        }
+        let value = blob
+            .get(&key)
+            .await?
+            .ok_or_else(|| anyhow!("referenced blob {} no longer exists", key))?;
+        backup
+            .set(&key, Bytes::from(value.into_contiguous()))
+            .await?;
+        Ok(())
+    };
+    while let Some(part) = parts.pop() {
+        match part {
+            RunPart::Single(BatchPart::Inline { .. }) => {}
+            RunPart::Single(BatchPart::Hollow(part)) => {
+                copy(part.key.complete(&shard_id)).await?;
+            }
+            RunPart::Many(runs) => {
+                copy(runs.key.complete(&shard_id)).await?;
+                let runs = runs
+                    .get(shard_id, blob, metrics)
+                    .await
+                    .ok_or_else(|| anyhow!("fetching just-copied run"))?;
+                parts.extend(runs.parts);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the batches that were committed to the given txn-wal txns shard as
+/// of `as_of`, along with the data shard each was written to.
+///
+/// This assumes txn-wal's default codec, which appends the commit timestamp to
+/// each encoded batch (and registrations, which have only the timestamp).
+async fn txns_batches(
+    shard_id: ShardId,
+    state: &State<u64>,
+    blob: &dyn Blob,
+    as_of: u64,
+    metrics: &Metrics,
+) -> anyhow::Result<Vec<(ShardId, HollowBatch<u64>)>> {
+    let shard_metrics = metrics.shards.shard(&shard_id, "backup");
+    let mut entries = BTreeMap::<(Vec<u8>, Vec<u8>), i64>::new();
+    for batch in state.collections.trace.batches() {
+        let mut part_stream = pin!(batch.part_stream(shard_id, blob, metrics));
+        while let Some(part) = part_stream.try_next().await? {
+            let encoded = EncodedPart::fetch(
+                &shard_id,
+                blob,
+                metrics,
+                &shard_metrics,
+                &metrics.read.snapshot,
+                &batch.desc,
+                &part,
+            )
+            .await
+            .map_err(|key| anyhow!("missing blob {}", key))?;
+            let mut updates = encoded.normalize(&metrics.columnar);
+            let records =
+                updates.get_or_make_codec::<ShardId, Vec<u8>>(&ShardIdSchema, &VecU8Schema);
+            for ((k, v), t, d) in records.iter() {
+                if <u64 as Codec64>::decode(t) <= as_of {
+                    *entries.entry((k.to_vec(), v.to_vec())).or_default() +=
+                        <i64 as Codec64>::decode(d);
+                }
+            }
+        }
+    }
+
+    let mut batches = vec![];
+    for ((key, val), diff) in entries {
+        let Some(batch_len) = val.len().checked_sub(8) else {
+            bail!("txns shard {} entry is missing its timestamp", shard_id);
+        };
+        // Retracted once txn-wal has applied it and moved on, or a
+        // registration.
+        if diff <= 0 || batch_len == 0 {
+            continue;
+        }
+        let data_id = ShardId::decode(&key, &ShardIdSchema).map_err(|err| anyhow!(err))?;
+        let buf = &val[..batch_len];
+        let batch = match ProtoTxnsBatch::decode(buf)?.batch {
+            Some(batch) => batch,
+            None => ProtoBatch::decode(buf)?,
+        };
+        let batch = batch.batch.into_rust_if_some("ProtoBatch::batch")?;
+        batches.push((data_id, batch));
+    }
+    Ok(batches)
+}
+
+/// Copies the given shard's blobs from `backup` to `blob` and then initializes
+/// its state in `consensus` from the backed up rollup, cut back to `as_of`.
+///
+/// Returns an error if the shard already exists in `consensus`.
+pub(crate) async fn restore_shard(
+    cfg: &PersistConfig,
+    backup: &dyn Blob,
+    blob: &dyn Blob,
+    consensus: &dyn Consensus,
+    shard_id: ShardId,
+    shard: &BackupShard,
+    as_of: u64,
+    metrics: &Metrics,
+) -> anyhow::Result<()> {
+    // Copy every blob before writing to consensus, so that the shard is never
+    // observable with dangling references. The backed up rollup is replaced
+    // below by one of the cut state.
+    let mut keys = vec![];
+    backup
+        .list_keys_and_metadata(&BlobKeyPrefix::Shard(&shard_id).to_string(), &mut |x| {
+            keys.push(x.key.to_owned())
+        })
+        .await?;
+    for key in keys {
+        if let Ok((_, PartialBlobKey::Rollup(..))) = BlobKey::parse_ids(&key) {
+            continue;
+        }
+        let value = backup
+            .get(&key)
+            .await?
+            .ok_or_else(|| anyhow!("backed up blob {} disappeared", key))?;
+        blob.set(&key, Bytes::from(value.into_contiguous())).await?;
+    }
+
+    let rollup_key = shard.rollup_key.complete(&shard_id);
+    let buf = backup
+        .get(&rollup_key)
+        .await?
+        .ok_or_else(|| anyhow!("backed up rollup {} is missing", rollup_key))?;
+    let state = UntypedState::<u64>::decode(&cfg.build_version, buf);
+    let (key_codec, val_codec, ts_codec, diff_codec) = (
+        state.key_codec.clone(),
+        state.val_codec.clone(),
+        state.ts_codec.clone(),
+        state.diff_codec.clone(),
+    );
+    let mut state = state.check_ts_codec(&shard_id)?;
+    if state.seqno != shard.seqno {
+        bail!(
+            "backed up rollup {} is for seqno {}, expected {}",
+            rollup_key,
+            state.seqno,
+            shard.seqno
+        );
+    }
+
+    // Shards that aren't readable as of `as_of` (because they've been
+    // finalized, or were never written to) are restored as they were.
+    let trace = &state.collections.trace;
+    let upper = Antichain::from_elem(as_of + 1);
+    if trace.since().less_equal(&as_of) && PartialOrder::less_than(&upper, trace.upper()) {
+        state.collections.trace = cut_trace(cfg, blob, shard_id, trace, as_of, metrics).await?;
+    }
+
+    let rollup_key = PartialRollupKey::new(state.seqno, &RollupId::new());
+    state.collections.rollups = BTreeMap::from([(
+        state.seqno,
+        HollowRollup {
+            key: rollup_key.clone(),
+            encoded_size_bytes: None,
+        },
+    )]);
+    let rollup =
+        Rollup::from_state_without_diffs(state.clone(), key_codec, val_codec, ts_codec, diff_codec)
+            .state;
+    // The state's only rollup is for its own seqno, so there are no diffs to
+    // inline.
+    let mut buf = Vec::new();
+    Rollup::from(rollup, vec![])
+        .into_proto()
+        .encode(&mut buf)
+        .expect("no required fields means no initialization errors");
+    blob.set(&rollup_key.complete(&shard_id), Bytes::from(buf))
+        .await?;
+
+    // The shard's first (and only) diff in consensus is from the empty state,
+    // which persist never needs to apply: readers start from the rollup it
+    // references.
+    let empty_state = TypedState::<(), (), u64, i64>::new(
+        state.applier_version.clone(),
+        shard_id,
+        state.hostname.clone(),
+        state.walltime_ms,
+    );
+    let diff = StateDiff::from_diff(&empty_state.state, &state);
+    let mut buf = Vec::new();
+    diff.encode(&mut buf);
+    let data = VersionedData {
+        seqno: state.seqno,
+        data: Bytes::from(buf),
+    };
+    match consensus
+        .compare_and_set(&shard_id.to_string(), None, data)
+        .await?
+    {
+        CaSResult::Committed => Ok(()),
+        CaSResult::ExpectationMismatch => {
+            bail!("shard {} already exists in the target consensus", shard_id)
+        }
+    }
+}
+
+/// Returns `trace` with an upper of `as_of + 1`: batches that begin after
+/// `as_of` are dropped, and the one that contains it is rewritten to end just
+/// after it.
+async fn cut_trace(
+    cfg: &PersistConfig,
+    blob: &dyn Blob,
+    shard_id: ShardId,
+    trace: &Trace<u64>,
+    as_of: u64,
+    metrics: &Metrics,
+) -> anyhow::Result<Trace<u64>> {
+    let upper = Antichain::from_elem(as_of + 1);
+    let mut cut = Trace::default();
+    cut.roundtrip_structure = trace.roundtrip_structure;
+    cut.downgrade_since(trace.since());
+    for batch in trace.batches() {
+        if PartialOrder::less_equal(batch.desc.upper(), &upper) {
+            cut.push_batch_no_merge_reqs(batch.clone());
+        } else if batch.desc.lower().less_equal(&as_of) {
+            let batch = cut_batch(cfg, blob, shard_id, batch, as_of, metrics).await?;
+            cut.push_batch_no_merge_reqs(batch);
+        }
+    }
+    Ok(cut)
+}
+
+/// Writes a copy of `batch` with only the updates at times `<= as_of`, and an
+/// upper of `as_of + 1`.
+///
+/// Every part is rewritten, even those entirely before `as_of`: parts written
+/// by compaction must have exactly the desc of the batch that references them.
+async fn cut_batch(
+    cfg: &PersistConfig,
+    blob: &dyn Blob,
+    shard_id: ShardId,
+    batch: &HollowBatch<u64>,
+    as_of: u64,
+    metrics: &Metrics,
+) -> anyhow::Result<HollowBatch<u64>> {
+    let desc = Description::new(
+        batch.desc.lower().clone(),
+        Antichain::from_elem(as_of + 1),
+        batch.desc.since().clone(),
+    );
+    let batch_cfg = BatchBuilderConfig::new(cfg, shard_id);
+    let writer_key = WriterKey::for_version(&cfg.build_version);
+    let shard_metrics = metrics.shards.shard(&shard_id, "restore");
+    let mut parts = vec![];
+    let mut len = 0;
+    let mut run_meta = vec![];
+    let mut run_splits = vec![];
+    for (meta, run) in batch.runs() {
+        let run_start = parts.len();
+        for run_part in run {
+            let mut part_stream = pin!(run_part.part_stream(shard_id, blob, metrics));
+            while let Some(part) = part_stream.try_next().await? {
+                let encoded = EncodedPart::fetch(
+                    &shard_id,
+                    blob,
+                    metrics,
+                    &shard_metrics,
+                    &metrics.read.snapshot,
+                    &batch.desc,
+                    &part,
+                )
+                .await
+                .map_err(|key| anyhow!("missing blob {}", key))?;
+                let updates = filter_updates(&encoded.normalize(&metrics.columnar), as_of);
+                if updates.len() == 0 {
+                    continue;
+                }
+                len += updates.len();
+                let format = match &updates {
+                    BlobTraceUpdates::Row(_) => BatchColumnarFormat::Row,
+                    BlobTraceUpdates::Both(..) => BatchColumnarFormat::default(),
+                    BlobTraceUpdates::Structured { .. } => BatchColumnarFormat::Structured,
+                };
+                let encoded = BlobTraceBatchPart {
+                    desc: desc.clone(),
+                    index: u64::cast_from(parts.len()),
+                    updates,
+                };
+                let mut buf = Vec::new();
+                encoded.encode(&mut buf, &metrics.columnar, &batch_cfg.encoding_config);
+                let key = PartialBatchKey::new(&writer_key, &PartId::new());
+                let encoded_size_bytes = buf.len();
+                blob.set(&key.complete(&shard_id), Bytes::from(buf)).await?;
+                // Filtering out updates keeps the key lowers valid lower
+                // bounds, but not the stats.
+                let (key_lower, structured_key_lower, schema_id, deprecated_schema_id) =
+                    match &*part {
+                        BatchPart::Hollow(x) => (
+                            x.key_lower.clone(),
+                            x.structured_key_lower.clone(),
+                            x.schema_id,
+                            x.deprecated_schema_id,
+                        ),
+                        BatchPart::Inline {
+                            schema_id,
+                            deprecated_schema_id,
+                            ..
+                        } => (vec![], None, *schema_id, *deprecated_schema_id),
+                    };
+                parts.push(RunPart::Single(BatchPart::Hollow(HollowBatchPart {
+                    key,
+                    encoded_size_bytes,
+                    key_lower,
+                    structured_key_lower,
+                    stats: None,
+                    ts_rewrite: None,
+                    diffs_sum: None,
+                    format: Some(format),
+                    schema_id,
+                    deprecated_schema_id,
+                    encryption_key_id: batch_cfg.encryption_key_id.clone(),
+                })));
+            }
+        }
+        if parts.len() > run_start {
+            if run_start > 0 {
+                run_splits.push(run_start);
+            }
+            run_meta.push(meta.clone());
+        }
+    }
+    Ok(HollowBatch::new(desc, parts, len, run_meta, run_splits))
+}
+
+/// Returns the updates at times `<= as_of`.
+fn filter_updates(updates: &BlobTraceUpdates, as_of: u64) -> BlobTraceUpdates {
+    let filter = BooleanArray::from_unary(updates.timestamps(), |i| {
+        <u64 as Codec64>::decode(i.to_le_bytes()) <= as_of
+    });
+    let filter = FilterBuilder::new(&filter).optimize().build();
+    let do_filter = |array: &dyn Array| filter.filter(array).expect("valid filter len");
+    let timestamps = do_filter(updates.timestamps())
+        .as_primitive::<Int64Type>()
+        .clone();
+    let diffs = do_filter(updates.diffs())
+        .as_primitive::<Int64Type>()
+        .clone();
+    let structured = updates
+        .structured()
+        .map(|ext| ColumnarRecordsStructuredExt {
+            key: do_filter(&*ext.key),
+            val: do_filter(&*ext.val),
+        });
+    match (updates.records(), structured) {
+        (Some(records), None) => BlobTraceUpdates::Row(ColumnarRecords::new(
+            do_filter(records.keys()).as_binary::<i32>().clone(),
+            do_filter(records.vals()).as_binary::<i32>().clone(),
+            timestamps,
+            diffs,
+        )),
+        (Some(records), Some(ext)) => BlobTraceUpdates::Both(
+            ColumnarRecords::new(
+                do_filter(records.keys()).as_binary::<i32>().clone(),
+                do_filter(records.vals()).as_binary::<i32>().clone(),
+                timestamps,
+                diffs,
+            ),
+            ext,
+        ),
+        (None, Some(ext)) => BlobTraceUpdates::Structured {
+            key_values: ext,
+            timestamps,
+            diffs,
+        },
+        (None, None) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_ore::now::NowFn;
+    use mz_persist::mem::{MemBlob, MemBlobConfig, MemConsensus};
+
+    use crate::async_runtime::IsolatedRuntime;
+    use crate::batch::INLINE_WRITES_SINGLE_MAX_BYTES;
+    use crate::cache::StateCache;
+    use crate::rpc::NoopPubSubSender;
+    use crate::tests::all_ok;
+    use crate::{Diagnostics, PersistClient};
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn backup_and_restore() {
+        let data = vec![
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 2, 1),
+        ];
+
+        let now = Arc::new(AtomicU64::new(1_000));
+        let mut cfg = PersistConfig::new_for_tests();
+        cfg.now = NowFn::from({
+            let now = Arc::clone(&now);
+            move || now.load(Ordering::SeqCst)
+        });
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let client = |blob: &Arc<dyn Blob>, consensus: &Arc<dyn Consensus>| {
+            PersistClient::new(
+                cfg.clone(),
+                Arc::clone(blob),
+                Arc::clone(consensus),
+                Arc::clone(&metrics),
+                Arc::new(IsolatedRuntime::default()),
+                Arc::new(StateCache::new_no_metrics()),
+                Arc::new(NoopPubSubSender),
+            )
+            .expect("client construction failed")
+        };
+        let blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+
+        // Write some data, to a second shard past the as_of, and create a
+        // third shard that is never written to.
+        let shard_id = ShardId::new();
+        let (mut write, read) = client(&blob, &consensus)
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data[0..1], 0, 2).await;
+        let wide_shard_id = ShardId::new();
+        let (mut wide_write, wide_read) = client(&blob, &consensus)
+            .expect_open::<String, String, u64, i64>(wide_shard_id)
+            .await;
+        let wide_data = vec![
+            (("a".to_owned(), "one".to_owned()), 1, 1),
+            (("b".to_owned(), "three".to_owned()), 3, 1),
+            (("c".to_owned(), "four".to_owned()), 4, 1),
+            (("d".to_owned(), "two".to_owned()), 2, 1),
+        ];
+        wide_write
+            .expect_compare_and_append(&wide_data[0..2], 0, 4)
+            .await;
+        wide_write
+            .expect_compare_and_append(&wide_data[2..3], 4, 5)
+            .await;
+        let empty_shard_id = ShardId::new();
+        let (_empty_write, empty_read) = client(&blob, &consensus)
+            .expect_open::<String, String, u64, i64>(empty_shard_id)
+            .await;
+
+        // The readers hold back each shard's since and the seqno to back up,
+        // while writes continue.
+        let seqno = read.machine.seqno();
+        let wide_seqno = wide_read.machine.seqno();
+        let empty_seqno = empty_read.machine.seqno();
+        let as_of = backup_as_of(
+            [
+                (shard_id, read.since(), &Antichain::from_elem(2)),
+                (wide_shard_id, wide_read.since(), &Antichain::from_elem(5)),
+                (empty_shard_id, empty_read.since(), &Antichain::from_elem(0)),
+            ],
+            None,
+        )
+        .expect("as_of");
+        assert_eq!(as_of, 1);
+        now.store(3_000, Ordering::SeqCst);
+        write.expect_compare_and_append(&data[1..2], 2, 3).await;
+
+        let versions = StateVersions::new(
+            cfg.clone(),
+            Arc::clone(&consensus),
+            Arc::clone(&blob),
+            Arc::clone(&metrics),
+        );
+        let backup: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let mut shards = BTreeMap::new();
+        for (shard_id, seqno) in [
+            (shard_id, seqno),
+            (wide_shard_id, wide_seqno),
+            (empty_shard_id, empty_seqno),
+        ] {
+            let shard = backup_shard(
+                &versions,
+                blob.as_ref(),
+                backup.as_ref(),
+                &BTreeSet::new(),
+                shard_id,
+                seqno,
+                as_of,
+                &metrics,
+            )
+            .await
+            .expect("backup");
+            assert_eq!(shard.seqno, seqno);
+            shards.insert(shard_id, shard);
+        }
+        let manifest = BackupManifest { as_of, shards };
+        manifest
+            .write(backup.as_ref())
+            .await
+            .expect("write manifest");
+
+        // Restore into an empty environment, which sees only the data from
+        // the backed up version of state and can be written to as usual.
+        let manifest = BackupManifest::fetch(backup.as_ref())
+            .await
+            .expect("fetch manifest");
+        assert_eq!(manifest.as_of, as_of);
+        let shard = &manifest.shards[&shard_id];
+        let restored_blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let restored_consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+        restore_shard(
+            &cfg,
+            backup.as_ref(),
+            restored_blob.as_ref(),
+            restored_consensus.as_ref(),
+            shard_id,
+            shard,
+            manifest.as_of,
+            &metrics,
+        )
+        .await
+        .expect("restore");
+        let (mut write, mut read) = client(&restored_blob, &restored_consensus)
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        assert_eq!(
+            read.expect_snapshot_and_fetch(1).await,
+            all_ok(&data[0..1], 1)
+        );
+        write.expect_compare_and_append(&data[2..3], 2, 3).await;
+        assert_eq!(
+            read.expect_snapshot_and_fetch(2).await,
+            all_ok(&[data[0].clone(), data[2].clone()], 2)
+        );
+
+        // A shard whose upper was past the as_of is cut back to it: the batch
+        // that contains the as_of keeps only the updates at or before it, and
+        // later batches are dropped.
+        restore_shard(
+            &cfg,
+            backup.as_ref(),
+            restored_blob.as_ref(),
+            restored_consensus.as_ref(),
+            wide_shard_id,
+            &manifest.shards[&wide_shard_id],
+            manifest.as_of,
+            &metrics,
+        )
+        .await
+        .expect("restore");
+        let (mut wide_write, mut wide_read) = client(&restored_blob, &restored_consensus)
+            .expect_open::<String, String, u64, i64>(wide_shard_id)
+            .await;
+        assert_eq!(
+            wide_write.fetch_recent_upper().await,
+            &Antichain::from_elem(2)
+        );
+        assert_eq!(
+            wide_read.expect_snapshot_and_fetch(1).await,
+            all_ok(&wide_data[0..1], 1)
+        );
+        wide_write
+            .expect_compare_and_append(&wide_data[3..4], 2, 3)
+            .await;
+        assert_eq!(
+            wide_read.expect_snapshot_and_fetch(2).await,
+            all_ok(&[wide_data[0].clone(), wide_data[3].clone()], 2)
+        );
+
+        // Restoring over an existing shard is refused.
+        let err = restore_shard(
+            &cfg,
+            backup.as_ref(),
+            restored_blob.as_ref(),
+            restored_consensus.as_ref(),
+            shard_id,
+            shard,
+            manifest.as_of,
+            &metrics,
+        )
+        .await
+        .expect_err("shard already exists");
+        assert!(err.to_string().contains("already exists"), "{}", err);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn backup_txns_batches() {
+        let cfg = PersistConfig::new_for_tests();
+        cfg.set_config(&INLINE_WRITES_SINGLE_MAX_BYTES, 0);
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+        let client = PersistClient::new(
+            cfg.clone(),
+            Arc::clone(&blob),
+            Arc::clone(&consensus),
+            Arc::clone(&metrics),
+            Arc::new(IsolatedRuntime::default()),
+            Arc::new(StateCache::new_no_metrics()),
+            Arc::new(NoopPubSubSender),
+        )
+        .expect("client construction failed");
+
+        // Batches written to a data shard and committed to a txns shard, the
+        // way txn-wal does, but never applied to the data shard.
+        let data_id = ShardId::new();
+        let (mut data_write, _data_read) = client
+            .expect_open::<String, String, u64, i64>(data_id)
+            .await;
+        let update = |k: &str| vec![((k.to_owned(), "".to_owned()), 1, 1)];
+        let batch = data_write.expect_batch(&update("a"), 0, 2).await;
+        let mut wrapped = Vec::new();
+        ProtoTxnsBatch {
+            batch: Some(batch.into_transmittable_batch()),
+        }
+        .encode(&mut wrapped)
+        .expect("encodable");
+        let legacy = data_write
+            .expect_batch(&update("b"), 0, 2)
+            .await
+            .into_transmittable_batch()
+            .encode_to_vec();
+        let ts = |buf: Vec<u8>, ts: u64| {
+            buf.into_iter()
+                .chain(<u64 as Codec64>::encode(&ts))
+                .collect::<Vec<_>>()
+        };
+
+        let txns_id = ShardId::new();
+        let (mut txns_write, _txns_read) = client
+            .open::<ShardId, Vec<u8>, u64, i64>(
+                txns_id,
+                Arc::new(ShardIdSchema),
+                Arc::new(VecU8Schema),
+                Diagnostics::for_tests(),
+                true,
+            )
+            .await
+            .expect("codec mismatch");
+        txns_write
+            .expect_compare_and_append(
+                &[
+                    ((data_id, ts(vec![], 0)), 0, 1),
+                    ((data_id, ts(wrapped, 1)), 1, 1),
+                    ((data_id, ts(legacy, 1)), 1, 1),
+                ],
+                0,
+                2,
+            )
+            .await;
+
+        let versions = StateVersions::new(
+            cfg.clone(),
+            Arc::clone(&consensus),
+            Arc::clone(&blob),
+            Arc::clone(&metrics),
+        );
+        let backup: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        backup_shard(
+            &versions,
+            blob.as_ref(),
+            backup.as_ref(),
+            &BTreeSet::new(),
+            txns_id,
+            txns_write.machine.seqno(),
+            1,
+            &metrics,
+        )
+        .await
+        .expect("backup");
+
+        // Backing up the txns shard copied the data shard's batches.
+        let batch_keys = |blob: Arc<dyn Blob>| async move {
+            let mut keys = BTreeSet::new();
+            blob.list_keys_and_metadata(&BlobKeyPrefix::Shard(&data_id).to_string(), &mut |x| {
+                if let Ok((_, PartialBlobKey::Batch(..))) = BlobKey::parse_ids(x.key) {
+                    keys.insert(x.key.to_owned());
+                }
+            })
+            .await
+            .expect("list keys");
+            keys
+        };
+        let expected = batch_keys(Arc::clone(&blob)).await;
+        assert_eq!(expected.len(), 2);
+        assert_eq!(batch_keys(backup).await, expected);
+    }
+
+    #[mz_ore::test]
+    fn backup_as_of_frontiers() {
+        let a = ShardId::new();
+        let b = ShardId::new();
+        let f = |t: &[u64]| Antichain::from_iter(t.iter().copied());
+        let as_of = |frontiers: &[(ShardId, Antichain<u64>, Antichain<u64>)], as_of| {
+            backup_as_of(
+                frontiers
+                    .iter()
+                    .map(|(id, since, upper)| (*id, since, upper)),
+                as_of,
+            )
+            .map_err(|err| err.to_string())
+        };
+
+        // The latest timestamp readable in every shard, or the requested one.
+        let frontiers = [(a, f(&[2]), f(&[10])), (b, f(&[5]), f(&[7]))];
+        assert_eq!(as_of(&frontiers, None), Ok(6));
+        assert_eq!(as_of(&frontiers, Some(5)), Ok(5));
+        assert!(as_of(&frontiers, Some(4)).is_err());
+        assert!(as_of(&frontiers, Some(7)).is_err());
+
+        // Closed, empty, and finalized shards don't constrain the as_of.
+        let frontiers = [
+            (a, f(&[2]), f(&[])),
+            (b, f(&[3]), f(&[3])),
+            (ShardId::new(), f(&[]), f(&[])),
+        ];
+        assert_eq!(as_of(&frontiers, None), Ok(2));
+
+        // There may be no timestamp readable in every shard.
+        let frontiers = [(a, f(&[2]), f(&[4])), (b, f(&[5]), f(&[7]))];
+        assert!(as_of(&frontiers, None).is_err());
+    }
+}
@@ -9,6 +9,9 @@
 
 //! In-process caches of [Blob].
 
+use std::io::{Read, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
@@ -16,7 +19,10 @@ use bytes::Bytes;
 use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
-use mz_persist::location::{Blob, BlobMetadata, ExternalError};
+use mz_persist::location::{Blob, BlobMetadata, ExternalError, value_range};
+use sha2::{Digest, Sha256};
+use tracing::warn;
+use uuid::Uuid;
 
 use crate::cfg::PersistConfig;
 use crate::internal::metrics::Metrics;
@@ -135,6 +141,29 @@ impl Blob for BlobMemCache {
         Ok(res)
     }
 
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let cached_value = self
+            .cache
+            .lock()
+            .expect("lock poisoned")
+            .get(key)
+            .map(|(_, cached_value)| cached_value.clone());
+        if let Some(cached_value) = cached_value {
+            self.metrics.blob_cache_mem.hits_blobs.inc();
+            self.metrics
+                .blob_cache_mem
+                .hits_bytes
+                .inc_by(u64::cast_from(range.len()));
+            return value_range(key, cached_value, range).map(Some);
+        }
+        // Partial values aren't cached.
+        self.blob.get_range(key, range).await
+    }
+
     async fn list_keys_and_metadata(
         &self,
         key_prefix: &str,
@@ -170,6 +199,365 @@ impl Blob for BlobMemCache {
     }
 }
 
+pub(crate) const BLOB_CACHE_DISK_ENABLED: Config<bool> = Config::new(
+    "persist_blob_cache_disk_enabled",
+    false,
+    "Whether to cache fetched blobs on local disk, if the process has a disk cache directory (Materialize).",
+);
+
+pub(crate) const BLOB_CACHE_DISK_LIMIT_BYTES: Config<usize> = Config::new(
+    "persist_blob_cache_disk_limit_bytes",
+    // 16GiB
+    16 * 1024 * 1024 * 1024,
+    "Capacity of on-disk blob cache in bytes (Materialize).",
+);
+
+/// The process-wide state of an on-disk cache of [Blob] data.
+///
+/// This is shared by every [BlobDiskCache] in a process, regardless of blob
+/// location: blob keys are unique and blobs are write-once modify-never, so a
+/// key always maps to the same data.
+///
+/// Each cached blob is stored in its own file, named by the hex-encoded sha256
+/// of the blob key (blob keys can be longer than a file name is allowed to
+/// be). The file starts with a crc32 checksum, followed by the length-prefixed
+/// blob key and then the data. The checksum covers everything after it, and
+/// the stored key is verified on every read. Files are written to a temporary
+/// path and then renamed into place, but never fsync'd: anything torn by a
+/// crash fails its checksum when next read and is refetched.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    /// [`ConfigSet`] of dynamic configs.
+    cfg: Arc<ConfigSet>,
+    metrics: Arc<Metrics>,
+    /// The blobs stored in `dir`, weighted by their size on disk.
+    entries: Mutex<lru::Lru<String, ()>>,
+}
+
+impl DiskCache {
+    const CHECKSUM_LEN: usize = 4;
+    const KEY_LEN_LEN: usize = 4;
+    const HEADER_LEN: usize = Self::CHECKSUM_LEN + Self::KEY_LEN_LEN;
+
+    /// Opens the disk cache rooted at `dir`, creating the directory if
+    /// necessary.
+    ///
+    /// Blobs left in `dir` by a previous process are adopted, least recently
+    /// modified first, and partial writes are removed.
+    pub fn open(
+        cfg: &PersistConfig,
+        metrics: Arc<Metrics>,
+        dir: PathBuf,
+    ) -> Result<Self, std::io::Error> {
+        std::fs::create_dir_all(&dir)?;
+        let mut existing = Vec::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            let key = match entry.file_name().to_str() {
+                Some(name) if !name.ends_with(".tmp") => Self::read_key(&entry.path())
+                    .ok()
+                    .flatten()
+                    .filter(|key| Self::file_name(key) == name),
+                _ => None,
+            };
+            match key {
+                Some(key) => existing.push((metadata.modified()?, key, metadata.len())),
+                // A partial write, or something we didn't put there.
+                None => std::fs::remove_file(entry.path())?,
+            }
+        }
+        existing.sort();
+
+        let eviction_dir = dir.clone();
+        let eviction_metrics = Arc::clone(&metrics);
+        let mut entries = lru::Lru::new(
+            BLOB_CACHE_DISK_LIMIT_BYTES.get(&cfg.configs),
+            move |key: String, (), _| {
+                eviction_metrics.blob_cache_disk.evictions.inc();
+                // Best effort. A file we fail to remove here is adopted (and
+                // so eventually evicted again) by the next process.
+                let _ = std::fs::remove_file(Self::path(&eviction_dir, &key));
+            },
+        );
+        for (_, key, len) in existing {
+            entries.insert(key, (), usize::cast_from(len));
+        }
+
+        let cache = DiskCache {
+            dir,
+            cfg: Arc::clone(&cfg.configs),
+            metrics,
+            entries: Mutex::new(entries),
+        };
+        cache.resize_and_update_size_metrics(&mut cache.entries.lock().expect("lock poisoned"));
+        Ok(cache)
+    }
+
+    fn file_name(key: &str) -> String {
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    fn path(dir: &Path, key: &str) -> PathBuf {
+        dir.join(Self::file_name(key))
+    }
+
+    /// Reads the blob key stored in the header of the file at `path`, without
+    /// reading (or verifying) the rest of the file.
+    fn read_key(path: &Path) -> Result<Option<String>, std::io::Error> {
+        let mut file = std::fs::File::open(path)?;
+        let mut header = [0u8; Self::HEADER_LEN];
+        file.read_exact(&mut header)?;
+        let key_len = u32::from_le_bytes(
+            header[Self::CHECKSUM_LEN..]
+                .try_into()
+                .expect("key len len"),
+        );
+        let mut key = Vec::new();
+        file.take(u64::from(key_len)).read_to_end(&mut key)?;
+        if key.len() != usize::cast_from(key_len) {
+            return Ok(None);
+        }
+        Ok(String::from_utf8(key).ok())
+    }
+
+    fn resize_and_update_size_metrics(&self, entries: &mut lru::Lru<String, ()>) {
+        entries.update_capacity(BLOB_CACHE_DISK_LIMIT_BYTES.get(&self.cfg));
+        self.metrics
+            .blob_cache_disk
+            .size_blobs
+            .set(u64::cast_from(entries.entry_count()));
+        self.metrics
+            .blob_cache_disk
+            .size_bytes
+            .set(u64::cast_from(entries.entry_weight()));
+    }
+
+    /// Returns the cached data for `key`, if present and intact.
+    async fn get(&self, key: &str) -> Option<SegmentedBytes> {
+        if self
+            .entries
+            .lock()
+            .expect("lock poisoned")
+            .get(key)
+            .is_none()
+        {
+            return None;
+        }
+        let path = Self::path(&self.dir, key);
+        let buf = mz_ore::task::spawn_blocking(
+            || "persist::blob_cache_disk::get",
+            move || std::fs::read(path),
+        )
+        .await;
+        // A failed read most likely means the entry was concurrently evicted.
+        let Some(buf) = buf.ok().and_then(Result::ok) else {
+            self.remove(key);
+            return None;
+        };
+        let Some(data) = Self::decode(key, buf) else {
+            warn!(
+                "persist disk blob cache entry for {} failed verification, refetching",
+                key
+            );
+            self.metrics.blob_cache_disk.checksum_failures.inc();
+            self.remove(key);
+            return None;
+        };
+        self.metrics.blob_cache_disk.hits_blobs.inc();
+        self.metrics
+            .blob_cache_disk
+            .hits_bytes
+            .inc_by(u64::cast_from(data.len()));
+        Some(SegmentedBytes::from(data))
+    }
+
+    /// Adds `data` to the cache under `key`, evicting as necessary.
+    async fn insert(&self, key: &str, data: &SegmentedBytes) {
+        let weight = Self::HEADER_LEN + key.len() + data.len();
+        {
+            let mut entries = self.entries.lock().expect("lock poisoned");
+            self.resize_and_update_size_metrics(&mut entries);
+            // If the weight of this single blob is greater than the capacity
+            // of the cache, it will push out everything in the cache and then
+            // immediately get evicted itself. So, skip adding it in that case.
+            if weight > entries.capacity() {
+                return;
+            }
+        }
+
+        let path = Self::path(&self.dir, key);
+        // Unique, so that concurrent fetches of the same key don't clobber
+        // each other's partial writes.
+        let tmp_path = self
+            .dir
+            .join(format!("{}.{}.tmp", Self::file_name(key), Uuid::new_v4()));
+        let owned_key = key.to_owned();
+        let data = data.clone();
+        let res = mz_ore::task::spawn_blocking(
+            || "persist::blob_cache_disk::insert",
+            move || {
+                let res = Self::write(&tmp_path, &path, owned_key.as_bytes(), data);
+                if res.is_err() {
+                    let _ = std::fs::remove_file(&tmp_path);
+                }
+                res
+            },
+        )
+        .await;
+        match res {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => {
+                warn!(
+                    "failed to write persist disk blob cache entry for {}: {}",
+                    key, err
+                );
+                return;
+            }
+            Err(_) => return,
+        }
+
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        entries.insert(key.to_owned(), (), weight);
+        self.resize_and_update_size_metrics(&mut entries);
+    }
+
+    /// Removes any cached data for `key`.
+    fn remove(&self, key: &str) {
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        if entries.remove(key).is_some() {
+            let _ = std::fs::remove_file(Self::path(&self.dir, key));
+        }
+        self.resize_and_update_size_metrics(&mut entries);
+    }
+
+    fn write(
+        tmp_path: &Path,
+        path: &Path,
+        key: &[u8],
+        data: SegmentedBytes,
+    ) -> Result<(), std::io::Error> {
+        let key_len = u32::try_from(key.len())
+            .map_err(|_| std::io::Error::other(format!("key too long: {}", key.len())))?
+            .to_le_bytes();
+        let segments = data.into_segments().collect::<Vec<_>>();
+        let mut checksum = crc32fast::Hasher::new();
+        checksum.update(&key_len);
+        checksum.update(key);
+        for segment in segments.iter() {
+            checksum.update(segment);
+        }
+        let mut file = std::io::BufWriter::new(std::fs::File::create(tmp_path)?);
+        file.write_all(&checksum.finalize().to_le_bytes())?;
+        file.write_all(&key_len)?;
+        file.write_all(key)?;
+        for segment in segments.iter() {
+            file.write_all(segment)?;
+        }
+        file.flush()?;
+        std::fs::rename(tmp_path, path)
+    }
+
+    /// Returns the data in `buf`, if it passes its checksum and was stored
+    /// under `key`.
+    fn decode(key: &str, buf: Vec<u8>) -> Option<Bytes> {
+        if buf.len() < Self::HEADER_LEN {
+            return None;
+        }
+        let buf = Bytes::from(buf);
+        let checksum =
+            u32::from_le_bytes(buf[..Self::CHECKSUM_LEN].try_into().expect("checksum len"));
+        if crc32fast::hash(&buf[Self::CHECKSUM_LEN..]) != checksum {
+            return None;
+        }
+        let key_len = u32::from_le_bytes(
+            buf[Self::CHECKSUM_LEN..Self::HEADER_LEN]
+                .try_into()
+                .expect("key len len"),
+        );
+        let data_start = Self::HEADER_LEN.checked_add(usize::cast_from(key_len))?;
+        let stored_key = buf.get(Self::HEADER_LEN..data_start)?;
+        (stored_key == key.as_bytes()).then(|| buf.slice(data_start..))
+    }
+}
+
+/// On-disk cache for [Blob], backed by a process-wide [DiskCache].
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    cache: Arc<DiskCache>,
+    blob: Arc<dyn Blob>,
+}
+
+impl BlobDiskCache {
+    pub fn new(cache: Arc<DiskCache>, blob: Arc<dyn Blob>) -> Arc<dyn Blob> {
+        Arc::new(BlobDiskCache { cache, blob })
+    }
+}
+
+#[async_trait]
+impl Blob for BlobDiskCache {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        if !BLOB_CACHE_DISK_ENABLED.get(&self.cache.cfg) {
+            return self.blob.get(key).await;
+        }
+        // As with BlobMemCache, blobs are write-once modify-never, so a cached
+        // value is guaranteed to match the one in blob.
+        if let Some(cached_value) = self.cache.get(key).await {
+            return Ok(Some(cached_value));
+        }
+        self.cache.metrics.blob_cache_disk.misses_blobs.inc();
+
+        let res = self.blob.get(key).await?;
+        if let Some(blob) = res.as_ref() {
+            self.cache.insert(key, blob).await;
+        }
+        Ok(res)
+    }
+
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        if BLOB_CACHE_DISK_ENABLED.get(&self.cache.cfg) {
+            if let Some(cached_value) = self.cache.get(key).await {
+                return value_range(key, cached_value, range).map(Some);
+            }
+        }
+        // Partial values aren't cached.
+        self.blob.get_range(key, range).await
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        // Intentionally not cached: anything this process reads back soon
+        // after writing is served by the in-mem cache, and a write is not a
+        // good signal that a blob will be read again later.
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let res = self.blob.delete(key).await;
+        self.cache.remove(key);
+        res
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
 mod lru {
     use std::borrow::Borrow;
     use std::collections::BTreeMap;
@@ -411,11 +799,14 @@ mod lru {
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_none;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
     use proptest::arbitrary::any;
     use proptest::proptest;
     use proptest_derive::Arbitrary;
 
     use super::lru::*;
+    use super::*;
 
     #[derive(Debug, Arbitrary)]
     enum LruOp {
@@ -555,4 +946,105 @@ mod tests {
         assert_eq!(cache.entry_weight(), 2);
         assert_eq!(cache.keys(), &["j", "i"]);
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `mkdir` on OS `linux`
+    async fn blob_disk_cache() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cfg = PersistConfig::new_for_tests();
+        cfg.set_config(&BLOB_CACHE_DISK_ENABLED, true);
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let open = || {
+            let cache =
+                DiskCache::open(&cfg, Arc::clone(&metrics), dir.path().to_owned()).expect("open");
+            Arc::new(cache)
+        };
+        let underlying: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::new(false)));
+        let get = |blob: &Arc<dyn Blob>, key: &'static str| {
+            let blob = Arc::clone(blob);
+            async move {
+                blob.get(key)
+                    .await
+                    .expect("get")
+                    .map(|x| x.into_contiguous())
+            }
+        };
+        underlying.set("k1", Bytes::from("v1")).await.expect("set");
+        underlying.set("k2", Bytes::from("v22")).await.expect("set");
+
+        // Miss, then hit.
+        let blob = BlobDiskCache::new(open(), Arc::clone(&underlying));
+        assert_eq!(get(&blob, "k1").await, Some(b"v1".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.misses_blobs.get(), 1);
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 0);
+        assert_eq!(get(&blob, "k1").await, Some(b"v1".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.misses_blobs.get(), 1);
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 1);
+        assert_eq!(metrics.blob_cache_disk.hits_bytes.get(), 2);
+        assert_eq!(get(&blob, "missing").await, None);
+
+        // Cached data survives a restart.
+        drop(blob);
+        let blob = BlobDiskCache::new(open(), Arc::clone(&underlying));
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 1);
+        assert_eq!(get(&blob, "k1").await, Some(b"v1".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 2);
+
+        // A corrupted entry is discarded and refetched.
+        let path = DiskCache::path(dir.path(), "k1");
+        let mut buf = std::fs::read(&path).expect("read");
+        *buf.last_mut().expect("nonempty") ^= 1;
+        std::fs::write(&path, buf).expect("write");
+        assert_eq!(get(&blob, "k1").await, Some(b"v1".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.checksum_failures.get(), 1);
+        assert_eq!(get(&blob, "k1").await, Some(b"v1".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 3);
+
+        // An entry stored under a different key is never returned.
+        let buf = std::fs::read(&path).expect("read");
+        std::fs::write(DiskCache::path(dir.path(), "k2"), &buf).expect("write");
+        drop(blob);
+        let blob = BlobDiskCache::new(open(), Arc::clone(&underlying));
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 1);
+        assert!(!DiskCache::path(dir.path(), "k2").exists());
+        assert_eq!(DiskCache::decode("k2", buf.clone()), None);
+        assert_eq!(DiskCache::decode("k1", buf), Some(Bytes::from("v1")));
+
+        // Keys too long to be file names are cached all the same.
+        let long_key = "k".repeat(1024);
+        underlying
+            .set(&long_key, Bytes::from("v3"))
+            .await
+            .expect("set");
+        let long = blob.get(&long_key).await.expect("get");
+        assert_eq!(long.map(|x| x.into_contiguous()), Some(b"v3".to_vec()));
+        let long = blob.get(&long_key).await.expect("get");
+        assert_eq!(long.map(|x| x.into_contiguous()), Some(b"v3".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.hits_blobs.get(), 4);
+        blob.delete(&long_key).await.expect("delete");
+
+        // Leftover partial writes are cleaned up on restart.
+        let tmp_path = dir
+            .path()
+            .join(format!("{}.tmp", DiskCache::file_name("k2")));
+        std::fs::write(&tmp_path, b"partial").expect("write");
+        drop(blob);
+        let blob = BlobDiskCache::new(open(), Arc::clone(&underlying));
+        assert!(!tmp_path.exists());
+
+        // Shrinking the limit evicts the least recently used entries.
+        cfg.set_config(
+            &BLOB_CACHE_DISK_LIMIT_BYTES,
+            DiskCache::HEADER_LEN + "k2".len() + "v22".len(),
+        );
+        assert_eq!(get(&blob, "k2").await, Some(b"v22".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 1);
+        assert_eq!(metrics.blob_cache_disk.evictions.get(), 1);
+        assert!(!path.exists());
+
+        // Deletes remove the cached copy.
+        blob.delete("k2").await.expect("delete");
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 0);
+        assert_eq!(get(&blob, "k2").await, None);
+    }
 }
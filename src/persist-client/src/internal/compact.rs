@@ -25,7 +25,9 @@ use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
 use mz_persist::location::Blob;
 use mz_persist_types::part::Part;
+use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
+use mz_proto::{ProtoType, RustType};
 use timely::PartialOrder;
 use timely::progress::{Antichain, Timestamp};
 use tokio::sync::mpsc::Sender;
@@ -45,6 +47,10 @@ use crate::internal::gc::GarbageCollector;
 use crate::internal::machine::Machine;
 use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::ShardMetrics;
+use crate::internal::service::{
+    ProtoApplyMergeResult, ProtoCompactionRequest, ProtoCompactionResponse,
+    proto_compaction_response,
+};
 use crate::internal::state::{HollowBatch, RunMeta, RunOrder, RunPart};
 use crate::internal::trace::{ApplyMergeResult, FueledMergeRes};
 use crate::iter::{Consolidator, StructuredSort};
@@ -81,6 +87,10 @@ pub struct CompactConfig {
     pub(crate) compaction_yield_after_n_updates: usize,
     pub(crate) version: semver::Version,
     pub(crate) batch: BatchBuilderConfig,
+    /// Runs written with a schema before this one must be re-sorted before
+    /// they're consolidated. See
+    /// [crate::internal::state::StateCollections::resort_runs_before].
+    pub(crate) resort_runs_before: Option<SchemaId>,
 }
 
 impl CompactConfig {
@@ -91,19 +101,25 @@ impl CompactConfig {
             compaction_yield_after_n_updates: value.compaction_yield_after_n_updates,
             version: value.build_version.clone(),
             batch: BatchBuilderConfig::new(value, shard_id),
+            resort_runs_before: None,
         }
     }
 }
 
 /// A service for performing physical and logical compaction.
 ///
-/// This will possibly be called over RPC in the future. Physical compaction is
-/// merging adjacent batches. Logical compaction is advancing timestamps to a
-/// new since and consolidating the resulting updates.
+/// Physical compaction is merging adjacent batches. Logical compaction is
+/// advancing timestamps to a new since and consolidating the resulting updates.
+///
+/// Requests are performed in-process, unless `persist_compaction_service_enabled`
+/// is set, in which case they are handed off over PubSub to a standalone
+/// compaction service (see [crate::compaction_service]). Requests the service
+/// doesn't ack in time are still performed in-process.
 #[derive(Debug)]
 pub struct Compactor<K, V, T, D> {
     cfg: PersistConfig,
     metrics: Arc<Metrics>,
+    write_schema_id: Option<SchemaId>,
     sender: Sender<(
         Instant,
         CompactReq<T>,
@@ -118,6 +134,7 @@ impl<K, V, T, D> Clone for Compactor<K, V, T, D> {
         Compactor {
             cfg: self.cfg.clone(),
             metrics: Arc::clone(&self.metrics),
+            write_schema_id: self.write_schema_id,
             sender: self.sender.clone(),
             _phantom: Default::default(),
         }
@@ -144,6 +161,23 @@ pub(crate) const COMPACTION_USE_MOST_RECENT_SCHEMA: Config<bool> = Config::new(
     ",
 );
 
+pub(crate) const COMPACTION_SERVICE_ENABLED: Config<bool> = Config::new(
+    "persist_compaction_service_enabled",
+    false,
+    "\
+    Whether to send compaction requests over Persist PubSub to a standalone \
+    compaction service, instead of performing them in-process (Materialize).",
+);
+
+pub(crate) const COMPACTION_SERVICE_RESPONSE_TIMEOUT: Config<Duration> = Config::new(
+    "persist_compaction_service_response_timeout",
+    Duration::from_secs(30),
+    "\
+    How long to wait for a compaction service to respond to a request, on top \
+    of the timeout of the compaction itself, before performing it in-process \
+    instead (Materialize).",
+);
+
 pub(crate) const COMPACTION_CHECK_PROCESS_FLAG: Config<bool> = Config::new(
     "persist_compaction_check_process_flag",
     true,
@@ -151,6 +185,22 @@ pub(crate) const COMPACTION_CHECK_PROCESS_FLAG: Config<bool> = Config::new(
         which allows dynamically disabling compaction. If false, all compaction requests will be processed.",
 );
 
+/// Picks a timeout for a compaction request proportional to the amount of data
+/// that must be read (with a minimum set by PersistConfig).
+fn compaction_timeout<T>(cfg: &PersistConfig, req: &CompactReq<T>) -> Duration {
+    let total_input_bytes = req
+        .inputs
+        .iter()
+        .map(|batch| batch.encoded_size_bytes())
+        .sum::<usize>();
+    Duration::max(
+        // either our minimum timeout
+        COMPACTION_MINIMUM_TIMEOUT.get(cfg),
+        // or 1s per MB of input data
+        Duration::from_secs(u64::cast_from(total_input_bytes / MiB)),
+    )
+}
+
 impl<K, V, T, D> Compactor<K, V, T, D>
 where
     K: Debug + Codec,
@@ -175,6 +225,7 @@ where
         ));
         let check_process_requests = COMPACTION_CHECK_PROCESS_FLAG.handle(&cfg.configs);
         let process_requests = Arc::clone(&cfg.compaction_process_requests);
+        let write_schema_id = write_schemas.id;
 
         // spin off a single task responsible for executing compaction requests.
         // work is enqueued into the task through a channel
@@ -251,6 +302,7 @@ where
         Compactor {
             cfg,
             metrics,
+            write_schema_id,
             sender: compact_req_sender,
             _phantom: PhantomData,
         }
@@ -280,6 +332,116 @@ where
             return None;
         }
 
+        self.compact_and_apply_background_unchecked(req, machine)
+    }
+
+    /// The same as [Self::compact_and_apply_background], but without the
+    /// heuristics that skip small requests, for requests that rewrite data
+    /// rather than merge it (e.g. to re-encrypt it).
+    pub(crate) fn compact_and_apply_background_unchecked(
+        &self,
+        req: CompactReq<T>,
+        machine: &Machine<K, V, T, D>,
+    ) -> Option<oneshot::Receiver<Result<ApplyMergeResult, anyhow::Error>>> {
+        if COMPACTION_SERVICE_ENABLED.get(&self.cfg) {
+            // Mirror the in-process worker, which won't perform requests while
+            // compaction is disabled (e.g. before a cluster is allowed to write).
+            if COMPACTION_CHECK_PROCESS_FLAG.get(&self.cfg)
+                && !self
+                    .cfg
+                    .compaction_process_requests
+                    .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                self.metrics.compaction.disabled.inc();
+                return None;
+            }
+            return Some(self.offload(req, machine));
+        }
+
+        self.enqueue(req, machine)
+    }
+
+    /// Hands a [CompactReq] to a compaction service, falling back to enqueueing
+    /// it in-process if the service nacks it or doesn't respond in time.
+    fn offload(
+        &self,
+        req: CompactReq<T>,
+        machine: &Machine<K, V, T, D>,
+    ) -> oneshot::Receiver<Result<ApplyMergeResult, anyhow::Error>> {
+        let timeout = compaction_timeout(&self.cfg, &req)
+            + COMPACTION_SERVICE_RESPONSE_TIMEOUT.get(&self.cfg);
+        let response = machine
+            .applier
+            .send_compaction_request(ProtoCompactionRequest {
+                req: Some(req.into_proto()),
+                key_codec: K::codec_name(),
+                val_codec: V::codec_name(),
+                ts_codec: T::codec_name(),
+                diff_codec: D::codec_name(),
+                schema_id: self.write_schema_id.map(|id| id.into_proto()),
+                // Assigned by the PubSub sender.
+                request_id: 0,
+                dyncfgs: Some(self.cfg.config_updates()),
+            });
+        self.metrics.compaction.offloaded.inc();
+
+        let (compaction_completed_sender, compaction_completed_receiver) = oneshot::channel();
+        let compactor = self.clone();
+        let machine = machine.clone();
+        mz_ore::task::spawn(|| "PersistCompactionOffload", async move {
+            let reason = match tokio::time::timeout(timeout, response).await {
+                Ok(Ok(response)) => match Self::decode_response(response) {
+                    Ok(res) => {
+                        let _ = compaction_completed_sender.send(Ok(res));
+                        return;
+                    }
+                    Err(reason) => reason,
+                },
+                Ok(Err(_)) => "lost connection to the PubSub server".to_string(),
+                Err(_) => format!("no response within {:?}", timeout),
+            };
+
+            // It's possible the service still performs a request we've given
+            // up on, in which case only one of the results is applied.
+            compactor.metrics.compaction.offload_fallback.inc();
+            debug!(
+                "compacting {} in-process, as the compaction service didn't ack: {}",
+                machine.shard_id(),
+                reason
+            );
+            // If the in-process queue is full, the request is dropped, same
+            // as if it had been enqueued directly.
+            let Some(receiver) = compactor.enqueue(req, &machine) else {
+                return;
+            };
+            if let Ok(res) = receiver.await {
+                let _ = compaction_completed_sender.send(res);
+            }
+        });
+
+        compaction_completed_receiver
+    }
+
+    /// Returns the result of an acked request, or the reason it wasn't acked.
+    fn decode_response(response: ProtoCompactionResponse) -> Result<ApplyMergeResult, String> {
+        match response.response {
+            Some(proto_compaction_response::Response::Ack(ack)) => {
+                let res = ProtoApplyMergeResult::try_from(ack.result)
+                    .map_err(|err| format!("invalid ack: {}", err))?;
+                res.into_rust()
+                    .map_err(|err| format!("invalid ack: {}", err))
+            }
+            Some(proto_compaction_response::Response::Nack(nack)) => Err(nack.reason),
+            None => Err("empty response".to_string()),
+        }
+    }
+
+    /// Enqueues a [CompactReq] to be performed in-process.
+    fn enqueue(
+        &self,
+        req: CompactReq<T>,
+        machine: &Machine<K, V, T, D>,
+    ) -> Option<oneshot::Receiver<Result<ApplyMergeResult, anyhow::Error>>> {
         let (compaction_completed_sender, compaction_completed_receiver) = oneshot::channel();
         let new_compaction_sender = self.sender.clone();
 
@@ -312,19 +474,12 @@ where
         metrics.compaction.started.inc();
         let start = Instant::now();
 
-        // pick a timeout for our compaction request proportional to the amount
-        // of data that must be read (with a minimum set by PersistConfig)
         let total_input_bytes = req
             .inputs
             .iter()
             .map(|batch| batch.encoded_size_bytes())
             .sum::<usize>();
-        let timeout = Duration::max(
-            // either our minimum timeout
-            COMPACTION_MINIMUM_TIMEOUT.get(&machine.applier.cfg),
-            // or 1s per MB of input data
-            Duration::from_secs(u64::cast_from(total_input_bytes / MiB)),
-        );
+        let timeout = compaction_timeout(&machine.applier.cfg, &req);
         // always use most recent schema from all the Runs we're compacting to prevent Compactors
         // created before the schema was evolved, from trying to "de-evolve" a Part.
         let compaction_schema_id = req
@@ -361,6 +516,11 @@ where
             }
         };
 
+        let mut compact_cfg = CompactConfig::new(&machine.applier.cfg, machine.shard_id());
+        compact_cfg.resort_runs_before = compaction_schema
+            .id
+            .and_then(|id| machine.applier.resort_runs_before(id));
+
         trace!(
             "compaction request for {}MBs ({} bytes), with timeout of {}s, and schema {:?}.",
             total_input_bytes / MiB,
@@ -378,7 +538,7 @@ where
                 .spawn_named(
                     || "persist::compact::consolidate",
                     Self::compact(
-                        CompactConfig::new(&machine.applier.cfg, machine.shard_id()),
+                        compact_cfg,
                         Arc::clone(&machine.applier.state_versions.blob),
                         Arc::clone(&metrics),
                         Arc::clone(&machine.applier.shard_metrics),
@@ -814,6 +974,7 @@ where
                 since: desc.since().clone(),
             },
             prefetch_budget_bytes,
+            cfg.resort_runs_before,
         );
 
         for (desc, meta, parts) in runs {
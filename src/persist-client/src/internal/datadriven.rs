@@ -113,6 +113,7 @@ impl<'a> DirectiveArgs<'a> {
                         format: None,
                         schema_id: None,
                         deprecated_schema_id: None,
+                        encryption_key_id: None,
                     }))
                 })
                 .collect(),
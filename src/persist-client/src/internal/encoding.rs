@@ -37,8 +37,10 @@ use uuid::Uuid;
 
 use crate::critical::CriticalReaderId;
 use crate::error::{CodecMismatch, CodecMismatchT};
+use crate::internal::compact::CompactReq;
 use crate::internal::metrics::Metrics;
 use crate::internal::paths::{PartialBatchKey, PartialRollupKey};
+use crate::internal::service::{ProtoApplyMergeResult, ProtoCompactReq};
 use crate::internal::state::{
     ActiveGc, ActiveRollup, BatchPart, CriticalReaderState, EncodedSchemas, HandleDebugState,
     HollowBatch, HollowBatchPart, HollowRollup, HollowRun, HollowRunRef, IdempotencyToken,
@@ -56,7 +58,7 @@ use crate::internal::state_diff::{
     ProtoStateFieldDiff, ProtoStateFieldDiffsWriter, StateDiff, StateFieldDiff, StateFieldValDiff,
 };
 use crate::internal::trace::{
-    ActiveCompaction, FlatTrace, SpineId, ThinMerge, ThinSpineBatch, Trace,
+    ActiveCompaction, ApplyMergeResult, FlatTrace, SpineId, ThinMerge, ThinSpineBatch, Trace,
 };
 use crate::read::{LeasedReaderId, READER_LEASE_DURATION};
 use crate::{PersistConfig, ShardId, WriterId, cfg};
@@ -1372,6 +1374,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatch> for HollowBatch<T> {
                 format: None,
                 schema_id: None,
                 deprecated_schema_id: None,
+                encryption_key_id: None,
             }))
         }));
         // We discard default metadatas from the proto above; re-add them here.
@@ -1393,6 +1396,54 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatch> for HollowBatch<T> {
     }
 }
 
+impl<T: Timestamp + Codec64> RustType<ProtoCompactReq> for CompactReq<T> {
+    fn into_proto(&self) -> ProtoCompactReq {
+        ProtoCompactReq {
+            shard_id: self.shard_id.into_proto(),
+            desc: Some(self.desc.into_proto()),
+            inputs: self.inputs.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoCompactReq) -> Result<Self, TryFromProtoError> {
+        Ok(CompactReq {
+            shard_id: proto.shard_id.into_rust()?,
+            desc: proto.desc.into_rust_if_some("desc")?,
+            inputs: proto.inputs.into_rust()?,
+        })
+    }
+}
+
+impl RustType<ProtoApplyMergeResult> for ApplyMergeResult {
+    fn into_proto(&self) -> ProtoApplyMergeResult {
+        match self {
+            ApplyMergeResult::AppliedExact => ProtoApplyMergeResult::AppliedExact,
+            ApplyMergeResult::AppliedSubset => ProtoApplyMergeResult::AppliedSubset,
+            ApplyMergeResult::NotAppliedNoMatch => ProtoApplyMergeResult::NotAppliedNoMatch,
+            ApplyMergeResult::NotAppliedInvalidSince => {
+                ProtoApplyMergeResult::NotAppliedInvalidSince
+            }
+            ApplyMergeResult::NotAppliedTooManyUpdates => {
+                ProtoApplyMergeResult::NotAppliedTooManyUpdates
+            }
+        }
+    }
+
+    fn from_proto(proto: ProtoApplyMergeResult) -> Result<Self, TryFromProtoError> {
+        Ok(match proto {
+            ProtoApplyMergeResult::AppliedExact => ApplyMergeResult::AppliedExact,
+            ProtoApplyMergeResult::AppliedSubset => ApplyMergeResult::AppliedSubset,
+            ProtoApplyMergeResult::NotAppliedNoMatch => ApplyMergeResult::NotAppliedNoMatch,
+            ProtoApplyMergeResult::NotAppliedInvalidSince => {
+                ApplyMergeResult::NotAppliedInvalidSince
+            }
+            ProtoApplyMergeResult::NotAppliedTooManyUpdates => {
+                ApplyMergeResult::NotAppliedTooManyUpdates
+            }
+        })
+    }
+}
+
 impl RustType<ProtoRunMeta> for RunMeta {
     fn into_proto(&self) -> ProtoRunMeta {
         let order = match self.order {
@@ -1457,6 +1508,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for HollowRunRef<T>
             schema_id: None,
             structured_key_lower: self.structured_key_lower.into_proto(),
             deprecated_schema_id: None,
+            encryption_key_id: None,
         };
         part
     }
@@ -1494,6 +1546,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for BatchPart<T> {
                 format: x.format.map(|f| f.into_proto()),
                 schema_id: x.schema_id.into_proto(),
                 deprecated_schema_id: x.deprecated_schema_id.into_proto(),
+                encryption_key_id: x.encryption_key_id.clone(),
             },
             BatchPart::Inline {
                 updates,
@@ -1511,6 +1564,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for BatchPart<T> {
                 format: None,
                 schema_id: schema_id.into_proto(),
                 deprecated_schema_id: deprecated_schema_id.into_proto(),
+                encryption_key_id: None,
             },
         }
     }
@@ -1535,6 +1589,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatchPart> for BatchPart<T> {
                     format: proto.format.map(|f| f.into_rust()).transpose()?,
                     schema_id,
                     deprecated_schema_id,
+                    encryption_key_id: proto.encryption_key_id,
                 }))
             }
             Some(proto_hollow_batch_part::Kind::Inline(x)) => {
@@ -1902,6 +1957,7 @@ mod tests {
                 format: None,
                 schema_id: None,
                 deprecated_schema_id: None,
+                encryption_key_id: None,
             }))],
             4,
         );
@@ -1929,6 +1985,7 @@ mod tests {
                 format: None,
                 schema_id: None,
                 deprecated_schema_id: None,
+                encryption_key_id: None,
             })));
         assert_eq!(<HollowBatch<u64>>::from_proto(old).unwrap(), expected);
     }
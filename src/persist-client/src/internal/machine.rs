@@ -41,7 +41,7 @@ use crate::cfg::RetryParameters;
 use crate::critical::CriticalReaderId;
 use crate::error::{CodecMismatch, InvalidUsage};
 use crate::internal::apply::Applier;
-use crate::internal::compact::CompactReq;
+use crate::internal::compact::{CompactReq, Compactor};
 use crate::internal::gc::GarbageCollector;
 use crate::internal::maintenance::{RoutineMaintenance, WriterMaintenance};
 use crate::internal::metrics::{CmdMetrics, Metrics, MetricsRetryStream, RetryMetrics};
@@ -97,6 +97,13 @@ pub(crate) const CLAIM_COMPACTION_MIN_VERSION: Config<String> = Config::new(
     "If set to a valid version string, compact away any earlier versions if possible.",
 );
 
+pub(crate) const BLOB_REENCRYPTION_INTERVAL: Config<Duration> = Config::new(
+    "persist_blob_reencryption_interval",
+    Duration::from_secs(5 * 60),
+    "How often a writer checks its shard for blob data that isn't encrypted with the current \
+    key, and compacts it to re-encrypt it.",
+);
+
 impl<K, V, T, D> Machine<K, V, T, D>
 where
     K: Debug + Codec,
@@ -283,6 +290,77 @@ where
         (reqs, maintenance)
     }
 
+    /// Claims the compaction of every batch in the shard with parts that are
+    /// stored in blob, but not encrypted with the current key of the blob
+    /// encryption keyring, and returns requests to rewrite them with it.
+    ///
+    /// Batches whose compaction another writer has claimed within the writer
+    /// lease duration are skipped.
+    pub async fn reencryption_reqs(&self) -> (Vec<CompactReq<T>>, RoutineMaintenance) {
+        let Some(keyring) = self.applier.cfg.blob_encryption_keyring.as_ref() else {
+            return (Vec::new(), RoutineMaintenance::default());
+        };
+        let key_id = keyring.current_key_id();
+        let metrics = Arc::clone(&self.applier.metrics);
+        let lease_duration_ms: u64 = self
+            .applier
+            .cfg
+            .writer_lease_duration
+            .as_millis()
+            .try_into()
+            .expect("reasonable duration");
+        let now_ms = (self.applier.cfg.now)();
+
+        self.applier.fetch_and_update_state(None).await;
+        let threshold_ms = now_ms.saturating_sub(lease_duration_ms);
+        let blob = &*self.applier.state_versions.blob;
+        let mut ids = Vec::new();
+        'reqs: for req in self.applier.all_merge_reqs_before_ms(threshold_ms) {
+            for part in req.inputs.iter().flat_map(|b| b.batch.parts.iter()) {
+                match part
+                    .needs_reencryption(self.shard_id(), blob, &metrics, key_id)
+                    .await
+                {
+                    Ok(false) => {}
+                    Ok(true) => {
+                        ids.push(req.id);
+                        continue 'reqs;
+                    }
+                    // The batch was compacted away, and the run GC'd, since
+                    // we fetched state.
+                    Err(_) => continue 'reqs,
+                }
+            }
+        }
+        if ids.is_empty() {
+            return (Vec::new(), RoutineMaintenance::default());
+        }
+
+        let (_seqno, reqs, maintenance) = self
+            .apply_unbatched_idempotent_cmd(
+                &metrics.cmds.claim_compactions,
+                |_seqno, _cfg, state| state.claim_compactions(&ids, now_ms, lease_duration_ms),
+            )
+            .await;
+        metrics
+            .compaction
+            .reencryption_count
+            .inc_by(u64::cast_from(reqs.len()));
+        let reqs = reqs
+            .into_iter()
+            .map(|req| CompactReq {
+                shard_id: self.shard_id(),
+                desc: req.desc,
+                inputs: req
+                    .inputs
+                    .into_iter()
+                    .map(|b| Arc::unwrap_or_clone(b.batch))
+                    .collect(),
+            })
+            .collect();
+        (reqs, maintenance)
+    }
+
     pub async fn compare_and_append(
         &self,
         batch: &HollowBatch<T>,
@@ -1291,6 +1369,49 @@ where
             }
         }
     }
+
+    /// Starts a task that periodically compacts the batches of this shard
+    /// that aren't encrypted with the current key of the blob encryption
+    /// keyring, so that they are re-encrypted with it even if the shard is
+    /// never written to again.
+    pub(crate) fn start_reencryption_task(
+        self,
+        gc: GarbageCollector<K, V, T, D>,
+        compactor: Compactor<K, V, T, D>,
+    ) -> JoinHandle<()>
+    where
+        D: Ord,
+    {
+        let name = format!("persist::reencrypt({})", self.shard_id());
+        mz_ore::task::spawn(|| name, Self::reencryption_task(self, gc, compactor))
+    }
+
+    async fn reencryption_task(
+        machine: Self,
+        gc: GarbageCollector<K, V, T, D>,
+        compactor: Compactor<K, V, T, D>,
+    ) where
+        D: Ord,
+    {
+        loop {
+            tokio::time::sleep(BLOB_REENCRYPTION_INTERVAL.get(&machine.applier.cfg)).await;
+
+            let (reqs, maintenance) = machine.reencryption_reqs().await;
+            maintenance.start_performing(&machine, &gc);
+            // Re-encrypt one batch at a time, so that a shard with a lot of
+            // old data doesn't crowd out regular compaction.
+            for req in reqs {
+                let Some(receiver) =
+                    compactor.compact_and_apply_background_unchecked(req, &machine)
+                else {
+                    continue;
+                };
+                // Failures are logged by the compactor, and the batch is
+                // retried once its claim expires.
+                let _ = receiver.await;
+            }
+        }
+    }
 }
 
 pub(crate) const NEXT_LISTEN_BATCH_RETRYER_FIXED_SLEEP: Config<Duration> = Config::new(
@@ -12,6 +12,7 @@
 use async_stream::stream;
 use mz_persist_types::stats::PartStatsMetrics;
 use std::collections::BTreeMap;
+use std::ops::Range;
 use std::sync::{Arc, Mutex, Weak};
 use std::time::{Duration, Instant};
 use tokio::sync::{OnceCell, OwnedSemaphorePermit, Semaphore};
@@ -94,6 +95,8 @@ pub struct Metrics {
     pub consolidation: ConsolidationMetrics,
     /// Metrics for blob caching.
     pub blob_cache_mem: BlobMemCache,
+    /// Metrics for on-disk blob caching.
+    pub blob_cache_disk: BlobDiskCache,
     /// Metrics for tokio tasks.
     pub tasks: TasksMetrics,
     /// Metrics for columnar data encoding and decoding.
@@ -166,6 +169,7 @@ impl Metrics {
             pushdown: PushdownMetrics::new(registry),
             consolidation: ConsolidationMetrics::new(registry),
             blob_cache_mem: BlobMemCache::new(registry),
+            blob_cache_disk: BlobDiskCache::new(registry),
             tasks: TasksMetrics::new(registry),
             columnar,
             schema: SchemaMetrics::new(registry),
@@ -428,6 +432,7 @@ impl MetricsVecs {
             become_tombstone: self.cmd_metrics("become_tombstone"),
             compare_and_evolve_schema: self.cmd_metrics("compare_and_evolve_schema"),
             spine_exert: self.cmd_metrics("spine_exert"),
+            claim_compactions: self.cmd_metrics("claim_compactions"),
             fetch_upper_count: registry.register(metric!(
                 name: "mz_persist_cmd_fetch_upper_count",
                 help: "count of fetch_upper calls",
@@ -458,6 +463,7 @@ impl MetricsVecs {
                 compaction_noop_delete: Arc::new(self.retry_metrics("compaction_noop::delete")),
                 consensus_open: self.retry_metrics("consensus::open"),
                 fetch_batch_get: self.retry_metrics("fetch_batch::get"),
+                fetch_batch_get_range: self.retry_metrics("fetch_batch::get_range"),
                 fetch_state_scan: self.retry_metrics("fetch_state::scan"),
                 gc_truncate: self.retry_metrics("gc::truncate"),
                 maybe_init_cas: self.retry_metrics("maybe_init::cas"),
@@ -637,6 +643,7 @@ pub struct CmdsMetrics {
     pub(crate) become_tombstone: CmdMetrics,
     pub(crate) compare_and_evolve_schema: CmdMetrics,
     pub(crate) spine_exert: CmdMetrics,
+    pub(crate) claim_compactions: CmdMetrics,
     pub(crate) fetch_upper_count: IntCounter,
 }
 
@@ -668,6 +675,7 @@ pub struct RetryExternal {
     pub(crate) compaction_noop_delete: Arc<RetryMetrics>,
     pub(crate) consensus_open: RetryMetrics,
     pub(crate) fetch_batch_get: RetryMetrics,
+    pub(crate) fetch_batch_get_range: RetryMetrics,
     pub(crate) fetch_state_scan: RetryMetrics,
     pub(crate) gc_truncate: RetryMetrics,
     pub(crate) maybe_init_cas: RetryMetrics,
@@ -810,6 +818,11 @@ pub struct CompactionMetrics {
     pub(crate) parts_waited: IntCounter,
     pub(crate) fast_path_eligible: IntCounter,
     pub(crate) admin_count: IntCounter,
+    pub(crate) reencryption_count: IntCounter,
+    pub(crate) offloaded: IntCounter,
+    pub(crate) offload_fallback: IntCounter,
+    pub(crate) service_count: IntCounter,
+    pub(crate) service_unknown_codecs: IntCounter,
 
     pub(crate) applied_exact_match: IntCounter,
     pub(crate) applied_subset_match: IntCounter,
@@ -916,6 +929,26 @@ impl CompactionMetrics {
                 name: "mz_persist_compaction_admin_count",
                 help: "count of compaction requests that were performed by admin tooling",
             )),
+            reencryption_count: registry.register(metric!(
+                name: "mz_persist_compaction_reencryption_count",
+                help: "count of compaction requests that were made to re-encrypt blob data with the current key",
+            )),
+            offloaded: registry.register(metric!(
+                name: "mz_persist_compaction_offloaded",
+                help: "count of compaction requests sent to a compaction service",
+            )),
+            offload_fallback: registry.register(metric!(
+                name: "mz_persist_compaction_offload_fallback",
+                help: "count of compaction requests performed in-process after a compaction service didn't ack them",
+            )),
+            service_count: registry.register(metric!(
+                name: "mz_persist_compaction_service_count",
+                help: "count of compaction requests that were performed by a compaction service",
+            )),
+            service_unknown_codecs: registry.register(metric!(
+                name: "mz_persist_compaction_service_unknown_codecs",
+                help: "count of compaction requests a compaction service nacked for unknown codecs",
+            )),
             applied_exact_match: registry.register(metric!(
                 name: "mz_persist_compaction_applied_exact_match",
                 help: "count of merge results that exactly replaced a SpineBatch",
@@ -2072,6 +2105,11 @@ pub struct PubSubServerMetrics {
     pub(crate) broadcasted_diff_count: IntCounter,
     pub(crate) broadcasted_diff_bytes: IntCounter,
     pub(crate) broadcasted_diff_dropped_channel_full: IntCounter,
+    pub(crate) active_compactors: UIntGauge,
+    pub(crate) compact_forwarded_count: IntCounter,
+    pub(crate) compact_nacked_no_compactor: IntCounter,
+    pub(crate) compact_nacked_channel_full: IntCounter,
+    pub(crate) compact_nacked_compactor_disconnected: IntCounter,
 
     pub(crate) push_seconds: Counter,
     pub(crate) subscribe_seconds: Counter,
@@ -2081,6 +2119,9 @@ pub struct PubSubServerMetrics {
     pub(crate) push_call_count: IntCounter,
     pub(crate) subscribe_call_count: IntCounter,
     pub(crate) unsubscribe_call_count: IntCounter,
+    pub(crate) compact_call_count: IntCounter,
+    pub(crate) compaction_response_call_count: IntCounter,
+    pub(crate) register_compactor_call_count: IntCounter,
 }
 
 impl PubSubServerMetrics {
@@ -2113,6 +2154,26 @@ impl PubSubServerMetrics {
                     name: "mz_persist_pubsub_server_broadcasted_diff_dropped_channel_full",
                     help: "count of diffs dropped due to full connection channel",
             )),
+            active_compactors: registry.register(metric!(
+                    name: "mz_persist_pubsub_server_active_compactors",
+                    help: "number of connections registered to receive compaction requests",
+            )),
+            compact_forwarded_count: registry.register(metric!(
+                    name: "mz_persist_pubsub_server_compact_forwarded_count",
+                    help: "count of compaction requests forwarded to a compactor",
+            )),
+            compact_nacked_no_compactor: registry.register(metric!(
+                    name: "mz_persist_pubsub_server_compact_nacked_no_compactor",
+                    help: "count of compaction requests nacked because no compactor was registered",
+            )),
+            compact_nacked_channel_full: registry.register(metric!(
+                    name: "mz_persist_pubsub_server_compact_nacked_channel_full",
+                    help: "count of compaction requests nacked due to full connection channel",
+            )),
+            compact_nacked_compactor_disconnected: registry.register(metric!(
+                    name: "mz_persist_pubsub_server_compact_nacked_compactor_disconnected",
+                    help: "count of compaction requests nacked because their compactor disconnected",
+            )),
 
             push_seconds: op_timings.with_label_values(&["push"]),
             subscribe_seconds: op_timings.with_label_values(&["subscribe"]),
@@ -2122,6 +2183,10 @@ impl PubSubServerMetrics {
             push_call_count: call_count.with_label_values(&["push"]),
             subscribe_call_count: call_count.with_label_values(&["subscribe"]),
             unsubscribe_call_count: call_count.with_label_values(&["unsubscribe"]),
+            compact_call_count: call_count.with_label_values(&["compact"]),
+            compaction_response_call_count: call_count
+                .with_label_values(&["compaction_response"]),
+            register_compactor_call_count: call_count.with_label_values(&["register_compactor"]),
         }
     }
 }
@@ -2229,6 +2294,9 @@ pub struct PubSubClientSenderMetrics {
     pub push: PubSubClientCallMetrics,
     pub subscribe: PubSubClientCallMetrics,
     pub unsubscribe: PubSubClientCallMetrics,
+    pub compact: PubSubClientCallMetrics,
+    pub compaction_response: PubSubClientCallMetrics,
+    pub register_compactor: PubSubClientCallMetrics,
 }
 
 #[derive(Debug)]
@@ -2272,6 +2340,21 @@ impl PubSubClientSenderMetrics {
                 failed: call_failed.with_label_values(&["unsubscribe"]),
                 bytes_sent: call_bytes_sent.with_label_values(&["unsubscribe"]),
             },
+            compact: PubSubClientCallMetrics {
+                succeeded: call_succeeded.with_label_values(&["compact"]),
+                failed: call_failed.with_label_values(&["compact"]),
+                bytes_sent: call_bytes_sent.with_label_values(&["compact"]),
+            },
+            compaction_response: PubSubClientCallMetrics {
+                succeeded: call_succeeded.with_label_values(&["compaction_response"]),
+                failed: call_failed.with_label_values(&["compaction_response"]),
+                bytes_sent: call_bytes_sent.with_label_values(&["compaction_response"]),
+            },
+            register_compactor: PubSubClientCallMetrics {
+                succeeded: call_succeeded.with_label_values(&["register_compactor"]),
+                failed: call_failed.with_label_values(&["register_compactor"]),
+                bytes_sent: call_bytes_sent.with_label_values(&["register_compactor"]),
+            },
         }
     }
 }
@@ -2377,6 +2460,9 @@ pub struct PushdownMetrics {
     pub(crate) parts_stats_trimmed_count: IntCounter,
     pub(crate) parts_stats_trimmed_bytes: IntCounter,
     pub(crate) parts_projection_trimmed_bytes: IntCounter,
+    pub(crate) parts_projection_count: IntCounter,
+    pub(crate) parts_projection_range_read_count: IntCounter,
+    pub(crate) parts_projection_skipped_bytes: IntCounter,
     pub part_stats: PartStatsMetrics,
 }
 
@@ -2435,6 +2521,18 @@ impl PushdownMetrics {
                 name: "mz_persist_pushdown_parts_projection_trimmed_bytes",
                 help: "total bytes trimmed from columnar data because of projection pushdown",
             )),
+            parts_projection_count: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_projection_count",
+                help: "count of parts decoded with only the columns needed by projection pushdown",
+            )),
+            parts_projection_range_read_count: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_projection_range_read_count",
+                help: "count of parts fetched with range reads because of projection pushdown",
+            )),
+            parts_projection_skipped_bytes: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_projection_skipped_bytes",
+                help: "total bytes not fetched from blob because of projection pushdown",
+            )),
             part_stats: PartStatsMetrics::new(registry),
         }
     }
@@ -2512,6 +2610,59 @@ impl BlobMemCache {
     }
 }
 
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    pub(crate) size_blobs: UIntGauge,
+    pub(crate) size_bytes: UIntGauge,
+    pub(crate) hits_blobs: IntCounter,
+    pub(crate) hits_bytes: IntCounter,
+    pub(crate) misses_blobs: IntCounter,
+    pub(crate) evictions: IntCounter,
+    pub(crate) checksum_failures: IntCounter,
+}
+
+impl BlobDiskCache {
+    fn new(registry: &MetricsRegistry) -> Self {
+        BlobDiskCache {
+            size_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_blobs",
+                help: "count of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            size_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_bytes",
+                help: "total size of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_blobs",
+                help: "count of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_bytes",
+                help: "total size of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            misses_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_misses_blobs",
+                help: "count of blobs not found in the cache and fetched from s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            evictions: registry.register(metric!(
+                name: "mz_persist_blob_cache_evictions",
+                help: "count of capacity-based cache evictions",
+                const_labels: {"cache" => "disk"},
+            )),
+            checksum_failures: registry.register(metric!(
+                name: "mz_persist_blob_cache_checksum_failures",
+                help: "count of cached blobs discarded for failing checksum verification",
+                const_labels: {"cache" => "disk"},
+            )),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SemaphoreMetrics {
     cfg: PersistConfig,
@@ -2807,6 +2958,28 @@ impl Blob for MetricsBlob {
         res
     }
 
+    #[instrument(name = "blob::get_range", fields(shard=blob_key_shard_id(key)))]
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let res = self
+            .metrics
+            .blob
+            .get
+            .run_op(|| self.blob.get_range(key, range), Self::on_err)
+            .await;
+        if let Ok(Some(value)) = res.as_ref() {
+            self.metrics
+                .blob
+                .get
+                .bytes
+                .inc_by(u64::cast_from(value.len()));
+        }
+        res
+    }
+
     #[instrument(name = "blob::list_keys_and_metadata", fields(shard=blob_key_shard_id(key_prefix)))]
     async fn list_keys_and_metadata(
         &self,
@@ -18,6 +18,7 @@ use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
 use std::ops::ControlFlow::{self, Break, Continue};
 use std::ops::{Deref, DerefMut};
+use std::pin::pin;
 use std::time::Duration;
 
 use arrow::array::{Array, ArrayData, make_array};
@@ -28,7 +29,8 @@ use differential_dataflow::lattice::Lattice;
 use differential_dataflow::trace::Description;
 use differential_dataflow::trace::implementations::BatchContainer;
 use futures::Stream;
-use futures_util::StreamExt;
+use futures_util::{StreamExt, TryStreamExt};
+use itertools::Itertools;
 use mz_dyncfg::Config;
 use mz_ore::cast::CastFrom;
 use mz_ore::now::EpochMillis;
@@ -59,7 +61,7 @@ use crate::internal::gc::GcReq;
 use crate::internal::machine::retry_external;
 use crate::internal::paths::{BlobKey, PartId, PartialBatchKey, PartialRollupKey, WriterKey};
 use crate::internal::trace::{
-    ActiveCompaction, ApplyMergeResult, FueledMergeReq, FueledMergeRes, Trace,
+    ActiveCompaction, ApplyMergeResult, FueledMergeReq, FueledMergeRes, SpineId, Trace,
 };
 use crate::metrics::Metrics;
 use crate::read::LeasedReaderId;
@@ -678,6 +680,30 @@ impl<T: Timestamp + Codec64 + Sync> RunPart<T> {
             }
         }
     }
+
+    /// Whether any of the parts this refers to is stored in blob, but not
+    /// encrypted with the given key.
+    ///
+    /// Fetches the metadata of a hollow run to check the parts it references.
+    pub async fn needs_reencryption(
+        &self,
+        shard_id: ShardId,
+        blob: &dyn Blob,
+        metrics: &Metrics,
+        key_id: &str,
+    ) -> Result<bool, MissingBlob> {
+        let mut parts = pin!(self.part_stream(shard_id, blob, metrics));
+        while let Some(part) = parts.try_next().await? {
+            match &*part {
+                BatchPart::Hollow(x) if x.encryption_key_id.as_deref() != Some(key_id) => {
+                    return Ok(true);
+                }
+                // Inline parts are stored in consensus.
+                BatchPart::Hollow(_) | BatchPart::Inline { .. } => {}
+            }
+        }
+        Ok(false)
+    }
 }
 
 impl<T: Ord> PartialOrd for BatchPart<T> {
@@ -791,6 +817,11 @@ pub struct HollowBatchPart<T> {
 
     /// ID of a schema that has since been deprecated and exists only to cleanly roundtrip.
     pub deprecated_schema_id: Option<SchemaId>,
+    /// ID of the key that this part was encrypted with.
+    ///
+    /// This is None if the part was written without encryption, including all
+    /// parts written before encryption was enabled.
+    pub encryption_key_id: Option<String>,
 }
 
 /// A [Batch] but with the updates themselves stored externally.
@@ -1123,6 +1154,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
             format: self_format,
             schema_id: self_schema_id,
             deprecated_schema_id: self_deprecated_schema_id,
+            encryption_key_id: self_encryption_key_id,
         } = self;
         let HollowBatchPart {
             key: other_key,
@@ -1135,6 +1167,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
             format: other_format,
             schema_id: other_schema_id,
             deprecated_schema_id: other_deprecated_schema_id,
+            encryption_key_id: other_encryption_key_id,
         } = other;
         (
             self_key,
@@ -1147,6 +1180,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
             self_format,
             self_schema_id,
             self_deprecated_schema_id,
+            self_encryption_key_id,
         )
             .cmp(&(
                 other_key,
@@ -1159,6 +1193,7 @@ impl<T: Ord> Ord for HollowBatchPart<T> {
                 other_format,
                 other_schema_id,
                 other_deprecated_schema_id,
+                other_encryption_key_id,
             ))
     }
 }
@@ -1541,12 +1576,9 @@ where
         let (Some(key_fn), Some(val_fn)) = (key_fn, val_fn) else {
             return Break(NoOpStateTransition(CaESchema::Incompatible));
         };
-        // Persist initially disallows dropping columns. This would require a
-        // bunch more work (e.g. not safe to use the latest schema in
-        // compaction) and isn't initially necessary in mz.
-        if key_fn.contains_drop() || val_fn.contains_drop() {
-            return Break(NoOpStateTransition(CaESchema::Incompatible));
-        }
+        // NB: Dropping a column is allowed, but it means data written with an
+        // earlier schema is no longer sorted once migrated. Readers and
+        // compaction account for this via `resort_runs_before`.
 
         // We'll have to do something more sophisticated here to
         // generate the next id if/when we start supporting the removal
@@ -1564,6 +1596,31 @@ where
         Continue(CaESchema::Ok(id))
     }
 
+    /// Returns the latest schema, no later than `schema_id`, whose migration
+    /// from the previous schema does not preserve sort order (e.g. because it
+    /// drops a column).
+    ///
+    /// Runs written with a schema before the returned one are not
+    /// necessarily sorted once migrated to `schema_id`, and must be re-sorted
+    /// before they can be consolidated with other runs.
+    pub fn resort_runs_before(&self, schema_id: SchemaId) -> Option<SchemaId> {
+        self.schemas
+            .range(..=schema_id)
+            .tuple_windows()
+            .filter(|((_, prev), (_, next))| {
+                let preserves_order = |prev_dt: &Bytes, next_dt: &Bytes| {
+                    let prev_dt = EncodedSchemas::decode_data_type(prev_dt);
+                    let next_dt = EncodedSchemas::decode_data_type(next_dt);
+                    backward_compatible(&prev_dt, &next_dt)
+                        .map_or(false, |migration| migration.preserves_order())
+                };
+                !preserves_order(&prev.key_data_type, &next.key_data_type)
+                    || !preserves_order(&prev.val_data_type, &next.val_data_type)
+            })
+            .map(|(_, (id, _))| *id)
+            .last()
+    }
+
     pub fn compare_and_append(
         &mut self,
         batch: &HollowBatch<T>,
@@ -1756,6 +1813,39 @@ where
         }
     }
 
+    /// Claims the compaction of the spine batches with the given ids that
+    /// haven't been claimed within the last `lease_duration_ms`, returning a
+    /// merge req for each one claimed.
+    pub fn claim_compactions(
+        &mut self,
+        ids: &[SpineId],
+        heartbeat_timestamp_ms: u64,
+        lease_duration_ms: u64,
+    ) -> ControlFlow<NoOpStateTransition<Vec<FueledMergeReq<T>>>, Vec<FueledMergeReq<T>>> {
+        if self.is_tombstone() {
+            return Break(NoOpStateTransition(Vec::new()));
+        }
+
+        let threshold_ms = heartbeat_timestamp_ms.saturating_sub(lease_duration_ms);
+        let merge_reqs: Vec<_> = self
+            .trace
+            .all_merge_reqs_before_ms(threshold_ms)
+            .filter(|req| ids.contains(&req.id))
+            .collect();
+        if merge_reqs.is_empty() {
+            return Break(NoOpStateTransition(Vec::new()));
+        }
+        for req in &merge_reqs {
+            self.trace.claim_compaction(
+                req.id,
+                ActiveCompaction {
+                    start_ms: heartbeat_timestamp_ms,
+                },
+            )
+        }
+        Continue(merge_reqs)
+    }
+
     pub fn downgrade_since(
         &mut self,
         reader_id: &LeasedReaderId,
@@ -2838,6 +2928,7 @@ pub(crate) mod tests {
                     format,
                     schema_id,
                     deprecated_schema_id,
+                    encryption_key_id: None,
                 }
             },
         )
@@ -3011,6 +3102,7 @@ pub(crate) mod tests {
                         format: None,
                         schema_id: None,
                         deprecated_schema_id: None,
+                        encryption_key_id: None,
                     }))
                 })
                 .collect(),
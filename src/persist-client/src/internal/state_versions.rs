@@ -9,6 +9,7 @@
 
 //! A durable, truncatable log of versions of [State].
 
+use std::collections::BTreeMap;
 #[cfg(debug_assertions)]
 use std::collections::BTreeSet;
 use std::fmt::Debug;
@@ -1071,6 +1072,11 @@ impl<T: Timestamp + Lattice + Codec64> StateVersionsIter<T> {
         &self.state
     }
 
+    /// The names of the shard's key and val codecs.
+    pub(crate) fn kv_codecs(&self) -> (&str, &str) {
+        (&self.key_codec, &self.val_codec)
+    }
+
     pub fn into_rollup_proto_without_diffs(&self) -> impl serde::Serialize + use<T> {
         Rollup::from_state_without_diffs(
             State {
@@ -1088,6 +1094,55 @@ impl<T: Timestamp + Lattice + Codec64> StateVersionsIter<T> {
         )
         .into_proto()
     }
+
+    /// Encodes a copy of the current state as a rollup to be written to `key`,
+    /// replacing any rollups the state references with that one.
+    ///
+    /// Unlike the rollups written during normal operation, the result depends
+    /// on no other version of state, so it can be used to initialize the shard
+    /// somewhere else (e.g. when restoring from a backup). Leased readers are
+    /// dropped: they belong to processes that can't exist there, and they'd
+    /// otherwise hold back the seqno_since to before the only rollup.
+    pub fn encode_standalone_rollup(&self, key: PartialRollupKey) -> Bytes {
+        let mut collections = self.state.collections.clone();
+        collections.leased_readers.clear();
+        collections.rollups = BTreeMap::from([(
+            self.state.seqno,
+            HollowRollup {
+                key,
+                encoded_size_bytes: None,
+            },
+        )]);
+        collections.active_rollup = None;
+        collections.active_gc = None;
+        let state = State {
+            applier_version: self.state.applier_version.clone(),
+            shard_id: self.state.shard_id,
+            seqno: self.state.seqno,
+            walltime_ms: self.state.walltime_ms,
+            hostname: self.state.hostname.clone(),
+            collections,
+        };
+        let state = Rollup::from_state_without_diffs(
+            state,
+            self.key_codec.clone(),
+            self.val_codec.clone(),
+            T::codec_name(),
+            self.diff_codec.clone(),
+        )
+        .state;
+        // The state's only rollup is for its own seqno, so there are no diffs
+        // to inline.
+        let rollup = Rollup::from(state, vec![]);
+        self.metrics.codecs.state.encode(|| {
+            let mut buf = Vec::new();
+            rollup
+                .into_proto()
+                .encode(&mut buf)
+                .expect("no required fields means no initialization errors");
+            Bytes::from(buf)
+        })
+    }
 }
 
 /// This represents a diff, either directly or, in the case of the FromInitial
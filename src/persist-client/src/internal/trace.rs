@@ -575,20 +575,38 @@ impl<T: Timestamp + Lattice> Trace<T> {
         threshold_ms: u64,
         threshold_writer: Option<WriterKey>,
     ) -> impl Iterator<Item = FueledMergeReq<T>> + '_ {
+        self.merge_reqs_before_ms(threshold_ms, move |b| {
+            let noncompact = !b.is_compact();
+            let old_writer = threshold_writer.as_ref().map_or(false, |min_writer| {
+                b.parts.iter().any(|b| {
+                    b.batch
+                        .parts
+                        .iter()
+                        .any(|p| p.writer_key().map_or(false, |writer| writer < *min_writer))
+                })
+            });
+            noncompact || old_writer
+        })
+    }
+
+    /// Obtain merge reqs for all batches, including ones that are already compact, that either
+    /// have no active compaction, or the previous compaction was started at or before the
+    /// threshold time, in order from oldest to newest.
+    pub(crate) fn all_merge_reqs_before_ms(
+        &self,
+        threshold_ms: u64,
+    ) -> impl Iterator<Item = FueledMergeReq<T>> + '_ {
+        self.merge_reqs_before_ms(threshold_ms, |_| true)
+    }
+
+    fn merge_reqs_before_ms<'a>(
+        &'a self,
+        threshold_ms: u64,
+        include: impl Fn(&SpineBatch<T>) -> bool + 'a,
+    ) -> impl Iterator<Item = FueledMergeReq<T>> + 'a {
         self.spine
             .spine_batches()
-            .filter(move |b| {
-                let noncompact = !b.is_compact();
-                let old_writer = threshold_writer.as_ref().map_or(false, |min_writer| {
-                    b.parts.iter().any(|b| {
-                        b.batch
-                            .parts
-                            .iter()
-                            .any(|p| p.writer_key().map_or(false, |writer| writer < *min_writer))
-                    })
-                });
-                noncompact || old_writer
-            })
+            .filter(move |b| include(b))
             .filter(move |b| {
                 // Either there's no active compaction, or the last active compaction
                 // is not after the timeout timestamp.
@@ -2097,6 +2115,11 @@ pub(crate) mod tests {
             1,
             "one batch is older than a future version"
         );
+        assert_eq!(
+            trace.all_merge_reqs_before_ms(u64::MAX).count(),
+            1,
+            "compact batches are included in all merge reqs"
+        );
     }
 
     #[mz_ore::test]
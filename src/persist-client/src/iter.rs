@@ -31,6 +31,7 @@ use mz_persist::location::Blob;
 use mz_persist::metrics::ColumnarMetrics;
 use mz_persist_types::arrow::{ArrayBound, ArrayIdx, ArrayOrd};
 use mz_persist_types::part::Part;
+use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use semver::Version;
 use timely::progress::Timestamp;
@@ -56,6 +57,9 @@ pub(crate) struct FetchData<T> {
     part_desc: Description<T>,
     part: RunPart<T>,
     structured_lower: Option<ArrayBound>,
+    /// Whether this part may no longer be sorted once migrated to the current
+    /// schema, and so must be re-sorted before consolidation.
+    resort: bool,
 }
 
 pub(crate) trait RowSort<T, D> {
@@ -343,6 +347,9 @@ pub(crate) struct Consolidator<T, D, Sort: RowSort<T, D>> {
     runs: Vec<VecDeque<(ConsolidationPart<T, D>, usize)>>,
     filter: FetchBatchFilter<T>,
     budget: usize,
+    /// Runs written with a schema before this one need to be re-sorted. See
+    /// [crate::internal::state::StateCollections::resort_runs_before].
+    resort_runs_before: Option<SchemaId>,
     // NB: this is the tricky part!
     // One hazard of streaming consolidation is that we may start consolidating a particular KVT,
     // but not be able to finish, because some other part that might also contain the same KVT
@@ -370,6 +377,7 @@ where
         read_metrics: ReadMetrics,
         filter: FetchBatchFilter<T>,
         prefetch_budget_bytes: usize,
+        resort_runs_before: Option<SchemaId>,
     ) -> Self {
         Self {
             context,
@@ -382,6 +390,7 @@ where
             runs: vec![],
             filter,
             budget: prefetch_budget_bytes,
+            resort_runs_before,
             drop_stash: None,
         }
     }
@@ -405,16 +414,29 @@ where
         run_meta: &RunMeta,
         parts: impl IntoIterator<Item = RunPart<T>>,
     ) {
+        let resort = match (self.resort_runs_before, run_meta.schema) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(before), Some(schema)) => schema < before,
+        };
         let run = parts
             .into_iter()
             .map(|part| {
                 let bytes = part.encoded_size_bytes();
+                // Lower bounds recorded before a resort-requiring migration
+                // aren't meaningful in the current sort order.
+                let structured_lower = if resort {
+                    None
+                } else {
+                    part.structured_key_lower()
+                };
                 let c_part = ConsolidationPart::Queued {
                     data: FetchData {
                         run_meta: run_meta.clone(),
                         part_desc: desc.clone(),
-                        structured_lower: part.structured_key_lower(),
+                        structured_lower,
                         part,
+                        resort,
                     },
                     task: None,
                     _diff: Default::default(),
@@ -431,7 +453,7 @@ where
         // splitting up a run if it contains possibly-unconsolidated parts.
         let wrong_sort = run.iter().any(|(p, _)| match p {
             ConsolidationPart::Queued { data, .. } => {
-                data.run_meta.order != Some(RunOrder::Structured)
+                data.resort || data.run_meta.order != Some(RunOrder::Structured)
             }
             ConsolidationPart::Encoded { .. } => false,
         });
@@ -552,7 +574,8 @@ where
                     }
                     self.metrics.consolidation.parts_fetched.inc();
 
-                    let wrong_sort = data.run_meta.order != Some(RunOrder::Structured);
+                    let wrong_sort =
+                        data.resort || data.run_meta.order != Some(RunOrder::Structured);
                     let fetch_result: anyhow::Result<FetchResult<T>> = match task.take() {
                         Some(handle) => handle
                             .await
@@ -578,7 +601,11 @@ where
                             // Since we're pushing these onto the _front_ of the queue, we need to
                             // iterate in reverse order.
                             for part in run_part.parts.into_iter().rev() {
-                                let structured_lower = part.structured_key_lower();
+                                let structured_lower = if data.resort {
+                                    None
+                                } else {
+                                    part.structured_key_lower()
+                                };
                                 let size = part.max_part_bytes();
                                 run.push_front((
                                     ConsolidationPart::Queued {
@@ -587,6 +614,7 @@ where
                                             part_desc: data.part_desc.clone(),
                                             part,
                                             structured_lower,
+                                            resort: data.resort,
                                         },
                                         task: None,
                                         _diff: Default::default(),
@@ -1165,6 +1193,7 @@ mod tests {
                         since: desc.since().clone(),
                     },
                     budget,
+                    None,
                 );
 
             for run in runs {
@@ -1184,6 +1213,7 @@ mod tests {
                             format: None,
                             schema_id: None,
                             deprecated_schema_id: None,
+                            encryption_key_id: None,
                         }))
                     })
                     .collect();
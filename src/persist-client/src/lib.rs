@@ -25,7 +25,9 @@ use itertools::Itertools;
 use mz_build_info::{BuildInfo, build_info};
 use mz_dyncfg::ConfigSet;
 use mz_ore::{instrument, soft_assert_or_log};
+use mz_persist::indexed::columnar::parquet::PartProjection;
 use mz_persist::location::{Blob, Consensus, ExternalError};
+use mz_persist_types::columnar::data_type;
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64, Opaque};
 use mz_proto::{IntoRustIfSome, ProtoType};
@@ -64,6 +66,7 @@ pub mod cli {
     pub mod bench;
     pub mod inspect;
 }
+pub mod compaction_service;
 pub mod critical;
 pub mod error;
 pub mod fetch;
@@ -101,6 +104,7 @@ pub mod write;
 /// An implementation of the public crate interface.
 mod internal {
     pub mod apply;
+    pub mod backup;
     pub mod cache;
     pub mod compact;
     pub mod encoding;
@@ -394,6 +398,16 @@ impl PersistClient {
             key: key_schema,
             val: val_schema,
         };
+        // The fields of the structured key and val that aren't in the read
+        // schemas are dropped when migrating a part, so they can be skipped
+        // when fetching and decoding it.
+        let projection = match (
+            data_type::<K>(&*read_schemas.key),
+            data_type::<V>(&*read_schemas.val),
+        ) {
+            (Ok(key), Ok(val)) => Some(Arc::new(PartProjection { key, val })),
+            _ => None,
+        };
         let schema_cache = machine.applier.schema_cache();
         let fetcher = BatchFetcher {
             cfg: BatchFetcherConfig::new(&self.cfg),
@@ -403,6 +417,7 @@ impl PersistClient {
             shard_id,
             read_schemas,
             schema_cache,
+            projection,
             is_transient,
             _phantom: PhantomData,
         };
@@ -678,6 +693,8 @@ impl PersistClient {
             batches,
             should_fetch_part,
             memory_budget_bytes,
+            // The batches are expected to have been written with `read_schemas`.
+            None,
         )
     }
 
@@ -30,6 +30,7 @@ use mz_ore::now::EpochMillis;
 use mz_ore::task::{AbortOnDropHandle, JoinHandle, RuntimeExt};
 use mz_persist::location::{Blob, SeqNo};
 use mz_persist_types::columnar::{ColumnDecoder, Schema};
+use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
@@ -1008,6 +1009,10 @@ where
     ) -> Result<Cursor<K, V, T, D>, Since<T>> {
         let batches = self.machine.snapshot(&as_of).await?;
         let lease = self.lease_seqno();
+        let resort_runs_before = self
+            .read_schemas
+            .id
+            .and_then(|id| self.machine.applier.resort_runs_before(id));
 
         Self::read_batches_consolidated(
             &self.cfg,
@@ -1022,6 +1027,7 @@ where
             lease,
             should_fetch_part,
             COMPACTION_MEMORY_BOUND_BYTES.get(&self.cfg),
+            resort_runs_before,
         )
     }
 
@@ -1038,6 +1044,7 @@ where
         lease: L,
         should_fetch_part: impl for<'a> Fn(Option<&'a LazyPartStats>) -> bool,
         memory_budget_bytes: usize,
+        resort_runs_before: Option<SchemaId>,
     ) -> Result<Cursor<K, V, T, D, L>, Since<T>> {
         let context = format!("{}[as_of={:?}]", shard_id, as_of.elements());
         let filter = FetchBatchFilter::Snapshot {
@@ -1054,6 +1061,7 @@ where
             read_metrics,
             filter,
             memory_budget_bytes,
+            resort_runs_before,
         );
         for batch in batches {
             for (meta, run) in batch.runs() {
@@ -12,10 +12,11 @@
 use std::collections::BTreeMap;
 use std::collections::btree_map::Entry;
 use std::fmt::{Debug, Formatter};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::net::SocketAddr;
 use std::pin::Pin;
 use std::str::FromStr;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{Duration, Instant, SystemTime};
 
@@ -35,6 +36,7 @@ use mz_proto::{ProtoType, RustType};
 use prost::Message;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::oneshot;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
 use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
@@ -49,8 +51,9 @@ use crate::internal::metrics::{PubSubClientCallMetrics, PubSubServerMetrics};
 use crate::internal::service::proto_persist_pub_sub_client::ProtoPersistPubSubClient;
 use crate::internal::service::proto_persist_pub_sub_server::ProtoPersistPubSubServer;
 use crate::internal::service::{
-    ProtoPubSubMessage, ProtoPushDiff, ProtoSubscribe, ProtoUnsubscribe,
-    proto_persist_pub_sub_server, proto_pub_sub_message,
+    ProtoCompactionNack, ProtoCompactionRequest, ProtoCompactionResponse, ProtoPubSubMessage,
+    ProtoPushDiff, ProtoRegisterCompactor, ProtoSubscribe, ProtoUnsubscribe,
+    proto_compaction_response, proto_persist_pub_sub_server, proto_pub_sub_message,
 };
 use crate::metrics::Metrics;
 
@@ -184,6 +187,26 @@ pub trait PubSubSender: std::fmt::Debug + Send + Sync {
     /// If the client is already subscribed to the shard, repeated calls will make
     /// no further calls to the server and instead return clones of the `Arc<ShardSubscriptionToken>`.
     fn subscribe(self: Arc<Self>, shard_id: &ShardId) -> Arc<ShardSubscriptionToken>;
+
+    /// Hands a compaction request to the server, which forwards it to one of
+    /// its registered compaction services.
+    ///
+    /// Returns a receiver for the response to the request: an ack once the
+    /// compaction has been performed, or a nack if it won't be (e.g. because
+    /// no compaction service is registered). The receiver resolves to an error
+    /// if the connection to the server is lost, and may never resolve if a
+    /// response is dropped, so callers must not wait on it indefinitely.
+    fn compact(&self, req: ProtoCompactionRequest) -> oneshot::Receiver<ProtoCompactionResponse>;
+
+    /// Responds to a compaction request received by a compaction service.
+    fn respond_compaction(&self, resp: ProtoCompactionResponse);
+
+    /// Registers the corresponding [PubSubReceiver] as a compaction service,
+    /// making it eligible to receive compaction requests sent by any client.
+    ///
+    /// The registration is kept for the lifetime of the client, including
+    /// across reconnects.
+    fn register_compactor(&self);
 }
 
 /// The internal send-side client trait to Persist PubSub, responsible for issuing RPCs
@@ -203,6 +226,20 @@ trait PubSubSenderInternal: Debug + Send + Sync {
     ///
     /// This call is idempotent and is a no-op for already unsubscribed shards.
     fn unsubscribe(&self, shard_id: &ShardId);
+
+    /// Hands a compaction request to a registered compaction service.
+    ///
+    /// The request's `request_id` identifies the response to it. Returns false
+    /// if the request couldn't be sent, in which case there won't be one.
+    fn compact(&self, req: ProtoCompactionRequest) -> bool;
+
+    /// Responds to a compaction request received by a compaction service.
+    fn respond_compaction(&self, resp: ProtoCompactionResponse);
+
+    /// Registers the corresponding [PubSubReceiver] as a compaction service.
+    ///
+    /// This call is idempotent.
+    fn register_compactor(&self);
 }
 
 /// The receive-side client to Persist PubSub.
@@ -285,6 +322,9 @@ impl GrpcPubSubClient {
         loop {
             let sender = Arc::clone(&sender);
             metrics.pubsub_client.grpc_connection.connected.set(0);
+            // The server forgets about compaction requests when the connection
+            // they were sent on is lost, so they won't be responded to.
+            sender.drop_compactions();
 
             if !PUBSUB_CLIENT_ENABLED.get(&config.persist_cfg) {
                 tokio::time::sleep(Duration::from_secs(5)).await;
@@ -359,6 +399,13 @@ impl GrpcPubSubClient {
                             shard_id: id.into_proto(),
                         }));
                     }
+                    // Likewise, compaction services are tracked by connection.
+                    if sender.is_registered_compactor() {
+                        debug!("re-registering as compactor");
+                        yield create_request(proto_pub_sub_message::Message::RegisterCompactor(
+                            ProtoRegisterCompactor {},
+                        ));
+                    }
 
                     // Forward on messages from the broadcast channel, reconnecting if necessary.
                     while let Some(message) = broadcast.next().await {
@@ -389,6 +436,7 @@ impl GrpcPubSubClient {
             let stream_completed = GrpcPubSubClient::consume_grpc_stream(
                 responses,
                 receiver_input,
+                &sender,
                 &config,
                 metrics.as_ref(),
             )
@@ -410,6 +458,7 @@ impl GrpcPubSubClient {
     async fn consume_grpc_stream(
         mut responses: Streaming<ProtoPubSubMessage>,
         receiver_input: &Sender<ProtoPubSubMessage>,
+        sender: &SubscriptionTrackingSender,
         config: &PersistPubSubClientConfig,
         metrics: &Metrics,
     ) -> Result<(), Error> {
@@ -422,6 +471,15 @@ impl GrpcPubSubClient {
             match responses.next().await {
                 Some(Ok(message)) => {
                     debug!("received pubsub message: {:?}", message);
+                    // Compaction responses are for the sender that's waiting on
+                    // them, rather than the receiver.
+                    let message = match message.message {
+                        Some(proto_pub_sub_message::Message::CompactionResponse(resp)) => {
+                            sender.complete_compaction(resp);
+                            continue;
+                        }
+                        _ => message,
+                    };
                     match receiver_input.send(message).await {
                         Ok(_) => {}
                         // if the receiver has dropped, we can drop our
@@ -523,17 +581,23 @@ fn create_request(message: proto_pub_sub_message::Message) -> ProtoPubSubMessage
 }
 
 impl GrpcPubSubSender {
-    fn send(&self, message: proto_pub_sub_message::Message, metrics: &PubSubClientCallMetrics) {
+    fn send(
+        &self,
+        message: proto_pub_sub_message::Message,
+        metrics: &PubSubClientCallMetrics,
+    ) -> bool {
         let size = message.encoded_len();
 
         match self.requests.send(create_request(message)) {
             Ok(_) => {
                 metrics.succeeded.inc();
                 metrics.bytes_sent.inc_by(u64::cast_from(size));
+                true
             }
             Err(err) => {
                 metrics.failed.inc();
                 debug!("error sending client message: {}", err);
+                false
             }
         }
     }
@@ -548,7 +612,7 @@ impl PubSubSenderInternal for GrpcPubSubSender {
                 diff: diff.data.clone(),
             }),
             &self.metrics.pubsub_client.sender.push,
-        )
+        );
     }
 
     fn subscribe(&self, shard_id: &ShardId) {
@@ -557,7 +621,7 @@ impl PubSubSenderInternal for GrpcPubSubSender {
                 shard_id: shard_id.into_proto(),
             }),
             &self.metrics.pubsub_client.sender.subscribe,
-        )
+        );
     }
 
     fn unsubscribe(&self, shard_id: &ShardId) {
@@ -566,16 +630,41 @@ impl PubSubSenderInternal for GrpcPubSubSender {
                 shard_id: shard_id.into_proto(),
             }),
             &self.metrics.pubsub_client.sender.unsubscribe,
+        );
+    }
+
+    fn compact(&self, req: ProtoCompactionRequest) -> bool {
+        self.send(
+            proto_pub_sub_message::Message::Compact(req),
+            &self.metrics.pubsub_client.sender.compact,
         )
     }
+
+    fn respond_compaction(&self, resp: ProtoCompactionResponse) {
+        self.send(
+            proto_pub_sub_message::Message::CompactionResponse(resp),
+            &self.metrics.pubsub_client.sender.compaction_response,
+        );
+    }
+
+    fn register_compactor(&self) {
+        self.send(
+            proto_pub_sub_message::Message::RegisterCompactor(ProtoRegisterCompactor {}),
+            &self.metrics.pubsub_client.sender.register_compactor,
+        );
+    }
 }
 
 /// An wrapper for a [PubSubSenderInternal] that implements [PubSubSender]
-/// by maintaining a map of active shard subscriptions to their tokens.
+/// by maintaining a map of active shard subscriptions to their tokens, and of
+/// in-flight compaction requests to their responders.
 #[derive(Debug)]
 struct SubscriptionTrackingSender {
     delegate: Arc<dyn PubSubSenderInternal>,
     subscribes: Arc<Mutex<BTreeMap<ShardId, Weak<ShardSubscriptionToken>>>>,
+    registered_compactor: AtomicBool,
+    compaction_id_counter: AtomicU64,
+    compactions: Mutex<BTreeMap<u64, oneshot::Sender<ProtoCompactionResponse>>>,
 }
 
 impl SubscriptionTrackingSender {
@@ -583,9 +672,41 @@ impl SubscriptionTrackingSender {
         Self {
             delegate: sender,
             subscribes: Default::default(),
+            registered_compactor: AtomicBool::new(false),
+            compaction_id_counter: AtomicU64::new(0),
+            compactions: Default::default(),
         }
     }
 
+    /// Hands the response to a compaction request to whoever is waiting on it.
+    fn complete_compaction(&self, resp: ProtoCompactionResponse) {
+        let responder = self
+            .compactions
+            .lock()
+            .expect("lock")
+            .remove(&resp.request_id);
+        match responder {
+            // The requester may have stopped waiting, which is fine.
+            Some(responder) => {
+                let _ = responder.send(resp);
+            }
+            None => debug!(
+                "ignoring response to unknown compaction request: {}",
+                resp.request_id
+            ),
+        }
+    }
+
+    /// Drops the responders of all in-flight compaction requests, resolving
+    /// their receivers to an error.
+    fn drop_compactions(&self) {
+        self.compactions.lock().expect("lock").clear();
+    }
+
+    fn is_registered_compactor(&self) -> bool {
+        self.registered_compactor.load(Ordering::SeqCst)
+    }
+
     fn subscriptions(&self) -> Vec<ShardId> {
         let mut subscribes = self.subscribes.lock().expect("lock");
         let mut out = Vec::with_capacity(subscribes.len());
@@ -634,6 +755,35 @@ impl PubSubSender for SubscriptionTrackingSender {
 
         token
     }
+
+    fn compact(
+        &self,
+        mut req: ProtoCompactionRequest,
+    ) -> oneshot::Receiver<ProtoCompactionResponse> {
+        let (tx, rx) = oneshot::channel();
+        req.request_id = self.compaction_id_counter.fetch_add(1, Ordering::SeqCst);
+        let request_id = req.request_id;
+        {
+            let mut compactions = self.compactions.lock().expect("lock");
+            // Forget about requests whose requester has stopped waiting.
+            compactions.retain(|_, responder| !responder.is_closed());
+            compactions.insert(request_id, tx);
+        }
+        if !self.delegate.compact(req) {
+            // Resolve the receiver to an error right away.
+            self.compactions.lock().expect("lock").remove(&request_id);
+        }
+        rx
+    }
+
+    fn respond_compaction(&self, resp: ProtoCompactionResponse) {
+        self.delegate.respond_compaction(resp)
+    }
+
+    fn register_compactor(&self) {
+        self.registered_compactor.store(true, Ordering::SeqCst);
+        self.delegate.register_compactor()
+    }
 }
 
 /// A wrapper intended to provide client-side metrics for a connection
@@ -684,6 +834,32 @@ impl PubSubSender for MetricsSameProcessPubSubSender {
             })
         }
     }
+
+    fn compact(&self, req: ProtoCompactionRequest) -> oneshot::Receiver<ProtoCompactionResponse> {
+        let rx = self.delegate.compact(req);
+        self.metrics.pubsub_client.sender.compact.succeeded.inc();
+        rx
+    }
+
+    fn respond_compaction(&self, resp: ProtoCompactionResponse) {
+        self.delegate.respond_compaction(resp);
+        self.metrics
+            .pubsub_client
+            .sender
+            .compaction_response
+            .succeeded
+            .inc();
+    }
+
+    fn register_compactor(&self) {
+        self.delegate.register_compactor();
+        self.metrics
+            .pubsub_client
+            .sender
+            .register_compactor
+            .succeeded
+            .inc();
+    }
 }
 
 #[derive(Debug)]
@@ -693,6 +869,11 @@ impl PubSubSenderInternal for NoopPubSubSender {
     fn push_diff(&self, _shard_id: &ShardId, _diff: &VersionedData) {}
     fn subscribe(&self, _shard_id: &ShardId) {}
     fn unsubscribe(&self, _shard_id: &ShardId) {}
+    fn compact(&self, _req: ProtoCompactionRequest) -> bool {
+        false
+    }
+    fn respond_compaction(&self, _resp: ProtoCompactionResponse) {}
+    fn register_compactor(&self) {}
 }
 
 impl PubSubSender for NoopPubSubSender {
@@ -704,6 +885,16 @@ impl PubSubSender for NoopPubSubSender {
             sender: self,
         })
     }
+
+    fn compact(&self, _req: ProtoCompactionRequest) -> oneshot::Receiver<ProtoCompactionResponse> {
+        // There's no server to respond, so fail the request right away.
+        let (_tx, rx) = oneshot::channel();
+        rx
+    }
+
+    fn respond_compaction(&self, _resp: ProtoCompactionResponse) {}
+
+    fn register_compactor(&self) {}
 }
 
 /// Spawns a Tokio task that consumes a [PubSubReceiver], applying its diffs to a [StateCache].
@@ -803,10 +994,30 @@ pub(crate) struct PubSubState {
         Arc<RwLock<BTreeMap<ShardId, BTreeMap<usize, Sender<Result<ProtoPubSubMessage, Status>>>>>>,
     /// Active connections.
     connections: Arc<RwLock<HashSet<usize>>>,
+    /// Maintains a mapping of `ConnectionId -> Tx` for the connections that
+    /// have registered as compaction services.
+    compactors: Arc<RwLock<BTreeMap<usize, Sender<Result<ProtoPubSubMessage, Status>>>>>,
+    /// Assigns a unique ID to each compaction request forwarded to a compaction
+    /// service.
+    compaction_id_counter: AtomicU64,
+    /// Compaction requests that were forwarded to a compaction service but not
+    /// yet responded to, by the ID assigned to them.
+    compactions_in_flight: Arc<Mutex<BTreeMap<u64, InFlightCompaction>>>,
     /// Server-side metrics.
     metrics: Arc<PubSubServerMetrics>,
 }
 
+/// A compaction request forwarded to a compaction service by [PubSubState].
+#[derive(Debug)]
+struct InFlightCompaction {
+    /// The ID the requester assigned to the request.
+    request_id: u64,
+    /// The requester's connection.
+    requester: Sender<Result<ProtoPubSubMessage, Status>>,
+    /// The ID of the compaction service's connection.
+    compactor: usize,
+}
+
 impl PubSubState {
     fn new_connection(
         self: Arc<Self>,
@@ -848,6 +1059,31 @@ impl PubSubState {
             });
         }
 
+        {
+            let mut compactors = self.compactors.write().expect("lock poisoned");
+            if compactors.remove(&connection_id).is_some() {
+                self.metrics.active_compactors.dec();
+
+                // Nobody else is going to respond to the requests this
+                // compaction service was working on.
+                let mut in_flight = self.compactions_in_flight.lock().expect("lock poisoned");
+                let lost: Vec<_> = in_flight
+                    .iter()
+                    .filter(|(_, compaction)| compaction.compactor == connection_id)
+                    .map(|(id, _)| *id)
+                    .collect();
+                for id in lost {
+                    let compaction = in_flight.remove(&id).expect("in flight");
+                    self.metrics.compact_nacked_compactor_disconnected.inc();
+                    Self::nack_compaction(
+                        &compaction.requester,
+                        compaction.request_id,
+                        "compaction service disconnected",
+                    );
+                }
+            }
+        }
+
         self.metrics
             .connection_cleanup_seconds
             .inc_by(now.elapsed().as_secs_f64());
@@ -973,12 +1209,180 @@ impl PubSubState {
             .inc_by(now.elapsed().as_secs_f64());
     }
 
+    fn register_compactor(
+        &self,
+        connection_id: usize,
+        notifier: Sender<Result<ProtoPubSubMessage, Status>>,
+    ) {
+        self.metrics.register_compactor_call_count.inc();
+
+        assert!(
+            self.connections
+                .read()
+                .expect("lock")
+                .contains(&connection_id),
+            "unknown connection id: {}",
+            connection_id
+        );
+
+        let mut compactors = self.compactors.write().expect("lock poisoned");
+        if compactors.insert(connection_id, notifier).is_none() {
+            info!("registered connection {} as compactor", connection_id);
+            self.metrics.active_compactors.inc();
+        }
+    }
+
+    /// Forwards the compaction request to one of the registered compaction
+    /// services, or nacks it if that isn't possible.
+    ///
+    /// Requests for a given shard are consistently routed to the same service
+    /// (for a fixed set of services), which keeps a service's state cache warm
+    /// and avoids racing compactions of the same shard against each other.
+    /// The service's response is routed back to the requester.
+    fn compact(
+        &self,
+        connection_id: usize,
+        notifier: Sender<Result<ProtoPubSubMessage, Status>>,
+        mut req: ProtoCompactionRequest,
+    ) {
+        self.metrics.compact_call_count.inc();
+
+        assert!(
+            self.connections
+                .read()
+                .expect("lock")
+                .contains(&connection_id),
+            "unknown connection id: {}",
+            connection_id
+        );
+
+        let request_id = req.request_id;
+        let compactors = self.compactors.read().expect("lock poisoned");
+        if compactors.is_empty() {
+            self.metrics.compact_nacked_no_compactor.inc();
+            Self::nack_compaction(&notifier, request_id, "no compaction service registered");
+            return;
+        }
+
+        let shard_id = req
+            .req
+            .as_ref()
+            .map(|req| req.shard_id.as_str())
+            .unwrap_or_default();
+        let mut hasher = DefaultHasher::new();
+        shard_id.hash(&mut hasher);
+        let idx = usize::cast_from(hasher.finish()) % compactors.len();
+        let (compactor_conn_id, tx) = compactors.iter().nth(idx).expect("idx in bounds");
+        debug!(
+            "server forwarding compaction req to conn {}: {}",
+            compactor_conn_id, shard_id
+        );
+
+        let id = self.compaction_id_counter.fetch_add(1, Ordering::SeqCst);
+        req.request_id = id;
+        self.compactions_in_flight
+            .lock()
+            .expect("lock poisoned")
+            .insert(
+                id,
+                InFlightCompaction {
+                    request_id,
+                    requester: notifier,
+                    compactor: *compactor_conn_id,
+                },
+            );
+
+        let reason = match tx.try_send(Ok(create_request(proto_pub_sub_message::Message::Compact(
+            req,
+        )))) {
+            Ok(_) => {
+                self.metrics.compact_forwarded_count.inc();
+                return;
+            }
+            Err(TrySendError::Full(_)) => {
+                self.metrics.compact_nacked_channel_full.inc();
+                "compaction service is at capacity"
+            }
+            Err(TrySendError::Closed(_)) => {
+                self.metrics.compact_nacked_compactor_disconnected.inc();
+                "compaction service disconnected"
+            }
+        };
+        let compaction = self
+            .compactions_in_flight
+            .lock()
+            .expect("lock poisoned")
+            .remove(&id);
+        if let Some(compaction) = compaction {
+            Self::nack_compaction(&compaction.requester, compaction.request_id, reason);
+        }
+    }
+
+    /// Routes a compaction service's response back to the requester.
+    fn respond_compaction(&self, connection_id: usize, mut resp: ProtoCompactionResponse) {
+        self.metrics.compaction_response_call_count.inc();
+
+        assert!(
+            self.connections
+                .read()
+                .expect("lock")
+                .contains(&connection_id),
+            "unknown connection id: {}",
+            connection_id
+        );
+
+        let compaction = self
+            .compactions_in_flight
+            .lock()
+            .expect("lock poisoned")
+            .remove(&resp.request_id);
+        let Some(compaction) = compaction else {
+            debug!(
+                "ignoring response to unknown compaction request: {}",
+                resp.request_id
+            );
+            return;
+        };
+        resp.request_id = compaction.request_id;
+        Self::send_compaction_response(&compaction.requester, resp);
+    }
+
+    fn nack_compaction(
+        requester: &Sender<Result<ProtoPubSubMessage, Status>>,
+        request_id: u64,
+        reason: &str,
+    ) {
+        let resp = ProtoCompactionResponse {
+            request_id,
+            response: Some(proto_compaction_response::Response::Nack(
+                ProtoCompactionNack {
+                    reason: reason.to_string(),
+                },
+            )),
+        };
+        Self::send_compaction_response(requester, resp);
+    }
+
+    fn send_compaction_response(
+        requester: &Sender<Result<ProtoPubSubMessage, Status>>,
+        resp: ProtoCompactionResponse,
+    ) {
+        // Requesters stop waiting for a response after a while, so it's fine
+        // to drop one if the requester can't keep up or has disconnected.
+        let _ = requester.try_send(Ok(create_request(
+            proto_pub_sub_message::Message::CompactionResponse(resp),
+        )));
+    }
+
     #[cfg(test)]
     fn new_for_test() -> Self {
         Self {
             connection_id_counter: AtomicUsize::new(0),
             shard_subscribers: Default::default(),
             connections: Default::default(),
+            compactors: Default::default(),
+            compaction_id_counter: AtomicU64::new(0),
+            compactions_in_flight: Default::default(),
             metrics: Arc::new(PubSubServerMetrics::new(&MetricsRegistry::new())),
         }
     }
@@ -1002,6 +1406,16 @@ impl PubSubState {
         shards
     }
 
+    #[cfg(test)]
+    fn compactors(&self) -> HashSet<usize> {
+        self.compactors
+            .read()
+            .expect("lock")
+            .keys()
+            .copied()
+            .collect()
+    }
+
     #[cfg(test)]
     fn shard_subscription_counts(&self) -> mz_ore::collections::HashMap<ShardId, usize> {
         let mut shards = mz_ore::collections::HashMap::new();
@@ -1030,6 +1444,9 @@ impl PersistGrpcPubSubServer {
             connection_id_counter: AtomicUsize::new(0),
             shard_subscribers: Default::default(),
             connections: Default::default(),
+            compactors: Default::default(),
+            compaction_id_counter: AtomicU64::new(0),
+            compactions_in_flight: Default::default(),
             metrics: Arc::new(metrics),
         });
 
@@ -1045,14 +1462,28 @@ impl PersistGrpcPubSubServer {
     pub fn new_same_process_connection(&self) -> PubSubClientConnection {
         let (tx, rx) =
             tokio::sync::mpsc::channel(PUBSUB_CLIENT_RECEIVER_CHANNEL_SIZE.get(&self.cfg));
-        let sender: Arc<dyn PubSubSender> = Arc::new(SubscriptionTrackingSender::new(Arc::new(
+        let sender = Arc::new(SubscriptionTrackingSender::new(Arc::new(
             Arc::clone(&self.state).new_connection(tx),
         )));
+        let compaction_sender = Arc::clone(&sender);
 
         PubSubClientConnection {
             sender,
             receiver: Box::new(
-                ReceiverStream::new(rx).map(|x| x.expect("cannot receive grpc errors locally")),
+                ReceiverStream::new(rx)
+                    .map(|x| x.expect("cannot receive grpc errors locally"))
+                    // Compaction responses are for the sender that's waiting on
+                    // them, rather than the receiver.
+                    .filter_map(move |message| {
+                        let message = match message.message {
+                            Some(proto_pub_sub_message::Message::CompactionResponse(resp)) => {
+                                compaction_sender.complete_compaction(resp);
+                                None
+                            }
+                            _ => Some(message),
+                        };
+                        futures::future::ready(message)
+                    }),
             ),
         }
     }
@@ -1145,6 +1576,15 @@ impl proto_persist_pub_sub_server::ProtoPersistPubSub for PersistGrpcPubSubServe
                             let shard_id = diff.shard_id.parse().expect("valid shard id");
                             connection.unsubscribe(&shard_id);
                         }
+                        Some(proto_pub_sub_message::Message::Compact(req)) => {
+                            connection.compact(req);
+                        }
+                        Some(proto_pub_sub_message::Message::RegisterCompactor(_)) => {
+                            connection.register_compactor();
+                        }
+                        Some(proto_pub_sub_message::Message::CompactionResponse(resp)) => {
+                            connection.respond_compaction(resp);
+                        }
                     }
                 }
 
@@ -1181,6 +1621,21 @@ impl PubSubSenderInternal for PubSubConnection {
     fn unsubscribe(&self, shard_id: &ShardId) {
         self.state.unsubscribe(self.connection_id, shard_id)
     }
+
+    fn compact(&self, req: ProtoCompactionRequest) -> bool {
+        self.state
+            .compact(self.connection_id, self.notifier.clone(), req);
+        true
+    }
+
+    fn respond_compaction(&self, resp: ProtoCompactionResponse) {
+        self.state.respond_compaction(self.connection_id, resp)
+    }
+
+    fn register_compactor(&self) {
+        self.state
+            .register_compactor(self.connection_id, self.notifier.clone())
+    }
 }
 
 impl Drop for PubSubConnection {
@@ -1204,8 +1659,12 @@ mod pubsub_state {
     use tonic::Status;
 
     use crate::ShardId;
-    use crate::internal::service::ProtoPubSubMessage;
+    use crate::internal::service::proto_compaction_response::Response;
     use crate::internal::service::proto_pub_sub_message::Message;
+    use crate::internal::service::{
+        ProtoCompactReq, ProtoCompactionAck, ProtoCompactionRequest, ProtoCompactionResponse,
+        ProtoPubSubMessage,
+    };
     use crate::rpc::{PubSubSenderInternal, PubSubState};
 
     static SHARD_ID_0: LazyLock<ShardId> =
@@ -1383,6 +1842,113 @@ mod pubsub_state {
         );
     }
 
+    #[mz_ore::test]
+    fn test_compactors() {
+        let state = Arc::new(PubSubState::new_for_test());
+
+        let (tx1, mut rx1) = tokio::sync::mpsc::channel(100);
+        let writer = Arc::clone(&state).new_connection(tx1);
+
+        // with no registered compactors, requests are nacked
+        writer.compact(compact_req(&SHARD_ID_0, 7));
+        assert_compaction_response(&mut rx1, 7, false);
+
+        let (tx2, mut rx2) = tokio::sync::mpsc::channel(100);
+        let compactor = Arc::clone(&state).new_connection(tx2);
+        compactor.register_compactor();
+        // registration is idempotent
+        compactor.register_compactor();
+        assert_eq!(state.compactors(), HashSet::from([compactor.connection_id]));
+
+        // requests are forwarded to the registered compactor, whose responses
+        // are routed back to the writer
+        writer.compact(compact_req(&SHARD_ID_0, 8));
+        writer.compact(compact_req(&SHARD_ID_1, 9));
+        let id_0 = assert_compact(&mut rx2, &SHARD_ID_0);
+        assert_compact(&mut rx2, &SHARD_ID_1);
+        assert!(matches!(rx1.try_recv(), Err(TryRecvError::Empty)));
+        compactor.respond_compaction(ProtoCompactionResponse {
+            request_id: id_0,
+            response: Some(Response::Ack(ProtoCompactionAck::default())),
+        });
+        assert_compaction_response(&mut rx1, 8, true);
+        // responses to unknown requests are ignored
+        compactor.respond_compaction(ProtoCompactionResponse {
+            request_id: id_0,
+            response: Some(Response::Ack(ProtoCompactionAck::default())),
+        });
+        assert!(matches!(rx1.try_recv(), Err(TryRecvError::Empty)));
+
+        // dropping the connection unregisters the compactor, and nacks the
+        // requests it hadn't responded to
+        drop(compactor);
+        assert!(state.compactors().is_empty());
+        assert_compaction_response(&mut rx1, 9, false);
+        writer.compact(compact_req(&SHARD_ID_0, 10));
+        assert_compaction_response(&mut rx1, 10, false);
+    }
+
+    fn compact_req(shard: &ShardId, request_id: u64) -> ProtoCompactionRequest {
+        ProtoCompactionRequest {
+            req: Some(ProtoCompactReq {
+                shard_id: shard.into_proto(),
+                ..Default::default()
+            }),
+            request_id,
+            ..Default::default()
+        }
+    }
+
+    fn assert_compact(
+        rx: &mut Receiver<Result<ProtoPubSubMessage, Status>>,
+        shard: &ShardId,
+    ) -> u64 {
+        let message = rx
+            .try_recv()
+            .expect("message in channel")
+            .expect("pubsub")
+            .message
+            .expect("proto contains message");
+        match message {
+            Message::Compact(x) => {
+                assert_eq!(x.req.expect("req").shard_id, shard.into_proto());
+                x.request_id
+            }
+            Message::PushDiff(_)
+            | Message::Subscribe(_)
+            | Message::Unsubscribe(_)
+            | Message::RegisterCompactor(_)
+            | Message::CompactionResponse(_) => panic!("unexpected message type"),
+        }
+    }
+
+    fn assert_compaction_response(
+        rx: &mut Receiver<Result<ProtoPubSubMessage, Status>>,
+        request_id: u64,
+        ack: bool,
+    ) {
+        let message = rx
+            .try_recv()
+            .expect("message in channel")
+            .expect("pubsub")
+            .message
+            .expect("proto contains message");
+        match message {
+            Message::CompactionResponse(x) => {
+                assert_eq!(x.request_id, request_id);
+                match x.response.expect("response") {
+                    Response::Ack(_) => assert!(ack, "unexpected ack"),
+                    Response::Nack(_) => assert!(!ack, "unexpected nack"),
+                }
+            }
+            Message::PushDiff(_)
+            | Message::Subscribe(_)
+            | Message::Unsubscribe(_)
+            | Message::Compact(_)
+            | Message::RegisterCompactor(_) => panic!("unexpected message type"),
+        };
+    }
+
     fn assert_push(
         rx: &mut Receiver<Result<ProtoPubSubMessage, Status>>,
         shard: &ShardId,
@@ -1400,7 +1966,11 @@ mod pubsub_state {
                 assert_eq!(x.seqno, data.seqno.into_proto());
                 assert_eq!(x.diff, data.data);
             }
-            Message::Subscribe(_) | Message::Unsubscribe(_) => panic!("unexpected message type"),
+            Message::Subscribe(_)
+            | Message::Unsubscribe(_)
+            | Message::Compact(_)
+            | Message::RegisterCompactor(_)
+            | Message::CompactionResponse(_) => panic!("unexpected message type"),
         };
     }
 }
@@ -1831,7 +2401,11 @@ mod grpc {
                 assert_eq!(x.seqno, data.seqno.into_proto());
                 assert_eq!(x.diff, data.data);
             }
-            Message::Subscribe(_) | Message::Unsubscribe(_) => panic!("unexpected message type"),
+            Message::Subscribe(_)
+            | Message::Unsubscribe(_)
+            | Message::Compact(_)
+            | Message::RegisterCompactor(_)
+            | Message::CompactionResponse(_) => panic!("unexpected message type"),
         };
     }
 
@@ -611,13 +611,13 @@ mod tests {
             .unwrap();
         assert_eq!(write0.write_schemas.id.unwrap(), SchemaId(0));
 
-        // Not backward compatible (yet... we don't support dropping a column at
-        // the moment).
+        // Not backward compatible: existing data has no value for a new
+        // non-nullable column.
         let res = client
             .compare_and_evolve_schema::<Strings, (), u64, i64>(
                 shard_id,
                 SchemaId(0),
-                &StringsSchema(vec![]),
+                &StringsSchema(vec![false, false]),
                 &UnitSchema,
                 d.clone(),
             )
@@ -670,6 +670,19 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(write1.write_schemas.id.unwrap(), SchemaId(1));
+
+        // Dropping a column is also a successful evolution.
+        let res = client
+            .compare_and_evolve_schema::<Strings, (), u64, i64>(
+                shard_id,
+                SchemaId(1),
+                &StringsSchema(vec![true]),
+                &UnitSchema,
+                d.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(res, CaESchema::Ok(SchemaId(2)));
     }
 
     fn strings(xs: &[((Result<Strings, String>, Result<(), String>), u64, i64)]) -> Vec<Vec<&str>> {
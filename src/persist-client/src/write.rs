@@ -20,7 +20,7 @@ use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 use mz_dyncfg::Config;
 use mz_ore::instrument;
-use mz_ore::task::RuntimeExt;
+use mz_ore::task::{AbortOnDropHandle, RuntimeExt};
 use mz_persist::location::Blob;
 use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
@@ -131,6 +131,7 @@ pub struct WriteHandle<K: Codec, V: Codec, T, D> {
 
     pub(crate) upper: Antichain<T>,
     expire_fn: Option<ExpireFn>,
+    _reencryption_task: Option<AbortOnDropHandle<()>>,
 }
 
 impl<K, V, T, D> WriteHandle<K, V, T, D>
@@ -165,6 +166,15 @@ where
         };
         let upper = machine.applier.clone_upper();
         let expire_fn = Self::expire_fn(machine.clone(), gc.clone(), writer_id.clone());
+        let reencryption_task = match (&compact, &cfg.blob_encryption_keyring) {
+            (Some(compact), Some(_)) => Some(
+                machine
+                    .clone()
+                    .start_reencryption_task(gc.clone(), compact.clone())
+                    .abort_on_drop(),
+            ),
+            _ => None,
+        };
         WriteHandle {
             cfg,
             metrics,
@@ -178,6 +188,7 @@ where
             write_schemas,
             upper,
             expire_fn: Some(expire_fn),
+            _reencryption_task: reencryption_task,
         }
     }
 
@@ -1005,19 +1016,30 @@ impl<K: Codec, V: Codec, T, D> Drop for WriteHandle<K, V, T, D> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+    use std::pin::pin;
     use std::str::FromStr;
     use std::sync::mpsc;
+    use std::time::Duration;
 
     use differential_dataflow::consolidation::consolidate_updates;
     use futures_util::FutureExt;
     use mz_dyncfg::ConfigUpdates;
     use mz_ore::collections::CollectionExt;
+    use mz_ore::metrics::MetricsRegistry;
     use mz_ore::task;
+    use mz_persist::encryption::EncryptedBlob;
+    use mz_persist::location::Consensus;
+    use mz_persist::mem::{MemBlob, MemBlobConfig, MemConsensus};
     use serde_json::json;
 
-    use crate::cache::PersistClientCache;
+    use crate::batch::{BLOB_TARGET_SIZE, INLINE_WRITES_SINGLE_MAX_BYTES, MAX_RUN_LEN};
+    use crate::cache::{PersistClientCache, StateCache};
+    use crate::cfg::BlobKeyring;
+    use crate::internal::machine::BLOB_REENCRYPTION_INTERVAL;
+    use crate::rpc::NoopPubSubSender;
     use crate::tests::{all_ok, new_test_client};
-    use crate::{PersistLocation, ShardId};
+    use crate::{PersistClient, PersistLocation, ShardId};
 
     use super::*;
 
@@ -1106,6 +1128,102 @@ mod tests {
         assert_eq!(actual, all_ok(&expected, 3));
     }
 
+    // Verifies that a writer re-encrypts the data of its shard after the blob
+    // encryption key is rotated, including the parts of hollow runs, even if
+    // it never writes to the shard.
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // too slow
+    async fn reencrypt_idle_shard() {
+        let data = (0..10u64)
+            .map(|i| ((i.to_string(), i.to_string()), i, 1))
+            .collect::<Vec<_>>();
+
+        let blob: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let consensus: Arc<dyn Consensus> = Arc::new(MemConsensus::default());
+        let new_client = |keys: &[(&str, [u8; 32])]| {
+            let keyring = BlobKeyring::new(keys.iter().map(|(id, key)| (id.to_string(), *key)))
+                .expect("valid keyring");
+            let keyring = Arc::new(keyring);
+            let mut cfg = PersistConfig::new_for_tests();
+            cfg.compaction_enabled = true;
+            cfg.set_config(&BLOB_TARGET_SIZE, 10);
+            cfg.set_config(&INLINE_WRITES_SINGLE_MAX_BYTES, 0);
+            cfg.set_config(&MAX_RUN_LEN, 2);
+            cfg.set_config(&BLOB_REENCRYPTION_INTERVAL, Duration::from_millis(10));
+            cfg.blob_encryption_keyring = Some(Arc::clone(&keyring));
+            let blob = Arc::new(EncryptedBlob::new(
+                Arc::clone(&blob),
+                keyring,
+                Arc::clone(&cfg.configs),
+            ));
+            let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+            PersistClient::new(
+                cfg,
+                blob,
+                Arc::clone(&consensus),
+                metrics,
+                Arc::new(IsolatedRuntime::default()),
+                Arc::new(StateCache::new_no_metrics()),
+                Arc::new(NoopPubSubSender),
+            )
+            .expect("client construction failed")
+        };
+
+        let shard_id = ShardId::new();
+        let (mut write, read) = new_client(&[("k1", [1; 32])])
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_compare_and_append(&data, 0, 10).await;
+        assert!(
+            write
+                .machine
+                .applier
+                .all_batches()
+                .iter()
+                .flat_map(|b| b.parts.iter())
+                .any(|p| matches!(p, RunPart::Many(_)))
+        );
+        write.expire().await;
+        read.expire().await;
+
+        // Rotate the key, and open a writer that never writes.
+        let (write, mut read) = new_client(&[("k2", [2; 32]), ("k1", [1; 32])])
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        let machine = &write.machine;
+        let blob = &*machine.applier.state_versions.blob;
+        let reencrypted = async {
+            'retry: loop {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                machine.applier.fetch_and_update_state(None).await;
+                let mut key_ids = BTreeSet::new();
+                for batch in machine.applier.all_batches() {
+                    let mut parts =
+                        pin!(batch.part_stream(shard_id, blob, &machine.applier.metrics));
+                    while let Some(part) = parts.next().await {
+                        // The batch was compacted away since we fetched state.
+                        let Ok(part) = part else {
+                            continue 'retry;
+                        };
+                        if let BatchPart::Hollow(x) = &*part {
+                            key_ids.insert(x.encryption_key_id.clone());
+                        }
+                    }
+                }
+                if key_ids == BTreeSet::from([Some("k2".to_owned())]) {
+                    return;
+                }
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(60), reencrypted)
+            .await
+            .expect("data was not re-encrypted");
+
+        let mut actual = read.expect_snapshot_and_fetch(9).await;
+        consolidate_updates(&mut actual);
+        assert_eq!(actual, all_ok(&data, 9));
+    }
+
     #[mz_ore::test]
     fn writer_id_human_readable_serde() {
         #[derive(Debug, Serialize, Deserialize)]
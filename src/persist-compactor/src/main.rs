@@ -0,0 +1,186 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+#![warn(missing_debug_implementations)]
+
+//! A standalone persist compaction service.
+//!
+//! Registers with the Persist PubSub server and performs the compaction
+//! requests that writers hand off to it when
+//! `persist_compaction_service_enabled` is set. See
+//! [mz_persist_client::compaction_service] for details.
+
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use mz_dyncfg::ConfigUpdates;
+use mz_orchestrator_tracing::{StaticTracingConfig, TracingCliArgs};
+use mz_ore::cli::{self, CliConfig};
+use mz_ore::error::ErrorExt;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::SYSTEM_TIME;
+use mz_ore::url::SensitiveUrl;
+use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::cfg::{BlobKeyring, COMPACTION_MEMORY_BOUND_BYTES, PersistConfig};
+use mz_persist_client::compaction_service::CompactionService;
+use mz_persist_client::rpc::{GrpcPubSubClient, PersistPubSubClient, PersistPubSubClientConfig};
+use mz_persist_client::{PersistLocation, ShardId};
+use mz_repr::Timestamp;
+use mz_storage_types::StorageDiff;
+use mz_storage_types::sources::SourceData;
+use tokio::net::TcpListener;
+use tracing::info;
+
+#[derive(Debug, clap::Parser)]
+#[clap(about = "A standalone persist compaction service", long_about = None)]
+struct Args {
+    /// Handle to the persist consensus system.
+    #[clap(long, env = "CONSENSUS_URL", value_name = "CONSENSUS_URL")]
+    consensus_url: SensitiveUrl,
+    /// Handle to the persist blob storage.
+    #[clap(long, env = "BLOB_URL", value_name = "BLOB_URL")]
+    blob_url: SensitiveUrl,
+    /// A file containing the keys to encrypt persist blob data with.
+    #[clap(long, env = "PERSIST_BLOB_ENCRYPTION_KEYRING", value_name = "PATH")]
+    persist_blob_encryption_keyring: Option<PathBuf>,
+    /// The URL for the Persist PubSub service.
+    #[clap(
+        long,
+        env = "PERSIST_PUBSUB_URL",
+        value_name = "http://HOST:PORT",
+        default_value = "http://localhost:6879"
+    )]
+    persist_pubsub_url: String,
+    /// The maximum number of compactions to perform at once.
+    #[clap(long, env = "COMPACTION_CONCURRENCY", default_value_t = 4)]
+    compaction_concurrency: usize,
+    /// An upper bound on the memory consumption of each compaction.
+    #[clap(long, env = "COMPACTION_MEMORY_BOUND_BYTES")]
+    compaction_memory_bound_bytes: Option<usize>,
+    /// The address of the internal HTTP server.
+    #[clap(
+        long,
+        env = "INTERNAL_HTTP_LISTEN_ADDR",
+        value_name = "HOST:PORT",
+        default_value = "127.0.0.1:6880"
+    )]
+    internal_http_listen_addr: SocketAddr,
+
+    #[clap(flatten)]
+    tracing: TracingCliArgs,
+}
+
+fn main() {
+    let args: Args = cli::parse_args(CliConfig::default());
+
+    // Mirror the tokio Runtime configuration in our production binaries.
+    let ncpus_useful = usize::max(1, std::cmp::min(num_cpus::get(), num_cpus::get_physical()));
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(ncpus_useful)
+        .enable_all()
+        .build()
+        .expect("Failed building the Runtime");
+
+    let metrics_registry = MetricsRegistry::new();
+    let (_, _tracing_guard) = runtime
+        .block_on(args.tracing.configure_tracing(
+            StaticTracingConfig {
+                service_name: "persist-compactor",
+                build_info: mz_persist_client::BUILD_INFO,
+            },
+            metrics_registry.clone(),
+        ))
+        .expect("failed to init tracing");
+
+    if let Err(err) = runtime.block_on(run(args, metrics_registry)) {
+        eprintln!("persist-compactor: fatal: {}", err.display_with_causes());
+        std::process::exit(1);
+    }
+    drop(_tracing_guard);
+}
+
+async fn run(args: Args, metrics_registry: MetricsRegistry) -> Result<(), anyhow::Error> {
+    {
+        let metrics_registry = metrics_registry.clone();
+        info!(
+            "serving internal HTTP server on http://{}/metrics",
+            args.internal_http_listen_addr
+        );
+        let listener = TcpListener::bind(&args.internal_http_listen_addr).await?;
+        mz_ore::task::spawn(
+            || "http_server",
+            axum::serve(
+                listener,
+                axum::Router::new()
+                    .route(
+                        "/metrics",
+                        axum::routing::get(move || async move {
+                            mz_http_util::handle_prometheus(&metrics_registry).await
+                        }),
+                    )
+                    .into_make_service(),
+            )
+            .into_future(),
+        );
+    }
+
+    let mut persist_cfg =
+        PersistConfig::new_default_configs(&mz_persist_client::BUILD_INFO, SYSTEM_TIME.clone());
+    if let Some(path) = &args.persist_blob_encryption_keyring {
+        persist_cfg.blob_encryption_keyring = Some(Arc::new(BlobKeyring::load(path)?));
+    }
+    // Each compaction request carries the dyncfgs of its requester, which are
+    // applied before performing it. Until the first request arrives, we run
+    // with the defaults plus our own overrides, which also take precedence
+    // over the requests'.
+    let mut overrides = ConfigUpdates::default();
+    if let Some(bound) = args.compaction_memory_bound_bytes {
+        overrides.add(&COMPACTION_MEMORY_BOUND_BYTES, bound);
+    }
+    // PubSub clients wait for a first sync before connecting.
+    persist_cfg.apply_from(&overrides);
+
+    let caller_id = std::env::var("HOSTNAME").unwrap_or_else(|_| "compactor".to_string());
+    let pubsub_config = |persist_cfg: &PersistConfig| PersistPubSubClientConfig {
+        url: args.persist_pubsub_url.clone(),
+        caller_id: caller_id.clone(),
+        persist_cfg: persist_cfg.clone(),
+    };
+    let persist_clients =
+        PersistClientCache::new(persist_cfg, &metrics_registry, |persist_cfg, metrics| {
+            GrpcPubSubClient::connect(pubsub_config(persist_cfg), metrics)
+        });
+    // Compaction requests arrive on a connection of their own, so they don't
+    // compete with the state diffs consumed by the client cache.
+    let connection = GrpcPubSubClient::connect(
+        pubsub_config(persist_clients.cfg()),
+        Arc::clone(persist_clients.metrics()),
+    );
+    let client = persist_clients
+        .open(PersistLocation {
+            blob_uri: args.blob_url,
+            consensus_uri: args.consensus_url,
+        })
+        .await?;
+
+    let mut service = CompactionService::new(client, args.compaction_concurrency);
+    service.override_configs(overrides);
+    // Storage collections and the catalog.
+    service.register::<SourceData, (), Timestamp, StorageDiff>();
+    // The txns shard.
+    service.register::<ShardId, Vec<u8>, Timestamp, i64>();
+
+    info!(
+        "compaction service running with concurrency {}",
+        args.compaction_concurrency
+    );
+    service.run(connection).await;
+    Ok(())
+}
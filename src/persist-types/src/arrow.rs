@@ -514,6 +514,17 @@ fn list_range<'a>(
 }
 
 impl<'a> ArrayIdx<'a> {
+    /// Returns the value at this index of a signed integer array as an i64, or
+    /// None if it is null.
+    fn widened_int(&self) -> Option<i64> {
+        match self.array {
+            ArrayOrd::Int16(a) => a.is_valid(self.idx).then(|| a.value(self.idx).into()),
+            ArrayOrd::Int32(a) => a.is_valid(self.idx).then(|| a.value(self.idx).into()),
+            ArrayOrd::Int64(a) => a.is_valid(self.idx).then(|| a.value(self.idx)),
+            _ => panic!("expected a signed integer array"),
+        }
+    }
+
     /// Returns the rough amount of space required for this entry in bytes.
     /// (Not counting nulls, dictionary encoding, or other space optimizations.)
     pub fn goodbytes(&self) -> usize {
@@ -621,6 +632,17 @@ impl<'a> Ord for ArrayIdx<'a> {
                     }
                 }
             }
+            // Integer columns may have been widened by a schema migration, in which case
+            // bounds recorded with the narrower type get compared against migrated data.
+            (
+                ArrayOrd::Int16(_) | ArrayOrd::Int32(_) | ArrayOrd::Int64(_),
+                ArrayOrd::Int16(_) | ArrayOrd::Int32(_) | ArrayOrd::Int64(_),
+            ) => match (self.widened_int(), other.widened_int()) {
+                (Some(_), None) => Ordering::Less,
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(s), Some(o)) => s.cmp(&o),
+            },
             (_, _) => panic!("array types did not match"),
         }
     }
@@ -757,7 +779,8 @@ fn maybe_trim_proto(data_type: &mut proto::DataType, body: &mut ProtoArrayData,
 mod tests {
     use crate::arrow::{ArrayBound, ArrayOrd};
     use arrow::array::{
-        ArrayRef, AsArray, BooleanArray, StringArray, StructArray, UInt64Array, make_array,
+        ArrayRef, AsArray, BooleanArray, Int16Array, Int64Array, StringArray, StructArray,
+        UInt64Array, make_array,
     };
     use arrow::datatypes::{DataType, Field, Fields};
     use mz_ore::assert_none;
@@ -836,6 +859,20 @@ mod tests {
         assert!(prefix_ord.at(2) > full_ord.at(2), "(5) < (4, 'c')");
     }
 
+    #[mz_ore::test]
+    fn widened_int_ord() {
+        let narrow = Int16Array::from(vec![Some(-3), Some(7), None]);
+        let wide = Int64Array::from(vec![Some(-3), Some(100_000), None]);
+        let narrow_ord = ArrayOrd::new(&narrow);
+        let wide_ord = ArrayOrd::new(&wide);
+
+        assert_eq!(narrow_ord.at(0), wide_ord.at(0));
+        assert!(narrow_ord.at(1) < wide_ord.at(1));
+        // Nulls sort last, regardless of width.
+        assert!(narrow_ord.at(1) < wide_ord.at(2));
+        assert_eq!(narrow_ord.at(2), wide_ord.at(2));
+    }
+
     #[mz_ore::test]
     #[should_panic(expected = "array types did not match")]
     fn struct_ord_incompat() {
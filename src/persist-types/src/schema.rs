@@ -98,6 +98,9 @@ pub(crate) enum ArrayMigration {
     NoOp,
     Struct(Vec<StructArrayMigration>),
     List(FieldRef, Box<ArrayMigration>),
+    /// Losslessly widen a signed integer array to the provided (larger)
+    /// integer type.
+    Widen(DataType),
 }
 
 #[derive(Debug, PartialEq)]
@@ -135,6 +138,7 @@ impl ArrayMigration {
             NoOp => false,
             Struct(xs) => xs.iter().any(|x| x.contains_drop()),
             List(_f, x) => x.contains_drop(),
+            Widen(_) => false,
         }
     }
 
@@ -184,6 +188,9 @@ impl ArrayMigration {
                 let entries = entry_migration.migrate(entries);
                 Arc::new(ListArray::new(Arc::clone(field), offsets, entries, nulls))
             }
+            Widen(to) => {
+                arrow::compute::cast(&array, to).expect("widening integer casts are infallible")
+            }
         }
     }
 }
@@ -273,6 +280,9 @@ fn backward_compatible_typ(old: &DataType, new: &DataType) -> Option<ArrayMigrat
                 .collect();
             Some(ArrayMigration::Struct(migrations))
         }
+        // Every value of a narrower signed integer is representable in a wider
+        // one, and widening preserves the sort order.
+        (Int16, Int32 | Int64) | (Int32, Int64) => Some(ArrayMigration::Widen(new.clone())),
         (
             Null | Boolean | Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64
             | Float16 | Float32 | Float64 | Binary | Utf8 | Date32 | Date64 | LargeBinary
@@ -390,6 +400,7 @@ fn backward_compatible_struct(old: &Fields, new: &Fields) -> Option<ArrayMigrati
                 fn recursively_all_nullable(migration: &ArrayMigration) -> bool {
                     match migration {
                         NoOp => true,
+                        Widen(_) => false,
                         List(_field, child) => recursively_all_nullable(child),
                         Struct(children) => children.iter().all(|child| match child {
                             AddFieldNullableAtEnd { .. } | DropField { .. } | MakeNull { .. } => {
@@ -501,6 +512,18 @@ mod tests {
         testcase(Boolean, Utf8, None);
         testcase(Utf8, Boolean, None);
 
+        // Widening signed integers, but never narrowing them.
+        testcase(Int16, Int32, Some(false));
+        testcase(Int16, Int64, Some(false));
+        testcase(Int32, Int64, Some(false));
+        testcase(Int64, Int32, None);
+        testcase(Int32, Int16, None);
+        testcase(
+            struct_([("a", Int16, false), ("b", Utf8, true)]),
+            struct_([("a", Int64, false), ("b", Utf8, true)]),
+            Some(false),
+        );
+
         // Matching structs.
         testcase(
             struct_([("a", Boolean, true)]),
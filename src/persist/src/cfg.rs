@@ -22,7 +22,8 @@ use mz_postgres_client::PostgresClientKnobs;
 use mz_postgres_client::metrics::PostgresClientMetrics;
 
 use crate::azure::{AzureBlob, AzureBlobConfig};
-use crate::file::{FileBlob, FileBlobConfig};
+use crate::file::{FileBlob, FileBlobConfig, FileConsensus, FileConsensusConfig};
+use crate::gcs::{GcsBlob, GcsBlobConfig};
 use crate::location::{Blob, Consensus, Determinate, ExternalError};
 use crate::mem::{MemBlob, MemBlobConfig, MemConsensus};
 use crate::metrics::S3BlobMetrics;
@@ -37,6 +38,7 @@ pub fn all_dyn_configs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::s3::ENABLE_S3_LGALLOC_CC_SIZES)
         .add(&crate::s3::ENABLE_S3_LGALLOC_NONCC_SIZES)
         .add(&crate::postgres::USE_POSTGRES_TUNED_QUERIES)
+        .add(&crate::encryption::BLOB_ENCRYPTION_ALLOW_PLAINTEXT)
 }
 
 /// Config for an implementation of [Blob].
@@ -51,6 +53,8 @@ pub enum BlobConfig {
     Mem(bool),
     /// Config for [AzureBlob].
     Azure(AzureBlobConfig),
+    /// Config for [GcsBlob].
+    Gcs(GcsBlobConfig),
 }
 
 /// Configuration knobs for [Blob].
@@ -74,6 +78,7 @@ impl BlobConfig {
             BlobConfig::File(config) => Ok(Arc::new(FileBlob::open(config).await?)),
             BlobConfig::S3(config) => Ok(Arc::new(S3Blob::open(config).await?)),
             BlobConfig::Azure(config) => Ok(Arc::new(AzureBlob::open(config).await?)),
+            BlobConfig::Gcs(config) => Ok(Arc::new(GcsBlob::open(config).await?)),
             BlobConfig::Mem(tombstone) => {
                 Ok(Arc::new(MemBlob::open(MemBlobConfig::new(tombstone))))
             }
@@ -138,6 +143,21 @@ impl BlobConfig {
 
                 Ok(BlobConfig::S3(config))
             }
+            "gs" => {
+                let bucket = url
+                    .host()
+                    .ok_or_else(|| anyhow!("missing bucket: {}", &url.as_str()))?
+                    .to_string();
+                let prefix = url
+                    .path()
+                    .strip_prefix('/')
+                    .unwrap_or_else(|| url.path())
+                    .to_string();
+                let endpoint = query_params.remove("endpoint").map(|x| x.into_owned());
+
+                let config = GcsBlobConfig::new(bucket, prefix, endpoint, knobs, metrics, cfg)?;
+                Ok(BlobConfig::Gcs(config))
+            }
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem blob in a release binary");
@@ -214,6 +234,8 @@ impl BlobConfig {
 pub enum ConsensusConfig {
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
+    /// Config for [FileConsensus].
+    File(FileConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
     Mem,
 }
@@ -225,6 +247,7 @@ impl ConsensusConfig {
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
+            ConsensusConfig::File(config) => Ok(Arc::new(FileConsensus::open(config).await?)),
             ConsensusConfig::Mem => Ok(Arc::new(MemConsensus::default())),
         }
     }
@@ -240,6 +263,7 @@ impl ConsensusConfig {
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(url, knobs, metrics, dyncfg)?,
             )),
+            "file" => Ok(ConsensusConfig::File(FileConsensusConfig::from(url.path()))),
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem consensus in a release binary");
@@ -0,0 +1,461 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side envelope encryption of [Blob] data.
+//!
+//! Every blob written through an [EncryptedBlob] is encrypted with a fresh,
+//! random data key, which is in turn encrypted ("wrapped") with the current
+//! key-encryption key of a [BlobKeyring]. The wrapped data key and the id of
+//! the key-encryption key are stored in a header in front of the ciphertext,
+//! so that blobs can be decrypted with any key still in the keyring. Rotating
+//! keys is thus a matter of adding a new current key to the keyring: existing
+//! blobs remain readable and are re-encrypted as they are rewritten.
+//!
+//! Unencrypted blobs are rejected, unless plaintext is explicitly allowed with
+//! `persist_blob_encryption_allow_plaintext` while migrating existing data to
+//! encryption.
+
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow, bail};
+use async_trait::async_trait;
+use base64::Engine;
+use bytes::{Buf, Bytes};
+use mz_dyncfg::{Config, ConfigSet};
+use mz_ore::bytes::SegmentedBytes;
+use openssl::rand::rand_bytes;
+use openssl::symm::{Cipher, decrypt_aead, encrypt_aead};
+
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError, value_range};
+
+/// The length of a key-encryption key, and of the data keys it wraps.
+pub const KEY_LEN: usize = 32;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const WRAPPED_KEY_LEN: usize = NONCE_LEN + KEY_LEN + TAG_LEN;
+
+/// The prefix of every encrypted blob, which also versions its format.
+const MAGIC: &[u8; 8] = b"MZPENC01";
+
+pub(crate) const BLOB_ENCRYPTION_ALLOW_PLAINTEXT: Config<bool> = Config::new(
+    "persist_blob_encryption_allow_plaintext",
+    false,
+    "Whether blobs that were written unencrypted are read as is when blob encryption is \
+    configured, instead of being rejected. Only meant to be enabled while migrating existing \
+    data to encryption.",
+);
+
+/// A set of key-encryption keys for an [EncryptedBlob].
+///
+/// New blobs are encrypted with the current key. Blobs encrypted with any key
+/// in the keyring can be decrypted.
+#[derive(Clone)]
+pub struct BlobKeyring {
+    current: String,
+    keys: BTreeMap<String, [u8; KEY_LEN]>,
+}
+
+impl Debug for BlobKeyring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Intentionally don't print the keys themselves.
+        let BlobKeyring { current, keys } = self;
+        f.debug_struct("BlobKeyring")
+            .field("current", current)
+            .field("keys", &keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl BlobKeyring {
+    /// Returns a keyring of the given `(id, key)` pairs, in which the first
+    /// key is the current one.
+    pub fn new(
+        keys: impl IntoIterator<Item = (String, [u8; KEY_LEN])>,
+    ) -> Result<Self, anyhow::Error> {
+        let mut current = None;
+        let mut by_id = BTreeMap::new();
+        for (id, key) in keys {
+            if id.is_empty() || id.len() > usize::from(u8::MAX) {
+                bail!("key ids must be between 1 and 255 bytes long: {:?}", id);
+            }
+            if id.contains(char::is_whitespace) {
+                bail!("key ids must not contain whitespace: {:?}", id);
+            }
+            if by_id.insert(id.clone(), key).is_some() {
+                bail!("duplicate key id: {}", id);
+            }
+            current.get_or_insert(id);
+        }
+        let current = current.ok_or_else(|| anyhow!("keyring must contain at least one key"))?;
+        Ok(BlobKeyring {
+            current,
+            keys: by_id,
+        })
+    }
+
+    /// Reads a keyring from the file at `path`.
+    ///
+    /// The file contains one key per line, as its id and the base64 encoding
+    /// of its 32 bytes separated by whitespace. The key on the first line is
+    /// the current one. Blank lines and lines starting with `#` are ignored.
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("reading keyring {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("parsing keyring {}", path.display()))
+    }
+
+    fn parse(contents: &str) -> Result<Self, anyhow::Error> {
+        let mut keys = Vec::new();
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((id, key)) = line.split_once(char::is_whitespace) else {
+                bail!("expected a key id and key, got a single field");
+            };
+            let key = base64::engine::general_purpose::STANDARD
+                .decode(key.trim())
+                .with_context(|| format!("decoding key {}", id))?;
+            let key = <[u8; KEY_LEN]>::try_from(key.as_slice())
+                .map_err(|_| anyhow!("key {} is {} bytes, expected {}", id, key.len(), KEY_LEN))?;
+            keys.push((id.to_owned(), key));
+        }
+        Self::new(keys)
+    }
+
+    /// Returns the id of the key that new blobs are encrypted with.
+    pub fn current_key_id(&self) -> &str {
+        &self.current
+    }
+
+    /// Encrypts `data`, to be stored under `blob_key`, with the current key.
+    fn encrypt(&self, blob_key: &str, data: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        let cipher = Cipher::aes_256_gcm();
+        let kek = &self.keys[&self.current];
+
+        let mut data_key = [0; KEY_LEN];
+        rand_bytes(&mut data_key)?;
+        let mut wrap_nonce = [0; NONCE_LEN];
+        rand_bytes(&mut wrap_nonce)?;
+        let mut wrap_tag = [0; TAG_LEN];
+        let wrapped_key = encrypt_aead(
+            cipher,
+            kek,
+            Some(&wrap_nonce),
+            self.current.as_bytes(),
+            &data_key,
+            &mut wrap_tag,
+        )?;
+
+        let mut nonce = [0; NONCE_LEN];
+        rand_bytes(&mut nonce)?;
+        let mut tag = [0; TAG_LEN];
+        // Binding the ciphertext to its key prevents blobs from being swapped
+        // for one another.
+        let ciphertext = encrypt_aead(
+            cipher,
+            &data_key,
+            Some(&nonce),
+            blob_key.as_bytes(),
+            data,
+            &mut tag,
+        )?;
+
+        let key_id_len = u8::try_from(self.current.len()).expect("validated in new");
+        let mut buf = Vec::with_capacity(
+            MAGIC.len()
+                + 1
+                + self.current.len()
+                + WRAPPED_KEY_LEN
+                + NONCE_LEN
+                + ciphertext.len()
+                + TAG_LEN,
+        );
+        buf.extend_from_slice(MAGIC);
+        buf.push(key_id_len);
+        buf.extend_from_slice(self.current.as_bytes());
+        buf.extend_from_slice(&wrap_nonce);
+        buf.extend_from_slice(&wrapped_key);
+        buf.extend_from_slice(&wrap_tag);
+        buf.extend_from_slice(&nonce);
+        buf.extend_from_slice(&ciphertext);
+        buf.extend_from_slice(&tag);
+        Ok(buf)
+    }
+
+    /// Decrypts `buf`, stored under `blob_key`, with whichever key in the
+    /// keyring it was encrypted with.
+    fn decrypt(&self, blob_key: &str, buf: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        fn split(buf: &[u8], len: usize) -> Result<(&[u8], &[u8]), anyhow::Error> {
+            buf.split_at_checked(len)
+                .ok_or_else(|| anyhow!("encrypted blob is truncated"))
+        }
+
+        let cipher = Cipher::aes_256_gcm();
+        let (magic, buf) = split(buf, MAGIC.len())?;
+        debug_assert_eq!(magic, MAGIC);
+        let (key_id_len, buf) = split(buf, 1)?;
+        let (key_id, buf) = split(buf, usize::from(key_id_len[0]))?;
+        let key_id = std::str::from_utf8(key_id)?;
+        let kek = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| anyhow!("blob {} encrypted with unknown key {}", blob_key, key_id))?;
+
+        let (wrap_nonce, buf) = split(buf, NONCE_LEN)?;
+        let (wrapped_key, buf) = split(buf, KEY_LEN)?;
+        let (wrap_tag, buf) = split(buf, TAG_LEN)?;
+        let data_key = decrypt_aead(
+            cipher,
+            kek,
+            Some(wrap_nonce),
+            key_id.as_bytes(),
+            wrapped_key,
+            wrap_tag,
+        )
+        .with_context(|| format!("unwrapping data key of blob {}", blob_key))?;
+
+        let (nonce, buf) = split(buf, NONCE_LEN)?;
+        let (ciphertext, tag) = split(buf, buf.len().saturating_sub(TAG_LEN))?;
+        if tag.len() != TAG_LEN {
+            bail!("encrypted blob is truncated");
+        }
+        let data = decrypt_aead(
+            cipher,
+            &data_key,
+            Some(nonce),
+            blob_key.as_bytes(),
+            ciphertext,
+            tag,
+        )
+        .with_context(|| format!("decrypting blob {}", blob_key))?;
+        Ok(data)
+    }
+}
+
+/// Returns whether `value` was written by an [EncryptedBlob].
+fn is_encrypted(value: &SegmentedBytes) -> bool {
+    value.len() >= MAGIC.len() && value.clone().copy_to_bytes(MAGIC.len()) == MAGIC[..]
+}
+
+/// An implementation of [Blob] that encrypts the data it stores in another.
+///
+/// Blobs that were written unencrypted, e.g. before encryption was enabled,
+/// are an error to read, unless `persist_blob_encryption_allow_plaintext` is
+/// set, in which case they are returned as is.
+#[derive(Debug)]
+pub struct EncryptedBlob {
+    blob: Arc<dyn Blob>,
+    keyring: Arc<BlobKeyring>,
+    cfg: Arc<ConfigSet>,
+}
+
+impl EncryptedBlob {
+    /// Returns a new [EncryptedBlob] that encrypts data with the current key
+    /// of `keyring` before storing it in `blob`.
+    pub fn new(blob: Arc<dyn Blob>, keyring: Arc<BlobKeyring>, cfg: Arc<ConfigSet>) -> Self {
+        EncryptedBlob { blob, keyring, cfg }
+    }
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(value) = self.blob.get(key).await? else {
+            return Ok(None);
+        };
+        if !is_encrypted(&value) {
+            if BLOB_ENCRYPTION_ALLOW_PLAINTEXT.get(&self.cfg) {
+                return Ok(Some(value));
+            }
+            return Err(ExternalError::from(Determinate::new(anyhow!(
+                "blob {} is not encrypted",
+                key
+            ))));
+        }
+        let data = self
+            .keyring
+            .decrypt(key, &value.into_contiguous())
+            .map_err(|err| ExternalError::from(Determinate::new(err)))?;
+        Ok(Some(SegmentedBytes::from(data)))
+    }
+
+    /// Returns the bytes in `range` of the decrypted value.
+    ///
+    /// The ciphertext can only be authenticated as a whole, so this fetches
+    /// and decrypts the entire value.
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(value) = self.get(key).await? else {
+            return Ok(None);
+        };
+        value_range(key, value, range).map(Some)
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let data = self
+            .keyring
+            .encrypt(key, &value)
+            .map_err(|err| ExternalError::from(Determinate::new(err)))?;
+        self.blob.set(key, Bytes::from(data)).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        self.blob.delete(key).await
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_dyncfg::ConfigUpdates;
+
+    use crate::mem::{MemBlob, MemBlobConfig};
+
+    use super::*;
+
+    fn keyring(ids: &[&str]) -> Arc<BlobKeyring> {
+        let keys = ids.iter().map(|id| {
+            let mut key = [0; KEY_LEN];
+            rand_bytes(&mut key).expect("rand");
+            (id.to_string(), key)
+        });
+        Arc::new(BlobKeyring::new(keys).expect("valid keyring"))
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_crypto`
+    fn keyring_parse() {
+        let key = base64::engine::general_purpose::STANDARD.encode([7; KEY_LEN]);
+        let keyring = BlobKeyring::parse(&format!("# comment\n\nk2 {key}\nk1\t{key}\n"))
+            .expect("valid keyring");
+        assert_eq!(keyring.current_key_id(), "k2");
+        assert_eq!(keyring.keys.len(), 2);
+        // Keys never show up in debug output.
+        assert_eq!(
+            format!("{:?}", keyring),
+            r#"BlobKeyring { current: "k2", keys: ["k1", "k2"] }"#
+        );
+
+        assert!(BlobKeyring::parse("").is_err());
+        assert!(BlobKeyring::parse("k1").is_err());
+        assert!(BlobKeyring::parse("k1 not-base64").is_err());
+        assert!(BlobKeyring::parse("k1 AAAA").is_err());
+        assert!(BlobKeyring::parse(&format!("k1 {key}\nk1 {key}")).is_err());
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_crypto`
+    async fn encrypted_blob() -> Result<(), ExternalError> {
+        let mem: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::new(false)));
+        let plaintext = Bytes::from("plaintext");
+        let cfg = Arc::new(ConfigSet::default().add(&BLOB_ENCRYPTION_ALLOW_PLAINTEXT));
+
+        // Data written before encryption was enabled is rejected, unless
+        // plaintext is explicitly allowed.
+        mem.set("before", plaintext.clone()).await?;
+
+        let old_keyring = keyring(&["k1"]);
+        let old = EncryptedBlob::new(Arc::clone(&mem), Arc::clone(&old_keyring), Arc::clone(&cfg));
+        assert!(old.get("before").await.is_err());
+        assert!(old.get_range("before", 0..1).await.is_err());
+        let mut updates = ConfigUpdates::default();
+        updates.add(&BLOB_ENCRYPTION_ALLOW_PLAINTEXT, true);
+        updates.apply(&cfg);
+        assert_eq!(
+            old.get("before").await?.map(|x| x.into_contiguous()),
+            Some(plaintext.to_vec())
+        );
+        let mut updates = ConfigUpdates::default();
+        updates.add(&BLOB_ENCRYPTION_ALLOW_PLAINTEXT, false);
+        updates.apply(&cfg);
+        old.set("old", plaintext.clone()).await?;
+        old.set("empty", Bytes::new()).await?;
+        assert_eq!(
+            old.get("old").await?.map(|x| x.into_contiguous()),
+            Some(plaintext.to_vec())
+        );
+        assert_eq!(
+            old.get("empty").await?.map(|x| x.into_contiguous()),
+            Some(vec![])
+        );
+        assert_eq!(old.get("missing").await?, None);
+        assert_eq!(
+            old.get_range("old", 1..3)
+                .await?
+                .map(|x| x.into_contiguous()),
+            Some(plaintext[1..3].to_vec())
+        );
+        assert!(old.get_range("old", 1..100).await.is_err());
+
+        // The underlying blob only sees ciphertext.
+        let stored = mem.get("old").await?.expect("present").into_contiguous();
+        assert!(stored.starts_with(MAGIC));
+        assert!(
+            !stored
+                .windows(plaintext.len())
+                .any(|x| x == plaintext.as_ref())
+        );
+
+        // After rotation, new data is encrypted with the new key and old data
+        // is still readable.
+        let mut keys = keyring(&["k2"])
+            .keys
+            .clone()
+            .into_iter()
+            .collect::<Vec<_>>();
+        keys.extend(old_keyring.keys.clone());
+        let new_keyring = Arc::new(BlobKeyring::new(keys).expect("valid keyring"));
+        assert_eq!(new_keyring.current_key_id(), "k2");
+        let new = EncryptedBlob::new(Arc::clone(&mem), new_keyring, Arc::clone(&cfg));
+        new.set("new", plaintext.clone()).await?;
+        assert_eq!(
+            new.get("old").await?.map(|x| x.into_contiguous()),
+            Some(plaintext.to_vec())
+        );
+        assert_eq!(
+            new.get("new").await?.map(|x| x.into_contiguous()),
+            Some(plaintext.to_vec())
+        );
+        // But data encrypted with the new key can't be read without it.
+        assert!(old.get("new").await.is_err());
+
+        // Tampered, truncated, or moved ciphertext is rejected.
+        let mut tampered = stored.clone();
+        *tampered.last_mut().expect("nonempty") ^= 1;
+        mem.set("old", Bytes::from(tampered)).await?;
+        assert!(old.get("old").await.is_err());
+        mem.set("old", Bytes::from(stored[..stored.len() - 1].to_vec()))
+            .await?;
+        assert!(old.get("old").await.is_err());
+        mem.set("moved", Bytes::from(stored)).await?;
+        assert!(old.get("moved").await.is_err());
+
+        Ok(())
+    }
+}
@@ -7,22 +7,36 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! File backed implementations for testing and benchmarking.
-
-use std::io::ErrorKind;
+//! File backed implementations of [Blob] and [Consensus].
+//!
+//! [FileBlob] is meant for testing and benchmarking. [FileConsensus] is a
+//! durable, embedded [Consensus] for single-node deployments, which would
+//! otherwise need a separate metadata database.
+
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::os::fd::AsRawFd;
+use std::os::unix::fs::MetadataExt;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use anyhow::anyhow;
 use async_trait::async_trait;
 use bytes::Bytes;
 use fail::fail_point;
+use futures_util::{StreamExt, stream};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use tokio::fs::{self, File};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tracing::{debug, warn};
 
 use crate::error::Error;
-use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::location::{
+    Blob, BlobMetadata, CaSResult, Consensus, Determinate, ExternalError, ResultStream, SeqNo,
+    VersionedData,
+};
 
 /// Configuration for opening a [FileBlob].
 #[derive(Debug, Clone)]
@@ -97,6 +111,28 @@ impl Blob for FileBlob {
         Ok(Some(SegmentedBytes::from(buf)))
     }
 
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let file_path = self.blob_path(&FileBlob::replace_forward_slashes(key));
+        let mut file = match File::open(file_path).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let len = file.metadata().await?.len();
+        if range.start > range.end || u64::cast_from(range.end) > len {
+            return Err(anyhow!("range {:?} out of bounds for {} of len {}", range, key, len).into());
+        }
+        file.seek(SeekFrom::Start(u64::cast_from(range.start)))
+            .await?;
+        let mut buf = vec![0; range.len()];
+        file.read_exact(&mut buf).await?;
+        Ok(Some(SegmentedBytes::from(buf)))
+    }
+
     async fn list_keys_and_metadata(
         &self,
         key_prefix: &str,
@@ -269,9 +305,479 @@ impl Blob for FileBlob {
     }
 }
 
+/// Configuration for opening a [FileConsensus].
+#[derive(Debug, Clone)]
+pub struct FileConsensusConfig {
+    base_dir: PathBuf,
+    /// The size the log has to reach before it is compacted.
+    pub(crate) compaction_threshold_bytes: u64,
+}
+
+impl FileConsensusConfig {
+    /// The default size the log has to reach before it is compacted.
+    const DEFAULT_COMPACTION_THRESHOLD_BYTES: u64 = 64 * 1024 * 1024;
+}
+
+impl<P: AsRef<Path>> From<P> for FileConsensusConfig {
+    fn from(base_dir: P) -> Self {
+        FileConsensusConfig {
+            base_dir: base_dir.as_ref().to_path_buf(),
+            compaction_threshold_bytes: Self::DEFAULT_COMPACTION_THRESHOLD_BYTES,
+        }
+    }
+}
+
+/// Implementation of [Consensus] backed by a log file.
+///
+/// Every committed compare_and_set and truncate is appended to a log in the
+/// base directory, and synced to disk before it is acknowledged. The versions
+/// that haven't been truncated are kept in memory, and rebuilt from the log
+/// when it is opened. Once most of the log is made up of truncated versions,
+/// it is rewritten to hold only the live ones.
+///
+/// Several processes on the same host can share a log, e.g. environmentd and
+/// clusterd. Each operation holds an exclusive lock on the base directory, and
+/// starts by applying any records that other processes appended to the log.
+#[derive(Debug)]
+pub struct FileConsensus {
+    core: Arc<Mutex<FileConsensusCore>>,
+}
+
+impl FileConsensus {
+    const LOCK_FILE: &'static str = "LOCK";
+    const LOG_FILE: &'static str = "consensus.log";
+    const TMP_LOG_FILE: &'static str = "consensus.log.tmp";
+
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: FileConsensusConfig) -> Result<Self, ExternalError> {
+        let core = mz_ore::task::spawn_blocking(
+            || "persist::file_consensus::open",
+            move || FileConsensusCore::open(config),
+        )
+        .await??;
+        Ok(FileConsensus {
+            core: Arc::new(Mutex::new(core)),
+        })
+    }
+
+    /// Runs `f` on the up-to-date state of the log, while holding the lock on
+    /// it.
+    async fn run<R, F>(&self, name: &'static str, f: F) -> Result<R, ExternalError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut FileConsensusCore) -> Result<R, ExternalError> + Send + 'static,
+    {
+        let core = Arc::clone(&self.core);
+        mz_ore::task::spawn_blocking(
+            || name,
+            move || {
+                let mut core = core.lock().map_err(Error::from)?;
+                core.locked(f)
+            },
+        )
+        .await?
+    }
+}
+
+#[async_trait]
+impl Consensus for FileConsensus {
+    fn list_keys(&self) -> ResultStream<String> {
+        // Like the other implementations, this is only used in administrative
+        // contexts, so it's fine to block on the lock here.
+        let keys = self
+            .core
+            .lock()
+            .map_err(|err| ExternalError::from(Error::from(err)))
+            .and_then(|mut core| core.locked(|core| Ok(core.data.keys().cloned().collect())));
+        match keys {
+            Ok(keys) => Box::pin(stream::iter::<Vec<String>>(keys).map(Ok)),
+            Err(err) => Box::pin(stream::once(async move { Err(err) })),
+        }
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::file_consensus::head", move |core| {
+            Ok(core
+                .data
+                .get(&key)
+                .and_then(|values| values.last().cloned()))
+        })
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        if let Some(expected) = expected {
+            if new.seqno <= expected {
+                return Err(ExternalError::from(anyhow!(
+                    "new seqno must be strictly greater than expected. Got new: {:?} expected: {:?}",
+                    new.seqno,
+                    expected
+                )));
+            }
+        }
+
+        if new.seqno.0 > i64::MAX.try_into().expect("i64::MAX known to fit in u64") {
+            return Err(ExternalError::from(anyhow!(
+                "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+                new.seqno
+            )));
+        }
+
+        let key = key.to_owned();
+        self.run("persist::file_consensus::cas", move |core| {
+            let seqno = core
+                .data
+                .get(&key)
+                .and_then(|values| values.last())
+                .map(|data| data.seqno);
+            if seqno != expected {
+                return Ok(CaSResult::ExpectationMismatch);
+            }
+
+            core.append(&LogRecord::Set {
+                key: &key,
+                seqno: new.seqno,
+                data: &new.data,
+            })?;
+            core.apply_set(key, new);
+            Ok(CaSResult::Committed)
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::file_consensus::scan", move |core| {
+            let results = match core.data.get(&key) {
+                Some(values) => {
+                    let from_idx = values.partition_point(|x| x.seqno < from);
+                    values[from_idx..].iter().take(limit).cloned().collect()
+                }
+                None => Vec::new(),
+            };
+            Ok(results)
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<usize, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::file_consensus::truncate", move |core| {
+            let current = core.data.get(&key).and_then(|values| values.last());
+            if current.is_none_or(|data| data.seqno < seqno) {
+                return Err(ExternalError::from(anyhow!(
+                    "upper bound too high for truncate: {:?}",
+                    seqno
+                )));
+            }
+
+            let deleted = core.data[&key].partition_point(|x| x.seqno < seqno);
+            if deleted > 0 {
+                core.append(&LogRecord::Truncate { key: &key, seqno })?;
+                core.apply_truncate(&key, seqno);
+                core.maybe_compact()?;
+            }
+            Ok(deleted)
+        })
+        .await
+    }
+}
+
+/// The state of a [FileConsensus], as of the prefix of the log it has applied.
+#[derive(Debug)]
+struct FileConsensusCore {
+    base_dir: PathBuf,
+    lock: std::fs::File,
+    log: std::fs::File,
+    /// The inode of `log`, to notice when another process has replaced the log
+    /// with a compacted one.
+    log_ino: u64,
+    /// The length of the prefix of `log` that has been applied to `data`.
+    applied_len: u64,
+    /// The total length of the records of the versions in `data`.
+    live_len: u64,
+    data: BTreeMap<String, Vec<VersionedData>>,
+    compaction_threshold_bytes: u64,
+}
+
+impl FileConsensusCore {
+    fn open(config: FileConsensusConfig) -> Result<Self, ExternalError> {
+        let FileConsensusConfig {
+            base_dir,
+            compaction_threshold_bytes,
+        } = config;
+        std::fs::create_dir_all(&base_dir).map_err(Error::from)?;
+        let lock = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(base_dir.join(FileConsensus::LOCK_FILE))
+            .map_err(Error::from)?;
+        let mut core = FileConsensusCore {
+            log: Self::open_log(&base_dir)?,
+            base_dir,
+            lock,
+            // Forces `locked` to read the log from the start.
+            log_ino: 0,
+            applied_len: 0,
+            live_len: 0,
+            data: BTreeMap::new(),
+            compaction_threshold_bytes,
+        };
+        core.locked(|_| Ok(()))?;
+        Ok(core)
+    }
+
+    fn open_log(base_dir: &Path) -> Result<std::fs::File, ExternalError> {
+        let log = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .read(true)
+            .open(base_dir.join(FileConsensus::LOG_FILE))
+            .map_err(Error::from)?;
+        Ok(log)
+    }
+
+    /// Runs `f` while holding the lock on the log, after applying any records
+    /// that other processes have appended to it.
+    fn locked<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<R, ExternalError>,
+    ) -> Result<R, ExternalError> {
+        flock(&self.lock, libc::LOCK_EX).map_err(Error::from)?;
+        let result = self.catch_up().and_then(|()| f(self));
+        flock(&self.lock, libc::LOCK_UN).map_err(Error::from)?;
+        result
+    }
+
+    fn catch_up(&mut self) -> Result<(), ExternalError> {
+        let log_path = self.base_dir.join(FileConsensus::LOG_FILE);
+        let ino = std::fs::metadata(&log_path).map_err(Error::from)?.ino();
+        if ino != self.log_ino {
+            // The log was compacted since we last looked at it, so start over.
+            self.log = Self::open_log(&self.base_dir)?;
+            self.log_ino = self.log.metadata().map_err(Error::from)?.ino();
+            self.applied_len = 0;
+            self.live_len = 0;
+            self.data.clear();
+        }
+
+        let mut buf = Vec::new();
+        self.log
+            .seek(SeekFrom::Start(self.applied_len))
+            .map_err(Error::from)?;
+        self.log.read_to_end(&mut buf).map_err(Error::from)?;
+
+        let mut offset = 0;
+        while offset < buf.len() {
+            let Some((record, len)) = LogRecord::decode(&buf[offset..]) else {
+                // A process crashed in the middle of appending this record, so
+                // it was never acknowledged. Nobody else can be writing to the
+                // log while we hold the lock, so it's safe to cut it off.
+                warn!(
+                    "truncating {} bytes of incomplete records from {}",
+                    buf.len() - offset,
+                    log_path.display()
+                );
+                self.log
+                    .set_len(self.applied_len)
+                    .and_then(|()| self.log.sync_data())
+                    .map_err(Error::from)?;
+                break;
+            };
+            match record {
+                LogRecord::Set { key, seqno, data } => {
+                    let data = Bytes::copy_from_slice(data);
+                    self.apply_set(key.to_owned(), VersionedData { seqno, data });
+                }
+                LogRecord::Truncate { key, seqno } => self.apply_truncate(key, seqno),
+            }
+            offset += len;
+            self.applied_len += u64::cast_from(len);
+        }
+        Ok(())
+    }
+
+    /// Durably appends `record` to the log.
+    fn append(&mut self, record: &LogRecord) -> Result<(), ExternalError> {
+        let mut buf = Vec::new();
+        record.encode(&mut buf);
+        if let Err(err) = self.log.write_all(&buf) {
+            // Don't leave a partial record behind for the next append to be
+            // written after.
+            let _ = self.log.set_len(self.applied_len);
+            return Err(Error::from(err).into());
+        }
+        // NB: If this fails, the record may or may not be durable, which the
+        // (indeterminate) error leaves for the caller to find out. Either way,
+        // the next operation reads it back from the log if it is there.
+        self.log.sync_data().map_err(Error::from)?;
+        self.applied_len += u64::cast_from(buf.len());
+        Ok(())
+    }
+
+    fn apply_set(&mut self, key: String, data: VersionedData) {
+        self.live_len += u64::cast_from(LogRecord::set_len(&key, &data.data));
+        self.data.entry(key).or_default().push(data);
+    }
+
+    fn apply_truncate(&mut self, key: &str, seqno: SeqNo) {
+        if let Some(values) = self.data.get_mut(key) {
+            let deleted = values.partition_point(|x| x.seqno < seqno);
+            for value in values.drain(..deleted) {
+                self.live_len -= u64::cast_from(LogRecord::set_len(key, &value.data));
+            }
+        }
+    }
+
+    /// Rewrites the log to contain only the live versions, once it has grown
+    /// large enough and is made up mostly of truncated ones.
+    fn maybe_compact(&mut self) -> Result<(), ExternalError> {
+        if self.applied_len < self.compaction_threshold_bytes
+            || self.applied_len < 2 * self.live_len
+        {
+            return Ok(());
+        }
+
+        let tmp_path = self.base_dir.join(FileConsensus::TMP_LOG_FILE);
+        let log_path = self.base_dir.join(FileConsensus::LOG_FILE);
+        let mut buf = Vec::with_capacity(usize::cast_from(self.live_len));
+        for (key, values) in &self.data {
+            for value in values {
+                LogRecord::Set {
+                    key,
+                    seqno: value.seqno,
+                    data: &value.data,
+                }
+                .encode(&mut buf);
+            }
+        }
+        let mut tmp = std::fs::File::create(&tmp_path).map_err(Error::from)?;
+        tmp.write_all(&buf).map_err(Error::from)?;
+        tmp.sync_all().map_err(Error::from)?;
+        std::fs::rename(&tmp_path, &log_path).map_err(Error::from)?;
+        std::fs::File::open(&self.base_dir)
+            .and_then(|dir| dir.sync_all())
+            .map_err(Error::from)?;
+        debug!(
+            "compacted {} from {} to {} bytes",
+            log_path.display(),
+            self.applied_len,
+            buf.len()
+        );
+
+        self.log = Self::open_log(&self.base_dir)?;
+        self.log_ino = self.log.metadata().map_err(Error::from)?.ino();
+        self.applied_len = u64::cast_from(buf.len());
+        Ok(())
+    }
+}
+
+/// Applies the `flock(2)` `operation` to `file`.
+fn flock(file: &std::fs::File, operation: libc::c_int) -> Result<(), io::Error> {
+    loop {
+        // SAFETY: `file` is an open file, so its descriptor is valid for the
+        // duration of the call.
+        let ret = unsafe { libc::flock(file.as_raw_fd(), operation) };
+        if ret == 0 {
+            return Ok(());
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
+
+/// A record in the log of a [FileConsensus].
+///
+/// Each record is framed as its length and a CRC32 checksum, so that a record
+/// torn by a crash can be told apart from a complete one, followed by a tag and
+/// the fields of the record. All integers are little-endian.
+#[derive(Debug, PartialEq)]
+enum LogRecord<'a> {
+    /// A new version of `key` was committed.
+    Set {
+        key: &'a str,
+        seqno: SeqNo,
+        data: &'a [u8],
+    },
+    /// The versions of `key` before `seqno` were deleted.
+    Truncate { key: &'a str, seqno: SeqNo },
+}
+
+impl<'a> LogRecord<'a> {
+    const HEADER_LEN: usize = 8;
+    const SET_TAG: u8 = 1;
+    const TRUNCATE_TAG: u8 = 2;
+
+    /// Returns the encoded length of a [LogRecord::Set] of `key` to `data`.
+    fn set_len(key: &str, data: &[u8]) -> usize {
+        Self::HEADER_LEN + 1 + 4 + key.len() + 8 + data.len()
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let start = buf.len();
+        buf.extend_from_slice(&[0; Self::HEADER_LEN]);
+        let (tag, key, seqno, data) = match self {
+            LogRecord::Set { key, seqno, data } => (Self::SET_TAG, key, seqno, *data),
+            LogRecord::Truncate { key, seqno } => (Self::TRUNCATE_TAG, key, seqno, &[][..]),
+        };
+        buf.push(tag);
+        let key_len = u32::try_from(key.len()).expect("consensus keys are short");
+        buf.extend_from_slice(&key_len.to_le_bytes());
+        buf.extend_from_slice(key.as_bytes());
+        buf.extend_from_slice(&seqno.0.to_le_bytes());
+        buf.extend_from_slice(data);
+
+        let body = &buf[start + Self::HEADER_LEN..];
+        let len = u32::try_from(body.len()).expect("consensus data is smaller than 4GiB");
+        let crc = crc32fast::hash(body);
+        buf[start..start + 4].copy_from_slice(&len.to_le_bytes());
+        buf[start + 4..start + 8].copy_from_slice(&crc.to_le_bytes());
+    }
+
+    /// Decodes the record at the start of `buf`, returning it along with its
+    /// encoded length, or None if `buf` doesn't start with a complete record.
+    fn decode(buf: &'a [u8]) -> Option<(Self, usize)> {
+        let (header, rest) = buf.split_at_checked(Self::HEADER_LEN)?;
+        let len = usize::cast_from(u32::from_le_bytes(header[0..4].try_into().ok()?));
+        let crc = u32::from_le_bytes(header[4..8].try_into().ok()?);
+        let body = rest.get(..len)?;
+        if crc32fast::hash(body) != crc {
+            return None;
+        }
+
+        let (&tag, body) = body.split_first()?;
+        let (key_len, body) = body.split_at_checked(4)?;
+        let key_len = usize::cast_from(u32::from_le_bytes(key_len.try_into().ok()?));
+        let (key, body) = body.split_at_checked(key_len)?;
+        let key = std::str::from_utf8(key).ok()?;
+        let (seqno, data) = body.split_at_checked(8)?;
+        let seqno = SeqNo(u64::from_le_bytes(seqno.try_into().ok()?));
+        let record = match tag {
+            Self::SET_TAG => LogRecord::Set { key, seqno, data },
+            Self::TRUNCATE_TAG if data.is_empty() => LogRecord::Truncate { key, seqno },
+            _ => return None,
+        };
+        Some((record, Self::HEADER_LEN + len))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::location::tests::blob_impl_test;
+    use crate::location::tests::{blob_impl_test, consensus_impl_test};
 
     use super::*;
 
@@ -296,4 +802,137 @@ mod tests {
 
         Ok(())
     }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let config = FileConsensusConfig::from(temp_dir.path());
+        consensus_impl_test(|| FileConsensus::open(config.clone())).await?;
+
+        // Also run the suite with a log that is compacted on every truncate.
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let mut config = FileConsensusConfig::from(temp_dir.path());
+        config.compaction_threshold_bytes = 0;
+        consensus_impl_test(|| FileConsensus::open(config.clone())).await?;
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_durability() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let mut config = FileConsensusConfig::from(temp_dir.path());
+        config.compaction_threshold_bytes = 0;
+        let data = |seqno| VersionedData {
+            seqno: SeqNo(seqno),
+            data: Bytes::from(format!("data-{seqno}")),
+        };
+
+        let a = FileConsensus::open(config.clone()).await?;
+        let b = FileConsensus::open(config.clone()).await?;
+        assert_eq!(
+            a.compare_and_set("k", None, data(1)).await?,
+            CaSResult::Committed
+        );
+        // Writes are visible to, and checked against, other instances sharing
+        // the log.
+        assert_eq!(b.head("k").await?, Some(data(1)));
+        assert_eq!(
+            b.compare_and_set("k", None, data(2)).await?,
+            CaSResult::ExpectationMismatch
+        );
+        assert_eq!(
+            b.compare_and_set("k", Some(SeqNo(1)), data(2)).await?,
+            CaSResult::Committed
+        );
+        assert_eq!(
+            a.compare_and_set("k", Some(SeqNo(2)), data(3)).await?,
+            CaSResult::Committed
+        );
+
+        // Truncating compacts the log, which the other instance notices.
+        assert_eq!(a.truncate("k", SeqNo(2)).await?, 1);
+        assert_eq!(b.scan("k", SeqNo(0), 10).await?, vec![data(2), data(3)]);
+        assert_eq!(
+            b.compare_and_set("k", Some(SeqNo(3)), data(4)).await?,
+            CaSResult::Committed
+        );
+        drop(a);
+        drop(b);
+
+        // A record torn by a crash is discarded when the log is reopened.
+        let log_path = temp_dir.path().join(FileConsensus::LOG_FILE);
+        let mut torn = Vec::new();
+        LogRecord::Set {
+            key: "k",
+            seqno: SeqNo(5),
+            data: b"data-5",
+        }
+        .encode(&mut torn);
+        let mut log = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&log_path)
+            .map_err(Error::from)?;
+        log.write_all(&torn[..torn.len() - 1])
+            .map_err(Error::from)?;
+        drop(log);
+
+        let c = FileConsensus::open(config).await?;
+        assert_eq!(
+            c.scan("k", SeqNo(0), 10).await?,
+            vec![data(2), data(3), data(4)]
+        );
+        assert_eq!(
+            c.compare_and_set("k", Some(SeqNo(4)), data(5)).await?,
+            CaSResult::Committed
+        );
+        assert_eq!(c.head("k").await?, Some(data(5)));
+
+        Ok(())
+    }
+
+    #[mz_ore::test]
+    fn log_record_roundtrip() {
+        let records = [
+            LogRecord::Set {
+                key: "key",
+                seqno: SeqNo(7),
+                data: b"data",
+            },
+            LogRecord::Set {
+                key: "",
+                seqno: SeqNo(u64::MAX),
+                data: b"",
+            },
+            LogRecord::Truncate {
+                key: "key",
+                seqno: SeqNo(3),
+            },
+        ];
+        let mut buf = Vec::new();
+        for record in &records {
+            record.encode(&mut buf);
+        }
+        assert_eq!(
+            LogRecord::set_len("key", b"data"),
+            LogRecord::decode(&buf).expect("complete record").1
+        );
+
+        let mut offset = 0;
+        for record in &records {
+            let (decoded, len) = LogRecord::decode(&buf[offset..]).expect("complete record");
+            assert_eq!(&decoded, record);
+            // Any prefix of a record, or a corrupted record, doesn't decode.
+            for prefix in 0..len {
+                assert_eq!(LogRecord::decode(&buf[offset..offset + prefix]), None);
+            }
+            let mut corrupted = buf[offset..offset + len].to_vec();
+            corrupted[len - 1] ^= 1;
+            assert_eq!(LogRecord::decode(&corrupted), None);
+            offset += len;
+        }
+        assert_eq!(offset, buf.len());
+    }
 }
@@ -0,0 +1,1165 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A Google Cloud Storage implementation of [Blob] storage.
+//!
+//! This speaks the GCS JSON API directly. GCS has no equivalent of S3's
+//! multipart uploads, so large blobs are uploaded as parts in temporary objects
+//! that are then composed into the final object. Downloads of large objects are
+//! split into concurrent ranged reads.
+
+use std::cmp;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use futures_util::stream::FuturesOrdered;
+use mz_dyncfg::ConfigSet;
+use mz_ore::bytes::SegmentedBytes;
+use mz_ore::cast::CastFrom;
+use mz_ore::lgbytes::MetricsRegion;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::retry::{Retry, RetryResult};
+use mz_ore::task::RuntimeExt;
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use tokio::runtime::Handle as AsyncHandle;
+use tokio::sync::Mutex;
+use tracing::{Instrument, debug, debug_span, info, trace, warn};
+use url::Url;
+use uuid::Uuid;
+
+use crate::cfg::BlobKnobs;
+use crate::error::Error;
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::metrics::S3BlobMetrics;
+use crate::s3::{MinElapsed, MultipartChunkIter, MultipartConfig};
+
+/// The endpoint of the GCS JSON API.
+const DEFAULT_ENDPOINT: &str = "https://storage.googleapis.com";
+
+/// The maximum number of objects that can be composed into one with a single
+/// request.
+///
+/// From <https://cloud.google.com/storage/docs/composite-objects>
+const MAX_COMPOSE_SOURCES: usize = 32;
+
+/// Configuration for opening a [GcsBlob].
+#[derive(Clone, Debug)]
+pub struct GcsBlobConfig {
+    // Like [crate::azure::AzureBlobConfig], this reuses [S3BlobMetrics] so that
+    // dashboards for S3 work unchanged for GCS.
+    metrics: S3BlobMetrics,
+    client: GcsClient,
+    bucket: String,
+    prefix: String,
+    cfg: Arc<ConfigSet>,
+}
+
+impl GcsBlobConfig {
+    const EXTERNAL_TESTS_GCS_BUCKET: &'static str = "MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET";
+
+    /// Returns a new [GcsBlobConfig] for use in production.
+    ///
+    /// Stores objects in the given bucket prepended with the (possibly empty)
+    /// prefix. Requests are authenticated with access tokens for the default
+    /// service account of the metadata server, e.g. via GKE Workload Identity.
+    ///
+    /// If an endpoint is given, requests are sent to it instead of GCS and are
+    /// not authenticated. This is meant for emulators like fake-gcs-server.
+    pub fn new(
+        bucket: String,
+        prefix: String,
+        endpoint: Option<String>,
+        knobs: Box<dyn BlobKnobs>,
+        metrics: S3BlobMetrics,
+        cfg: Arc<ConfigSet>,
+    ) -> Result<Self, Error> {
+        let (endpoint, credentials) = match endpoint {
+            Some(endpoint) => {
+                info!("Connecting to GCS emulator at {endpoint}");
+                (endpoint, GcsCredentials::Anonymous)
+            }
+            None => (
+                DEFAULT_ENDPOINT.to_string(),
+                GcsCredentials::MetadataServer {
+                    token: Arc::new(Mutex::new(None)),
+                },
+            ),
+        };
+        let endpoint = Url::parse(&endpoint)
+            .map_err(|err| Error::String(format!("invalid gcs endpoint {endpoint}: {err}")))?;
+
+        let http = reqwest::ClientBuilder::new()
+            .connect_timeout(knobs.connect_timeout())
+            .read_timeout(knobs.read_timeout())
+            .build()
+            .map_err(|err| Error::String(format!("building gcs http client: {err}")))?;
+
+        let client = GcsClient {
+            http,
+            endpoint,
+            credentials,
+            knobs: Arc::from(knobs),
+            metrics: metrics.clone(),
+        };
+        Ok(GcsBlobConfig {
+            metrics,
+            client,
+            bucket,
+            prefix,
+            cfg,
+        })
+    }
+
+    /// Returns a new [GcsBlobConfig] for use in unit tests.
+    ///
+    /// Like the other external storage tests, these are no-ops unless the
+    /// `MZ_PERSIST_EXTERNAL_STORAGE_TEST_GCS_BUCKET` env var is set, in which
+    /// case they run against a fake-gcs-server listening on `localhost:40112`.
+    pub fn new_for_test() -> Result<Option<Self>, Error> {
+        struct TestBlobKnobs;
+        impl Debug for TestBlobKnobs {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("TestBlobKnobs").finish_non_exhaustive()
+            }
+        }
+        impl BlobKnobs for TestBlobKnobs {
+            fn operation_timeout(&self) -> Duration {
+                Duration::from_secs(30)
+            }
+
+            fn operation_attempt_timeout(&self) -> Duration {
+                Duration::from_secs(10)
+            }
+
+            fn connect_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn read_timeout(&self) -> Duration {
+                Duration::from_secs(5)
+            }
+
+            fn is_cc_active(&self) -> bool {
+                false
+            }
+        }
+
+        let bucket = match std::env::var(Self::EXTERNAL_TESTS_GCS_BUCKET) {
+            Ok(bucket) => bucket,
+            Err(_) => {
+                if mz_ore::env::is_var_truthy("CI") {
+                    panic!("CI is supposed to run this test but something has gone wrong!");
+                }
+                return Ok(None);
+            }
+        };
+
+        let prefix = Uuid::new_v4().to_string();
+        let metrics = S3BlobMetrics::new(&MetricsRegistry::new());
+        let config = GcsBlobConfig::new(
+            bucket,
+            prefix,
+            Some("http://localhost:40112".to_string()),
+            Box::new(TestBlobKnobs),
+            metrics,
+            Arc::new(ConfigSet::default()),
+        )?;
+        Ok(Some(config))
+    }
+
+    /// Returns a clone of Self with a new v4 uuid prefix.
+    pub fn clone_with_new_uuid_prefix(&self) -> Self {
+        let mut ret = self.clone();
+        ret.prefix = Uuid::new_v4().to_string();
+        ret
+    }
+}
+
+/// Implementation of [Blob] backed by Google Cloud Storage.
+#[derive(Debug)]
+pub struct GcsBlob {
+    metrics: S3BlobMetrics,
+    client: GcsClient,
+    bucket: String,
+    prefix: String,
+    // Maximum number of keys we get information about per list request.
+    //
+    // Defaults to 1000 which is the current GCS max.
+    max_keys: usize,
+    multipart_config: MultipartConfig,
+    _cfg: Arc<ConfigSet>,
+}
+
+impl GcsBlob {
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: GcsBlobConfig) -> Result<Self, ExternalError> {
+        if let GcsCredentials::Anonymous = config.client.credentials {
+            // Emulators start out empty, so create the bucket for them. See
+            // the similar logic in [crate::azure::AzureBlob::open].
+            if let Err(err) = config.client.create_bucket(&config.bucket).await {
+                warn!("Failed to create bucket: {err:#}");
+            }
+        }
+
+        let ret = GcsBlob {
+            metrics: config.metrics,
+            client: config.client,
+            bucket: config.bucket,
+            prefix: config.prefix,
+            max_keys: 1_000,
+            multipart_config: MultipartConfig::default(),
+            _cfg: config.cfg,
+        };
+        // Connect before returning success. We don't particularly care about
+        // what's stored in this blob (nothing writes to it, so presumably it's
+        // empty) just that we were able and allowed to fetch it.
+        let _ = ret.get("HEALTH_CHECK").await?;
+        Ok(ret)
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        format!("{}/{}", self.prefix, key)
+    }
+
+    /// Returns the name of a temporary object holding one part of a multipart
+    /// upload.
+    ///
+    /// These live outside of `prefix/` so that they are never returned when
+    /// listing keys. Parts orphaned by a failed upload are expected to be
+    /// cleaned up by a lifecycle rule on the bucket.
+    fn get_part_path(&self, upload_id: &Uuid, part_num: u32) -> String {
+        format!("{}.multipart/{}/{}", self.prefix, upload_id, part_num)
+    }
+}
+
+#[async_trait]
+impl Blob for GcsBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let start_overall = Instant::now();
+        let path = self.get_path(key);
+        let url = self.client.object_url(&self.bucket, &path);
+        let part_size = self.multipart_config.multipart_chunk_size;
+
+        // GCS keeps no record of the parts a composed object was uploaded with,
+        // so instead we read objects in ranges of the multipart chunk size. The
+        // first request tells us the total size of the object, which we use to
+        // fetch the remaining ranges concurrently. If the first request returns
+        // the whole object instead of the requested range, there is nothing
+        // left to fetch.
+        let min_body_elapsed = Arc::new(MinElapsed::default());
+        let min_header_elapsed = Arc::new(MinElapsed::default());
+        self.metrics.get_part.inc();
+
+        let header_start = Instant::now();
+        let first_part = self
+            .client
+            .send("GetObject", || {
+                self.client
+                    .http
+                    .get(url.clone())
+                    .query(&[("alt", "media")])
+                    .header(RANGE, format!("bytes=0-{}", part_size - 1))
+            })
+            .await
+            .context("gcs get meta err")?;
+        min_header_elapsed.observe(header_start.elapsed(), "gcs download first part header");
+
+        let total_len = match first_part.status() {
+            StatusCode::NOT_FOUND => return Ok(None),
+            // GCS refuses any range of an empty object.
+            StatusCode::RANGE_NOT_SATISFIABLE => return Ok(Some(SegmentedBytes::default())),
+            StatusCode::OK => None,
+            StatusCode::PARTIAL_CONTENT => {
+                let total_len = first_part
+                    .headers()
+                    .get(CONTENT_RANGE)
+                    .and_then(|range| range.to_str().ok())
+                    .and_then(|range| range.rsplit_once('/'))
+                    .and_then(|(_, total)| total.parse::<u64>().ok());
+                match total_len {
+                    Some(total_len) => Some(usize::cast_from(total_len)),
+                    None => return Err(anyhow!("gcs get response missing object size").into()),
+                }
+            }
+            status => {
+                self.client
+                    .update_error_metrics("GetObject", status.as_str());
+                return Err(status_error("get", first_part).await.into());
+            }
+        };
+        // Pin the remaining reads to the generation we started reading, so that
+        // we never stitch together the ranges of two different objects.
+        let generation = first_part
+            .headers()
+            .get("x-goog-generation")
+            .and_then(|generation| generation.to_str().ok())
+            .map(|generation| generation.to_owned());
+
+        let num_parts = match total_len {
+            Some(total_len) => cmp::max(1, total_len.div_ceil(part_size)),
+            None => 1,
+        };
+        trace!(
+            "gcs download first header took {:?} ({num_parts} parts)",
+            start_overall.elapsed(),
+        );
+
+        let mut body_futures = FuturesOrdered::new();
+        let mut first_part = Some(first_part);
+        for part_idx in 0..num_parts {
+            let min_header_elapsed = Arc::clone(&min_header_elapsed);
+            let min_body_elapsed = Arc::clone(&min_body_elapsed);
+            let first_part = first_part.take();
+            let url = &url;
+            let generation = &generation;
+            let request_future = async move {
+                let response = match first_part {
+                    Some(first_part) => {
+                        assert_eq!(part_idx, 0, "only the first part should be prefetched");
+                        first_part
+                    }
+                    None => {
+                        assert_ne!(part_idx, 0, "first part should be prefetched");
+                        let total_len =
+                            total_len.expect("only ranged responses have remaining parts");
+                        let start = part_idx * part_size;
+                        let end = cmp::min(start + part_size, total_len);
+                        let header_start = Instant::now();
+                        self.metrics.get_part.inc();
+                        let response = self
+                            .client
+                            .send("GetObject", || {
+                                let mut request = self
+                                    .client
+                                    .http
+                                    .get(url.clone())
+                                    .query(&[("alt", "media")])
+                                    .header(RANGE, format!("bytes={}-{}", start, end - 1));
+                                if let Some(generation) = generation {
+                                    request = request.query(&[("generation", generation)]);
+                                }
+                                request
+                            })
+                            .await
+                            .context("gcs get meta err")?;
+                        if !response.status().is_success() {
+                            self.client
+                                .update_error_metrics("GetObject", response.status().as_str());
+                            return Err(status_error("get", response).await);
+                        }
+                        min_header_elapsed
+                            .observe(header_start.elapsed(), "gcs download part header");
+                        response
+                    }
+                };
+
+                let body_start = Instant::now();
+                let body = read_body(response, &self.metrics).await?;
+                min_body_elapsed.observe(body_start.elapsed(), "gcs download part body");
+                Ok::<_, anyhow::Error>(body)
+            };
+            body_futures.push_back(request_future);
+        }
+
+        let mut segments = SegmentedBytes::with_capacity(num_parts);
+        while let Some(result) = body_futures.next().await {
+            let body = result
+                .inspect_err(|e| {
+                    self.metrics
+                        .error_counts
+                        .with_label_values(&["GetObjectStream", e.to_string().as_str()])
+                        .inc()
+                })
+                .context("gcs get body err")?;
+            segments.push(body);
+        }
+
+        debug!(
+            "gcs GetObject took {:?} ({} parts)",
+            start_overall.elapsed(),
+            num_parts
+        );
+        Ok(Some(segments))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        let mut page_token = None;
+        // we only want to return keys that match the specified blob key prefix
+        let blob_key_prefix = self.get_path(key_prefix);
+        // but we want to exclude the shared root prefix from our returned keys,
+        // so only the blob key itself is passed in to `f`
+        let strippable_root_prefix = format!("{}/", self.prefix);
+        let max_keys = self.max_keys.to_string();
+
+        loop {
+            self.metrics.list_objects.inc();
+            let resp: ListObjectsResponse = self
+                .client
+                .send_json("ListObjects", || {
+                    let mut request = self
+                        .client
+                        .http
+                        .get(self.client.objects_url(&self.bucket))
+                        .query(&[
+                            ("prefix", blob_key_prefix.as_str()),
+                            ("maxResults", max_keys.as_str()),
+                            ("fields", "items(name,size),nextPageToken"),
+                        ]);
+                    if let Some(page_token) = &page_token {
+                        request = request.query(&[("pageToken", page_token)]);
+                    }
+                    request
+                })
+                .await
+                .context("list bucket error")?;
+
+            for object in resp.items {
+                match object.name.strip_prefix(&strippable_root_prefix) {
+                    Some(key) => {
+                        let size_in_bytes = object.size()?;
+                        f(BlobMetadata { key, size_in_bytes });
+                    }
+                    None => {
+                        return Err(ExternalError::from(anyhow!(
+                            "found key with invalid prefix: {}",
+                            object.name
+                        )));
+                    }
+                }
+            }
+
+            match resp.next_page_token {
+                Some(next_page_token) => page_token = Some(next_page_token),
+                None => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let value_len = value.len();
+        if self
+            .multipart_config
+            .should_multipart(value_len)
+            .map_err(anyhow::Error::msg)?
+        {
+            self.set_multi_part(key, value)
+                .instrument(debug_span!("gcsset_multi", payload_len = value_len))
+                .await
+        } else {
+            self.set_single_part(key, value).await
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        // As with S3, two concurrent deletes of the same key might both think
+        // they did the actual deletion. This return value is only used for
+        // metrics, so it's unfortunate, but fine.
+        let path = self.get_path(key);
+        let url = self.client.object_url(&self.bucket, &path);
+        self.metrics.delete_head.inc();
+        let head = self
+            .client
+            .send("GetObjectMetadata", || {
+                self.client
+                    .http
+                    .get(url.clone())
+                    .query(&[("fields", "name,size")])
+            })
+            .await
+            .context("gcs delete head err")?;
+        let size_bytes = match head.status() {
+            StatusCode::NOT_FOUND => return Ok(None),
+            status if status.is_success() => head
+                .json::<ObjectResource>()
+                .await
+                .context("gcs delete head err")?
+                .size()?,
+            status => {
+                self.client
+                    .update_error_metrics("GetObjectMetadata", status.as_str());
+                return Err(status_error("delete head", head).await.into());
+            }
+        };
+
+        self.metrics.delete_object.inc();
+        let resp = self
+            .client
+            .send("DeleteObject", || self.client.http.delete(url.clone()))
+            .await
+            .context("gcs delete object err")?;
+        match resp.status() {
+            StatusCode::NOT_FOUND => {}
+            status if status.is_success() => {}
+            status => {
+                self.client
+                    .update_error_metrics("DeleteObject", status.as_str());
+                return Err(status_error("delete object", resp).await.into());
+            }
+        }
+        Ok(Some(usize::cast_from(size_bytes)))
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        let path = self.get_path(key);
+        let url = self.client.object_url(&self.bucket, &path);
+
+        let head = self
+            .client
+            .send("GetObjectMetadata", || {
+                self.client
+                    .http
+                    .get(url.clone())
+                    .query(&[("fields", "name")])
+            })
+            .await
+            .context("gcs restore head err")?;
+        match head.status() {
+            StatusCode::NOT_FOUND => {}
+            status if status.is_success() => return Ok(()),
+            status => {
+                self.client
+                    .update_error_metrics("GetObjectMetadata", status.as_str());
+                return Err(status_error("restore head", head).await.into());
+            }
+        }
+
+        // With object versioning enabled on the bucket, a deleted object lives
+        // on as a noncurrent generation. Restore the most recent one by copying
+        // it over the live object.
+        let mut generation = None;
+        let mut page_token = None;
+        loop {
+            let versions: ListObjectsResponse = self
+                .client
+                .send_json("ListObjectVersions", || {
+                    let mut request = self
+                        .client
+                        .http
+                        .get(self.client.objects_url(&self.bucket))
+                        .query(&[
+                            ("prefix", path.as_str()),
+                            ("versions", "true"),
+                            ("fields", "items(name,generation),nextPageToken"),
+                        ]);
+                    if let Some(page_token) = &page_token {
+                        request = request.query(&[("pageToken", page_token)]);
+                    }
+                    request
+                })
+                .await
+                .context("listing object versions during restore")?;
+            let page_generation = versions
+                .items
+                .into_iter()
+                // We need to check that any versions we're looking at have the
+                // right key, not just a key with our key as a prefix.
+                .filter(|object| object.name == path)
+                .filter_map(|object| object.generation?.parse::<u64>().ok())
+                .max();
+            generation = cmp::max(generation, page_generation);
+
+            match versions.next_page_token {
+                Some(next_page_token) => page_token = Some(next_page_token),
+                None => break,
+            }
+        }
+        let Some(generation) = generation else {
+            return Err(Determinate::new(anyhow!(
+                "unable to restore {key} in gcs: no valid version exists"
+            ))
+            .into());
+        };
+
+        let rewrite_url = self.client.rewrite_url(&self.bucket, &path);
+        let generation = generation.to_string();
+        let mut rewrite_token: Option<String> = None;
+        loop {
+            let resp: RewriteResponse = self
+                .client
+                .send_json("RewriteObject", || {
+                    let mut request = self
+                        .client
+                        .http
+                        .post(rewrite_url.clone())
+                        .query(&[("sourceGeneration", generation.as_str())])
+                        .json(&EmptyRequest {});
+                    if let Some(rewrite_token) = &rewrite_token {
+                        request = request.query(&[("rewriteToken", rewrite_token)]);
+                    }
+                    request
+                })
+                .await
+                .context("restoring object version")?;
+            if resp.done {
+                return Ok(());
+            }
+            rewrite_token = resp.rewrite_token;
+        }
+    }
+}
+
+impl GcsBlob {
+    async fn set_single_part(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let start_overall = Instant::now();
+        let path = self.get_path(key);
+
+        let value_len = value.len();
+        let part_span = debug_span!("gcsset_single", payload_len = value_len);
+        self.metrics.set_single.inc();
+        self.client
+            .upload(&self.bucket, &path, value)
+            .instrument(part_span)
+            .await
+            .context("set single part")?;
+        debug!(
+            "gcs PutObject single done {}b / {:?}",
+            value_len,
+            start_overall.elapsed()
+        );
+        Ok(())
+    }
+
+    async fn set_multi_part(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let start_overall = Instant::now();
+        let path = self.get_path(key);
+        let upload_id = Uuid::new_v4();
+        trace!("gcs PutObject multi start {}b", value.len());
+        self.metrics.set_multi_create.inc();
+
+        let async_runtime = AsyncHandle::try_current().map_err(anyhow::Error::new)?;
+
+        // Fire off all the individual parts, each to its own temporary object.
+        let start_parts = Instant::now();
+        let mut part_futs = Vec::new();
+        // Unlike S3, GCS has no minimum part size, so this uses the chunk size
+        // as is.
+        let part_iter =
+            MultipartChunkIter::new(self.multipart_config.multipart_chunk_size, value.len());
+        for (part_num, part_range) in part_iter {
+            let part_path = self.get_part_path(&upload_id, part_num);
+            let part_span = debug_span!("gcsset_multi_part", payload_len = part_range.len());
+            let client = self.client.clone();
+            let bucket = self.bucket.clone();
+            let part = value.slice(part_range);
+            self.metrics.set_multi_part.inc();
+            let part_fut = async_runtime.spawn_named(|| "persist_gcsblob_put_part", {
+                let part_path = part_path.clone();
+                async move {
+                    let res = client.upload(&bucket, &part_path, part).await;
+                    (start_parts.elapsed(), res)
+                }
+                .instrument(part_span)
+            });
+            part_futs.push((part_path, part_fut));
+        }
+        let parts_len = part_futs.len();
+
+        let min_part_elapsed = MinElapsed::default();
+        let mut parts = Vec::with_capacity(parts_len);
+        for (part_path, part_fut) in part_futs {
+            let (this_part_elapsed, part_res) = part_fut
+                .await
+                .inspect_err(|_| {
+                    self.metrics
+                        .error_counts
+                        .with_label_values(&["UploadPart", "AsyncSpawnError"])
+                        .inc()
+                })
+                .map_err(|err| anyhow!(err).context("gcs spawn err"))?;
+            part_res.context("gcs upload part err")?;
+            parts.push(part_path);
+            min_part_elapsed.observe(this_part_elapsed, "gcs upload part took");
+        }
+        trace!(
+            "gcs upload parts overall took {:?} ({} parts)",
+            start_parts.elapsed(),
+            parts_len
+        );
+
+        // Compose the parts into the final object. A single compose request
+        // takes a bounded number of sources, so larger uploads are composed in
+        // rounds through intermediate temporary objects.
+        let start_complete = Instant::now();
+        self.metrics.set_multi_complete.inc();
+        let mut temporaries = parts.clone();
+        let mut sources = parts;
+        let mut round = 0;
+        while sources.len() > MAX_COMPOSE_SOURCES {
+            round += 1;
+            let mut composed = Vec::new();
+            for (idx, chunk) in sources.chunks(MAX_COMPOSE_SOURCES).enumerate() {
+                let dest = format!(
+                    "{}.multipart/{}/compose-{}-{}",
+                    self.prefix, upload_id, round, idx
+                );
+                self.client
+                    .compose(&self.bucket, chunk, &dest)
+                    .await
+                    .context("gcs compose err")?;
+                composed.push(dest);
+            }
+            temporaries.extend(composed.iter().cloned());
+            sources = composed;
+        }
+        self.client
+            .compose(&self.bucket, &sources, &path)
+            .instrument(debug_span!("gcsset_multi_complete", num_parts = parts_len))
+            .await
+            .context("gcs compose err")?;
+        trace!("gcs compose took {:?}", start_complete.elapsed());
+
+        // The temporary objects are garbage now, but there's no need to make
+        // the caller wait for them to be deleted. Any that we fail to delete
+        // are cleaned up by the bucket's lifecycle rule.
+        let client = self.client.clone();
+        let bucket = self.bucket.clone();
+        mz_ore::task::spawn(|| "persist_gcsblob_delete_parts", async move {
+            for temporary in temporaries {
+                let url = client.object_url(&bucket, &temporary);
+                if let Err(err) = client
+                    .send("DeleteObject", || client.http.delete(url.clone()))
+                    .await
+                {
+                    debug!("failed to delete gcs multipart part {temporary}: {err:#}");
+                }
+            }
+        });
+
+        debug!(
+            "gcs PutObject multi done {}b / {:?} ({} parts)",
+            value.len(),
+            start_overall.elapsed(),
+            parts_len
+        );
+        Ok(())
+    }
+}
+
+/// A client for the GCS JSON API.
+#[derive(Clone, Debug)]
+struct GcsClient {
+    http: reqwest::Client,
+    endpoint: Url,
+    credentials: GcsCredentials,
+    knobs: Arc<dyn BlobKnobs>,
+    metrics: S3BlobMetrics,
+}
+
+/// How requests to GCS are authenticated.
+#[derive(Clone, Debug)]
+enum GcsCredentials {
+    /// Requests are not authenticated, as expected by emulators.
+    Anonymous,
+    /// Requests carry an access token for the default service account of the
+    /// metadata server, cached until shortly before it expires.
+    MetadataServer {
+        token: Arc<Mutex<Option<(String, Instant)>>>,
+    },
+}
+
+impl GcsClient {
+    fn url(&self, segments: &[&str]) -> Url {
+        let mut url = self.endpoint.clone();
+        url.path_segments_mut()
+            .expect("gcs endpoint is a base url")
+            .pop_if_empty()
+            .extend(segments);
+        url
+    }
+
+    fn objects_url(&self, bucket: &str) -> Url {
+        self.url(&["storage", "v1", "b", bucket, "o"])
+    }
+
+    fn object_url(&self, bucket: &str, name: &str) -> Url {
+        self.url(&["storage", "v1", "b", bucket, "o", name])
+    }
+
+    fn rewrite_url(&self, bucket: &str, name: &str) -> Url {
+        self.url(&[
+            "storage",
+            "v1",
+            "b",
+            bucket,
+            "o",
+            name,
+            "rewriteTo",
+            "b",
+            bucket,
+            "o",
+            name,
+        ])
+    }
+
+    async fn create_bucket(&self, bucket: &str) -> Result<(), anyhow::Error> {
+        let url = self.url(&["storage", "v1", "b"]);
+        let resp = self
+            .send("CreateBucket", || {
+                self.http
+                    .post(url.clone())
+                    .query(&[("project", "materialize")])
+                    .json(&BucketResource { name: bucket })
+            })
+            .await?;
+        match resp.status() {
+            StatusCode::CONFLICT => Ok(()),
+            status if status.is_success() => Ok(()),
+            _ => Err(status_error("create bucket", resp).await),
+        }
+    }
+
+    async fn upload(&self, bucket: &str, name: &str, value: Bytes) -> Result<(), anyhow::Error> {
+        let url = self.url(&["upload", "storage", "v1", "b", bucket, "o"]);
+        let _: ObjectResource = self
+            .send_json("PutObject", || {
+                self.http
+                    .post(url.clone())
+                    .query(&[("uploadType", "media"), ("name", name)])
+                    .body(value.clone())
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn compose(
+        &self,
+        bucket: &str,
+        sources: &[String],
+        dest: &str,
+    ) -> Result<(), anyhow::Error> {
+        let url = self.url(&["storage", "v1", "b", bucket, "o", dest, "compose"]);
+        let body = ComposeRequest {
+            source_objects: sources.iter().map(|name| ComposeSource { name }).collect(),
+            destination: ComposeDestination {
+                content_type: "application/octet-stream",
+            },
+        };
+        let _: ObjectResource = self
+            .send_json("ComposeObject", || self.http.post(url.clone()).json(&body))
+            .await?;
+        Ok(())
+    }
+
+    /// Returns an access token to authenticate requests with, if any.
+    async fn access_token(&self) -> Result<Option<String>, anyhow::Error> {
+        let GcsCredentials::MetadataServer { token } = &self.credentials else {
+            return Ok(None);
+        };
+        let mut token = token.lock().await;
+        if let Some((token, expires_at)) = &*token {
+            if Instant::now() < *expires_at {
+                return Ok(Some(token.clone()));
+            }
+        }
+
+        let host = std::env::var("GCE_METADATA_HOST")
+            .unwrap_or_else(|_| "metadata.google.internal".to_string());
+        let resp: AccessTokenResponse = self
+            .http
+            .get(format!(
+                "http://{host}/computeMetadata/v1/instance/service-accounts/default/token"
+            ))
+            .header("Metadata-Flavor", "Google")
+            .timeout(self.knobs.operation_attempt_timeout())
+            .send()
+            .await
+            .and_then(|resp| resp.error_for_status())
+            .context("fetching gcs access token")?
+            .json()
+            .await
+            .context("decoding gcs access token")?;
+        // Refresh tokens a minute early, so that they don't expire in flight.
+        let expires_in = Duration::from_secs(resp.expires_in.saturating_sub(60));
+        *token = Some((resp.access_token.clone(), Instant::now() + expires_in));
+        Ok(Some(resp.access_token))
+    }
+
+    /// Sends the request built by `request`, retrying transient failures.
+    ///
+    /// Responses with a non-retryable status are returned for the caller to
+    /// interpret.
+    async fn send<F>(&self, op: &'static str, request: F) -> Result<Response, anyhow::Error>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let operation_timeout = self.knobs.operation_timeout();
+        let (this, request) = (self, &request);
+        let retry = Retry::default()
+            .initial_backoff(Duration::from_millis(50))
+            .clamp_backoff(Duration::from_secs(5))
+            .max_duration(operation_timeout)
+            .retry_async(move |_| async move {
+                let token = match this.access_token().await {
+                    Ok(token) => token,
+                    Err(err) => return RetryResult::RetryableErr(err),
+                };
+                let mut request = request().timeout(this.knobs.operation_attempt_timeout());
+                if let Some(token) = token {
+                    request = request.bearer_auth(token);
+                }
+                match request.send().await {
+                    Ok(resp) if is_retryable(resp.status()) => {
+                        this.update_error_metrics(op, resp.status().as_str());
+                        RetryResult::RetryableErr(status_error(op, resp).await)
+                    }
+                    Ok(resp) => RetryResult::Ok(resp),
+                    Err(err) => {
+                        let code = if err.is_connect() && err.is_timeout() {
+                            this.metrics.connect_timeouts.inc();
+                            "ConnectTimeoutError"
+                        } else if err.is_timeout() {
+                            this.metrics.operation_attempt_timeouts.inc();
+                            "TimeoutError"
+                        } else if err.is_connect() {
+                            "ConnectError"
+                        } else {
+                            "IOError"
+                        };
+                        this.update_error_metrics(op, code);
+                        RetryResult::RetryableErr(anyhow!(err).context(format!("gcs {op} err")))
+                    }
+                }
+            });
+        match tokio::time::timeout(operation_timeout, retry).await {
+            Ok(result) => result,
+            Err(_) => {
+                self.metrics.operation_timeouts.inc();
+                Err(anyhow!("gcs {op} timed out after {operation_timeout:?}"))
+            }
+        }
+    }
+
+    /// Like [Self::send], but decodes a successful JSON response and treats
+    /// all other responses as errors.
+    async fn send_json<F, T>(&self, op: &'static str, request: F) -> Result<T, anyhow::Error>
+    where
+        F: Fn() -> RequestBuilder,
+        T: for<'de> Deserialize<'de>,
+    {
+        let resp = self.send(op, request).await?;
+        if !resp.status().is_success() {
+            self.update_error_metrics(op, resp.status().as_str());
+            return Err(status_error(op, resp).await);
+        }
+        resp.json()
+            .await
+            .with_context(|| format!("decoding gcs {op} response"))
+    }
+
+    fn update_error_metrics(&self, op: &str, code: &str) {
+        self.metrics
+            .error_counts
+            .with_label_values(&[op, code])
+            .inc();
+    }
+}
+
+/// Whether a request that failed with `status` should be retried.
+///
+/// From <https://cloud.google.com/storage/docs/retry-strategy>
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status.is_server_error()
+}
+
+/// Returns an error describing the unexpected status of `resp`.
+async fn status_error(op: &str, resp: Response) -> anyhow::Error {
+    let status = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    anyhow!("gcs {op} error: {status}: {body}")
+}
+
+/// Reads the body of `resp` into lgalloc.
+async fn read_body(mut resp: Response, metrics: &S3BlobMetrics) -> Result<Bytes, anyhow::Error> {
+    let content_length = resp.content_length().unwrap_or(0);
+
+    // As in [crate::azure::AzureBlob::get], be defensive about a missing or
+    // zero content-length: collect the body first and size the region after.
+    let mut buffer = match content_length {
+        1.. => {
+            let region = metrics
+                .lgbytes
+                .persist_gcs
+                .new_region(usize::cast_from(content_length));
+            PreSizedBuffer::Sized(region)
+        }
+        0 => PreSizedBuffer::Unknown(SegmentedBytes::new()),
+    };
+
+    while let Some(chunk) = resp.chunk().await.context("gcs get body err")? {
+        match &mut buffer {
+            PreSizedBuffer::Sized(region) => region.extend_from_slice(&chunk),
+            PreSizedBuffer::Unknown(segments) => segments.push(chunk),
+        }
+    }
+
+    let lgbytes: Bytes = match buffer {
+        PreSizedBuffer::Sized(region) => region.into(),
+        PreSizedBuffer::Unknown(segments) => {
+            let mut region = metrics.lgbytes.persist_gcs.new_region(segments.len());
+            for segment in segments.into_segments() {
+                region.extend_from_slice(segment.as_ref());
+            }
+            region.into()
+        }
+    };
+
+    // Report if the content-length header didn't match the number of bytes we
+    // read from the network.
+    if content_length != u64::cast_from(lgbytes.len()) {
+        metrics.get_invalid_resp.inc();
+    }
+
+    Ok(lgbytes)
+}
+
+/// If possible we'll pre-allocate a chunk of memory in lgalloc and write into
+/// that as we read bytes off the network.
+enum PreSizedBuffer {
+    Sized(MetricsRegion<u8>),
+    Unknown(SegmentedBytes),
+}
+
+/// An empty JSON object, the body of requests that take no parameters.
+#[derive(Serialize)]
+struct EmptyRequest {}
+
+#[derive(Serialize)]
+struct BucketResource<'a> {
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ComposeRequest<'a> {
+    source_objects: Vec<ComposeSource<'a>>,
+    destination: ComposeDestination,
+}
+
+#[derive(Serialize)]
+struct ComposeSource<'a> {
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ComposeDestination {
+    content_type: &'static str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListObjectsResponse {
+    #[serde(default)]
+    items: Vec<ObjectResource>,
+    next_page_token: Option<String>,
+}
+
+/// The subset of a GCS object resource that we use.
+///
+/// GCS encodes 64-bit integers as strings.
+#[derive(Deserialize)]
+struct ObjectResource {
+    name: String,
+    size: Option<String>,
+    generation: Option<String>,
+}
+
+impl ObjectResource {
+    fn size(&self) -> Result<u64, ExternalError> {
+        self.size
+            .as_deref()
+            .and_then(|size| size.parse().ok())
+            .ok_or_else(|| ExternalError::from(anyhow!("object missing size: {}", self.name)))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RewriteResponse {
+    done: bool,
+    rewrite_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AccessTokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info;
+
+    use crate::location::tests::blob_impl_test;
+
+    use super::*;
+
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `TLS_method` on OS `linux`
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    async fn gcs_blob() -> Result<(), ExternalError> {
+        let config = match GcsBlobConfig::new_for_test()? {
+            Some(client) => client,
+            None => {
+                info!(
+                    "{} env not set: skipping test that uses external service",
+                    GcsBlobConfig::EXTERNAL_TESTS_GCS_BUCKET
+                );
+                return Ok(());
+            }
+        };
+        let config_multipart = config.clone_with_new_uuid_prefix();
+
+        blob_impl_test(move |path| {
+            let path = path.to_owned();
+            let config = config.clone();
+            async move {
+                let config = GcsBlobConfig {
+                    metrics: config.metrics.clone(),
+                    client: config.client.clone(),
+                    bucket: config.bucket.clone(),
+                    prefix: format!("{}/gcs_blob_impl_test/{}", config.prefix, path),
+                    cfg: Arc::new(ConfigSet::default()),
+                };
+                let mut blob = GcsBlob::open(config).await?;
+                blob.max_keys = 2;
+                Ok(blob)
+            }
+        })
+        .await?;
+
+        // Also specifically test multipart, including composing in several
+        // rounds and reading back in several ranges, with tiny parts.
+        {
+            let mut blob = GcsBlob::open(config_multipart).await?;
+            blob.multipart_config.multipart_chunk_size = 2;
+            let value = Bytes::from((0..100u8).collect::<Vec<_>>());
+            blob.set_multi_part("multipart", value.clone()).await?;
+            let fetched = blob.get("multipart").await?.expect("multipart blob exists");
+            assert_eq!(fetched.into_contiguous(), value.to_vec());
+            assert_eq!(blob.delete("multipart").await?, Some(value.len()));
+        }
+
+        Ok(())
+    }
+}
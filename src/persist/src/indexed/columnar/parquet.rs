@@ -9,19 +9,26 @@
 
 //! Apache Parquet encodings and utils for persist data
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
+use std::ops::Range;
 use std::sync::Arc;
 
+use arrow::datatypes::DataType;
+use bytes::{Buf, Bytes};
 use differential_dataflow::trace::Description;
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use mz_persist_types::Codec64;
 use mz_persist_types::parquet::EncodingConfig;
-use parquet::arrow::ArrowWriter;
 use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ParquetRecordBatchReaderBuilder};
+use parquet::arrow::{ArrowWriter, ProjectionMask};
 use parquet::basic::Encoding;
-use parquet::file::metadata::KeyValue;
+use parquet::errors::ParquetError;
+use parquet::file::metadata::{KeyValue, ParquetMetaData, ParquetMetaDataReader};
 use parquet::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+use parquet::file::reader::{ChunkReader, Length};
 use timely::progress::{Antichain, Timestamp};
 use tracing::warn;
 
@@ -36,6 +43,12 @@ use crate::metrics::{ColumnarMetrics, ParquetColumnMetrics};
 
 const INLINE_METADATA_KEY: &str = "MZ:inline";
 
+/// The length of the Parquet footer: the length of the file metadata followed
+/// by the magic number.
+const PARQUET_FOOTER_LEN: usize = 8;
+
+const PARQUET_MAGIC: &[u8; 4] = b"PAR1";
+
 /// Encodes a [`BlobTraceBatchPart`] into the Parquet format.
 pub fn encode_trace_parquet<W: Write + Send, T: Timestamp + Codec64>(
     w: &mut W,
@@ -55,15 +68,26 @@ pub fn decode_trace_parquet<T: Timestamp + Codec64>(
     buf: SegmentedBytes,
     metrics: &ColumnarMetrics,
 ) -> Result<BlobTraceBatchPart<T>, Error> {
+    let (part, _projected) = decode_trace_parquet_projected(buf, None, metrics)?;
+    Ok(part)
+}
+
+/// Decodes a BlobTraceBatchPart from the Parquet format, skipping any columns
+/// that aren't needed by `projection`.
+///
+/// Returns whether the part was projected. If it was, the structured key and
+/// value may be missing fields of their original types and the codec encoded
+/// key and value are omitted.
+pub fn decode_trace_parquet_projected<T: Timestamp + Codec64, R: ChunkReader + 'static>(
+    buf: R,
+    projection: Option<&PartProjection>,
+    metrics: &ColumnarMetrics,
+) -> Result<(BlobTraceBatchPart<T>, bool), Error> {
     let metadata = ArrowReaderMetadata::load(&buf, Default::default())?;
-    let metadata = metadata
-        .metadata()
-        .file_metadata()
-        .key_value_metadata()
-        .as_ref()
-        .and_then(|x| x.iter().find(|x| x.key == INLINE_METADATA_KEY));
+    let leaves = projection.and_then(|projection| projection.leaves(metadata.metadata()));
+    let metadata = inline_metadata(metadata.metadata());
 
-    let (format, metadata) = decode_trace_inline_meta(metadata.and_then(|x| x.value.as_ref()))?;
+    let (format, metadata) = decode_trace_inline_meta(metadata)?;
     let updates = match format {
         ProtoBatchFormat::Unknown => return Err("unknown format".into()),
         ProtoBatchFormat::ArrowKvtd => {
@@ -77,7 +101,12 @@ pub fn decode_trace_parquet<T: Timestamp + Codec64>(
                 .format_metadata
                 .as_ref()
                 .ok_or_else(|| "missing field 'format_metadata'".to_string())?;
-            decode_parquet_file_kvtd(buf, Some(format_metadata), metrics)?
+            decode_parquet_file_kvtd_projected(
+                buf,
+                Some(format_metadata),
+                leaves.as_deref(),
+                metrics,
+            )?
         }
     };
 
@@ -96,7 +125,15 @@ pub fn decode_trace_parquet<T: Timestamp + Codec64>(
         updates,
     };
     ret.validate()?;
-    Ok(ret)
+    Ok((ret, leaves.is_some()))
+}
+
+fn inline_metadata(metadata: &ParquetMetaData) -> Option<&String> {
+    metadata
+        .file_metadata()
+        .key_value_metadata()
+        .and_then(|x| x.iter().find(|x| x.key == INLINE_METADATA_KEY))
+        .and_then(|x| x.value.as_ref())
 }
 
 /// Encodes [`BlobTraceUpdates`] to Parquet using the [`parquet`] crate.
@@ -146,8 +183,26 @@ pub fn decode_parquet_file_kvtd(
     r: impl parquet::file::reader::ChunkReader + 'static,
     format_metadata: Option<&ProtoFormatMetadata>,
     metrics: &ColumnarMetrics,
+) -> Result<BlobTraceUpdates, Error> {
+    decode_parquet_file_kvtd_projected(r, format_metadata, None, metrics)
+}
+
+/// Decodes [`BlobTraceUpdates`] from a reader, using [`arrow`], decoding only
+/// the provided leaf columns if any are provided.
+fn decode_parquet_file_kvtd_projected(
+    r: impl parquet::file::reader::ChunkReader + 'static,
+    format_metadata: Option<&ProtoFormatMetadata>,
+    leaves: Option<&[usize]>,
+    metrics: &ColumnarMetrics,
 ) -> Result<BlobTraceUpdates, Error> {
     let builder = ParquetRecordBatchReaderBuilder::try_new(r)?;
+    let builder = match leaves {
+        Some(leaves) => {
+            let mask = ProjectionMask::leaves(builder.parquet_schema(), leaves.iter().copied());
+            builder.with_projection(mask)
+        }
+        None => builder,
+    };
 
     // To match arrow2, we default the batch size to the number of rows in the RowGroup.
     let row_groups = builder.metadata().row_groups();
@@ -240,3 +295,357 @@ fn report_parquet_metrics(
     report_column_size("k_s", &metrics.parquet().k_s_metrics);
     report_column_size("v_s", &metrics.parquet().v_s_metrics);
 }
+
+/// The Arrow types that a reader will migrate the structured key and value of a
+/// part to.
+///
+/// Any fields of the structured key and value that the migration would drop
+/// don't need to be decoded, or even fetched, to read the part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartProjection {
+    /// The type of the structured key once migrated.
+    pub key: DataType,
+    /// The type of the structured value once migrated.
+    pub val: DataType,
+}
+
+impl PartProjection {
+    /// Returns the indexes of the leaf columns of the Parquet file described by
+    /// `metadata` that are needed to read it with this projection.
+    ///
+    /// Returns None if the file can't be projected or if there's nothing to
+    /// skip.
+    pub fn leaves(&self, metadata: &ParquetMetaData) -> Option<Vec<usize>> {
+        // Only the structured format can be projected, and version 1 of it
+        // ignores the structured columns entirely.
+        let (format, inline) = decode_trace_inline_meta(inline_metadata(metadata)).ok()?;
+        match (format, inline.format_metadata) {
+            (
+                ProtoBatchFormat::ParquetStructured,
+                Some(ProtoFormatMetadata::StructuredMigration(2..=3)),
+            ) => {}
+            _ => return None,
+        }
+
+        let columns = metadata.file_metadata().schema_descr().columns();
+        let mut needed = vec![false; columns.len()];
+        // For each leaf, the number of leading path elements naming a struct
+        // that exists in the projection. Every one of these structs needs at
+        // least one of its leaves decoded, or it would be missing entirely.
+        let mut containers = vec![0; columns.len()];
+        for (idx, column) in columns.iter().enumerate() {
+            let path = column.path().parts();
+            let typ = match path[0].as_str() {
+                "k_s" => &self.key,
+                "v_s" => &self.val,
+                // The codec columns are never needed to read the structured
+                // ones.
+                "k" | "v" => continue,
+                _ => {
+                    needed[idx] = true;
+                    continue;
+                }
+            };
+            let (leaf_needed, depth) = projected_path(typ, &path[1..]);
+            needed[idx] = leaf_needed;
+            containers[idx] = depth;
+        }
+
+        let mut covered = BTreeSet::new();
+        for (idx, column) in columns.iter().enumerate() {
+            if needed[idx] {
+                let path = column.path().parts();
+                for len in 1..=containers[idx] {
+                    covered.insert(path[..len].to_vec());
+                }
+            }
+        }
+        for (idx, column) in columns.iter().enumerate() {
+            let path = column.path().parts();
+            let Some(len) = (1..=containers[idx]).find(|len| !covered.contains(&path[..*len]))
+            else {
+                continue;
+            };
+            // Decode the entire child of the struct that this leaf belongs to,
+            // so that the child is complete and can be dropped by the
+            // migration like any other.
+            let child = &path[..len + 1];
+            for (other, other_column) in columns.iter().enumerate().skip(idx) {
+                let other_path = other_column.path().parts();
+                if !other_path.starts_with(child) {
+                    break;
+                }
+                needed[other] = true;
+                for len in 1..=containers[other] {
+                    covered.insert(other_path[..len].to_vec());
+                }
+            }
+        }
+
+        if needed.iter().all(|x| *x) {
+            return None;
+        }
+        let leaves = needed
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, needed)| needed.then_some(idx))
+            .collect();
+        Some(leaves)
+    }
+}
+
+/// Returns whether the leaf at `path` within a column of type `typ` is needed,
+/// along with the number of path elements, counting the column itself, that
+/// name a struct in `typ`.
+fn projected_path(typ: &DataType, path: &[String]) -> (bool, usize) {
+    let mut typ = typ;
+    for (depth, name) in path.iter().enumerate() {
+        match typ {
+            DataType::Struct(fields) => match fields.find(name) {
+                Some((_, field)) => typ = field.data_type(),
+                None => return (false, depth + 1),
+            },
+            // A struct with every one of its fields projected away is
+            // migrated to a null.
+            DataType::Null => return (false, depth + 1),
+            // Lists, maps, and the like are needed in their entirety.
+            _ => return (true, depth),
+        }
+    }
+    (true, path.len())
+}
+
+/// Returns the byte ranges of the Parquet file described by `metadata` that
+/// contain the provided leaf columns, sorted and with nearby ranges merged.
+pub fn leaf_byte_ranges(metadata: &ParquetMetaData, leaves: &[usize]) -> Vec<Range<usize>> {
+    // Fetching a few unneeded bytes is cheaper than another request.
+    const MERGE_GAP_BYTES: usize = 64 * 1024;
+
+    let mut ranges: Vec<Range<usize>> = metadata
+        .row_groups()
+        .iter()
+        .flat_map(|row_group| {
+            leaves.iter().map(|leaf| {
+                let (start, len) = row_group.column(*leaf).byte_range();
+                let start = usize::cast_from(start);
+                start..start + usize::cast_from(len)
+            })
+        })
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(prev) if range.start <= prev.end.saturating_add(MERGE_GAP_BYTES) => {
+                prev.end = std::cmp::max(prev.end, range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Some of the bytes of a Parquet file, at their offsets in the file.
+///
+/// This is enough to decode the file as long as it contains the footer, the
+/// file metadata, and the column chunks being decoded.
+#[derive(Debug, Clone)]
+pub struct PartialFile {
+    len: usize,
+    chunks: BTreeMap<usize, Bytes>,
+}
+
+impl PartialFile {
+    /// Returns a [PartialFile] for a file of the given length, with none of its
+    /// bytes.
+    pub fn new(len: usize) -> Self {
+        PartialFile {
+            len,
+            chunks: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the bytes of the file starting at `offset`.
+    pub fn insert(&mut self, offset: usize, mut bytes: SegmentedBytes) {
+        let bytes = bytes.copy_to_bytes(bytes.remaining());
+        self.chunks.insert(offset, bytes);
+    }
+
+    /// Returns whether all of the bytes in `range` have been added.
+    pub fn contains(&self, range: &Range<usize>) -> bool {
+        self.chunk(range.start, range.end).is_some()
+    }
+
+    /// Returns the range of the file that holds its metadata.
+    ///
+    /// The footer of the file must have been added.
+    pub fn metadata_range(&self) -> Result<Range<usize>, Error> {
+        let footer_start = self
+            .len
+            .checked_sub(PARQUET_FOOTER_LEN)
+            .ok_or_else(|| format!("file of len {} is too short", self.len))?;
+        let footer = self.get(footer_start, PARQUET_FOOTER_LEN)?;
+        if &footer[4..] != PARQUET_MAGIC {
+            return Err("invalid parquet magic".into());
+        }
+        let metadata_len = u32::from_le_bytes(footer[..4].try_into().expect("known len"));
+        let metadata_start = footer_start
+            .checked_sub(usize::cast_from(metadata_len))
+            .ok_or_else(|| format!("invalid metadata len {}", metadata_len))?;
+        Ok(metadata_start..footer_start)
+    }
+
+    /// Decodes the metadata of the file.
+    ///
+    /// The metadata and footer of the file must have been added.
+    pub fn metadata(&self) -> Result<ParquetMetaData, Error> {
+        let range = self.metadata_range()?;
+        let buf = self.get(range.start, range.len())?;
+        Ok(ParquetMetaDataReader::decode_metadata(&buf)?)
+    }
+
+    fn chunk(&self, start: usize, end: usize) -> Option<(usize, &Bytes)> {
+        // Chunks may overlap, so check all of the ones that start early enough.
+        self.chunks
+            .range(..=start)
+            .rev()
+            .find(|(offset, chunk)| end <= **offset + chunk.len())
+            .map(|(offset, chunk)| (*offset, chunk))
+    }
+
+    fn get(&self, start: usize, length: usize) -> parquet::errors::Result<Bytes> {
+        let end = start.saturating_add(length);
+        match self.chunk(start, end) {
+            Some((offset, chunk)) => Ok(chunk.slice(start - offset..end - offset)),
+            None => Err(ParquetError::General(format!(
+                "bytes {start}..{end} were not fetched"
+            ))),
+        }
+    }
+}
+
+impl Length for PartialFile {
+    fn len(&self) -> u64 {
+        u64::cast_from(self.len)
+    }
+}
+
+impl ChunkReader for PartialFile {
+    type T = bytes::buf::Reader<Bytes>;
+
+    fn get_read(&self, start: u64) -> parquet::errors::Result<Self::T> {
+        let start = usize::cast_from(start);
+        let (offset, chunk) = self
+            .chunk(start, start)
+            .ok_or_else(|| ParquetError::General(format!("byte {start} was not fetched")))?;
+        Ok(chunk.slice(start - offset..).reader())
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+        self.get(usize::cast_from(start), length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, ArrayRef, AsArray, Int64Array, StringArray, StructArray};
+    use arrow::datatypes::{Field, Fields};
+    use timely::progress::Antichain;
+
+    use crate::indexed::columnar::ColumnarRecordsStructuredExt;
+
+    use super::*;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn projected_decode() {
+        let metrics = ColumnarMetrics::disconnected();
+        let key = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int64, true)),
+                Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, true)),
+                Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+            ),
+        ]);
+        let val = StructArray::from(vec![(
+            Arc::new(Field::new("c", DataType::Int64, true)),
+            Arc::new(Int64Array::from(vec![3, 4])) as ArrayRef,
+        )]);
+        let part = BlobTraceBatchPart {
+            desc: Description::new(
+                Antichain::from_elem(0u64),
+                Antichain::from_elem(1),
+                Antichain::from_elem(0),
+            ),
+            index: 0,
+            updates: BlobTraceUpdates::Structured {
+                key_values: ColumnarRecordsStructuredExt {
+                    key: Arc::new(key.clone()),
+                    val: Arc::new(val.clone()),
+                },
+                timestamps: Int64Array::from(vec![0, 0]),
+                diffs: Int64Array::from(vec![1, 1]),
+            },
+        };
+        let mut buf = Vec::new();
+        part.encode(&mut buf, &metrics, &EncodingConfig::default());
+        let buf = Bytes::from(buf);
+        let metadata =
+            ArrowReaderMetadata::load(&SegmentedBytes::from(buf.clone()), Default::default())
+                .expect("valid metadata");
+
+        // Nothing to skip if every column is needed.
+        let everything = PartProjection {
+            key: key.data_type().clone(),
+            val: val.data_type().clone(),
+        };
+        assert_eq!(everything.leaves(metadata.metadata()), None);
+
+        let projection = PartProjection {
+            key: DataType::Struct(Fields::from(vec![Field::new("a", DataType::Int64, true)])),
+            val: val.data_type().clone(),
+        };
+        let leaves = projection
+            .leaves(metadata.metadata())
+            .expect("projectable part");
+        let assert_projected = |decoded: BlobTraceBatchPart<u64>, projected: bool| {
+            assert!(projected);
+            let structured = decoded.updates.structured().expect("structured part");
+            assert_eq!(structured.key.as_struct().column_names(), vec!["a"]);
+            assert_eq!(structured.val.as_struct().column_names(), vec!["c"]);
+            assert_eq!(decoded.updates.len(), 2);
+        };
+
+        // Decoding the entire blob.
+        let (decoded, projected) = decode_trace_parquet_projected::<u64, _>(
+            SegmentedBytes::from(buf.clone()),
+            Some(&projection),
+            &metrics,
+        )
+        .expect("valid part");
+        assert_projected(decoded, projected);
+
+        // Decoding only the bytes needed for the projection.
+        let mut file = PartialFile::new(buf.len());
+        let tail = buf.len() - PARQUET_FOOTER_LEN..buf.len();
+        file.insert(tail.start, SegmentedBytes::from(buf.slice(tail)));
+        let metadata_range = file.metadata_range().expect("valid footer");
+        assert!(!file.contains(&metadata_range));
+        let ranges = leaf_byte_ranges(metadata.metadata(), &leaves);
+        for range in ranges.into_iter().chain([metadata_range.start..buf.len()]) {
+            file.insert(range.start, SegmentedBytes::from(buf.slice(range)));
+        }
+        assert_eq!(
+            file.metadata().expect("valid metadata").num_row_groups(),
+            metadata.metadata().num_row_groups()
+        );
+        let (decoded, projected) =
+            decode_trace_parquet_projected::<u64, _>(file, Some(&projection), &metrics)
+                .expect("valid part");
+        assert_projected(decoded, projected);
+    }
+}
@@ -36,6 +36,7 @@ use mz_persist_types::part::Part;
 use mz_persist_types::schema::backward_compatible;
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::{RustType, TryFromProtoError};
+use parquet::file::reader::ChunkReader;
 use proptest::arbitrary::Arbitrary;
 use proptest::prelude::*;
 use proptest::strategy::{BoxedStrategy, Just};
@@ -52,7 +53,9 @@ use crate::generated::persist::{
     ProtoU64Description,
 };
 use crate::indexed::columnar::arrow::realloc_array;
-use crate::indexed::columnar::parquet::{decode_trace_parquet, encode_trace_parquet};
+use crate::indexed::columnar::parquet::{
+    PartProjection, decode_trace_parquet, decode_trace_parquet_projected, encode_trace_parquet,
+};
 use crate::indexed::columnar::{ColumnarRecords, ColumnarRecordsStructuredExt};
 use crate::location::Blob;
 use crate::metrics::ColumnarMetrics;
@@ -725,6 +728,18 @@ impl<T: Timestamp + Codec64> BlobTraceBatchPart<T> {
         decode_trace_parquet(buf.clone(), metrics)
     }
 
+    /// Decodes a BlobTraceBatchPart from the Parquet format, skipping any
+    /// columns not needed by `projection`.
+    ///
+    /// Returns whether the part was projected.
+    pub fn decode_projected<R: ChunkReader + 'static>(
+        buf: R,
+        projection: Option<&PartProjection>,
+        metrics: &ColumnarMetrics,
+    ) -> Result<(Self, bool), Error> {
+        decode_trace_parquet_projected(buf, projection, metrics)
+    }
+
     /// Scans the part and returns a lower bound on the contained keys.
     pub fn key_lower(&self) -> &[u8] {
         self.updates
@@ -19,8 +19,10 @@
 
 pub mod azure;
 pub mod cfg;
+pub mod encryption;
 pub mod error;
 pub mod file;
+pub mod gcs;
 pub mod generated;
 pub mod indexed;
 pub mod intercept;
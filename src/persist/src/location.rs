@@ -10,6 +10,7 @@
 //! Abstractions over files, cloud storage, etc used in persistence.
 
 use std::fmt;
+use std::ops::Range;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Instant;
@@ -17,7 +18,7 @@ use std::time::Instant;
 use anyhow::anyhow;
 use async_trait::async_trait;
 use azure_core::StatusCode;
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
 use futures_util::Stream;
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::u64_to_usize;
@@ -522,6 +523,22 @@ pub trait Blob: std::fmt::Debug + Send + Sync {
     /// Returns a reference to the value corresponding to the key.
     async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError>;
 
+    /// Returns the bytes in `range` of the value corresponding to the key.
+    ///
+    /// Returns an error if the range extends past the end of the value. The
+    /// default implementation fetches the entire value, so implementations
+    /// that can fetch part of a value more cheaply should override it.
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(value) = self.get(key).await? else {
+            return Ok(None);
+        };
+        value_range(key, value, range).map(Some)
+    }
+
     /// List all of the keys in the map with metadata about the entry.
     ///
     /// Can be optionally restricted to only list keys starting with a
@@ -556,6 +573,27 @@ pub trait Blob: std::fmt::Debug + Send + Sync {
     async fn restore(&self, key: &str) -> Result<(), ExternalError>;
 }
 
+/// Returns the bytes in `range` of `value`, the entire value of `key`.
+///
+/// A helper for implementations of [Blob::get_range] that have the entire value
+/// on hand.
+pub fn value_range(
+    key: &str,
+    mut value: SegmentedBytes,
+    range: Range<usize>,
+) -> Result<SegmentedBytes, ExternalError> {
+    if range.start > range.end || range.end > value.len() {
+        return Err(ExternalError::from(anyhow!(
+            "range {:?} out of bounds for {} of len {}",
+            range,
+            key,
+            value.len()
+        )));
+    }
+    value.advance(range.start);
+    Ok(SegmentedBytes::from(value.copy_to_bytes(range.len())))
+}
+
 #[async_trait]
 impl<A: Blob + 'static> Blob for Tasked<A> {
     async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
@@ -568,6 +606,20 @@ impl<A: Blob + 'static> Blob for Tasked<A> {
         .await?
     }
 
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let backing = self.clone_backing();
+        let key = key.to_owned();
+        mz_ore::task::spawn(
+            || "persist::task::get_range",
+            async move { backing.get_range(&key, range).await }.instrument(Span::current()),
+        )
+        .await?
+    }
+
     /// List all of the keys in the map with metadata about the entry.
     ///
     /// Can be optionally restricted to only list keys starting with a
@@ -697,6 +749,18 @@ pub mod tests {
             Some(values[0].clone())
         );
 
+        // Get part of a key.
+        assert_eq!(
+            blob0.get_range(k0, 1..2).await?.map(|s| s.into_contiguous()),
+            Some(values[0][1..2].to_vec())
+        );
+        assert_eq!(
+            blob1.get_range(k0, 0..0).await?.map(|s| s.into_contiguous()),
+            Some(Vec::new())
+        );
+        assert!(blob0.get_range(k0, 1..3).await.is_err());
+        assert_eq!(blob0.get_range("nope", 0..1).await?, None);
+
         // Set another key and get it back.
         blob0.set("k0a", values[0].clone().into()).await?;
         assert_eq!(
@@ -569,6 +569,57 @@ impl Blob for S3Blob {
         Ok(Some(SegmentedBytes::from(segments)))
     }
 
+    async fn get_range(
+        &self,
+        key: &str,
+        range: Range<usize>,
+    ) -> Result<Option<SegmentedBytes>, ExternalError> {
+        // An HTTP range can't be empty, but we still need to know whether the
+        // key exists.
+        if range.is_empty() {
+            return Ok(self.get(key).await?.map(|_| SegmentedBytes::default()));
+        }
+
+        let start = Instant::now();
+        let path = self.get_path(key);
+        self.metrics.get_part.inc();
+        let object = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&path)
+            .range(format!("bytes={}-{}", range.start, range.end - 1))
+            .send()
+            .await;
+        let mut object = match object {
+            Ok(object) => object,
+            Err(SdkError::ServiceError(err)) if err.err().is_no_such_key() => return Ok(None),
+            Err(err) => {
+                self.update_error_metrics("GetObject", &err);
+                Err(anyhow!(err).context("s3 get range err"))?
+            }
+        };
+
+        let mut segments = Vec::new();
+        while let Some(data) = object.body.next().await {
+            segments.push(data.context("s3 get range body err")?);
+        }
+        let value = SegmentedBytes::from(segments);
+        // S3 truncates ranges that extend past the end of the object.
+        if value.len() != range.len() {
+            return Err(anyhow!(
+                "range {:?} out of bounds for {}: got {} bytes",
+                range,
+                key,
+                value.len()
+            )
+            .into());
+        }
+
+        debug!("s3 GetObject range took {:?}", start.elapsed());
+        Ok(Some(value))
+    }
+
     async fn list_keys_and_metadata(
         &self,
         key_prefix: &str,
@@ -982,9 +1033,9 @@ impl S3Blob {
 }
 
 #[derive(Clone, Debug)]
-struct MultipartConfig {
+pub(crate) struct MultipartConfig {
     multipart_threshold: usize,
-    multipart_chunk_size: usize,
+    pub(crate) multipart_chunk_size: usize,
 }
 
 impl Default for MultipartConfig {
@@ -1032,7 +1083,7 @@ impl MultipartConfig {
     /// From <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
     const MAX_PART_NUM: u32 = 10_000;
 
-    fn should_multipart(&self, blob_len: usize) -> Result<bool, String> {
+    pub(crate) fn should_multipart(&self, blob_len: usize) -> Result<bool, String> {
         if blob_len > Self::MAX_SINGLE_UPLOAD_SIZE {
             return Err(format!(
                 "S3 does not support blobs larger than {} bytes got: {}",
@@ -1043,21 +1094,21 @@ impl MultipartConfig {
         Ok(blob_len > self.multipart_threshold)
     }
 
-    fn part_iter(&self, blob_len: usize) -> MultipartChunkIter {
+    pub(crate) fn part_iter(&self, blob_len: usize) -> MultipartChunkIter {
         debug_assert!(self.multipart_chunk_size >= MultipartConfig::MIN_UPLOAD_CHUNK_SIZE);
         MultipartChunkIter::new(self.multipart_chunk_size, blob_len)
     }
 }
 
 #[derive(Clone, Debug)]
-struct MultipartChunkIter {
+pub(crate) struct MultipartChunkIter {
     total_len: usize,
     part_size: usize,
     part_idx: u32,
 }
 
 impl MultipartChunkIter {
-    fn new(default_part_size: usize, blob_len: usize) -> Self {
+    pub(crate) fn new(default_part_size: usize, blob_len: usize) -> Self {
         let max_parts: usize = usize::cast_from(MultipartConfig::MAX_PART_NUM);
 
         // Compute the minimum part size we can use without going over the max
@@ -1097,7 +1148,7 @@ impl Iterator for MultipartChunkIter {
 
 /// A helper for tracking the minimum of a set of Durations.
 #[derive(Debug)]
-struct MinElapsed {
+pub(crate) struct MinElapsed {
     min: AtomicU64,
     alert_factor: u64,
 }
@@ -1112,7 +1163,7 @@ impl Default for MinElapsed {
 }
 
 impl MinElapsed {
-    fn observe(&self, x: Duration, msg: &'static str) {
+    pub(crate) fn observe(&self, x: Duration, msg: &'static str) {
         let nanos = x.as_nanos();
         let nanos = u64::try_from(nanos).unwrap_or(u64::MAX);
 
@@ -90,6 +90,24 @@ pub const TYPE_TSRANGE_OID: u32 = 3908;
 pub const TYPE_TSRANGE_ARRAY_OID: u32 = 3909;
 pub const TYPE_TSTZRANGE_OID: u32 = 3910;
 pub const TYPE_TSTZRANGE_ARRAY_OID: u32 = 3911;
+pub const TYPE_INT4MULTIRANGE_OID: u32 = 4451;
+pub const TYPE_INT4MULTIRANGE_ARRAY_OID: u32 = 6150;
+pub const TYPE_ANYMULTIRANGE_OID: u32 = 4537;
+pub const TYPE_ANYCOMPATIBLEMULTIRANGE_OID: u32 = 4538;
+pub const TYPE_INT8MULTIRANGE_OID: u32 = 4536;
+pub const TYPE_INT8MULTIRANGE_ARRAY_OID: u32 = 6157;
+pub const TYPE_DATEMULTIRANGE_OID: u32 = 4535;
+pub const TYPE_DATEMULTIRANGE_ARRAY_OID: u32 = 6155;
+pub const TYPE_NUMMULTIRANGE_OID: u32 = 4532;
+pub const TYPE_NUMMULTIRANGE_ARRAY_OID: u32 = 6151;
+pub const TYPE_TSMULTIRANGE_OID: u32 = 4533;
+pub const TYPE_TSMULTIRANGE_ARRAY_OID: u32 = 6152;
+pub const TYPE_TSTZMULTIRANGE_OID: u32 = 4534;
+pub const TYPE_TSTZMULTIRANGE_ARRAY_OID: u32 = 6153;
+pub const TYPE_BIT_OID: u32 = 1560;
+pub const TYPE_BIT_ARRAY_OID: u32 = 1561;
+pub const TYPE_VARBIT_OID: u32 = 1562;
+pub const TYPE_VARBIT_ARRAY_OID: u32 = 1563;
 
 /// The first OID in PostgreSQL's system catalog that is not pinned during
 /// bootstrapping.
@@ -777,3 +795,55 @@ pub const VIEW_MZ_WALLCLOCK_GLOBAL_LAG_OID: u32 = 17054;
 pub const SOURCE_MZ_WALLCLOCK_GLOBAL_LAG_HISTOGRAM_RAW_OID: u32 = 17055;
 pub const VIEW_MZ_WALLCLOCK_GLOBAL_LAG_HISTOGRAM_OID: u32 = 17056;
 pub const TABLE_MZ_SQL_SERVER_SOURCE_TABLES_OID: u32 = 17057;
+pub const FUNC_MZ_FLOAT8_PROMOTION_OID: u32 = 17058;
+pub const FUNC_JSONB_INSERT_DEFAULT_AFTER_OID: u32 = 17059;
+pub const FUNC_JSONB_PATH_EXISTS_DEFAULT_VARS_OID: u32 = 17060;
+pub const FUNC_JSONB_PATH_EXISTS_DEFAULT_SILENT_OID: u32 = 17061;
+pub const FUNC_JSONB_PATH_MATCH_DEFAULT_VARS_OID: u32 = 17062;
+pub const FUNC_JSONB_PATH_MATCH_DEFAULT_SILENT_OID: u32 = 17063;
+pub const FUNC_JSONB_PATH_QUERY_DEFAULT_VARS_OID: u32 = 17064;
+pub const FUNC_JSONB_PATH_QUERY_DEFAULT_SILENT_OID: u32 = 17065;
+pub const FUNC_JSONB_PATH_QUERY_ARRAY_DEFAULT_VARS_OID: u32 = 17066;
+pub const FUNC_JSONB_PATH_QUERY_ARRAY_DEFAULT_SILENT_OID: u32 = 17067;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_DEFAULT_VARS_OID: u32 = 17068;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_DEFAULT_SILENT_OID: u32 = 17069;
+pub const FUNC_JSONB_SET_DEFAULT_CREATE_MISSING_OID: u32 = 17070;
+pub const FUNC_INT4MULTIRANGE_NULLARY_OID: u32 = 17071;
+pub const FUNC_INT4MULTIRANGE_VARIADIC_OID: u32 = 17072;
+pub const FUNC_INT8MULTIRANGE_NULLARY_OID: u32 = 17073;
+pub const FUNC_INT8MULTIRANGE_VARIADIC_OID: u32 = 17074;
+pub const FUNC_DATEMULTIRANGE_NULLARY_OID: u32 = 17075;
+pub const FUNC_DATEMULTIRANGE_VARIADIC_OID: u32 = 17076;
+pub const FUNC_NUMMULTIRANGE_NULLARY_OID: u32 = 17077;
+pub const FUNC_NUMMULTIRANGE_VARIADIC_OID: u32 = 17078;
+pub const FUNC_TSMULTIRANGE_NULLARY_OID: u32 = 17079;
+pub const FUNC_TSMULTIRANGE_VARIADIC_OID: u32 = 17080;
+pub const FUNC_TSTZMULTIRANGE_NULLARY_OID: u32 = 17081;
+pub const FUNC_TSTZMULTIRANGE_VARIADIC_OID: u32 = 17082;
+pub const FUNC_MULTIRANGE_OID: u32 = 17083;
+pub const FUNC_RANGE_MERGE_MULTIRANGE_OID: u32 = 17084;
+pub const FUNC_RANGE_AGG_RANGE_OID: u32 = 17085;
+pub const FUNC_RANGE_AGG_MULTIRANGE_OID: u32 = 17086;
+pub const FUNC_RANGE_INTERSECT_AGG_RANGE_OID: u32 = 17087;
+pub const FUNC_RANGE_INTERSECT_AGG_MULTIRANGE_OID: u32 = 17088;
+pub const FUNC_UNNEST_MULTIRANGE_OID: u32 = 17089;
+pub const OP_CONTAINS_MULTIRANGE_ELEM_OID: u32 = 17090;
+pub const OP_CONTAINS_MULTIRANGE_RANGE_OID: u32 = 17091;
+pub const OP_CONTAINS_MULTIRANGE_MULTIRANGE_OID: u32 = 17092;
+pub const OP_CONTAINS_RANGE_MULTIRANGE_OID: u32 = 17093;
+pub const OP_CONTAINED_ELEM_MULTIRANGE_OID: u32 = 17094;
+pub const OP_CONTAINED_RANGE_MULTIRANGE_OID: u32 = 17095;
+pub const OP_CONTAINED_MULTIRANGE_MULTIRANGE_OID: u32 = 17096;
+pub const OP_CONTAINED_MULTIRANGE_RANGE_OID: u32 = 17097;
+pub const OP_OVERLAPS_MULTIRANGE_RANGE_OID: u32 = 17098;
+pub const OP_OVERLAPS_RANGE_MULTIRANGE_OID: u32 = 17099;
+pub const OP_OVERLAPS_MULTIRANGE_MULTIRANGE_OID: u32 = 17100;
+pub const OP_UNION_MULTIRANGE_OID: u32 = 17101;
+pub const OP_INTERSECTION_MULTIRANGE_OID: u32 = 17102;
+pub const OP_DIFFERENCE_MULTIRANGE_OID: u32 = 17103;
+pub const FUNC_MULTIRANGE_LT_OID: u32 = 17104;
+pub const FUNC_MULTIRANGE_LTE_OID: u32 = 17105;
+pub const FUNC_MULTIRANGE_GT_OID: u32 = 17106;
+pub const FUNC_MULTIRANGE_GTE_OID: u32 = 17107;
+pub const FUNC_MULTIRANGE_EQ_OID: u32 = 17108;
+pub const FUNC_MULTIRANGE_NOT_EQ_OID: u32 = 17109;
@@ -13,6 +13,7 @@ use std::mem::size_of;
 use std::sync::LazyLock;
 
 use mz_repr::ScalarType;
+use mz_repr::adt::bit::{BitLength as AdtBitLength, InvalidBitLengthError};
 use mz_repr::adt::char::{CharLength as AdtCharLength, InvalidCharLengthError};
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
 use mz_repr::adt::numeric::{
@@ -54,6 +55,13 @@ const MAX_TIME_PRECISION: i32 = 6;
 pub enum Type {
     /// A variable-length multidimensional array of values.
     Array(Box<Type>),
+    /// A fixed-length bit string.
+    Bit {
+        /// The length of the bit string.
+        ///
+        /// If unspecified, the type represents a variable-length bit string.
+        length: Option<BitLength>,
+    },
     /// A boolean value.
     Bool,
     /// A byte array, i.e., a variable-length binary string.
@@ -115,6 +123,11 @@ pub enum Type {
         /// If unspecified, the type represents a variable-length string.
         length: Option<CharLength>,
     },
+    /// A variable-length bit string with an optional limit.
+    VarBit {
+        /// An optional maximum length to enforce, in bits.
+        max_length: Option<BitLength>,
+    },
     /// A variable-length string with an optional limit.
     VarChar {
         /// An optional maximum length to enforce, in characters.
@@ -163,6 +176,11 @@ pub enum Type {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem,
+    /// A set of non-overlapping ranges of values of the inner type.
+    MultiRange {
+        /// The domain type.
+        element_type: Box<Type>,
+    },
 }
 
 /// An unpacked [`typmod`](Type::typmod) for a [`Type`].
@@ -226,6 +244,49 @@ impl fmt::Display for CharLength {
     }
 }
 
+/// A length associated with [`Type::Bit`] and [`Type::VarBit`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BitLength(i32);
+
+impl TypeConstraint for BitLength {
+    fn from_typmod(typmod: i32) -> Result<Option<BitLength>, String> {
+        // Unlike the character types, the bit types store the length directly
+        // in the typmod.
+        // https://github.com/postgres/postgres/blob/52377bb81/src/backend/utils/adt/varbit.c#L118
+        if typmod >= 0 {
+            Ok(Some(BitLength(typmod)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn into_typmod(&self) -> i32 {
+        self.0
+    }
+}
+
+impl BitLength {
+    /// Consumes the newtype wrapper, returning the contents as an `i32`.
+    pub fn into_i32(self) -> i32 {
+        self.0
+    }
+}
+
+impl From<AdtBitLength> for BitLength {
+    fn from(length: AdtBitLength) -> BitLength {
+        // The `AdtBitLength` newtype wrapper ensures that the inner `u32` is
+        // small enough to fit into an `i32`.
+        BitLength(i32::try_from(length.into_u32()).unwrap())
+    }
+}
+
+impl fmt::Display for BitLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // https://github.com/postgres/postgres/blob/52377bb81/src/backend/utils/adt/varbit.c#L128
+        write!(f, "({})", self.0)
+    }
+}
+
 /// Constraints associated with [`Type::Interval`]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct IntervalConstraints {
@@ -546,6 +607,7 @@ impl Type {
     pub fn from_oid_and_typmod(oid: u32, typmod: i32) -> Result<Type, TypeFromOidError> {
         let typ = postgres_types::Type::from_oid(oid).ok_or(TypeFromOidError::UnknownOid(oid))?;
         let mut typ = match typ {
+            postgres_types::Type::BIT => Type::Bit { length: None },
             postgres_types::Type::BOOL => Type::Bool,
             postgres_types::Type::BYTEA => Type::Bytea,
             postgres_types::Type::DATE => Type::Date,
@@ -563,6 +625,7 @@ impl Type {
             postgres_types::Type::BPCHAR | postgres_types::Type::CHAR => {
                 Type::BpChar { length: None }
             }
+            postgres_types::Type::VARBIT => Type::VarBit { max_length: None },
             postgres_types::Type::VARCHAR => Type::VarChar { max_length: None },
             postgres_types::Type::TIME => Type::Time { precision: None },
             postgres_types::Type::TIMETZ => Type::TimeTz { precision: None },
@@ -572,6 +635,7 @@ impl Type {
             postgres_types::Type::REGCLASS => Type::RegClass,
             postgres_types::Type::REGPROC => Type::RegProc,
             postgres_types::Type::REGTYPE => Type::RegType,
+            postgres_types::Type::BIT_ARRAY => Type::Array(Box::new(Type::Bit { length: None })),
             postgres_types::Type::BOOL_ARRAY => Type::Array(Box::new(Type::Bool)),
             postgres_types::Type::BYTEA_ARRAY => Type::Array(Box::new(Type::Bytea)),
             postgres_types::Type::BPCHAR_ARRAY => {
@@ -606,6 +670,9 @@ impl Type {
                 Type::Array(Box::new(Type::TimestampTz { precision: None }))
             }
             postgres_types::Type::UUID_ARRAY => Type::Array(Box::new(Type::Uuid)),
+            postgres_types::Type::VARBIT_ARRAY => {
+                Type::Array(Box::new(Type::VarBit { max_length: None }))
+            }
             postgres_types::Type::VARCHAR_ARRAY => {
                 Type::Array(Box::new(Type::VarChar { max_length: None }))
             }
@@ -650,6 +717,50 @@ impl Type {
             postgres_types::Type::DATE_RANGE_ARRAY => Type::Array(Box::new(Type::Range {
                 element_type: Box::new(Type::Date),
             })),
+            postgres_types::Type::INT4MULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Int4),
+            },
+            postgres_types::Type::INT4MULTI_RANGE_ARRAY => {
+                Type::Array(Box::new(Type::MultiRange {
+                    element_type: Box::new(Type::Int4),
+                }))
+            }
+            postgres_types::Type::INT8MULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Int8),
+            },
+            postgres_types::Type::INT8MULTI_RANGE_ARRAY => {
+                Type::Array(Box::new(Type::MultiRange {
+                    element_type: Box::new(Type::Int8),
+                }))
+            }
+            postgres_types::Type::NUMMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Numeric { constraints: None }),
+            },
+            postgres_types::Type::NUMMULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Numeric { constraints: None }),
+            })),
+            postgres_types::Type::TSMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Timestamp { precision: None }),
+            },
+            postgres_types::Type::TSMULTI_RANGE_ARRAY => Type::Array(Box::new(Type::MultiRange {
+                element_type: Box::new(Type::Timestamp { precision: None }),
+            })),
+            postgres_types::Type::TSTZMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::TimestampTz { precision: None }),
+            },
+            postgres_types::Type::TSTZMULTI_RANGE_ARRAY => {
+                Type::Array(Box::new(Type::MultiRange {
+                    element_type: Box::new(Type::TimestampTz { precision: None }),
+                }))
+            }
+            postgres_types::Type::DATEMULTI_RANGE => Type::MultiRange {
+                element_type: Box::new(Type::Date),
+            },
+            postgres_types::Type::DATEMULTI_RANGE_ARRAY => {
+                Type::Array(Box::new(Type::MultiRange {
+                    element_type: Box::new(Type::Date),
+                }))
+            }
             _ => return Err(TypeFromOidError::UnknownOid(oid)),
         };
 
@@ -664,6 +775,7 @@ impl Type {
                     typ => typ,
                 };
                 match elem_typ {
+                    Type::Bit { length } => *length = BitLength::from_typmod(typmod)?,
                     Type::BpChar { length } => *length = CharLength::from_typmod(typmod)?,
                     Type::Numeric { constraints } => {
                         *constraints = NumericConstraints::from_typmod(typmod)?
@@ -679,6 +791,7 @@ impl Type {
                     Type::TimestampTz { precision } => {
                         *precision = TimestampPrecision::from_typmod(typmod)?
                     }
+                    Type::VarBit { max_length } => *max_length = BitLength::from_typmod(typmod)?,
                     Type::VarChar { max_length } => *max_length = CharLength::from_typmod(typmod)?,
                     _ if typmod != -1 => return Err("type does not support type modifiers".into()),
                     _ => (),
@@ -702,6 +815,7 @@ impl Type {
             Type::Array(t) => match &**t {
                 Type::AclItem => &postgres_types::Type::ACLITEM_ARRAY,
                 Type::Array(_) => unreachable!(),
+                Type::Bit { .. } => &postgres_types::Type::BIT_ARRAY,
                 Type::Bool => &postgres_types::Type::BOOL_ARRAY,
                 Type::Bytea => &postgres_types::Type::BYTEA_ARRAY,
                 Type::Char => &postgres_types::Type::CHAR_ARRAY,
@@ -725,6 +839,7 @@ impl Type {
                 Type::Record(_) => &postgres_types::Type::RECORD_ARRAY,
                 Type::Text => &postgres_types::Type::TEXT_ARRAY,
                 Type::BpChar { .. } => &postgres_types::Type::BPCHAR_ARRAY,
+                Type::VarBit { .. } => &postgres_types::Type::VARBIT_ARRAY,
                 Type::VarChar { .. } => &postgres_types::Type::VARCHAR_ARRAY,
                 Type::Time { .. } => &postgres_types::Type::TIME_ARRAY,
                 Type::TimeTz { .. } => &postgres_types::Type::TIMETZ_ARRAY,
@@ -745,8 +860,18 @@ impl Type {
                     Type::Date => &postgres_types::Type::DATE_RANGE_ARRAY,
                     _ => unreachable!(),
                 },
+                Type::MultiRange { element_type } => match **element_type {
+                    Type::Int4 => &postgres_types::Type::INT4MULTI_RANGE_ARRAY,
+                    Type::Int8 => &postgres_types::Type::INT8MULTI_RANGE_ARRAY,
+                    Type::Numeric { .. } => &postgres_types::Type::NUMMULTI_RANGE_ARRAY,
+                    Type::Timestamp { .. } => &postgres_types::Type::TSMULTI_RANGE_ARRAY,
+                    Type::TimestampTz { .. } => &postgres_types::Type::TSTZMULTI_RANGE_ARRAY,
+                    Type::Date => &postgres_types::Type::DATEMULTI_RANGE_ARRAY,
+                    _ => unreachable!(),
+                },
                 Type::MzAclItem => &MZ_ACL_ITEM_ARRAY,
             },
+            Type::Bit { .. } => &postgres_types::Type::BIT,
             Type::Bool => &postgres_types::Type::BOOL,
             Type::Bytea => &postgres_types::Type::BYTEA,
             Type::Char => &postgres_types::Type::CHAR,
@@ -770,6 +895,7 @@ impl Type {
             Type::Record(_) => &postgres_types::Type::RECORD,
             Type::Text => &postgres_types::Type::TEXT,
             Type::BpChar { .. } => &postgres_types::Type::BPCHAR,
+            Type::VarBit { .. } => &postgres_types::Type::VARBIT,
             Type::VarChar { .. } => &postgres_types::Type::VARCHAR,
             Type::Time { .. } => &postgres_types::Type::TIME,
             Type::TimeTz { .. } => &postgres_types::Type::TIMETZ,
@@ -790,6 +916,15 @@ impl Type {
                 Type::Date => &postgres_types::Type::DATE_RANGE,
                 t => unreachable!("{t:?} is not a range element type"),
             },
+            Type::MultiRange { element_type } => match &**element_type {
+                Type::Int4 => &postgres_types::Type::INT4MULTI_RANGE,
+                Type::Int8 => &postgres_types::Type::INT8MULTI_RANGE,
+                Type::Numeric { .. } => &postgres_types::Type::NUMMULTI_RANGE,
+                Type::Timestamp { .. } => &postgres_types::Type::TSMULTI_RANGE,
+                Type::TimestampTz { .. } => &postgres_types::Type::TSTZMULTI_RANGE,
+                Type::Date => &postgres_types::Type::DATEMULTI_RANGE,
+                t => unreachable!("{t:?} is not a range element type"),
+            },
             Type::MzAclItem => &MZ_ACL_ITEM,
         }
     }
@@ -825,6 +960,7 @@ impl Type {
             &postgres_types::Type::TIMESTAMP_ARRAY => "timestamp without time zone[]",
             &postgres_types::Type::TIMESTAMPTZ_ARRAY => "timestamp with time zone[]",
             &postgres_types::Type::UUID_ARRAY => "uuid[]",
+            &postgres_types::Type::VARBIT_ARRAY => "bit varying[]",
             &postgres_types::Type::VARCHAR_ARRAY => "character varying[]",
             &postgres_types::Type::BOOL => "boolean",
             &postgres_types::Type::BPCHAR => "character",
@@ -835,6 +971,7 @@ impl Type {
             &postgres_types::Type::INT8 => "bigint",
             &postgres_types::Type::TIMESTAMP => "timestamp without time zone",
             &postgres_types::Type::TIMESTAMPTZ => "timestamp with time zone",
+            &postgres_types::Type::VARBIT => "bit varying",
             &postgres_types::Type::VARCHAR => "character varying",
             &postgres_types::Type::REGCLASS_ARRAY => "regclass[]",
             &postgres_types::Type::REGPROC_ARRAY => "regproc[]",
@@ -861,6 +998,12 @@ impl Type {
     /// Returns the constraint on the type, if any.
     pub fn constraint(&self) -> Option<&dyn TypeConstraint> {
         match self {
+            Type::Bit {
+                length: Some(length),
+            } => Some(length),
+            Type::VarBit {
+                max_length: Some(max_length),
+            } => Some(max_length),
             Type::BpChar {
                 length: Some(length),
             } => Some(length),
@@ -887,6 +1030,7 @@ impl Type {
             } => Some(precision),
             Type::AclItem
             | Type::Array(_)
+            | Type::Bit { length: None }
             | Type::Bool
             | Type::Bytea
             | Type::BpChar { length: None }
@@ -920,8 +1064,10 @@ impl Type {
             | Type::TimestampTz { precision: None }
             | Type::Uuid
             | Type::MzTimestamp
+            | Type::VarBit { max_length: None }
             | Type::VarChar { max_length: None }
             | Type::Range { .. }
+            | Type::MultiRange { .. }
             | Type::MzAclItem => None,
         }
     }
@@ -931,6 +1077,7 @@ impl Type {
     pub fn typlen(&self) -> i16 {
         match self {
             Type::Array(_) => -1,
+            Type::Bit { .. } => -1,
             Type::Bool => 1,
             Type::Bytea => -1,
             Type::Char => 1,
@@ -954,6 +1101,7 @@ impl Type {
             Type::Record(_) => -1,
             Type::Text => -1,
             Type::BpChar { .. } => -1,
+            Type::VarBit { .. } => -1,
             Type::VarChar { .. } => -1,
             Type::Time { .. } => 4,
             Type::TimeTz { .. } => 4,
@@ -970,6 +1118,7 @@ impl Type {
                 .try_into()
                 .expect("must fit"),
             Type::Range { .. } => -1,
+            Type::MultiRange { .. } => -1,
             Type::MzAclItem => MzAclItem::binary_size().try_into().expect("must fit"),
             Type::AclItem => AclItem::binary_size().try_into().expect("must fit"),
         }
@@ -1008,6 +1157,12 @@ impl TryFrom<&Type> for ScalarType {
         match typ {
             Type::AclItem => Ok(ScalarType::AclItem),
             Type::Array(t) => Ok(ScalarType::Array(Box::new(TryFrom::try_from(&**t)?))),
+            Type::Bit { length } => Ok(ScalarType::Bit {
+                length: match length {
+                    Some(length) => Some(AdtBitLength::try_from(i64::from(length.into_i32()))?),
+                    None => None,
+                },
+            }),
             Type::Bool => Ok(ScalarType::Bool),
             Type::Bytea => Ok(ScalarType::Bytes),
             Type::Char => Ok(ScalarType::PgLegacyChar),
@@ -1070,6 +1225,14 @@ impl TryFrom<&Type> for ScalarType {
                     None => None,
                 },
             }),
+            Type::VarBit { max_length } => Ok(ScalarType::VarBit {
+                max_length: match max_length {
+                    Some(max_length) => {
+                        Some(AdtBitLength::try_from(i64::from(max_length.into_i32()))?)
+                    }
+                    None => None,
+                },
+            }),
             Type::VarChar { max_length } => Ok(ScalarType::VarChar {
                 max_length: match max_length {
                     Some(max_length) => Some(VarCharMaxLength::try_from(i64::from(
@@ -1103,6 +1266,9 @@ impl TryFrom<&Type> for ScalarType {
             Type::Range { element_type } => Ok(ScalarType::Range {
                 element_type: Box::new(TryFrom::try_from(&**element_type)?),
             }),
+            Type::MultiRange { element_type } => Ok(ScalarType::MultiRange {
+                element_type: Box::new(TryFrom::try_from(&**element_type)?),
+            }),
             Type::MzAclItem => Ok(ScalarType::MzAclItem),
         }
     }
@@ -1162,6 +1328,9 @@ pub enum TypeConversionError {
     /// The source type contained an invalid max length for a
     /// [`ScalarType::VarChar`].
     InvalidVarCharMaxLength(InvalidVarCharMaxLengthError),
+    /// The source type contained an invalid length for a [`ScalarType::Bit`]
+    /// or [`ScalarType::VarBit`].
+    InvalidBitLength(InvalidBitLengthError),
     /// The source type contained an invalid precision for a
     /// [`ScalarType::Timestamp`] or [`ScalarType::TimestampTz`].
     InvalidTimestampPrecision(InvalidTimestampPrecisionError),
@@ -1175,6 +1344,7 @@ impl fmt::Display for TypeConversionError {
             TypeConversionError::InvalidNumericConstraint(msg) => f.write_str(msg),
             TypeConversionError::InvalidCharLength(e) => e.fmt(f),
             TypeConversionError::InvalidVarCharMaxLength(e) => e.fmt(f),
+            TypeConversionError::InvalidBitLength(e) => e.fmt(f),
             TypeConversionError::InvalidTimestampPrecision(e) => e.fmt(f),
         }
     }
@@ -1200,6 +1370,12 @@ impl From<InvalidVarCharMaxLengthError> for TypeConversionError {
     }
 }
 
+impl From<InvalidBitLengthError> for TypeConversionError {
+    fn from(e: InvalidBitLengthError) -> TypeConversionError {
+        TypeConversionError::InvalidBitLength(e)
+    }
+}
+
 impl From<InvalidTimestampPrecisionError> for TypeConversionError {
     fn from(e: InvalidTimestampPrecisionError) -> TypeConversionError {
         TypeConversionError::InvalidTimestampPrecision(e)
@@ -1211,6 +1387,9 @@ impl From<&ScalarType> for Type {
         match typ {
             ScalarType::AclItem => Type::AclItem,
             ScalarType::Array(t) => Type::Array(Box::new(From::from(&**t))),
+            ScalarType::Bit { length } => Type::Bit {
+                length: (*length).map(BitLength::from),
+            },
             ScalarType::Bool => Type::Bool,
             ScalarType::Bytes => Type::Bytea,
             ScalarType::PgLegacyChar => Type::Char,
@@ -1246,6 +1425,9 @@ impl From<&ScalarType> for Type {
             ScalarType::VarChar { max_length } => Type::VarChar {
                 max_length: (*max_length).map(CharLength::from),
             },
+            ScalarType::VarBit { max_length } => Type::VarBit {
+                max_length: (*max_length).map(BitLength::from),
+            },
             ScalarType::Time => Type::Time { precision: None },
             ScalarType::Timestamp { precision } => Type::Timestamp {
                 precision: (*precision).map(TimestampPrecision::from),
@@ -1271,6 +1453,9 @@ impl From<&ScalarType> for Type {
             ScalarType::Range { element_type } => Type::Range {
                 element_type: Box::new(From::from(&**element_type)),
             },
+            ScalarType::MultiRange { element_type } => Type::MultiRange {
+                element_type: Box::new(From::from(&**element_type)),
+            },
             ScalarType::MzAclItem => Type::MzAclItem,
         }
     }
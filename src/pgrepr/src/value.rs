@@ -16,9 +16,11 @@ use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use mz_ore::cast::ReinterpretCast;
 use mz_pgwire_common::Format;
 use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::bit::BitString;
 use mz_repr::adt::char;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::multirange;
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
 use mz_repr::adt::pg_legacy_name::NAME_MAX_BYTES;
 use mz_repr::adt::range::{Range, RangeInner};
@@ -47,6 +49,8 @@ pub enum Value {
         /// The elements of the array.
         elements: Vec<Option<Value>>,
     },
+    /// A fixed-length bit string.
+    Bit(BitString),
     /// A boolean value.
     Bool(bool),
     /// A byte array, i.e., a variable-length binary string.
@@ -99,6 +103,8 @@ pub enum Value {
     BpChar(String),
     /// A variable-length string with an optional limit.
     VarChar(String),
+    /// A variable-length bit string with an optional limit.
+    VarBit(BitString),
     /// A universally unique identifier.
     Uuid(Uuid),
     /// A small int vector.
@@ -110,6 +116,8 @@ pub enum Value {
     MzTimestamp(mz_repr::Timestamp),
     /// A contiguous range of values along a domain.
     Range(Range<Box<Value>>),
+    /// A set of non-overlapping ranges of values along a domain.
+    MultiRange(Vec<Range<Box<Value>>>),
     /// A list of privileges granted to a role, that uses [`mz_repr::role_id::RoleId`]s for role
     /// references.
     MzAclItem(MzAclItem),
@@ -153,6 +161,10 @@ impl Value {
             }
             (Datum::Interval(iv), ScalarType::Interval) => Some(Value::Interval(Interval(iv))),
             (Datum::Bytes(b), ScalarType::Bytes) => Some(Value::Bytea(b.to_vec())),
+            (Datum::Bytes(b), ScalarType::Bit { .. }) => Some(Value::Bit(BitString::decode(b))),
+            (Datum::Bytes(b), ScalarType::VarBit { .. }) => {
+                Some(Value::VarBit(BitString::decode(b)))
+            }
             (Datum::String(s), ScalarType::String) => Some(Value::Text(s.to_owned())),
             (Datum::String(s), ScalarType::VarChar { .. }) => Some(Value::VarChar(s.to_owned())),
             (Datum::String(s), ScalarType::Char { length }) => {
@@ -213,6 +225,20 @@ impl Value {
                 });
                 Some(Value::Range(value_range))
             }
+            (Datum::List(list), ScalarType::MultiRange { element_type }) => {
+                let ranges = list
+                    .iter()
+                    .map(|range| {
+                        range.unwrap_range().into_bounds(|b| {
+                            Box::new(
+                                Value::from_datum(b, element_type)
+                                    .expect("RangeBounds never contain Datum::Null"),
+                            )
+                        })
+                    })
+                    .collect();
+                Some(Value::MultiRange(ranges))
+            }
             _ => panic!("can't serialize {}::{:?}", datum, typ),
         }
     }
@@ -299,6 +325,7 @@ impl Value {
                 Datum::String(buf.push_string(s))
             }
             Value::BpChar(s) => Datum::String(buf.push_string(s.trim_end().into())),
+            Value::Bit(b) | Value::VarBit(b) => Datum::Bytes(buf.push_bytes(b.encode())),
             Value::Uuid(u) => Datum::Uuid(u),
             Value::Numeric(n) => Datum::Numeric(n.0),
             Value::MzTimestamp(t) => Datum::MzTimestamp(t),
@@ -311,6 +338,33 @@ impl Value {
 
                 buf.make_datum(|packer| packer.push_range(range).unwrap())
             }
+            Value::MultiRange(ranges) => {
+                let elem_pg_type = match typ {
+                    Type::MultiRange { element_type } => &*element_type,
+                    _ => panic!(
+                        "Value::MultiRange should have type Type::MultiRange. Found {:?}",
+                        typ
+                    ),
+                };
+                let ranges = ranges
+                    .into_iter()
+                    .map(|range| {
+                        let mut range =
+                            range.into_bounds(|elem| elem.into_datum(buf, elem_pg_type));
+                        range.canonicalize().unwrap();
+                        range
+                    })
+                    .collect();
+                let ranges = multirange::normalize(ranges);
+
+                buf.make_datum(|packer| {
+                    packer.push_list_with(|packer| {
+                        for range in ranges {
+                            packer.push_range(range).unwrap();
+                        }
+                    })
+                })
+            }
             Value::MzAclItem(mz_acl_item) => Datum::MzAclItem(mz_acl_item),
             Value::AclItem(acl_item) => Datum::AclItem(acl_item),
         }
@@ -384,6 +438,7 @@ impl Value {
             Value::Text(s) | Value::VarChar(s) | Value::BpChar(s) | Value::Name(s) => {
                 strconv::format_string(buf, s)
             }
+            Value::Bit(b) | Value::VarBit(b) => strconv::format_bit(buf, b),
             Value::Time(t) => strconv::format_time(buf, *t),
             Value::Timestamp(ts) => strconv::format_timestamp(buf, ts),
             Value::TimestampTz(ts) => strconv::format_timestamptz(buf, ts),
@@ -395,6 +450,13 @@ impl Value {
                 None => Ok::<_, ()>(buf.write_null()),
             })
             .expect("provided closure never fails"),
+            Value::MultiRange(ranges) => strconv::format_multirange(buf, ranges, |buf, range| {
+                strconv::format_range(buf, range, |buf, elem| match elem {
+                    Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer())),
+                    None => Ok::<_, ()>(buf.write_null()),
+                })
+            })
+            .expect("provided closure never fails"),
             Value::MzAclItem(mz_acl_item) => strconv::format_mz_acl_item(buf, *mz_acl_item),
             Value::AclItem(acl_item) => strconv::format_acl_item(buf, *acl_item),
         }
@@ -505,6 +567,12 @@ impl Value {
             Value::Text(s) => s.to_sql(&PgType::TEXT, buf),
             Value::BpChar(s) => s.to_sql(&PgType::BPCHAR, buf),
             Value::VarChar(s) => s.to_sql(&PgType::VARCHAR, buf),
+            Value::Bit(b) | Value::VarBit(b) => {
+                // https://github.com/postgres/postgres/blob/52377bb81/src/backend/utils/adt/varbit.c#L680-L699
+                buf.put_i32(pg_len("bit string length", b.len())?);
+                buf.put_slice(b.packed());
+                Ok(postgres_types::IsNull::No)
+            }
             Value::Time(t) => t.to_sql(&PgType::TIME, buf),
             Value::Timestamp(ts) => ts.to_sql(&PgType::TIMESTAMP, buf),
             Value::TimestampTz(ts) => ts.to_sql(&PgType::TIMESTAMPTZ, buf),
@@ -512,23 +580,25 @@ impl Value {
             Value::Numeric(a) => a.to_sql(&PgType::NUMERIC, buf),
             Value::MzTimestamp(t) => t.to_string().to_sql(&PgType::TEXT, buf),
             Value::Range(range) => {
-                buf.put_u8(range.pg_flag_bits());
-
                 let elem_type = match ty {
                     Type::Range { element_type } => element_type,
                     _ => unreachable!(),
                 };
-
-                if let Some(RangeInner { lower, upper }) = &range.inner {
-                    for bound in [&lower.bound, &upper.bound] {
-                        if let Some(bound) = bound {
-                            let base = buf.len();
-                            buf.put_i32(0);
-                            bound.encode_binary(elem_type, buf)?;
-                            let len = pg_len("encoded range bound", buf.len() - base - 4)?;
-                            buf[base..base + 4].copy_from_slice(&len.to_be_bytes());
-                        }
-                    }
+                encode_range(buf, range, elem_type)?;
+                Ok(postgres_types::IsNull::No)
+            }
+            Value::MultiRange(ranges) => {
+                let elem_type = match ty {
+                    Type::MultiRange { element_type } => element_type,
+                    _ => unreachable!(),
+                };
+                buf.put_i32(pg_len("number of multirange ranges", ranges.len())?);
+                for range in ranges {
+                    let base = buf.len();
+                    buf.put_i32(0);
+                    encode_range(buf, range, elem_type)?;
+                    let len = pg_len("encoded range", buf.len() - base - 4)?;
+                    buf[base..base + 4].copy_from_slice(&len.to_be_bytes());
                 }
                 Ok(postgres_types::IsNull::No)
             }
@@ -577,6 +647,8 @@ impl Value {
             ScalarType::String => true,
             ScalarType::VarChar { .. } => true,
             ScalarType::Char { .. } => true,
+            ScalarType::Bit { .. } => true,
+            ScalarType::VarBit { .. } => true,
             ScalarType::PgLegacyName => true,
             ScalarType::Jsonb => true,
             ScalarType::Uuid => true,
@@ -587,7 +659,9 @@ impl Value {
             ScalarType::Record { fields, .. } => fields
                 .iter()
                 .all(|(_, ty)| Self::can_encode_binary(&ty.scalar_type)),
-            ScalarType::Range { element_type } => Self::can_encode_binary(element_type),
+            ScalarType::Range { element_type } | ScalarType::MultiRange { element_type } => {
+                Self::can_encode_binary(element_type)
+            }
         }
     }
 
@@ -664,6 +738,8 @@ impl Value {
             Type::Text => Value::Text(s.to_owned()),
             Type::BpChar { .. } => Value::BpChar(s.to_owned()),
             Type::VarChar { .. } => Value::VarChar(s.to_owned()),
+            Type::Bit { .. } => Value::Bit(strconv::parse_bit(s)?),
+            Type::VarBit { .. } => Value::VarBit(strconv::parse_bit(s)?),
             Type::Time { .. } => Value::Time(strconv::parse_time(s)?),
             Type::TimeTz { .. } => return Err("input of timetz types is not implemented".into()),
             Type::Timestamp { .. } => Value::Timestamp(strconv::parse_timestamp(s)?),
@@ -673,6 +749,11 @@ impl Value {
             Type::Range { element_type } => Value::Range(strconv::parse_range(s, |elem_text| {
                 Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
             })?),
+            Type::MultiRange { element_type } => {
+                Value::MultiRange(strconv::parse_multirange(s, |elem_text| {
+                    Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
+                })?)
+            }
             Type::MzAclItem => Value::MzAclItem(strconv::parse_mz_acl_item(s)?),
             Type::AclItem => Value::AclItem(strconv::parse_acl_item(s)?),
         })
@@ -768,6 +849,9 @@ impl Value {
             Type::Text => packer.push(Datum::String(s)),
             Type::BpChar { .. } => packer.push(Datum::String(s.trim_end())),
             Type::VarChar { .. } => packer.push(Datum::String(s)),
+            Type::Bit { .. } | Type::VarBit { .. } => {
+                packer.push(Datum::Bytes(&strconv::parse_bit(s)?.encode()))
+            }
             Type::Time { .. } => packer.push(Datum::Time(strconv::parse_time(s)?)),
             Type::TimeTz { .. } => return Err("input of timetz types is not implemented".into()),
             Type::Timestamp { .. } => packer.push(Datum::Timestamp(strconv::parse_timestamp(s)?)),
@@ -787,6 +871,24 @@ impl Value {
 
                 packer.push_range(range).unwrap()
             }
+            Type::MultiRange { element_type } => {
+                let ranges = strconv::parse_multirange(s, |elem_text| {
+                    Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
+                })?;
+                let buf = RowArena::new();
+                let mut datum_ranges = Vec::with_capacity(ranges.len());
+                for range in ranges {
+                    let mut range = range.into_bounds(|elem| elem.into_datum(&buf, element_type));
+                    range.canonicalize()?;
+                    datum_ranges.push(range);
+                }
+
+                packer.push_list_with(|packer| {
+                    for range in multirange::normalize(datum_ranges) {
+                        packer.push_range(range).unwrap();
+                    }
+                })
+            }
             Type::MzAclItem => packer.push(Datum::MzAclItem(strconv::parse_mz_acl_item(s)?)),
             Type::AclItem => packer.push(Datum::AclItem(strconv::parse_acl_item(s)?)),
         })
@@ -835,6 +937,8 @@ impl Value {
             Type::Text => String::from_sql(ty.inner(), raw).map(Value::Text),
             Type::BpChar { .. } => String::from_sql(ty.inner(), raw).map(Value::BpChar),
             Type::VarChar { .. } => String::from_sql(ty.inner(), raw).map(Value::VarChar),
+            Type::Bit { .. } => decode_bit_binary(raw).map(Value::Bit),
+            Type::VarBit { .. } => decode_bit_binary(raw).map(Value::VarBit),
             Type::Time { .. } => NaiveTime::from_sql(ty.inner(), raw).map(Value::Time),
             Type::TimeTz { .. } => Err("input of timetz types is not implemented".into()),
             Type::Timestamp { .. } => {
@@ -854,6 +958,9 @@ impl Value {
                 Ok(Value::MzTimestamp(t))
             }
             Type::Range { .. } => Err("binary decoding of range types is not implemented".into()),
+            Type::MultiRange { .. } => {
+                Err("binary decoding of multirange types is not implemented".into())
+            }
             Type::MzAclItem => {
                 let mz_acl_item = MzAclItem::decode_binary(raw)?;
                 Ok(Value::MzAclItem(mz_acl_item))
@@ -863,6 +970,27 @@ impl Value {
     }
 }
 
+fn encode_range(
+    buf: &mut BytesMut,
+    range: &Range<Box<Value>>,
+    elem_type: &Type,
+) -> Result<(), io::Error> {
+    buf.put_u8(range.pg_flag_bits());
+
+    if let Some(RangeInner { lower, upper }) = &range.inner {
+        for bound in [&lower.bound, &upper.bound] {
+            if let Some(bound) = bound {
+                let base = buf.len();
+                buf.put_i32(0);
+                bound.encode_binary(elem_type, buf)?;
+                let len = pg_len("encoded range bound", buf.len() - base - 4)?;
+                buf[base..base + 4].copy_from_slice(&len.to_be_bytes());
+            }
+        }
+    }
+    Ok(())
+}
+
 fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result<(), io::Error> {
     match elem {
         None => buf.put_i32(-1),
@@ -877,6 +1005,17 @@ fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result
     Ok(())
 }
 
+/// Decodes a bit string in PostgreSQL's binary format: the length in bits
+/// followed by the bits, packed most significant bit first.
+fn decode_bit_binary(raw: &[u8]) -> Result<BitString, Box<dyn Error + Sync + Send>> {
+    let (len, bytes) = raw
+        .split_first_chunk::<4>()
+        .ok_or("invalid bit string: missing length")?;
+    let len = usize::try_from(i32::from_be_bytes(*len))
+        .map_err(|_| "invalid length in external bit string")?;
+    Ok(BitString::from_packed(len, bytes)?)
+}
+
 fn pg_len(what: &str, len: usize) -> Result<i32, io::Error> {
     len.try_into().map_err(|_| {
         io::Error::new(
@@ -21,7 +21,8 @@
 //! Additional messages are accumulated and returned as well.
 //!
 //! The first time a `conn=name` argument is specified, `cluster=name` can also
-//! be specified to set the sessions cluster on initial connection.
+//! be specified to set the sessions cluster on initial connection, and
+//! `replication=mode` to open a replication connection.
 //!
 //! During debugging, set the environment variable `PGTEST_VERBOSE=1` to see
 //! messages sent and received.
@@ -507,6 +508,11 @@ pub fn run_test(tf: &mut datadriven::TestFile, addr: String, user: String, timeo
             let cluster = cluster.into_first();
             options.push(("cluster", cluster.as_str()));
         }
+        let replication = args.remove("replication");
+        if let Some(replication) = &replication {
+            let replication = replication.into_first();
+            options.push(("replication", replication.as_str()));
+        }
         match tc.directive.as_str() {
             "send" => {
                 for line in lines {
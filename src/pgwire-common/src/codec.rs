@@ -18,6 +18,7 @@ use std::collections::BTreeMap;
 use std::error::Error;
 use std::{fmt, str};
 
+use base64::prelude::*;
 use byteorder::{ByteOrder, NetworkEndian};
 use bytes::{BufMut, BytesMut};
 use mz_ore::cast::{CastFrom, u64_to_usize};
@@ -26,7 +27,10 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::FrontendMessage;
 use crate::format::Format;
-use crate::message::{FrontendStartupMessage, VERSION_CANCEL, VERSION_GSSENC, VERSION_SSL};
+use crate::message::{
+    ChannelBinding, FrontendStartupMessage, GS2Header, SASLClientFinalResponse,
+    SASLInitialResponse, VERSION_CANCEL, VERSION_GSSENC, VERSION_SSL,
+};
 
 pub const REJECT_ENCRYPTION: u8 = b'N';
 pub const ACCEPT_SSL_ENCRYPTION: u8 = b'S';
@@ -204,6 +208,119 @@ impl FrontendMessage {
     }
 }
 
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] as a
+/// Password message.
+pub fn decode_password(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
+    Ok(FrontendMessage::Password {
+        password: buf.read_cstr()?.to_owned(),
+    })
+}
+
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] as a
+/// SASLInitialResponse message.
+pub fn decode_sasl_initial_response(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
+    let mechanism = buf.read_cstr()?.to_owned();
+    let len = buf.read_i32()?;
+    let data = match usize::try_from(len) {
+        Ok(len) => buf.read_bytes(len)?,
+        // A length of -1 indicates that there is no initial response.
+        Err(_) => return Err(input_err("SASL initial response is missing")),
+    };
+    let data = str::from_utf8(data).map_err(input_err)?;
+    Ok(FrontendMessage::SASLInitialResponse {
+        mechanism,
+        initial_response: parse_client_first_message(data)?,
+    })
+}
+
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] as a
+/// SASLResponse message.
+pub fn decode_sasl_response(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
+    let data = buf.read_bytes(buf.remaining())?;
+    let data = str::from_utf8(data).map_err(input_err)?;
+    Ok(FrontendMessage::SASLResponse(parse_client_final_message(
+        data,
+    )?))
+}
+
+/// Parses a SCRAM client-first-message.
+///
+/// See [RFC 5802, section 7](https://datatracker.ietf.org/doc/html/rfc5802#section-7).
+fn parse_client_first_message(data: &str) -> Result<SASLInitialResponse, io::Error> {
+    let malformed = || input_err("malformed SCRAM message");
+
+    let mut parts = data.splitn(3, ',');
+    let cbind_flag = match parts.next().ok_or_else(malformed)? {
+        "n" => ChannelBinding::NotSupported,
+        "y" => ChannelBinding::ClientSupported,
+        flag => match flag.strip_prefix("p=") {
+            Some(name) => ChannelBinding::Required(name.to_owned()),
+            None => return Err(malformed()),
+        },
+    };
+    let authzid = match parts.next().ok_or_else(malformed)? {
+        "" => None,
+        authzid => Some(authzid.strip_prefix("a=").ok_or_else(malformed)?.to_owned()),
+    };
+    let bare = parts.next().ok_or_else(malformed)?;
+    let raw = data[..data.len() - bare.len()].to_owned();
+
+    let mut attrs = bare.split(',');
+    let user = attrs.next().ok_or_else(malformed)?;
+    if user.starts_with("m=") {
+        return Err(input_err("unsupported SCRAM mandatory extension"));
+    }
+    // PostgreSQL ignores the SCRAM username in favor of the user in the
+    // startup message, and so do we.
+    if !user.starts_with("n=") {
+        return Err(malformed());
+    }
+    let nonce = attrs
+        .next()
+        .and_then(|attr| attr.strip_prefix("r="))
+        .filter(|nonce| !nonce.is_empty())
+        .ok_or_else(malformed)?;
+
+    Ok(SASLInitialResponse {
+        gs2_header: GS2Header {
+            cbind_flag,
+            authzid,
+            raw,
+        },
+        nonce: nonce.to_owned(),
+        client_first_message_bare_raw: bare.to_owned(),
+    })
+}
+
+/// Parses a SCRAM client-final-message.
+///
+/// See [RFC 5802, section 7](https://datatracker.ietf.org/doc/html/rfc5802#section-7).
+fn parse_client_final_message(data: &str) -> Result<SASLClientFinalResponse, io::Error> {
+    let malformed = || input_err("malformed SCRAM message");
+
+    // The proof is always the last attribute.
+    let (bare, proof) = data.rsplit_once(",p=").ok_or_else(malformed)?;
+    let mut attrs = bare.split(',');
+    let channel_binding = attrs
+        .next()
+        .and_then(|attr| attr.strip_prefix("c="))
+        .ok_or_else(malformed)?;
+    let channel_binding = BASE64_STANDARD
+        .decode(channel_binding)
+        .map_err(|_| malformed())?;
+    let nonce = attrs
+        .next()
+        .and_then(|attr| attr.strip_prefix("r="))
+        .ok_or_else(malformed)?;
+
+    Ok(SASLClientFinalResponse {
+        channel_binding,
+        nonce: nonce.to_owned(),
+        proof: proof.to_owned(),
+        client_final_message_bare_raw: bare.to_owned(),
+    })
+}
+
 #[derive(Debug)]
 pub enum DecodeState {
     Head,
@@ -317,6 +434,21 @@ impl<'a> Cursor<'a> {
         Ok(val)
     }
 
+    /// Reads the next `n` bytes, advancing the cursor by `n` bytes.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], io::Error> {
+        if self.buf.len() < n {
+            return Err(input_err("not enough buffer for bytes"));
+        }
+        let val = &self.buf[..n];
+        self.advance(n);
+        Ok(val)
+    }
+
+    /// Returns the number of bytes remaining in the cursor.
+    pub fn remaining(&self) -> usize {
+        self.buf.len()
+    }
+
     /// Reads the next 16-bit format code, advancing the cursor by two bytes.
     pub fn read_format(&mut self) -> Result<Format, io::Error> {
         Format::try_from(self.read_i16()?)
@@ -333,3 +465,47 @@ impl<'a> Cursor<'a> {
 pub fn input_err(source: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
     io::Error::new(io::ErrorKind::InvalidInput, source.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_parse_client_first_message() {
+        let msg = parse_client_first_message("n,,n=,r=rOprNGfwEbeRWgbNEkqO").unwrap();
+        assert_eq!(msg.gs2_header.cbind_flag, ChannelBinding::NotSupported);
+        assert_eq!(msg.gs2_header.authzid, None);
+        assert_eq!(msg.gs2_header.raw, "n,,");
+        assert_eq!(msg.nonce, "rOprNGfwEbeRWgbNEkqO");
+        assert_eq!(
+            msg.client_first_message_bare_raw,
+            "n=,r=rOprNGfwEbeRWgbNEkqO"
+        );
+
+        let msg = parse_client_first_message("p=tls-server-end-point,a=u,n=u,r=abc").unwrap();
+        assert_eq!(
+            msg.gs2_header.cbind_flag,
+            ChannelBinding::Required("tls-server-end-point".into())
+        );
+        assert_eq!(msg.gs2_header.authzid.as_deref(), Some("u"));
+        assert_eq!(msg.gs2_header.raw, "p=tls-server-end-point,a=u,");
+
+        assert!(parse_client_first_message("n,,m=ext,n=,r=abc").is_err());
+        assert!(parse_client_first_message("x,,n=,r=abc").is_err());
+        assert!(parse_client_first_message("n,,n=,r=").is_err());
+        assert!(parse_client_first_message("n,,n=").is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_parse_client_final_message() {
+        let msg = parse_client_final_message("c=biws,r=abcdef,p=cHJvb2Y=").unwrap();
+        assert_eq!(msg.channel_binding, b"n,,");
+        assert_eq!(msg.nonce, "abcdef");
+        assert_eq!(msg.proof, "cHJvb2Y=");
+        assert_eq!(msg.client_final_message_bare_raw, "c=biws,r=abcdef");
+
+        assert!(parse_client_final_message("c=biws,r=abcdef").is_err());
+        assert!(parse_client_final_message("c=!!,r=abcdef,p=cHJvb2Y=").is_err());
+        assert!(parse_client_final_message("r=abcdef,p=cHJvb2Y=").is_err());
+    }
+}
@@ -15,6 +15,9 @@ use async_trait::async_trait;
 use derivative::Derivative;
 use mz_ore::netio::AsyncReady;
 use mz_server_core::TlsMode;
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::x509::{X509, X509VerifyResult};
 use tokio::io::{self, AsyncRead, AsyncWrite, Interest, ReadBuf, Ready};
 use tokio_openssl::SslStream;
 use tokio_postgres::error::SqlState;
@@ -63,6 +66,47 @@ impl<A> Conn<A> {
 
         Ok(())
     }
+
+    /// Returns the `tls-server-end-point` channel binding data for this
+    /// connection, or `None` if the connection is not encrypted.
+    ///
+    /// Per [RFC 5929, section 4.1], this is the hash of the server's
+    /// certificate, using the hash function from the certificate's signature
+    /// algorithm, or SHA-256 if that function is MD5 or SHA-1.
+    ///
+    /// [RFC 5929, section 4.1]: https://datatracker.ietf.org/doc/html/rfc5929#section-4.1
+    pub fn channel_binding_data(&self) -> Option<Vec<u8>> {
+        let Conn::Ssl(inner) = self else {
+            return None;
+        };
+        let cert = inner.ssl().certificate()?;
+        let digest = cert
+            .signature_algorithm()
+            .object()
+            .nid()
+            .signature_algorithms()
+            .and_then(|algorithms| match algorithms.digest {
+                Nid::MD5 | Nid::SHA1 => None,
+                digest => MessageDigest::from_nid(digest),
+            })
+            .unwrap_or_else(MessageDigest::sha256);
+        let digest = cert.digest(digest).ok()?;
+        Some(digest.to_vec())
+    }
+
+    /// Returns the certificate that the client presented during the TLS
+    /// handshake, if any, and if it was verified against the configured client
+    /// CA.
+    pub fn peer_certificate(&self) -> Option<X509> {
+        let Conn::Ssl(inner) = self else {
+            return None;
+        };
+        let ssl = inner.ssl();
+        if ssl.verify_result() != X509VerifyResult::OK {
+            return None;
+        }
+        ssl.peer_certificate()
+    }
 }
 
 impl<A> AsyncRead for Conn<A>
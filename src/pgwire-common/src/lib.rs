@@ -20,7 +20,8 @@ mod severity;
 
 pub use codec::{
     ACCEPT_SSL_ENCRYPTION, CodecError, Cursor, DecodeState, MAX_REQUEST_SIZE, Pgbuf,
-    REJECT_ENCRYPTION, decode_startup, input_err, parse_frame_len,
+    REJECT_ENCRYPTION, decode_password, decode_sasl_initial_response, decode_sasl_response,
+    decode_startup, input_err, parse_frame_len,
 };
 pub use conn::{
     CONN_UUID_KEY, Conn, ConnectionCounter, ConnectionError, ConnectionHandle,
@@ -28,7 +29,8 @@ pub use conn::{
 };
 pub use format::Format;
 pub use message::{
-    ErrorResponse, FrontendMessage, FrontendStartupMessage, VERSION_3, VERSION_CANCEL,
-    VERSION_GSSENC, VERSION_SSL, VERSIONS,
+    ChannelBinding, ErrorResponse, FrontendMessage, FrontendStartupMessage, GS2Header,
+    SASLClientFinalResponse, SASLInitialResponse, SCRAM_SHA_256, SCRAM_SHA_256_PLUS,
+    TLS_SERVER_END_POINT, VERSION_3, VERSION_CANCEL, VERSION_GSSENC, VERSION_SSL, VERSIONS,
 };
 pub use severity::Severity;
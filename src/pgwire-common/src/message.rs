@@ -179,9 +179,30 @@ pub enum FrontendMessage {
 
     CopyFail(String),
 
+    /// An authentication response whose contents have not yet been decoded.
+    ///
+    /// Password, SASLInitialResponse, and SASLResponse messages all share the
+    /// same message type, so their contents can only be decoded once the
+    /// backend knows which authentication method it requested. See
+    /// [`decode_password`](crate::decode_password),
+    /// [`decode_sasl_initial_response`](crate::decode_sasl_initial_response),
+    /// and [`decode_sasl_response`](crate::decode_sasl_response).
+    RawAuthentication(Vec<u8>),
+
     Password {
         password: String,
     },
+
+    /// Begin a SASL authentication exchange.
+    SASLInitialResponse {
+        /// The SASL mechanism the client selected.
+        mechanism: String,
+        /// The client-first-message of the SCRAM exchange.
+        initial_response: SASLInitialResponse,
+    },
+
+    /// Continue a SASL authentication exchange.
+    SASLResponse(SASLClientFinalResponse),
 }
 
 impl FrontendMessage {
@@ -201,11 +222,78 @@ impl FrontendMessage {
             FrontendMessage::CopyData(_) => "copy_data",
             FrontendMessage::CopyDone => "copy_done",
             FrontendMessage::CopyFail(_) => "copy_fail",
+            FrontendMessage::RawAuthentication(_) => "raw_authentication",
             FrontendMessage::Password { .. } => "password",
+            FrontendMessage::SASLInitialResponse { .. } => "sasl_initial_response",
+            FrontendMessage::SASLResponse(_) => "sasl_response",
         }
     }
 }
 
+/// The SASL mechanism for SCRAM-SHA-256 without channel binding.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+/// The SASL mechanism for SCRAM-SHA-256 with channel binding.
+pub const SCRAM_SHA_256_PLUS: &str = "SCRAM-SHA-256-PLUS";
+
+/// The only channel binding type supported by `SCRAM-SHA-256-PLUS`.
+pub const TLS_SERVER_END_POINT: &str = "tls-server-end-point";
+
+/// The channel binding flag in a [`GS2Header`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelBinding {
+    /// The client does not support channel binding (`n`).
+    NotSupported,
+    /// The client supports channel binding, but believes the server does not
+    /// (`y`).
+    ClientSupported,
+    /// The client requires channel binding of the named type (`p=<name>`).
+    Required(String),
+}
+
+/// The GS2 header that prefixes a SCRAM client-first-message.
+///
+/// See [RFC 5802, section 7](https://datatracker.ietf.org/doc/html/rfc5802#section-7).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GS2Header {
+    /// The channel binding flag.
+    pub cbind_flag: ChannelBinding,
+    /// The authorization identity, if any.
+    pub authzid: Option<String>,
+    /// The header exactly as the client sent it, including the trailing
+    /// comma. The client echoes this back in the client-final-message.
+    pub raw: String,
+}
+
+/// The contents of a SASLInitialResponse message, i.e., a SCRAM
+/// client-first-message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SASLInitialResponse {
+    /// The GS2 header.
+    pub gs2_header: GS2Header,
+    /// The client nonce.
+    pub nonce: String,
+    /// The client-first-message without the GS2 header, which forms the
+    /// start of the SCRAM AuthMessage.
+    pub client_first_message_bare_raw: String,
+}
+
+/// The contents of a SASLResponse message, i.e., a SCRAM
+/// client-final-message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SASLClientFinalResponse {
+    /// The decoded channel binding attribute (`c`), which is the GS2 header
+    /// followed by the channel binding data, if any.
+    pub channel_binding: Vec<u8>,
+    /// The combined client and server nonce.
+    pub nonce: String,
+    /// The base64-encoded client proof.
+    pub proof: String,
+    /// The client-final-message without the proof, which forms the end of the
+    /// SCRAM AuthMessage.
+    pub client_final_message_bare_raw: String,
+}
+
 #[derive(Debug)]
 pub struct ErrorResponse {
     pub severity: Severity,
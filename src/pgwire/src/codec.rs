@@ -232,6 +232,9 @@ impl Encoder<BackendMessage> for Codec {
         let byte = match &msg {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::AuthenticationSASL { .. } => b'R',
+            BackendMessage::AuthenticationSASLContinue(_) => b'R',
+            BackendMessage::AuthenticationSASLFinal { .. } => b'R',
             BackendMessage::RowDescription(_) => b'T',
             BackendMessage::DataRow(_) => b'D',
             BackendMessage::CommandComplete { .. } => b'C',
@@ -242,6 +245,7 @@ impl Encoder<BackendMessage> for Codec {
             BackendMessage::PortalSuspended => b's',
             BackendMessage::BackendKeyData { .. } => b'K',
             BackendMessage::ParameterDescription(_) => b't',
+            BackendMessage::NotificationResponse { .. } => b'A',
             BackendMessage::ParseComplete => b'1',
             BackendMessage::BindComplete => b'2',
             BackendMessage::CloseComplete => b'3',
@@ -254,6 +258,7 @@ impl Encoder<BackendMessage> for Codec {
             }
             BackendMessage::CopyInResponse { .. } => b'G',
             BackendMessage::CopyOutResponse { .. } => b'H',
+            BackendMessage::CopyBothResponse => b'W',
             BackendMessage::CopyData(_) => b'd',
             BackendMessage::CopyDone => b'c',
         };
@@ -279,6 +284,11 @@ impl Encoder<BackendMessage> for Codec {
                     dst.put_format_i16(format);
                 }
             }
+            BackendMessage::CopyBothResponse => {
+                // Replication streams are always binary and have no columns.
+                dst.put_format_i8(mz_pgwire_common::Format::Binary);
+                dst.put_length_i16(0)?;
+            }
             BackendMessage::CopyData(data) => {
                 dst.put_slice(&data);
             }
@@ -289,6 +299,21 @@ impl Encoder<BackendMessage> for Codec {
             BackendMessage::AuthenticationCleartextPassword => {
                 dst.put_u32(3);
             }
+            BackendMessage::AuthenticationSASL { mechanisms } => {
+                dst.put_u32(10);
+                for mechanism in mechanisms {
+                    dst.put_string(mechanism);
+                }
+                dst.put_u8(b'\0');
+            }
+            BackendMessage::AuthenticationSASLContinue(server_first) => {
+                dst.put_u32(11);
+                dst.put_slice(server_first.to_string().as_bytes());
+            }
+            BackendMessage::AuthenticationSASLFinal { verifier } => {
+                dst.put_u32(12);
+                dst.put_slice(format!("v={}", verifier).as_bytes());
+            }
             BackendMessage::RowDescription(fields) => {
                 dst.put_length_i16(fields.len())?;
                 for f in &fields {
@@ -351,6 +376,15 @@ impl Encoder<BackendMessage> for Codec {
                     dst.put_u32(param.oid());
                 }
             }
+            BackendMessage::NotificationResponse {
+                pid,
+                channel,
+                payload,
+            } => {
+                dst.put_u32(pid);
+                dst.put_string(&channel);
+                dst.put_string(&payload);
+            }
             BackendMessage::ErrorResponse(ErrorResponse {
                 severity,
                 code,
@@ -446,7 +480,7 @@ impl Decoder for Codec {
                         b'X' => decode_terminate(buf)?,
 
                         // Authentication.
-                        b'p' => decode_password(buf)?,
+                        b'p' => decode_raw_authentication(buf, frame_len)?,
 
                         // Copy from flow.
                         b'f' => decode_copy_fail(buf)?,
@@ -475,10 +509,15 @@ fn decode_terminate(mut _buf: Cursor) -> Result<FrontendMessage, io::Error> {
     Ok(FrontendMessage::Terminate)
 }
 
-fn decode_password(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
-    Ok(FrontendMessage::Password {
-        password: buf.read_cstr()?.to_owned(),
-    })
+fn decode_raw_authentication(
+    mut buf: Cursor,
+    frame_len: usize,
+) -> Result<FrontendMessage, io::Error> {
+    // The message type is shared by several authentication messages, so the
+    // contents are decoded later once the expected message is known.
+    Ok(FrontendMessage::RawAuthentication(
+        buf.read_bytes(frame_len)?.to_vec(),
+    ))
 }
 
 fn decode_query(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
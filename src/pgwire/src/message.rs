@@ -7,9 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::fmt;
+
 use enum_kinds::EnumKind;
 use itertools::Itertools;
-use mz_adapter::session::TransactionCode;
+use mz_adapter::session::{Notification, TransactionCode};
 use mz_pgwire_common::ErrorResponse;
 use mz_repr::{ColumnName, RelationDesc};
 
@@ -21,6 +23,17 @@ use mz_repr::{ColumnName, RelationDesc};
 pub enum BackendMessage {
     AuthenticationOk,
     AuthenticationCleartextPassword,
+    /// Begin a SASL exchange using one of the listed mechanisms.
+    AuthenticationSASL {
+        mechanisms: Vec<&'static str>,
+    },
+    /// Continue a SASL exchange with a SCRAM server-first-message.
+    AuthenticationSASLContinue(SASLServerFirstMessage),
+    /// Complete a SASL exchange with a SCRAM server-final-message.
+    AuthenticationSASLFinal {
+        /// The base64-encoded server signature.
+        verifier: String,
+    },
     CommandComplete {
         tag: String,
     },
@@ -34,6 +47,12 @@ pub enum BackendMessage {
         secret_key: u32,
     },
     ParameterDescription(Vec<mz_pgrepr::Type>),
+    /// An asynchronous notification raised on a channel the session is listening on.
+    NotificationResponse {
+        pid: u32,
+        channel: String,
+        payload: String,
+    },
     PortalSuspended,
     NoData,
     ParseComplete,
@@ -48,16 +67,57 @@ pub enum BackendMessage {
         overall_format: mz_pgwire_common::Format,
         column_formats: Vec<mz_pgwire_common::Format>,
     },
+    /// Begin a bidirectional copy, as used by streaming replication.
+    CopyBothResponse,
     CopyData(Vec<u8>),
     CopyDone,
 }
 
+/// A SCRAM server-first-message.
+///
+/// See [RFC 5802, section 7](https://datatracker.ietf.org/doc/html/rfc5802#section-7).
+#[derive(Debug, Clone)]
+pub struct SASLServerFirstMessage {
+    /// The combined client and server nonce.
+    pub nonce: String,
+    /// The base64-encoded salt.
+    pub salt: String,
+    /// The PBKDF2 iteration count.
+    pub iteration_count: usize,
+}
+
+impl fmt::Display for SASLServerFirstMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "r={},s={},i={}",
+            self.nonce, self.salt, self.iteration_count
+        )
+    }
+}
+
 impl From<ErrorResponse> for BackendMessage {
     fn from(err: ErrorResponse) -> BackendMessage {
         BackendMessage::ErrorResponse(err)
     }
 }
 
+impl From<Notification> for BackendMessage {
+    fn from(
+        Notification {
+            pid,
+            channel,
+            payload,
+        }: Notification,
+    ) -> BackendMessage {
+        BackendMessage::NotificationResponse {
+            pid,
+            channel,
+            payload,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct FieldDescription {
     pub name: ColumnName,
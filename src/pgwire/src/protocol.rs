@@ -27,7 +27,6 @@ use mz_adapter::{
     AdapterError, AdapterNotice, ExecuteContextExtra, ExecuteResponse, PeekResponseUnary,
     verify_datum_desc,
 };
-use mz_auth::password::Password;
 use mz_authenticator::Authenticator;
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
@@ -36,17 +35,22 @@ use mz_ore::str::StrExt;
 use mz_ore::{assert_none, assert_ok, instrument};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_pgwire_common::{
-    ConnectionCounter, ErrorResponse, Format, FrontendMessage, Severity, VERSION_3, VERSIONS,
+    ChannelBinding, ConnectionCounter, Cursor, ErrorResponse, Format, FrontendMessage,
+    SCRAM_SHA_256, SCRAM_SHA_256_PLUS, Severity, TLS_SERVER_END_POINT, VERSION_3, VERSIONS,
+    decode_password, decode_sasl_initial_response, decode_sasl_response,
 };
 use mz_repr::user::InternalUserMetadata;
 use mz_repr::{
-    CatalogItemId, ColumnIndex, Datum, RelationDesc, RelationType, RowArena, RowIterator, RowRef,
-    ScalarType,
+    CatalogItemId, ColumnIndex, Datum, RelationDesc, RelationType, RelationVersionSelector,
+    RowArena, RowIterator, RowRef, ScalarType, Timestamp,
 };
 use mz_server_core::TlsMode;
 use mz_server_core::listeners::AllowedRoles;
 use mz_sql::ast::display::AstDisplay;
-use mz_sql::ast::{CopyDirection, CopyStatement, FetchDirection, Ident, Raw, Statement};
+use mz_sql::ast::{
+    CopyDirection, CopyStatement, FetchDirection, Ident, Raw, Statement, UnresolvedItemName,
+};
+use mz_sql::catalog::{CatalogItem, SessionCatalog};
 use mz_sql::parse::StatementParseResult;
 use mz_sql::plan::{CopyFormat, ExecuteTimeout, StatementDesc};
 use mz_sql::session::metadata::SessionMetadata;
@@ -61,7 +65,11 @@ use tracing::{Instrument, debug, debug_span, warn};
 use uuid::Uuid;
 
 use crate::codec::FramedConn;
-use crate::message::{self, BackendMessage};
+use crate::message::{self, BackendMessage, SASLServerFirstMessage};
+use crate::replication::{
+    self, Lsn, PgOutputOptions, ReplicatedRelation, ReplicationCommand, ReplicationCommandError,
+    ReplicationStream, StandbyMessage,
+};
 
 /// Reports whether the given stream begins with a pgwire handshake.
 ///
@@ -107,6 +115,175 @@ pub struct RunParams<'a, A> {
     pub allowed_roles: AllowedRoles,
 }
 
+/// Authenticates `user` with a SCRAM-SHA-256 SASL exchange.
+///
+/// If the connection is encrypted, `SCRAM-SHA-256-PLUS` is offered as well,
+/// which binds the exchange to the TLS channel. Returns the error to report to
+/// the client, if authentication fails.
+async fn sasl_authenticate<A>(
+    conn: &mut FramedConn<A>,
+    adapter_client: &mz_adapter::Client,
+    user: &String,
+) -> Result<Result<mz_adapter::AuthResponse, ErrorResponse>, io::Error>
+where
+    A: AsyncRead + AsyncWrite + Unpin,
+{
+    let channel_binding_data = conn.inner().channel_binding_data();
+    let mechanisms = match channel_binding_data {
+        Some(_) => vec![SCRAM_SHA_256_PLUS, SCRAM_SHA_256],
+        None => vec![SCRAM_SHA_256],
+    };
+    conn.send(BackendMessage::AuthenticationSASL { mechanisms })
+        .await?;
+    conn.flush().await?;
+
+    let (mechanism, initial_response) = match conn.recv().await? {
+        Some(FrontendMessage::RawAuthentication(data)) => {
+            match decode_sasl_initial_response(Cursor::new(&data)) {
+                Ok(FrontendMessage::SASLInitialResponse {
+                    mechanism,
+                    initial_response,
+                }) => (mechanism, initial_response),
+                Ok(_) => unreachable!("decoded a SASLInitialResponse"),
+                Err(err) => {
+                    return Ok(Err(ErrorResponse::fatal(
+                        SqlState::PROTOCOL_VIOLATION,
+                        err.to_string(),
+                    )));
+                }
+            }
+        }
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                "expected SASLInitialResponse message",
+            )));
+        }
+    };
+
+    // Check that the channel binding flag agrees with the chosen mechanism.
+    let cbind_flag = &initial_response.gs2_header.cbind_flag;
+    let cbind_data = match (mechanism.as_str(), cbind_flag, &channel_binding_data) {
+        (SCRAM_SHA_256_PLUS, ChannelBinding::Required(name), Some(data))
+            if name == TLS_SERVER_END_POINT =>
+        {
+            Some(data.as_slice())
+        }
+        (SCRAM_SHA_256, ChannelBinding::NotSupported, _)
+        | (SCRAM_SHA_256, ChannelBinding::ClientSupported, None) => None,
+        (SCRAM_SHA_256 | SCRAM_SHA_256_PLUS, _, _) => {
+            // In particular, a client that supports channel binding but
+            // believes we do not, despite us offering it, may be the victim
+            // of a downgrade attack.
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::PROTOCOL_VIOLATION,
+                "SCRAM channel binding negotiation error",
+            )));
+        }
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::PROTOCOL_VIOLATION,
+                "client selected an invalid SASL authentication mechanism",
+            )));
+        }
+    };
+
+    let nonce = match mz_auth::hash::generate_nonce(&initial_response.nonce) {
+        Ok(nonce) => nonce,
+        Err(err) => {
+            warn!(?err, "failed to generate SCRAM nonce");
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::INTERNAL_ERROR,
+                "failed to generate SCRAM nonce",
+            )));
+        }
+    };
+    let challenge = match adapter_client.get_sasl_challenge(user).await {
+        Ok(challenge) => challenge,
+        Err(err) => {
+            warn!(?err, "pgwire connection failed authentication");
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::INVALID_PASSWORD,
+                "invalid password",
+            )));
+        }
+    };
+    let server_first = SASLServerFirstMessage {
+        nonce,
+        salt: challenge.salt,
+        iteration_count: challenge.iteration_count,
+    };
+    conn.send(BackendMessage::AuthenticationSASLContinue(
+        server_first.clone(),
+    ))
+    .await?;
+    conn.flush().await?;
+
+    let final_response = match conn.recv().await? {
+        Some(FrontendMessage::RawAuthentication(data)) => {
+            match decode_sasl_response(Cursor::new(&data)) {
+                Ok(FrontendMessage::SASLResponse(final_response)) => final_response,
+                Ok(_) => unreachable!("decoded a SASLResponse"),
+                Err(err) => {
+                    return Ok(Err(ErrorResponse::fatal(
+                        SqlState::PROTOCOL_VIOLATION,
+                        err.to_string(),
+                    )));
+                }
+            }
+        }
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                "expected SASLResponse message",
+            )));
+        }
+    };
+
+    if final_response.nonce != server_first.nonce {
+        return Ok(Err(ErrorResponse::fatal(
+            SqlState::PROTOCOL_VIOLATION,
+            "SCRAM nonce mismatch",
+        )));
+    }
+    // The client must echo back its GS2 header, followed by the channel
+    // binding data if it requested channel binding.
+    let mut expected_channel_binding = initial_response.gs2_header.raw.clone().into_bytes();
+    expected_channel_binding.extend(cbind_data.unwrap_or_default());
+    if final_response.channel_binding != expected_channel_binding {
+        return Ok(Err(ErrorResponse::fatal(
+            SqlState::PROTOCOL_VIOLATION,
+            "SCRAM channel binding check failed",
+        )));
+    }
+
+    let auth_message = format!(
+        "{},{},{}",
+        initial_response.client_first_message_bare_raw,
+        server_first,
+        final_response.client_final_message_bare_raw
+    );
+    let verified = adapter_client
+        .verify_sasl_proof(user, &final_response.proof, &auth_message)
+        .await;
+    match verified {
+        Ok(verified) => {
+            conn.send(BackendMessage::AuthenticationSASLFinal {
+                verifier: verified.verifier,
+            })
+            .await?;
+            Ok(Ok(verified.auth_resp))
+        }
+        Err(err) => {
+            warn!(?err, "pgwire connection failed authentication");
+            Ok(Err(ErrorResponse::fatal(
+                SqlState::INVALID_PASSWORD,
+                "invalid password",
+            )))
+        }
+    }
+}
+
 /// Runs a pgwire connection to completion.
 ///
 /// This involves responding to `FrontendMessage::StartupMessage` and all future
@@ -145,6 +322,33 @@ where
 
     let user = params.remove("user").unwrap_or_else(String::new);
 
+    // Logical replication connections may issue replication commands in
+    // addition to SQL. Physical replication is not supported.
+    let replication = match params.remove("replication") {
+        None => false,
+        Some(value) => match value.to_lowercase().as_str() {
+            "database" => true,
+            "false" | "off" | "no" | "0" => false,
+            "true" | "on" | "yes" | "1" => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::FEATURE_NOT_SUPPORTED,
+                        "physical replication is not supported",
+                    ))
+                    .await;
+            }
+            _ => {
+                let msg = format!(
+                    "invalid value for parameter \"replication\": {}",
+                    value.quoted()
+                );
+                return conn
+                    .send(ErrorResponse::fatal(SqlState::INVALID_PARAMETER_VALUE, msg))
+                    .await;
+            }
+        },
+    };
+
     // TODO move this somewhere it can be shared with HTTP
     let is_internal_user = INTERNAL_USER_NAMES.contains(&user);
     // this is a superset of internal users
@@ -171,15 +375,18 @@ where
                 .await?;
             conn.flush().await?;
             let password = match conn.recv().await? {
-                Some(FrontendMessage::Password { password }) => password,
-                _ => {
-                    return conn
-                        .send(ErrorResponse::fatal(
-                            SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
-                            "expected Password message",
-                        ))
-                        .await;
+                Some(FrontendMessage::RawAuthentication(data)) => {
+                    decode_password(Cursor::new(&data)).ok()
                 }
+                _ => None,
+            };
+            let Some(FrontendMessage::Password { password }) = password else {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                        "expected Password message",
+                    ))
+                    .await;
             };
 
             let auth_response = frontegg.authenticate(&user, &password).await;
@@ -216,24 +423,78 @@ where
             }
         }
         Authenticator::Password(adapter_client) => {
-            conn.send(BackendMessage::AuthenticationCleartextPassword)
-                .await?;
-            conn.flush().await?;
-            let password = match conn.recv().await? {
-                Some(FrontendMessage::Password { password }) => Password(password),
-                _ => {
+            let auth_response = match sasl_authenticate(conn, &adapter_client, &user).await? {
+                Ok(resp) => resp,
+                Err(err) => return conn.send(err).await,
+            };
+            let session = adapter_client.new_session(SessionConfig {
+                conn_id: conn.conn_id().clone(),
+                uuid: conn_uuid,
+                user,
+                client_ip: conn.peer_addr().clone(),
+                external_metadata_rx: None,
+                internal_user_metadata: Some(InternalUserMetadata {
+                    superuser: auth_response.superuser,
+                }),
+                helm_chart_version,
+            });
+            // No frontegg check, so auth session lasts indefinitely.
+            let auth_session = pending().right_future();
+            (session, auth_session)
+        }
+        Authenticator::Certificate(certificate) => {
+            let cert = conn.inner().peer_certificate();
+            let login = match certificate.authenticate(cert.as_deref(), Some(&user)).await {
+                Ok(login) => login,
+                Err(err) => {
+                    warn!(?err, "pgwire connection failed certificate authentication");
                     return conn
                         .send(ErrorResponse::fatal(
                             SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
-                            "expected Password message",
+                            format!("certificate authentication failed for user \"{user}\""),
                         ))
                         .await;
                 }
             };
-            let auth_response = match adapter_client.authenticate(&user, &password).await {
-                Ok(resp) => resp,
+            let session = adapter_client.new_session(SessionConfig {
+                conn_id: conn.conn_id().clone(),
+                uuid: conn_uuid,
+                user: login.role_name,
+                client_ip: conn.peer_addr().clone(),
+                external_metadata_rx: None,
+                internal_user_metadata: Some(InternalUserMetadata {
+                    superuser: login.auth_resp.superuser,
+                }),
+                helm_chart_version,
+            });
+            // No frontegg check, so auth session lasts indefinitely.
+            let auth_session = pending().right_future();
+            (session, auth_session)
+        }
+        Authenticator::Oidc(oidc) => {
+            // The token is sent as the password.
+            conn.send(BackendMessage::AuthenticationCleartextPassword)
+                .await?;
+            conn.flush().await?;
+            let password = match conn.recv().await? {
+                Some(FrontendMessage::RawAuthentication(data)) => {
+                    decode_password(Cursor::new(&data)).ok()
+                }
+                _ => None,
+            };
+            let Some(FrontendMessage::Password { password }) = password else {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                        "expected Password message",
+                    ))
+                    .await;
+            };
+
+            let login = match oidc.authenticate(&password, Some(&user)).await {
+                Ok(login) => login,
                 Err(err) => {
-                    warn!(?err, "pgwire connection failed authentication");
+                    warn!(?err, "pgwire connection failed OIDC authentication");
                     return conn
                         .send(ErrorResponse::fatal(
                             SqlState::INVALID_PASSWORD,
@@ -245,15 +506,16 @@ where
             let session = adapter_client.new_session(SessionConfig {
                 conn_id: conn.conn_id().clone(),
                 uuid: conn_uuid,
-                user,
+                user: login.role_name,
                 client_ip: conn.peer_addr().clone(),
                 external_metadata_rx: None,
                 internal_user_metadata: Some(InternalUserMetadata {
-                    superuser: auth_response.superuser,
+                    superuser: login.superuser,
                 }),
                 helm_chart_version,
             });
-            // No frontegg check, so auth session lasts indefinitely.
+            // Tokens are only checked at login, so the auth session lasts
+            // indefinitely.
             let auth_session = pending().right_future();
             (session, auth_session)
         }
@@ -349,6 +611,7 @@ where
         conn,
         adapter_client,
         txn_needs_commit: false,
+        replication,
     };
 
     select! {
@@ -469,6 +732,8 @@ struct StateMachine<'a, A> {
     conn: &'a mut FramedConn<A>,
     adapter_client: mz_adapter::SessionClient,
     txn_needs_commit: bool,
+    /// Whether this is a logical replication connection.
+    replication: bool,
 }
 
 enum SendRowsEndedReason {
@@ -482,6 +747,23 @@ enum SendRowsEndedReason {
     Canceled,
 }
 
+/// An event that advances a logical replication stream.
+enum ReplicationEvent {
+    Message(Option<FrontendMessage>),
+    Batch(Option<PeekResponseUnary>),
+    Keepalive,
+}
+
+fn protocol_violation(msg: String) -> (ErrorResponse, StatementEndedExecutionReason) {
+    let err = ErrorResponse::fatal(SqlState::PROTOCOL_VIOLATION, msg.clone());
+    (err, StatementEndedExecutionReason::Errored { error: msg })
+}
+
+fn internal_error(msg: String) -> (ErrorResponse, StatementEndedExecutionReason) {
+    let err = ErrorResponse::error(SqlState::INTERNAL_ERROR, msg.clone());
+    (err, StatementEndedExecutionReason::Errored { error: msg })
+}
+
 const ABORTED_TXN_MSG: &str =
     "current transaction is aborted, commands ignored until end of transaction block";
 
@@ -512,6 +794,26 @@ where
 
     #[instrument(level = "debug")]
     async fn advance_ready(&mut self) -> Result<State, io::Error> {
+        // Outside of a transaction there is no pending idle-in-transaction timeout, and
+        // asynchronous notifications are delivered as soon as they arrive.
+        if matches!(
+            self.adapter_client.session().transaction(),
+            TransactionStatus::Default
+        ) {
+            let message = select! {
+                biased;
+
+                // `recv()` is cancel-safe as per it's docs.
+                message = self.conn.recv() => message?,
+                // `recv_notification()` is cancel-safe as per it's docs.
+                notification = self.adapter_client.session().recv_notification() => {
+                    self.send(notification).await?;
+                    return self.flush().await;
+                },
+            };
+            return self.advance_ready_message(message).await;
+        }
+
         // Handle timeouts first so we don't execute any statements when there's a pending timeout.
         let message = select! {
             biased;
@@ -538,6 +840,14 @@ where
             // `recv()` is cancel-safe as per it's docs.
             message = self.conn.recv() => message?,
         };
+        self.advance_ready_message(message).await
+    }
+
+    #[instrument(level = "debug")]
+    async fn advance_ready_message(
+        &mut self,
+        message: Option<FrontendMessage>,
+    ) -> Result<State, io::Error> {
         let received = SYSTEM_TIME();
 
         self.adapter_client
@@ -633,7 +943,10 @@ where
             Some(FrontendMessage::CopyData(_))
             | Some(FrontendMessage::CopyDone)
             | Some(FrontendMessage::CopyFail(_))
-            | Some(FrontendMessage::Password { .. }) => State::Drain,
+            | Some(FrontendMessage::RawAuthentication(_))
+            | Some(FrontendMessage::Password { .. })
+            | Some(FrontendMessage::SASLInitialResponse { .. })
+            | Some(FrontendMessage::SASLResponse(_)) => State::Drain,
             None => State::Done,
         };
         if let Some(start) = start {
@@ -778,6 +1091,20 @@ where
     /// For implicit transaction handling, see "Multiple Statements in a Simple Query" in the above.
     #[instrument(level = "debug")]
     async fn query(&mut self, sql: String, received: EpochMillis) -> Result<State, io::Error> {
+        if let Some(command) = self
+            .replication
+            .then(|| replication::parse_command(&sql))
+            .flatten()
+        {
+            if let State::Done = self.replication_command(command).await? {
+                return Ok(State::Done);
+            }
+            if self.adapter_client.session().transaction().is_implicit() {
+                self.commit_transaction().await?;
+            }
+            return self.ready().await;
+        }
+
         // Parse first before doing any transaction checking.
         let stmts = match self.parse_sql(&sql) {
             Ok(stmts) => stmts,
@@ -830,6 +1157,435 @@ where
         self.ready().await
     }
 
+    /// Executes a command received on a logical replication connection.
+    async fn replication_command(
+        &mut self,
+        command: Result<ReplicationCommand, ReplicationCommandError>,
+    ) -> Result<State, io::Error> {
+        let command = match command {
+            Ok(command) => command,
+            Err(ReplicationCommandError::Syntax(msg)) => {
+                return self
+                    .error(ErrorResponse::error(SqlState::SYNTAX_ERROR, msg))
+                    .await;
+            }
+            Err(ReplicationCommandError::Unsupported(msg)) => {
+                return self
+                    .error(ErrorResponse::error(SqlState::FEATURE_NOT_SUPPORTED, msg))
+                    .await;
+            }
+        };
+        if !matches!(
+            self.adapter_client.session().transaction(),
+            TransactionStatus::Default
+        ) {
+            return self
+                .error(ErrorResponse::error(
+                    SqlState::ACTIVE_SQL_TRANSACTION,
+                    "cannot execute replication commands inside a transaction block",
+                ))
+                .await;
+        }
+        match command {
+            ReplicationCommand::IdentifySystem => self.identify_system().await,
+            ReplicationCommand::CreateReplicationSlot { slot } => {
+                self.create_replication_slot(slot).await
+            }
+            ReplicationCommand::DropReplicationSlot { slot } => {
+                match self.adapter_client.drop_replication_slot(slot).await {
+                    Ok(()) => {
+                        self.send(BackendMessage::CommandComplete {
+                            tag: "DROP_REPLICATION_SLOT".into(),
+                        })
+                        .await?;
+                        Ok(State::Ready)
+                    }
+                    Err(e) => self.error(e.into_response(Severity::Error)).await,
+                }
+            }
+            ReplicationCommand::StartReplication {
+                slot,
+                start_lsn,
+                options,
+            } => {
+                self.ensure_transaction(1).await?;
+                self.start_replication(slot, start_lsn, options).await
+            }
+        }
+    }
+
+    /// Responds to `IDENTIFY_SYSTEM`. The current time serves as the current
+    /// LSN.
+    async fn identify_system(&mut self) -> Result<State, io::Error> {
+        let desc = RelationDesc::builder()
+            .with_column("systemid", ScalarType::String.nullable(false))
+            .with_column("timeline", ScalarType::Int32.nullable(false))
+            .with_column("xlogpos", ScalarType::String.nullable(false))
+            .with_column("dbname", ScalarType::String.nullable(false))
+            .finish();
+        let formats = vec![Format::Text; desc.arity()];
+        self.send(BackendMessage::RowDescription(
+            message::encode_row_description(&desc, &formats),
+        ))
+        .await?;
+        self.conn.set_encode_state(
+            desc.typ()
+                .column_types
+                .iter()
+                .map(|ty| mz_pgrepr::Type::from(&ty.scalar_type))
+                .zip(formats)
+                .collect(),
+        );
+
+        let catalog = self.adapter_client.catalog_snapshot().await;
+        let system_id = catalog.config().environment_id.to_string();
+        let database = self.adapter_client.session().vars().database().to_string();
+        self.send(BackendMessage::DataRow(vec![
+            Some(mz_pgrepr::Value::Text(system_id)),
+            Some(mz_pgrepr::Value::Int4(1)),
+            Some(mz_pgrepr::Value::Text(Lsn(SYSTEM_TIME()).to_string())),
+            Some(mz_pgrepr::Value::Text(database)),
+        ]))
+        .await?;
+        self.send(BackendMessage::CommandComplete {
+            tag: "IDENTIFY_SYSTEM".into(),
+        })
+        .await?;
+        Ok(State::Ready)
+    }
+
+    /// Responds to `CREATE_REPLICATION_SLOT`. The slot has no consistent
+    /// point of its own: streaming from it at LSN 0 begins with a snapshot.
+    async fn create_replication_slot(&mut self, slot: String) -> Result<State, io::Error> {
+        if let Err(e) = self
+            .adapter_client
+            .create_replication_slot(slot.clone())
+            .await
+        {
+            return self.error(e.into_response(Severity::Error)).await;
+        }
+
+        let desc = RelationDesc::builder()
+            .with_column("slot_name", ScalarType::String.nullable(false))
+            .with_column("consistent_point", ScalarType::String.nullable(false))
+            .with_column("snapshot_name", ScalarType::String.nullable(true))
+            .with_column("output_plugin", ScalarType::String.nullable(false))
+            .finish();
+        let formats = vec![Format::Text; desc.arity()];
+        self.send(BackendMessage::RowDescription(
+            message::encode_row_description(&desc, &formats),
+        ))
+        .await?;
+        self.conn.set_encode_state(
+            desc.typ()
+                .column_types
+                .iter()
+                .map(|ty| mz_pgrepr::Type::from(&ty.scalar_type))
+                .zip(formats)
+                .collect(),
+        );
+        self.send(BackendMessage::DataRow(vec![
+            Some(mz_pgrepr::Value::Text(slot)),
+            Some(mz_pgrepr::Value::Text(Lsn(0).to_string())),
+            None,
+            Some(mz_pgrepr::Value::Text("pgoutput".into())),
+        ]))
+        .await?;
+        self.send(BackendMessage::CommandComplete {
+            tag: "CREATE_REPLICATION_SLOT".into(),
+        })
+        .await?;
+        Ok(State::Ready)
+    }
+
+    /// Streams the changes to the relation named by the requested publication
+    /// as pgoutput messages, until the client ends the copy.
+    ///
+    /// Streaming resumes after the greater of `start_lsn` and the position
+    /// that the client of `slot` has confirmed flushing. As in Postgres, the
+    /// confirmed position of a slot is only made durable periodically, so a
+    /// client that resumes after a restart may receive changes again that it
+    /// had already confirmed.
+    async fn start_replication(
+        &mut self,
+        slot: Option<String>,
+        start_lsn: Lsn,
+        options: Vec<(String, Option<String>)>,
+    ) -> Result<State, io::Error> {
+        let options = match PgOutputOptions::from_options(options) {
+            Ok(options) => options,
+            Err(msg) => {
+                return self
+                    .error(ErrorResponse::error(SqlState::FEATURE_NOT_SUPPORTED, msg))
+                    .await;
+            }
+        };
+
+        // The publication names the relation to stream, optionally qualified
+        // by its schema and database.
+        let name = options.publication;
+        let catalog = self.adapter_client.catalog_snapshot().await;
+        let resolved = {
+            let conn_catalog = catalog.for_session(self.adapter_client.session());
+            mz_sql::normalize::unresolved_item_name(name.clone())
+                .map_err(|e| e.to_string())
+                .and_then(|partial| {
+                    conn_catalog
+                        .resolve_item(&partial)
+                        .map_err(|e| e.to_string())
+                })
+                .map(|item| {
+                    let full_name = conn_catalog.resolve_full_name(item.name());
+                    let global_id = item.at_version(RelationVersionSelector::Latest).global_id();
+                    (global_id, item.oid(), full_name.schema, full_name.item)
+                })
+        };
+        let (global_id, oid, namespace, relation_name) = match resolved {
+            Ok(resolved) => resolved,
+            Err(msg) => {
+                return self
+                    .error(ErrorResponse::error(SqlState::UNDEFINED_OBJECT, msg))
+                    .await;
+            }
+        };
+
+        let Some(slot) = slot else {
+            return self
+                .stream_relation(None, start_lsn, name, oid, namespace, relation_name)
+                .await;
+        };
+        let start_lsn = match self
+            .adapter_client
+            .acquire_replication_slot(slot.clone(), global_id)
+            .await
+        {
+            Ok(confirmed) => start_lsn.max(Lsn(confirmed.into())),
+            Err(e) => return self.error(e.into_response(Severity::Error)).await,
+        };
+        let result = self
+            .stream_relation(
+                Some(slot.clone()),
+                start_lsn,
+                name,
+                oid,
+                namespace,
+                relation_name,
+            )
+            .await;
+        self.adapter_client.release_replication_slot(slot);
+        result
+    }
+
+    /// Streams the changes to the relation `name` after `start_lsn`, via a
+    /// `SUBSCRIBE` to it.
+    async fn stream_relation(
+        &mut self,
+        slot: Option<String>,
+        start_lsn: Lsn,
+        name: UnresolvedItemName,
+        oid: u32,
+        namespace: String,
+        relation_name: String,
+    ) -> Result<State, io::Error> {
+        const EMPTY_PORTAL: &str = "";
+
+        // Resuming from an LSN skips the snapshot and streams only the changes
+        // after it.
+        let sql = format!(
+            "SUBSCRIBE {} WITH (PROGRESS, SNAPSHOT = {}){}",
+            name.to_ast_string_stable(),
+            start_lsn == Lsn(0),
+            if start_lsn == Lsn(0) {
+                String::new()
+            } else {
+                format!(" AS OF {}", start_lsn.0)
+            },
+        );
+        let stmt = match self.parse_sql(&sql) {
+            Ok(mut stmts) if stmts.len() == 1 => stmts.remove(0).ast,
+            Ok(_) => unreachable!("generated a single statement"),
+            Err(err) => return self.error(err).await,
+        };
+        if let Err(e) = self
+            .adapter_client
+            .declare(EMPTY_PORTAL.to_string(), stmt, sql)
+            .await
+        {
+            return self.error(e.into_response(Severity::Error)).await;
+        }
+        let row_desc = self
+            .adapter_client
+            .session()
+            .get_portal_unverified(EMPTY_PORTAL)
+            .expect("unnamed portal should be present")
+            .desc
+            .relation_desc
+            .clone()
+            .expect("SUBSCRIBE has a relation description");
+
+        let result = match self
+            .adapter_client
+            .execute(EMPTY_PORTAL.to_string(), self.conn.wait_closed(), None)
+            .await
+        {
+            Ok((ExecuteResponse::Subscribing { rx, ctx_extra, .. }, _)) => {
+                // Skip the mz_timestamp, mz_progressed, and mz_diff columns.
+                let (columns, column_types) = row_desc
+                    .iter()
+                    .skip(3)
+                    .map(|(name, typ)| {
+                        (
+                            (name.to_string(), mz_pgrepr::Type::from(&typ.scalar_type)),
+                            typ.scalar_type.clone(),
+                        )
+                    })
+                    .unzip();
+                let relation = ReplicatedRelation {
+                    oid,
+                    namespace,
+                    name: relation_name,
+                    columns,
+                };
+                let stream = ReplicationStream::new(relation, column_types, start_lsn);
+                let (result, reason) = match self.stream_replication(slot, stream, rx).await {
+                    Ok((state, reason)) => (Ok(state), reason),
+                    // An error communicating with the connection is considered
+                    // a cancelation.
+                    Err(e) => (Err(e), StatementEndedExecutionReason::Canceled),
+                };
+                self.adapter_client.retire_execute(ctx_extra, reason);
+                result
+            }
+            Ok((response, _)) => unreachable!("unexpected SUBSCRIBE response: {response:?}"),
+            Err(e) => {
+                self.send_pending_notices().await?;
+                self.error(e.into_response(Severity::Error)).await
+            }
+        };
+
+        self.adapter_client.session().remove_portal(EMPTY_PORTAL);
+
+        result
+    }
+
+    /// Sends the changes produced by a replication `SUBSCRIBE` over a
+    /// CopyBoth stream, until the client ends it.
+    ///
+    /// The LSNs that the client reports flushing advance the confirmed
+    /// position of `slot`. Once [`replication::MAX_UNCONFIRMED_TRANSACTIONS`]
+    /// transactions are unconfirmed, no more are sent until the client
+    /// confirms some of them.
+    async fn stream_replication(
+        &mut self,
+        slot: Option<String>,
+        mut stream: ReplicationStream,
+        mut rx: tokio::sync::mpsc::UnboundedReceiver<PeekResponseUnary>,
+    ) -> Result<(State, StatementEndedExecutionReason), io::Error> {
+        self.send(BackendMessage::CopyBothResponse).await?;
+        self.conn.flush().await?;
+
+        let mut keepalive = time::interval(replication::KEEPALIVE_INTERVAL);
+        let mut rows_returned = 0;
+        let (err, reason) = loop {
+            let event = select! {
+                message = self.conn.recv() => ReplicationEvent::Message(message?),
+                batch = rx.recv(), if !stream.awaiting_confirmation() => {
+                    ReplicationEvent::Batch(batch)
+                }
+                _ = keepalive.tick() => ReplicationEvent::Keepalive,
+            };
+            let send_keepalive = match event {
+                ReplicationEvent::Message(Some(FrontendMessage::CopyData(data))) => {
+                    match replication::decode_standby_message(&data) {
+                        Ok(StandbyMessage::StatusUpdate {
+                            flushed,
+                            reply_requested,
+                            ..
+                        }) => {
+                            let confirmed = stream.confirm(flushed);
+                            if let Some(slot) = &slot {
+                                self.adapter_client.confirm_replication_slot(
+                                    slot.clone(),
+                                    Timestamp::from(confirmed.0),
+                                );
+                            }
+                            debug!(
+                                "cid={} replication client flushed through {confirmed}",
+                                self.adapter_client.session().conn_id(),
+                            );
+                            reply_requested
+                        }
+                        Ok(StandbyMessage::HotStandbyFeedback) => false,
+                        Err(msg) => break protocol_violation(msg),
+                    }
+                }
+                ReplicationEvent::Message(Some(FrontendMessage::CopyDone)) => {
+                    self.send(BackendMessage::CopyDone).await?;
+                    self.send(BackendMessage::CommandComplete {
+                        tag: "START_REPLICATION".into(),
+                    })
+                    .await?;
+                    let reason = StatementEndedExecutionReason::Success {
+                        result_size: None,
+                        rows_returned: Some(rows_returned),
+                        execution_strategy: None,
+                    };
+                    return Ok((State::Ready, reason));
+                }
+                ReplicationEvent::Message(Some(FrontendMessage::CopyFail(msg))) => {
+                    let err = ErrorResponse::error(
+                        SqlState::QUERY_CANCELED,
+                        format!("replication stream failed: {msg}"),
+                    );
+                    break (err, StatementEndedExecutionReason::Canceled);
+                }
+                ReplicationEvent::Message(Some(FrontendMessage::Terminate) | None) => {
+                    return Ok((State::Done, StatementEndedExecutionReason::Canceled));
+                }
+                ReplicationEvent::Message(Some(message)) => {
+                    break protocol_violation(format!(
+                        "unexpected message type during replication: {}",
+                        message.name()
+                    ));
+                }
+                ReplicationEvent::Batch(Some(PeekResponseUnary::Rows(mut rows))) => {
+                    let messages = match stream.push_rows(&mut rows, SYSTEM_TIME()) {
+                        Ok(messages) => messages,
+                        Err(msg) => break internal_error(msg),
+                    };
+                    rows_returned += u64::cast_from(messages.len());
+                    self.send_all(messages.into_iter().map(BackendMessage::CopyData))
+                        .await?;
+                    self.conn.flush().await?;
+                    // Ask a client that has fallen behind to report its
+                    // progress, so that streaming can resume.
+                    stream.awaiting_confirmation()
+                }
+                ReplicationEvent::Batch(Some(PeekResponseUnary::Error(msg))) => {
+                    break internal_error(msg);
+                }
+                ReplicationEvent::Batch(Some(PeekResponseUnary::Canceled) | None) => {
+                    let err = ErrorResponse::error(
+                        SqlState::QUERY_CANCELED,
+                        "canceling statement due to user request",
+                    );
+                    break (err, StatementEndedExecutionReason::Canceled);
+                }
+                ReplicationEvent::Keepalive => true,
+            };
+            if send_keepalive {
+                let keepalive = replication::primary_keepalive(
+                    stream.wal_end(),
+                    replication::pg_timestamp(SYSTEM_TIME()),
+                    stream.awaiting_confirmation(),
+                );
+                self.send(BackendMessage::CopyData(keepalive)).await?;
+                self.conn.flush().await?;
+            }
+        };
+        Ok((self.error(err).await?, reason))
+    }
+
     #[instrument(level = "debug")]
     async fn parse(
         &mut self,
@@ -1461,6 +2217,7 @@ where
 
     #[instrument(level = "debug")]
     async fn ready(&mut self) -> Result<State, io::Error> {
+        self.send_pending_notifications().await?;
         let txn_state = self.adapter_client.session().transaction().into();
         self.send(BackendMessage::ReadyForQuery(txn_state)).await?;
         self.flush().await
@@ -1820,6 +2577,7 @@ where
             | ExecuteResponse::CreatedClusterReplica { .. }
             | ExecuteResponse::CreatedConnection { .. }
             | ExecuteResponse::CreatedDatabase { .. }
+            | ExecuteResponse::CreatedEndpoint
             | ExecuteResponse::CreatedIndex { .. }
             | ExecuteResponse::CreatedIntrospectionSubscribe
             | ExecuteResponse::CreatedMaterializedView { .. }
@@ -1839,11 +2597,16 @@ where
             | ExecuteResponse::Deleted(..)
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DiscardedTemp
+            | ExecuteResponse::DiscardedPlans
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedSubscription
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
+            | ExecuteResponse::Listened
+            | ExecuteResponse::Notified
+            | ExecuteResponse::Unlistened
             | ExecuteResponse::Copied(..)
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
@@ -2330,6 +3093,22 @@ where
         Ok(())
     }
 
+    /// Sends the asynchronous notifications raised on channels the session listens on. Like
+    /// PostgreSQL, notifications are held back while the session is in a transaction.
+    #[instrument(level = "debug")]
+    async fn send_pending_notifications(&mut self) -> Result<(), io::Error> {
+        let session = self.adapter_client.session();
+        if !matches!(session.transaction(), TransactionStatus::Default) {
+            return Ok(());
+        }
+        let notifications = session
+            .drain_notifications()
+            .into_iter()
+            .map(BackendMessage::from);
+        self.send_all(notifications).await?;
+        Ok(())
+    }
+
     #[instrument(level = "debug")]
     async fn error(&mut self, err: ErrorResponse) -> Result<State, io::Error> {
         assert!(err.severity.is_error());
@@ -0,0 +1,1020 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The logical replication protocol.
+//!
+//! Connections that start up with `replication=database` may issue
+//! replication commands alongside SQL. `START_REPLICATION ... LOGICAL` streams
+//! the changes to a relation over a CopyBoth stream as [pgoutput] messages,
+//! driven by a `SUBSCRIBE` to the relation. Materialize has no publications:
+//! the publication name instead names the table, view, materialized view, or
+//! source to stream.
+//!
+//! Materialize timestamps serve as log sequence numbers (LSNs). All of the
+//! changes at a timestamp are sent as one transaction whose commit LSN is that
+//! timestamp. Streaming from a non-zero LSN resumes with the changes after it,
+//! for as long as the relation has not been compacted past that LSN.
+//!
+//! Replication slots are created and dropped with `CREATE_REPLICATION_SLOT`
+//! and `DROP_REPLICATION_SLOT`, and are stored in the catalog. The first
+//! `START_REPLICATION` on a slot binds it to the relation it streams. Clients
+//! report the LSN through which they have flushed the changes they were sent
+//! in standby status updates. That LSN advances the confirmed position of the
+//! slot, from which a later `START_REPLICATION` on the same slot resumes, and
+//! bounds the number of transactions that may be sent before the client
+//! catches up.
+//!
+//! [pgoutput]: https://www.postgresql.org/docs/current/protocol-logicalrep-message-formats.html
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bytes::{BufMut, BytesMut};
+use mz_ore::now::EpochMillis;
+use mz_pgrepr::Value;
+use mz_repr::{Datum, RowIterator, ScalarType, Timestamp};
+use mz_sql::ast::UnresolvedItemName;
+
+/// The number of microseconds between the Unix epoch and the Postgres epoch
+/// (2000-01-01).
+const PG_EPOCH_OFFSET_MICROS: i64 = 946_684_800_000_000;
+
+/// How often a keepalive is sent to an idle replication client.
+pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The number of transactions that may be sent to a replication client before
+/// it must confirm that it has flushed them.
+pub const MAX_UNCONFIRMED_TRANSACTIONS: usize = 1024;
+
+/// A log sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Lsn(pub u64);
+
+impl fmt::Display for Lsn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}/{:X}", self.0 >> 32, self.0 & 0xFFFF_FFFF)
+    }
+}
+
+impl FromStr for Lsn {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hi, lo) = s.split_once('/').ok_or(())?;
+        let hi = u32::from_str_radix(hi, 16).map_err(|_| ())?;
+        let lo = u32::from_str_radix(lo, 16).map_err(|_| ())?;
+        Ok(Lsn((u64::from(hi) << 32) | u64::from(lo)))
+    }
+}
+
+/// A replication command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplicationCommand {
+    /// `IDENTIFY_SYSTEM`.
+    IdentifySystem,
+    /// `CREATE_REPLICATION_SLOT slot_name LOGICAL pgoutput [NOEXPORT_SNAPSHOT]`.
+    CreateReplicationSlot { slot: String },
+    /// `DROP_REPLICATION_SLOT slot_name`.
+    DropReplicationSlot { slot: String },
+    /// `START_REPLICATION [SLOT slot_name] LOGICAL lsn [(option ['value'], ...)]`.
+    StartReplication {
+        slot: Option<String>,
+        start_lsn: Lsn,
+        options: Vec<(String, Option<String>)>,
+    },
+}
+
+/// An error parsing a replication command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplicationCommandError {
+    /// The command is malformed.
+    Syntax(String),
+    /// The command is well-formed but not supported.
+    Unsupported(String),
+}
+
+/// The replication commands that Postgres supports.
+const REPLICATION_COMMANDS: &[&str] = &[
+    "IDENTIFY_SYSTEM",
+    "START_REPLICATION",
+    "CREATE_REPLICATION_SLOT",
+    "DROP_REPLICATION_SLOT",
+    "ALTER_REPLICATION_SLOT",
+    "READ_REPLICATION_SLOT",
+    "TIMELINE_HISTORY",
+    "UPLOAD_MANIFEST",
+    "BASE_BACKUP",
+];
+
+/// Parses `sql` as a replication command.
+///
+/// Returns `None` if `sql` is not a replication command, in which case it
+/// should be handled as SQL.
+pub fn parse_command(sql: &str) -> Option<Result<ReplicationCommand, ReplicationCommandError>> {
+    let mut tokens = Tokens::new(sql.trim().trim_end_matches(';'));
+    let command = tokens.peek_keyword()?;
+    if !REPLICATION_COMMANDS.contains(&command.as_str()) {
+        return None;
+    }
+    tokens.next();
+    let result = match command.as_str() {
+        "IDENTIFY_SYSTEM" => Ok(ReplicationCommand::IdentifySystem),
+        "START_REPLICATION" => parse_start_replication(&mut tokens),
+        "CREATE_REPLICATION_SLOT" => parse_create_replication_slot(&mut tokens),
+        "DROP_REPLICATION_SLOT" => parse_drop_replication_slot(&mut tokens),
+        _ => Err(ReplicationCommandError::Unsupported(format!(
+            "{command} is not supported"
+        ))),
+    };
+    Some(result.and_then(|command| match tokens.next() {
+        None => Ok(command),
+        Some(token) => Err(ReplicationCommandError::Syntax(format!(
+            "unexpected {token:?} at end of replication command"
+        ))),
+    }))
+}
+
+fn parse_create_replication_slot(
+    tokens: &mut Tokens,
+) -> Result<ReplicationCommand, ReplicationCommandError> {
+    let syntax = |msg: &str| ReplicationCommandError::Syntax(msg.to_string());
+    let unsupported = |msg: &str| ReplicationCommandError::Unsupported(msg.to_string());
+    let slot = tokens
+        .next_ident()
+        .ok_or_else(|| syntax("expected slot name"))?;
+    match tokens.peek_keyword().as_deref() {
+        Some("TEMPORARY") => {
+            return Err(unsupported("temporary replication slots are not supported"));
+        }
+        Some("LOGICAL") => {
+            tokens.next();
+        }
+        _ => return Err(unsupported("physical replication is not supported")),
+    }
+    let plugin = tokens
+        .next_ident()
+        .ok_or_else(|| syntax("expected output plugin name"))?;
+    if plugin != "pgoutput" {
+        return Err(ReplicationCommandError::Unsupported(format!(
+            "output plugin \"{plugin}\" is not supported"
+        )));
+    }
+    // Snapshots cannot be exported, so the only supported snapshot action is
+    // to create none, with either the legacy or the parenthesized syntax. The
+    // changes a slot streams from LSN 0 begin with a snapshot of the relation
+    // instead.
+    match tokens.peek() {
+        None => {}
+        Some(Token::Word(word)) if word.eq_ignore_ascii_case("NOEXPORT_SNAPSHOT") => {
+            tokens.next();
+        }
+        Some(Token::LParen) => {
+            tokens.next();
+            let option = tokens
+                .next_ident()
+                .ok_or_else(|| syntax("expected option name"))?;
+            let value = match tokens.next() {
+                Some(Token::String(value)) => value,
+                _ => return Err(syntax("expected option value")),
+            };
+            if tokens.next() != Some(Token::RParen) {
+                return Err(syntax("expected )"));
+            }
+            if option != "snapshot" || value != "nothing" {
+                return Err(ReplicationCommandError::Unsupported(format!(
+                    "option {option} '{value}' is not supported"
+                )));
+            }
+        }
+        Some(Token::Word(word)) => {
+            return Err(ReplicationCommandError::Unsupported(format!(
+                "{} is not supported",
+                word.to_uppercase()
+            )));
+        }
+        Some(_) => return Err(syntax("expected snapshot action")),
+    }
+    Ok(ReplicationCommand::CreateReplicationSlot { slot })
+}
+
+fn parse_drop_replication_slot(
+    tokens: &mut Tokens,
+) -> Result<ReplicationCommand, ReplicationCommandError> {
+    let slot = tokens
+        .next_ident()
+        .ok_or_else(|| ReplicationCommandError::Syntax("expected slot name".into()))?;
+    if tokens.peek_keyword().as_deref() == Some("WAIT") {
+        return Err(ReplicationCommandError::Unsupported(
+            "DROP_REPLICATION_SLOT ... WAIT is not supported".into(),
+        ));
+    }
+    Ok(ReplicationCommand::DropReplicationSlot { slot })
+}
+
+fn parse_start_replication(
+    tokens: &mut Tokens,
+) -> Result<ReplicationCommand, ReplicationCommandError> {
+    let syntax = |msg: &str| ReplicationCommandError::Syntax(msg.to_string());
+    let mut slot = None;
+    if tokens.peek_keyword().as_deref() == Some("SLOT") {
+        tokens.next();
+        slot = Some(
+            tokens
+                .next_ident()
+                .ok_or_else(|| syntax("expected slot name"))?,
+        );
+    }
+    if tokens.peek_keyword().as_deref() != Some("LOGICAL") {
+        return Err(ReplicationCommandError::Unsupported(
+            "physical replication is not supported".into(),
+        ));
+    }
+    tokens.next();
+    let start_lsn = tokens
+        .next()
+        .and_then(|token| match token {
+            Token::Word(word) => word.parse().ok(),
+            _ => None,
+        })
+        .ok_or_else(|| syntax("expected start LSN"))?;
+    let mut options = Vec::new();
+    if tokens.peek() == Some(&Token::LParen) {
+        tokens.next();
+        loop {
+            let name = tokens
+                .next_ident()
+                .ok_or_else(|| syntax("expected option name"))?;
+            let value = match tokens.peek() {
+                Some(Token::String(value)) => {
+                    let value = value.clone();
+                    tokens.next();
+                    Some(value)
+                }
+                _ => None,
+            };
+            options.push((name, value));
+            match tokens.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::RParen) => break,
+                _ => return Err(syntax("expected , or ) in option list")),
+            }
+        }
+    }
+    Ok(ReplicationCommand::StartReplication {
+        slot,
+        start_lsn,
+        options,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// A bare word, like a keyword, identifier, or LSN.
+    Word(String),
+    /// A double-quoted identifier.
+    QuotedIdent(String),
+    /// A single-quoted string.
+    String(String),
+    LParen,
+    RParen,
+    Comma,
+    /// A token that is not valid in a replication command.
+    Invalid(char),
+}
+
+struct Tokens {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Tokens {
+    fn new(s: &str) -> Tokens {
+        let mut tokens = Vec::new();
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                c if c.is_whitespace() => continue,
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                ',' => Token::Comma,
+                '"' | '\'' => {
+                    // Quotes are escaped by doubling them.
+                    let mut quoted = String::new();
+                    let terminated = loop {
+                        match chars.next() {
+                            Some(q) if q == c && chars.peek() == Some(&c) => {
+                                chars.next();
+                                quoted.push(c);
+                            }
+                            Some(q) if q == c => break true,
+                            Some(q) => quoted.push(q),
+                            None => break false,
+                        }
+                    };
+                    match (terminated, c) {
+                        (false, _) => Token::Invalid(c),
+                        (true, '"') => Token::QuotedIdent(quoted),
+                        (true, _) => Token::String(quoted),
+                    }
+                }
+                c if c.is_alphanumeric() || c == '_' => {
+                    let mut word = String::from(c);
+                    while let Some(&c) = chars.peek() {
+                        if !(c.is_alphanumeric() || c == '_' || c == '/') {
+                            break;
+                        }
+                        word.push(c);
+                        chars.next();
+                    }
+                    Token::Word(word)
+                }
+                c => Token::Invalid(c),
+            };
+            tokens.push(token);
+        }
+        Tokens { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// Returns the next token, uppercased, if it is a bare word.
+    fn peek_keyword(&self) -> Option<String> {
+        match self.peek() {
+            Some(Token::Word(word)) => Some(word.to_uppercase()),
+            _ => None,
+        }
+    }
+
+    /// Consumes the next token if it is an identifier. Bare identifiers are
+    /// folded to lowercase.
+    fn next_ident(&mut self) -> Option<String> {
+        let ident = match self.peek()? {
+            Token::Word(word) => word.to_lowercase(),
+            Token::QuotedIdent(ident) => ident.clone(),
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(ident)
+    }
+}
+
+/// The options of a pgoutput `START_REPLICATION` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgOutputOptions {
+    /// The publication to stream, which names a relation.
+    pub publication: UnresolvedItemName,
+}
+
+impl PgOutputOptions {
+    /// Validates the options of a `START_REPLICATION` command.
+    pub fn from_options(options: Vec<(String, Option<String>)>) -> Result<Self, String> {
+        let mut proto_version = None;
+        let mut publications = None;
+        for (name, value) in options {
+            let value = value.unwrap_or_default();
+            match name.as_str() {
+                "proto_version" => proto_version = Some(value),
+                // Publication names are identifiers, so they may be quoted
+                // to contain commas, dots, or uppercase letters.
+                "publication_names" if value.trim().is_empty() => publications = Some(vec![]),
+                "publication_names" => {
+                    publications = Some(
+                        mz_sql::parse::parse_item_names(&value)
+                            .map_err(|e| format!("invalid publication_names: {}", e.message))?,
+                    )
+                }
+                // We only ever send text.
+                "binary" if !is_true(&value) => {}
+                // We never send in-progress transactions or logical decoding
+                // messages, so these may be safely ignored.
+                "streaming" | "messages" | "origin" => {}
+                _ => return Err(format!("option \"{name}\" is not supported")),
+            }
+        }
+        match proto_version.as_deref() {
+            Some("1") | Some("2") => {}
+            Some(version) => {
+                return Err(format!("proto_version \"{version}\" is not supported"));
+            }
+            None => return Err("proto_version option missing".into()),
+        }
+        match publications.as_deref() {
+            Some([publication]) => Ok(PgOutputOptions {
+                publication: publication.clone(),
+            }),
+            Some([]) | None => Err("publication_names option missing".into()),
+            Some(_) => Err("streaming more than one publication is not supported".into()),
+        }
+    }
+}
+
+fn is_true(value: &str) -> bool {
+    matches!(
+        value.to_lowercase().as_str(),
+        "" | "true" | "on" | "yes" | "1"
+    )
+}
+
+/// Converts a time in milliseconds since the Unix epoch to the number of
+/// microseconds since the Postgres epoch.
+pub fn pg_timestamp(millis: EpochMillis) -> i64 {
+    let micros = i64::try_from(millis.saturating_mul(1000)).unwrap_or(i64::MAX);
+    micros.saturating_sub(PG_EPOCH_OFFSET_MICROS)
+}
+
+/// A relation whose changes are streamed, as described in a pgoutput Relation
+/// message.
+#[derive(Debug, Clone)]
+pub struct ReplicatedRelation {
+    pub oid: u32,
+    pub namespace: String,
+    pub name: String,
+    pub columns: Vec<(String, mz_pgrepr::Type)>,
+}
+
+/// A pgoutput message.
+#[derive(Debug)]
+pub enum PgOutputMessage<'a> {
+    Begin {
+        final_lsn: Lsn,
+        commit_time: i64,
+        xid: u32,
+    },
+    Relation(&'a ReplicatedRelation),
+    Insert {
+        relation: u32,
+        tuple: &'a [Option<Value>],
+    },
+    Delete {
+        relation: u32,
+        tuple: &'a [Option<Value>],
+    },
+    Commit {
+        lsn: Lsn,
+        commit_time: i64,
+    },
+}
+
+impl PgOutputMessage<'_> {
+    /// Encodes this message.
+    pub fn encode(&self, buf: &mut BytesMut) {
+        match self {
+            PgOutputMessage::Begin {
+                final_lsn,
+                commit_time,
+                xid,
+            } => {
+                buf.put_u8(b'B');
+                buf.put_u64(final_lsn.0);
+                buf.put_i64(*commit_time);
+                buf.put_u32(*xid);
+            }
+            PgOutputMessage::Relation(relation) => {
+                buf.put_u8(b'R');
+                buf.put_u32(relation.oid);
+                put_string(buf, &relation.namespace);
+                put_string(buf, &relation.name);
+                // Materialize relations have no keys, so replicate them with
+                // REPLICA IDENTITY FULL.
+                buf.put_u8(b'f');
+                buf.put_i16(i16::try_from(relation.columns.len()).unwrap_or(i16::MAX));
+                for (name, typ) in &relation.columns {
+                    // Every column is part of the replica identity.
+                    buf.put_u8(1);
+                    put_string(buf, name);
+                    buf.put_u32(typ.oid());
+                    buf.put_i32(typ.typmod());
+                }
+            }
+            PgOutputMessage::Insert { relation, tuple } => {
+                buf.put_u8(b'I');
+                buf.put_u32(*relation);
+                buf.put_u8(b'N');
+                put_tuple(buf, tuple);
+            }
+            PgOutputMessage::Delete { relation, tuple } => {
+                buf.put_u8(b'D');
+                buf.put_u32(*relation);
+                buf.put_u8(b'O');
+                put_tuple(buf, tuple);
+            }
+            PgOutputMessage::Commit { lsn, commit_time } => {
+                buf.put_u8(b'C');
+                // No flags are defined.
+                buf.put_u8(0);
+                buf.put_u64(lsn.0);
+                buf.put_u64(lsn.0);
+                buf.put_i64(*commit_time);
+            }
+        }
+    }
+}
+
+fn put_string(buf: &mut BytesMut, s: &str) {
+    buf.put_slice(s.as_bytes());
+    buf.put_u8(0);
+}
+
+fn put_tuple(buf: &mut BytesMut, tuple: &[Option<Value>]) {
+    buf.put_i16(i16::try_from(tuple.len()).unwrap_or(i16::MAX));
+    for value in tuple {
+        match value {
+            None => buf.put_u8(b'n'),
+            Some(value) => {
+                buf.put_u8(b't');
+                let base = buf.len();
+                buf.put_u32(0);
+                value.encode_text(buf);
+                let len = u32::try_from(buf.len() - base - 4).unwrap_or(u32::MAX);
+                buf[base..base + 4].copy_from_slice(&len.to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Wraps a pgoutput message in an XLogData message.
+pub fn xlog_data(lsn: Lsn, send_time: i64, message: &PgOutputMessage) -> Vec<u8> {
+    let mut buf = BytesMut::new();
+    buf.put_u8(b'w');
+    buf.put_u64(lsn.0);
+    buf.put_u64(lsn.0);
+    buf.put_i64(send_time);
+    message.encode(&mut buf);
+    buf.to_vec()
+}
+
+/// Encodes a primary keepalive message.
+pub fn primary_keepalive(wal_end: Lsn, send_time: i64, reply_requested: bool) -> Vec<u8> {
+    let mut buf = BytesMut::new();
+    buf.put_u8(b'k');
+    buf.put_u64(wal_end.0);
+    buf.put_i64(send_time);
+    buf.put_u8(u8::from(reply_requested));
+    buf.to_vec()
+}
+
+/// A message sent by the client during streaming replication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StandbyMessage {
+    /// A standby status update, reporting the client's progress.
+    StatusUpdate {
+        written: Lsn,
+        flushed: Lsn,
+        applied: Lsn,
+        reply_requested: bool,
+    },
+    /// Hot standby feedback, which only applies to physical replication.
+    HotStandbyFeedback,
+}
+
+/// Decodes a message sent by the client in a CopyData message.
+pub fn decode_standby_message(data: &[u8]) -> Result<StandbyMessage, String> {
+    let read_u64 = |offset: usize| -> Result<u64, String> {
+        data.get(offset..offset + 8)
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().expect("8 bytes")))
+            .ok_or_else(|| "standby status update too short".to_string())
+    };
+    match data.first() {
+        Some(b'r') => Ok(StandbyMessage::StatusUpdate {
+            written: Lsn(read_u64(1)?),
+            flushed: Lsn(read_u64(9)?),
+            applied: Lsn(read_u64(17)?),
+            // The client's clock, at offset 25, is unused.
+            reply_requested: data.get(33).is_some_and(|b| *b == 1),
+        }),
+        Some(b'h') => Ok(StandbyMessage::HotStandbyFeedback),
+        Some(b) => Err(format!(
+            "unexpected standby message type {:?}",
+            char::from(*b)
+        )),
+        None => Err("empty standby message".into()),
+    }
+}
+
+/// Translates the rows of a `SUBSCRIBE ... WITH (PROGRESS)` to a relation into
+/// the XLogData messages of a replication stream.
+#[derive(Debug)]
+pub struct ReplicationStream {
+    relation: ReplicatedRelation,
+    column_types: Vec<ScalarType>,
+    /// The timestamp of the transaction being sent, if any.
+    open: Option<Timestamp>,
+    sent_relation: bool,
+    next_xid: u32,
+    /// The LSN through which all changes have been sent.
+    wal_end: Lsn,
+    /// The LSN through which the client has confirmed flushing.
+    confirmed_flush: Lsn,
+    /// The commit LSNs of the sent transactions that the client has not yet
+    /// confirmed flushing, in order.
+    unconfirmed: VecDeque<Lsn>,
+}
+
+impl ReplicationStream {
+    /// Creates a stream of the changes to `relation` after `start_lsn`.
+    pub fn new(
+        relation: ReplicatedRelation,
+        column_types: Vec<ScalarType>,
+        start_lsn: Lsn,
+    ) -> Self {
+        ReplicationStream {
+            relation,
+            column_types,
+            open: None,
+            sent_relation: false,
+            next_xid: 1,
+            wal_end: start_lsn,
+            confirmed_flush: start_lsn,
+            unconfirmed: VecDeque::new(),
+        }
+    }
+
+    /// The LSN through which all changes have been sent.
+    pub fn wal_end(&self) -> Lsn {
+        self.wal_end
+    }
+
+    /// Records that the client has flushed the changes through `flushed`,
+    /// returning the LSN through which the client has confirmed flushing.
+    ///
+    /// The confirmed LSN never moves backward, nor past the changes that have
+    /// been sent.
+    pub fn confirm(&mut self, flushed: Lsn) -> Lsn {
+        self.confirmed_flush = self.confirmed_flush.max(flushed.min(self.wal_end));
+        while self
+            .unconfirmed
+            .front()
+            .is_some_and(|lsn| *lsn <= self.confirmed_flush)
+        {
+            self.unconfirmed.pop_front();
+        }
+        self.confirmed_flush
+    }
+
+    /// Reports whether no more changes may be sent until the client confirms
+    /// flushing some of the ones it was sent.
+    pub fn awaiting_confirmation(&self) -> bool {
+        self.unconfirmed.len() >= MAX_UNCONFIRMED_TRANSACTIONS
+    }
+
+    /// Translates a batch of `SUBSCRIBE` rows, returning the XLogData messages
+    /// to send.
+    ///
+    /// A transaction is committed only once a progress row shows that no more
+    /// changes can arrive at its timestamp.
+    pub fn push_rows(
+        &mut self,
+        rows: &mut dyn RowIterator,
+        now: EpochMillis,
+    ) -> Result<Vec<Vec<u8>>, String> {
+        let send_time = pg_timestamp(now);
+        let mut out = Vec::new();
+        while let Some(row) = rows.next() {
+            let mut datums = row.iter();
+            let ts = match datums.next() {
+                Some(Datum::Numeric(ts)) => Timestamp::try_from(ts.0)
+                    .map_err(|_| format!("invalid mz_timestamp: {}", ts.0))?,
+                _ => return Err("SUBSCRIBE row missing mz_timestamp".into()),
+            };
+            let progressed = datums.next() == Some(Datum::True);
+            let diff = datums.next();
+            if progressed {
+                if self.open.is_some_and(|open| open < ts) {
+                    self.commit(send_time, &mut out);
+                }
+                self.wal_end = self.wal_end.max(Lsn(u64::from(ts).saturating_sub(1)));
+                continue;
+            }
+            let diff = match diff {
+                Some(Datum::Int64(diff)) => diff,
+                _ => return Err("SUBSCRIBE row missing mz_diff".into()),
+            };
+            if self.open != Some(ts) {
+                if self.open.is_some() {
+                    self.commit(send_time, &mut out);
+                }
+                self.begin(ts, send_time, &mut out);
+            }
+            let tuple: Vec<_> = datums
+                .zip(&self.column_types)
+                .map(|(datum, typ)| Value::from_datum(datum, typ))
+                .collect();
+            let message = if diff > 0 {
+                PgOutputMessage::Insert {
+                    relation: self.relation.oid,
+                    tuple: &tuple,
+                }
+            } else {
+                PgOutputMessage::Delete {
+                    relation: self.relation.oid,
+                    tuple: &tuple,
+                }
+            };
+            // pgoutput has no notion of multiplicity, so a row with a diff of
+            // n is sent as n separate changes.
+            let data = xlog_data(Lsn(u64::from(ts)), send_time, &message);
+            for _ in 1..diff.unsigned_abs() {
+                out.push(data.clone());
+            }
+            out.push(data);
+        }
+        Ok(out)
+    }
+
+    fn begin(&mut self, ts: Timestamp, send_time: i64, out: &mut Vec<Vec<u8>>) {
+        let lsn = Lsn(u64::from(ts));
+        let xid = self.next_xid;
+        self.next_xid = self.next_xid.wrapping_add(1).max(1);
+        out.push(xlog_data(
+            lsn,
+            send_time,
+            &PgOutputMessage::Begin {
+                final_lsn: lsn,
+                commit_time: pg_timestamp(u64::from(ts)),
+                xid,
+            },
+        ));
+        // Clients must see a relation's description before its first change.
+        if !self.sent_relation {
+            out.push(xlog_data(
+                lsn,
+                send_time,
+                &PgOutputMessage::Relation(&self.relation),
+            ));
+            self.sent_relation = true;
+        }
+        self.open = Some(ts);
+    }
+
+    fn commit(&mut self, send_time: i64, out: &mut Vec<Vec<u8>>) {
+        if let Some(ts) = self.open.take() {
+            let lsn = Lsn(u64::from(ts));
+            out.push(xlog_data(
+                lsn,
+                send_time,
+                &PgOutputMessage::Commit {
+                    lsn,
+                    commit_time: pg_timestamp(u64::from(ts)),
+                },
+            ));
+            self.wal_end = self.wal_end.max(lsn);
+            self.unconfirmed.push_back(lsn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_sql::ast::Ident;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_lsn() {
+        assert_eq!(Lsn(0).to_string(), "0/0");
+        assert_eq!(Lsn(0x16_B374_D848).to_string(), "16/B374D848");
+        assert_eq!("16/B374D848".parse(), Ok(Lsn(0x16_B374_D848)));
+        assert_eq!("0/0".parse(), Ok(Lsn(0)));
+        assert!("16B374D848".parse::<Lsn>().is_err());
+        assert!("1/G".parse::<Lsn>().is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("SELECT 1"), None);
+        assert_eq!(parse_command("show transaction_isolation"), None);
+        assert_eq!(
+            parse_command("IDENTIFY_SYSTEM;"),
+            Some(Ok(ReplicationCommand::IdentifySystem))
+        );
+        assert_eq!(
+            parse_command(
+                r#"START_REPLICATION SLOT "Slot" LOGICAL 0/1A (proto_version '1', publication_names 'mv')"#
+            ),
+            Some(Ok(ReplicationCommand::StartReplication {
+                slot: Some("Slot".into()),
+                start_lsn: Lsn(0x1A),
+                options: vec![
+                    ("proto_version".into(), Some("1".into())),
+                    ("publication_names".into(), Some("mv".into())),
+                ],
+            }))
+        );
+        assert_eq!(
+            parse_command("start_replication logical 0/0 (\"binary\")"),
+            Some(Ok(ReplicationCommand::StartReplication {
+                slot: None,
+                start_lsn: Lsn(0),
+                options: vec![("binary".into(), None)],
+            }))
+        );
+        assert!(matches!(
+            parse_command("START_REPLICATION SLOT s 0/0"),
+            Some(Err(ReplicationCommandError::Unsupported(_)))
+        ));
+        for create in [
+            "CREATE_REPLICATION_SLOT s LOGICAL pgoutput",
+            "create_replication_slot S logical pgoutput noexport_snapshot;",
+            "CREATE_REPLICATION_SLOT s LOGICAL pgoutput (SNAPSHOT 'nothing')",
+        ] {
+            assert_eq!(
+                parse_command(create),
+                Some(Ok(ReplicationCommand::CreateReplicationSlot {
+                    slot: "s".into()
+                })),
+                "{create}"
+            );
+        }
+        assert_eq!(
+            parse_command(r#"DROP_REPLICATION_SLOT "S""#),
+            Some(Ok(ReplicationCommand::DropReplicationSlot {
+                slot: "S".into()
+            }))
+        );
+        for unsupported in [
+            "CREATE_REPLICATION_SLOT s TEMPORARY LOGICAL pgoutput",
+            "CREATE_REPLICATION_SLOT s PHYSICAL",
+            "CREATE_REPLICATION_SLOT s LOGICAL test_decoding",
+            "CREATE_REPLICATION_SLOT s LOGICAL pgoutput EXPORT_SNAPSHOT",
+            "CREATE_REPLICATION_SLOT s LOGICAL pgoutput (SNAPSHOT 'export')",
+            "DROP_REPLICATION_SLOT s WAIT",
+            "READ_REPLICATION_SLOT s",
+        ] {
+            assert!(
+                matches!(
+                    parse_command(unsupported),
+                    Some(Err(ReplicationCommandError::Unsupported(_)))
+                ),
+                "{unsupported}"
+            );
+        }
+        for bad in [
+            "START_REPLICATION LOGICAL",
+            "START_REPLICATION LOGICAL 0/0 (proto_version '1'",
+            "START_REPLICATION LOGICAL 0/0 (proto_version 'unterminated)",
+            "IDENTIFY_SYSTEM extra",
+            "CREATE_REPLICATION_SLOT",
+            "CREATE_REPLICATION_SLOT s LOGICAL",
+            "CREATE_REPLICATION_SLOT s LOGICAL pgoutput (SNAPSHOT 'nothing'",
+            "DROP_REPLICATION_SLOT",
+        ] {
+            assert!(
+                matches!(
+                    parse_command(bad),
+                    Some(Err(ReplicationCommandError::Syntax(_)))
+                ),
+                "{bad}"
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_pgoutput_options() {
+        let options = |options: &[(&str, &str)]| {
+            PgOutputOptions::from_options(
+                options
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), Some(value.to_string())))
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            options(&[("proto_version", "1"), ("publication_names", "mv")]),
+            Ok(PgOutputOptions {
+                publication: UnresolvedItemName::unqualified(Ident::new_unchecked("mv"))
+            })
+        );
+        assert_eq!(
+            options(&[
+                ("proto_version", "1"),
+                ("publication_names", r#"public."My.View""#)
+            ]),
+            Ok(PgOutputOptions {
+                publication: UnresolvedItemName(vec![
+                    Ident::new_unchecked("public"),
+                    Ident::new_unchecked("My.View"),
+                ])
+            })
+        );
+        assert!(options(&[("proto_version", "1"), ("publication_names", "")]).is_err());
+        assert!(options(&[("proto_version", "1"), ("publication_names", "a.")]).is_err());
+        assert!(options(&[("publication_names", "mv")]).is_err());
+        assert!(options(&[("proto_version", "1")]).is_err());
+        assert!(options(&[("proto_version", "1"), ("publication_names", "a,b")]).is_err());
+        assert!(
+            options(&[
+                ("proto_version", "1"),
+                ("publication_names", "mv"),
+                ("binary", "true")
+            ])
+            .is_err()
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_pgoutput_encoding() {
+        let mut buf = BytesMut::new();
+        PgOutputMessage::Insert {
+            relation: 16384,
+            tuple: &[Some(Value::Int4(7)), None],
+        }
+        .encode(&mut buf);
+        assert_eq!(&buf[..], b"I\x00\x00\x40\x00N\x00\x02t\x00\x00\x00\x017n");
+
+        let data = xlog_data(
+            Lsn(5),
+            pg_timestamp(946_684_800_000),
+            &PgOutputMessage::Commit {
+                lsn: Lsn(5),
+                commit_time: 0,
+            },
+        );
+        assert_eq!(data.len(), 25 + 26);
+        assert_eq!(&data[..1], b"w");
+        assert_eq!(&data[17..25], &[0; 8]);
+
+        let mut update = vec![b'r'];
+        for lsn in [3u64, 2, 1, 0] {
+            update.extend(lsn.to_be_bytes());
+        }
+        update.push(1);
+        assert_eq!(
+            decode_standby_message(&update),
+            Ok(StandbyMessage::StatusUpdate {
+                written: Lsn(3),
+                flushed: Lsn(2),
+                applied: Lsn(1),
+                reply_requested: true,
+            })
+        );
+        assert!(decode_standby_message(b"r\x00").is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_replication_stream() {
+        use mz_repr::adt::numeric::Numeric;
+        use mz_repr::{IntoRowIterator, Row};
+
+        let relation = ReplicatedRelation {
+            oid: 20_000,
+            namespace: "public".into(),
+            name: "mv".into(),
+            columns: vec![("a".into(), mz_pgrepr::Type::Int4)],
+        };
+        let mut stream = ReplicationStream::new(relation, vec![ScalarType::Int32], Lsn(0));
+        let data = |ts: u64, diff: i64, a: i32| {
+            Row::pack_slice(&[
+                Datum::from(Numeric::from(ts)),
+                Datum::False,
+                Datum::Int64(diff),
+                Datum::Int32(a),
+            ])
+        };
+        let progress = |ts: u64| {
+            Row::pack_slice(&[
+                Datum::from(Numeric::from(ts)),
+                Datum::True,
+                Datum::Null,
+                Datum::Null,
+            ])
+        };
+        let kinds = |messages: Vec<Vec<u8>>| -> Vec<char> {
+            messages.iter().map(|m| char::from(m[25])).collect()
+        };
+
+        let mut rows = vec![data(5, 2, 1), data(5, -1, 2)].into_row_iter();
+        let messages = stream.push_rows(&mut rows, 0).unwrap();
+        assert_eq!(kinds(messages), ['B', 'R', 'I', 'I', 'D']);
+        // The transaction remains open until progress passes its timestamp.
+        assert_eq!(stream.wal_end(), Lsn(0));
+
+        let mut rows = vec![progress(7), data(8, 1, 3), progress(9)].into_row_iter();
+        let messages = stream.push_rows(&mut rows, 0).unwrap();
+        assert_eq!(kinds(messages), ['C', 'B', 'I', 'C']);
+        assert_eq!(stream.wal_end(), Lsn(8));
+
+        // Confirmations never move backward, nor past what has been sent.
+        assert_eq!(stream.confirm(Lsn(5)), Lsn(5));
+        assert_eq!(stream.unconfirmed, [Lsn(8)]);
+        assert_eq!(stream.confirm(Lsn(3)), Lsn(5));
+        assert_eq!(stream.confirm(Lsn(100)), Lsn(8));
+        assert!(stream.unconfirmed.is_empty());
+        assert!(!stream.awaiting_confirmation());
+    }
+}
@@ -12,7 +12,7 @@
 use std::collections::BTreeSet;
 
 use anyhow::bail;
-use mz_proto::{IntoRustIfSome, RustType, TryFromProtoError};
+use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use proptest::prelude::any;
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
@@ -148,6 +148,9 @@ pub struct PostgresColumnDesc {
     pub type_mod: i32,
     /// True if the column lacks a `NOT NULL` constraint.
     pub nullable: bool,
+    /// The constraints of the column's type, if it is a domain. In that case,
+    /// `type_oid` and `type_mod` describe the domain's base type.
+    pub domain: Option<PostgresDomainDesc>,
 }
 
 impl PostgresColumnDesc {
@@ -168,6 +171,7 @@ impl PostgresColumnDesc {
             && self.col_num == other.col_num
             && (self.type_oid == other.type_oid || allow_type_change)
             && (self.type_mod == other.type_mod || allow_type_change)
+            && (self.domain == other.domain || allow_type_change)
             // Columns are compatible if:
             // - self is nullable; introducing a not null constraint doesn't
             //   change this column's behavior.
@@ -184,6 +188,7 @@ impl RustType<ProtoPostgresColumnDesc> for PostgresColumnDesc {
             type_oid: self.type_oid,
             type_mod: self.type_mod,
             nullable: self.nullable,
+            domain: self.domain.into_proto(),
         }
     }
 
@@ -199,6 +204,65 @@ impl RustType<ProtoPostgresColumnDesc> for PostgresColumnDesc {
             type_oid: proto.type_oid,
             type_mod: proto.type_mod,
             nullable: proto.nullable,
+            domain: proto.domain.into_rust()?,
+        })
+    }
+}
+
+/// Describes the domain type of a [`PostgresColumnDesc`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct PostgresDomainDesc {
+    /// The name of the domain.
+    pub name: String,
+    /// True if the domain, or any domain it is defined over, has a `NOT NULL`
+    /// constraint.
+    pub not_null: bool,
+    /// The `CHECK` constraints of the domain and of any domains it is defined
+    /// over.
+    #[proptest(strategy = "proptest::collection::vec(any::<PostgresDomainCheck>(), 0..4)")]
+    pub checks: Vec<PostgresDomainCheck>,
+}
+
+impl RustType<ProtoPostgresDomainDesc> for PostgresDomainDesc {
+    fn into_proto(&self) -> ProtoPostgresDomainDesc {
+        ProtoPostgresDomainDesc {
+            name: self.name.clone(),
+            not_null: self.not_null,
+            checks: self.checks.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresDomainDesc) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresDomainDesc {
+            name: proto.name,
+            not_null: proto.not_null,
+            checks: proto.checks.into_rust()?,
+        })
+    }
+}
+
+/// Describes a `CHECK` constraint of a [`PostgresDomainDesc`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Arbitrary)]
+pub struct PostgresDomainCheck {
+    /// The name of the constraint.
+    pub name: String,
+    /// The constraint's expression, as rendered by PostgreSQL's `pg_get_expr`,
+    /// in which `VALUE` refers to the value being checked.
+    pub expr: String,
+}
+
+impl RustType<ProtoPostgresDomainCheck> for PostgresDomainCheck {
+    fn into_proto(&self) -> ProtoPostgresDomainCheck {
+        ProtoPostgresDomainCheck {
+            name: self.name.clone(),
+            expr: self.expr.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresDomainCheck) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresDomainCheck {
+            name: proto.name,
+            expr: proto.expr,
         })
     }
 }
@@ -16,7 +16,10 @@ use tokio_postgres::Client;
 use tokio_postgres::types::Oid;
 
 use crate::PostgresError;
-use crate::desc::{PostgresColumnDesc, PostgresKeyDesc, PostgresSchemaDesc, PostgresTableDesc};
+use crate::desc::{
+    PostgresColumnDesc, PostgresDomainCheck, PostgresDomainDesc, PostgresKeyDesc,
+    PostgresSchemaDesc, PostgresTableDesc,
+};
 
 pub async fn get_schemas(client: &Client) -> Result<Vec<PostgresSchemaDesc>, PostgresError> {
     Ok(client
@@ -152,10 +155,38 @@ pub async fn publication_info(
             type_oid,
             type_mod,
             nullable: !not_null,
+            domain: None,
         };
         columns.entry(table_oid).or_default().push(desc);
     }
 
+    // Replace the types of columns of domain types with the domains' base
+    // types, preserving the domains' constraints.
+    let type_oids = columns.values().flatten().map(|c| c.type_oid).collect();
+    let domains = domain_info(client, type_oids).await?;
+    for column in columns.values_mut().flatten() {
+        let Some(mut domain) = domains.get(&column.type_oid) else {
+            continue;
+        };
+        let mut desc = PostgresDomainDesc {
+            name: domain.name.clone(),
+            not_null: false,
+            checks: vec![],
+        };
+        // Domains may be defined over other domains.
+        loop {
+            desc.not_null |= domain.not_null;
+            desc.checks.extend(domain.checks.iter().cloned());
+            column.type_oid = domain.base_oid;
+            column.type_mod = domain.base_type_mod;
+            match domains.get(&domain.base_oid) {
+                Some(base) => domain = base,
+                None => break,
+            }
+        }
+        column.domain = Some(desc);
+    }
+
     // PG 15 adds UNIQUE NULLS NOT DISTINCT, which would let us use `UNIQUE` constraints over
     // nullable columns as keys; i.e. aligns a PG index's NULL handling with an arrangement's
     // keys. For more info, see https://www.postgresql.org/about/featurematrix/detail/392/
@@ -223,3 +254,66 @@ pub async fn publication_info(
         })
         .collect())
 }
+
+/// A domain type in an upstream Postgres database.
+struct DomainInfo {
+    name: String,
+    base_oid: Oid,
+    base_type_mod: i32,
+    not_null: bool,
+    checks: Vec<PostgresDomainCheck>,
+}
+
+/// Fetches information about the domains among `type_oids`, and about any
+/// domains those domains are defined over. Types that are not domains are
+/// omitted from the result.
+async fn domain_info(
+    client: &Client,
+    type_oids: BTreeSet<Oid>,
+) -> Result<BTreeMap<Oid, DomainInfo>, PostgresError> {
+    let pg_domains = "
+        SELECT
+            t.oid,
+            t.typname AS name,
+            t.typbasetype AS base_oid,
+            t.typtypmod AS base_typmod,
+            t.typnotnull AS not_null,
+            c.conname AS check_name,
+            pg_catalog.pg_get_expr(c.conbin, 0) AS check_expr
+        FROM pg_catalog.pg_type t
+        LEFT JOIN pg_catalog.pg_constraint c
+            ON c.contypid = t.oid
+            AND c.contype = 'c'
+        WHERE t.typtype = 'd'
+            AND t.oid = ANY ($1)
+        ORDER BY t.oid, c.conname";
+
+    let mut domains: BTreeMap<Oid, DomainInfo> = BTreeMap::new();
+    let mut fetched = BTreeSet::new();
+    let mut to_fetch = type_oids;
+    while !to_fetch.is_empty() {
+        let oids: Vec<Oid> = to_fetch.iter().copied().collect();
+        fetched.append(&mut to_fetch);
+        for row in client.query(pg_domains, &[&oids]).await? {
+            let oid: Oid = row.get("oid");
+            let domain = domains.entry(oid).or_insert_with(|| DomainInfo {
+                name: row.get("name"),
+                base_oid: row.get("base_oid"),
+                base_type_mod: row.get("base_typmod"),
+                not_null: row.get("not_null"),
+                checks: vec![],
+            });
+            let check_name: Option<String> = row.get("check_name");
+            let check_expr: Option<String> = row.get("check_expr");
+            if let (Some(name), Some(expr)) = (check_name, check_expr) {
+                domain.checks.push(PostgresDomainCheck { name, expr });
+            }
+        }
+        to_fetch = domains
+            .values()
+            .map(|domain| domain.base_oid)
+            .filter(|oid| !fetched.contains(oid))
+            .collect();
+    }
+    Ok(domains)
+}
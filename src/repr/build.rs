@@ -18,6 +18,7 @@ fn main() {
         .compile_protos(
             &[
                 "repr/src/adt/array.proto",
+                "repr/src/adt/bit.proto",
                 "repr/src/adt/char.proto",
                 "repr/src/adt/date.proto",
                 "repr/src/adt/datetime.proto",
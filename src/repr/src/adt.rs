@@ -18,11 +18,13 @@
 //! [PostgreSQL ADTs]: https://github.com/postgres/postgres/tree/master/src/backend/utils/adt
 
 pub mod array;
+pub mod bit;
 pub mod char;
 pub mod date;
 pub mod datetime;
 pub mod interval;
 pub mod jsonb;
+pub mod multirange;
 pub mod mz_acl_item;
 pub mod numeric;
 pub mod pg_legacy_name;
@@ -0,0 +1,504 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Bit string types.
+//!
+//! The values of [`ScalarType::Bit`] and [`ScalarType::VarBit`] are stored as
+//! [`Datum::Bytes`]. Rather than storing PostgreSQL's packed representation,
+//! each bit is stored as a two-bit symbol, `0b10` for a zero and `0b11` for a
+//! one, packed four to a byte starting with the most significant bits. The
+//! final byte is padded with `0b00` symbols. Because the padding symbol sorts
+//! before both bit symbols, encoded bit strings compare exactly like
+//! PostgreSQL compares bit strings, i.e. bit by bit with a bit string sorting
+//! before its extensions, so equality and ordering can be delegated to the
+//! bytes'.
+//!
+//! [`ScalarType::Bit`]: crate::ScalarType::Bit
+//! [`ScalarType::VarBit`]: crate::ScalarType::VarBit
+//! [`Datum::Bytes`]: crate::Datum::Bytes
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
+use mz_proto::{RustType, TryFromProtoError};
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.bit.rs"));
+
+// https://github.com/postgres/postgres/blob/REL_14_0/src/backend/utils/adt/varbit.c#L124-L127
+pub const MAX_LENGTH: u32 = 83_886_080;
+
+/// The `length` of a [`ScalarType::Bit`] or the `max_length` of a
+/// [`ScalarType::VarBit`].
+///
+/// This newtype wrapper ensures that the length is within the valid range.
+///
+/// [`ScalarType::Bit`]: crate::ScalarType::Bit
+/// [`ScalarType::VarBit`]: crate::ScalarType::VarBit
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, MzReflect,
+)]
+pub struct BitLength(pub(crate) u32);
+
+impl BitLength {
+    /// A length of one.
+    pub const ONE: BitLength = BitLength(1);
+
+    /// Consumes the newtype wrapper, returning the inner `u32`.
+    pub fn into_u32(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<i64> for BitLength {
+    type Error = InvalidBitLengthError;
+
+    fn try_from(length: i64) -> Result<Self, Self::Error> {
+        match u32::try_from(length) {
+            Ok(length) if length > 0 && length <= MAX_LENGTH => Ok(BitLength(length)),
+            _ => Err(InvalidBitLengthError),
+        }
+    }
+}
+
+impl RustType<ProtoBitLength> for BitLength {
+    fn into_proto(&self) -> ProtoBitLength {
+        ProtoBitLength { value: self.0 }
+    }
+
+    fn from_proto(proto: ProtoBitLength) -> Result<Self, TryFromProtoError> {
+        Ok(BitLength(proto.value))
+    }
+}
+
+impl Arbitrary for BitLength {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<BitLength>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        proptest::arbitrary::any::<u32>()
+            // We cap the maximum BitLength to prevent generating massive bit
+            // strings which can greatly slow down tests and are relatively
+            // uninteresting.
+            .prop_map(|len| BitLength(len % 300 + 1))
+            .boxed()
+    }
+}
+
+/// The error returned when constructing a [`BitLength`] from an invalid value.
+#[derive(Debug, Clone)]
+pub struct InvalidBitLengthError;
+
+impl fmt::Display for InvalidBitLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "length for type bit must be between 1 and {}",
+            MAX_LENGTH
+        )
+    }
+}
+
+impl Error for InvalidBitLengthError {}
+
+/// A string of bits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitString {
+    /// The number of bits in the string.
+    len: usize,
+    /// The bits, packed eight to a byte starting with the most significant
+    /// bit, as in PostgreSQL. Unused bits in the last byte are always zero.
+    bytes: Vec<u8>,
+}
+
+impl BitString {
+    /// Returns a bit string of `len` zeros.
+    pub fn zeros(len: usize) -> BitString {
+        BitString {
+            len,
+            bytes: vec![0; len.div_ceil(8)],
+        }
+    }
+
+    /// Constructs a bit string from its bits, in order.
+    pub fn from_bits<I>(bits: I) -> BitString
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut s = BitString::default();
+        for bit in bits {
+            s.push(bit);
+        }
+        s
+    }
+
+    /// Constructs a bit string of `len` bits from PostgreSQL's packed
+    /// representation, as used by its binary wire format.
+    pub fn from_packed(len: usize, bytes: &[u8]) -> Result<BitString, BitStringError> {
+        if bytes.len() != len.div_ceil(8) {
+            return Err(BitStringError::InvalidPackedLength);
+        }
+        let mut s = BitString {
+            len,
+            bytes: bytes.to_vec(),
+        };
+        s.clear_padding();
+        Ok(s)
+    }
+
+    /// Returns the bits packed eight to a byte, as in PostgreSQL's binary wire
+    /// format.
+    pub fn packed(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the number of bits in the string.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Reports whether the string contains no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at index `i`, counting from the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit index out of bounds");
+        self.bytes[i / 8] & (0x80 >> (i % 8)) != 0
+    }
+
+    /// Sets the bit at index `i`, counting from the left, to `bit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn set(&mut self, i: usize, bit: bool) {
+        assert!(i < self.len, "bit index out of bounds");
+        let mask = 0x80 >> (i % 8);
+        if bit {
+            self.bytes[i / 8] |= mask;
+        } else {
+            self.bytes[i / 8] &= !mask;
+        }
+    }
+
+    /// Returns an iterator over the bits of the string, from left to right.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> u64 {
+        self.bytes.iter().map(|b| u64::from(b.count_ones())).sum()
+    }
+
+    /// Returns the bitwise negation of the string.
+    pub fn invert(&self) -> BitString {
+        let mut s = BitString {
+            len: self.len,
+            bytes: self.bytes.iter().map(|b| !b).collect(),
+        };
+        s.clear_padding();
+        s
+    }
+
+    /// Returns the bitwise AND of two strings of the same length.
+    pub fn and(&self, other: &BitString) -> Result<BitString, BitStringError> {
+        self.zip_bytes(other, "AND", |a, b| a & b)
+    }
+
+    /// Returns the bitwise OR of two strings of the same length.
+    pub fn or(&self, other: &BitString) -> Result<BitString, BitStringError> {
+        self.zip_bytes(other, "OR", |a, b| a | b)
+    }
+
+    /// Returns the bitwise XOR of two strings of the same length.
+    pub fn xor(&self, other: &BitString) -> Result<BitString, BitStringError> {
+        self.zip_bytes(other, "XOR", |a, b| a ^ b)
+    }
+
+    /// Shifts the string `n` bits to the left, filling with zeros and
+    /// preserving its length. Negative values of `n` shift to the right.
+    pub fn shift_left(&self, n: i32) -> BitString {
+        let mut s = BitString::zeros(self.len);
+        for i in 0..self.len {
+            let src = i64::cast_from(i) + i64::from(n);
+            if let Ok(src) = usize::try_from(src) {
+                if src < self.len && self.get(src) {
+                    s.set(i, true);
+                }
+            }
+        }
+        s
+    }
+
+    /// Returns the concatenation of the string and `other`.
+    pub fn concat(&self, other: &BitString) -> BitString {
+        BitString::from_bits(self.iter().chain(other.iter()))
+    }
+
+    /// Returns the string truncated or zero-padded on the right to `len` bits.
+    pub fn resize(&self, len: usize) -> BitString {
+        let mut s = BitString::zeros(len);
+        for i in 0..len.min(self.len) {
+            s.set(i, self.get(i));
+        }
+        s
+    }
+
+    /// Returns the rightmost `len` bits of the two's complement
+    /// representation of `value`, sign extending it if `len` exceeds 64.
+    pub fn from_i64(value: i64, len: usize) -> BitString {
+        BitString::from_bits((0..len).rev().map(|k| match u32::try_from(k) {
+            Ok(k) if k < 64 => (value >> k) & 1 == 1,
+            _ => value < 0,
+        }))
+    }
+
+    /// Interprets the string as an unsigned integer, or returns `None` if it
+    /// has more than 64 bits.
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.len > 64 {
+            return None;
+        }
+        Some(self.iter().fold(0, |acc, bit| (acc << 1) | u64::from(bit)))
+    }
+
+    /// Encodes the string in the form stored in a [`Datum::Bytes`]. See the
+    /// module documentation for details.
+    ///
+    /// [`Datum::Bytes`]: crate::Datum::Bytes
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = vec![0; self.len.div_ceil(4)];
+        for (i, bit) in self.iter().enumerate() {
+            let symbol = if bit { 0b11 } else { 0b10 };
+            out[i / 4] |= symbol << (6 - 2 * (i % 4));
+        }
+        out
+    }
+
+    /// Decodes a string produced by [`BitString::encode`].
+    pub fn decode(bytes: &[u8]) -> BitString {
+        BitString::from_bits(
+            bytes
+                .iter()
+                .flat_map(|b| [6, 4, 2, 0].map(|shift| (b >> shift) & 0b11))
+                .take_while(|symbol| *symbol != 0)
+                .map(|symbol| symbol == 0b11),
+        )
+    }
+
+    fn push(&mut self, bit: bool) {
+        if self.len % 8 == 0 {
+            self.bytes.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, bit);
+    }
+
+    fn clear_padding(&mut self) {
+        let used = self.len % 8;
+        if let (Some(last), true) = (self.bytes.last_mut(), used != 0) {
+            *last &= 0xFF << (8 - used);
+        }
+    }
+
+    fn zip_bytes(
+        &self,
+        other: &BitString,
+        op: &'static str,
+        f: impl Fn(u8, u8) -> u8,
+    ) -> Result<BitString, BitStringError> {
+        if self.len != other.len {
+            return Err(BitStringError::SizeMismatch { op });
+        }
+        Ok(BitString {
+            len: self.len,
+            bytes: self
+                .bytes
+                .iter()
+                .zip(&other.bytes)
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+        })
+    }
+}
+
+impl fmt::Display for BitString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for BitString {
+    type Err = BitStringError;
+
+    /// Parses PostgreSQL's text representation of a bit string: binary digits
+    /// optionally prefixed with `b`, or hexadecimal digits prefixed with `x`.
+    fn from_str(s: &str) -> Result<BitString, BitStringError> {
+        match s.chars().next() {
+            Some('x' | 'X') => {
+                let mut out = BitString::default();
+                for c in s[1..].chars() {
+                    let nibble = c.to_digit(16).ok_or(BitStringError::InvalidHexDigit(c))?;
+                    for shift in (0..4).rev() {
+                        out.push((nibble >> shift) & 1 == 1);
+                    }
+                }
+                Ok(out)
+            }
+            first => {
+                let digits = match first {
+                    Some('b' | 'B') => &s[1..],
+                    _ => s,
+                };
+                let mut out = BitString::default();
+                for c in digits.chars() {
+                    match c {
+                        '0' => out.push(false),
+                        '1' => out.push(true),
+                        _ => return Err(BitStringError::InvalidBinaryDigit(c)),
+                    }
+                }
+                Ok(out)
+            }
+        }
+    }
+}
+
+/// An error produced by an operation on a [`BitString`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitStringError {
+    InvalidBinaryDigit(char),
+    InvalidHexDigit(char),
+    InvalidPackedLength,
+    SizeMismatch { op: &'static str },
+}
+
+impl fmt::Display for BitStringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BitStringError::InvalidBinaryDigit(c) => {
+                write!(f, "\"{}\" is not a valid binary digit", c)
+            }
+            BitStringError::InvalidHexDigit(c) => {
+                write!(f, "\"{}\" is not a valid hexadecimal digit", c)
+            }
+            BitStringError::InvalidPackedLength => {
+                f.write_str("invalid length in external bit string")
+            }
+            BitStringError::SizeMismatch { op } => {
+                write!(f, "cannot {} bit strings of different sizes", op)
+            }
+        }
+    }
+}
+
+impl Error for BitStringError {}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::assert_ok;
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[mz_ore::test]
+        fn bit_length_protobuf_roundtrip(expect in any::<BitLength>()) {
+            let actual = protobuf_roundtrip::<_, ProtoBitLength>(&expect);
+            assert_ok!(actual);
+            assert_eq!(actual.unwrap(), expect);
+        }
+
+        #[mz_ore::test]
+        fn bit_string_encode_roundtrip(bits in proptest::collection::vec(any::<bool>(), 0..50)) {
+            let s = BitString::from_bits(bits);
+            assert_eq!(BitString::decode(&s.encode()), s);
+        }
+
+        #[mz_ore::test]
+        fn bit_string_encoding_preserves_order(
+            a in proptest::collection::vec(any::<bool>(), 0..20),
+            b in proptest::collection::vec(any::<bool>(), 0..20),
+        ) {
+            let encoded_a = BitString::from_bits(a.iter().copied()).encode();
+            let encoded_b = BitString::from_bits(b.iter().copied()).encode();
+            assert_eq!(a.cmp(&b), encoded_a.cmp(&encoded_b));
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_parse_and_format() {
+        for (input, expected) in [
+            ("", ""),
+            ("0101", "0101"),
+            ("B0101", "0101"),
+            ("x1F", "00011111"),
+            ("X", ""),
+        ] {
+            let s: BitString = input.parse().unwrap();
+            assert_eq!(s.to_string(), expected, "input: {input}");
+        }
+        assert_eq!(
+            "012".parse::<BitString>(),
+            Err(BitStringError::InvalidBinaryDigit('2'))
+        );
+        assert_eq!(
+            "xG".parse::<BitString>(),
+            Err(BitStringError::InvalidHexDigit('G'))
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_ops() {
+        let bits = |s: &str| s.parse::<BitString>().unwrap();
+        assert_eq!(bits("1100").and(&bits("1010")), Ok(bits("1000")));
+        assert_eq!(bits("1100").or(&bits("1010")), Ok(bits("1110")));
+        assert_eq!(bits("1100").xor(&bits("1010")), Ok(bits("0110")));
+        assert_eq!(
+            bits("1100").and(&bits("101")),
+            Err(BitStringError::SizeMismatch { op: "AND" })
+        );
+        assert_eq!(bits("101").invert(), bits("010"));
+        assert_eq!(bits("10011").shift_left(2), bits("01100"));
+        assert_eq!(bits("10011").shift_left(-2), bits("00100"));
+        assert_eq!(bits("101").concat(&bits("01")), bits("10101"));
+        assert_eq!(bits("101").resize(5), bits("10100"));
+        assert_eq!(bits("10101").resize(2), bits("10"));
+        assert_eq!(bits("101101101").count_ones(), 6);
+        assert_eq!(BitString::from_i64(5, 4), bits("0101"));
+        assert_eq!(BitString::from_i64(-1, 3), bits("111"));
+        assert_eq!(BitString::from_i64(-2, 66).to_string().len(), 66);
+        assert_eq!(bits("0101").to_u64(), Some(5));
+        assert_eq!(BitString::zeros(65).to_u64(), None);
+        assert_eq!(
+            BitString::from_packed(9, &[0xFF, 0xFF]),
+            Ok(bits("111111111"))
+        );
+        assert_eq!(
+            BitString::from_packed(9, &[0xFF]),
+            Err(BitStringError::InvalidPackedLength)
+        );
+    }
+}
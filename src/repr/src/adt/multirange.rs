@@ -0,0 +1,246 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Multirange operations.
+//!
+//! A multirange is stored as a [`Datum::List`] of [`Datum::Range`]s. Every
+//! multirange we store is _normalized_, i.e. its ranges are non-empty, sorted,
+//! and neither overlap nor abut one another. This makes the representation of
+//! a multirange unique, so equality and ordering can be delegated to the
+//! list's.
+//!
+//! All of the functions in this module expect their inputs to be normalized,
+//! except for [`normalize`] itself, and produce normalized outputs.
+
+use crate::Datum;
+use crate::adt::range::{InvalidRangeError, Range, RangeBound, RangeInner, RangeOps};
+
+/// Returns the ranges of the multirange stored in `datum`.
+///
+/// # Panics
+///
+/// Panics if `datum` is not a [`Datum::List`] of [`Datum::Range`]s.
+pub fn unwrap_ranges<'a>(datum: Datum<'a>) -> Vec<Range<Datum<'a>>> {
+    datum
+        .unwrap_list()
+        .iter()
+        .map(|r| r.unwrap_range())
+        .collect()
+}
+
+/// Renders the multirange stored in `datum` in its PostgreSQL text form, e.g.
+/// `{[1,3),[5,7)}`.
+pub fn to_string(datum: Datum) -> String {
+    let ranges: Vec<_> = unwrap_ranges(datum).iter().map(|r| r.to_string()).collect();
+    format!("{{{}}}", ranges.join(","))
+}
+
+/// Normalizes `ranges`, which must each already be canonicalized, by removing
+/// empty ranges, sorting the ranges, and merging all overlapping and adjacent
+/// ranges.
+pub fn normalize<'a>(mut ranges: Vec<Range<Datum<'a>>>) -> Vec<Range<Datum<'a>>> {
+    ranges.retain(|r| r.inner.is_some());
+    ranges.sort();
+
+    let mut normalized: Vec<Range<Datum<'a>>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match normalized.last_mut() {
+            Some(last) if last.overlaps(&range) || last.adjacent(&range) => {
+                *last = last
+                    .union(&range)
+                    .expect("overlapping or adjacent ranges have contiguous unions");
+            }
+            _ => normalized.push(range),
+        }
+    }
+    normalized
+}
+
+/// Returns the union of `a` and `b`.
+pub fn union<'a>(a: &[Range<Datum<'a>>], b: &[Range<Datum<'a>>]) -> Vec<Range<Datum<'a>>> {
+    normalize(a.iter().chain(b.iter()).cloned().collect())
+}
+
+/// Returns the intersection of `a` and `b`.
+pub fn intersection<'a>(a: &[Range<Datum<'a>>], b: &[Range<Datum<'a>>]) -> Vec<Range<Datum<'a>>> {
+    let mut out = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let (l, r) = (&a[i], &b[j]);
+        if l.overlaps(r) {
+            out.push(l.intersection(r));
+        }
+        // Advance whichever range ends first; it cannot overlap any of the
+        // other side's remaining ranges.
+        let (Some(li), Some(ri)) = (l.inner, r.inner) else {
+            unreachable!("normalized multiranges contain no empty ranges")
+        };
+        if li.upper <= ri.upper {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    normalize(out)
+}
+
+/// Returns the ranges in `a` less the ranges in `b`.
+pub fn difference<'a>(
+    a: &[Range<Datum<'a>>],
+    b: &[Range<Datum<'a>>],
+) -> Result<Vec<Range<Datum<'a>>>, InvalidRangeError> {
+    let mut out = vec![];
+    for range in a {
+        let mut pieces = vec![*range];
+        for subtrahend in b.iter().filter(|s| range.overlaps(s)) {
+            let mut remaining = vec![];
+            for piece in pieces {
+                range_difference(&piece, subtrahend, &mut remaining)?;
+            }
+            pieces = remaining;
+        }
+        out.extend(pieces);
+    }
+    Ok(normalize(out))
+}
+
+/// Pushes the (up to two) pieces of `a` not covered by `b` onto `out`.
+fn range_difference<'a>(
+    a: &Range<Datum<'a>>,
+    b: &Range<Datum<'a>>,
+    out: &mut Vec<Range<Datum<'a>>>,
+) -> Result<(), InvalidRangeError> {
+    let (a_inner, b_inner) = match (a.inner, b.inner) {
+        (Some(a_inner), Some(b_inner)) if a.overlaps(b) => (a_inner, b_inner),
+        _ => {
+            out.push(*a);
+            return Ok(());
+        }
+    };
+
+    if a_inner.lower < b_inner.lower {
+        let mut left = Range {
+            inner: Some(RangeInner {
+                lower: a_inner.lower,
+                upper: RangeBound {
+                    inclusive: !b_inner.lower.inclusive,
+                    bound: b_inner.lower.bound,
+                },
+            }),
+        };
+        left.canonicalize()?;
+        out.push(left);
+    }
+
+    if b_inner.upper < a_inner.upper {
+        let mut right = Range {
+            inner: Some(RangeInner {
+                lower: RangeBound {
+                    inclusive: !b_inner.upper.inclusive,
+                    bound: b_inner.upper.bound,
+                },
+                upper: a_inner.upper,
+            }),
+        };
+        right.canonicalize()?;
+        out.push(right);
+    }
+
+    Ok(())
+}
+
+/// Reports whether any range in `ranges` contains `elem`.
+pub fn contains_elem<'a, T: RangeOps<'a>>(ranges: &[Range<Datum<'a>>], elem: &T) -> bool
+where
+    <T as TryFrom<Datum<'a>>>::Error: std::fmt::Debug,
+{
+    ranges.iter().any(|r| r.contains_elem(elem))
+}
+
+/// Reports whether `range` is contained by a single range in `ranges`.
+///
+/// Because normalized multiranges never contain adjacent ranges, this is
+/// equivalent to `range` being contained by the union of `ranges`.
+pub fn contains_range<'a>(ranges: &[Range<Datum<'a>>], range: &Range<Datum<'a>>) -> bool {
+    range.inner.is_none() || ranges.iter().any(|r| r.contains_range(range))
+}
+
+/// Reports whether every range in `b` is contained by `a`.
+pub fn contains_multirange<'a>(a: &[Range<Datum<'a>>], b: &[Range<Datum<'a>>]) -> bool {
+    b.iter().all(|range| contains_range(a, range))
+}
+
+/// Reports whether any range in `ranges` overlaps `range`.
+pub fn overlaps_range<'a>(ranges: &[Range<Datum<'a>>], range: &Range<Datum<'a>>) -> bool {
+    ranges.iter().any(|r| r.overlaps(range))
+}
+
+/// Reports whether any range in `a` overlaps any range in `b`.
+pub fn overlaps_multirange<'a>(a: &[Range<Datum<'a>>], b: &[Range<Datum<'a>>]) -> bool {
+    b.iter().any(|range| overlaps_range(a, range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_range<'a>(lower: i32, upper: i32) -> Range<Datum<'a>> {
+        let mut range = Range::new(Some((
+            RangeBound::new(Datum::Int32(lower), true),
+            RangeBound::new(Datum::Int32(upper), false),
+        )));
+        range.canonicalize().unwrap();
+        range
+    }
+
+    #[mz_ore::test]
+    fn test_normalize() {
+        let normalized = normalize(vec![
+            int_range(5, 7),
+            int_range(1, 3),
+            int_range(3, 4),
+            int_range(6, 9),
+            int_range(2, 2),
+        ]);
+        assert_eq!(normalized, vec![int_range(1, 4), int_range(5, 9)]);
+    }
+
+    #[mz_ore::test]
+    fn test_set_operations() {
+        let a = vec![int_range(1, 5), int_range(10, 20)];
+        let b = vec![int_range(3, 12), int_range(15, 16)];
+
+        assert_eq!(union(&a, &b), vec![int_range(1, 20)]);
+        assert_eq!(
+            intersection(&a, &b),
+            vec![int_range(3, 5), int_range(10, 12), int_range(15, 16)]
+        );
+        assert_eq!(
+            difference(&a, &b).unwrap(),
+            vec![int_range(1, 3), int_range(12, 15), int_range(16, 20)]
+        );
+        assert_eq!(difference(&b, &b).unwrap(), vec![]);
+    }
+
+    #[mz_ore::test]
+    fn test_containment() {
+        let a = vec![int_range(1, 5), int_range(10, 20)];
+
+        assert!(contains_elem(&a, &4));
+        assert!(!contains_elem(&a, &5));
+        assert!(contains_range(&a, &int_range(11, 13)));
+        assert!(!contains_range(&a, &int_range(4, 11)));
+        assert!(contains_multirange(
+            &a,
+            &[int_range(1, 2), int_range(12, 20)]
+        ));
+        assert!(overlaps_range(&a, &int_range(4, 11)));
+        assert!(!overlaps_multirange(&a, &[int_range(5, 10)]));
+    }
+}
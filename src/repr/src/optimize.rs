@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 /// A macro for feature flags managed by the optimizer.
 macro_rules! optimizer_feature_flags {
     ({ $($feature:ident: $type:ty,)* }) => {
-        #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Arbitrary)]
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Arbitrary)]
         pub struct OptimizerFeatures {
             $(pub $feature: $type),*
         }
@@ -126,6 +126,10 @@ optimizer_feature_flags!({
     enable_less_reduce_in_eqprop: bool,
     // See the feature flag of the same name.
     enable_dequadratic_eqprop_map: bool,
+    // Bound from the `persist_part_projection_pushdown` and
+    // `compute_apply_column_demands` dyncfgs, which together decide whether
+    // sources only fetch the columns demanded by their consumers.
+    persist_projection_pushdown: bool,
 });
 
 /// A trait used to implement layered config construction.
@@ -1115,11 +1115,19 @@ impl RelationVersionSelector {
 #[derive(Debug, Clone, Serialize)]
 pub struct VersionedRelationDesc {
     inner: RelationDesc,
+    /// Versions at which the type of a column was changed.
+    ///
+    /// Note: [`RelationDesc`] only stores a single type per column, so a type
+    /// change applies to every version of the column.
+    altered: BTreeMap<RelationVersion, ColumnName>,
 }
 
 impl VersionedRelationDesc {
     pub fn new(inner: RelationDesc) -> Self {
-        VersionedRelationDesc { inner }
+        VersionedRelationDesc {
+            inner,
+            altered: BTreeMap::new(),
+        }
     }
 
     /// Adds a new column to this [`RelationDesc`], creating a new version of the [`RelationDesc`].
@@ -1206,6 +1214,39 @@ impl VersionedRelationDesc {
         new_version
     }
 
+    /// Changes the type of the column `name` to `typ`, creating a new version of the
+    /// [`RelationDesc`].
+    ///
+    /// It's up to the caller to ensure that data of the existing type can be
+    /// losslessly read as `typ`, because the new type applies to all versions of
+    /// the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a column with `name` does not exist.
+    #[must_use]
+    pub fn alter_column_type<N, T>(&mut self, name: N, typ: T) -> RelationVersion
+    where
+        N: Into<ColumnName>,
+        T: Into<ColumnType>,
+    {
+        let name = name.into();
+        let latest_version = self.latest_version();
+        let new_version = latest_version.bump();
+
+        let col = self
+            .inner
+            .metadata
+            .values()
+            .find(|meta| meta.name == name && meta.dropped.is_none())
+            .expect("column to exist");
+        self.inner.typ.column_types[col.typ_idx] = typ.into();
+        self.altered.insert(new_version, name);
+
+        self.validate();
+        new_version
+    }
+
     /// Returns the [`RelationDesc`] at the latest version.
     pub fn latest(&self) -> RelationDesc {
         self.inner.clone()
@@ -1287,6 +1328,7 @@ impl VersionedRelationDesc {
             .values()
             // N.B. Dropped is always greater than added.
             .map(|meta| meta.dropped.unwrap_or(meta.added))
+            .chain(self.altered.keys().copied())
             .max()
             // If there aren't any columns we're implicitly the root version.
             .unwrap_or_else(RelationVersion::root)
@@ -1298,7 +1340,10 @@ impl VersionedRelationDesc {
     ///
     /// Panics if a constraint is not satisfied.
     fn validate(&self) {
-        fn validate_inner(desc: &RelationDesc) -> Result<(), anyhow::Error> {
+        fn validate_inner(
+            desc: &RelationDesc,
+            altered: &BTreeMap<RelationVersion, ColumnName>,
+        ) -> Result<(), anyhow::Error> {
             if desc.typ.column_types.len() != desc.metadata.len() {
                 anyhow::bail!("mismatch between number of types and metadatas");
             }
@@ -1326,7 +1371,8 @@ impl VersionedRelationDesc {
             let versions = desc
                 .metadata
                 .values()
-                .map(|meta| meta.dropped.unwrap_or(meta.added));
+                .flat_map(|meta| std::iter::once(meta.added).chain(meta.dropped))
+                .chain(altered.keys().copied());
             let mut max = 0;
             let mut sum = 0;
             for version in versions {
@@ -1350,7 +1396,10 @@ impl VersionedRelationDesc {
             Ok(())
         }
 
-        assert_ok!(validate_inner(&self.inner), "validate failed! {self:?}");
+        assert_ok!(
+            validate_inner(&self.inner, &self.altered),
+            "validate failed! {self:?}"
+        );
     }
 }
 
@@ -1522,9 +1571,7 @@ mod tests {
             .with_column("z", ScalarType::String.nullable(false))
             .finish();
 
-        let mut versioned_desc = VersionedRelationDesc {
-            inner: desc.clone(),
-        };
+        let mut versioned_desc = VersionedRelationDesc::new(desc.clone());
         versioned_desc.validate();
 
         let latest = versioned_desc.at_version(RelationVersionSelector::Latest);
@@ -1628,9 +1675,7 @@ mod tests {
             .with_key(vec![1])
             .finish();
 
-        let mut versioned_desc = VersionedRelationDesc {
-            inner: desc.clone(),
-        };
+        let mut versioned_desc = VersionedRelationDesc::new(desc.clone());
         versioned_desc.validate();
 
         let v1 = versioned_desc.drop_column("a");
@@ -1812,6 +1857,34 @@ mod tests {
         "###);
     }
 
+    #[mz_ore::test]
+    fn test_alter_column_type() {
+        let desc = RelationDesc::builder()
+            .with_column("a", ScalarType::Int16.nullable(true))
+            .with_column("b", ScalarType::String.nullable(false))
+            .finish();
+        let mut versioned = VersionedRelationDesc::new(desc);
+
+        let v1 = versioned.add_column("c", ScalarType::Int32.nullable(true));
+        let v2 = versioned.alter_column_type("a", ScalarType::Int64.nullable(true));
+        let v3 = versioned.drop_column("c");
+        assert_eq!(
+            (v1, v2, v3),
+            (RelationVersion(1), RelationVersion(2), RelationVersion(3))
+        );
+        assert_eq!(versioned.latest_version(), v3);
+
+        // The new type applies to all versions of the column.
+        let v0 = versioned.at_version(RelationVersionSelector::specific(0));
+        assert_eq!(
+            v0.get_by_name(&"a".into()).map(|(_, typ)| typ.clone()),
+            Some(ScalarType::Int64.nullable(true))
+        );
+        let latest = versioned.at_version(RelationVersionSelector::Latest);
+        assert_eq!(latest.arity(), 2);
+        assert_none!(latest.get_by_name(&"c".into()));
+    }
+
     #[mz_ore::test]
     #[cfg_attr(miri, ignore)]
     fn apply_demand() {
@@ -41,8 +41,9 @@ use crate::adt::range::{
     self, InvalidRangeError, Range, RangeBound, RangeInner, RangeLowerBound, RangeUpperBound,
 };
 use crate::adt::timestamp::CheckedTimestamp;
+use crate::row::proto_datum::DatumType;
 use crate::scalar::{DatumKind, arb_datum};
-use crate::{Datum, RelationDesc, Timestamp};
+use crate::{Datum, RelationDesc, ScalarType, Timestamp};
 
 pub(crate) mod encode;
 pub mod iter;
@@ -121,7 +122,7 @@ impl Row {
         desc: &RelationDesc,
     ) -> Result<(), String> {
         let mut packer = self.packer();
-        for (col_idx, _, _) in desc.iter_all() {
+        for (col_idx, _, col_type) in desc.iter_all() {
             let d = match proto.datums.get(col_idx.to_raw()) {
                 Some(x) => x,
                 None => {
@@ -129,7 +130,14 @@ impl Row {
                     continue;
                 }
             };
-            packer.try_push_proto(d)?;
+            // Integer columns may have been widened since this row was encoded.
+            match (&d.datum_type, &col_type.scalar_type) {
+                (Some(DatumType::Int16(x)), ScalarType::Int32) => packer.push(Datum::Int32(*x)),
+                (Some(DatumType::Int16(x) | DatumType::Int32(x)), ScalarType::Int64) => {
+                    packer.push(Datum::Int64(i64::from(*x)))
+                }
+                _ => packer.try_push_proto(d)?,
+            }
         }
 
         Ok(())
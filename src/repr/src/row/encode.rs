@@ -101,6 +101,8 @@ pub fn preserves_order(scalar_type: &ScalarType) -> bool {
         | ScalarType::TimestampTz { .. }
         | ScalarType::Interval
         | ScalarType::Bytes
+        | ScalarType::Bit { .. }
+        | ScalarType::VarBit { .. }
         | ScalarType::String
         | ScalarType::Uuid
         | ScalarType::MzTimestamp
@@ -131,7 +133,8 @@ pub fn preserves_order(scalar_type: &ScalarType) -> bool {
         | ScalarType::RegType
         | ScalarType::RegClass
         | ScalarType::Int2Vector
-        | ScalarType::Range { .. } => false,
+        | ScalarType::Range { .. }
+        | ScalarType::MultiRange { .. } => false,
     }
 }
 
@@ -1609,7 +1612,10 @@ fn array_to_decoder(
             let array = downcast_array::<StringArray>(array)?;
             DatumColumnDecoder::String(array.clone())
         }
-        (DataType::Binary, ScalarType::Bytes) => {
+        (
+            DataType::Binary,
+            ScalarType::Bytes | ScalarType::Bit { .. } | ScalarType::VarBit { .. },
+        ) => {
             let array = downcast_array::<BinaryArray>(array)?;
             DatumColumnDecoder::Bytes(array.clone())
         }
@@ -1696,6 +1702,18 @@ fn array_to_decoder(
                 nulls: array.nulls().cloned(),
             }
         }
+        (DataType::List(_), ScalarType::MultiRange { element_type }) => {
+            let array = downcast_array::<ListArray>(array)?;
+            let range_type = ScalarType::Range {
+                element_type: element_type.clone(),
+            };
+            let inner_decoder = array_to_decoder(array.values(), &range_type)?;
+            DatumColumnDecoder::List {
+                offsets: array.offsets().clone(),
+                values: Box::new(inner_decoder),
+                nulls: array.nulls().cloned(),
+            }
+        }
         (DataType::Map(_, true), ScalarType::Map { value_type, .. }) => {
             let array = downcast_array::<MapArray>(array)?;
             let keys = downcast_array::<StringArray>(array.keys())?;
@@ -1779,7 +1797,9 @@ fn scalar_type_to_encoder(col_ty: &ScalarType) -> Result<DatumColumnEncoder, any
         | ScalarType::PgLegacyName
         | ScalarType::Char { .. }
         | ScalarType::VarChar { .. } => DatumColumnEncoder::String(StringBuilder::new()),
-        ScalarType::Bytes => DatumColumnEncoder::Bytes(BinaryBuilder::new()),
+        ScalarType::Bytes | ScalarType::Bit { .. } | ScalarType::VarBit { .. } => {
+            DatumColumnEncoder::Bytes(BinaryBuilder::new())
+        }
         ScalarType::Date => DatumColumnEncoder::Date(Int32Builder::new()),
         ScalarType::Time => DatumColumnEncoder::Time(FixedSizeBinaryBuilder::new(TIME_FIXED_BYTES)),
         ScalarType::Timestamp { .. } => {
@@ -1825,6 +1845,16 @@ fn scalar_type_to_encoder(col_ty: &ScalarType) -> Result<DatumColumnEncoder, any
                 nulls: None,
             }
         }
+        ScalarType::MultiRange { element_type } => {
+            let inner = scalar_type_to_encoder(&ScalarType::Range {
+                element_type: element_type.clone(),
+            })?;
+            DatumColumnEncoder::List {
+                lengths: Vec::new(),
+                values: Box::new(inner),
+                nulls: None,
+            }
+        }
         ScalarType::Map { value_type, .. } => {
             let inner = scalar_type_to_encoder(&*value_type)?;
             DatumColumnEncoder::Map {
@@ -2319,6 +2349,7 @@ mod tests {
                         ScalarType::AclItem
                         | ScalarType::MzAclItem
                         | ScalarType::Range { .. }
+                        | ScalarType::MultiRange { .. }
                         | ScalarType::Array(_)
                         | ScalarType::Map { .. }
                         | ScalarType::List { .. }
@@ -2481,6 +2512,30 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn decode_widened_ints() {
+        let narrow = RelationDesc::builder()
+            .with_column("a", ScalarType::Int16.nullable(false))
+            .with_column("b", ScalarType::Int32.nullable(true))
+            .finish();
+        let wide = RelationDesc::builder()
+            .with_column("a", ScalarType::Int64.nullable(false))
+            .with_column("b", ScalarType::Int64.nullable(true))
+            .finish();
+
+        let mut buf = Vec::new();
+        Row::pack_slice(&[Datum::Int16(-7), Datum::Int32(i32::MAX)]).encode(&mut buf);
+        let row = Row::decode(&buf, &wide).unwrap();
+        assert_eq!(
+            row,
+            Row::pack_slice(&[Datum::Int64(-7), Datum::Int64(i64::from(i32::MAX))])
+        );
+        assert_eq!(
+            Row::decode(&buf, &narrow).unwrap().unpack_first(),
+            Datum::Int16(-7)
+        );
+    }
+
     #[mz_ore::test]
     fn smoketest_row() {
         let desc = RelationDesc::builder()
@@ -30,6 +30,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::adt::array::{Array, ArrayDimension};
+use crate::adt::bit::{BitLength, BitString};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
 use crate::adt::interval::Interval;
@@ -1007,7 +1008,9 @@ impl<'a> Datum<'a> {
                     (Datum::TimestampTz(_), _) => false,
                     (Datum::Interval(_), ScalarType::Interval) => true,
                     (Datum::Interval(_), _) => false,
-                    (Datum::Bytes(_), ScalarType::Bytes) => true,
+                    (Datum::Bytes(_), ScalarType::Bytes)
+                    | (Datum::Bytes(_), ScalarType::Bit { .. })
+                    | (Datum::Bytes(_), ScalarType::VarBit { .. }) => true,
                     (Datum::Bytes(_), _) => false,
                     (Datum::String(_), ScalarType::String)
                     | (Datum::String(_), ScalarType::VarChar { .. })
@@ -1033,6 +1036,12 @@ impl<'a> Datum<'a> {
                     (Datum::List(list), ScalarType::List { element_type, .. }) => list
                         .iter()
                         .all(|e| e.is_null() || is_instance_of_scalar(e, element_type)),
+                    (Datum::List(list), ScalarType::MultiRange { element_type }) => {
+                        let range_type = ScalarType::Range {
+                            element_type: element_type.clone(),
+                        };
+                        list.iter().all(|e| is_instance_of_scalar(e, &range_type))
+                    }
                     (Datum::List(list), ScalarType::Record { fields, .. }) => {
                         list.iter().zip_eq(fields).all(|(e, (_, t))| {
                             (e.is_null() && t.nullable) || is_instance_of_scalar(e, &t.scalar_type)
@@ -1477,6 +1486,19 @@ pub enum ScalarType {
     VarChar {
         max_length: Option<VarCharMaxLength>,
     },
+    /// A fixed-length bit string, stored as [`Datum::Bytes`]. See
+    /// [`crate::adt::bit`].
+    ///
+    /// Note that a `length` of `None` is used in special cases, such as
+    /// creating lists.
+    Bit {
+        length: Option<BitLength>,
+    },
+    /// A bit string that can optionally express a limit on its length, stored
+    /// as [`Datum::Bytes`]. See [`crate::adt::bit`].
+    VarBit {
+        max_length: Option<BitLength>,
+    },
     /// The type of a datum that may represent any valid JSON value.
     ///
     /// Valid datum variants for this type are:
@@ -1543,6 +1565,11 @@ pub enum ScalarType {
     MzAclItem,
     /// The type of [`Datum::AclItem`]
     AclItem,
+    /// A set of non-overlapping ranges, stored as a [`Datum::List`] of
+    /// [`Datum::Range`]s. See [`crate::adt::multirange`].
+    MultiRange {
+        element_type: Box<ScalarType>,
+    },
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, ColumnType) {
@@ -1609,6 +1636,12 @@ impl RustType<ProtoScalarType> for ScalarType {
                 ScalarType::VarChar { max_length } => VarChar(ProtoVarChar {
                     max_length: max_length.into_proto(),
                 }),
+                ScalarType::Bit { length } => Bit(ProtoBit {
+                    length: length.into_proto(),
+                }),
+                ScalarType::VarBit { max_length } => VarBit(ProtoVarBit {
+                    max_length: max_length.into_proto(),
+                }),
 
                 ScalarType::List {
                     element_type,
@@ -1635,6 +1668,9 @@ impl RustType<ProtoScalarType> for ScalarType {
                 })),
                 ScalarType::MzAclItem => MzAclItem(()),
                 ScalarType::AclItem => AclItem(()),
+                ScalarType::MultiRange { element_type } => MultiRange(Box::new(ProtoRange {
+                    element_type: Some(element_type.into_proto()),
+                })),
             }),
         }
     }
@@ -1687,6 +1723,12 @@ impl RustType<ProtoScalarType> for ScalarType {
             VarChar(x) => Ok(ScalarType::VarChar {
                 max_length: x.max_length.into_rust()?,
             }),
+            Bit(x) => Ok(ScalarType::Bit {
+                length: x.length.into_rust()?,
+            }),
+            VarBit(x) => Ok(ScalarType::VarBit {
+                max_length: x.max_length.into_rust()?,
+            }),
             Array(x) => Ok(ScalarType::Array({
                 let st: ScalarType = (*x).into_rust()?;
                 st.into()
@@ -1721,6 +1763,13 @@ impl RustType<ProtoScalarType> for ScalarType {
             }),
             MzAclItem(()) => Ok(ScalarType::MzAclItem),
             AclItem(()) => Ok(ScalarType::AclItem),
+            MultiRange(x) => Ok(ScalarType::MultiRange {
+                element_type: Box::new(
+                    x.element_type
+                        .map(|x| *x)
+                        .into_rust_if_some("ProtoRange::element_type")?,
+                ),
+            }),
         }
     }
 }
@@ -2757,9 +2806,16 @@ impl ScalarType {
             // to support Char values of different lengths in e.g. lists.
             Char { .. } => Char { length: None },
             VarChar { .. } => VarChar { max_length: None },
+            // As with Char, Bit's default length is `None` to support bit
+            // strings of different lengths in e.g. lists.
+            Bit { .. } => Bit { length: None },
+            VarBit { .. } => VarBit { max_length: None },
             Range { element_type } => Range {
                 element_type: Box::new(element_type.without_modifiers()),
             },
+            MultiRange { element_type } => MultiRange {
+                element_type: Box::new(element_type.without_modifiers()),
+            },
             v => v.clone(),
         }
     }
@@ -2835,6 +2891,21 @@ impl ScalarType {
         }
     }
 
+    /// Returns the length of a [`ScalarType::Bit`] or the max length of a
+    /// [`ScalarType::VarBit`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on anything other than a [`ScalarType::Bit`] or
+    /// [`ScalarType::VarBit`].
+    pub fn unwrap_bit_length(&self) -> Option<BitLength> {
+        match self {
+            ScalarType::Bit { length } => *length,
+            ScalarType::VarBit { max_length } => *max_length,
+            _ => panic!("ScalarType::unwrap_bit_length called on {:?}", self),
+        }
+    }
+
     /// Returns the [`ScalarType`] of elements in a [`ScalarType::Range`].
     ///
     /// # Panics
@@ -2847,6 +2918,22 @@ impl ScalarType {
         }
     }
 
+    /// Returns the [`ScalarType`] of the ranges' elements in a
+    /// [`ScalarType::MultiRange`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on anything other than a [`ScalarType::MultiRange`].
+    pub fn unwrap_multirange_element_type(&self) -> &ScalarType {
+        match self {
+            ScalarType::MultiRange { element_type } => &**element_type,
+            _ => panic!(
+                "ScalarType::unwrap_multirange_element_type called on {:?}",
+                self
+            ),
+        }
+    }
+
     /// Returns a "near match" of `self`, which are types that are implicitly
     /// castable from `self` and offer a means to leverage Materialize's type
     /// system to achieve more reasonable approaches to unifying types.
@@ -2967,7 +3054,9 @@ impl ScalarType {
                     custom_id: oid_r,
                 },
             ) => l.eq_inner(r, structure_only) && (oid_l == oid_r || structure_only),
-            (Array(a), Array(b)) | (Range { element_type: a }, Range { element_type: b }) => {
+            (Array(a), Array(b))
+            | (Range { element_type: a }, Range { element_type: b })
+            | (MultiRange { element_type: a }, MultiRange { element_type: b }) => {
                 a.eq_inner(b, structure_only)
             }
             (
@@ -3381,6 +3470,14 @@ impl ScalarType {
             ])
         });
         static RANGE: LazyLock<Row> = LazyLock::new(|| Row::pack_slice(&[]));
+        static MULTIRANGE: LazyLock<Row> = LazyLock::new(|| Row::pack_slice(&[]));
+        static BIT: LazyLock<Row> = LazyLock::new(|| {
+            let bits: Vec<_> = ["", "0", "1", "101010101"]
+                .iter()
+                .map(|s| s.parse::<BitString>().expect("valid bit string").encode())
+                .collect();
+            Row::pack(bits.iter().map(|b| Datum::Bytes(b)))
+        });
         static MZACLITEM: LazyLock<Row> = LazyLock::new(|| {
             Row::pack_slice(&[
                 Datum::MzAclItem(MzAclItem {
@@ -3466,6 +3563,32 @@ impl ScalarType {
             ScalarType::Range { .. } => Box::new((*RANGE).iter()),
             ScalarType::MzAclItem { .. } => Box::new((*MZACLITEM).iter()),
             ScalarType::AclItem { .. } => Box::new((*ACLITEM).iter()),
+            ScalarType::MultiRange { .. } => Box::new((*MULTIRANGE).iter()),
+            ScalarType::Bit { length: None } | ScalarType::VarBit { max_length: None } => {
+                Box::new((*BIT).iter())
+            }
+            // Only yield bit strings that are valid for the declared length:
+            // exactly that length for `bit(n)`, at most it for `varbit(n)`.
+            ScalarType::Bit {
+                length: Some(length),
+            } => {
+                let length = usize::cast_from(length.into_u32());
+                Box::new(
+                    (*BIT)
+                        .iter()
+                        .filter(move |d| BitString::decode(d.unwrap_bytes()).len() == length),
+                )
+            }
+            ScalarType::VarBit {
+                max_length: Some(max_length),
+            } => {
+                let max_length = usize::cast_from(max_length.into_u32());
+                Box::new(
+                    (*BIT)
+                        .iter()
+                        .filter(move |d| BitString::decode(d.unwrap_bytes()).len() <= max_length),
+                )
+            }
         };
 
         iter
@@ -3515,6 +3638,10 @@ impl ScalarType {
                 length: Some(CharLength(1)),
             },
             ScalarType::VarChar { max_length: None },
+            ScalarType::Bit {
+                length: Some(BitLength::ONE),
+            },
+            ScalarType::VarBit { max_length: None },
             ScalarType::Jsonb,
             ScalarType::Uuid,
             ScalarType::Oid,
@@ -3543,6 +3670,9 @@ impl ScalarType {
             ScalarType::Range {
                 element_type: todo!(),
             }
+            ScalarType::MultiRange {
+                element_type: todo!(),
+            }
             */
         ]
     }
@@ -3574,6 +3704,8 @@ impl ScalarType {
             | ScalarType::Bytes
             | ScalarType::String
             | ScalarType::VarChar { .. }
+            | ScalarType::Bit { .. }
+            | ScalarType::VarBit { .. }
             | ScalarType::Jsonb
             | ScalarType::Uuid
             | ScalarType::Record { .. }
@@ -3584,6 +3716,7 @@ impl ScalarType {
             | ScalarType::Int2Vector
             | ScalarType::MzTimestamp
             | ScalarType::Range { .. }
+            | ScalarType::MultiRange { .. }
             | ScalarType::MzAclItem { .. }) => Ok(t),
 
             ScalarType::Array(elem) => Ok(elem.array_of_self_elem_type()?),
@@ -3637,6 +3770,12 @@ impl Arbitrary for ScalarType {
             any::<Option<VarCharMaxLength>>()
                 .prop_map(|max_length| ScalarType::VarChar { max_length })
                 .boxed(),
+            any::<Option<BitLength>>()
+                .prop_map(|length| ScalarType::Bit { length })
+                .boxed(),
+            any::<Option<BitLength>>()
+                .prop_map(|max_length| ScalarType::VarBit { max_length })
+                .boxed(),
             Just(ScalarType::PgLegacyName).boxed(),
             Just(ScalarType::Jsonb).boxed(),
             Just(ScalarType::Uuid).boxed(),
@@ -3668,6 +3807,12 @@ impl Arbitrary for ScalarType {
                 .prop_map(|precision| ScalarType::TimestampTz { precision })
                 .boxed(),
         ]);
+        let multirange = range_leaf
+            .clone()
+            .prop_map(|inner_type| ScalarType::MultiRange {
+                element_type: Box::new(inner_type),
+            })
+            .boxed();
         let range = range_leaf
             .prop_map(|inner_type| ScalarType::Range {
                 element_type: Box::new(inner_type),
@@ -3680,7 +3825,7 @@ impl Arbitrary for ScalarType {
             .prop_map(|inner_type| ScalarType::Array(Box::new(inner_type)))
             .boxed();
 
-        let leaf = Union::new_weighted(vec![(30, leaf), (1, array), (1, range)]);
+        let leaf = Union::new_weighted(vec![(30, leaf), (1, array), (1, range), (1, multirange)]);
 
         leaf.prop_recursive(2, 3, 5, |inner| {
             Union::new(vec![
@@ -3924,6 +4069,17 @@ pub fn arb_datum_for_scalar(scalar_type: ScalarType) -> impl Strategy<Value = Pr
         ScalarType::Bytes => prop::collection::vec(any::<u8>(), 300)
             .prop_map(PropDatum::Bytes)
             .boxed(),
+        ScalarType::Bit { length } | ScalarType::VarBit { max_length: length } => {
+            let len = length.map_or(300, |l| usize::cast_from(l.into_u32()));
+            // Fixed-length bit strings always have exactly their length.
+            let min_len = match scalar_type {
+                ScalarType::Bit { .. } => len,
+                _ => 0,
+            };
+            prop::collection::vec(any::<bool>(), min_len..=len)
+                .prop_map(|bits| PropDatum::Bytes(BitString::from_bits(bits).encode()))
+                .boxed()
+        }
         ScalarType::Date => arb_date().prop_map(PropDatum::Date).boxed(),
         ScalarType::Time => add_arb_duration(chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap())
             .prop_map(PropDatum::Time)
@@ -3948,6 +4104,25 @@ pub fn arb_datum_for_scalar(scalar_type: ScalarType) -> impl Strategy<Value = Pr
             );
             arb_range(data_strat).prop_map(PropDatum::Range).boxed()
         }
+        ScalarType::MultiRange { element_type } => {
+            let data_strat = (
+                arb_datum_for_scalar(*element_type.clone()),
+                arb_datum_for_scalar(*element_type),
+            );
+            // A single non-empty range is always a normalized multirange.
+            arb_range(data_strat)
+                .prop_map(|range| {
+                    let range = PropDatum::Range(range);
+                    let elements = match Datum::from(&range) {
+                        Datum::Range(r) if r.inner.is_none() => vec![],
+                        _ => vec![range],
+                    };
+                    let mut row = Row::default();
+                    row.packer().push_list(elements.iter().map(Datum::from));
+                    PropDatum::List(PropList(row, elements))
+                })
+                .boxed()
+        }
         ScalarType::List { element_type, .. } => arb_list(arb_datum_for_scalar(*element_type))
             .prop_map(PropDatum::List)
             .boxed(),
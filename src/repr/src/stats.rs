@@ -144,6 +144,16 @@ pub fn col_values<'a>(
         (ScalarType::Int64, ColumnStatKinds::Primitive(I64(stats))) => {
             map_stats(stats, Datum::Int64)
         }
+        // Integer columns may have been widened since these stats were written.
+        (ScalarType::Int32, ColumnStatKinds::Primitive(I16(stats))) => {
+            map_stats(stats, |x| Datum::Int32(x.into()))
+        }
+        (ScalarType::Int64, ColumnStatKinds::Primitive(I16(stats))) => {
+            map_stats(stats, |x| Datum::Int64(x.into()))
+        }
+        (ScalarType::Int64, ColumnStatKinds::Primitive(I32(stats))) => {
+            map_stats(stats, |x| Datum::Int64(x.into()))
+        }
         (ScalarType::Float32, ColumnStatKinds::Primitive(F32(stats))) => {
             map_stats(stats, |x| Datum::Float32(OrderedFloat(x)))
         }
@@ -172,9 +182,10 @@ pub fn col_values<'a>(
             | ScalarType::VarChar { .. },
             ColumnStatKinds::Primitive(String(stats)),
         ) => map_stats(stats, Datum::String),
-        (ScalarType::Bytes, ColumnStatKinds::Bytes(BytesStats::Primitive(stats))) => {
-            Some((Datum::Bytes(&stats.lower), Datum::Bytes(&stats.upper)))
-        }
+        (
+            ScalarType::Bytes | ScalarType::Bit { .. } | ScalarType::VarBit { .. },
+            ColumnStatKinds::Bytes(BytesStats::Primitive(stats)),
+        ) => Some((Datum::Bytes(&stats.lower), Datum::Bytes(&stats.upper))),
         (ScalarType::Date, ColumnStatKinds::Primitive(I32(stats))) => {
             let lower = soft_expect_or_log(Date::from_pg_epoch(stats.lower))?;
             let upper = soft_expect_or_log(Date::from_pg_epoch(stats.upper))?;
@@ -229,6 +240,7 @@ pub fn col_values<'a>(
             ScalarType::AclItem
             | ScalarType::MzAclItem
             | ScalarType::Range { .. }
+            | ScalarType::MultiRange { .. }
             | ScalarType::Array(_)
             | ScalarType::Map { .. }
             | ScalarType::List { .. }
@@ -50,6 +50,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::adt::array::ArrayDimension;
+use crate::adt::bit::BitString;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
 use crate::adt::interval::Interval;
@@ -729,6 +730,19 @@ where
     write!(buf, "{:#}", jsonb)
 }
 
+pub fn parse_bit(s: &str) -> Result<BitString, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("bit", s).with_details(e))
+}
+
+pub fn format_bit<F>(buf: &mut F, bits: &BitString) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{}", bits);
+    Nestable::Yes
+}
+
 pub fn parse_uuid(s: &str) -> Result<Uuid, ParseError> {
     s.trim()
         .parse()
@@ -1595,6 +1609,94 @@ where
     }
 }
 
+/// Parses a multirange, i.e. a brace-enclosed, comma-separated list of
+/// ranges, e.g. `{[1,3), [5,7)}`.
+///
+/// The returned ranges are neither canonicalized nor normalized.
+pub fn parse_multirange<'a, V, E>(
+    s: &'a str,
+    mut gen_elem: impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Vec<Range<V>>, ParseError>
+where
+    E: ToString,
+{
+    parse_multirange_inner(s, &mut gen_elem)
+        .map_err(|details| ParseError::invalid_input_syntax("multirange", s).with_details(details))
+}
+
+fn parse_multirange_inner<'a, V, E>(
+    s: &'a str,
+    gen_elem: &mut impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Vec<Range<V>>, String>
+where
+    E: ToString,
+{
+    let buf = &mut LexBuf::new(s);
+    let mut ranges = vec![];
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    if !buf.consume('{') {
+        bail!("Missing left brace.");
+    }
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    if !buf.consume('}') {
+        loop {
+            let start = buf.pos();
+            if !buf.consume_str("empty") {
+                buf.take_while(|ch| !matches!(ch, ']' | ')' | '}'));
+                if !matches!(buf.next(), Some(']' | ')')) {
+                    bail!("Expected range start.");
+                }
+            }
+            let range = &s[start..buf.pos()];
+            ranges.push(Range {
+                inner: parse_range_inner(range, &mut *gen_elem)?,
+            });
+
+            buf.take_while(|ch| ch.is_ascii_whitespace());
+
+            match buf.next() {
+                Some(',') => buf.take_while(|ch| ch.is_ascii_whitespace()),
+                Some('}') => break,
+                _ => bail!("Expected comma or end of multirange."),
+            };
+        }
+    }
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+
+    if buf.next().is_some() {
+        bail!("Junk after closing right brace.")
+    }
+
+    Ok(ranges)
+}
+
+/// Writes a multirange to `buf`, using `format_range` to write each of its
+/// ranges.
+pub fn format_multirange<F, T, E>(
+    buf: &mut F,
+    ranges: impl IntoIterator<Item = T>,
+    mut format_range: impl FnMut(&mut F, T) -> Result<Nestable, E>,
+) -> Result<Nestable, E>
+where
+    F: FormatBuffer,
+{
+    buf.write_char('{');
+    let mut ranges = ranges.into_iter().peekable();
+    while let Some(range) = ranges.next() {
+        format_range(buf, range)?;
+        if ranges.peek().is_some() {
+            buf.write_char(',');
+        }
+    }
+    buf.write_char('}');
+    Ok(Nestable::MayNeedEscaping)
+}
+
 pub fn format_array<F, T, E>(
     buf: &mut F,
     dims: &[ArrayDimension],
@@ -29,7 +29,8 @@ use mz_ore::error::ErrorExt;
 use mz_ore::netio::AsyncReady;
 use mz_ore::option::OptionExt;
 use mz_ore::task::JoinSetExt;
-use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod};
+use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod, SslVerifyMode};
+use openssl::x509::X509Name;
 use proxy_header::{ParseConfig, ProxiedAddress, ProxyHeader};
 use schemars::JsonSchema;
 use scopeguard::ScopeGuard;
@@ -425,6 +426,13 @@ pub struct TlsCertConfig {
     pub cert: PathBuf,
     /// The path to the TLS key.
     pub key: PathBuf,
+    /// The path to a CA certificate bundle used to verify client certificates.
+    ///
+    /// If set, clients are asked to present a certificate during the TLS
+    /// handshake, and any certificate they present must chain to one of these
+    /// CAs. Presenting a certificate is not itself required; that is up to the
+    /// authenticator.
+    pub client_ca: Option<PathBuf>,
 }
 
 impl TlsCertConfig {
@@ -439,6 +447,14 @@ impl TlsCertConfig {
         let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
         builder.set_certificate_chain_file(&self.cert)?;
         builder.set_private_key_file(&self.key, SslFiletype::PEM)?;
+        if let Some(client_ca) = &self.client_ca {
+            builder.set_ca_file(client_ca)?;
+            builder.set_client_ca_list(X509Name::load_client_ca_file(client_ca)?);
+            builder.set_verify(SslVerifyMode::PEER);
+            // Required for session resumption when client certificates are
+            // verified.
+            builder.set_session_id_context(b"materialize")?;
+        }
         Ok(builder.build().into_context())
     }
 
@@ -555,6 +571,16 @@ pub struct TlsCliArgs {
         value_name = "PATH"
     )]
     tls_key: Option<PathBuf>,
+    /// CA certificate bundle used to verify TLS client certificates.
+    ///
+    /// Required to use certificate authentication.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_CA",
+        requires = "tls_cert",
+        value_name = "PATH"
+    )]
+    tls_client_ca: Option<PathBuf>,
 }
 
 impl TlsCliArgs {
@@ -567,11 +593,18 @@ impl TlsCliArgs {
             if self.tls_key.is_some() {
                 bail!("cannot specify --tls-mode=disable and --tls-key simultaneously");
             }
+            if self.tls_client_ca.is_some() {
+                bail!("cannot specify --tls-mode=disable and --tls-client-ca simultaneously");
+            }
             Ok(None)
         } else {
             let cert = self.tls_cert.unwrap();
             let key = self.tls_key.unwrap();
-            Ok(Some(TlsCertConfig { cert, key }))
+            Ok(Some(TlsCertConfig {
+                cert,
+                key,
+                client_ca: self.tls_client_ca,
+            }))
         }
     }
 }
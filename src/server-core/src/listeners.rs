@@ -19,6 +19,12 @@ pub enum AuthenticatorKind {
     Frontegg,
     /// Authenticate users using internally stored password hashes.
     Password,
+    /// Authenticate users by their TLS client certificate, mapping certificate
+    /// identities to roles.
+    Certificate,
+    /// Authenticate users by an OpenID Connect (OIDC) JWT, presented as their
+    /// password or as a bearer token.
+    Oidc,
     /// Do not authenticate users. Trust they are who they say they are without verification.
     #[default]
     None,
@@ -55,9 +61,12 @@ pub struct HttpRoutesEnabled {
 pub struct ListenersConfig {
     pub sql: BTreeMap<String, SqlListenerConfig>,
     pub http: BTreeMap<String, HttpListenerConfig>,
+    /// Arrow Flight SQL listeners.
+    #[serde(default)]
+    pub flight: BTreeMap<String, FlightListenerConfig>,
 }
 
-/// Base configuration used by both SQL and HTTP listeners.
+/// Base configuration used by SQL, HTTP, and Arrow Flight SQL listeners.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BaseListenerConfig {
     /// The IP address and port to listen for connections on.
@@ -67,6 +76,7 @@ pub struct BaseListenerConfig {
     pub enable_tls: bool,
 }
 pub type SqlListenerConfig = BaseListenerConfig;
+pub type FlightListenerConfig = BaseListenerConfig;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HttpListenerConfig {
@@ -1783,6 +1783,8 @@ impl_display_t!(ColumnOption);
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ColumnVersioned {
     Added,
+    Dropped,
+    Altered,
 }
 
 impl AstDisplay for ColumnVersioned {
@@ -1791,8 +1793,9 @@ impl AstDisplay for ColumnVersioned {
         W: fmt::Write,
     {
         match self {
-            // TODO(alter_table): Support dropped columns.
             ColumnVersioned::Added => f.write_str("ADDED"),
+            ColumnVersioned::Dropped => f.write_str("DROPPED"),
+            ColumnVersioned::Altered => f.write_str("ALTERED"),
         }
     }
 }
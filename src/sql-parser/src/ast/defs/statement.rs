@@ -27,13 +27,13 @@ use smallvec::{SmallVec, smallvec};
 
 use crate::ast::display::{self, AstDisplay, AstFormatter, WithOptionName};
 use crate::ast::{
-    AstInfo, ColumnDef, ConnectionOption, ConnectionOptionName, ContinualTaskOption,
-    CreateConnectionOption, CreateConnectionType, CreateSinkConnection, CreateSourceConnection,
-    CreateSourceOption, CreateSourceOptionName, CteMutRecColumnDef, DeferredItemName, Expr, Format,
-    FormatSpecifier, Ident, IntervalValue, KeyConstraint, MaterializedViewOption, Query,
-    SelectItem, SinkEnvelope, SourceEnvelope, SourceIncludeMetadata, SubscribeOutput, TableAlias,
-    TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName,
-    UnresolvedObjectName, UnresolvedSchemaName, Value,
+    AstInfo, ColumnDef, ColumnOptionDef, ConnectionOption, ConnectionOptionName,
+    ContinualTaskOption, CreateConnectionOption, CreateConnectionType, CreateSinkConnection,
+    CreateSourceConnection, CreateSourceOption, CreateSourceOptionName, CteMutRecColumnDef,
+    DeferredItemName, Expr, Format, FormatSpecifier, Ident, IntervalValue, KeyConstraint,
+    MaterializedViewOption, Query, SelectItem, SinkEnvelope, SourceEnvelope, SourceIncludeMetadata,
+    SubscribeOutput, TableAlias, TableConstraint, TableWithJoins, UnresolvedDatabaseName,
+    UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
@@ -64,6 +64,7 @@ pub enum Statement<T: AstInfo> {
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
+    CreateEndpoint(CreateEndpointStatement<T>),
     CreateNetworkPolicy(CreateNetworkPolicyStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
@@ -82,9 +83,12 @@ pub enum Statement<T: AstInfo> {
     AlterNetworkPolicy(AlterNetworkPolicyStatement<T>),
     AlterRole(AlterRoleStatement<T>),
     AlterTableAddColumn(AlterTableAddColumnStatement<T>),
+    AlterTableAlterColumnType(AlterTableAlterColumnTypeStatement<T>),
+    AlterTableDropColumn(AlterTableDropColumnStatement),
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
+    DropSubscription(DropSubscriptionStatement),
     SetVariable(SetVariableStatement),
     ResetVariable(ResetVariableStatement),
     Show(ShowStatement<T>),
@@ -103,8 +107,12 @@ pub enum Statement<T: AstInfo> {
     Close(CloseStatement),
     Prepare(PrepareStatement<T>),
     Execute(ExecuteStatement<T>),
+    ExecuteEndpoint(ExecuteEndpointStatement<T>),
     Deallocate(DeallocateStatement),
     Raise(RaiseStatement),
+    Listen(ListenStatement<T>),
+    Unlisten(UnlistenStatement),
+    Notify(NotifyStatement),
     GrantRole(GrantRoleStatement<T>),
     RevokeRole(RevokeRoleStatement<T>),
     GrantPrivileges(GrantPrivilegesStatement<T>),
@@ -138,6 +146,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateIndex(stmt) => f.write_node(stmt),
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
+            Statement::CreateEndpoint(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
@@ -159,9 +168,12 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterConnection(stmt) => f.write_node(stmt),
             Statement::AlterRole(stmt) => f.write_node(stmt),
             Statement::AlterTableAddColumn(stmt) => f.write_node(stmt),
+            Statement::AlterTableAlterColumnType(stmt) => f.write_node(stmt),
+            Statement::AlterTableDropColumn(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
+            Statement::DropSubscription(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::Show(stmt) => f.write_node(stmt),
@@ -180,8 +192,12 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Fetch(stmt) => f.write_node(stmt),
             Statement::Prepare(stmt) => f.write_node(stmt),
             Statement::Execute(stmt) => f.write_node(stmt),
+            Statement::ExecuteEndpoint(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
+            Statement::Listen(stmt) => f.write_node(stmt),
+            Statement::Unlisten(stmt) => f.write_node(stmt),
+            Statement::Notify(stmt) => f.write_node(stmt),
             Statement::GrantRole(stmt) => f.write_node(stmt),
             Statement::RevokeRole(stmt) => f.write_node(stmt),
             Statement::GrantPrivileges(stmt) => f.write_node(stmt),
@@ -221,6 +237,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
         StatementKind::CreateSecret => "create_secret",
+        StatementKind::CreateEndpoint => "create_endpoint",
         StatementKind::CreateNetworkPolicy => "create_network_policy",
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
@@ -239,9 +256,12 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterOwner => "alter_owner",
         StatementKind::AlterConnection => "alter_connection",
         StatementKind::AlterTableAddColumn => "alter_table",
+        StatementKind::AlterTableAlterColumnType => "alter_table",
+        StatementKind::AlterTableDropColumn => "alter_table",
         StatementKind::Discard => "discard",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
+        StatementKind::DropSubscription => "drop_subscription",
         StatementKind::SetVariable => "set_variable",
         StatementKind::ResetVariable => "reset_variable",
         StatementKind::Show => "show",
@@ -260,8 +280,12 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Close => "close",
         StatementKind::Prepare => "prepare",
         StatementKind::Execute => "execute",
+        StatementKind::ExecuteEndpoint => "execute_endpoint",
         StatementKind::Deallocate => "deallocate",
         StatementKind::Raise => "raise",
+        StatementKind::Listen => "listen",
+        StatementKind::Unlisten => "unlisten",
+        StatementKind::Notify => "notify",
         StatementKind::GrantRole => "grant_role",
         StatementKind::RevokeRole => "revoke_role",
         StatementKind::GrantPrivileges => "grant_privileges",
@@ -2126,7 +2150,29 @@ impl<T: AstInfo> AstDisplay for CreateSecretStatement<T> {
 }
 impl_display_t!(CreateSecretStatement);
 
-/// `CREATE TYPE ..`
+/// A `CREATE ENDPOINT` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateEndpointStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    /// The query that the endpoint executes, which may reference parameters.
+    pub query: Query<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateEndpointStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE ENDPOINT ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" AS ");
+        f.write_node(&self.query);
+    }
+}
+impl_display_t!(CreateEndpointStatement);
+
+/// `CREATE TYPE ..` or `CREATE DOMAIN ..`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTypeStatement<T: AstInfo> {
     /// Name of the created type.
@@ -2137,6 +2183,17 @@ pub struct CreateTypeStatement<T: AstInfo> {
 
 impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let CreateTypeAs::Domain { data_type, options } = &self.as_type {
+            f.write_str("CREATE DOMAIN ");
+            f.write_node(&self.name);
+            f.write_str(" AS ");
+            f.write_node(data_type);
+            for option in options {
+                f.write_str(" ");
+                f.write_node(option);
+            }
+            return;
+        }
         f.write_str("CREATE TYPE ");
         f.write_node(&self.name);
         f.write_str(" AS ");
@@ -2164,6 +2221,7 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
                 }
                 f.write_str(")");
             }
+            CreateTypeAs::Domain { .. } => unreachable!("handled above"),
         };
     }
 }
@@ -2586,6 +2644,12 @@ pub enum CreateTypeAs<T: AstInfo> {
     Record {
         column_defs: Vec<ColumnDef<T>>,
     },
+    /// A domain over `data_type`, restricted by `options`, which may only be
+    /// `NOT NULL`, `NULL`, or `CHECK` constraints.
+    Domain {
+        data_type: T::DataType,
+        options: Vec<ColumnOptionDef<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
@@ -2594,6 +2658,7 @@ impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
             CreateTypeAs::List { .. } => f.write_str("LIST "),
             CreateTypeAs::Map { .. } => f.write_str("MAP "),
             CreateTypeAs::Record { .. } => f.write_str("RECORD "),
+            CreateTypeAs::Domain { .. } => f.write_str("DOMAIN "),
         }
     }
 }
@@ -3183,6 +3248,65 @@ impl<T: AstInfo> AstDisplay for AlterTableAddColumnStatement<T> {
 
 impl_display_t!(AlterTableAddColumnStatement);
 
+/// `ALTER TABLE ... ALTER COLUMN ... [SET DATA] TYPE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableAlterColumnTypeStatement<T: AstInfo> {
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub column_name: Ident,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableAlterColumnTypeStatement<T> {
+    fn fmt<W>(&self, f: &mut AstFormatter<W>)
+    where
+        W: fmt::Write,
+    {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+
+        f.write_str(" ALTER COLUMN ");
+        f.write_node(&self.column_name);
+        f.write_str(" TYPE ");
+        f.write_node(&self.data_type);
+    }
+}
+
+impl_display_t!(AlterTableAlterColumnTypeStatement);
+
+/// `ALTER TABLE ... DROP COLUMN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableDropColumnStatement {
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub if_col_exists: bool,
+    pub column_name: Ident,
+}
+
+impl AstDisplay for AlterTableDropColumnStatement {
+    fn fmt<W>(&self, f: &mut AstFormatter<W>)
+    where
+        W: fmt::Write,
+    {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+
+        f.write_str(" DROP COLUMN ");
+        if self.if_col_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.column_name);
+    }
+}
+
+impl_display!(AlterTableDropColumnStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DiscardStatement {
     pub target: DiscardTarget,
@@ -3277,6 +3401,26 @@ impl<T: AstInfo> AstDisplay for DropOwnedStatement<T> {
 }
 impl_display_t!(DropOwnedStatement);
 
+/// `DROP SUBSCRIPTION ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropSubscriptionStatement {
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+    /// The name of the durable subscription to drop.
+    pub name: Ident,
+}
+
+impl AstDisplay for DropSubscriptionStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP SUBSCRIPTION ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+    }
+}
+impl_display!(DropSubscriptionStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct QualifiedReplica {
     pub cluster: Ident,
@@ -3567,6 +3711,28 @@ impl<T: AstInfo> AstDisplay for ShowColumnsStatement<T> {
 }
 impl_display_t!(ShowColumnsStatement);
 
+/// `SHOW HISTORY RETENTION [FROM <schema>]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ShowHistoryRetentionStatement<T: AstInfo> {
+    pub from: Option<T::SchemaName>,
+    pub filter: Option<ShowStatementFilter<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ShowHistoryRetentionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("SHOW HISTORY RETENTION");
+        if let Some(from) = &self.from {
+            f.write_str(" FROM ");
+            f.write_node(from);
+        }
+        if let Some(filter) = &self.filter {
+            f.write_str(" ");
+            f.write_node(filter);
+        }
+    }
+}
+impl_display_t!(ShowHistoryRetentionStatement);
+
 /// `SHOW [REDACTED] CREATE VIEW <view>`
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ShowCreateViewStatement<T: AstInfo> {
@@ -3786,6 +3952,7 @@ impl_display!(RollbackStatement);
 pub enum SubscribeOptionName {
     Snapshot,
     Progress,
+    Name,
 }
 
 impl AstDisplay for SubscribeOptionName {
@@ -3793,6 +3960,7 @@ impl AstDisplay for SubscribeOptionName {
         match self {
             SubscribeOptionName::Snapshot => f.write_str("SNAPSHOT"),
             SubscribeOptionName::Progress => f.write_str("PROGRESS"),
+            SubscribeOptionName::Name => f.write_str("NAME"),
         }
     }
 }
@@ -3806,7 +3974,9 @@ impl WithOptionName for SubscribeOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            SubscribeOptionName::Snapshot | SubscribeOptionName::Progress => false,
+            SubscribeOptionName::Snapshot
+            | SubscribeOptionName::Progress
+            | SubscribeOptionName::Name => false,
         }
     }
 }
@@ -4153,6 +4323,7 @@ pub enum ObjectType {
     Subsource,
     ContinualTask,
     NetworkPolicy,
+    Endpoint,
 }
 
 impl ObjectType {
@@ -4169,7 +4340,8 @@ impl ObjectType {
             | ObjectType::Connection
             | ObjectType::Func
             | ObjectType::Subsource
-            | ObjectType::ContinualTask => true,
+            | ObjectType::ContinualTask
+            | ObjectType::Endpoint => true,
             ObjectType::Database
             | ObjectType::Schema
             | ObjectType::Cluster
@@ -4201,6 +4373,7 @@ impl AstDisplay for ObjectType {
             ObjectType::Subsource => "SUBSOURCE",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Endpoint => "ENDPOINT",
         })
     }
 }
@@ -4939,6 +5112,26 @@ impl<T: AstInfo> AstDisplay for ExecuteStatement<T> {
 }
 impl_display_t!(ExecuteStatement);
 
+/// `EXECUTE ENDPOINT ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExecuteEndpointStatement<T: AstInfo> {
+    pub name: T::ItemName,
+    pub params: Vec<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for ExecuteEndpointStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("EXECUTE ENDPOINT ");
+        f.write_node(&self.name);
+        if !self.params.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.params));
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(ExecuteEndpointStatement);
+
 /// `DEALLOCATE ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DeallocateStatement {
@@ -4970,6 +5163,69 @@ impl AstDisplay for RaiseStatement {
 }
 impl_display!(RaiseStatement);
 
+/// `LISTEN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenStatement<T: AstInfo> {
+    pub channel: Ident,
+    /// The relation whose changes are notified on the channel, if any.
+    pub relation: Option<T::ItemName>,
+}
+
+impl<T: AstInfo> AstDisplay for ListenStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LISTEN ");
+        f.write_node(&self.channel);
+        if let Some(relation) = &self.relation {
+            f.write_str(" ON ");
+            f.write_node(relation);
+        }
+    }
+}
+impl_display_t!(ListenStatement);
+
+/// `UNLISTEN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlistenStatement {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<Ident>,
+}
+
+impl AstDisplay for UnlistenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNLISTEN ");
+        match &self.channel {
+            Some(channel) => f.write_node(channel),
+            None => f.write_str("*"),
+        }
+    }
+}
+impl_display!(UnlistenStatement);
+
+/// `NOTIFY ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotifyStatement {
+    pub channel: Ident,
+    pub payload: Option<String>,
+}
+
+impl AstDisplay for NotifyStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("NOTIFY ");
+        f.write_node(&self.channel);
+        if let Some(payload) = &self.payload {
+            f.write_str(", ");
+            if f.redacted() {
+                f.write_str("'<REDACTED>'");
+            } else {
+                f.write_str("'");
+                f.write_node(&display::escape_single_quote_string(payload));
+                f.write_str("'");
+            }
+        }
+    }
+}
+impl_display!(NotifyStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NoticeSeverity {
     Debug,
@@ -5058,6 +5314,7 @@ impl_display_t!(AsOf);
 pub enum ShowStatement<T: AstInfo> {
     ShowObjects(ShowObjectsStatement<T>),
     ShowColumns(ShowColumnsStatement<T>),
+    ShowHistoryRetention(ShowHistoryRetentionStatement<T>),
     ShowCreateView(ShowCreateViewStatement<T>),
     ShowCreateMaterializedView(ShowCreateMaterializedViewStatement<T>),
     ShowCreateSource(ShowCreateSourceStatement<T>),
@@ -5075,6 +5332,7 @@ impl<T: AstInfo> AstDisplay for ShowStatement<T> {
         match self {
             ShowStatement::ShowObjects(stmt) => f.write_node(stmt),
             ShowStatement::ShowColumns(stmt) => f.write_node(stmt),
+            ShowStatement::ShowHistoryRetention(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateView(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateMaterializedView(stmt) => f.write_node(stmt),
             ShowStatement::ShowCreateSource(stmt) => f.write_node(stmt),
@@ -5140,6 +5398,7 @@ pub enum Privilege {
     CREATEDB,
     CREATECLUSTER,
     CREATENETWORKPOLICY,
+    EXECUTE,
 }
 
 impl AstDisplay for Privilege {
@@ -5155,6 +5414,7 @@ impl AstDisplay for Privilege {
             Privilege::CREATEDB => "CREATEDB",
             Privilege::CREATECLUSTER => "CREATECLUSTER",
             Privilege::CREATENETWORKPOLICY => "CREATENETWORKPOLICY",
+            Privilege::EXECUTE => "EXECUTE",
         });
     }
 }
@@ -179,6 +179,31 @@ pub fn parse_data_type(sql: &str) -> Result<RawDataType, ParserError> {
     }
 }
 
+/// Parses a string containing a single, possibly qualified, item name.
+pub fn parse_item_name(s: &str) -> Result<UnresolvedItemName, ParserError> {
+    let tokens = lexer::lex(s)?;
+    let mut parser = Parser::new(s, tokens);
+    let name = parser.parse_item_name()?;
+    if parser.next_token().is_some() {
+        parser_err!(parser, parser.peek_prev_pos(), "extra token after name")
+    } else {
+        Ok(name)
+    }
+}
+
+/// Parses a string containing a comma-separated list of possibly qualified
+/// item names, like the `publication_names` option of logical replication.
+pub fn parse_item_names(s: &str) -> Result<Vec<UnresolvedItemName>, ParserError> {
+    let tokens = lexer::lex(s)?;
+    let mut parser = Parser::new(s, tokens);
+    let names = parser.parse_comma_separated(Parser::parse_item_name)?;
+    if parser.next_token().is_some() {
+        parser_err!(parser, parser.peek_prev_pos(), "extra token after name")
+    } else {
+        Ok(names)
+    }
+}
+
 /// Parses a string containing a comma-separated list of identifiers and
 /// returns their underlying string values.
 ///
@@ -436,15 +461,37 @@ impl<'a> Parser<'a> {
                     Ok(self.parse_close().map_parser_err(StatementKind::Close)?)
                 }
                 Token::Keyword(PREPARE) => Ok(self.parse_prepare()?),
-                Token::Keyword(EXECUTE) => Ok(self
-                    .parse_execute()
-                    .map_parser_err(StatementKind::Execute)?),
+                Token::Keyword(EXECUTE) => {
+                    if self.peek_keyword(ENDPOINT)
+                        && matches!(
+                            self.peek_nth_token(1),
+                            Some(Token::Ident(_) | Token::Keyword(_))
+                        )
+                    {
+                        Ok(self
+                            .parse_execute_endpoint()
+                            .map_parser_err(StatementKind::ExecuteEndpoint)?)
+                    } else {
+                        Ok(self
+                            .parse_execute()
+                            .map_parser_err(StatementKind::Execute)?)
+                    }
+                }
                 Token::Keyword(DEALLOCATE) => Ok(self
                     .parse_deallocate()
                     .map_parser_err(StatementKind::Deallocate)?),
                 Token::Keyword(RAISE) => {
                     Ok(self.parse_raise().map_parser_err(StatementKind::Raise)?)
                 }
+                Token::Keyword(LISTEN) => {
+                    Ok(self.parse_listen().map_parser_err(StatementKind::Listen)?)
+                }
+                Token::Keyword(UNLISTEN) => Ok(self
+                    .parse_unlisten()
+                    .map_parser_err(StatementKind::Unlisten)?),
+                Token::Keyword(NOTIFY) => {
+                    Ok(self.parse_notify().map_parser_err(StatementKind::Notify)?)
+                }
                 Token::Keyword(GRANT) => Ok(self.parse_grant()?),
                 Token::Keyword(REVOKE) => Ok(self.parse_revoke()?),
                 Token::Keyword(REASSIGN) => Ok(self
@@ -1887,6 +1934,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(TYPE) {
             self.parse_create_type()
                 .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(DOMAIN) {
+            self.parse_create_domain()
+                .map_parser_err(StatementKind::CreateType)
         } else if self.peek_keyword(ROLE) {
             self.parse_create_role()
                 .map_parser_err(StatementKind::CreateRole)
@@ -1924,6 +1974,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(SECRET) {
             self.parse_create_secret()
                 .map_parser_err(StatementKind::CreateSecret)
+        } else if self.peek_keyword(ENDPOINT) {
+            self.parse_create_endpoint()
+                .map_parser_err(StatementKind::CreateEndpoint)
         } else if self.peek_keyword(CONNECTION) {
             self.parse_create_connection()
                 .map_parser_err(StatementKind::CreateConnection)
@@ -4243,6 +4296,19 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_endpoint(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(ENDPOINT)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_keyword(AS)?;
+        let query = self.parse_query()?;
+        Ok(Statement::CreateEndpoint(CreateEndpointStatement {
+            name,
+            if_not_exists,
+            query,
+        }))
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
@@ -4279,6 +4345,25 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_create_domain(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(DOMAIN)?;
+        let name = self.parse_item_name()?;
+        // For PostgreSQL compatibility, `AS` is optional.
+        let _ = self.parse_keyword(AS);
+        let data_type = self.parse_data_type()?;
+        let mut options = vec![];
+        loop {
+            match self.peek_token() {
+                None | Some(Token::Semicolon) => break,
+                _ => options.push(self.parse_column_option_def()?),
+            }
+        }
+        Ok(Statement::CreateType(CreateTypeStatement {
+            name,
+            as_type: CreateTypeAs::Domain { data_type, options },
+        }))
+    }
+
     fn parse_create_type_list_option(&mut self) -> Result<CreateTypeListOption<Raw>, ParserError> {
         self.expect_keywords(&[ELEMENT, TYPE])?;
         let name = CreateTypeListOptionName::ElementType;
@@ -4640,6 +4725,9 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.parse_keyword(SUBSCRIPTION) {
+            self.parse_drop_subscription()
+                .map_parser_err(StatementKind::DropSubscription)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
@@ -4714,7 +4802,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Type
             | ObjectType::Secret
             | ObjectType::Connection
-            | ObjectType::ContinualTask => {
+            | ObjectType::ContinualTask
+            | ObjectType::Endpoint => {
                 let names = self.parse_comma_separated(|parser| {
                     Ok(UnresolvedObjectName::Item(parser.parse_item_name()?))
                 })?;
@@ -4786,6 +4875,15 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_drop_subscription(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        Ok(Statement::DropSubscription(DropSubscriptionStatement {
+            if_exists,
+            name,
+        }))
+    }
+
     fn parse_cluster_replica_name(&mut self) -> Result<QualifiedReplica, ParserError> {
         let cluster = self.parse_identifier()?;
         self.expect_token(&Token::Dot)?;
@@ -5211,8 +5309,12 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::RParen)?;
             ColumnOption::Check(expr)
         } else if self.parse_keyword(VERSION) {
-            self.expect_keyword(ADDED)?;
-            let action = ColumnVersioned::Added;
+            let action = match self.expect_one_of_keywords(&[ADDED, DROPPED, ALTERED])? {
+                ADDED => ColumnVersioned::Added,
+                DROPPED => ColumnVersioned::Dropped,
+                ALTERED => ColumnVersioned::Altered,
+                _ => unreachable!(),
+            };
             let version = self.parse_version()?;
 
             ColumnOption::Versioned { action, version }
@@ -5423,7 +5525,7 @@ impl<'a> Parser<'a> {
             | ObjectType::MaterializedView
             | ObjectType::Table
             | ObjectType::ContinualTask => self.parse_alter_views(object_type),
-            ObjectType::Type => {
+            ObjectType::Type | ObjectType::Endpoint => {
                 let if_exists = self
                     .parse_if_exists()
                     .map_parser_err(StatementKind::AlterOwner)?;
@@ -6247,7 +6349,7 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
         let keywords = if object_type == ObjectType::Table {
-            [SET, RENAME, OWNER, RESET, ADD].as_slice()
+            [SET, RENAME, OWNER, RESET, ADD, ALTER, DROP].as_slice()
         } else {
             [SET, RENAME, OWNER, RESET].as_slice()
         };
@@ -6340,6 +6442,54 @@ impl<'a> Parser<'a> {
                     },
                 ))
             }
+            ALTER => {
+                assert_eq!(object_type, ObjectType::Table, "checked object_type above");
+
+                self.expect_keyword(COLUMN)
+                    .map_parser_err(StatementKind::AlterTableAlterColumnType)?;
+                let column_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterTableAlterColumnType)?;
+                if self.parse_keyword(SET) {
+                    self.expect_keyword(DATA)
+                        .map_parser_err(StatementKind::AlterTableAlterColumnType)?;
+                }
+                self.expect_keyword(TYPE)
+                    .map_parser_err(StatementKind::AlterTableAlterColumnType)?;
+                let data_type = self
+                    .parse_data_type()
+                    .map_parser_err(StatementKind::AlterTableAlterColumnType)?;
+
+                Ok(Statement::AlterTableAlterColumnType(
+                    AlterTableAlterColumnTypeStatement {
+                        if_exists,
+                        name,
+                        column_name,
+                        data_type,
+                    },
+                ))
+            }
+            DROP => {
+                assert_eq!(object_type, ObjectType::Table, "checked object_type above");
+
+                self.expect_keyword(COLUMN)
+                    .map_parser_err(StatementKind::AlterTableDropColumn)?;
+                let if_col_exists = self
+                    .parse_if_exists()
+                    .map_parser_err(StatementKind::AlterTableDropColumn)?;
+                let column_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterTableDropColumn)?;
+
+                Ok(Statement::AlterTableDropColumn(
+                    AlterTableDropColumnStatement {
+                        if_exists,
+                        name,
+                        if_col_exists,
+                        column_name,
+                    },
+                ))
+            }
             _ => unreachable!(),
         }
     }
@@ -6751,6 +6901,17 @@ impl<'a> Parser<'a> {
                         typ_mod: self.parse_typ_mod()?,
                     }
                 }
+                BIT => {
+                    let name = if self.parse_keyword(VARYING) {
+                        ident!("varbit")
+                    } else {
+                        ident!("bit")
+                    };
+                    RawDataType::Other {
+                        name: RawItemName::Name(UnresolvedItemName::unqualified(name)),
+                        typ_mod: self.parse_typ_mod()?,
+                    }
+                }
                 BPCHAR => RawDataType::Other {
                     name: RawItemName::Name(UnresolvedItemName::unqualified(ident!("bpchar"))),
                     typ_mod: self.parse_typ_mod()?,
@@ -7054,7 +7215,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Secret
             | ObjectType::Connection
             | ObjectType::Func
-            | ObjectType::ContinualTask => UnresolvedObjectName::Item(self.parse_item_name()?),
+            | ObjectType::ContinualTask
+            | ObjectType::Endpoint => UnresolvedObjectName::Item(self.parse_item_name()?),
             ObjectType::Role => UnresolvedObjectName::Role(self.parse_identifier()?),
             ObjectType::Cluster => UnresolvedObjectName::Cluster(self.parse_identifier()?),
             ObjectType::ClusterReplica => {
@@ -7787,6 +7949,18 @@ impl<'a> Parser<'a> {
                 from,
                 filter: self.parse_show_statement_filter()?,
             }))
+        } else if self.parse_keywords(&[HISTORY, RETENTION]) {
+            let from = if self.parse_keywords(&[FROM]) {
+                Some(self.parse_schema_name()?)
+            } else {
+                None
+            };
+            Ok(ShowStatement::ShowHistoryRetention(
+                ShowHistoryRetentionStatement {
+                    from,
+                    filter: self.parse_show_statement_filter()?,
+                },
+            ))
         } else if let Some(object_type) = self.parse_plural_object_type() {
             let from = if object_type.lives_in_schema() {
                 if self.parse_keywords(&[FROM]) {
@@ -7878,7 +8052,7 @@ impl<'a> Parser<'a> {
                         on_object,
                     }
                 }
-                ObjectType::Func => {
+                ObjectType::Func | ObjectType::Endpoint => {
                     return parser_err!(
                         self,
                         self.peek_prev_pos(),
@@ -8593,7 +8767,8 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_subscribe_option(&mut self) -> Result<SubscribeOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[PROGRESS, SNAPSHOT])? {
+        let name = match self.expect_one_of_keywords(&[NAME, PROGRESS, SNAPSHOT])? {
+            NAME => SubscribeOptionName::Name,
             PROGRESS => SubscribeOptionName::Progress,
             SNAPSHOT => SubscribeOptionName::Snapshot,
             _ => unreachable!(),
@@ -9052,6 +9227,24 @@ impl<'a> Parser<'a> {
         Ok(Statement::Execute(ExecuteStatement { name, params }))
     }
 
+    /// Parse an `EXECUTE ENDPOINT` statement, assuming that the `EXECUTE` token
+    /// has already been consumed.
+    fn parse_execute_endpoint(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(ENDPOINT)?;
+        let name = self.parse_raw_name()?;
+        let params = if self.consume_token(&Token::LParen) {
+            let params = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            params
+        } else {
+            Vec::new()
+        };
+        Ok(Statement::ExecuteEndpoint(ExecuteEndpointStatement {
+            name,
+            params,
+        }))
+    }
+
     /// Parse a `DEALLOCATE` statement, assuming that the `DEALLOCATE` token
     /// has already been consumed.
     fn parse_deallocate(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -9116,6 +9309,41 @@ impl<'a> Parser<'a> {
         Ok(Statement::Raise(RaiseStatement { severity }))
     }
 
+    /// Parse a `LISTEN` statement, assuming that the `LISTEN` token
+    /// has already been consumed.
+    fn parse_listen(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        let relation = if self.parse_keyword(ON) {
+            Some(self.parse_raw_name()?)
+        } else {
+            None
+        };
+        Ok(Statement::Listen(ListenStatement { channel, relation }))
+    }
+
+    /// Parse an `UNLISTEN` statement, assuming that the `UNLISTEN` token
+    /// has already been consumed.
+    fn parse_unlisten(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = if self.consume_token(&Token::Star) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::Unlisten(UnlistenStatement { channel }))
+    }
+
+    /// Parse a `NOTIFY` statement, assuming that the `NOTIFY` token
+    /// has already been consumed.
+    fn parse_notify(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        let payload = if self.consume_token(&Token::Comma) {
+            Some(self.parse_literal_string()?)
+        } else {
+            None
+        };
+        Ok(Statement::Notify(NotifyStatement { channel, payload }))
+    }
+
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
@@ -9323,7 +9551,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Connection
             | ObjectType::Database
             | ObjectType::Schema
-            | ObjectType::NetworkPolicy => Ok(object_type),
+            | ObjectType::NetworkPolicy
+            | ObjectType::Endpoint => Ok(object_type),
         }
     }
 
@@ -9348,6 +9577,7 @@ impl<'a> Parser<'a> {
                 FUNCTION,
                 CONTINUAL,
                 NETWORK,
+                ENDPOINT,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -9389,6 +9619,7 @@ impl<'a> Parser<'a> {
                     }
                     ObjectType::NetworkPolicy
                 }
+                ENDPOINT => ObjectType::Endpoint,
                 _ => unreachable!(),
             },
         )
@@ -9413,6 +9644,7 @@ impl<'a> Parser<'a> {
                 DATABASE,
                 SCHEMA,
                 FUNCTION,
+                ENDPOINT,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -9441,6 +9673,7 @@ impl<'a> Parser<'a> {
                 DATABASE => ObjectType::Database,
                 SCHEMA => ObjectType::Schema,
                 FUNCTION => ObjectType::Func,
+                ENDPOINT => ObjectType::Endpoint,
                 _ => unreachable!(),
             },
         )
@@ -9466,6 +9699,7 @@ impl<'a> Parser<'a> {
                 DATABASES,
                 SCHEMAS,
                 POLICIES,
+                ENDPOINTS,
             ])? {
                 TABLES => ObjectType::Table,
                 VIEWS => ObjectType::View,
@@ -9494,6 +9728,7 @@ impl<'a> Parser<'a> {
                 DATABASES => ObjectType::Database,
                 SCHEMAS => ObjectType::Schema,
                 POLICIES => ObjectType::NetworkPolicy,
+                ENDPOINTS => ObjectType::Endpoint,
                 _ => unreachable!(),
             },
         )
@@ -9521,6 +9756,7 @@ impl<'a> Parser<'a> {
                 SUBSOURCES,
                 CONTINUAL,
                 NETWORK,
+                ENDPOINTS,
             ])? {
                 TABLES => ObjectType::Table,
                 VIEWS => ObjectType::View,
@@ -9567,6 +9803,7 @@ impl<'a> Parser<'a> {
                         return None;
                     }
                 }
+                ENDPOINTS => ObjectType::Endpoint,
                 _ => unreachable!(),
             },
         )
@@ -9600,6 +9837,7 @@ impl<'a> Parser<'a> {
                 CONNECTIONS,
                 DATABASES,
                 SCHEMAS,
+                ENDPOINTS,
             ])? {
                 TABLES => ObjectType::Table,
                 TYPES => ObjectType::Type,
@@ -9608,6 +9846,7 @@ impl<'a> Parser<'a> {
                 CONNECTIONS => ObjectType::Connection,
                 DATABASES => ObjectType::Database,
                 SCHEMAS => ObjectType::Schema,
+                ENDPOINTS => ObjectType::Endpoint,
                 _ => unreachable!(),
             },
         )
@@ -9644,6 +9883,7 @@ impl<'a> Parser<'a> {
                 CONNECTIONS,
                 DATABASES,
                 SCHEMAS,
+                ENDPOINTS,
             ])? {
                 SYSTEM => SystemObjectType::System,
                 TABLES => SystemObjectType::Object(ObjectType::Table),
@@ -9653,6 +9893,7 @@ impl<'a> Parser<'a> {
                 CONNECTIONS => SystemObjectType::Object(ObjectType::Connection),
                 DATABASES => SystemObjectType::Object(ObjectType::Database),
                 SCHEMAS => SystemObjectType::Object(ObjectType::Schema),
+                ENDPOINTS => SystemObjectType::Object(ObjectType::Endpoint),
                 _ => unreachable!(),
             },
         )
@@ -9672,6 +9913,7 @@ impl<'a> Parser<'a> {
                 CREATEDB,
                 CREATECLUSTER,
                 CREATENETWORKPOLICY,
+                EXECUTE,
             ])? {
                 INSERT => Privilege::INSERT,
                 SELECT => Privilege::SELECT,
@@ -9683,6 +9925,7 @@ impl<'a> Parser<'a> {
                 CREATEDB => Privilege::CREATEDB,
                 CREATECLUSTER => Privilege::CREATECLUSTER,
                 CREATENETWORKPOLICY => Privilege::CREATENETWORKPOLICY,
+                EXECUTE => Privilege::EXECUTE,
                 _ => unreachable!(),
             },
         )
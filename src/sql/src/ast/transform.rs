@@ -18,10 +18,11 @@ use mz_sql_parser::ast::CreateTableFromSourceStatement;
 use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
-    AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    AstInfo, CreateConnectionStatement, CreateEndpointStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateSecretStatement, CreateSinkStatement,
+    CreateSourceStatement, CreateSubsourceStatement, CreateTableStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, Expr, Ident, Query, Raw, RawItemName, Statement,
+    UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -47,7 +48,8 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTableFromSource(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
-        | stmt @ Statement::CreateSecret(_) => {
+        | stmt @ Statement::CreateSecret(_)
+        | stmt @ Statement::CreateEndpoint(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
                 cur_schema,
@@ -151,6 +153,7 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
         | Statement::CreateTable(CreateTableStatement { name, .. })
         | Statement::CreateTableFromSource(CreateTableFromSourceStatement { name, .. })
         | Statement::CreateSecret(CreateSecretStatement { name, .. })
+        | Statement::CreateEndpoint(CreateEndpointStatement { name, .. })
         | Statement::CreateConnection(CreateConnectionStatement { name, .. })
         | Statement::CreateWebhookSource(CreateWebhookSourceStatement { name, .. }) => {
             // The last name in an ItemName is the item name. The item name
@@ -203,7 +206,8 @@ pub fn create_stmt_rename_refs(
             definition: ViewDefinition { query, .. },
             ..
         })
-        | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. }) => {
+        | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. })
+        | Statement::CreateEndpoint(CreateEndpointStatement { query, .. }) => {
             rewrite_query(from_name, to_item_name, query)?;
         }
         Statement::CreateSource(_)
@@ -52,7 +52,9 @@ use crate::names::{
 };
 use crate::plan::statement::StatementDesc;
 use crate::plan::statement::ddl::PlannedRoleAttributes;
-use crate::plan::{ClusterSchedule, CreateClusterPlan, PlanError, PlanNotice, query};
+use crate::plan::{
+    ClusterSchedule, CreateClusterPlan, EndpointQuery, PlanError, PlanNotice, query,
+};
 use crate::session::vars::{OwnedVarInput, SystemVars};
 
 /// A catalog keeps track of SQL objects and session state available to the
@@ -747,10 +749,18 @@ pub trait CatalogItem {
     /// catalog item is a table that accepts writes.
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]>;
 
+    /// Returns the domain type of each column, if the catalog item is a table
+    /// that accepts writes. Columns that are not of a domain type are `None`.
+    fn writable_table_domains(&self) -> Option<&[Option<CatalogItemId>]>;
+
     /// Returns the type information associated with the catalog item, if the
     /// catalog item is a type.
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>>;
 
+    /// Returns the prepared query associated with the catalog item, if the
+    /// catalog item is an endpoint.
+    fn endpoint_query(&self) -> Option<&EndpointQuery>;
+
     /// Returns the ID of the owning role.
     fn owner_id(&self) -> RoleId;
 
@@ -806,6 +816,8 @@ pub enum CatalogItemType {
     Connection,
     /// A continual task.
     ContinualTask,
+    /// An endpoint.
+    Endpoint,
 }
 
 impl CatalogItemType {
@@ -840,6 +852,7 @@ impl CatalogItemType {
             CatalogItemType::Secret => false,
             CatalogItemType::Connection => false,
             CatalogItemType::ContinualTask => true,
+            CatalogItemType::Endpoint => false,
         }
     }
 }
@@ -858,6 +871,7 @@ impl fmt::Display for CatalogItemType {
             CatalogItemType::Secret => f.write_str("secret"),
             CatalogItemType::Connection => f.write_str("connection"),
             CatalogItemType::ContinualTask => f.write_str("continual task"),
+            CatalogItemType::Endpoint => f.write_str("endpoint"),
         }
     }
 }
@@ -876,6 +890,7 @@ impl From<CatalogItemType> for ObjectType {
             CatalogItemType::Secret => ObjectType::Secret,
             CatalogItemType::Connection => ObjectType::Connection,
             CatalogItemType::ContinualTask => ObjectType::ContinualTask,
+            CatalogItemType::Endpoint => ObjectType::Endpoint,
         }
     }
 }
@@ -894,6 +909,7 @@ impl From<CatalogItemType> for mz_audit_log::ObjectType {
             CatalogItemType::Secret => mz_audit_log::ObjectType::Secret,
             CatalogItemType::Connection => mz_audit_log::ObjectType::Connection,
             CatalogItemType::ContinualTask => mz_audit_log::ObjectType::ContinualTask,
+            CatalogItemType::Endpoint => mz_audit_log::ObjectType::Endpoint,
         }
     }
 }
@@ -952,10 +968,17 @@ pub enum CatalogType<T: TypeReference> {
     Array {
         element_reference: T::Reference,
     },
+    Bit,
     Bool,
     Bytes,
     Char,
     Date,
+    Domain {
+        base_reference: T::Reference,
+        base_modifiers: Vec<i64>,
+        not_null: bool,
+        checks: Vec<CatalogDomainCheck>,
+    },
     Float32,
     Float64,
     Int16,
@@ -977,6 +1000,9 @@ pub enum CatalogType<T: TypeReference> {
         value_reference: T::Reference,
         value_modifiers: Vec<i64>,
     },
+    MultiRange {
+        element_reference: T::Reference,
+    },
     Numeric,
     Oid,
     PgLegacyChar,
@@ -996,6 +1022,7 @@ pub enum CatalogType<T: TypeReference> {
     Timestamp,
     TimestampTz,
     Uuid,
+    VarBit,
     VarChar,
     Int2Vector,
     MzAclItem,
@@ -1038,6 +1065,16 @@ pub struct CatalogRecordField<T: TypeReference> {
     pub type_modifiers: Vec<i64>,
 }
 
+/// A `CHECK` constraint on a [`CatalogType::Domain`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatalogDomainCheck {
+    /// The name of the constraint.
+    pub name: String,
+    /// The constraint's expression, in which `VALUE` refers to the value being
+    /// checked.
+    pub expr: Expr<Aug>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 /// Mirrored from [PostgreSQL's `typcategory`][typcategory].
 ///
@@ -1429,6 +1466,7 @@ pub enum ObjectType {
     Func,
     ContinualTask,
     NetworkPolicy,
+    Endpoint,
 }
 
 impl ObjectType {
@@ -1451,7 +1489,8 @@ impl ObjectType {
             | ObjectType::Cluster
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::NetworkPolicy => false,
+            | ObjectType::NetworkPolicy
+            | ObjectType::Endpoint => false,
         }
     }
 }
@@ -1477,6 +1516,7 @@ impl From<mz_sql_parser::ast::ObjectType> for ObjectType {
             mz_sql_parser::ast::ObjectType::Func => ObjectType::Func,
             mz_sql_parser::ast::ObjectType::ContinualTask => ObjectType::ContinualTask,
             mz_sql_parser::ast::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
+            mz_sql_parser::ast::ObjectType::Endpoint => ObjectType::Endpoint,
         }
     }
 }
@@ -1500,6 +1540,7 @@ impl From<CommentObjectId> for ObjectType {
             CommentObjectId::Cluster(_) => ObjectType::Cluster,
             CommentObjectId::ClusterReplica(_) => ObjectType::ClusterReplica,
             CommentObjectId::ContinualTask(_) => ObjectType::ContinualTask,
+            CommentObjectId::Endpoint(_) => ObjectType::Endpoint,
             CommentObjectId::NetworkPolicy(_) => ObjectType::NetworkPolicy,
         }
     }
@@ -1525,6 +1566,7 @@ impl Display for ObjectType {
             ObjectType::Func => "FUNCTION",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Endpoint => "ENDPOINT",
         })
     }
 }
@@ -17,6 +17,7 @@ use std::sync::LazyLock;
 
 use itertools::Itertools;
 use mz_expr::func;
+use mz_expr::jsonpath::JsonPathKind;
 use mz_ore::collections::CollectionExt;
 use mz_ore::str::StrExt;
 use mz_pgrepr::oid;
@@ -74,6 +75,7 @@ impl TypeCategory {
         match typ {
             ScalarType::Array(..) | ScalarType::Int2Vector => Self::Array,
             ScalarType::Bool => Self::Boolean,
+            ScalarType::Bit { .. } | ScalarType::VarBit { .. } => Self::BitString,
             ScalarType::AclItem
             | ScalarType::Bytes
             | ScalarType::Jsonb
@@ -112,7 +114,7 @@ impl TypeCategory {
             }
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
-            ScalarType::Range { .. } => Self::Range,
+            ScalarType::Range { .. } | ScalarType::MultiRange { .. } => Self::Range,
         }
     }
 
@@ -132,7 +134,10 @@ impl TypeCategory {
             | ParamType::MapAny
             | ParamType::MapAnyCompatible
             | ParamType::RecordAny => Self::Pseudo,
-            ParamType::RangeAnyCompatible | ParamType::RangeAny => Self::Range,
+            ParamType::RangeAnyCompatible
+            | ParamType::RangeAny
+            | ParamType::MultiRangeAny
+            | ParamType::MultiRangeAnyCompatible => Self::Range,
             ParamType::Plain(t) => Self::from_type(t),
         }
     }
@@ -148,11 +153,15 @@ impl TypeCategory {
         match catalog_type {
             CatalogType::Array { .. } | CatalogType::Int2Vector => Self::Array,
             CatalogType::Bool => Self::Boolean,
+            CatalogType::Bit { .. } | CatalogType::VarBit { .. } => Self::BitString,
             CatalogType::AclItem
             | CatalogType::Bytes
             | CatalogType::Jsonb
             | CatalogType::Uuid
             | CatalogType::MzAclItem => Self::UserDefined,
+            // The category of a domain is the category of its base type,
+            // which cannot be determined without access to the catalog.
+            CatalogType::Domain { .. } => Self::UserDefined,
             CatalogType::Date
             | CatalogType::Time
             | CatalogType::Timestamp
@@ -180,7 +189,7 @@ impl TypeCategory {
             CatalogType::Record { .. } => TypeCategory::Composite,
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
-            CatalogType::Range { .. } => Self::Range,
+            CatalogType::Range { .. } | CatalogType::MultiRange { .. } => Self::Range,
         }
     }
 
@@ -194,7 +203,6 @@ impl TypeCategory {
     pub fn preferred_type(&self) -> Option<ScalarType> {
         match self {
             Self::Array
-            | Self::BitString
             | Self::Composite
             | Self::Enum
             | Self::Geometric
@@ -204,6 +212,7 @@ impl TypeCategory {
             | Self::Range
             | Self::Unknown
             | Self::UserDefined => None,
+            Self::BitString => Some(ScalarType::VarBit { max_length: None }),
             Self::Boolean => Some(ScalarType::Bool),
             Self::DateTime => Some(ScalarType::TimestampTz { precision: None }),
             Self::Numeric => Some(ScalarType::Float64),
@@ -477,6 +486,53 @@ fn experimental_sql_impl_table_func(
     sql_impl_table_func_inner(sql, Some(feature))
 }
 
+/// Plans a call to one of the `jsonb_path_*` functions, filling in the
+/// optional `vars` and `silent` arguments when they are omitted.
+fn jsonb_path_func(kind: JsonPathKind, silent: bool) -> Operation<HirScalarExpr> {
+    Operation::variadic(move |_ecx, mut exprs| {
+        if exprs.len() < 3 {
+            exprs.push(HirScalarExpr::literal(
+                Datum::empty_map(),
+                ScalarType::Jsonb,
+            ));
+        }
+        if exprs.len() < 4 {
+            exprs.push(HirScalarExpr::literal(
+                Datum::from(silent),
+                ScalarType::Bool,
+            ));
+        }
+        Ok(HirScalarExpr::call_variadic(
+            VariadicFunc::JsonbPath { kind },
+            exprs,
+        ))
+    })
+}
+
+/// The parameters of a multirange constructor, which accepts any number of
+/// ranges over `element_type`.
+fn multirange_create_params(element_type: ScalarType) -> ParamList {
+    ParamList::Variadic {
+        leading: vec![],
+        trailing: ScalarType::Range {
+            element_type: Box::new(element_type),
+        }
+        .into(),
+    }
+}
+
+/// Plans a call to a multirange constructor.
+fn multirange_create(elem_type: ScalarType) -> Operation<HirScalarExpr> {
+    Operation::variadic(move |_ecx, exprs| {
+        Ok(HirScalarExpr::call_variadic(
+            VariadicFunc::MultiRangeCreate {
+                elem_type: elem_type.clone(),
+            },
+            exprs,
+        ))
+    })
+}
+
 /// Describes a single function's implementation.
 pub struct FuncImpl<R> {
     pub oid: u32,
@@ -722,6 +778,14 @@ pub enum ParamType {
     /// this type into generating non-existent range types (e.g. ranges of
     /// floats) that will panic.
     RangeAnyCompatible,
+    /// An pseudotype permitting any multirange type, requiring other
+    /// "Any"-type parameters to be of the same type.
+    MultiRangeAny,
+    /// A pseudotype permitting any multirange type, permitting other
+    /// "Compatibility"-type parameters to find the best common type.
+    ///
+    /// The same caveats as [`ParamType::RangeAnyCompatible`] apply.
+    MultiRangeAnyCompatible,
     /// A psuedotype indicating that the function is only meant to be called
     /// internally by the database system.
     Internal,
@@ -739,6 +803,7 @@ impl ParamType {
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             RangeAny | RangeAnyCompatible => matches!(t, Range { .. }),
+            MultiRangeAny | MultiRangeAnyCompatible => matches!(t, MultiRange { .. }),
             NonVecAny | NonVecAnyCompatible => !t.is_vec(),
             Internal => false,
             Plain(to) => typeconv::can_cast(ecx, CastContext::Implicit, t, to),
@@ -794,7 +859,9 @@ impl ParamType {
             // `PolymorphicCompatClass::StructuralEq`.
             | RecordAny
             | RangeAny
-            | RangeAnyCompatible => true,
+            | RangeAnyCompatible
+            | MultiRangeAny
+            | MultiRangeAnyCompatible => true,
             Any | Internal | Plain(_)  => false,
         }
     }
@@ -826,6 +893,8 @@ impl ParamType {
             ParamType::RecordAny => "record",
             ParamType::RangeAny => "anyrange",
             ParamType::RangeAnyCompatible => "anycompatiblerange",
+            ParamType::MultiRangeAny => "anymultirange",
+            ParamType::MultiRangeAnyCompatible => "anycompatiblemultirange",
         }
     }
 }
@@ -856,7 +925,7 @@ impl From<ScalarBaseType> for ParamType {
     fn from(s: ScalarBaseType) -> ParamType {
         use ScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record | Range => {
+            Array | List | Map | Record | Range | MultiRange => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             AclItem => ScalarType::AclItem,
@@ -879,6 +948,8 @@ impl From<ScalarBaseType> for ParamType {
             String => ScalarType::String,
             Char => ScalarType::Char { length: None },
             VarChar => ScalarType::VarChar { max_length: None },
+            Bit => ScalarType::Bit { length: None },
+            VarBit => ScalarType::VarBit { max_length: None },
             PgLegacyChar => ScalarType::PgLegacyChar,
             PgLegacyName => ScalarType::PgLegacyName,
             Jsonb => ScalarType::Jsonb,
@@ -1321,12 +1392,14 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny => {
+            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny | MultiRangeAny => {
                 PolymorphicCompatClass::Any
             }
-            ArrayAnyCompatible | AnyCompatible | RangeAnyCompatible | NonVecAnyCompatible => {
-                PolymorphicCompatClass::BestCommonAny
-            }
+            ArrayAnyCompatible
+            | AnyCompatible
+            | RangeAnyCompatible
+            | MultiRangeAnyCompatible
+            | NonVecAnyCompatible => PolymorphicCompatClass::BestCommonAny,
             ListAnyCompatible | ListElementAnyCompatible => PolymorphicCompatClass::BestCommonList,
             MapAnyCompatible => PolymorphicCompatClass::BestCommonMap,
             RecordAny => PolymorphicCompatClass::StructuralEq,
@@ -1395,6 +1468,7 @@ impl PolymorphicSolution {
             ListAny => seen.map_coerced(|array| array.unwrap_list_element_type().clone()),
             ArrayAny | ArrayAnyCompatible => seen.map_coerced(|array| array.unwrap_array_element_type().clone()),
             RangeAny | RangeAnyCompatible => seen.map_coerced(|range| range.unwrap_range_element_type().clone()),
+            MultiRangeAny | MultiRangeAnyCompatible => seen.map_coerced(|multirange| multirange.unwrap_multirange_element_type().clone()),
             ListElementAnyCompatible => seen.map_coerced(|el| ScalarType::List {
                 custom_id: None,
                 element_type: Box::new(el),
@@ -1528,6 +1602,11 @@ impl PolymorphicSolution {
             RangeAny | RangeAnyCompatible => self.key.as_ref().map(|key| ScalarType::Range {
                 element_type: Box::new(key.clone()),
             }),
+            MultiRangeAny | MultiRangeAnyCompatible => {
+                self.key.as_ref().map(|key| ScalarType::MultiRange {
+                    element_type: Box::new(key.clone()),
+                })
+            }
             ListElementAnyCompatible => self
                 .key
                 .as_ref()
@@ -1861,9 +1940,11 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(Interval) => Operation::nullary(|_ecx| catalog_name_only!("avg")) => Interval, 2106;
         },
         "bit_count" => Scalar {
+            params!(Bit) => UnaryFunc::BitCountBitString(func::BitCountBitString) => Int64, 6162;
             params!(Bytes) => UnaryFunc::BitCountBytes(func::BitCountBytes) => Int64, 6163;
         },
         "bit_length" => Scalar {
+            params!(Bit) => UnaryFunc::BitLengthBitString(func::BitLengthBitString) => Int32, 1812;
             params!(Bytes) => UnaryFunc::BitLengthBytes(func::BitLengthBytes) => Int32, 1810;
             params!(String) => UnaryFunc::BitLengthString(func::BitLengthString) => Int32, 1811;
         },
@@ -1953,6 +2034,15 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "cot" => Scalar {
             params!(Float64) => UnaryFunc::Cot(func::Cot) => Float64, 1607;
         },
+        "corr" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("corr")) => Float64, 2829;
+        },
+        "covar_pop" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("covar_pop")) => Float64, 2827;
+        },
+        "covar_samp" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("covar_samp")) => Float64, 2828;
+        },
         "current_schema" => Scalar {
             // TODO: this should be `name`. This is tricky in Materialize
             // because `name` truncates to 63 characters but Materialize does
@@ -2049,6 +2139,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     exprs))
             }) => ScalarType::Range { element_type: Box::new(ScalarType::Date)}, 3942;
         },
+        "datemultirange" => Scalar {
+            params!() => multirange_create(ScalarType::Date) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Date) }, oid::FUNC_DATEMULTIRANGE_NULLARY_OID;
+            multirange_create_params(ScalarType::Date) => multirange_create(ScalarType::Date) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Date) }, oid::FUNC_DATEMULTIRANGE_VARIADIC_OID;
+        },
         "degrees" => Scalar {
             params!(Float64) => UnaryFunc::Degrees(func::Degrees) => Float64, 1608;
         },
@@ -2079,6 +2173,7 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             ) => String, 1081;
         },
         "get_bit" => Scalar {
+            params!(Bit, Int32) => BinaryFunc::GetBitBitString => Int32, 3032;
             params!(Bytes, Int32) => BinaryFunc::GetBit => Int32, 723;
         },
         "get_byte" => Scalar {
@@ -2130,6 +2225,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     exprs))
             }) => ScalarType::Range { element_type: Box::new(ScalarType::Int32)}, 3841;
         },
+        "int4multirange" => Scalar {
+            params!() => multirange_create(ScalarType::Int32) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Int32) }, oid::FUNC_INT4MULTIRANGE_NULLARY_OID;
+            multirange_create_params(ScalarType::Int32) => multirange_create(ScalarType::Int32) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Int32) }, oid::FUNC_INT4MULTIRANGE_VARIADIC_OID;
+        },
         "int8range" => Scalar {
             params!(Int64, Int64) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(Datum::String("[)"), ScalarType::String));
@@ -2141,6 +2240,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     exprs))
                 }) => ScalarType::Range { element_type: Box::new(ScalarType::Int64)}, 3946;
         },
+        "int8multirange" => Scalar {
+            params!() => multirange_create(ScalarType::Int64) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Int64) }, oid::FUNC_INT8MULTIRANGE_NULLARY_OID;
+            multirange_create_params(ScalarType::Int64) => multirange_create(ScalarType::Int64) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Int64) }, oid::FUNC_INT8MULTIRANGE_VARIADIC_OID;
+        },
         "isempty" => Scalar {
             params!(RangeAny) => UnaryFunc::RangeEmpty(func::RangeEmpty) => Bool, 3850;
         },
@@ -2167,9 +2270,43 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     }).flatten().collect()))
             }) => Jsonb, 3273;
         },
+        "jsonb_insert" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => Operation::variadic(|_ecx, mut exprs| {
+                exprs.push(HirScalarExpr::literal_false());
+                Ok(HirScalarExpr::call_variadic(VariadicFunc::JsonbInsert, exprs))
+            }) => Jsonb, oid::FUNC_JSONB_INSERT_DEFAULT_AFTER_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbInsert => Jsonb, 3579;
+        },
+        "jsonb_path_exists" => Scalar {
+            params!(Jsonb, String) => jsonb_path_func(JsonPathKind::Exists, false) => Bool, oid::FUNC_JSONB_PATH_EXISTS_DEFAULT_VARS_OID;
+            params!(Jsonb, String, Jsonb) => jsonb_path_func(JsonPathKind::Exists, false) => Bool, oid::FUNC_JSONB_PATH_EXISTS_DEFAULT_SILENT_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPath { kind: JsonPathKind::Exists } => Bool, 4005;
+        },
+        "jsonb_path_match" => Scalar {
+            params!(Jsonb, String) => jsonb_path_func(JsonPathKind::Match, false) => Bool, oid::FUNC_JSONB_PATH_MATCH_DEFAULT_VARS_OID;
+            params!(Jsonb, String, Jsonb) => jsonb_path_func(JsonPathKind::Match, false) => Bool, oid::FUNC_JSONB_PATH_MATCH_DEFAULT_SILENT_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPath { kind: JsonPathKind::Match } => Bool, 4009;
+        },
+        "jsonb_path_query_array" => Scalar {
+            params!(Jsonb, String) => jsonb_path_func(JsonPathKind::QueryArray, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_ARRAY_DEFAULT_VARS_OID;
+            params!(Jsonb, String, Jsonb) => jsonb_path_func(JsonPathKind::QueryArray, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_ARRAY_DEFAULT_SILENT_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPath { kind: JsonPathKind::QueryArray } => Jsonb, 4007;
+        },
+        "jsonb_path_query_first" => Scalar {
+            params!(Jsonb, String) => jsonb_path_func(JsonPathKind::QueryFirst, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_DEFAULT_VARS_OID;
+            params!(Jsonb, String, Jsonb) => jsonb_path_func(JsonPathKind::QueryFirst, false) => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_DEFAULT_SILENT_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPath { kind: JsonPathKind::QueryFirst } => Jsonb, 4008;
+        },
         "jsonb_pretty" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbPretty(func::JsonbPretty) => String, 3306;
         },
+        "jsonb_set" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => Operation::variadic(|_ecx, mut exprs| {
+                exprs.push(HirScalarExpr::literal_true());
+                Ok(HirScalarExpr::call_variadic(VariadicFunc::JsonbSet, exprs))
+            }) => Jsonb, oid::FUNC_JSONB_SET_DEFAULT_CREATE_MISSING_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbSet => Jsonb, 3304;
+        },
         "jsonb_strip_nulls" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbStripNulls(func::JsonbStripNulls) => Jsonb, 3262;
         },
@@ -2189,6 +2326,7 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, Int32) => BinaryFunc::Left => String, 3060;
         },
         "length" => Scalar {
+            params!(Bit) => UnaryFunc::BitLengthBitString(func::BitLengthBitString) => Int32, 1681;
             params!(Bytes) => UnaryFunc::ByteLengthBytes(func::ByteLengthBytes) => Int32, 2010;
             // bpcharlen is redundant with automatic coercion to string, 1318.
             params!(String) => UnaryFunc::CharLength(func::CharLength) => Int32, 1317;
@@ -2255,6 +2393,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(UInt32, UInt32) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt32, oid::FUNC_MOD_UINT32_OID;
             params!(UInt64, UInt64) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt64, oid::FUNC_MOD_UINT64_OID;
         },
+        "multirange" => Scalar {
+            params!(RangeAny) => UnaryFunc::RangeToMultiRange(func::RangeToMultiRange) => MultiRangeAny, oid::FUNC_MULTIRANGE_OID;
+        },
         "now" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp => TimestampTz, 1299;
         },
@@ -2269,6 +2410,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     exprs))
             }) => ScalarType::Range { element_type: Box::new(ScalarType::Numeric { max_scale: None })}, 3845;
         },
+        "nummultirange" => Scalar {
+            params!() => multirange_create(ScalarType::Numeric { max_scale: None }) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Numeric { max_scale: None }) }, oid::FUNC_NUMMULTIRANGE_NULLARY_OID;
+            multirange_create_params(ScalarType::Numeric { max_scale: None }) => multirange_create(ScalarType::Numeric { max_scale: None }) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Numeric { max_scale: None }) }, oid::FUNC_NUMMULTIRANGE_VARIADIC_OID;
+        },
         "octet_length" => Scalar {
             params!(Bytes) => UnaryFunc::ByteLengthBytes(func::ByteLengthBytes) => Int32, 720;
             params!(String) => UnaryFunc::ByteLengthString(func::ByteLengthString) => Int32, 1374;
@@ -2526,6 +2671,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "radians" => Scalar {
             params!(Float64) => UnaryFunc::Radians(func::Radians) => Float64, 1609;
         },
+        "range_merge" => Scalar {
+            params!(MultiRangeAny) => UnaryFunc::RangeMerge(func::RangeMerge) => RangeAny, oid::FUNC_RANGE_MERGE_MULTIRANGE_OID;
+        },
         "repeat" => Scalar {
             params!(String, Int32) => BinaryFunc::RepeatString => String, 1622;
         },
@@ -2533,6 +2681,33 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3396;
             params!(String, String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3397;
         },
+        "regr_avgx" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_avgx")) => Float64, 2822;
+        },
+        "regr_avgy" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_avgy")) => Float64, 2823;
+        },
+        "regr_count" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_count")) => Int64, 2818;
+        },
+        "regr_intercept" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_intercept")) => Float64, 2826;
+        },
+        "regr_r2" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_r2")) => Float64, 2824;
+        },
+        "regr_slope" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_slope")) => Float64, 2825;
+        },
+        "regr_sxx" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_sxx")) => Float64, 2819;
+        },
+        "regr_sxy" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_sxy")) => Float64, 2821;
+        },
+        "regr_syy" => Scalar {
+            params!(Float64, Float64) => Operation::nullary(|_ecx| catalog_name_only!("regr_syy")) => Float64, 2820;
+        },
         "replace" => Scalar {
             params!(String, String, String) => VariadicFunc::Replace => String, 2087;
         },
@@ -2549,6 +2724,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String) => UnaryFunc::TrimTrailingWhitespace(func::TrimTrailingWhitespace) => String, 882;
             params!(String, String) => BinaryFunc::TrimTrailing => String, 876;
         },
+        "set_bit" => Scalar {
+            params!(Bit, Int32, Int32) => VariadicFunc::SetBitBitString => Bit, 3033;
+        },
         "sha224" => Scalar {
             params!(Bytes) => digest("sha224") => Bytes, 3419;
         },
@@ -2713,6 +2891,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     exprs))
             }) => ScalarType::Range { element_type: Box::new(ScalarType::Timestamp { precision: None})}, 3934;
         },
+        "tsmultirange" => Scalar {
+            params!() => multirange_create(ScalarType::Timestamp { precision: None }) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Timestamp { precision: None }) }, oid::FUNC_TSMULTIRANGE_NULLARY_OID;
+            multirange_create_params(ScalarType::Timestamp { precision: None }) => multirange_create(ScalarType::Timestamp { precision: None }) => ScalarType::MultiRange { element_type: Box::new(ScalarType::Timestamp { precision: None }) }, oid::FUNC_TSMULTIRANGE_VARIADIC_OID;
+        },
         "tstzrange" => Scalar {
             params!(TimestampTz, TimestampTz) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(Datum::String("[)"), ScalarType::String));
@@ -2724,6 +2906,10 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                     exprs))
             }) => ScalarType::Range { element_type: Box::new(ScalarType::TimestampTz { precision: None})}, 3938;
         },
+        "tstzmultirange" => Scalar {
+            params!() => multirange_create(ScalarType::TimestampTz { precision: None }) => ScalarType::MultiRange { element_type: Box::new(ScalarType::TimestampTz { precision: None }) }, oid::FUNC_TSTZMULTIRANGE_NULLARY_OID;
+            multirange_create_params(ScalarType::TimestampTz { precision: None }) => multirange_create(ScalarType::TimestampTz { precision: None }) => ScalarType::MultiRange { element_type: Box::new(ScalarType::TimestampTz { precision: None }) }, oid::FUNC_TSTZMULTIRANGE_VARIADIC_OID;
+        },
         "upper" => Scalar {
             params!(String) => UnaryFunc::Upper(func::Upper) => String, 871;
             params!(RangeAny) => UnaryFunc::RangeUpper(func::RangeUpper) => AnyElement, 3849;
@@ -2793,6 +2979,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "anycompatiblerange_in" => Scalar {
             params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anycompatiblerange_in")) => RangeAnyCompatible, 5094;
         },
+        "anycompatiblemultirange_in" => Scalar {
+            params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anycompatiblemultirange_in")) => MultiRangeAnyCompatible, 4226;
+        },
         "anyelement_in" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anyelement_in")) => AnyElement, 2312;
         },
@@ -2802,10 +2991,16 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "anyrange_in" => Scalar {
             params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anyrange_in")) => RangeAny, 3832;
         },
+        "anymultirange_in" => Scalar {
+            params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("anymultirange_in")) => MultiRangeAny, 4229;
+        },
         "array_in" => Scalar {
             params!(String, Oid, Int32) =>
                 Operation::variadic(|_ecx, _exprs| bail_unsupported!("array_in")) => ArrayAny, 750;
         },
+        "bit_in" => Scalar {
+            params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("bit_in")) => Bit, 1564;
+        },
         "boolin" => Scalar {
             params!(String) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("boolin")) => Bool, 1242;
         },
@@ -2860,6 +3055,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "range_in" => Scalar {
             params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("range_in")) => RangeAny, 3834;
         },
+        "multirange_in" => Scalar {
+            params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("multirange_in")) => MultiRangeAny, 4231;
+        },
         "record_in" => Scalar {
             params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("record_in")) => RecordAny, 2290;
         },
@@ -2884,6 +3082,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "timestamptz_in" => Scalar {
             params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("timestamptz_in")) => TimestampTz, 1150;
         },
+        "varbit_in" => Scalar {
+            params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("varbit_in")) => VarBit, 1579;
+        },
         "varcharin" => Scalar {
             params!(String, Oid, Int32) => Operation::variadic(|_ecx, _exprs| bail_unsupported!("varcharin")) => VarChar, 1046;
         },
@@ -2899,6 +3100,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "anyarray_recv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("anyarray_recv")) => ArrayAny, 2502;
         },
+        "bit_recv" => Scalar {
+            params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("bit_recv")) => Bit, 2456;
+        },
         "bytearecv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("bytearecv")) => Bytes, 2412;
         },
@@ -2965,6 +3169,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "uuid_recv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("uuid_recv")) => Uuid, 2961;
         },
+        "varbit_recv" => Scalar {
+            params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("varbit_recv")) => VarBit, 2458;
+        },
         "varcharrecv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("varcharrecv")) => VarChar, 2432;
         },
@@ -2980,6 +3187,9 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "range_recv" => Scalar {
             params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("range_recv")) => RangeAny, 3836;
         },
+        "multirange_recv" => Scalar {
+            params!(Internal) => Operation::nullary(|_ecx| catalog_name_only!("multirange_recv")) => MultiRangeAny, 4233;
+        },
 
 
         // Aggregates.
@@ -3114,6 +3324,19 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 Ok((e, AggregateFunc::JsonbObjectAgg { order_by }))
             }) => Jsonb, 3270;
         },
+        "range_agg" => Aggregate {
+            params!(RangeAny) => Operation::unary(|_ecx, e| {
+                // `AggregateFunc::RangeAgg` unions multiranges, so wrap each
+                // range in a multirange.
+                let e = e.call_unary(UnaryFunc::RangeToMultiRange(func::RangeToMultiRange));
+                Ok((e, AggregateFunc::RangeAgg))
+            }) => MultiRangeAny, oid::FUNC_RANGE_AGG_RANGE_OID;
+            params!(MultiRangeAny) => AggregateFunc::RangeAgg => MultiRangeAny, oid::FUNC_RANGE_AGG_MULTIRANGE_OID;
+        },
+        "range_intersect_agg" => Aggregate {
+            params!(RangeAny) => AggregateFunc::RangeIntersectAgg => RangeAny, oid::FUNC_RANGE_INTERSECT_AGG_RANGE_OID;
+            params!(MultiRangeAny) => AggregateFunc::RangeIntersectAgg => MultiRangeAny, oid::FUNC_RANGE_INTERSECT_AGG_MULTIRANGE_OID;
+        },
         "string_agg" => Aggregate {
             params!(String, String) => Operation::binary_ordered(|_ecx, value, sep, order_by| {
                 let e = HirScalarExpr::call_variadic(
@@ -3355,6 +3578,17 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 })
             }) => ReturnType::set_of(String.into()), 3931;
         },
+        "jsonb_path_query" => Table {
+            params!(Jsonb, String) => sql_impl_table_func("
+                SELECT * FROM jsonb_array_elements(jsonb_path_query_array($1, $2)) AS t(jsonb_path_query)
+            ") => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_DEFAULT_VARS_OID;
+            params!(Jsonb, String, Jsonb) => sql_impl_table_func("
+                SELECT * FROM jsonb_array_elements(jsonb_path_query_array($1, $2, $3)) AS t(jsonb_path_query)
+            ") => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_DEFAULT_SILENT_OID;
+            params!(Jsonb, String, Jsonb, Bool) => sql_impl_table_func("
+                SELECT * FROM jsonb_array_elements(jsonb_path_query_array($1, $2, $3, $4)) AS t(jsonb_path_query)
+            ") => ReturnType::set_of(Jsonb.into()), 4006;
+        },
         // Note that these implementations' input to `generate_series` is
         // contrived to match Flink's expected values. There are other,
         // equally valid windows we could generate.
@@ -3897,6 +4131,21 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             }) =>
                 // This return type should be equivalent to "ListElementAny", but this would be its sole use.
                 ReturnType::set_of(Any), oid::FUNC_UNNEST_MAP_OID;
+            vec![MultiRangeAny] => Operation::unary(move |ecx, e| {
+                // Multiranges are stored as lists of ranges.
+                let el_typ = ScalarType::Range {
+                    element_type: Box::new(
+                        ecx.scalar_type(&e).unwrap_multirange_element_type().clone(),
+                    ),
+                };
+                Ok(TableFuncPlan {
+                    expr: HirRelationExpr::CallTable {
+                        func: TableFunc::UnnestList { el_typ },
+                        exprs: vec![e],
+                    },
+                    column_names: vec!["unnest".into()],
+                })
+            }) => ReturnType::set_of(RangeAny), oid::FUNC_UNNEST_MULTIRANGE_OID;
         }
     }
 });
@@ -4371,6 +4620,13 @@ pub static MZ_UNSAFE_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock
                 )
             }) => Numeric, oid::FUNC_MZ_AVG_PROMOTION_NUMERIC_OID;
         },
+        "mz_float8_promotion" => Scalar {
+            // Implicitly casts its argument to a double precision float. This
+            // is primarily useful for the statistical aggregate functions over
+            // pairs of values (`corr`, `covar_pop`, `regr_slope`, etc.),
+            // which PostgreSQL only defines for `double precision` inputs.
+            params!(Float64) => Operation::identity() => Float64, oid::FUNC_MZ_FLOAT8_PROMOTION_OID;
+        },
         "mz_error_if_null" => Scalar {
             // If the first argument is NULL, returns an EvalError::Internal whose error
             // message is the second argument.
@@ -4481,6 +4737,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             } => Time, 1849;
             params!(Numeric, Numeric) => AddNumeric => Numeric, 1758;
             params!(RangeAny, RangeAny) => RangeUnion => RangeAny, 3898;
+            params!(MultiRangeAny, MultiRangeAny) => MultiRangeUnion => MultiRangeAny, oid::OP_UNION_MULTIRANGE_OID;
         },
         "-" => Scalar {
             params!(Int16) => UnaryFunc::NegInt16(func::NegInt16) => Int16, 559;
@@ -4510,6 +4767,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Jsonb, Int64) => JsonbDeleteInt64 => Jsonb, 3286;
             params!(Jsonb, String) => JsonbDeleteString => Jsonb, 3285;
             params!(RangeAny, RangeAny) => RangeDifference => RangeAny, 3899;
+            params!(MultiRangeAny, MultiRangeAny) => MultiRangeDifference => MultiRangeAny, oid::OP_DIFFERENCE_MULTIRANGE_OID;
             // TODO(jamii) there should be corresponding overloads for
             // Array(Int64) and Array(String)
         },
@@ -4528,6 +4786,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             } => Interval, 1584;
             params!(Numeric, Numeric) => MulNumeric => Numeric, 1760;
             params!(RangeAny, RangeAny) => RangeIntersection => RangeAny, 3900;
+            params!(MultiRangeAny, MultiRangeAny) => MultiRangeIntersection => MultiRangeAny, oid::OP_INTERSECTION_MULTIRANGE_OID;
         },
         "/" => Scalar {
             params!(Int16, Int16) => DivInt16 => Int16, 527;
@@ -4559,6 +4818,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt16, UInt16) => BitAndUInt16 => UInt16, oid::FUNC_AND_UINT16;
             params!(UInt32, UInt32) => BitAndUInt32 => UInt32, oid::FUNC_AND_UINT32;
             params!(UInt64, UInt64) => BitAndUInt64 => UInt64, oid::FUNC_AND_UINT64;
+            params!(Bit, Bit) => BitAndBitString => Bit, 1791;
         },
         "|" => Scalar {
             params!(Int16, Int16) => BitOrInt16 => Int16, 1875;
@@ -4567,6 +4827,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt16, UInt16) => BitOrUInt16 => UInt16, oid::FUNC_OR_UINT16;
             params!(UInt32, UInt32) => BitOrUInt32 => UInt32, oid::FUNC_OR_UINT32;
             params!(UInt64, UInt64) => BitOrUInt64 => UInt64, oid::FUNC_OR_UINT64;
+            params!(Bit, Bit) => BitOrBitString => Bit, 1792;
         },
         "#" => Scalar {
             params!(Int16, Int16) => BitXorInt16 => Int16, 1876;
@@ -4575,6 +4836,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt16, UInt16) => BitXorUInt16 => UInt16, oid::FUNC_XOR_UINT16;
             params!(UInt32, UInt32) => BitXorUInt32 => UInt32, oid::FUNC_XOR_UINT32;
             params!(UInt64, UInt64) => BitXorUInt64 => UInt64, oid::FUNC_XOR_UINT64;
+            params!(Bit, Bit) => BitXorBitString => Bit, 1793;
         },
         "<<" => Scalar {
             params!(Int16, Int32) => BitShiftLeftInt16 => Int16, 1878;
@@ -4583,6 +4845,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt16, UInt32) => BitShiftLeftUInt16 => UInt16, oid::FUNC_SHIFT_LEFT_UINT16;
             params!(UInt32, UInt32) => BitShiftLeftUInt32 => UInt32, oid::FUNC_SHIFT_LEFT_UINT32;
             params!(UInt64, UInt32) => BitShiftLeftUInt64 => UInt64, oid::FUNC_SHIFT_LEFT_UINT64;
+            params!(Bit, Int32) => BitShiftLeftBitString => Bit, 1795;
             params!(RangeAny, RangeAny) => RangeBefore => Bool, 3893;
         },
         ">>" => Scalar {
@@ -4592,6 +4855,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt16, UInt32) => BitShiftRightUInt16 => UInt16, oid::FUNC_SHIFT_RIGHT_UINT16;
             params!(UInt32, UInt32) => BitShiftRightUInt32 => UInt32, oid::FUNC_SHIFT_RIGHT_UINT32;
             params!(UInt64, UInt32) => BitShiftRightUInt64 => UInt64, oid::FUNC_SHIFT_RIGHT_UINT64;
+            params!(Bit, Int32) => BitShiftRightBitString => Bit, 1796;
             params!(RangeAny, RangeAny) => RangeAfter => Bool, 3894;
         },
 
@@ -4654,6 +4918,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(UInt16) => UnaryFunc::BitNotUint16(func::BitNotUint16) => UInt16, oid::FUNC_BIT_NOT_UINT16_OID;
             params!(UInt32) => UnaryFunc::BitNotUint32(func::BitNotUint32) => UInt32, oid::FUNC_BIT_NOT_UINT32_OID;
             params!(UInt64) => UnaryFunc::BitNotUint64(func::BitNotUint64) => UInt64, oid::FUNC_BIT_NOT_UINT64_OID;
+            params!(Bit) => UnaryFunc::BitNotBitString(func::BitNotBitString) => Bit, 1794;
             params!(String, String) => IsRegexpMatch { case_insensitive: false } => Bool, 641;
             params!(Char, String) => Operation::binary(|ecx, lhs, rhs| {
                 let length = ecx.scalar_type(&lhs).unwrap_char_length();
@@ -4724,6 +4989,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             }) => String, 2780;
             params!(String, String) => TextConcat => String, 654;
             params!(Jsonb, Jsonb) => JsonbConcat => Jsonb, 3284;
+            params!(VarBit, VarBit) => BitStringConcat => VarBit, 1797;
             params!(ArrayAnyCompatible, ArrayAnyCompatible) => ArrayArrayConcat => ArrayAnyCompatible, 375;
             params!(ListAnyCompatible, ListAnyCompatible) => ListListConcat => ListAnyCompatible, oid::OP_CONCAT_LIST_LIST_OID;
             params!(ListAnyCompatible, ListElementAnyCompatible) => ListElementConcat => ListAnyCompatible, oid::OP_CONCAT_LIST_ELEMENT_OID;
@@ -4766,6 +5032,20 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BinaryFunc::RangeContainsRange { rev: false }))
             }) => Bool, 3890;
+            params!(MultiRangeAny, AnyElement) => Operation::binary(|ecx, lhs, rhs| {
+                let elem_type = ecx.scalar_type(&lhs).unwrap_multirange_element_type().clone();
+                Ok(lhs.call_binary(rhs, BinaryFunc::MultiRangeContainsElem { elem_type, rev: false }))
+            }) => Bool, oid::OP_CONTAINS_MULTIRANGE_ELEM_OID;
+            params!(MultiRangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(lhs.call_binary(rhs, BinaryFunc::MultiRangeContainsRange { rev: false }))
+            }) => Bool, oid::OP_CONTAINS_MULTIRANGE_RANGE_OID;
+            params!(MultiRangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(lhs.call_binary(rhs, BinaryFunc::MultiRangeContainsMultiRange { rev: false }))
+            }) => Bool, oid::OP_CONTAINS_MULTIRANGE_MULTIRANGE_OID;
+            params!(RangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                let lhs = lhs.call_unary(UnaryFunc::RangeToMultiRange(func::RangeToMultiRange));
+                Ok(lhs.call_binary(rhs, BinaryFunc::MultiRangeContainsMultiRange { rev: false }))
+            }) => Bool, oid::OP_CONTAINS_RANGE_MULTIRANGE_OID;
             params!(ArrayAny, ArrayAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BinaryFunc::ArrayContainsArray { rev: false }))
             }) => Bool, 2751;
@@ -4800,6 +5080,20 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(rhs.call_binary(lhs, BinaryFunc::RangeContainsRange { rev: true }))
             }) => Bool, 3892;
+            params!(AnyElement, MultiRangeAny) => Operation::binary(|ecx, lhs, rhs| {
+                let elem_type = ecx.scalar_type(&rhs).unwrap_multirange_element_type().clone();
+                Ok(rhs.call_binary(lhs, BinaryFunc::MultiRangeContainsElem { elem_type, rev: true }))
+            }) => Bool, oid::OP_CONTAINED_ELEM_MULTIRANGE_OID;
+            params!(RangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BinaryFunc::MultiRangeContainsRange { rev: true }))
+            }) => Bool, oid::OP_CONTAINED_RANGE_MULTIRANGE_OID;
+            params!(MultiRangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BinaryFunc::MultiRangeContainsMultiRange { rev: true }))
+            }) => Bool, oid::OP_CONTAINED_MULTIRANGE_MULTIRANGE_OID;
+            params!(MultiRangeAny, RangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                let rhs = rhs.call_unary(UnaryFunc::RangeToMultiRange(func::RangeToMultiRange));
+                Ok(rhs.call_binary(lhs, BinaryFunc::MultiRangeContainsMultiRange { rev: true }))
+            }) => Bool, oid::OP_CONTAINED_MULTIRANGE_RANGE_OID;
             params!(ArrayAny, ArrayAny) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(lhs.call_binary(rhs, BinaryFunc::ArrayContainsArray { rev: true }))
             }) => Bool, 2752;
@@ -4807,6 +5101,12 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
                 Ok(lhs.call_binary(rhs, BinaryFunc::ListContainsList { rev: true }))
             }) => Bool, oid::OP_IS_CONTAINED_LIST_LIST_OID;
         },
+        "@?" => Scalar {
+            params!(Jsonb, String) => jsonb_path_func(JsonPathKind::Exists, true) => Bool, 4012;
+        },
+        "@@" => Scalar {
+            params!(Jsonb, String) => jsonb_path_func(JsonPathKind::Match, true) => Bool, 4013;
+        },
         "?" => Scalar {
             params!(Jsonb, String) => JsonbContainsString => Bool, 3247;
             params!(MapAny, String) => MapContainsKey => Bool, oid::OP_CONTAINS_KEY_MAP_OID;
@@ -4819,6 +5119,11 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
         },
         "&&" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverlaps => Bool, 3888;
+            params!(MultiRangeAny, RangeAny) => BinaryFunc::MultiRangeOverlapsRange => Bool, oid::OP_OVERLAPS_MULTIRANGE_RANGE_OID;
+            params!(RangeAny, MultiRangeAny) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(rhs.call_binary(lhs, BinaryFunc::MultiRangeOverlapsRange))
+            }) => Bool, oid::OP_OVERLAPS_RANGE_MULTIRANGE_OID;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::MultiRangeOverlapsMultiRange => Bool, oid::OP_OVERLAPS_MULTIRANGE_MULTIRANGE_OID;
         },
         "&<" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverleft => Bool, 3895;
@@ -4850,6 +5155,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Uuid, Uuid) => BinaryFunc::Lt => Bool, 2974;
             params!(Interval, Interval) => BinaryFunc::Lt => Bool, 1332;
             params!(Bytes, Bytes) => BinaryFunc::Lt => Bool, 1957;
+            params!(Bit, Bit) => BinaryFunc::Lt => Bool, 1786;
+            params!(VarBit, VarBit) => BinaryFunc::Lt => Bool, 1806;
             params!(String, String) => BinaryFunc::Lt => Bool, 664;
             params!(Char, Char) => BinaryFunc::Lt => Bool, 1058;
             params!(PgLegacyChar, PgLegacyChar) => BinaryFunc::Lt => Bool, 631;
@@ -4859,6 +5166,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lt => Bool, 2990;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lt =>Bool, oid::FUNC_MZ_TIMESTAMP_LT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lt => Bool, 3884;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::Lt => Bool, oid::FUNC_MULTIRANGE_LT_OID;
         },
         "<=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Lte => Bool, 1755;
@@ -4879,6 +5187,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Uuid, Uuid) => BinaryFunc::Lte => Bool, 2976;
             params!(Interval, Interval) => BinaryFunc::Lte => Bool, 1333;
             params!(Bytes, Bytes) => BinaryFunc::Lte => Bool, 1958;
+            params!(Bit, Bit) => BinaryFunc::Lte => Bool, 1788;
+            params!(VarBit, VarBit) => BinaryFunc::Lte => Bool, 1808;
             params!(String, String) => BinaryFunc::Lte => Bool, 665;
             params!(Char, Char) => BinaryFunc::Lte => Bool, 1059;
             params!(PgLegacyChar, PgLegacyChar) => BinaryFunc::Lte => Bool, 632;
@@ -4888,6 +5198,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lte => Bool, 2992;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lte =>Bool, oid::FUNC_MZ_TIMESTAMP_LTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lte => Bool, 3885;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::Lte => Bool, oid::FUNC_MULTIRANGE_LTE_OID;
         },
         ">" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gt => Bool, 1756;
@@ -4908,6 +5219,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Uuid, Uuid) => BinaryFunc::Gt => Bool, 2975;
             params!(Interval, Interval) => BinaryFunc::Gt => Bool, 1334;
             params!(Bytes, Bytes) => BinaryFunc::Gt => Bool, 1959;
+            params!(Bit, Bit) => BinaryFunc::Gt => Bool, 1787;
+            params!(VarBit, VarBit) => BinaryFunc::Gt => Bool, 1807;
             params!(String, String) => BinaryFunc::Gt => Bool, 666;
             params!(Char, Char) => BinaryFunc::Gt => Bool, 1060;
             params!(PgLegacyChar, PgLegacyChar) => BinaryFunc::Gt => Bool, 633;
@@ -4917,6 +5230,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gt => Bool, 2991;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gt =>Bool, oid::FUNC_MZ_TIMESTAMP_GT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gt => Bool, 3887;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::Gt => Bool, oid::FUNC_MULTIRANGE_GT_OID;
         },
         ">=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gte => Bool, 1757;
@@ -4937,6 +5251,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Uuid, Uuid) => BinaryFunc::Gte => Bool, 2977;
             params!(Interval, Interval) => BinaryFunc::Gte => Bool, 1335;
             params!(Bytes, Bytes) => BinaryFunc::Gte => Bool, 1960;
+            params!(Bit, Bit) => BinaryFunc::Gte => Bool, 1789;
+            params!(VarBit, VarBit) => BinaryFunc::Gte => Bool, 1809;
             params!(String, String) => BinaryFunc::Gte => Bool, 667;
             params!(Char, Char) => BinaryFunc::Gte => Bool, 1061;
             params!(PgLegacyChar, PgLegacyChar) => BinaryFunc::Gte => Bool, 634;
@@ -4946,6 +5262,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gte => Bool, 2993;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gte =>Bool, oid::FUNC_MZ_TIMESTAMP_GTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gte => Bool, 3886;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::Gte => Bool, oid::FUNC_MULTIRANGE_GTE_OID;
         },
         // Warning!
         // - If you are writing functions here that do not simply use
@@ -4975,6 +5292,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Uuid, Uuid) => BinaryFunc::Eq => Bool, 2972;
             params!(Interval, Interval) => BinaryFunc::Eq => Bool, 1330;
             params!(Bytes, Bytes) => BinaryFunc::Eq => Bool, 1955;
+            params!(Bit, Bit) => BinaryFunc::Eq => Bool, 1784;
+            params!(VarBit, VarBit) => BinaryFunc::Eq => Bool, 1804;
             params!(String, String) => BinaryFunc::Eq => Bool, 98;
             params!(Char, Char) => BinaryFunc::Eq => Bool, 1054;
             params!(PgLegacyChar, PgLegacyChar) => BinaryFunc::Eq => Bool, 92;
@@ -4985,6 +5304,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Eq => Bool, 2988;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_TIMESTAMP_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Eq => Bool, 3882;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::Eq => Bool, oid::FUNC_MULTIRANGE_EQ_OID;
             params!(MzAclItem, MzAclItem) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_ACL_ITEM_EQ_MZ_ACL_ITEM_OID;
             params!(AclItem, AclItem) => BinaryFunc::Eq => Bool, 974;
         },
@@ -5007,6 +5327,8 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(Uuid, Uuid) => BinaryFunc::NotEq => Bool, 2973;
             params!(Interval, Interval) => BinaryFunc::NotEq => Bool, 1331;
             params!(Bytes, Bytes) => BinaryFunc::NotEq => Bool, 1956;
+            params!(Bit, Bit) => BinaryFunc::NotEq => Bool, 1785;
+            params!(VarBit, VarBit) => BinaryFunc::NotEq => Bool, 1805;
             params!(String, String) => BinaryFunc::NotEq => Bool, 531;
             params!(Char, Char) => BinaryFunc::NotEq => Bool, 1057;
             params!(PgLegacyChar, PgLegacyChar) => BinaryFunc::NotEq => Bool, 630;
@@ -5016,6 +5338,7 @@ pub static OP_IMPLS: LazyLock<BTreeMap<&'static str, Func>> = LazyLock::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::NotEq => Bool, 2989;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_TIMESTAMP_NOT_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::NotEq => Bool, 3883;
+            params!(MultiRangeAny, MultiRangeAny) => BinaryFunc::NotEq => Bool, oid::FUNC_MULTIRANGE_NOT_EQ_OID;
             params!(MzAclItem, MzAclItem) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_ACL_ITEM_NOT_EQ_MZ_ACL_ITEM_OID;
         }
     }
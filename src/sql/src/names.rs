@@ -1188,7 +1188,8 @@ impl From<CommentObjectId> for ObjectId {
             | CommentObjectId::Connection(item_id)
             | CommentObjectId::Type(item_id)
             | CommentObjectId::Secret(item_id)
-            | CommentObjectId::ContinualTask(item_id) => ObjectId::Item(item_id),
+            | CommentObjectId::ContinualTask(item_id)
+            | CommentObjectId::Endpoint(item_id) => ObjectId::Item(item_id),
             CommentObjectId::Role(id) => ObjectId::Role(id),
             CommentObjectId::Database(id) => ObjectId::Database(id),
             CommentObjectId::Schema(id) => ObjectId::Schema(id),
@@ -1244,6 +1245,7 @@ pub enum CommentObjectId {
     Type(CatalogItemId),
     Secret(CatalogItemId),
     ContinualTask(CatalogItemId),
+    Endpoint(CatalogItemId),
     Role(RoleId),
     Database(DatabaseId),
     Schema((ResolvedDatabaseSpecifier, SchemaSpecifier)),
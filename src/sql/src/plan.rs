@@ -140,6 +140,7 @@ pub enum Plan {
     CreateSource(CreateSourcePlan),
     CreateSources(Vec<CreateSourcePlanBundle>),
     CreateSecret(CreateSecretPlan),
+    CreateEndpoint(CreateEndpointPlan),
     CreateSink(CreateSinkPlan),
     CreateTable(CreateTablePlan),
     CreateView(CreateViewPlan),
@@ -150,9 +151,11 @@ pub enum Plan {
     CreateType(CreateTypePlan),
     Comment(CommentPlan),
     DiscardTemp,
+    DiscardPlans,
     DiscardAll,
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
+    DropSubscription(DropSubscriptionPlan),
     EmptyQuery,
     ShowAllVariables,
     ShowCreate(ShowCreatePlan),
@@ -193,6 +196,8 @@ pub enum Plan {
     AlterRole(AlterRolePlan),
     AlterOwner(AlterOwnerPlan),
     AlterTableAddColumn(AlterTablePlan),
+    AlterTableAlterColumnType(AlterTablePlan),
+    AlterTableDropColumn(AlterTableDropColumnPlan),
     AlterNetworkPolicy(AlterNetworkPolicyPlan),
     Declare(DeclarePlan),
     Fetch(FetchPlan),
@@ -200,8 +205,12 @@ pub enum Plan {
     ReadThenWrite(ReadThenWritePlan),
     Prepare(PreparePlan),
     Execute(ExecutePlan),
+    ExecuteEndpoint(ExecuteEndpointPlan),
     Deallocate(DeallocatePlan),
     Raise(RaisePlan),
+    Listen(ListenPlan),
+    Unlisten(UnlistenPlan),
+    Notify(NotifyPlan),
     GrantRole(GrantRolePlan),
     RevokeRole(RevokeRolePlan),
     GrantPrivileges(GrantPrivilegesPlan),
@@ -253,6 +262,12 @@ impl Plan {
             StatementKind::AlterTableAddColumn => {
                 &[PlanKind::AlterNoop, PlanKind::AlterTableAddColumn]
             }
+            StatementKind::AlterTableAlterColumnType => {
+                &[PlanKind::AlterNoop, PlanKind::AlterTableAlterColumnType]
+            }
+            StatementKind::AlterTableDropColumn => {
+                &[PlanKind::AlterNoop, PlanKind::AlterTableDropColumn]
+            }
             StatementKind::Close => &[PlanKind::Close],
             StatementKind::Comment => &[PlanKind::Comment],
             StatementKind::Commit => &[PlanKind::CommitTransaction],
@@ -273,6 +288,7 @@ impl Plan {
             StatementKind::CreateRole => &[PlanKind::CreateRole],
             StatementKind::CreateSchema => &[PlanKind::CreateSchema],
             StatementKind::CreateSecret => &[PlanKind::CreateSecret],
+            StatementKind::CreateEndpoint => &[PlanKind::CreateEndpoint],
             StatementKind::CreateSink => &[PlanKind::CreateSink],
             StatementKind::CreateSource | StatementKind::CreateSubsource => {
                 &[PlanKind::CreateSource]
@@ -285,10 +301,16 @@ impl Plan {
             StatementKind::Deallocate => &[PlanKind::Deallocate],
             StatementKind::Declare => &[PlanKind::Declare],
             StatementKind::Delete => &[PlanKind::ReadThenWrite],
-            StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
+            StatementKind::Discard => &[
+                PlanKind::DiscardAll,
+                PlanKind::DiscardTemp,
+                PlanKind::DiscardPlans,
+            ],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
+            StatementKind::DropSubscription => &[PlanKind::DropSubscription],
             StatementKind::Execute => &[PlanKind::Execute],
+            StatementKind::ExecuteEndpoint => &[PlanKind::ExecuteEndpoint],
             StatementKind::ExplainPlan => &[PlanKind::ExplainPlan],
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
             StatementKind::ExplainAnalyze => &[PlanKind::Select],
@@ -298,6 +320,8 @@ impl Plan {
             StatementKind::GrantPrivileges => &[PlanKind::GrantPrivileges],
             StatementKind::GrantRole => &[PlanKind::GrantRole],
             StatementKind::Insert => &[PlanKind::Insert],
+            StatementKind::Listen => &[PlanKind::Listen],
+            StatementKind::Notify => &[PlanKind::Notify],
             StatementKind::Prepare => &[PlanKind::Prepare],
             StatementKind::Raise => &[PlanKind::Raise],
             StatementKind::ReassignOwned => &[PlanKind::ReassignOwned],
@@ -318,6 +342,7 @@ impl Plan {
             ],
             StatementKind::StartTransaction => &[PlanKind::StartTransaction],
             StatementKind::Subscribe => &[PlanKind::Subscribe],
+            StatementKind::Unlisten => &[PlanKind::Unlisten],
             StatementKind::Update => &[PlanKind::ReadThenWrite],
             StatementKind::ValidateConnection => &[PlanKind::ValidateConnection],
             StatementKind::AlterRetainHistory => &[PlanKind::AlterRetainHistory],
@@ -336,6 +361,7 @@ impl Plan {
             Plan::CreateSource(_) => "create source",
             Plan::CreateSources(_) => "create source",
             Plan::CreateSecret(_) => "create secret",
+            Plan::CreateEndpoint(_) => "create endpoint",
             Plan::CreateSink(_) => "create sink",
             Plan::CreateTable(_) => "create table",
             Plan::CreateView(_) => "create view",
@@ -346,6 +372,7 @@ impl Plan {
             Plan::CreateNetworkPolicy(_) => "create network policy",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
+            Plan::DiscardPlans => "discard plans",
             Plan::DiscardAll => "discard all",
             Plan::DropObjects(plan) => match plan.object_type {
                 ObjectType::Table => "drop table",
@@ -365,8 +392,10 @@ impl Plan {
                 ObjectType::Func => "drop function",
                 ObjectType::ContinualTask => "drop continual task",
                 ObjectType::NetworkPolicy => "drop network policy",
+                ObjectType::Endpoint => "drop endpoint",
             },
             Plan::DropOwned(_) => "drop owned",
+            Plan::DropSubscription(_) => "drop subscription",
             Plan::EmptyQuery => "do nothing",
             Plan::ShowAllVariables => "show all variables",
             Plan::ShowCreate(_) => "show create",
@@ -406,6 +435,7 @@ impl Plan {
                 ObjectType::Func => "alter function",
                 ObjectType::ContinualTask => "alter continual task",
                 ObjectType::NetworkPolicy => "alter network policy",
+                ObjectType::Endpoint => "alter endpoint",
             },
             Plan::AlterCluster(_) => "alter cluster",
             Plan::AlterClusterRename(_) => "alter cluster rename",
@@ -442,8 +472,11 @@ impl Plan {
                 ObjectType::Func => "alter function owner",
                 ObjectType::ContinualTask => "alter continual task owner",
                 ObjectType::NetworkPolicy => "alter network policy owner",
+                ObjectType::Endpoint => "alter endpoint owner",
             },
             Plan::AlterTableAddColumn(_) => "alter table add column",
+            Plan::AlterTableAlterColumnType(_) => "alter table alter column type",
+            Plan::AlterTableDropColumn(_) => "alter table drop column",
             Plan::Declare(_) => "declare",
             Plan::Fetch(_) => "fetch",
             Plan::Close(_) => "close",
@@ -454,8 +487,12 @@ impl Plan {
             },
             Plan::Prepare(_) => "prepare",
             Plan::Execute(_) => "execute",
+            Plan::ExecuteEndpoint(_) => "execute endpoint",
             Plan::Deallocate(_) => "deallocate",
             Plan::Raise(_) => "raise",
+            Plan::Listen(_) => "listen",
+            Plan::Unlisten(_) => "unlisten",
+            Plan::Notify(_) => "notify",
             Plan::GrantRole(_) => "grant role",
             Plan::RevokeRole(_) => "revoke role",
             Plan::GrantPrivileges(_) => "grant privilege",
@@ -484,6 +521,7 @@ impl Plan {
             Plan::CommitTransaction(_) => true,
             Plan::AbortTransaction(_) => true,
             Plan::Select(_) => true,
+            Plan::ExecuteEndpoint(_) => true,
             Plan::EmptyQuery => true,
             Plan::ShowAllVariables => true,
             Plan::ShowCreate(_) => true,
@@ -497,6 +535,9 @@ impl Plan {
             Plan::ExplainTimestamp(_) => true,
             Plan::ExplainSinkSchema(_) => true,
             Plan::ValidateConnection(_) => true,
+            Plan::Listen(_) => true,
+            Plan::Unlisten(_) => true,
+            Plan::Notify(_) => true,
             _ => false,
         }
     }
@@ -705,6 +746,13 @@ pub struct CreateSecretPlan {
     pub if_not_exists: bool,
 }
 
+#[derive(Debug)]
+pub struct CreateEndpointPlan {
+    pub name: QualifiedItemName,
+    pub endpoint: Endpoint,
+    pub if_not_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct CreateSinkPlan {
     pub name: QualifiedItemName,
@@ -812,6 +860,14 @@ pub struct DropOwnedPlan {
     pub default_privilege_revokes: Vec<(DefaultPrivilegeObject, DefaultPrivilegeAclItem)>,
 }
 
+#[derive(Debug)]
+pub struct DropSubscriptionPlan {
+    /// The name of the durable subscription to drop.
+    pub name: String,
+    /// Whether `IF EXISTS` was specified.
+    pub if_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct ShowVariablePlan {
     pub name: String,
@@ -861,6 +917,11 @@ pub struct SelectPlan {
     pub finishing: RowSetFinishing,
     /// For `COPY TO`, the format to use.
     pub copy_to: Option<CopyFormat>,
+    /// The values bound to the parameters of the statement.
+    pub params: Params,
+    /// The plan as a HIR with placeholders in place of its parameters, if it can be optimized
+    /// once for all of their values. See [`HirScalarExpr::bind_parameter_placeholders`].
+    pub generic_source: Option<HirRelationExpr>,
 }
 
 impl SelectPlan {
@@ -872,6 +933,8 @@ impl SelectPlan {
             when: QueryWhen::Immediately,
             finishing: RowSetFinishing::trivial(arity),
             copy_to: None,
+            params: Params::empty(),
+            generic_source: None,
         }
     }
 }
@@ -902,6 +965,11 @@ pub struct SubscribePlan {
     pub copy_to: Option<CopyFormat>,
     pub emit_progress: bool,
     pub output: SubscribeOutput,
+    /// The name of the durable subscription to create or resume, if any.
+    pub name: Option<String>,
+    /// The `LISTEN` channel to notify of each batch of changes instead of
+    /// returning the changes, if any.
+    pub listen_channel: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -1326,6 +1394,12 @@ pub struct AlterTablePlan {
     pub raw_sql_type: RawDataType,
 }
 
+#[derive(Debug)]
+pub struct AlterTableDropColumnPlan {
+    pub relation_id: CatalogItemId,
+    pub column_name: ColumnName,
+}
+
 #[derive(Debug)]
 pub struct DeclarePlan {
     pub name: String,
@@ -1360,6 +1434,14 @@ pub struct ExecutePlan {
     pub params: Params,
 }
 
+#[derive(Debug)]
+pub struct ExecuteEndpointPlan {
+    /// The endpoint to execute.
+    pub id: CatalogItemId,
+    /// The query of the endpoint, with its parameters bound.
+    pub select: SelectPlan,
+}
+
 #[derive(Debug)]
 pub struct DeallocatePlan {
     pub name: Option<String>,
@@ -1370,6 +1452,26 @@ pub struct RaisePlan {
     pub severity: NoticeSeverity,
 }
 
+#[derive(Debug)]
+pub struct ListenPlan {
+    pub channel: String,
+    /// The subscribe that notifies the channel of each batch of changes to a
+    /// relation, for `LISTEN ... ON`.
+    pub subscribe: Option<SubscribePlan>,
+}
+
+#[derive(Debug)]
+pub struct UnlistenPlan {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct NotifyPlan {
+    pub channel: String,
+    pub payload: String,
+}
+
 #[derive(Debug)]
 pub struct GrantRolePlan {
     /// The roles that are gaining members.
@@ -1450,7 +1552,11 @@ pub struct CommentPlan {
 #[derive(Clone, Debug)]
 pub enum TableDataSource {
     /// The table owns data created via INSERT/UPDATE/DELETE statements.
-    TableWrites { defaults: Vec<Expr<Aug>> },
+    TableWrites {
+        defaults: Vec<Expr<Aug>>,
+        /// The domain type of each column, if any.
+        domains: Vec<Option<CatalogItemId>>,
+    },
 
     /// The table receives its data from the identified `DataSourceDesc`.
     /// This table type does not support INSERT/UPDATE/DELETE statements.
@@ -1783,6 +1889,30 @@ pub struct Sink {
     pub version: u64,
 }
 
+#[derive(Clone, Debug)]
+pub struct Endpoint {
+    /// Parse-able SQL that is stored durably and defines this endpoint.
+    pub create_sql: String,
+    /// The query that the endpoint executes.
+    pub query: EndpointQuery,
+    /// All of the catalog objects that are referenced by this endpoint, according to its query.
+    pub dependencies: DependencyIds,
+}
+
+/// A query planned by `CREATE ENDPOINT`, whose parameters are bound each time the endpoint is
+/// executed.
+#[derive(Clone, Debug)]
+pub struct EndpointQuery {
+    /// Unoptimized high-level expression of the query.
+    pub expr: HirRelationExpr,
+    /// Instructions how to form the result set.
+    pub finishing: RowSetFinishing<HirScalarExpr, HirScalarExpr>,
+    /// The shape of the result set.
+    pub desc: RelationDesc,
+    /// The types of the parameters of the query.
+    pub param_types: Vec<ScalarType>,
+}
+
 #[derive(Clone, Debug)]
 pub struct View {
     /// Parse-able SQL that is stored durably and defines this view.
@@ -21,6 +21,7 @@ use mz_ore::error::ErrorExt;
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::str::{StrExt, separated};
 use mz_postgres_util::PostgresError;
+use mz_repr::adt::bit::InvalidBitLengthError;
 use mz_repr::adt::char::InvalidCharLengthError;
 use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::adt::numeric::InvalidNumericMaxScaleError;
@@ -114,6 +115,7 @@ pub enum PlanError {
     InvalidWmrRecursionLimit(String),
     InvalidNumericMaxScale(InvalidNumericMaxScaleError),
     InvalidCharLength(InvalidCharLengthError),
+    InvalidBitLength(InvalidBitLengthError),
     InvalidId(CatalogItemId),
     InvalidIdent(IdentError),
     InvalidObject(Box<ResolvedItemName>),
@@ -153,6 +155,17 @@ pub enum PlanError {
         // (dependent type, name)
         dependents: Vec<(String, String)>,
     },
+    AlterColumnDependentObjectsExist {
+        object_name: String,
+        column_name: ColumnName,
+        // (dependent type, name)
+        dependents: Vec<(String, String)>,
+    },
+    InvalidColumnTypeChange {
+        column_name: ColumnName,
+        from: String,
+        to: String,
+    },
     AlterViewOnMaterializedView(String),
     ShowCreateViewOnMaterializedView(String),
     ExplainViewOnMaterializedView(String),
@@ -387,6 +400,11 @@ impl PlanError {
                 Some("Use DROP MATERIALIZED VIEW to remove a materialized view.".into())
             }
             Self::DependentObjectsStillExist {..} => Some("Use DROP ... CASCADE to drop the dependent objects too.".into()),
+            Self::AlterColumnDependentObjectsExist {..} => Some("Drop the dependent objects, alter the table, and then recreate them.".into()),
+            Self::InvalidColumnTypeChange {..} => Some(
+                "Only lossless widening type changes are supported, e.g. smallint to integer, \
+                integer to bigint, or varchar(n) to a longer varchar or text.".into()
+            ),
             Self::AlterViewOnMaterializedView(_) => {
                 Some("Use ALTER MATERIALIZED VIEW to rename a materialized view.".into())
             }
@@ -592,6 +610,7 @@ impl fmt::Display for PlanError {
             Self::InvalidWmrRecursionLimit(msg) => write!(f, "Invalid WITH MUTUALLY RECURSIVE recursion limit. {}", msg),
             Self::InvalidNumericMaxScale(e) => e.fmt(f),
             Self::InvalidCharLength(e) => e.fmt(f),
+            Self::InvalidBitLength(e) => e.fmt(f),
             Self::InvalidVarCharMaxLength(e) => e.fmt(f),
             Self::InvalidTimestampPrecision(e) => e.fmt(f),
             Self::Parser(e) => e.fmt(f),
@@ -671,6 +690,20 @@ impl fmt::Display for PlanError {
                 let object_name = object_name.quoted();
                 write!(f, "cannot drop {object_type} {object_name}{reason}")
             }
+            Self::AlterColumnDependentObjectsExist {object_name, column_name, dependents} => {
+                let dependents = dependents.iter().map(|(dependent_type, dependent_name)| format!("{} {}", dependent_type, dependent_name.quoted())).join(", ");
+                write!(
+                    f,
+                    "cannot alter column {} of table {}: still depended upon by {dependents}",
+                    column_name.quoted(),
+                    object_name.quoted(),
+                )
+            }
+            Self::InvalidColumnTypeChange { column_name, from, to } => write!(
+                f,
+                "cannot alter type of column {} from {from} to {to}",
+                column_name.quoted(),
+            ),
             Self::InvalidOptionValue { option_name, err } => write!(f, "invalid {} option value: {}", option_name, err),
             Self::UnexpectedDuplicateReference { name } => write!(f, "unexpected multiple references to {}", name.to_ast_string_simple()),
             Self::RecursiveTypeMismatch(name, declared, inferred) => {
@@ -849,6 +882,12 @@ impl From<InvalidCharLengthError> for PlanError {
     }
 }
 
+impl From<InvalidBitLengthError> for PlanError {
+    fn from(e: InvalidBitLengthError) -> PlanError {
+        PlanError::InvalidBitLength(e)
+    }
+}
+
 impl From<InvalidVarCharMaxLengthError> for PlanError {
     fn from(e: InvalidVarCharMaxLengthError) -> PlanError {
         PlanError::InvalidVarCharMaxLength(e)
@@ -1195,6 +1195,11 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Unions multiranges into a single multirange.
+    RangeAgg,
+    /// Intersects ranges into a single range, or multiranges into a single
+    /// multirange.
+    RangeIntersectAgg,
     /// A bundle of fused window aggregations: its input is a record, whose each
     /// component will be the input to one of the `AggregateFunc`s.
     ///
@@ -1278,6 +1283,8 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::RangeAgg => mz_expr::AggregateFunc::RangeAgg,
+            AggregateFunc::RangeIntersectAgg => mz_expr::AggregateFunc::RangeIntersectAgg,
             // `AggregateFunc::FusedWindowAgg` should be specially handled in
             // `AggregateWindowExpr::into_expr`.
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
@@ -1347,7 +1354,9 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg => Datum::Null,
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
                 // `identity_datum` is used only in HIR planning, and `FusedWindowAgg` can't occur
                 // in HIR planning, because it is introduced only during HIR transformation.
@@ -1432,6 +1441,8 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
+            | AggregateFunc::RangeAgg
+            | AggregateFunc::RangeIntersectAgg
             | AggregateFunc::Dummy => input_type.scalar_type,
             AggregateFunc::FusedWindowAgg { funcs } => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
@@ -2118,6 +2129,20 @@ impl HirRelationExpr {
         })
     }
 
+    /// Replaces any parameter references in the expression with placeholders of
+    /// the corresponding type in `param_types`.
+    ///
+    /// See [`HirScalarExpr::bind_parameter_placeholders`].
+    pub fn bind_parameter_placeholders(
+        &mut self,
+        param_types: &[ScalarType],
+    ) -> Result<(), PlanError> {
+        #[allow(deprecated)]
+        self.visit_scalar_expressions_mut(0, &mut |e: &mut HirScalarExpr, _: usize| {
+            e.bind_parameter_placeholders(param_types)
+        })
+    }
+
     pub fn contains_parameters(&self) -> Result<bool, PlanError> {
         let mut contains_parameters = false;
         #[allow(deprecated)]
@@ -2273,6 +2298,17 @@ impl HirRelationExpr {
         })?;
         Ok(contains)
     }
+
+    /// Whether the expression contains any [`UnmaterializableFunc`] call.
+    pub fn contains_unmaterializable(&self) -> Result<bool, RecursionLimitError> {
+        let mut contains = false;
+        self.visit_post(&mut |expr| {
+            expr.visit_children(|expr: &HirScalarExpr| {
+                contains = contains || expr.contains_unmaterializable()
+            })
+        })?;
+        Ok(contains)
+    }
 }
 
 impl CollectionPlan for HirRelationExpr {
@@ -3021,6 +3057,32 @@ impl HirScalarExpr {
         })
     }
 
+    /// Like [`HirScalarExpr::bind_parameters`], except that parameters are
+    /// replaced with [`UnmaterializableFunc::Parameter`] placeholders of the
+    /// corresponding type in `param_types` rather than a datum.
+    ///
+    /// This allows optimizing an expression once for all the values of its
+    /// parameters, which are bound when it is executed.
+    pub fn bind_parameter_placeholders(
+        &mut self,
+        param_types: &[ScalarType],
+    ) -> Result<(), PlanError> {
+        #[allow(deprecated)]
+        self.visit_recursively_mut(0, &mut |_: usize, e: &mut HirScalarExpr| {
+            if let HirScalarExpr::Parameter(n, name) = e {
+                let Some(typ) = param_types.get(*n - 1) else {
+                    return Err(PlanError::UnknownParameter(*n));
+                };
+                let func = UnmaterializableFunc::Parameter {
+                    position: *n,
+                    typ: typ.clone(),
+                };
+                *e = HirScalarExpr::CallUnmaterializable(func, name.clone());
+            }
+            Ok(())
+        })
+    }
+
     /// Like [`HirScalarExpr::bind_parameters`], except that parameters are
     /// replaced with the corresponding expression fragment from `params` rather
     /// than a datum.
@@ -3062,6 +3124,18 @@ impl HirScalarExpr {
         contains
     }
 
+    /// Whether the expression contains any [`UnmaterializableFunc`] call.
+    pub fn contains_unmaterializable(&self) -> bool {
+        let mut contains = false;
+        #[allow(deprecated)]
+        self.visit_post_nolimit(&mut |e| {
+            if let Self::CallUnmaterializable(..) = e {
+                contains = true;
+            }
+        });
+        contains
+    }
+
     /// Constructs an unnamed column reference in the current scope.
     /// Use [`HirScalarExpr::named_column`] when a name is known.
     /// Use [`HirScalarExpr::unnamed_column`] for a `ColumnRef`.
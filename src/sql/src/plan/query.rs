@@ -57,6 +57,7 @@ use mz_ore::id_gen::IdGen;
 use mz_ore::option::FallibleMapExt;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
+use mz_repr::adt::bit::BitLength;
 use mz_repr::adt::char::CharLength;
 use mz_repr::adt::numeric::{NUMERIC_DATUM_MAX_PRECISION, NumericMaxScale};
 use mz_repr::adt::timestamp::TimestampPrecision;
@@ -80,7 +81,7 @@ use mz_sql_parser::ast::{
 };
 use mz_sql_parser::ident;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{CatalogDomainCheck, CatalogItemType, CatalogType, SessionCatalog};
 use crate::func::{self, Func, FuncSpec};
 use crate::names::{
     Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
@@ -294,6 +295,9 @@ pub fn plan_insert_query(
             )
         })?
         .to_vec();
+    let domains = table
+        .writable_table_domains()
+        .expect("writable tables have domains");
 
     for default in &mut defaults {
         transform_ast::transform(scx, default)?;
@@ -400,6 +404,7 @@ pub fn plan_insert_query(
             qcx.humanize_scalar_type(&e.source_type, false),
         )
     })?;
+    let expr = plan_domain_columns(scx, expr, ordering.iter().map(|i| domains[*i]))?;
 
     // Fill in any omitted columns and rearrange into correct order
     let mut map_exprs = vec![];
@@ -413,7 +418,14 @@ pub fn plan_insert_query(
         if let Some(src_idx) = col_to_source.get(&col_idx) {
             project_key.push(*src_idx);
         } else {
-            let hir = plan_default_expr(scx, &default, &col_typ.scalar_type)?;
+            let mut hir = plan_default_expr(scx, &default, &col_typ.scalar_type)?;
+            if let Some(domain) = domains[col_idx] {
+                // Check the default against the domain's constraints via a
+                // column, so that it is only computed once.
+                let default_col = expr_arity + map_exprs.len();
+                map_exprs.push(hir);
+                hir = plan_domain_constraints(scx, domain, HirScalarExpr::column(default_col))?;
+            }
             project_key.push(expr_arity + map_exprs.len());
             map_exprs.push(hir);
         }
@@ -662,6 +674,9 @@ pub fn plan_copy_from_rows(
         .writable_table_details()
         .ok_or_else(|| sql_err!("cannot copy into non-writeable table"))?
         .to_vec();
+    let domains = table
+        .writable_table_domains()
+        .expect("writable tables have domains");
 
     for default in &mut defaults {
         transform_ast::transform(&scx, default)?;
@@ -684,14 +699,15 @@ pub fn plan_copy_from_rows(
     };
 
     // Exit early with just the raw constant if we know that all columns are present
-    // and in the correct order. This lets us bypass expensive downstream optimizations
-    // more easily, as at every stage we know this expression is nothing more than
-    // a constant (as opposed to e.g. a constant with with an identity map and identity
-    // projection).
+    // and in the correct order and that there are no domain constraints to enforce.
+    // This lets us bypass expensive downstream optimizations more easily, as at every
+    // stage we know this expression is nothing more than a constant (as opposed to
+    // e.g. a constant with with an identity map and identity projection).
     let default: Vec<_> = (0..desc.arity()).map(ColumnIndex::from_raw).collect();
-    if columns == default {
+    if columns == default && domains.iter().all(Option::is_none) {
         return Ok(expr);
     }
+    let expr = plan_domain_columns(&scx, expr, columns.iter().map(|i| domains[i.to_raw()]))?;
 
     // Fill in any omitted columns and rearrange into correct order
     let mut map_exprs = vec![];
@@ -705,7 +721,14 @@ pub fn plan_copy_from_rows(
         if let Some(src_idx) = col_to_source.get(&col_idx) {
             project_key.push(*src_idx);
         } else {
-            let hir = plan_default_expr(&scx, &default, &col_typ.scalar_type)?;
+            let mut hir = plan_default_expr(&scx, &default, &col_typ.scalar_type)?;
+            if let Some(domain) = domains[col_idx.to_raw()] {
+                // Check the default against the domain's constraints via a
+                // column, so that it is only computed once.
+                let default_col = typ.arity() + map_exprs.len();
+                map_exprs.push(hir);
+                hir = plan_domain_constraints(&scx, domain, HirScalarExpr::column(default_col))?;
+            }
             project_key.push(typ.arity() + map_exprs.len());
             map_exprs.push(hir);
         }
@@ -714,6 +737,32 @@ pub fn plan_copy_from_rows(
     Ok(expr.map(map_exprs).project(project_key))
 }
 
+/// Enforces the constraints of `domains`, which describe the domain type, if
+/// any, of each column of `expr`.
+fn plan_domain_columns(
+    scx: &StatementContext,
+    expr: HirRelationExpr,
+    domains: impl IntoIterator<Item = Option<CatalogItemId>>,
+) -> Result<HirRelationExpr, PlanError> {
+    let arity = expr.arity();
+    let mut map_exprs = vec![];
+    let mut project_key = Vec::with_capacity(arity);
+    for (i, domain) in domains.into_iter().enumerate() {
+        match domain {
+            Some(domain) => {
+                let hir = plan_domain_constraints(scx, domain, HirScalarExpr::column(i))?;
+                project_key.push(arity + map_exprs.len());
+                map_exprs.push(hir);
+            }
+            None => project_key.push(i),
+        }
+    }
+    if map_exprs.is_empty() {
+        return Ok(expr);
+    }
+    Ok(expr.map(map_exprs).project(project_key))
+}
+
 /// Common information used for DELETE, UPDATE, and INSERT INTO ... SELECT plans.
 pub struct ReadThenWritePlan {
     pub id: CatalogItemId,
@@ -785,7 +834,7 @@ pub fn plan_mutation_query_inner(
             table_name.full_name_str()
         );
     }
-    let _ = item.writable_table_details().ok_or_else(|| {
+    let domains = item.writable_table_domains().ok_or_else(|| {
         sql_err!(
             "cannot mutate non-writeable table '{}'",
             table_name.full_name_str()
@@ -839,11 +888,14 @@ pub fn plan_mutation_query_inner(
                     allow_parameters: true,
                     allow_windows: false,
                 };
-                let expr = plan_expr(ecx, &value)?.cast_to(
+                let mut expr = plan_expr(ecx, &value)?.cast_to(
                     ecx,
                     CastContext::Assignment,
                     &typ.scalar_type,
                 )?;
+                if let Some(domain) = domains[idx] {
+                    expr = plan_domain_constraints(qcx.scx, domain, expr)?;
+                }
 
                 if sets.insert(idx, expr).is_some() {
                     sql_bail!("column {} set twice", name)
@@ -2063,6 +2115,9 @@ fn plan_set_expr(
                 ShowStatement::ShowObjects(stmt) => {
                     show::show_objects(qcx.scx, stmt)?.plan_hir(qcx)
                 }
+                ShowStatement::ShowHistoryRetention(stmt) => {
+                    show::show_history_retention(qcx.scx, stmt)?.plan_hir(qcx)
+                }
                 ShowStatement::ShowVariable(_) => bail_unsupported!("SHOW variable in subqueries"),
                 ShowStatement::InspectShard(_) => sql_bail!("unsupported INSPECT statement"),
             }
@@ -4087,6 +4142,10 @@ fn plan_cast(
     let ecx = &ecx.with_name("CAST");
     let expr = typeconv::plan_coerce(ecx, expr, &to_scalar_type)?;
     let expr = typeconv::plan_cast(ecx, CastContext::Explicit, expr, &to_scalar_type)?;
+    let expr = match domain_id_from_sql(ecx.qcx.scx, data_type) {
+        Some(id) => plan_domain_constraints(ecx.qcx.scx, id, expr)?,
+        None => expr,
+    };
     Ok(expr.into())
 }
 
@@ -5983,6 +6042,28 @@ pub fn scalar_type_from_catalog(
             }
             Ok(ScalarType::VarChar { max_length: length })
         }
+        CatalogType::Bit => {
+            let mut modifiers = modifiers.iter().fuse();
+            let length = match modifiers.next() {
+                Some(l) => Some(BitLength::try_from(*l)?),
+                None => Some(BitLength::ONE),
+            };
+            if modifiers.next().is_some() {
+                sql_bail!("type bit supports at most one type modifier");
+            }
+            Ok(ScalarType::Bit { length })
+        }
+        CatalogType::VarBit => {
+            let mut modifiers = modifiers.iter().fuse();
+            let length = match modifiers.next() {
+                Some(l) => Some(BitLength::try_from(*l)?),
+                None => None,
+            };
+            if modifiers.next().is_some() {
+                sql_bail!("type bit varying supports at most one type modifier");
+            }
+            Ok(ScalarType::VarBit { max_length: length })
+        }
         CatalogType::Timestamp => {
             let mut modifiers = modifiers.iter().fuse();
             let precision = match modifiers.next() {
@@ -6049,6 +6130,16 @@ pub fn scalar_type_from_catalog(
                 } => Ok(ScalarType::Range {
                     element_type: Box::new(scalar_type_from_catalog(catalog, *element_id, &[])?),
                 }),
+                CatalogType::MultiRange {
+                    element_reference: element_id,
+                } => Ok(ScalarType::MultiRange {
+                    element_type: Box::new(scalar_type_from_catalog(catalog, *element_id, &[])?),
+                }),
+                CatalogType::Domain {
+                    base_reference,
+                    base_modifiers,
+                    ..
+                } => scalar_type_from_catalog(catalog, *base_reference, base_modifiers),
                 CatalogType::Record { fields } => {
                     let scalars: Box<[(ColumnName, ColumnType)]> = fields
                         .iter()
@@ -6107,6 +6198,8 @@ pub fn scalar_type_from_catalog(
                 CatalogType::Numeric => unreachable!("handled above"),
                 CatalogType::Char => unreachable!("handled above"),
                 CatalogType::VarChar => unreachable!("handled above"),
+                CatalogType::Bit => unreachable!("handled above"),
+                CatalogType::VarBit => unreachable!("handled above"),
                 CatalogType::Timestamp => unreachable!("handled above"),
                 CatalogType::TimestampTz => unreachable!("handled above"),
             }
@@ -6114,6 +6207,206 @@ pub fn scalar_type_from_catalog(
     }
 }
 
+/// Returns the ID of the domain type that `data_type` refers to, if any.
+pub fn domain_id_from_sql(
+    scx: &StatementContext,
+    data_type: &ResolvedDataType,
+) -> Option<CatalogItemId> {
+    match data_type {
+        ResolvedDataType::Named { id, .. } => match scx.catalog.get_item(id).type_details() {
+            Some(details) if matches!(details.typ, CatalogType::Domain { .. }) => Some(*id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Plans the `CHECK` constraint `check` of a domain whose base type is
+/// `base_type`.
+///
+/// The returned expression refers to the value being checked, spelled `VALUE`
+/// in `check`, as column 0.
+pub fn plan_domain_check(
+    scx: &StatementContext,
+    base_type: &ScalarType,
+    check: &Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut check = check.clone();
+    transform_ast::transform(scx, &mut check)?;
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "CHECK constraint",
+        scope: &Scope::from_source(None, [ColumnName::from("value")]),
+        relation_type: &RelationType::new(vec![base_type.clone().nullable(true)]),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    plan_expr(ecx, &check)?.type_as(ecx, &ScalarType::Bool)
+}
+
+/// A constraint of a domain type.
+struct DomainConstraint {
+    /// Evaluates to true if the value being checked, which it refers to as
+    /// column 0, violates the constraint.
+    violated: HirScalarExpr,
+    /// The error to report if the constraint is violated.
+    message: String,
+}
+
+/// Wraps `expr`, which must be of the base type of the domain `id`, such that
+/// evaluating it errors if its value violates a constraint of the domain or of
+/// any domain that the domain is defined over.
+///
+/// If `id` does not refer to a domain, returns `expr` unchanged.
+pub fn plan_domain_constraints(
+    scx: &StatementContext,
+    id: CatalogItemId,
+    expr: HirScalarExpr,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut constraints = vec![];
+    match collect_domain_constraints(scx, id, &mut constraints)? {
+        Some(base_type) => Ok(apply_domain_constraints(&base_type, constraints, expr)),
+        None => Ok(expr),
+    }
+}
+
+/// Pushes the constraints of the domain `id` onto `constraints`, preceded by
+/// those of any domain that the domain is defined over, and returns the
+/// domain's base type.
+///
+/// If `id` does not refer to a domain, returns `None`.
+fn collect_domain_constraints(
+    scx: &StatementContext,
+    id: CatalogItemId,
+    constraints: &mut Vec<DomainConstraint>,
+) -> Result<Option<ScalarType>, PlanError> {
+    let entry = scx.catalog.get_item(&id);
+    let Some(CatalogType::Domain {
+        base_reference,
+        base_modifiers,
+        not_null,
+        checks,
+    }) = entry.type_details().map(|details| &details.typ)
+    else {
+        return Ok(None);
+    };
+    collect_domain_constraints(scx, *base_reference, constraints)?;
+    let base_type = scalar_type_from_catalog(scx.catalog, *base_reference, base_modifiers)?;
+    plan_domain_constraint_list(
+        scx,
+        &entry.name().item,
+        &base_type,
+        *not_null,
+        checks,
+        constraints,
+    )?;
+    Ok(Some(base_type))
+}
+
+/// Wraps `expr`, which must be of type `base_type`, such that evaluating it
+/// errors if its value violates `not_null` or any of `checks`, the constraints
+/// of the domain named `domain_name`.
+pub fn enforce_domain_constraints(
+    scx: &StatementContext,
+    domain_name: &str,
+    base_type: &ScalarType,
+    not_null: bool,
+    checks: &[CatalogDomainCheck],
+    expr: HirScalarExpr,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut constraints = vec![];
+    plan_domain_constraint_list(
+        scx,
+        domain_name,
+        base_type,
+        not_null,
+        checks,
+        &mut constraints,
+    )?;
+    Ok(apply_domain_constraints(base_type, constraints, expr))
+}
+
+/// Plans `not_null` and `checks`, the constraints of the domain named
+/// `domain_name`, and pushes them onto `constraints`.
+fn plan_domain_constraint_list(
+    scx: &StatementContext,
+    domain_name: &str,
+    base_type: &ScalarType,
+    not_null: bool,
+    checks: &[CatalogDomainCheck],
+    constraints: &mut Vec<DomainConstraint>,
+) -> Result<(), PlanError> {
+    if not_null {
+        constraints.push(DomainConstraint {
+            violated: HirScalarExpr::column(0).call_is_null(),
+            message: format!("domain {} does not allow null values", domain_name),
+        });
+    }
+    for check in checks {
+        let cond = plan_domain_check(scx, base_type, &check.expr)?;
+        // As in PostgreSQL, a constraint that evaluates to NULL is satisfied.
+        constraints.push(DomainConstraint {
+            violated: cond.call_unary(UnaryFunc::IsFalse(expr_func::IsFalse)),
+            message: format!(
+                "value for domain {} violates check constraint {}",
+                domain_name,
+                check.name.quoted()
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Wraps `expr`, which must be of type `base_type`, such that evaluating it
+/// reports the error of the first of `constraints` that its value violates.
+///
+/// The constraints are checked against `expr` itself rather than against the
+/// result of checking the preceding constraints, so the size of the returned
+/// expression is linear in the number of constraints. Callers that plan the
+/// value as a column of a relation should pass a reference to that column, so
+/// that the value is computed only once.
+fn apply_domain_constraints(
+    base_type: &ScalarType,
+    constraints: Vec<DomainConstraint>,
+    expr: HirScalarExpr,
+) -> HirScalarExpr {
+    let mut result = expr.clone();
+    for DomainConstraint {
+        mut violated,
+        message,
+    } in constraints.into_iter().rev()
+    {
+        // Substitute the value being checked for `VALUE`. Check constraints
+        // cannot contain subqueries, so `VALUE` is always at level 0.
+        #[allow(deprecated)]
+        let _ = violated.visit_recursively_mut(0, &mut |_depth, e: &mut HirScalarExpr| {
+            if let HirScalarExpr::Column(
+                ColumnRef {
+                    level: 0,
+                    column: 0,
+                },
+                _,
+            ) = e
+            {
+                *e = expr.clone();
+            }
+            Ok::<_, ()>(())
+        });
+        let error = HirScalarExpr::call_variadic(
+            VariadicFunc::ErrorIfNull,
+            vec![
+                HirScalarExpr::literal_null(base_type.clone()),
+                HirScalarExpr::literal(Datum::String(&message), ScalarType::String),
+            ],
+        );
+        result = HirScalarExpr::if_then_else(violated, error, result);
+    }
+    result
+}
+
 /// This is used to collect aggregates and table functions from within an `Expr`.
 /// See the explanation of aggregate handling at the top of the file for more details.
 struct AggregateTableFuncVisitor<'a> {
@@ -138,6 +138,10 @@ pub fn describe(
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
         Statement::AlterTableAddColumn(stmt) => ddl::describe_alter_table_add_column(&scx, stmt)?,
+        Statement::AlterTableAlterColumnType(stmt) => {
+            ddl::describe_alter_table_alter_column_type(&scx, stmt)?
+        }
+        Statement::AlterTableDropColumn(stmt) => ddl::describe_alter_table_drop_column(&scx, stmt)?,
         Statement::AlterNetworkPolicy(stmt) => ddl::describe_alter_network_policy(&scx, stmt)?,
         Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
@@ -148,6 +152,7 @@ pub fn describe(
         Statement::CreateRole(stmt) => ddl::describe_create_role(&scx, stmt)?,
         Statement::CreateSchema(stmt) => ddl::describe_create_schema(&scx, stmt)?,
         Statement::CreateSecret(stmt) => ddl::describe_create_secret(&scx, stmt)?,
+        Statement::CreateEndpoint(stmt) => ddl::describe_create_endpoint(&scx, stmt)?,
         Statement::CreateSink(stmt) => ddl::describe_create_sink(&scx, stmt)?,
         Statement::CreateWebhookSource(stmt) => ddl::describe_create_webhook_source(&scx, stmt)?,
         Statement::CreateSource(stmt) => ddl::describe_create_source(&scx, stmt)?,
@@ -165,6 +170,7 @@ pub fn describe(
         Statement::CreateNetworkPolicy(stmt) => ddl::describe_create_network_policy(&scx, stmt)?,
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::DropOwned(stmt) => ddl::describe_drop_owned(&scx, stmt)?,
+        Statement::DropSubscription(stmt) => ddl::describe_drop_subscription(&scx, stmt)?,
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::describe_alter_owner(&scx, stmt)?,
@@ -208,6 +214,9 @@ pub fn describe(
         Statement::Show(ShowStatement::ShowObjects(stmt)) => {
             show::show_objects(&scx, stmt)?.describe()?
         }
+        Statement::Show(ShowStatement::ShowHistoryRetention(stmt)) => {
+            show::show_history_retention(&scx, stmt)?.describe()?
+        }
 
         // SCL statements.
         Statement::Close(stmt) => scl::describe_close(&scx, stmt)?,
@@ -216,9 +225,11 @@ pub fn describe(
         Statement::Discard(stmt) => scl::describe_discard(&scx, stmt)?,
         Statement::Execute(stmt) => scl::describe_execute(&scx, stmt)?,
         Statement::Fetch(stmt) => scl::describe_fetch(&scx, stmt)?,
+        Statement::Listen(stmt) => scl::describe_listen(&scx, stmt)?,
         Statement::Prepare(stmt) => scl::describe_prepare(&scx, stmt)?,
         Statement::ResetVariable(stmt) => scl::describe_reset_variable(&scx, stmt)?,
         Statement::SetVariable(stmt) => scl::describe_set_variable(&scx, stmt)?,
+        Statement::Unlisten(stmt) => scl::describe_unlisten(&scx, stmt)?,
         Statement::Show(ShowStatement::ShowVariable(stmt)) => {
             scl::describe_show_variable(&scx, stmt)?
         }
@@ -226,6 +237,7 @@ pub fn describe(
         // DML statements.
         Statement::Copy(stmt) => dml::describe_copy(&scx, stmt)?,
         Statement::Delete(stmt) => dml::describe_delete(&scx, stmt)?,
+        Statement::ExecuteEndpoint(stmt) => dml::describe_execute_endpoint(&scx, stmt)?,
         Statement::ExplainPlan(stmt) => dml::describe_explain_plan(&scx, stmt)?,
         Statement::ExplainPushdown(stmt) => dml::describe_explain_pushdown(&scx, stmt)?,
         Statement::ExplainAnalyze(stmt) => dml::describe_explain_analyze(&scx, stmt)?,
@@ -243,6 +255,7 @@ pub fn describe(
         Statement::StartTransaction(stmt) => tcl::describe_start_transaction(&scx, stmt)?,
 
         // Other statements.
+        Statement::Notify(stmt) => scl::describe_notify(&scx, stmt)?,
         Statement::Raise(stmt) => raise::describe_raise(&scx, stmt)?,
         Statement::Show(ShowStatement::InspectShard(stmt)) => {
             scl::describe_inspect_shard(&scx, stmt)?
@@ -328,6 +341,10 @@ pub fn plan(
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
         Statement::AlterTableAddColumn(stmt) => ddl::plan_alter_table_add_column(scx, stmt),
+        Statement::AlterTableAlterColumnType(stmt) => {
+            ddl::plan_alter_table_alter_column_type(scx, stmt)
+        }
+        Statement::AlterTableDropColumn(stmt) => ddl::plan_alter_table_drop_column(scx, stmt),
         Statement::AlterNetworkPolicy(stmt) => ddl::plan_alter_network_policy(scx, stmt),
         Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
@@ -338,6 +355,7 @@ pub fn plan(
         Statement::CreateRole(stmt) => ddl::plan_create_role(scx, stmt),
         Statement::CreateSchema(stmt) => ddl::plan_create_schema(scx, stmt),
         Statement::CreateSecret(stmt) => ddl::plan_create_secret(scx, stmt),
+        Statement::CreateEndpoint(stmt) => ddl::plan_create_endpoint(scx, stmt),
         Statement::CreateSink(stmt) => ddl::plan_create_sink(scx, stmt),
         Statement::CreateWebhookSource(stmt) => ddl::plan_create_webhook_source(scx, stmt),
         Statement::CreateSource(stmt) => ddl::plan_create_source(scx, stmt),
@@ -351,6 +369,7 @@ pub fn plan(
         Statement::CreateNetworkPolicy(stmt) => ddl::plan_create_network_policy(scx, stmt),
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::DropOwned(stmt) => ddl::plan_drop_owned(scx, stmt),
+        Statement::DropSubscription(stmt) => ddl::plan_drop_subscription(scx, stmt),
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::plan_alter_owner(scx, stmt),
@@ -364,6 +383,7 @@ pub fn plan(
         // DML statements.
         Statement::Copy(stmt) => dml::plan_copy(scx, stmt),
         Statement::Delete(stmt) => dml::plan_delete(scx, stmt, params),
+        Statement::ExecuteEndpoint(stmt) => dml::plan_execute_endpoint(scx, stmt),
         Statement::ExplainPlan(stmt) => dml::plan_explain_plan(scx, stmt, params),
         Statement::ExplainPushdown(stmt) => dml::plan_explain_pushdown(scx, stmt, params),
         Statement::ExplainAnalyze(stmt) => dml::plan_explain_analyze(scx, stmt, params),
@@ -401,6 +421,9 @@ pub fn plan(
             show::plan_show_create_materialized_view(scx, stmt).map(Plan::ShowCreate)
         }
         Statement::Show(ShowStatement::ShowObjects(stmt)) => show::show_objects(scx, stmt)?.plan(),
+        Statement::Show(ShowStatement::ShowHistoryRetention(stmt)) => {
+            show::show_history_retention(scx, stmt)?.plan()
+        }
 
         // SCL statements.
         Statement::Close(stmt) => scl::plan_close(scx, stmt),
@@ -409,9 +432,11 @@ pub fn plan(
         Statement::Discard(stmt) => scl::plan_discard(scx, stmt),
         Statement::Execute(stmt) => scl::plan_execute(scx, stmt),
         Statement::Fetch(stmt) => scl::plan_fetch(scx, stmt),
+        Statement::Listen(stmt) => scl::plan_listen(scx, stmt),
         Statement::Prepare(stmt) => scl::plan_prepare(scx, stmt),
         Statement::ResetVariable(stmt) => scl::plan_reset_variable(scx, stmt),
         Statement::SetVariable(stmt) => scl::plan_set_variable(scx, stmt),
+        Statement::Unlisten(stmt) => scl::plan_unlisten(scx, stmt),
         Statement::Show(ShowStatement::ShowVariable(stmt)) => scl::plan_show_variable(scx, stmt),
 
         // TCL statements.
@@ -421,6 +446,7 @@ pub fn plan(
         Statement::StartTransaction(stmt) => tcl::plan_start_transaction(scx, stmt),
 
         // Other statements.
+        Statement::Notify(stmt) => scl::plan_notify(scx, stmt),
         Statement::Raise(stmt) => raise::plan_raise(scx, stmt),
         Statement::Show(ShowStatement::InspectShard(stmt)) => scl::plan_inspect_shard(scx, stmt),
         Statement::ValidateConnection(stmt) => validate::plan_validate_connection(scx, stmt),
@@ -465,7 +491,8 @@ impl PartialEq<ObjectType> for CatalogItemType {
             | (CatalogItemType::Index, ObjectType::Index)
             | (CatalogItemType::Type, ObjectType::Type)
             | (CatalogItemType::Secret, ObjectType::Secret)
-            | (CatalogItemType::Connection, ObjectType::Connection) => true,
+            | (CatalogItemType::Connection, ObjectType::Connection)
+            | (CatalogItemType::Endpoint, ObjectType::Endpoint) => true,
             (_, _) => false,
         }
     }
@@ -1044,6 +1071,8 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterSystemReset(_) => DDL,
             Statement::AlterSystemResetAll(_) => DDL,
             Statement::AlterTableAddColumn(_) => DDL,
+            Statement::AlterTableAlterColumnType(_) => DDL,
+            Statement::AlterTableDropColumn(_) => DDL,
             Statement::Comment(_) => DDL,
             Statement::CreateCluster(_) => DDL,
             Statement::CreateClusterReplica(_) => DDL,
@@ -1054,6 +1083,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateRole(_) => DDL,
             Statement::CreateSchema(_) => DDL,
             Statement::CreateSecret(_) => DDL,
+            Statement::CreateEndpoint(_) => DDL,
             Statement::CreateSink(_) => DDL,
             Statement::CreateWebhookSource(_) => DDL,
             Statement::CreateSource(_) => DDL,
@@ -1066,6 +1096,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateNetworkPolicy(_) => DDL,
             Statement::DropObjects(_) => DDL,
             Statement::DropOwned(_) => DDL,
+            Statement::DropSubscription(_) => DDL,
 
             // `ACL` statements.
             Statement::AlterOwner(_) => ACL,
@@ -1079,6 +1110,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             // DML statements.
             Statement::Copy(_) => DML,
             Statement::Delete(_) => DML,
+            Statement::ExecuteEndpoint(_) => DML,
             Statement::ExplainPlan(_) => DML,
             Statement::ExplainPushdown(_) => DML,
             Statement::ExplainAnalyze(_) => DML,
@@ -1100,6 +1132,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::Show(ShowStatement::ShowCreateView(_)) => Show,
             Statement::Show(ShowStatement::ShowCreateMaterializedView(_)) => Show,
             Statement::Show(ShowStatement::ShowObjects(_)) => Show,
+            Statement::Show(ShowStatement::ShowHistoryRetention(_)) => Show,
 
             // SCL statements.
             Statement::Close(_) => SCL,
@@ -1108,9 +1141,11 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::Discard(_) => SCL,
             Statement::Execute(_) => SCL,
             Statement::Fetch(_) => SCL,
+            Statement::Listen(_) => SCL,
             Statement::Prepare(_) => SCL,
             Statement::ResetVariable(_) => SCL,
             Statement::SetVariable(_) => SCL,
+            Statement::Unlisten(_) => SCL,
             Statement::Show(ShowStatement::ShowVariable(_)) => SCL,
 
             // TCL statements.
@@ -1120,6 +1155,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::StartTransaction(_) => TCL,
 
             // Other statements.
+            Statement::Notify(_) => Other,
             Statement::Raise(_) => Other,
             Statement::Show(ShowStatement::InspectShard(_)) => Other,
             Statement::ValidateConnection(_) => Other,
@@ -611,6 +611,7 @@ fn privilege_to_acl_mode(privilege: Privilege) -> AclMode {
         Privilege::CREATEDB => AclMode::CREATE_DB,
         Privilege::CREATECLUSTER => AclMode::CREATE_CLUSTER,
         Privilege::CREATENETWORKPOLICY => AclMode::CREATE_NETWORK_POLICY,
+        Privilege::EXECUTE => AclMode::EXECUTE,
     }
 }
 
@@ -665,7 +666,8 @@ pub fn plan_alter_default_privileges(
         | ObjectType::Cluster
         | ObjectType::Database
         | ObjectType::Schema
-        | ObjectType::NetworkPolicy => {}
+        | ObjectType::NetworkPolicy
+        | ObjectType::Endpoint => {}
     }
 
     let acl_mode = privilege_spec_to_acl_mode(
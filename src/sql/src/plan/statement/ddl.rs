@@ -34,6 +34,7 @@ use mz_postgres_util::tunnel::PostgresFlavor;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::numeric::NUMERIC_DATUM_MAX_PRECISION;
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatureOverrides;
 use mz_repr::refresh_schedule::{RefreshEvery, RefreshSchedule};
@@ -51,35 +52,37 @@ use mz_sql_parser::ast::{
     AlterSetClusterStatement, AlterSinkAction, AlterSinkStatement, AlterSourceAction,
     AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName, AlterSourceStatement,
     AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
-    AlterTableAddColumnStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName,
+    AlterTableAddColumnStatement, AlterTableAlterColumnTypeStatement,
+    AlterTableDropColumnStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName,
     ClusterAlterOption, ClusterAlterOptionName, ClusterAlterOptionValue,
     ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName, ClusterFeature,
     ClusterFeatureName, ClusterOption, ClusterOptionName, ClusterScheduleOptionValue, ColumnDef,
-    ColumnOption, CommentObjectType, CommentStatement, ConnectionOption, ConnectionOptionName,
-    ContinualTaskOption, ContinualTaskOptionName, CreateClusterReplicaStatement,
-    CreateClusterStatement, CreateConnectionOption, CreateConnectionOptionName,
-    CreateConnectionStatement, CreateConnectionType, CreateContinualTaskStatement,
-    CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateNetworkPolicyStatement, CreateRoleStatement, CreateSchemaStatement,
-    CreateSecretStatement, CreateSinkConnection, CreateSinkOption, CreateSinkOptionName,
-    CreateSinkStatement, CreateSourceConnection, CreateSourceOption, CreateSourceOptionName,
-    CreateSourceStatement, CreateSubsourceOption, CreateSubsourceOptionName,
-    CreateSubsourceStatement, CreateTableFromSourceStatement, CreateTableStatement, CreateTypeAs,
-    CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName,
-    CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
-    CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf,
-    CsvColumns, DeferredItemName, DocOnIdentifier, DocOnSchema, DropObjectsStatement,
-    DropOwnedStatement, Expr, Format, FormatSpecifier, Ident, IfExistsBehavior, IndexOption,
+    ColumnOption, ColumnVersioned, CommentObjectType, CommentStatement, ConnectionOption,
+    ConnectionOptionName, ContinualTaskOption, ContinualTaskOptionName,
+    CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionOption,
+    CreateConnectionOptionName, CreateConnectionStatement, CreateConnectionType,
+    CreateContinualTaskStatement, CreateDatabaseStatement, CreateEndpointStatement,
+    CreateIndexStatement, CreateMaterializedViewStatement, CreateNetworkPolicyStatement,
+    CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection,
+    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection,
+    CreateSourceOption, CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
+    CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
+    CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
+    CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
+    DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement,
+    DropSubscriptionStatement, Expr, Format, FormatSpecifier, Ident, IfExistsBehavior, IndexOption,
     IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
     LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
     MySqlConfigOptionName, NetworkPolicyOption, NetworkPolicyOptionName,
     NetworkPolicyRuleDefinition, NetworkPolicyRuleOption, NetworkPolicyRuleOptionName,
-    PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica, RefreshAtOptionValue,
-    RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition, ReplicaOption,
-    ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
-    SqlServerConfigOption, SqlServerConfigOptionName, Statement, TableConstraint,
-    TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName, TableOption,
-    TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
+    PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica, RawDataType,
+    RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition,
+    ReplicaOption, ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy,
+    SourceIncludeMetadata, SqlServerConfigOption, SqlServerConfigOptionName, Statement,
+    TableConstraint, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
+    TableOption, TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
     UnresolvedSchemaName, Value, ViewDefinition, WithOptionValue,
 };
 use mz_sql_parser::ident;
@@ -122,8 +125,9 @@ use prost::Message;
 
 use crate::ast::display::AstDisplay;
 use crate::catalog::{
-    CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
-    CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
+    CatalogCluster, CatalogDatabase, CatalogDomainCheck, CatalogError, CatalogItem,
+    CatalogItemType, CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType,
+    SystemObjectType,
 };
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
@@ -148,18 +152,19 @@ use crate::plan::{
     AlterNetworkPolicyPlan, AlterNoopPlan, AlterOptionParameter, AlterRetainHistoryPlan,
     AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan, AlterSecretPlan,
     AlterSetClusterPlan, AlterSinkPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, AlterTablePlan, ClusterSchedule, CommentPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, ConnectionDetails, CreateClusterManagedPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    CreateConnectionPlan, CreateContinualTaskPlan, CreateDatabasePlan, CreateIndexPlan,
+    AlterSystemSetPlan, AlterTableDropColumnPlan, AlterTablePlan, ClusterSchedule, CommentPlan,
+    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
+    CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan,
+    CreateContinualTaskPlan, CreateDatabasePlan, CreateEndpointPlan, CreateIndexPlan,
     CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateRolePlan, CreateSchemaPlan,
     CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, HirRelationExpr, Index,
-    Ingestion, MaterializedView, NetworkPolicyRule, NetworkPolicyRuleAction,
-    NetworkPolicyRuleDirection, Plan, PlanClusterOption, PlanNotice, PolicyAddress, QueryContext,
-    ReplicaConfig, Secret, Sink, Source, Table, TableDataSource, Type, VariableValue, View,
-    WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal,
-    plan_utils, query, transform_ast,
+    CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, DropSubscriptionPlan, Endpoint,
+    EndpointQuery, HirRelationExpr, Index, Ingestion, MaterializedView, NetworkPolicyRule,
+    NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Plan, PlanClusterOption, PlanNotice,
+    PolicyAddress, QueryContext, ReplicaConfig, Secret, Sink, Source, Table, TableDataSource, Type,
+    VariableValue, View, WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders,
+    WebhookValidation, literal, plan_utils, query, transform_ast,
 };
 use crate::session::vars::{
     self, ENABLE_CLUSTER_SCHEDULE_REFRESH, ENABLE_COLLECTION_PARTITION_BY,
@@ -283,11 +288,16 @@ pub fn plan_create_table(
             // This set of `names` is used to create the initial RelationDesc.
             // Columns that have been added at later versions of the table will
             // get added further below.
-            let is_versioned = c
-                .options
-                .iter()
-                .any(|o| matches!(o.option, ColumnOption::Versioned { .. }));
-            !is_versioned
+            let is_added = c.options.iter().any(|o| {
+                matches!(
+                    o.option,
+                    ColumnOption::Versioned {
+                        action: ColumnVersioned::Added,
+                        ..
+                    }
+                )
+            });
+            !is_added
         })
         .map(|c| normalize::column_name(c.name.clone()))
         .collect();
@@ -300,6 +310,7 @@ pub fn plan_create_table(
     // and NOT NULL constraints.
     let mut column_types = Vec::with_capacity(columns.len());
     let mut defaults = Vec::with_capacity(columns.len());
+    let mut domains = Vec::with_capacity(columns.len());
     let mut changes = BTreeMap::new();
     let mut keys = Vec::new();
 
@@ -308,7 +319,7 @@ pub fn plan_create_table(
         let ty = query::scalar_type_from_sql(scx, aug_data_type)?;
         let mut nullable = true;
         let mut default = Expr::null();
-        let mut versioned = false;
+        let mut versions = Vec::new();
         for option in &c.options {
             match &option.option {
                 ColumnOption::NotNull => nullable = false,
@@ -327,25 +338,40 @@ pub fn plan_create_table(
                     }
                 }
                 ColumnOption::Versioned { action, version } => {
-                    let version = RelationVersion::from(*version);
-                    versioned = true;
-
-                    let name = normalize::column_name(c.name.clone());
-                    let typ = ty.clone().nullable(nullable);
-
-                    changes.insert(version, (action.clone(), name, typ));
+                    versions.push((action.clone(), RelationVersion::from(*version)));
                 }
                 other => {
                     bail_unsupported!(format!("CREATE TABLE with column constraint: {}", other))
                 }
             }
         }
-        // TODO(alter_table): This assumes all versioned columns are at the
-        // end. This will no longer be true when we support dropping columns.
-        if !versioned {
-            column_types.push(ty.nullable(nullable));
+        let name = normalize::column_name(c.name.clone());
+        let typ = ty.nullable(nullable);
+        let is_added = versions
+            .iter()
+            .any(|(action, _)| *action == ColumnVersioned::Added);
+        let is_dropped = versions
+            .iter()
+            .any(|(action, _)| *action == ColumnVersioned::Dropped);
+        for (action, version) in versions {
+            if changes
+                .insert(version, (action, name.clone(), typ.clone()))
+                .is_some()
+            {
+                sql_bail!("relation version {version} specified more than once");
+            }
+        }
+        // N.B. Added columns are always at the end, they'll get appended to
+        // the initial RelationDesc further below.
+        if !is_added {
+            column_types.push(typ);
+        }
+        // Defaults and domains are only tracked for columns in the latest
+        // version of the table.
+        if !is_dropped {
+            defaults.push(default);
+            domains.push(query::domain_id_from_sql(scx, aug_data_type));
         }
-        defaults.push(default);
     }
 
     let mut seen_primary = false;
@@ -442,8 +468,12 @@ pub fn plan_create_table(
 
     let desc = RelationDesc::new(typ, names);
     let mut desc = VersionedRelationDesc::new(desc);
-    for (version, (_action, name, typ)) in changes.into_iter() {
-        let new_version = desc.add_column(name, typ);
+    for (version, (action, name, typ)) in changes.into_iter() {
+        let new_version = match action {
+            ColumnVersioned::Added => desc.add_column(name, typ),
+            ColumnVersioned::Dropped => desc.drop_column(name),
+            ColumnVersioned::Altered => desc.alter_column_type(name, typ),
+        };
         if version != new_version {
             return Err(PlanError::InvalidTable {
                 name: full_name.item,
@@ -475,7 +505,7 @@ pub fn plan_create_table(
         desc,
         temporary,
         compaction_window,
-        data_source: TableDataSource::TableWrites { defaults },
+        data_source: TableDataSource::TableWrites { defaults, domains },
     };
     Ok(Plan::CreateTable(CreateTablePlan {
         name,
@@ -3024,7 +3054,8 @@ pub fn plan_create_continual_task(
         | CatalogItemType::Type
         | CatalogItemType::Func
         | CatalogItemType::Secret
-        | CatalogItemType::Connection => {
+        | CatalogItemType::Connection
+        | CatalogItemType::Endpoint => {
             sql_bail!(
                 "CONTINUAL TASK cannot use {} as an input",
                 input.item_type()
@@ -4172,6 +4203,63 @@ pub fn plan_create_type(
             }
             CatalogType::Record { fields }
         }
+        CreateTypeAs::Domain { data_type, options } => {
+            let (base_id, base_modifiers) = match data_type {
+                ResolvedDataType::Named { id, modifiers, .. } => (id, modifiers),
+                _ => sql_bail!(
+                    "CREATE DOMAIN can only use named data types, but found unnamed data type {}. \
+                    Use CREATE TYPE to create a named type first",
+                    data_type.human_readable_name(),
+                ),
+            };
+            let base_type = scalar_type_from_catalog(scx.catalog, base_id, &base_modifiers)?;
+            let domain_name = normalize::unresolved_item_name(name.clone())?.item;
+
+            let mut not_null = None;
+            let mut checks = vec![];
+            for option in options {
+                match option.option {
+                    ColumnOption::NotNull | ColumnOption::Null => {
+                        let is_not_null = matches!(option.option, ColumnOption::NotNull);
+                        if not_null.is_some_and(|not_null| not_null != is_not_null) {
+                            sql_bail!("conflicting NULL/NOT NULL constraints");
+                        }
+                        not_null = Some(is_not_null);
+                    }
+                    ColumnOption::Check(expr) => {
+                        // Ensure the expression can be planned, yields a
+                        // boolean, and is stable across evaluations.
+                        let check = query::plan_domain_check(scx, &base_type, &expr)?;
+                        if check.lower_uncorrelated()?.contains_unmaterializable() {
+                            sql_bail!(
+                                "CHECK constraint on domain cannot call unmaterializable functions"
+                            );
+                        }
+                        // Match PostgreSQL's naming of unnamed constraints.
+                        let name = match option.name {
+                            Some(name) => normalize::ident(name),
+                            None if checks.is_empty() => format!("{domain_name}_check"),
+                            None => format!("{domain_name}_check{}", checks.len()),
+                        };
+                        if checks.iter().any(|c: &CatalogDomainCheck| c.name == name) {
+                            sql_bail!(
+                                "constraint {} for domain {} already exists",
+                                name.quoted(),
+                                domain_name.quoted()
+                            );
+                        }
+                        checks.push(CatalogDomainCheck { name, expr });
+                    }
+                    other => bail_unsupported!(format!("CREATE DOMAIN with constraint: {}", other)),
+                }
+            }
+            CatalogType::Domain {
+                base_reference: base_id,
+                base_modifiers,
+                not_null: not_null.unwrap_or(false),
+                checks,
+            }
+        }
     };
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
@@ -4774,6 +4862,7 @@ pub fn unplan_create_cluster(
                 enable_projection_pushdown_after_relation_cse,
                 enable_less_reduce_in_eqprop: _,
                 enable_dequadratic_eqprop_map: _,
+                persist_projection_pushdown: _,
             } = optimizer_feature_overrides;
             // The ones from above that don't occur below are not wired up to cluster features.
             let features_extracted = ClusterFeatureExtracted {
@@ -5172,6 +5261,68 @@ pub fn plan_create_secret(
     }))
 }
 
+pub fn describe_create_endpoint(
+    _: &StatementContext,
+    _: CreateEndpointStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_endpoint(
+    scx: &StatementContext,
+    stmt: CreateEndpointStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ENDPOINTS)?;
+
+    let create_sql = normalize::create_statement(scx, Statement::CreateEndpoint(stmt.clone()))?;
+    let CreateEndpointStatement {
+        name,
+        if_not_exists,
+        query,
+    } = stmt;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+
+    // The query is planned once, here, and its parameters are bound to the arguments of each
+    // `EXECUTE ENDPOINT`.
+    let query::PlannedRootQuery {
+        expr,
+        desc,
+        finishing,
+        scope: _,
+    } = query::plan_root_query(scx, query, QueryLifetime::OneShot)?;
+    let mut param_types = vec![];
+    for (i, (n, typ)) in scx.param_types.borrow().iter().enumerate() {
+        if *n != i + 1 {
+            sql_bail!("unable to infer type for parameter ${}", i + 1);
+        }
+        param_types.push(typ.clone());
+    }
+
+    let dependencies = expr
+        .depends_on()
+        .into_iter()
+        .map(|gid| scx.catalog.resolve_item_id(&gid))
+        .collect();
+
+    let endpoint = Endpoint {
+        create_sql,
+        query: EndpointQuery {
+            expr,
+            finishing,
+            desc,
+            param_types,
+        },
+        dependencies,
+    };
+
+    Ok(Plan::CreateEndpoint(CreateEndpointPlan {
+        name,
+        endpoint,
+        if_not_exists,
+    }))
+}
+
 pub fn describe_create_connection(
     _: &StatementContext,
     _: CreateConnectionStatement<Aug>,
@@ -5535,7 +5686,8 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
             | CatalogItemType::Type
             | CatalogItemType::Secret
             | CatalogItemType::Connection
-            | CatalogItemType::ContinualTask => true,
+            | CatalogItemType::ContinualTask
+            | CatalogItemType::Endpoint => true,
             CatalogItemType::Index => false,
         },
     }
@@ -5782,6 +5934,24 @@ pub fn plan_drop_owned(
     }))
 }
 
+pub fn describe_drop_subscription(
+    _: &StatementContext,
+    _: DropSubscriptionStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_subscription(
+    scx: &StatementContext,
+    DropSubscriptionStatement { if_exists, name }: DropSubscriptionStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_DURABLE_SUBSCRIPTIONS)?;
+    Ok(Plan::DropSubscription(DropSubscriptionPlan {
+        name: name.into_string(),
+        if_exists,
+    }))
+}
+
 fn plan_retain_history_option(
     scx: &StatementContext,
     retain_history: Option<OptionalDuration>,
@@ -7265,6 +7435,282 @@ pub fn plan_alter_table_add_column(
     }))
 }
 
+pub fn describe_alter_table_alter_column_type(
+    _: &StatementContext,
+    _: AlterTableAlterColumnTypeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_alter_column_type(
+    scx: &StatementContext,
+    stmt: AlterTableAlterColumnTypeStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let AlterTableAlterColumnTypeStatement {
+        if_exists,
+        name,
+        column_name,
+        data_type,
+    } = stmt;
+    let object_type = ObjectType::Table;
+
+    scx.require_feature_flag(&vars::ENABLE_ALTER_TABLE_ALTER_COLUMN)?;
+
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+    let item_name = scx.catalog.resolve_full_name(item.name());
+    let desc = item
+        .at_version(RelationVersionSelector::Latest)
+        .desc(&item_name)?
+        .into_owned();
+
+    let column_name = ColumnName::from(column_name.as_str());
+    let Some((_, current_type)) = desc.get_by_name(&column_name) else {
+        return Err(PlanError::UnknownColumn {
+            table: Some(item_name.into()),
+            column: column_name,
+            similar: Box::new([]),
+        });
+    };
+
+    let scalar_type = scalar_type_from_sql(scx, &data_type)?;
+    let current_modifiers = declared_type_modifiers(item, &column_name)?;
+    let modifiers = match &data_type {
+        ResolvedDataType::Named { modifiers, .. } => modifiers.as_slice(),
+        _ => &[],
+    };
+    if scalar_type == current_type.scalar_type && modifiers == current_modifiers.as_slice() {
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    }
+    // Existing data is never rewritten, so it must be readable as the new type.
+    if !is_lossless_widening(
+        &current_type.scalar_type,
+        &current_modifiers,
+        &scalar_type,
+        modifiers,
+    ) {
+        return Err(PlanError::InvalidColumnTypeChange {
+            column_name,
+            from: scx.humanize_scalar_type(&current_type.scalar_type, false),
+            to: scx.humanize_scalar_type(&scalar_type, false),
+        });
+    }
+    ensure_no_alter_column_dependents(scx, item, &column_name)?;
+
+    let column_type = scalar_type.nullable(current_type.nullable);
+    // "unresolve" our data type so we can later update the persisted create_sql.
+    let raw_sql_type = mz_sql_parser::parser::parse_data_type(&data_type.to_ast_string_stable())?;
+
+    Ok(Plan::AlterTableAlterColumnType(AlterTablePlan {
+        relation_id: item.id(),
+        column_name,
+        column_type,
+        raw_sql_type,
+    }))
+}
+
+pub fn describe_alter_table_drop_column(
+    _: &StatementContext,
+    _: AlterTableDropColumnStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_drop_column(
+    scx: &StatementContext,
+    stmt: AlterTableDropColumnStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTableDropColumnStatement {
+        if_exists,
+        name,
+        if_col_exists,
+        column_name,
+    } = stmt;
+    let object_type = ObjectType::Table;
+
+    scx.require_feature_flag(&vars::ENABLE_ALTER_TABLE_ALTER_COLUMN)?;
+
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+    let item_name = scx.catalog.resolve_full_name(item.name());
+    let desc = item
+        .at_version(RelationVersionSelector::Latest)
+        .desc(&item_name)?
+        .into_owned();
+
+    let column_name = ColumnName::from(column_name.as_str());
+    let Some((column_idx, _)) = desc.get_by_name(&column_name) else {
+        if if_col_exists {
+            scx.catalog.add_notice(PlanNotice::ColumnDoesNotExist {
+                column_name: column_name.to_string(),
+                object_name: item_name.item,
+            });
+            return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+        } else {
+            return Err(PlanError::UnknownColumn {
+                table: Some(item_name.into()),
+                column: column_name,
+                similar: Box::new([]),
+            });
+        }
+    };
+
+    if desc.arity() == 1 {
+        sql_bail!(
+            "cannot drop column {} because it is the only column of table {}",
+            column_name.quoted(),
+            item_name.item.quoted()
+        );
+    }
+    if desc.typ().keys.iter().any(|key| key.contains(&column_idx)) {
+        sql_bail!(
+            "cannot drop column {} of table {} because it is part of a key",
+            column_name.quoted(),
+            item_name.item.quoted()
+        );
+    }
+    ensure_no_alter_column_dependents(scx, item, &column_name)?;
+
+    Ok(Plan::AlterTableDropColumn(AlterTableDropColumnPlan {
+        relation_id: item.id(),
+        column_name,
+    }))
+}
+
+/// Returns the type modifiers that the column `column_name` of the table `item`
+/// is declared with, e.g. the precision and scale of a `numeric`.
+fn declared_type_modifiers(
+    item: &dyn CatalogItem,
+    column_name: &ColumnName,
+) -> Result<Vec<i64>, PlanError> {
+    let stmts = mz_sql_parser::parser::parse_statements(item.create_sql())?;
+    let [stmt]: [StatementParseResult; 1] = stmts
+        .try_into()
+        .expect("create sql of table was not exactly one statement");
+    let Statement::CreateTable(stmt) = stmt.ast else {
+        sql_bail!("internal error: expected CREATE TABLE statement");
+    };
+    let column = stmt.columns.into_iter().find(|column| {
+        let dropped = column.options.iter().any(|option| {
+            matches!(
+                option.option,
+                ColumnOption::Versioned {
+                    action: ColumnVersioned::Dropped,
+                    ..
+                }
+            )
+        });
+        column.name.as_str() == column_name.as_str() && !dropped
+    });
+    match column.map(|column| column.data_type) {
+        Some(RawDataType::Other { typ_mod, .. }) => Ok(typ_mod),
+        _ => Ok(vec![]),
+    }
+}
+
+/// Returns whether every value of `from` can be read, unchanged, as a value of `to`.
+///
+/// `from_modifiers` and `to_modifiers` are the type modifiers the types are
+/// declared with, which for `numeric` include a precision that isn't recorded
+/// in the [`ScalarType`].
+fn is_lossless_widening(
+    from: &ScalarType,
+    from_modifiers: &[i64],
+    to: &ScalarType,
+    to_modifiers: &[i64],
+) -> bool {
+    match (from, to) {
+        (ScalarType::Int16, ScalarType::Int32 | ScalarType::Int64)
+        | (ScalarType::Int32, ScalarType::Int64) => true,
+        (
+            ScalarType::VarChar {
+                max_length: Some(from),
+            },
+            ScalarType::VarChar {
+                max_length: Some(to),
+            },
+        ) => to.into_u32() >= from.into_u32(),
+        (ScalarType::VarChar { .. }, ScalarType::VarChar { max_length: None })
+        | (ScalarType::VarChar { .. }, ScalarType::String) => true,
+        (
+            ScalarType::Numeric {
+                max_scale: from_scale,
+            },
+            ScalarType::Numeric {
+                max_scale: to_scale,
+            },
+        ) => {
+            // An unconstrained numeric can hold any numeric.
+            if to_modifiers.is_empty() {
+                return true;
+            }
+            let precision = |modifiers: &[i64]| {
+                modifiers
+                    .first()
+                    .copied()
+                    .unwrap_or(i64::from(NUMERIC_DATUM_MAX_PRECISION))
+            };
+            let (from_precision, to_precision) =
+                (precision(from_modifiers), precision(to_modifiers));
+            match (from_scale, to_scale) {
+                (Some(from_scale), Some(to_scale)) => {
+                    let (from_scale, to_scale) = (
+                        i64::from(from_scale.into_u8()),
+                        i64::from(to_scale.into_u8()),
+                    );
+                    // Neither the digits after the decimal point nor those
+                    // before it can shrink.
+                    to_scale >= from_scale
+                        && to_precision >= from_precision
+                        && to_precision - to_scale >= from_precision - from_scale
+                }
+                (_, None) => to_precision >= from_precision,
+                (None, Some(_)) => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Altering or dropping a column would invalidate the plans of any objects
+/// that depend on the table, so we require those be dropped first.
+fn ensure_no_alter_column_dependents(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+    column_name: &ColumnName,
+) -> Result<(), PlanError> {
+    let dependents: Vec<_> = item
+        .used_by()
+        .iter()
+        .map(|id| {
+            let dep = scx.catalog.get_item(id);
+            (
+                dep.item_type().to_string(),
+                scx.catalog.minimal_qualification(dep.name()).to_string(),
+            )
+        })
+        .collect();
+    if dependents.is_empty() {
+        Ok(())
+    } else {
+        Err(PlanError::AlterColumnDependentObjectsExist {
+            object_name: scx.catalog.minimal_qualification(item.name()).to_string(),
+            column_name: column_name.clone(),
+            dependents,
+        })
+    }
+}
+
 pub fn describe_comment(
     _: &StatementContext,
     _: CommentStatement<Aug>,
@@ -22,13 +22,13 @@ use mz_expr::visit::Visit;
 use mz_expr::{MirRelationExpr, RowSetFinishing};
 use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
-use mz_ore::str::separated;
+use mz_ore::str::{StrExt, separated};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::bytes::ByteSize;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
 use mz_repr::optimize::OptimizerFeatureOverrides;
-use mz_repr::{CatalogItemId, Datum, RelationDesc, RelationType, Row, ScalarType};
+use mz_repr::{CatalogItemId, Datum, RelationDesc, RelationType, Row, RowArena, ScalarType};
 use mz_sql_parser::ast::{
     CteBlock, ExplainAnalyzeComputationProperty, ExplainAnalyzeProperty, ExplainAnalyzeStatement,
     ExplainPlanOption, ExplainPlanOptionName, ExplainPushdownStatement, ExplainSinkSchemaFor,
@@ -44,24 +44,25 @@ use mz_storage_types::sinks::{
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
-    DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement, Query,
-    SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
-    UpdateStatement,
+    DeleteStatement, ExecuteEndpointStatement, ExplainPlanStatement, ExplainStage, Explainee,
+    Ident, InsertStatement, Query, SelectStatement, SubscribeOption, SubscribeOptionName,
+    SubscribeRelation, SubscribeStatement, UpdateStatement,
 };
-use crate::catalog::CatalogItemType;
+use crate::catalog::{CatalogCollectionItem, CatalogItemType};
 use crate::names::{Aug, ResolvedItemName};
 use crate::normalize;
 use crate::plan::query::{ExprContext, QueryLifetime, offset_into_value, plan_expr, plan_up_to};
 use crate::plan::scope::Scope;
 use crate::plan::statement::show::ShowSelect;
 use crate::plan::statement::{StatementContext, StatementDesc, ddl};
+use crate::plan::typeconv::CastContext;
 use crate::plan::{
     self, CopyFromFilter, CopyToPlan, CreateSinkPlan, ExplainPushdownPlan, ExplainSinkSchemaPlan,
     ExplainTimestampPlan, HirRelationExpr, HirScalarExpr, side_effecting_func, transform_ast,
 };
 use crate::plan::{
     CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan, PlanError,
-    QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, query,
+    QueryContext, QueryWhen, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, query,
 };
 use crate::plan::{CopyFromSource, with_options};
 use crate::session::vars::{self, ENABLE_COPY_FROM_REMOTE};
@@ -223,17 +224,36 @@ fn plan_select_inner(
         finishing,
         scope: _,
     } = query::plan_root_query(scx, select.query.clone(), lifetime)?;
+    let generic_source = generic_root_query(&expr, params)?;
+    let finishing = bind_root_query_parameters(scx, &mut expr, finishing, lifetime, params)?;
+
+    let plan = SelectPlan {
+        source: expr,
+        when,
+        finishing,
+        copy_to,
+        select: Some(Box::new(select)),
+        params: params.clone(),
+        generic_source,
+    };
+
+    Ok((plan, desc))
+}
+
+/// Binds `params` in a planned root query, and concretizes the `LIMIT` and `OFFSET` of its
+/// finishing.
+fn bind_root_query_parameters(
+    scx: &StatementContext,
+    expr: &mut HirRelationExpr,
+    finishing: RowSetFinishing<HirScalarExpr, HirScalarExpr>,
+    lifetime: QueryLifetime,
+    params: &Params,
+) -> Result<RowSetFinishing, PlanError> {
     expr.bind_parameters(scx, lifetime, params)?;
 
     // OFFSET clauses in `expr` should become constants with the above binding of parameters.
     // Let's check this and simplify them to literals.
-    expr.try_visit_mut_pre(&mut |expr| {
-        if let HirRelationExpr::TopK { offset, .. } = expr {
-            let offset_value = offset_into_value(offset.take())?;
-            *offset = HirScalarExpr::literal(Datum::Int64(offset_value), ScalarType::Int64);
-        }
-        Ok::<(), PlanError>(())
-    })?;
+    simplify_offsets(expr)?;
     // (We don't need to simplify LIMIT clauses in `expr`, because we can handle non-constant
     // expressions there. If they happen to be simplifiable to literals, then the optimizer will do
     // so later.)
@@ -273,20 +293,141 @@ fn plan_select_inner(
             .expect("checked in offset_into_value that it is not negative")
     };
 
-    let plan = SelectPlan {
-        source: expr,
-        when,
-        finishing: RowSetFinishing {
-            limit,
-            offset,
-            project: finishing.project,
-            order_by: finishing.order_by,
-        },
-        copy_to,
-        select: Some(Box::new(select)),
+    Ok(RowSetFinishing {
+        limit,
+        offset,
+        project: finishing.project,
+        order_by: finishing.order_by,
+    })
+}
+
+/// Returns a planned root query with placeholders in place of its parameters, if optimizing it
+/// does not depend on the values bound to them by `params`.
+fn generic_root_query(
+    expr: &HirRelationExpr,
+    params: &Params,
+) -> Result<Option<HirRelationExpr>, PlanError> {
+    // Arguments of other types than the expected ones are cast when they are bound, and OFFSET
+    // clauses must be constants, so both depend on the values of the parameters.
+    if params.execute_types != params.expected_types {
+        return Ok(None);
+    }
+    let mut parameterized_offset = false;
+    expr.visit_pre(&mut |expr| {
+        if let HirRelationExpr::TopK { offset, .. } = expr {
+            parameterized_offset |= offset.contains_parameters();
+        }
+    })?;
+    if parameterized_offset {
+        return Ok(None);
+    }
+
+    let mut expr = expr.clone();
+    expr.bind_parameter_placeholders(&params.expected_types)?;
+    simplify_offsets(&mut expr)?;
+    Ok(Some(expr))
+}
+
+/// Simplifies the OFFSET clauses in `expr`, which must be constants, to literals.
+fn simplify_offsets(expr: &mut HirRelationExpr) -> Result<(), PlanError> {
+    expr.try_visit_mut_pre(&mut |expr| {
+        if let HirRelationExpr::TopK { offset, .. } = expr {
+            let offset_value = offset_into_value(offset.take())?;
+            *offset = HirScalarExpr::literal(Datum::Int64(offset_value), ScalarType::Int64);
+        }
+        Ok::<(), PlanError>(())
+    })
+}
+
+pub fn describe_execute_endpoint(
+    scx: &StatementContext,
+    ExecuteEndpointStatement { name, params }: ExecuteEndpointStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    let endpoint = scx.get_item_by_resolved_name(&name)?;
+    let query = endpoint_query(scx, &*endpoint)?;
+    plan_endpoint_arguments(scx, params, &query.param_types)?;
+    Ok(StatementDesc::new(Some(query.desc.clone())))
+}
+
+pub fn plan_execute_endpoint(
+    scx: &StatementContext,
+    ExecuteEndpointStatement { name, params }: ExecuteEndpointStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ENDPOINTS)?;
+
+    let endpoint = scx.get_item_by_resolved_name(&name)?;
+    let query = endpoint_query(scx, &*endpoint)?;
+    let lifetime = QueryLifetime::OneShot;
+
+    let temp_storage = RowArena::new();
+    let mut datums = Row::default();
+    let mut packer = datums.packer();
+    for arg in plan_endpoint_arguments(scx, params, &query.param_types)? {
+        packer.push(arg.lower_uncorrelated()?.eval(&[], &temp_storage)?);
+    }
+    let params = Params {
+        datums,
+        execute_types: query.param_types.clone(),
+        expected_types: query.param_types.clone(),
     };
 
-    Ok((plan, desc))
+    let mut expr = query.expr.clone();
+    let generic_source = generic_root_query(&expr, &params)?;
+    let finishing =
+        bind_root_query_parameters(scx, &mut expr, query.finishing.clone(), lifetime, &params)?;
+
+    Ok(Plan::ExecuteEndpoint(plan::ExecuteEndpointPlan {
+        id: endpoint.id(),
+        select: SelectPlan {
+            source: expr,
+            when: QueryWhen::Immediately,
+            finishing,
+            copy_to: None,
+            select: None,
+            params,
+            generic_source,
+        },
+    }))
+}
+
+fn endpoint_query<'a>(
+    scx: &StatementContext,
+    item: &'a dyn CatalogCollectionItem,
+) -> Result<&'a plan::EndpointQuery, PlanError> {
+    match item.endpoint_query() {
+        Some(query) => Ok(query),
+        None => sql_bail!(
+            "{} is not an endpoint",
+            scx.catalog
+                .resolve_full_name(item.name())
+                .to_string()
+                .quoted()
+        ),
+    }
+}
+
+/// Plans the arguments of an `EXECUTE ENDPOINT` statement as values of the parameters of the
+/// endpoint's query.
+///
+/// Unlike the arguments of `EXECUTE`, string literals are coerced to the type of their parameter,
+/// so that clients can pass every argument as text.
+fn plan_endpoint_arguments(
+    scx: &StatementContext,
+    args: Vec<Expr<Aug>>,
+    param_types: &[ScalarType],
+) -> Result<Vec<HirScalarExpr>, PlanError> {
+    if args.len() != param_types.len() {
+        sql_bail!("expected {} params, got {}", param_types.len(), args.len());
+    }
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let ecx = query::execute_expr_context(&qcx);
+    args.into_iter()
+        .zip(param_types)
+        .map(|(mut arg, ty)| {
+            transform_ast::transform(scx, &mut arg)?;
+            plan_expr(&ecx, &arg)?.cast_to(&ecx, CastContext::Assignment, ty)
+        })
+        .collect()
 }
 
 pub fn describe_explain_plan(
@@ -556,6 +697,7 @@ impl TryFrom<ExplainPlanOptionExtracted> for ExplainConfig {
                     .enable_projection_pushdown_after_relation_cse,
                 enable_less_reduce_in_eqprop: Default::default(),
                 enable_dequadratic_eqprop_map: Default::default(),
+                persist_projection_pushdown: Default::default(),
             },
         })
     }
@@ -1057,7 +1199,12 @@ pub fn plan_query(
     })
 }
 
-generate_extracted_config!(SubscribeOption, (Snapshot, bool), (Progress, bool));
+generate_extracted_config!(
+    SubscribeOption,
+    (Snapshot, bool),
+    (Progress, bool),
+    (Name, Ident)
+);
 
 pub fn describe_subscribe(
     scx: &StatementContext,
@@ -1308,8 +1455,14 @@ pub fn plan_subscribe(
     };
 
     let SubscribeOptionExtracted {
-        progress, snapshot, ..
+        progress,
+        snapshot,
+        name,
+        ..
     } = options.try_into()?;
+    if name.is_some() {
+        scx.require_feature_flag(&vars::ENABLE_DURABLE_SUBSCRIPTIONS)?;
+    }
     Ok(Plan::Subscribe(SubscribePlan {
         from,
         when,
@@ -1318,6 +1471,8 @@ pub fn plan_subscribe(
         copy_to,
         emit_progress: progress.unwrap_or(false),
         output,
+        name: name.map(|name| name.into_string()),
+        listen_channel: None,
     }))
 }
 
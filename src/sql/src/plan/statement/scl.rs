@@ -20,15 +20,18 @@ use uncased::UncasedStr;
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     CloseStatement, DeallocateStatement, DeclareStatement, DiscardStatement, DiscardTarget,
-    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, PrepareStatement,
-    ResetVariableStatement, SetVariableStatement, SetVariableTo, ShowVariableStatement,
+    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, ListenStatement,
+    NotifyStatement, PrepareStatement, ResetVariableStatement, SetVariableStatement, SetVariableTo,
+    ShowVariableStatement, SubscribeOption, SubscribeOptionName, SubscribeOutput,
+    SubscribeRelation, SubscribeStatement, UnlistenStatement, Value, WithOptionValue,
 };
 use crate::names::{self, Aug};
-use crate::plan::statement::{StatementContext, StatementDesc};
+use crate::plan::statement::{StatementContext, StatementDesc, dml};
 use crate::plan::{
     ClosePlan, DeallocatePlan, DeclarePlan, ExecutePlan, ExecuteTimeout, FetchPlan,
-    InspectShardPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan, SetVariablePlan,
-    ShowVariablePlan, VariableValue, describe, query,
+    InspectShardPlan, ListenPlan, NotifyPlan, Params, Plan, PlanError, PreparePlan,
+    ResetVariablePlan, SetVariablePlan, ShowVariablePlan, UnlistenPlan, VariableValue, describe,
+    query,
 };
 use crate::session::vars;
 use crate::session::vars::{IsolationLevel, SCHEMA_ALIAS, TRANSACTION_ISOLATION_VAR_NAME};
@@ -173,7 +176,7 @@ pub fn plan_discard(
         DiscardTarget::All => Ok(Plan::DiscardAll),
         DiscardTarget::Temp => Ok(Plan::DiscardTemp),
         DiscardTarget::Sequences => bail_unsupported!("DISCARD SEQUENCES"),
-        DiscardTarget::Plans => bail_unsupported!("DISCARD PLANS"),
+        DiscardTarget::Plans => Ok(Plan::DiscardPlans),
     }
 }
 
@@ -335,3 +338,83 @@ pub fn plan_deallocate(
         name: name.map(|name| name.to_string()),
     }))
 }
+
+/// The maximum length of a `NOTIFY` payload, matching PostgreSQL.
+const MAX_NOTIFY_PAYLOAD_LEN: usize = 8000;
+
+pub fn describe_listen(
+    _: &StatementContext,
+    _: ListenStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_listen(
+    scx: &StatementContext,
+    ListenStatement { channel, relation }: ListenStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    let channel = channel.into_string();
+    let subscribe = match relation {
+        None => None,
+        Some(name) => {
+            // Only changes after the `LISTEN` are notified.
+            let stmt = SubscribeStatement {
+                relation: SubscribeRelation::Name(name),
+                options: vec![SubscribeOption {
+                    name: SubscribeOptionName::Snapshot,
+                    value: Some(WithOptionValue::Value(Value::Boolean(false))),
+                }],
+                as_of: None,
+                up_to: None,
+                output: SubscribeOutput::Diffs,
+            };
+            match dml::plan_subscribe(scx, stmt, &Params::empty(), None)? {
+                Plan::Subscribe(mut plan) => {
+                    plan.listen_channel = Some(channel.clone());
+                    Some(plan)
+                }
+                plan => unreachable!("planning SUBSCRIBE produced {plan:?}"),
+            }
+        }
+    };
+    Ok(Plan::Listen(ListenPlan { channel, subscribe }))
+}
+
+pub fn describe_unlisten(
+    _: &StatementContext,
+    _: UnlistenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_unlisten(
+    _: &StatementContext,
+    UnlistenStatement { channel }: UnlistenStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::Unlisten(UnlistenPlan {
+        channel: channel.map(|channel| channel.into_string()),
+    }))
+}
+
+pub fn describe_notify(
+    _: &StatementContext,
+    _: NotifyStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_notify(
+    scx: &StatementContext,
+    NotifyStatement { channel, payload }: NotifyStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    let payload = payload.unwrap_or_default();
+    if payload.len() >= MAX_NOTIFY_PAYLOAD_LEN {
+        sql_bail!("payload string too long");
+    }
+    Ok(Plan::Notify(NotifyPlan {
+        channel: channel.into_string(),
+        payload,
+    }))
+}
@@ -33,7 +33,7 @@ use crate::ast::visit_mut::VisitMut;
 use crate::ast::{
     SelectStatement, ShowColumnsStatement, ShowCreateIndexStatement, ShowCreateSinkStatement,
     ShowCreateSourceStatement, ShowCreateTableStatement, ShowCreateViewStatement,
-    ShowObjectsStatement, ShowStatementFilter, Statement, Value,
+    ShowHistoryRetentionStatement, ShowObjectsStatement, ShowStatementFilter, Statement, Value,
 };
 use crate::catalog::{CatalogItemType, SessionCatalog};
 use crate::names::{
@@ -678,7 +678,8 @@ pub fn show_columns<'a>(
         | ty @ CatalogItemType::Func
         | ty @ CatalogItemType::Secret
         | ty @ CatalogItemType::Type
-        | ty @ CatalogItemType::Sink => {
+        | ty @ CatalogItemType::Sink
+        | ty @ CatalogItemType::Endpoint => {
             sql_bail!("{full_name} is a {ty} and so does not have columns");
         }
     }
@@ -755,6 +756,37 @@ pub fn show_secrets<'a>(
     ShowSelect::new(scx, query, filter, None, Some(&["name", "comment"]))
 }
 
+pub fn show_history_retention<'a>(
+    scx: &'a StatementContext<'a>,
+    ShowHistoryRetentionStatement { from, filter }: ShowHistoryRetentionStatement<Aug>,
+) -> Result<ShowSelect<'a>, PlanError> {
+    let schema_spec = scx.resolve_optional_schema(&from)?;
+
+    // `earliest_time` is the oldest time the object can currently be queried
+    // at with `AS OF`, as determined by its read frontier.
+    let query = format!(
+        "SELECT
+            objs.name,
+            objs.type,
+            strategies.value::float8 * INTERVAL '1 millisecond' AS retain_history,
+            frontiers.read_frontier::timestamptz AS earliest_time
+        FROM mz_catalog.mz_objects objs
+        JOIN mz_internal.mz_history_retention_strategies strategies
+            ON objs.id = strategies.id
+        LEFT JOIN mz_internal.mz_frontiers frontiers
+            ON objs.id = frontiers.object_id
+        WHERE objs.schema_id = '{schema_spec}'",
+    );
+
+    ShowSelect::new(
+        scx,
+        query,
+        filter,
+        None,
+        Some(&["name", "type", "retain_history", "earliest_time"]),
+    )
+}
+
 pub fn show_privileges<'a>(
     scx: &'a StatementContext<'a>,
     object_type: Option<SystemObjectType>,
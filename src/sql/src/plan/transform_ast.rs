@@ -59,6 +59,10 @@ where
 //   * Rewrites the suite of standard deviation and variance functions in a
 //     manner similar to `avg`.
 //
+//   * Rewrites the suite of statistical aggregates over pairs of values
+//     (`corr`, `covar_pop`, `regr_slope`, etc.) in terms of `sum` and `count`
+//     of the inputs and their products, similar to the variance functions.
+//
 // TODO(sploiselle): rewrite these in terms of func::sql_op!
 struct FuncRewriter<'a> {
     scx: &'a StatementContext<'a>,
@@ -275,25 +279,7 @@ impl<'a> FuncRewriter<'a> {
         // If `result` is NULL, return NULL (no surprise here)
         // Otherwise, if `result` is >0, return `result` (no surprise here either)
         // Otherwise, return 0.
-        //
-        // Unfortunately, we can't use `GREATEST` directly for this,
-        // since `greatest(NULL, 0)` is 0, not NULL, so we need to
-        // create a `Case` expression that computes `result`
-        // twice. Hopefully the optimizer can deal with this!
-        let result_is_null = Expr::IsExpr {
-            expr: Box::new(result.clone()),
-            construct: IsExprConstruct::Null,
-            negated: false,
-        };
-        Expr::Case {
-            operand: None,
-            conditions: vec![result_is_null],
-            results: vec![Expr::Value(Value::Null)],
-            else_result: Some(Box::new(Expr::HomogenizingFunction {
-                function: HomogenizingFunction::Greatest,
-                exprs: vec![result, Expr::number("0")],
-            })),
-        }
+        Self::clamp_non_negative(result)
     }
 
     fn plan_stddev(
@@ -311,6 +297,160 @@ impl<'a> FuncRewriter<'a> {
             )
     }
 
+    fn plan_regr(
+        &mut self,
+        func: RegrFunc,
+        y: Expr<Aug>,
+        x: Expr<Aug>,
+        filter: Option<Box<Expr<Aug>>>,
+        distinct: bool,
+        over: Option<WindowSpec<Aug>>,
+    ) -> Expr<Aug> {
+        // Like the variance functions, these use the "textbook" algorithm
+        // rather than a numerically stable one, so that every aggregate
+        // involved is a simple accumulable sum that supports retractions
+        // efficiently.
+        //
+        // Only pairs in which both `y` and `x` are non-NULL contribute to the
+        // result, so each input is masked to NULL whenever its counterpart is
+        // NULL. The code below then computes, in terms of
+        //
+        //     N = count(y), Sx = sum(x), Sy = sum(y),
+        //     Sxx = sum(x²), Syy = sum(y²), Sxy = sum(x * y),
+        //
+        // the centered sums of squares and products
+        //
+        //     sxx = Sxx - Sx² / N
+        //     syy = Syy - Sy² / N
+        //     sxy = Sxy - Sx * Sy / N
+        //
+        // where sxx and syy are clamped at zero for the same reason as in
+        // `plan_variance`, and from which every function in the family is
+        // derived as in PostgreSQL's `float8_regr_*` and `float8_corr` functions.
+        if distinct && self.status.is_ok() {
+            self.status = Err(PlanError::Unsupported {
+                feature: format!("DISTINCT in {}", func.name()),
+                discussion_no: None,
+            });
+        }
+        let promotion = self
+            .scx
+            .dangerous_resolve_name(vec![MZ_UNSAFE_SCHEMA, "mz_float8_promotion"]);
+        let mask = |value: Expr<Aug>, other: &Expr<Aug>| Expr::Case {
+            operand: None,
+            conditions: vec![Expr::IsExpr {
+                expr: Box::new(other.clone()),
+                construct: IsExprConstruct::Null,
+                negated: false,
+            }],
+            results: vec![Expr::null()],
+            else_result: Some(Box::new(value)),
+        };
+        let masked_y = mask(y.clone().call_unary(promotion.clone()), &x);
+        let masked_x = mask(x.call_unary(promotion), &y);
+
+        let sum = |this: &mut Self, expr: Expr<Aug>| {
+            this.plan_agg(
+                this.scx
+                    .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sum"]),
+                expr,
+                vec![],
+                filter.clone(),
+                false,
+                over.clone(),
+            )
+        };
+        let sum_x = sum(self, masked_x.clone());
+        let sum_y = sum(self, masked_y.clone());
+        let sum_xx = sum(self, masked_x.clone().multiply(masked_x.clone()));
+        let sum_yy = sum(self, masked_y.clone().multiply(masked_y.clone()));
+        let sum_xy = sum(self, masked_x.multiply(masked_y.clone()));
+        let count = self.plan_agg(
+            self.scx
+                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "count"]),
+            masked_y,
+            vec![],
+            filter.clone(),
+            false,
+            over.clone(),
+        );
+
+        let centered = |sum_ab: Expr<Aug>, sum_a: Expr<Aug>, sum_b: Expr<Aug>| {
+            sum_ab.minus(Self::plan_divide(sum_a.multiply(sum_b), count.clone()))
+        };
+        let sxx =
+            || Self::clamp_non_negative(centered(sum_xx.clone(), sum_x.clone(), sum_x.clone()));
+        let syy =
+            || Self::clamp_non_negative(centered(sum_yy.clone(), sum_y.clone(), sum_y.clone()));
+        let sxy = || centered(sum_xy.clone(), sum_x.clone(), sum_y.clone());
+
+        match func {
+            RegrFunc::Count => {
+                // `count` returns 0 rather than NULL for empty inputs, as
+                // required.
+                count
+            }
+            RegrFunc::AvgX => Self::plan_divide(sum_x, count),
+            RegrFunc::AvgY => Self::plan_divide(sum_y, count),
+            RegrFunc::Sxx => sxx(),
+            RegrFunc::Syy => syy(),
+            RegrFunc::Sxy => sxy(),
+            RegrFunc::CovarPop => Self::plan_divide(sxy(), count),
+            RegrFunc::CovarSamp => Self::plan_divide(sxy(), count.minus(Expr::number("1"))),
+            RegrFunc::Slope => Self::plan_divide(sxy(), sxx()),
+            RegrFunc::Intercept => Self::plan_divide(
+                sum_y
+                    .clone()
+                    .minus(sum_x.clone().multiply(Self::plan_divide(sxy(), sxx()))),
+                count.clone(),
+            ),
+            RegrFunc::Corr => Self::plan_divide(
+                sxy(),
+                sxx().multiply(syy()).call_unary(
+                    self.scx
+                        .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sqrt"]),
+                ),
+            ),
+            RegrFunc::R2 => {
+                // regr_r2 is NULL if `x` is constant, and 1 if `y` is
+                // constant but `x` is not.
+                let r2 = Self::plan_divide(sxy().multiply(sxy()), sxx().multiply(syy()));
+                Expr::Case {
+                    operand: None,
+                    conditions: vec![
+                        sxx().equals(Expr::number("0")),
+                        syy().equals(Expr::number("0")),
+                    ],
+                    results: vec![Expr::null(), Expr::number("1")],
+                    else_result: Some(Box::new(r2)),
+                }
+            }
+        }
+    }
+
+    // Returns NULL if `expr` is NULL, `expr` if it is positive, and 0
+    // otherwise.
+    //
+    // Unfortunately, we can't use `GREATEST` directly for this, since
+    // `greatest(NULL, 0)` is 0, not NULL, so we need to create a `Case`
+    // expression that computes `expr` twice. Hopefully the optimizer can deal
+    // with this!
+    fn clamp_non_negative(expr: Expr<Aug>) -> Expr<Aug> {
+        Expr::Case {
+            operand: None,
+            conditions: vec![Expr::IsExpr {
+                expr: Box::new(expr.clone()),
+                construct: IsExprConstruct::Null,
+                negated: false,
+            }],
+            results: vec![Expr::Value(Value::Null)],
+            else_result: Some(Box::new(Expr::HomogenizingFunction {
+                function: HomogenizingFunction::Greatest,
+                exprs: vec![expr, Expr::number("0")],
+            })),
+        }
+    }
+
     fn plan_bool_and(
         &mut self,
         expr: Expr<Aug>,
@@ -434,7 +574,10 @@ impl<'a> FuncRewriter<'a> {
                             .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "power"]),
                         vec![lhs, rhs],
                     ),
-                    _ => return None,
+                    name => match RegrFunc::from_name(name) {
+                        Some(func) => self.plan_regr(func, lhs, rhs, filter, distinct, over),
+                        None => return None,
+                    },
                 }
             } else {
                 return None;
@@ -476,6 +619,61 @@ impl<'a> FuncRewriter<'a> {
     }
 }
 
+/// The statistical aggregate functions over pairs of values that
+/// [`FuncRewriter::plan_regr`] rewrites.
+#[derive(Debug, Clone, Copy)]
+enum RegrFunc {
+    Corr,
+    CovarPop,
+    CovarSamp,
+    AvgX,
+    AvgY,
+    Count,
+    Intercept,
+    R2,
+    Slope,
+    Sxx,
+    Sxy,
+    Syy,
+}
+
+impl RegrFunc {
+    fn from_name(name: &str) -> Option<RegrFunc> {
+        Some(match name {
+            "corr" => RegrFunc::Corr,
+            "covar_pop" => RegrFunc::CovarPop,
+            "covar_samp" => RegrFunc::CovarSamp,
+            "regr_avgx" => RegrFunc::AvgX,
+            "regr_avgy" => RegrFunc::AvgY,
+            "regr_count" => RegrFunc::Count,
+            "regr_intercept" => RegrFunc::Intercept,
+            "regr_r2" => RegrFunc::R2,
+            "regr_slope" => RegrFunc::Slope,
+            "regr_sxx" => RegrFunc::Sxx,
+            "regr_sxy" => RegrFunc::Sxy,
+            "regr_syy" => RegrFunc::Syy,
+            _ => return None,
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            RegrFunc::Corr => "corr",
+            RegrFunc::CovarPop => "covar_pop",
+            RegrFunc::CovarSamp => "covar_samp",
+            RegrFunc::AvgX => "regr_avgx",
+            RegrFunc::AvgY => "regr_avgy",
+            RegrFunc::Count => "regr_count",
+            RegrFunc::Intercept => "regr_intercept",
+            RegrFunc::R2 => "regr_r2",
+            RegrFunc::Slope => "regr_slope",
+            RegrFunc::Sxx => "regr_sxx",
+            RegrFunc::Sxy => "regr_sxy",
+            RegrFunc::Syy => "regr_syy",
+        }
+    }
+}
+
 impl<'ast> VisitMut<'ast, Aug> for FuncRewriter<'_> {
     fn visit_select_item_mut(&mut self, item: &'ast mut SelectItem<Aug>) {
         if let SelectItem::Expr { expr, alias: None } = item {
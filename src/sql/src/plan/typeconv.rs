@@ -18,6 +18,7 @@ use dynfmt::{Format, SimpleCurlyFormat};
 use itertools::Itertools;
 use mz_expr::func::{CastArrayToJsonb, CastListToJsonb};
 use mz_expr::{VariadicFunc, func};
+use mz_repr::adt::bit::BitLength;
 use mz_repr::{ColumnName, ColumnType, Datum, RelationType, ScalarBaseType, ScalarType};
 
 use crate::catalog::TypeCategory;
@@ -362,6 +363,10 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 Some(move |e: HirScalarExpr| e.call_unary(CastInt32ToNumeric(func::CastInt32ToNumeric(s))))
             }),
             (Int32, String) => Assignment: CastInt32ToString(func::CastInt32ToString),
+            (Int32, Bit) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let length = to_type.unwrap_bit_length().unwrap_or(BitLength::ONE);
+                Some(move |e: HirScalarExpr| e.call_unary(CastInt32ToBit(func::CastInt32ToBit { length })))
+            }),
 
             // INT64
             (Int64, Bool) => Explicit: CastInt64ToBool(func::CastInt64ToBool),
@@ -390,6 +395,10 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 CastOidToRegType(func::CastOidToRegType),
             ],
             (Int64, String) => Assignment: CastInt64ToString(func::CastInt64ToString),
+            (Int64, Bit) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let length = to_type.unwrap_bit_length().unwrap_or(BitLength::ONE);
+                Some(move |e: HirScalarExpr| e.call_unary(CastInt64ToBit(func::CastInt64ToBit { length })))
+            }),
 
             // UINT16
             (UInt16, UInt32) => Implicit: CastUint16ToUint32(func::CastUint16ToUint32),
@@ -630,6 +639,15 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                     cast_expr: Box::new(cast_expr),
                 })))
             }),
+            (String, MultiRange) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
+                let return_ty = to_type.clone();
+                let to_el_type = to_type.unwrap_multirange_element_type();
+                let cast_expr = plan_hypothetical_cast(ecx, ccx, from_type, to_el_type)?;
+                Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastStringToMultiRange(func::CastStringToMultiRange {
+                    return_ty,
+                    cast_expr: Box::new(cast_expr),
+                })))
+            }),
             (String, Int2Vector) => Explicit: CastStringToInt2Vector(func::CastStringToInt2Vector),
             (String, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
                 let length = to_type.unwrap_char_length();
@@ -640,6 +658,14 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 Some(move |e: HirScalarExpr| e.call_unary(CastStringToVarChar(func::CastStringToVarChar {length, fail_on_len: ccx != CastContext::Explicit})))
             }),
             (String, PgLegacyChar) => Assignment: CastStringToPgLegacyChar(func::CastStringToPgLegacyChar),
+            (String, Bit) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let length = to_type.unwrap_bit_length();
+                Some(move |e: HirScalarExpr| e.call_unary(CastStringToBit(func::CastStringToBit { length })))
+            }),
+            (String, VarBit) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+                let max_length = to_type.unwrap_bit_length();
+                Some(move |e: HirScalarExpr| e.call_unary(CastStringToVarBit(func::CastStringToVarBit { max_length })))
+            }),
             // CHAR
             (Char, String) => Implicit: CastCharToString(func::CastCharToString),
             (Char, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
@@ -664,6 +690,30 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
             }),
             (VarChar, PgLegacyChar) => Assignment: CastStringToPgLegacyChar(func::CastStringToPgLegacyChar),
 
+            // BIT
+            (Bit, String) => Assignment: CastBitToString(func::CastBitToString),
+            (Bit, Bit) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
+                let length = to_type.unwrap_bit_length();
+                Some(move |e: HirScalarExpr| e.call_unary(CastBitToBit(func::CastBitToBit { length, fail_on_len: ccx != CastContext::Explicit })))
+            }),
+            (Bit, VarBit) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
+                let max_length = to_type.unwrap_bit_length();
+                Some(move |e: HirScalarExpr| e.call_unary(CastBitToVarBit(func::CastBitToVarBit { max_length, fail_on_len: ccx != CastContext::Explicit })))
+            }),
+            (Bit, Int32) => Explicit: CastBitToInt32(func::CastBitToInt32),
+            (Bit, Int64) => Explicit: CastBitToInt64(func::CastBitToInt64),
+
+            // VARBIT
+            (VarBit, String) => Assignment: CastBitToString(func::CastBitToString),
+            (VarBit, Bit) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
+                let length = to_type.unwrap_bit_length();
+                Some(move |e: HirScalarExpr| e.call_unary(CastBitToBit(func::CastBitToBit { length, fail_on_len: ccx != CastContext::Explicit })))
+            }),
+            (VarBit, VarBit) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
+                let max_length = to_type.unwrap_bit_length();
+                Some(move |e: HirScalarExpr| e.call_unary(CastBitToVarBit(func::CastBitToVarBit { max_length, fail_on_len: ccx != CastContext::Explicit })))
+            }),
+
             // PG LEGACY CHAR
             (PgLegacyChar, String) => Implicit: CastPgLegacyCharToString(func::CastPgLegacyCharToString),
             (PgLegacyChar, Char) => Assignment: CastPgLegacyCharToChar(func::CastPgLegacyCharToChar),
@@ -803,6 +853,12 @@ static VALID_CASTS: LazyLock<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl
                 Some(|e: HirScalarExpr| e.call_unary(CastRangeToString(func::CastRangeToString { ty })))
             }),
 
+            // MultiRange
+            (MultiRange, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+                let ty = from_type.clone();
+                Some(|e: HirScalarExpr| e.call_unary(CastMultiRangeToString(func::CastMultiRangeToString { ty })))
+            }),
+
             // MzAclItem
             (MzAclItem, String) => Explicit: sql_impl_cast("(
                 SELECT
@@ -985,6 +1041,8 @@ pub fn to_jsonb(ecx: &ExprContext, expr: HirScalarExpr) -> HirScalarExpr {
         | String
         | Char { .. }
         | VarChar { .. }
+        | Bit { .. }
+        | VarBit { .. }
         | Uuid
         | Oid
         | Map { .. }
@@ -994,6 +1052,7 @@ pub fn to_jsonb(ecx: &ExprContext, expr: HirScalarExpr) -> HirScalarExpr {
         | Int2Vector
         | MzTimestamp
         | Range { .. }
+        | MultiRange { .. }
         | MzAclItem
         | AclItem => to_string(ecx, expr)
             .call_unary(UnaryFunc::CastJsonbableToJsonb(func::CastJsonbableToJsonb)),
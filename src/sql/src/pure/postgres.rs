@@ -13,8 +13,9 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
 use mz_expr::MirScalarExpr;
+use mz_ore::str::StrExt;
 use mz_postgres_util::Config;
-use mz_postgres_util::desc::PostgresTableDesc;
+use mz_postgres_util::desc::{PostgresDomainDesc, PostgresTableDesc};
 use mz_proto::RustType;
 use mz_repr::{ColumnType, RelationType, ScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
@@ -28,7 +29,8 @@ use prost::Message;
 use tokio_postgres::Client;
 use tokio_postgres::types::Oid;
 
-use crate::names::{Aug, ResolvedItemName};
+use crate::catalog::CatalogDomainCheck;
+use crate::names::{self, Aug, ResolvedItemName};
 use crate::normalize;
 use crate::plan::hir::ColumnRef;
 use crate::plan::typeconv::{CastContext, plan_cast};
@@ -574,6 +576,16 @@ pub(crate) fn generate_column_casts(
                             )
         };
 
+        // Enforce the constraints of upstream domains, which are otherwise
+        // lost by ingesting the column as the domain's base type. Columns
+        // ingested as text opt out of the domain entirely.
+        let cast = match (&column.domain, &cast_type) {
+            (Some(domain), CastType::Natural) => {
+                plan_domain_constraints(scx, table, &column.name, domain, &scalar_type, cast)?
+            }
+            _ => cast,
+        };
+
         // We expect only reg* types to encounter this issue. Users
         // can ingest the data as text if they need to ingest it.
         // This is acceptable because we don't expect the OIDs from
@@ -597,6 +609,50 @@ pub(crate) fn generate_column_casts(
     Ok(table_cast)
 }
 
+/// Plans the constraints of the upstream domain `domain`, the type of
+/// `column_name` in `table`, against `expr`.
+fn plan_domain_constraints(
+    scx: &StatementContext,
+    table: &PostgresTableDesc,
+    column_name: &str,
+    domain: &PostgresDomainDesc,
+    base_type: &ScalarType,
+    expr: HirScalarExpr,
+) -> Result<HirScalarExpr, PlanError> {
+    let unsupported = |e: PlanError| {
+        sql_err!(
+            "cannot ingest PG column {}.{}.{} of domain {}: {}; \
+            consider ingesting the column as text via TEXT COLUMNS",
+            table.namespace,
+            table.name,
+            column_name,
+            domain.name.quoted(),
+            e,
+        )
+    };
+    let mut checks = Vec::with_capacity(domain.checks.len());
+    for check in &domain.checks {
+        let expr = mz_sql_parser::parser::parse_expr(&check.expr)
+            .map_err(PlanError::from)
+            .and_then(|expr| names::resolve(scx.catalog, expr))
+            .map_err(unsupported)?
+            .0;
+        checks.push(CatalogDomainCheck {
+            name: check.name.clone(),
+            expr,
+        });
+    }
+    crate::plan::query::enforce_domain_constraints(
+        scx,
+        &domain.name,
+        base_type,
+        domain.not_null,
+        &checks,
+        expr,
+    )
+    .map_err(unsupported)
+}
+
 mod privileges {
     use mz_postgres_util::{Config, PostgresError};
 
@@ -529,6 +529,19 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::CreateEndpoint(plan::CreateEndpointPlan {
+            name,
+            endpoint: _,
+            if_not_exists: _,
+        }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::CreateSink(plan::CreateSinkPlan {
             name,
             sink,
@@ -801,6 +814,8 @@ fn generate_rbac_requirements(
             when: _,
             finishing: _,
             copy_to: _,
+            params: _,
+            generic_source: _,
         }) => {
             let items = source
                 .depends_on()
@@ -819,6 +834,20 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::ExecuteEndpoint(plan::ExecuteEndpointPlan { id, select }) => {
+            let mut privileges = generate_read_privileges(catalog, iter::once(*id), role_id);
+            if let Some(privilege) = generate_cluster_usage_privileges(
+                select.source.as_const().is_some(),
+                target_cluster_id,
+                role_id,
+            ) {
+                privileges.push(privilege);
+            }
+            RbacRequirements {
+                privileges,
+                ..Default::default()
+            }
+        }
         Plan::Subscribe(plan::SubscribePlan {
             from,
             with_snapshot: _,
@@ -827,6 +856,23 @@ fn generate_rbac_requirements(
             copy_to: _,
             emit_progress: _,
             output: _,
+            name: _,
+            listen_channel: _,
+        })
+        | Plan::Listen(plan::ListenPlan {
+            channel: _,
+            subscribe:
+                Some(plan::SubscribePlan {
+                    from,
+                    with_snapshot: _,
+                    when: _,
+                    up_to: _,
+                    copy_to: _,
+                    emit_progress: _,
+                    output: _,
+                    name: _,
+                    listen_channel: _,
+                }),
         }) => {
             let items = from
                 .depends_on()
@@ -1252,11 +1298,15 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
-        Plan::AlterTableAddColumn(plan::AlterTablePlan { relation_id, .. }) => RbacRequirements {
-            ownership: vec![ObjectId::Item(*relation_id)],
-            item_usage: &CREATE_ITEM_USAGE,
-            ..Default::default()
-        },
+        Plan::AlterTableAddColumn(plan::AlterTablePlan { relation_id, .. })
+        | Plan::AlterTableAlterColumnType(plan::AlterTablePlan { relation_id, .. })
+        | Plan::AlterTableDropColumn(plan::AlterTableDropColumnPlan { relation_id, .. }) => {
+            RbacRequirements {
+                ownership: vec![ObjectId::Item(*relation_id)],
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::AlterNetworkPolicy(plan::AlterNetworkPolicyPlan { id, .. }) => RbacRequirements {
             ownership: vec![ObjectId::NetworkPolicy(*id)],
             item_usage: &CREATE_ITEM_USAGE,
@@ -1479,6 +1529,7 @@ fn generate_rbac_requirements(
             }
         }
         Plan::DiscardTemp
+        | Plan::DiscardPlans
         | Plan::DiscardAll
         | Plan::EmptyQuery
         | Plan::ShowAllVariables
@@ -1525,6 +1576,21 @@ fn generate_rbac_requirements(
         })
         | Plan::Execute(plan::ExecutePlan { name: _, params: _ })
         | Plan::Deallocate(plan::DeallocatePlan { name: _ })
+        // Ownership of durable subscriptions is checked by the coordinator, since they are not
+        // catalog objects that the planner can resolve.
+        | Plan::DropSubscription(plan::DropSubscriptionPlan {
+            name: _,
+            if_exists: _,
+        })
+        | Plan::Listen(plan::ListenPlan {
+            channel: _,
+            subscribe: None,
+        })
+        | Plan::Unlisten(plan::UnlistenPlan { channel: _ })
+        | Plan::Notify(plan::NotifyPlan {
+            channel: _,
+            payload: _,
+        })
         | Plan::Raise(plan::RaisePlan { severity: _ }) => Default::default(),
     }
 }
@@ -1663,6 +1729,12 @@ fn generate_read_privileges_inner(
                 CatalogItemType::Type | CatalogItemType::Secret | CatalogItemType::Connection => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
                 }
+                // Like a view, an endpoint reads from its dependencies with the privileges of its
+                // owner, so executing it only requires EXECUTE on the endpoint itself.
+                CatalogItemType::Endpoint => {
+                    privileges.push((SystemObjectId::Object(id.into()), AclMode::EXECUTE, role_id));
+                    views.push((item.references().items().copied(), item.owner_id()));
+                }
                 CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {}
             }
         }
@@ -1785,6 +1857,7 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
         SystemObjectType::Object(ObjectType::ContinualTask) => AclMode::SELECT,
+        SystemObjectType::Object(ObjectType::Endpoint) => AclMode::EXECUTE,
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
@@ -1814,7 +1887,8 @@ const fn default_builtin_object_acl_mode(object_type: ObjectType) -> AclMode {
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Func
-        | ObjectType::NetworkPolicy => AclMode::empty(),
+        | ObjectType::NetworkPolicy
+        | ObjectType::Endpoint => AclMode::empty(),
     }
 }
 
@@ -1202,6 +1202,11 @@ impl SystemVars {
             &PG_TIMESTAMP_ORACLE_CONNECTION_POOL_TTL,
             &PG_TIMESTAMP_ORACLE_CONNECTION_POOL_TTL_STAGGER,
             &USER_STORAGE_MANAGED_COLLECTIONS_BATCH_DURATION,
+            &MAX_DURABLE_SUBSCRIPTION_LAG,
+            &DURABLE_SUBSCRIPTION_CHECKPOINT_INTERVAL,
+            &REPLICATION_SLOT_CHECKPOINT_INTERVAL,
+            &PLAN_CACHE_MAX_ENTRIES,
+            &ENABLE_SHARED_PLAN_CACHE,
             &FORCE_SOURCE_TABLE_SYNTAX,
             &OPTIMIZER_E2E_LATENCY_WARNING_THRESHOLD,
         ];
@@ -2178,6 +2183,31 @@ impl SystemVars {
         *self.expect_value(&USER_STORAGE_MANAGED_COLLECTIONS_BATCH_DURATION)
     }
 
+    /// Returns the `max_durable_subscription_lag` configuration parameter.
+    pub fn max_durable_subscription_lag(&self) -> Duration {
+        *self.expect_value(&MAX_DURABLE_SUBSCRIPTION_LAG)
+    }
+
+    /// Returns the `durable_subscription_checkpoint_interval` configuration parameter.
+    pub fn durable_subscription_checkpoint_interval(&self) -> Duration {
+        *self.expect_value(&DURABLE_SUBSCRIPTION_CHECKPOINT_INTERVAL)
+    }
+
+    /// Returns the `replication_slot_checkpoint_interval` configuration parameter.
+    pub fn replication_slot_checkpoint_interval(&self) -> Duration {
+        *self.expect_value(&REPLICATION_SLOT_CHECKPOINT_INTERVAL)
+    }
+
+    /// Returns the `plan_cache_max_entries` configuration parameter.
+    pub fn plan_cache_max_entries(&self) -> usize {
+        *self.expect_value(&PLAN_CACHE_MAX_ENTRIES)
+    }
+
+    /// Returns the `enable_shared_plan_cache` configuration parameter.
+    pub fn enable_shared_plan_cache(&self) -> bool {
+        *self.expect_value(&ENABLE_SHARED_PLAN_CACHE)
+    }
+
     pub fn force_source_table_syntax(&self) -> bool {
         *self.expect_value(&FORCE_SOURCE_TABLE_SYNTAX)
     }
@@ -19,7 +19,9 @@ use mz_adapter_types::timestamp_oracle::{
     DEFAULT_PG_TIMESTAMP_ORACLE_CONNPOOL_MAX_SIZE, DEFAULT_PG_TIMESTAMP_ORACLE_CONNPOOL_MAX_WAIT,
     DEFAULT_PG_TIMESTAMP_ORACLE_CONNPOOL_TTL, DEFAULT_PG_TIMESTAMP_ORACLE_CONNPOOL_TTL_STAGGER,
 };
+use mz_compute_types::dyncfgs::COMPUTE_APPLY_COLUMN_DEMANDS;
 use mz_ore::cast::{self, CastFrom};
+use mz_persist_client::fetch::PART_PROJECTION_PUSHDOWN;
 use mz_repr::adt::numeric::Numeric;
 use mz_repr::adt::timestamp::CheckedTimestamp;
 use mz_repr::bytes::ByteSize;
@@ -1492,6 +1494,45 @@ pub static USER_STORAGE_MANAGED_COLLECTIONS_BATCH_DURATION: VarDefinition = VarD
     false,
 );
 
+pub static MAX_DURABLE_SUBSCRIPTION_LAG: VarDefinition = VarDefinition::new(
+    "max_durable_subscription_lag",
+    value!(Duration; Duration::from_secs(24 * 60 * 60)),
+    "The maximum amount of time a disconnected durable subscription may hold back compaction \
+    of its inputs before it is dropped (Materialize).",
+    false,
+);
+
+pub static DURABLE_SUBSCRIPTION_CHECKPOINT_INTERVAL: VarDefinition = VarDefinition::new(
+    "durable_subscription_checkpoint_interval",
+    value!(Duration; Duration::from_secs(10)),
+    "How often the acknowledged frontiers of durable subscriptions are written to the catalog \
+    (Materialize).",
+    false,
+);
+
+pub static REPLICATION_SLOT_CHECKPOINT_INTERVAL: VarDefinition = VarDefinition::new(
+    "replication_slot_checkpoint_interval",
+    value!(Duration; Duration::from_secs(10)),
+    "How often the LSNs that logical replication clients confirm flushing are written to the \
+    catalog (Materialize).",
+    false,
+);
+
+pub static PLAN_CACHE_MAX_ENTRIES: VarDefinition = VarDefinition::new(
+    "plan_cache_max_entries",
+    value!(usize; 1000),
+    "The maximum number of optimized query plans cached per session, or 0 to disable plan \
+    caching (Materialize).",
+    false,
+);
+
+pub static ENABLE_SHARED_PLAN_CACHE: VarDefinition = VarDefinition::new(
+    "enable_shared_plan_cache",
+    value!(bool; false),
+    "Whether optimized query plans are additionally cached across sessions (Materialize).",
+    false,
+);
+
 // This system var will need to point to the name of an existing network policy
 // this will be enforced on alter_system_set
 pub static NETWORK_POLICY: VarDefinition = VarDefinition::new_lazy(
@@ -1938,6 +1979,24 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_durable_subscriptions,
+        desc: "durable, resumable `SUBSCRIBE ... WITH (NAME = ...)`",
+        default: false,
+        enable_for_item_parsing: false,
+    },
+    {
+        name: enable_endpoints,
+        desc: "CREATE ENDPOINT and the HTTP endpoint API",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_listen_notify,
+        desc: "`LISTEN` and `NOTIFY`",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_cardinality_estimates,
         desc: "join planning with cardinality estimates",
@@ -2119,6 +2178,12 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_alter_table_alter_column,
+        desc: "Enable ALTER TABLE ... ALTER COLUMN ... TYPE ... and ALTER TABLE ... DROP COLUMN ...",
+        default: false,
+        enable_for_item_parsing: false,
+    },
     {
         name: enable_zero_downtime_cluster_reconfiguration,
         desc: "Enable zero-downtime reconfiguration for alter cluster",
@@ -2229,6 +2294,8 @@ impl From<&super::SystemVars> for OptimizerFeatures {
                 .enable_projection_pushdown_after_relation_cse(),
             enable_less_reduce_in_eqprop: vars.enable_less_reduce_in_eqprop(),
             enable_dequadratic_eqprop_map: vars.enable_dequadratic_eqprop_map(),
+            persist_projection_pushdown: PART_PROJECTION_PUSHDOWN.get(vars.dyncfgs())
+                && COMPUTE_APPLY_COLUMN_DEMANDS.get(vars.dyncfgs()),
         }
     }
 }
@@ -146,6 +146,14 @@ pub enum Record<'a> {
     },
     /// A `reset-server` directive
     ResetServer,
+    /// A `metric` directive.
+    Metric {
+        location: Location,
+        name: &'a str,
+        labels: Vec<(&'a str, &'a str)>,
+        output: Output,
+        output_str: &'a str,
+    },
 }
 
 /// Specifies the dialect of a sqllogictest file. Different sqllogictest runners
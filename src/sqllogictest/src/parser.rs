@@ -152,6 +152,8 @@ impl<'a> Parser<'a> {
 
             "reset-server" => Ok(Record::ResetServer),
 
+            "metric" => self.parse_metric(words),
+
             other => bail!(
                 "Unexpected start of record on line {}: {}",
                 line_number,
@@ -431,6 +433,38 @@ impl<'a> Parser<'a> {
             output_str,
         })
     }
+
+    fn parse_metric(
+        &mut self,
+        mut words: impl Iterator<Item = &'a str>,
+    ) -> Result<Record<'a>, anyhow::Error> {
+        static SEPARATOR_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"^----\r?(\n|$)").unwrap());
+
+        let location = self.location();
+        let name = words
+            .next()
+            .ok_or_else(|| anyhow!("metric directive missing metric name"))?;
+        let mut labels = Vec::new();
+        if let Some(options) = words.next() {
+            for label in options.split(',') {
+                let label = label
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("invalid label {:?} in {:?}", label, options))?;
+                labels.push(label);
+            }
+        }
+        self.split_at(&SEPARATOR_REGEX)?;
+        let output_str = self.split_at(&DOUBLE_LINE_REGEX)?.trim_start();
+        let output = Output::Values(output_str.lines().map(String::from).collect());
+        Ok(Record::Metric {
+            location,
+            name,
+            labels,
+            output,
+            output_str,
+        })
+    }
 }
 
 fn split_at<'a>(input: &mut &'a str, sep: &Regex) -> Result<&'a str, anyhow::Error> {
@@ -461,6 +461,10 @@ impl<'a> FromSql<'a> for Slt {
             PgType::ACLITEM => Self(Value::AclItem(AclItem::decode_binary(
                 types::bytea_from_sql(raw),
             )?)),
+            PgType::BIT => Self(Value::decode_binary(
+                &mz_pgrepr::Type::Bit { length: None },
+                raw,
+            )?),
             PgType::BOOL => Self(Value::Bool(types::bool_from_sql(raw)?)),
             PgType::BYTEA => Self(Value::Bytea(types::bytea_from_sql(raw).to_vec())),
             PgType::CHAR => Self(Value::Char(u8::from_be_bytes(
@@ -485,6 +489,10 @@ impl<'a> FromSql<'a> for Slt {
             PgType::TEXT | PgType::BPCHAR | PgType::VARCHAR => {
                 Self(Value::Text(types::text_from_sql(raw)?.to_string()))
             }
+            PgType::VARBIT => Self(Value::decode_binary(
+                &mz_pgrepr::Type::VarBit { max_length: None },
+                raw,
+            )?),
             PgType::TIME => Self(Value::Time(NaiveTime::from_sql(ty, raw)?)),
             PgType::TIMESTAMP => Self(Value::Timestamp(
                 NaiveDateTime::from_sql(ty, raw)?.try_into()?,
@@ -517,6 +525,30 @@ impl<'a> FromSql<'a> for Slt {
                 let range: Range<Slt> = Range::from_sql(ty, raw)?;
                 Self(Value::Range(range.into_bounds(|b| Box::new(b.0))))
             }
+            PgType::INT4MULTI_RANGE
+            | PgType::INT8MULTI_RANGE
+            | PgType::DATEMULTI_RANGE
+            | PgType::NUMMULTI_RANGE
+            | PgType::TSMULTI_RANGE
+            | PgType::TSTZMULTI_RANGE => {
+                use mz_repr::adt::range::Range;
+                let range_type = match *ty {
+                    PgType::INT4MULTI_RANGE => PgType::INT4_RANGE,
+                    PgType::INT8MULTI_RANGE => PgType::INT8_RANGE,
+                    PgType::DATEMULTI_RANGE => PgType::DATE_RANGE,
+                    PgType::NUMMULTI_RANGE => PgType::NUM_RANGE,
+                    PgType::TSMULTI_RANGE => PgType::TS_RANGE,
+                    PgType::TSTZMULTI_RANGE => PgType::TSTZ_RANGE,
+                    _ => unreachable!(),
+                };
+                let num_ranges = read_be_i32(&mut raw)?;
+                let mut ranges = vec![];
+                for _ in 0..num_ranges {
+                    let range: Range<Slt> = read_value(&range_type, &mut raw)?;
+                    ranges.push(range.into_bounds(|b| Box::new(b.0)));
+                }
+                Self(Value::MultiRange(ranges))
+            }
 
             _ => match ty.kind() {
                 PgKind::Array(arr_type) => {
@@ -601,6 +633,8 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::TEXT
                 | PgType::BPCHAR
                 | PgType::VARCHAR
+                | PgType::BIT
+                | PgType::VARBIT
                 | PgType::TIME
                 | PgType::TIMESTAMP
                 | PgType::TIMESTAMPTZ
@@ -617,6 +651,12 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::TS_RANGE_ARRAY
                 | PgType::TSTZ_RANGE
                 | PgType::TSTZ_RANGE_ARRAY
+                | PgType::INT4MULTI_RANGE
+                | PgType::INT8MULTI_RANGE
+                | PgType::DATEMULTI_RANGE
+                | PgType::NUMMULTI_RANGE
+                | PgType::TSMULTI_RANGE
+                | PgType::TSTZMULTI_RANGE
         )
     }
 }
@@ -1058,6 +1098,7 @@ impl<'a> RunnerInner<'a> {
                     },
                 },
             ],
+            flight: BTreeMap::new(),
         };
         let listeners = mz_environmentd::Listeners::bind(listeners_config).await?;
         let host_name = format!(
@@ -1090,6 +1131,7 @@ impl<'a> RunnerInner<'a> {
                 now: SYSTEM_TIME.clone(),
                 metrics_registry: metrics_registry.clone(),
                 persist_pubsub_url: format!("http://localhost:{}", persist_pubsub_server_port),
+                persist_blob_encryption_keyring: None,
                 secrets_args: mz_service::secrets::SecretsReaderCliArgs {
                     secrets_reader: mz_service::secrets::SecretsControllerKind::LocalFile,
                     secrets_reader_local_file_dir: Some(secrets_dir),
@@ -1162,6 +1204,8 @@ impl<'a> RunnerInner<'a> {
             helm_chart_version: None,
             license_key: ValidatedLicenseKey::for_tests(),
             external_login_password_mz_system: None,
+            certificate_mapping_rules: Default::default(),
+            oidc: None,
         };
         // We need to run the server on its own Tokio runtime, which in turn
         // requires its own thread, so that we can wait for any tasks spawned
@@ -1313,6 +1357,16 @@ impl<'a> RunnerInner<'a> {
                 self.run_simple(*conn, *user, sql, sort.clone(), output, location.clone())
                     .await
             }
+            Record::Metric {
+                name,
+                labels,
+                output,
+                location,
+                ..
+            } => {
+                self.run_metric(name, labels, output, location.clone())
+                    .await
+            }
             Record::Copy {
                 table_name,
                 tsv_path,
@@ -1821,6 +1875,52 @@ impl<'a> RunnerInner<'a> {
         }
     }
 
+    /// Checks the sum of the values of the samples of the metric `name` that have all of the
+    /// `labels`, as exported by environmentd.
+    async fn run_metric<'r>(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        output: &'r Output,
+        location: Location,
+    ) -> Result<Outcome<'r>, anyhow::Error> {
+        let url = format!("http://{}/metrics", self.internal_http_server_addr);
+        let metrics = reqwest::get(&url).await?.text().await?;
+
+        let mut value = 0.0;
+        for line in metrics.lines().filter(|line| !line.starts_with('#')) {
+            let Some((sample, sample_value)) = line.rsplit_once(' ') else {
+                continue;
+            };
+            let (sample_name, sample_labels) = match sample.split_once('{') {
+                Some((sample_name, sample_labels)) => {
+                    (sample_name, sample_labels.trim_end_matches('}'))
+                }
+                None => (sample, ""),
+            };
+            let matches = sample_name == name
+                && labels.iter().all(|(key, val)| {
+                    let label = format!("{key}=\"{val}\"");
+                    sample_labels.split(',').any(|l| l == label)
+                });
+            if matches {
+                value += sample_value.parse::<f64>()?;
+            }
+        }
+
+        let actual = Output::Values(vec![value.to_string()]);
+        if *output != actual {
+            Ok(Outcome::OutputFailure {
+                expected_output: output,
+                actual_raw_output: vec![],
+                actual_output: actual,
+                location,
+            })
+        } else {
+            Ok(Outcome::Success)
+        }
+    }
+
     async fn check_catalog(&self) -> Result<(), anyhow::Error> {
         let url = format!(
             "http://{}/api/catalog/check",
@@ -2199,6 +2299,10 @@ pub async fn rewrite_file(runner: &mut Runner<'_>, filename: &Path) -> Result<()
                 Record::Simple {
                     output_str: expected_output,
                     ..
+                }
+                | Record::Metric {
+                    output_str: expected_output,
+                    ..
                 },
                 Outcome::OutputFailure {
                     actual_output: Output::Values(actual_output),
@@ -768,6 +768,15 @@ mod columnation {
                         EvalError::PrettyError(x) => {
                             EvalError::PrettyError(self.string_region.copy(x))
                         }
+                        EvalError::InvalidJsonPath(x) => {
+                            EvalError::InvalidJsonPath(self.string_region.copy(x))
+                        }
+                        EvalError::JsonPathEvaluation(x) => {
+                            EvalError::JsonPathEvaluation(self.string_region.copy(x))
+                        }
+                        EvalError::InvalidBitString(x) => {
+                            EvalError::InvalidBitString(self.string_region.copy(x))
+                        }
                     };
                     let reference = self.eval_error_region.copy_iter(once(err));
                     let boxed = unsafe { Box::from_raw(reference.as_mut_ptr()) };
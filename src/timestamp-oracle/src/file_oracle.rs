@@ -0,0 +1,494 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A timestamp oracle backed by a log file in a local directory, for
+//! deployments that run without an external metadata database.
+//!
+//! The state of each timeline is stored in a [`FileConsensus`], which makes
+//! every change durable before it is acknowledged and lets several processes
+//! on the same host share the directory. Operations read the current state of
+//! their timeline and then compare-and-set the next one, so they are
+//! linearized by the log without any external precautions.
+//!
+//! A [`FileConsensus`] keeps the whole log in memory, so the oracles of all
+//! timelines opened from clones of one [`FileTimestampOracleConfig`] share a
+//! single handle to it.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::StreamExt;
+use mz_ore::instrument;
+use mz_ore::metrics::MetricsRegistry;
+use mz_ore::url::SensitiveUrl;
+use mz_persist::file::{FileConsensus, FileConsensusConfig};
+use mz_persist::location::{CaSResult, Consensus, SeqNo, VersionedData};
+use mz_repr::Timestamp;
+use tokio::sync::OnceCell;
+use tracing::{debug, info};
+
+use crate::metrics::Metrics;
+use crate::postgres_oracle::retry_fallible;
+use crate::{GenericNowFn, TimestampOracle, WriteTimestamp};
+
+/// The prefix of the consensus keys that hold the state of timelines, which
+/// keeps them apart from persist shards when the directory is shared.
+const KEY_PREFIX: &str = "tsoracle/";
+
+/// A [`TimestampOracle`] backed by a log file.
+#[derive(Debug)]
+pub struct FileTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp>,
+{
+    timeline: String,
+    key: String,
+    next: N,
+    consensus: Arc<FileConsensus>,
+    metrics: Arc<Metrics>,
+    /// A read-only timestamp oracle is NOT allowed to do operations that change
+    /// the backing log.
+    read_only: bool,
+}
+
+/// Configuration of a file-backed implementation of [`TimestampOracle`].
+#[derive(Clone, Debug)]
+pub struct FileTimestampOracleConfig {
+    base_dir: PathBuf,
+    /// The log, opened on first use and shared by all clones of this config.
+    consensus: Arc<OnceCell<Arc<FileConsensus>>>,
+    pub metrics: Arc<Metrics>,
+}
+
+impl FileTimestampOracleConfig {
+    /// Returns a new instance of [`FileTimestampOracleConfig`] for the
+    /// directory of the `file://` URL `url`.
+    pub fn new(url: &SensitiveUrl, metrics_registry: &MetricsRegistry) -> Self {
+        FileTimestampOracleConfig {
+            base_dir: PathBuf::from(url.path()),
+            consensus: Arc::new(OnceCell::new()),
+            metrics: Arc::new(Metrics::new(metrics_registry)),
+        }
+    }
+
+    /// Returns the shared handle to the log, opening it if this is the first
+    /// use.
+    async fn open_consensus(&self) -> Result<Arc<FileConsensus>, anyhow::Error> {
+        let consensus = self
+            .consensus
+            .get_or_try_init(|| async {
+                let consensus =
+                    FileConsensus::open(FileConsensusConfig::from(&self.base_dir)).await?;
+                Ok::<_, anyhow::Error>(Arc::new(consensus))
+            })
+            .await?;
+        Ok(Arc::clone(consensus))
+    }
+}
+
+/// The durable state of a timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimelineState {
+    read_ts: Timestamp,
+    write_ts: Timestamp,
+}
+
+impl TimelineState {
+    fn encode(&self) -> Bytes {
+        let mut buf = Vec::with_capacity(16);
+        buf.extend_from_slice(&u64::from(self.read_ts).to_be_bytes());
+        buf.extend_from_slice(&u64::from(self.write_ts).to_be_bytes());
+        Bytes::from(buf)
+    }
+
+    fn decode(data: &[u8]) -> Result<Self, anyhow::Error> {
+        let data = <[u8; 16]>::try_from(data)
+            .map_err(|_| anyhow!("invalid timestamp oracle state: {data:?}"))?;
+        let (read_ts, write_ts) = data.split_at(8);
+        Ok(TimelineState {
+            read_ts: u64::from_be_bytes(read_ts.try_into().expect("known to fit")).into(),
+            write_ts: u64::from_be_bytes(write_ts.try_into().expect("known to fit")).into(),
+        })
+    }
+}
+
+impl<N> FileTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    /// Open a file-backed [`TimestampOracle`] instance with `config`, for the
+    /// timeline named `timeline`. `next` generates new timestamps when invoked.
+    /// Timestamps that are returned are made durable and will never retract.
+    pub async fn open(
+        config: FileTimestampOracleConfig,
+        timeline: String,
+        initially: Timestamp,
+        next: N,
+        read_only: bool,
+    ) -> Self {
+        info!(config = ?config, "opening FileTimestampOracle");
+
+        let fallible = || async {
+            let oracle = FileTimestampOracle {
+                key: format!("{KEY_PREFIX}{timeline}"),
+                timeline: timeline.clone(),
+                next: next.clone(),
+                consensus: config.open_consensus().await?,
+                metrics: Arc::clone(&config.metrics),
+                read_only,
+            };
+
+            // Create the state of our timeline, if it doesn't exist, so that
+            // the other operations can expect it to be present.
+            if oracle.consensus.head(&oracle.key).await?.is_none() {
+                let state = TimelineState {
+                    read_ts: initially,
+                    write_ts: initially,
+                };
+                let new = VersionedData {
+                    seqno: SeqNo::minimum(),
+                    data: state.encode(),
+                };
+                // Losing the race against another process that created the
+                // state is fine, `apply_write` below forwards it.
+                let _ = oracle
+                    .consensus
+                    .compare_and_set(&oracle.key, None, new)
+                    .await?;
+            }
+
+            // Forward timestamps to what we're given from outside. Remember,
+            // the state is only created at the initial timestamp if it didn't
+            // exist before.
+            if !read_only {
+                oracle.fallible_apply_write(initially).await?;
+            }
+
+            Result::<_, anyhow::Error>::Ok(oracle)
+        };
+
+        let metrics = &config.metrics.retries.open;
+
+        retry_fallible(metrics, fallible).await
+    }
+
+    /// Returns a `Vec` of all known timelines along with their current greatest
+    /// timestamp (max of read_ts and write_ts).
+    ///
+    /// For use when initializing another [`TimestampOracle`] implementation
+    /// from another oracle's state.
+    pub async fn get_all_timelines(
+        config: FileTimestampOracleConfig,
+    ) -> Result<Vec<(String, Timestamp)>, anyhow::Error> {
+        let fallible = || async {
+            let consensus = config.open_consensus().await?;
+            let keys: Vec<_> = consensus.list_keys().collect().await;
+
+            let mut result = Vec::new();
+            for key in keys {
+                let key = key?;
+                let Some(timeline) = key.strip_prefix(KEY_PREFIX) else {
+                    continue;
+                };
+                if let Some(current) = consensus.head(&key).await? {
+                    let state = TimelineState::decode(&current.data)?;
+                    let ts = std::cmp::max(state.read_ts, state.write_ts);
+                    result.push((timeline.to_owned(), ts));
+                }
+            }
+            Ok(result)
+        };
+
+        let metrics = &config.metrics.retries.get_all_timelines;
+
+        let result = retry_fallible(metrics, fallible).await;
+
+        Ok(result)
+    }
+
+    /// Returns the current state of the timeline and its sequence number.
+    async fn current(&self) -> Result<(SeqNo, TimelineState), anyhow::Error> {
+        match self.consensus.head(&self.key).await? {
+            Some(current) => Ok((current.seqno, TimelineState::decode(&current.data)?)),
+            None => Err(anyhow!("missing state for timeline {}", self.timeline)),
+        }
+    }
+
+    /// Replaces the state of the timeline with `update` applied to it, and
+    /// returns the new state.
+    async fn update(
+        &self,
+        update: impl Fn(TimelineState) -> TimelineState,
+    ) -> Result<TimelineState, anyhow::Error> {
+        loop {
+            let (seqno, state) = self.current().await?;
+            let new_state = update(state);
+            if new_state == state {
+                return Ok(state);
+            }
+            let new = VersionedData {
+                seqno: seqno.next(),
+                data: new_state.encode(),
+            };
+            match self
+                .consensus
+                .compare_and_set(&self.key, Some(seqno), new)
+                .await?
+            {
+                CaSResult::Committed => {
+                    // Only the latest state is ever read, so drop the older
+                    // ones to let the log compact them away.
+                    self.consensus.truncate(&self.key, seqno.next()).await?;
+                    return Ok(new_state);
+                }
+                CaSResult::ExpectationMismatch => continue,
+            }
+        }
+    }
+
+    #[mz_ore::instrument(name = "oracle::write_ts")]
+    async fn fallible_write_ts(&self) -> Result<WriteTimestamp<Timestamp>, anyhow::Error> {
+        if self.read_only {
+            panic!("attempting write_ts in read-only mode");
+        }
+
+        let proposed_next_ts = self.next.now();
+        let state = self
+            .update(|state| TimelineState {
+                read_ts: state.read_ts,
+                write_ts: std::cmp::max(state.write_ts.step_forward(), proposed_next_ts),
+            })
+            .await?;
+        let write_ts = state.write_ts;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            proposed_next_ts = ?proposed_next_ts,
+            "returning from write_ts()");
+
+        let advance_to = write_ts.step_forward();
+
+        Ok(WriteTimestamp {
+            timestamp: write_ts,
+            advance_to,
+        })
+    }
+
+    #[mz_ore::instrument(name = "oracle::peek_write_ts")]
+    async fn fallible_peek_write_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let (_, state) = self.current().await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?state.write_ts,
+            "returning from peek_write_ts()");
+
+        Ok(state.write_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::read_ts")]
+    async fn fallible_read_ts(&self) -> Result<Timestamp, anyhow::Error> {
+        let (_, state) = self.current().await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            read_ts = ?state.read_ts,
+            "returning from read_ts()");
+
+        Ok(state.read_ts)
+    }
+
+    #[mz_ore::instrument(name = "oracle::apply_write")]
+    async fn fallible_apply_write(&self, write_ts: Timestamp) -> Result<(), anyhow::Error> {
+        if self.read_only {
+            panic!("attempting apply_write in read-only mode");
+        }
+
+        self.update(|state| TimelineState {
+            read_ts: std::cmp::max(state.read_ts, write_ts),
+            write_ts: std::cmp::max(state.write_ts, write_ts),
+        })
+        .await?;
+
+        debug!(
+            timeline = ?self.timeline,
+            write_ts = ?write_ts,
+            "returning from apply_write()");
+
+        Ok(())
+    }
+}
+
+// A wrapper around the `fallible_` methods that adds operation metrics and
+// retries.
+#[async_trait]
+impl<N> TimestampOracle<Timestamp> for FileTimestampOracle<N>
+where
+    N: GenericNowFn<Timestamp> + std::fmt::Debug + 'static,
+{
+    #[instrument]
+    async fn write_ts(&self) -> WriteTimestamp<Timestamp> {
+        let metrics = &self.metrics.retries.write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .write_ts
+                .run_op(|| self.fallible_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn peek_write_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.peek_write_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .peek_write_ts
+                .run_op(|| self.fallible_peek_write_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn read_ts(&self) -> Timestamp {
+        let metrics = &self.metrics.retries.read_ts;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .read_ts
+                .run_op(|| self.fallible_read_ts())
+        })
+        .await
+    }
+
+    #[instrument]
+    async fn apply_write(&self, write_ts: Timestamp) {
+        let metrics = &self.metrics.retries.apply_write;
+
+        retry_fallible(metrics, || {
+            self.metrics
+                .oracle
+                .apply_write
+                .run_op(|| self.fallible_apply_write(write_ts))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use mz_ore::now::NowFn;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock`
+    async fn test_file_timestamp_oracle() -> Result<(), anyhow::Error> {
+        let dir = tempfile::tempdir()?;
+        let url = SensitiveUrl::from_str(&format!("file://{}", dir.path().display()))?;
+        let config = FileTimestampOracleConfig::new(&url, &MetricsRegistry::new());
+
+        crate::tests::timestamp_oracle_impl_test(|timeline, now_fn: NowFn, initial_ts| {
+            let oracle = FileTimestampOracle::open(
+                config.clone(),
+                timeline,
+                initial_ts,
+                now_fn,
+                false, /* read-only */
+            );
+
+            async {
+                let arced_oracle: Arc<dyn TimestampOracle<Timestamp> + Send + Sync> =
+                    Arc::new(oracle.await);
+
+                arced_oracle
+            }
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock`
+    async fn test_file_timestamp_oracle_reopen() -> Result<(), anyhow::Error> {
+        let dir = tempfile::tempdir()?;
+        let url = SensitiveUrl::from_str(&format!("file://{}", dir.path().display()))?;
+        let config = FileTimestampOracleConfig::new(&url, &MetricsRegistry::new());
+
+        let oracle = FileTimestampOracle::open(
+            config.clone(),
+            "timeline".to_owned(),
+            Timestamp::MIN,
+            NowFn::from(|| 0u64),
+            false, /* read-only */
+        )
+        .await;
+        oracle.apply_write(5u64.into()).await;
+        assert_eq!(oracle.write_ts().await.timestamp, Timestamp::from(6u64));
+        drop(oracle);
+
+        // Timestamps survive reopening the log, as after a restart.
+        let config = FileTimestampOracleConfig::new(&url, &MetricsRegistry::new());
+        let oracle = FileTimestampOracle::open(
+            config.clone(),
+            "timeline".to_owned(),
+            Timestamp::MIN,
+            NowFn::from(|| 0u64),
+            false, /* read-only */
+        )
+        .await;
+        assert_eq!(oracle.read_ts().await, Timestamp::from(5u64));
+        assert_eq!(oracle.peek_write_ts().await, Timestamp::from(6u64));
+        assert_eq!(
+            FileTimestampOracle::<NowFn>::get_all_timelines(config).await?,
+            vec![("timeline".to_owned(), Timestamp::from(6u64))]
+        );
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: can't call foreign function `flock`
+    async fn test_file_timestamp_oracle_shared_log() -> Result<(), anyhow::Error> {
+        let dir = tempfile::tempdir()?;
+        let url = SensitiveUrl::from_str(&format!("file://{}", dir.path().display()))?;
+        let config = FileTimestampOracleConfig::new(&url, &MetricsRegistry::new());
+
+        let open = |timeline: &str| {
+            FileTimestampOracle::open(
+                config.clone(),
+                timeline.to_owned(),
+                Timestamp::MIN,
+                NowFn::from(|| 0u64),
+                false, /* read-only */
+            )
+        };
+        let a = open("a").await;
+        let b = open("b").await;
+        // The oracles of different timelines share one in-memory log.
+        assert!(Arc::ptr_eq(&a.consensus, &b.consensus));
+
+        a.apply_write(5u64.into()).await;
+        assert_eq!(a.read_ts().await, Timestamp::from(5u64));
+        assert_eq!(b.read_ts().await, Timestamp::MIN);
+
+        Ok(())
+    }
+}
@@ -14,10 +14,19 @@
 //! reported completed write timestamps, and strictly less than all subsequently
 //! emitted write timestamps.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{EpochMillis, NowFn};
+use mz_ore::url::SensitiveUrl;
+
+use crate::file_oracle::{FileTimestampOracle, FileTimestampOracleConfig};
+use crate::metrics::Metrics;
+use crate::postgres_oracle::{PostgresTimestampOracle, PostgresTimestampOracleConfig};
 
 pub mod batching_oracle;
+pub mod file_oracle;
 pub mod metrics;
 pub mod postgres_oracle;
 pub mod retry;
@@ -62,6 +71,76 @@ pub trait TimestampOracle<T>: std::fmt::Debug {
     async fn apply_write(&self, lower_bound: T);
 }
 
+/// Configuration of the durable backend of a [`TimestampOracle`].
+#[derive(Clone, Debug)]
+pub enum TimestampOracleConfig {
+    /// An oracle backed by "Postgres".
+    Postgres(PostgresTimestampOracleConfig),
+    /// An oracle backed by a log file in a local directory.
+    File(FileTimestampOracleConfig),
+}
+
+impl TimestampOracleConfig {
+    /// Returns the configuration of the backend named by the scheme of `url`:
+    /// a local directory for `file://` URLs, and "Postgres" otherwise.
+    pub fn new(url: &SensitiveUrl, metrics_registry: &MetricsRegistry) -> Self {
+        match url.scheme() {
+            "file" => {
+                TimestampOracleConfig::File(FileTimestampOracleConfig::new(url, metrics_registry))
+            }
+            _ => TimestampOracleConfig::Postgres(PostgresTimestampOracleConfig::new(
+                url,
+                metrics_registry,
+            )),
+        }
+    }
+
+    /// The metrics of oracles opened with this configuration.
+    pub fn metrics(&self) -> &Arc<Metrics> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => &config.metrics,
+            TimestampOracleConfig::File(config) => &config.metrics,
+        }
+    }
+
+    /// Opens an oracle for the timeline named `timeline`. See
+    /// [`PostgresTimestampOracle::open`].
+    pub async fn open<N>(
+        self,
+        timeline: String,
+        initially: mz_repr::Timestamp,
+        next: N,
+        read_only: bool,
+    ) -> Arc<dyn TimestampOracle<mz_repr::Timestamp> + Send + Sync>
+    where
+        N: GenericNowFn<mz_repr::Timestamp> + std::fmt::Debug + 'static,
+    {
+        match self {
+            TimestampOracleConfig::Postgres(config) => Arc::new(
+                PostgresTimestampOracle::open(config, timeline, initially, next, read_only).await,
+            ),
+            TimestampOracleConfig::File(config) => Arc::new(
+                FileTimestampOracle::open(config, timeline, initially, next, read_only).await,
+            ),
+        }
+    }
+
+    /// Returns all known timelines along with their current greatest timestamp.
+    /// See [`PostgresTimestampOracle::get_all_timelines`].
+    pub async fn get_all_timelines(
+        &self,
+    ) -> Result<Vec<(String, mz_repr::Timestamp)>, anyhow::Error> {
+        match self {
+            TimestampOracleConfig::Postgres(config) => {
+                PostgresTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
+            }
+            TimestampOracleConfig::File(config) => {
+                FileTimestampOracle::<NowFn>::get_all_timelines(config.clone()).await
+            }
+        }
+    }
+}
+
 /// A [`NowFn`] that is generic over the timestamp.
 ///
 /// The oracle operations work in terms of [`mz_repr::Timestamp`] and we could
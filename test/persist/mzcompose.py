@@ -50,7 +50,7 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
     parser.add_argument(
         "--consensus",
         type=str,
-        choices=["mem", "cockroach", "maelstrom", "postgres"],
+        choices=["mem", "file", "cockroach", "maelstrom", "postgres"],
         default="maelstrom",
     )
     parser.add_argument(
@@ -64,6 +64,9 @@ def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
 
     if args.consensus == "mem":
         consensus_uri = "mem://consensus"
+    elif args.consensus == "file":
+        # All maelstrom nodes run in the same container and share the log.
+        consensus_uri = "file:///store/consensus"
     elif args.consensus == "cockroach":
         consensus_uri = (
             "postgres://root@cockroach:26257?options=--search_path=consensus"
@@ -112,7 +112,7 @@ def run_test_with_mv_on_table(c: Composition) -> None:
             4 501
 
             ! SELECT count(*) FROM retain_history_mv1 AS OF '{mz_time0}'::TIMESTAMP;
-            contains: is not valid for all inputs
+            contains: is outside the retained history
 
             > SELECT count(*) >= 2 FROM retain_history_mv1 AS OF AT LEAST '{mz_time1}'::TIMESTAMP;
             true
@@ -190,7 +190,7 @@ def run_test_with_mv_on_table(c: Composition) -> None:
               LEFT OUTER JOIN retain_history_mv1 mv
               ON t.key = mv.key
               AS OF '{mz_time2}'::TIMESTAMP;
-            contains: is not valid for all inputs
+            contains: is outside the retained history
 
             > UPDATE retain_history_table SET key = 9 WHERE key = 1;
             """,
@@ -230,7 +230,7 @@ def run_test_with_mv_on_table(c: Composition) -> None:
             f"""
             # retain period exceeded
             ! SELECT * FROM retain_history_mv_on_mv1 AS OF '{mz_time1}'::TIMESTAMP;
-            contains: is not valid for all inputs
+            contains: is outside the retained history
 
             # retain period on wrapping mv still valid
             > SELECT * FROM retain_history_mv_on_mv_on_mv1 AS OF '{mz_time1}'::TIMESTAMP;
@@ -303,10 +303,10 @@ def run_test_with_mv_on_table_with_altered_retention(c: Composition) -> None:
         dedent(
             f"""
             ! SELECT count(*) FROM retain_history_mv AS OF '{mz_time2}'::TIMESTAMP; -- mz_time2
-            contains: is not valid for all inputs
+            contains: is outside the retained history
 
             ! SELECT count(*) FROM retain_history_mv AS OF '{mz_time3}'::TIMESTAMP; -- mz_time3
-            contains: is not valid for all inputs
+            contains: is outside the retained history
 
             > SELECT count(*) FROM retain_history_mv;
             4
@@ -339,7 +339,7 @@ def run_test_with_mv_on_table_with_altered_retention(c: Composition) -> None:
             f"""
             # do not expect to regain old states
             ! SELECT count(*) FROM retain_history_mv AS OF '{mz_time3}'::TIMESTAMP; -- mz_time3
-            contains: is not valid for all inputs
+            contains: is outside the retained history
 
             # expect the new retention period to apply
             > SELECT count(*) FROM retain_history_mv AS OF '{mz_time4}'::TIMESTAMP; -- mz_time4